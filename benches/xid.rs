@@ -48,6 +48,39 @@ fn gen_string(p_nonascii: u32) -> String {
     string
 }
 
+fn gen_idents(p_nonascii: u32) -> String {
+    let mut rng = SmallRng::from_seed([b'!'; 32]);
+    let pick_nonascii = Bernoulli::from_ratio(p_nonascii, 100).unwrap();
+    let len = Uniform::new_inclusive(1, 32).unwrap();
+    let ascii = Uniform::new_inclusive('\0', '\x7f').unwrap();
+    let nonascii = Uniform::new_inclusive(0x80 as char, char::MAX).unwrap();
+
+    let mut string = String::new();
+    while string.len() < 500_000 {
+        for i in 0..len.sample(&mut rng) {
+            let distribution = if pick_nonascii.sample(&mut rng) {
+                nonascii
+            } else {
+                ascii
+            };
+            let is_valid = if i == 0 {
+                unicode_ident::is_xid_start
+            } else {
+                unicode_ident::is_xid_continue
+            };
+            string.push(
+                distribution
+                    .sample_iter(&mut rng)
+                    .find(|&ch| is_valid(ch))
+                    .unwrap(),
+            );
+        }
+        string.push(' ');
+    }
+
+    string
+}
+
 fn bench(c: &mut Criterion, group_name: &str, string: String) {
     let mut group = c.benchmark_group(group_name);
     group.measurement_time(Duration::from_secs(10));
@@ -106,6 +139,74 @@ fn bench(c: &mut Criterion, group_name: &str, string: String) {
     group.finish();
 }
 
+// Tokenize a string consisting of identifiers separated by spaces, comparing
+// per-char calls against scanning a whole identifier at once.
+fn bench_scan(c: &mut Criterion, group_name: &str, string: String) {
+    let mut group = c.benchmark_group(group_name);
+    group.measurement_time(Duration::from_secs(10));
+    group.bench_function("per-char", |b| {
+        b.iter(|| {
+            let mut rest = string.as_str();
+            while let Some(ch) = rest.chars().next() {
+                let len = if unicode_ident::is_xid_start(ch) {
+                    rest.char_indices()
+                        .skip(1)
+                        .find(|&(_, ch)| !unicode_ident::is_xid_continue(ch))
+                        .map_or(rest.len(), |(i, _)| i)
+                } else {
+                    ch.len_utf8()
+                };
+                black_box(&rest[..len]);
+                rest = &rest[len..];
+            }
+        });
+    });
+    group.bench_function("scan_ident", |b| {
+        b.iter(|| {
+            let mut rest = string.as_str();
+            while let Some(ch) = rest.chars().next() {
+                let len = match unicode_ident::scan_ident(rest) {
+                    0 => ch.len_utf8(),
+                    len => len,
+                };
+                black_box(&rest[..len]);
+                rest = &rest[len..];
+            }
+        });
+    });
+    group.finish();
+}
+
+// Script and Script_Extensions are looked up by binary search over runs of
+// codepoints, rather than in a trie like the boolean properties. Compare them
+// against a trie lookup of similar cost per character.
+fn bench_script(c: &mut Criterion, group_name: &str, string: String) {
+    let mut group = c.benchmark_group(group_name);
+    group.measurement_time(Duration::from_secs(10));
+    group.bench_function("is_xid_start", |b| {
+        b.iter(|| {
+            for ch in string.chars() {
+                black_box(unicode_ident::is_xid_start(ch));
+            }
+        });
+    });
+    group.bench_function("script", |b| {
+        b.iter(|| {
+            for ch in string.chars() {
+                black_box(unicode_ident::script(ch));
+            }
+        });
+    });
+    group.bench_function("script_extensions", |b| {
+        b.iter(|| {
+            for ch in string.chars() {
+                black_box(unicode_ident::script_extensions(ch));
+            }
+        });
+    });
+    group.finish();
+}
+
 fn bench0(c: &mut Criterion) {
     bench(c, "0%-nonascii", gen_string(0));
 }
@@ -122,5 +223,31 @@ fn bench100(c: &mut Criterion) {
     bench(c, "100%-nonascii", gen_string(100));
 }
 
-criterion_group!(benches, bench0, bench1, bench10, bench100);
+fn bench_scan0(c: &mut Criterion) {
+    bench_scan(c, "scan-0%-nonascii", gen_idents(0));
+}
+
+fn bench_scan10(c: &mut Criterion) {
+    bench_scan(c, "scan-10%-nonascii", gen_idents(10));
+}
+
+fn bench_script10(c: &mut Criterion) {
+    bench_script(c, "script-10%-nonascii", gen_string(10));
+}
+
+fn bench_script100(c: &mut Criterion) {
+    bench_script(c, "script-100%-nonascii", gen_string(100));
+}
+
+criterion_group!(
+    benches,
+    bench0,
+    bench1,
+    bench10,
+    bench100,
+    bench_scan0,
+    bench_scan10,
+    bench_script10,
+    bench_script100,
+);
 criterion_main!(benches);
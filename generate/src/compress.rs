@@ -0,0 +1,207 @@
+use crate::CHUNK;
+use std::collections::BTreeMap as Map;
+
+// Bitmaps of one or more properties, compressed together into a shared pool of
+// leaf chunks.
+pub struct Tries {
+    // For each property, one index per 512 codepoints into the leaf chunks, in
+    // units of half a chunk. Trailing zeros are trimmed.
+    pub index: Vec<Vec<u8>>,
+    pub leaf: Vec<u8>,
+}
+
+// ASCII is not included in the tries, because the runtime looks it up in a
+// separate bitmap.
+pub fn compress(properties: &[&dyn Fn(char) -> bool]) -> Tries {
+    let mut chunkmap = Map::<[u8; CHUNK], u8>::new();
+    let mut dense = Vec::<[u8; CHUNK]>::new();
+    let mut new_chunk = |chunk| {
+        if let Some(prev) = chunkmap.get(&chunk) {
+            *prev
+        } else {
+            dense.push(chunk);
+            let Ok(new) = u8::try_from(chunkmap.len()) else {
+                panic!("exceeded 256 unique chunks");
+            };
+            chunkmap.insert(chunk, new);
+            new
+        }
+    };
+
+    let empty_chunk = [0u8; CHUNK];
+    new_chunk(empty_chunk);
+
+    let mut index = vec![Vec::<u8>::new(); properties.len()];
+    for i in 0..(u32::from(char::MAX) + 1) / CHUNK as u32 / 8 {
+        for (property, index) in properties.iter().zip(&mut index) {
+            let mut bits = empty_chunk;
+            for j in 0..CHUNK as u32 {
+                let this = &mut bits[j as usize];
+                for k in 0..8u32 {
+                    let code = (i * CHUNK as u32 + j) * 8 + k;
+                    if code >= 0x80 {
+                        if let Some(ch) = char::from_u32(code) {
+                            *this |= (property(ch) as u8) << k;
+                        }
+                    }
+                }
+            }
+            index.push(new_chunk(bits));
+        }
+    }
+
+    for index in &mut index {
+        while let Some(0) = index.last() {
+            index.pop();
+        }
+    }
+
+    // Compress the leaf array by overlapping chunks at half-chunk boundaries.
+    //
+    // If chunk i's back half equals chunk j's front half, placing them
+    // adjacently saves 32 bytes. We find the maximum number of such overlaps by
+    // modeling this as a bipartite matching problem (left side = back halves,
+    // right side = front halves) and solving with Kuhn's algorithm.
+
+    let num_chunks = dense.len();
+
+    let front_of: Vec<[u8; CHUNK / 2]> = dense
+        .iter()
+        .map(|c| c[..CHUNK / 2].try_into().unwrap())
+        .collect();
+    let back_of: Vec<[u8; CHUNK / 2]> = dense
+        .iter()
+        .map(|c| c[CHUNK / 2..].try_into().unwrap())
+        .collect();
+
+    // Build index from front-half value to chunk indices for efficient lookup.
+    let mut chunks_by_front: Map<[u8; CHUNK / 2], Vec<usize>> = Map::new();
+    for (j, &front) in front_of.iter().enumerate() {
+        chunks_by_front
+            .entry(front)
+            .or_insert_with(Vec::new)
+            .push(j);
+    }
+
+    // adj_list[i] = chunks whose front half matches chunk i's back half,
+    // meaning they can follow chunk i with a 32-byte overlap. Exclude
+    // self-edges (the all-zeros and all-ones chunks have front == back).
+    let adj_list: Vec<Vec<usize>> = (0..num_chunks)
+        .map(|i| {
+            chunks_by_front
+                .get(&back_of[i])
+                .map_or_else(Vec::new, |js| {
+                    js.iter().copied().filter(|&j| j != i).collect()
+                })
+        })
+        .collect();
+
+    // Maximum bipartite matching via Kuhn's algorithm (augmenting paths).
+    // prev_of[j] = Some(i) means chunk i is matched to precede chunk j.
+    let mut prev_of: Vec<Option<usize>> = vec![None; num_chunks];
+
+    // DFS for an augmenting path from `src`. If found, augments the matching
+    // in-place (rehoming existing matches to preserve validity) and returns
+    // true.
+    fn try_kuhn(
+        src: usize,
+        adj_list: &[Vec<usize>],
+        visited: &mut [bool],
+        prev_of: &mut [Option<usize>],
+    ) -> bool {
+        for &dst in &adj_list[src] {
+            if !visited[dst] {
+                visited[dst] = true;
+                // If dst is free, or its current match can be rehomed, claim dst.
+                if prev_of[dst].is_none_or(|prev| try_kuhn(prev, adj_list, visited, prev_of)) {
+                    prev_of[dst] = Some(src);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    // Try every left vertex. A failed attempt stays failed because later rounds
+    // only shrink the set of free right vertices (Berge's theorem).
+    for i in 0..num_chunks {
+        let mut visited = vec![false; num_chunks];
+        try_kuhn(i, &adj_list, &mut visited, &mut prev_of);
+    }
+
+    // Invert the matching into a forward map for chain traversal.
+    let mut next_of: Vec<Option<usize>> = vec![None; num_chunks];
+    for (j, &prev) in prev_of.iter().enumerate() {
+        if let Some(prev) = prev {
+            next_of[prev] = Some(j);
+        }
+    }
+
+    // Chunk 0 (all zeros) is special and must be laid out first at halfdense
+    // position 0, because the runtime defaults to index 0 for codepoints beyond
+    // the trie. Remove any incoming edge so chunk 0 becomes a chain start.
+    if let Some(prev) = prev_of[0] {
+        next_of[prev] = None;
+        prev_of[0] = None;
+    }
+
+    // Lay out chains into halfdense, starting with chunk 0's chain.
+    let mut halfdense = Vec::<u8>::new();
+    let mut dense_to_halfdense = Map::<u8, u8>::new();
+
+    for start in (0..num_chunks).filter(|&i| prev_of[i].is_none()) {
+        dense_to_halfdense.insert(
+            start as u8,
+            u8::try_from(halfdense.len() / (CHUNK / 2)).expect("exceeded 256 half-chunks"),
+        );
+        halfdense.extend_from_slice(&front_of[start]);
+        halfdense.extend_from_slice(&back_of[start]);
+
+        // Write the rest of the chain: each chunk's front half overlaps the
+        // previous chunk's back half, so only append the back half.
+        let mut curr = start;
+        while let Some(next) = next_of[curr] {
+            dense_to_halfdense.insert(
+                next as u8,
+                u8::try_from(halfdense.len() / (CHUNK / 2) - 1).expect("exceeded 256 half-chunks"),
+            );
+            halfdense.extend_from_slice(&back_of[next]);
+            curr = next;
+        }
+    }
+
+    // Each chunk can be both a predecessor (back half) and a successor (front
+    // half), so next_of can form cycles with no chain start. We broke chunk 0's
+    // cycle above; verify no others exist.
+    assert_eq!(
+        dense_to_halfdense.len(),
+        num_chunks,
+        "not all chunks were laid out",
+    );
+
+    for index in index.iter_mut().flatten() {
+        *index = dense_to_halfdense[index];
+    }
+
+    Tries {
+        index,
+        leaf: halfdense,
+    }
+}
+
+// Properties with many distinct values, such as Script, do not deduplicate well
+// into chunks. Instead store the sorted list of codepoints at which the value
+// changes, each packed together with the new value as `codepoint << 8 | value`.
+// A lookup binary searches for the last entry at or below the codepoint.
+pub fn runs(mut property: impl FnMut(char) -> u8) -> Vec<u32> {
+    let mut runs = Vec::new();
+    let mut prev = None;
+    for ch in '\0'..=char::MAX {
+        let value = property(ch);
+        if prev != Some(value) {
+            runs.push((ch as u32) << 8 | value as u32);
+            prev = Some(value);
+        }
+    }
+    runs
+}
@@ -15,17 +15,18 @@
     clippy::let_underscore_untyped,
     clippy::match_wild_err_arm,
     clippy::module_name_repetitions,
+    clippy::too_many_arguments,
     clippy::too_many_lines,
     clippy::uninlined_format_args,
     clippy::unwrap_or_default
 )]
 
+mod compress;
 mod output;
 mod parse;
 mod write;
 
-use crate::parse::parse_xid_properties;
-use std::collections::BTreeMap as Map;
+use crate::parse::parse_properties;
 use std::fs;
 use std::io::{self, Write};
 use std::path::Path;
@@ -39,186 +40,274 @@ fn main() {
     let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
     let unicode_ident_dir = manifest_dir.parent().unwrap();
     let ucd_dir = unicode_ident_dir.join(UCD);
-    let properties = parse_xid_properties(&ucd_dir);
+    let properties = parse_properties(&ucd_dir);
 
-    let mut chunkmap = Map::<[u8; CHUNK], u8>::new();
-    let mut dense = Vec::<[u8; CHUNK]>::new();
-    let mut new_chunk = |chunk| {
-        if let Some(prev) = chunkmap.get(&chunk) {
-            *prev
-        } else {
-            dense.push(chunk);
-            let Ok(new) = u8::try_from(chunkmap.len()) else {
-                panic!("exceeded 256 unique chunks");
-            };
-            chunkmap.insert(chunk, new);
-            new
-        }
-    };
-
-    let empty_chunk = [0u8; CHUNK];
-    new_chunk(empty_chunk);
-
-    let mut index_start = Vec::<u8>::new();
-    let mut index_continue = Vec::<u8>::new();
-    for i in 0..(u32::from(char::MAX) + 1) / CHUNK as u32 / 8 {
-        let mut start_bits = empty_chunk;
-        let mut continue_bits = empty_chunk;
-        for j in 0..CHUNK as u32 {
-            let this_start = &mut start_bits[j as usize];
-            let this_continue = &mut continue_bits[j as usize];
-            for k in 0..8u32 {
-                let code = (i * CHUNK as u32 + j) * 8 + k;
-                if code >= 0x80 {
-                    if let Some(ch) = char::from_u32(code) {
-                        *this_start |= (properties.is_xid_start(ch) as u8) << k;
-                        *this_continue |= (properties.is_xid_continue(ch) as u8) << k;
-                    }
-                }
-            }
+    let xid = compress::compress(&[&|ch| properties.is_xid_start(ch), &|ch| {
+        properties.is_xid_continue(ch)
+    }]);
+
+    // XID_Start and XID_Continue are ID_Start and ID_Continue with a handful of
+    // codepoints removed to make them closed under NFKC. Rather than a second
+    // copy of nearly identical tries, the runtime looks up ID properties as
+    // XID plus a sparse trie of just the removed codepoints, with its own leaf
+    // chunks so that the XID tables stay as small as before. There are none of
+    // those in ASCII, so no separate ASCII bitmaps are needed either.
+    for ch in '\0'..=char::MAX {
+        assert!(!properties.is_xid_start(ch) || properties.is_id_start(ch));
+        assert!(!properties.is_xid_continue(ch) || properties.is_id_continue(ch));
+        if ch.is_ascii() {
+            assert_eq!(properties.is_xid_start(ch), properties.is_id_start(ch));
+            assert_eq!(
+                properties.is_xid_continue(ch),
+                properties.is_id_continue(ch)
+            );
         }
-        index_start.push(new_chunk(start_bits));
-        index_continue.push(new_chunk(continue_bits));
     }
 
-    while let Some(0) = index_start.last() {
-        index_start.pop();
+    let id_extra = compress::compress(&[
+        &|ch| properties.is_id_start(ch) && !properties.is_xid_start(ch),
+        &|ch| properties.is_id_continue(ch) && !properties.is_xid_continue(ch),
+    ]);
+
+    // ID_Compat_Math_Continue contains all of ID_Compat_Math_Start, and the
+    // rest of it is just super- and subscripts in the BMP. Storing that rest
+    // separately keeps its trie short. Neither contains any ASCII.
+    for ch in '\0'..=char::MAX {
+        assert!(
+            !properties.is_id_compat_math_start(ch) || properties.is_id_compat_math_continue(ch)
+        );
+        if ch.is_ascii() {
+            assert!(!properties.is_id_compat_math_continue(ch));
+        }
     }
-    while let Some(0) = index_continue.last() {
-        index_continue.pop();
+
+    // Default_Ignorable_Code_Point covers all of plane 14, which the runtime
+    // matches by range rather than stretching the trie out that far.
+    let is_plane_14 = |ch| ('\u{E0000}'..='\u{E0FFF}').contains(&ch);
+    for ch in '\u{20000}'..=char::MAX {
+        assert_eq!(properties.is_default_ignorable(ch), is_plane_14(ch));
     }
 
-    // Compress the LEAF array by overlapping chunks at half-chunk boundaries.
-    //
-    // If chunk i's back half equals chunk j's front half, placing them
-    // adjacently saves 32 bytes. We find the maximum number of such overlaps by
-    // modeling this as a bipartite matching problem (left side = back halves,
-    // right side = front halves) and solving with Kuhn's algorithm.
+    let pattern = compress::compress(&[
+        &|ch| properties.is_pattern_syntax(ch),
+        &|ch| properties.is_pattern_white_space(ch),
+        &|ch| properties.is_id_compat_math_start(ch),
+        &|ch| properties.is_id_compat_math_continue(ch) && !properties.is_id_compat_math_start(ch),
+        &|ch| properties.is_default_ignorable(ch) && !is_plane_14(ch),
+    ]);
 
-    let num_chunks = dense.len();
+    // In the emoji profile, ASCII characters only begin keycap sequences, which
+    // the runtime matches without consulting the tables.
+    for ch in '\0'..='\x7F' {
+        let keycap_base = matches!(ch, '0'..='9' | '#' | '*');
+        assert_eq!(properties.is_emoji(ch), keycap_base);
+        assert!(!properties.is_extended_pictographic(ch));
+    }
 
-    let front_of: Vec<[u8; CHUNK / 2]> = dense
-        .iter()
-        .map(|c| c[..CHUNK / 2].try_into().unwrap())
-        .collect();
-    let back_of: Vec<[u8; CHUNK / 2]> = dense
-        .iter()
-        .map(|c| c[CHUNK / 2..].try_into().unwrap())
-        .collect();
+    // Emoji_Modifier is the 5 Fitzpatrick skin tone modifiers, which the
+    // runtime matches by range rather than by a mostly empty trie.
+    for ch in '\0'..=char::MAX {
+        let fitzpatrick = ('\u{1F3FB}'..='\u{1F3FF}').contains(&ch);
+        assert_eq!(properties.is_emoji_modifier(ch), fitzpatrick);
+    }
 
-    // Build index from front-half value to chunk indices for efficient lookup.
-    let mut chunks_by_front: Map<[u8; CHUNK / 2], Vec<usize>> = Map::new();
-    for (j, &front) in front_of.iter().enumerate() {
-        chunks_by_front
-            .entry(front)
-            .or_insert_with(Vec::new)
-            .push(j);
+    // Tag characters are Emoji_Component, but they would stretch the hashtag
+    // trie out to plane 14 for a single range. The runtime matches them by
+    // range instead.
+    let is_tag = |ch| ('\u{E0020}'..='\u{E007F}').contains(&ch);
+    for ch in '\u{E0000}'..='\u{E00FF}' {
+        assert_eq!(properties.is_emoji_component(ch), is_tag(ch));
     }
 
-    // adj_list[i] = chunks whose front half matches chunk i's back half,
-    // meaning they can follow chunk i with a 32-byte overlap. Exclude
-    // self-edges (the all-zeros and all-ones chunks have front == back).
-    let adj_list: Vec<Vec<usize>> = (0..num_chunks)
-        .map(|i| {
-            chunks_by_front
-                .get(&back_of[i])
-                .map_or_else(Vec::new, |js| {
-                    js.iter().copied().filter(|&j| j != i).collect()
-                })
-        })
-        .collect();
+    let emoji = compress::compress(&[
+        &|ch| properties.is_emoji(ch) || properties.is_extended_pictographic(ch),
+        &|ch| properties.is_hashtag_continue(ch) && !properties.is_xid_continue(ch) && !is_tag(ch),
+    ]);
 
-    // Maximum bipartite matching via Kuhn's algorithm (augmenting paths).
-    // prev_of[j] = Some(i) means chunk i is matched to precede chunk j.
-    let mut prev_of: Vec<Option<usize>> = vec![None; num_chunks];
-
-    // DFS for an augmenting path from `src`. If found, augments the matching
-    // in-place (rehoming existing matches to preserve validity) and returns
-    // true.
-    fn try_kuhn(
-        src: usize,
-        adj_list: &[Vec<usize>],
-        visited: &mut [bool],
-        prev_of: &mut [Option<usize>],
-    ) -> bool {
-        for &dst in &adj_list[src] {
-            if !visited[dst] {
-                visited[dst] = true;
-                // If dst is free, or its current match can be rehomed, claim dst.
-                if prev_of[dst].is_none_or(|prev| try_kuhn(prev, adj_list, visited, prev_of)) {
-                    prev_of[dst] = Some(src);
-                    return true;
-                }
+    // Character classes used by the UAX #31 conditions on ZWJ and ZWNJ, as bit
+    // flags. Most letters are XID_Start, so rather than General_Category=Letter
+    // one flag holds its difference from XID_Start, which is far more compact.
+    let join_context = compress::runs(|ch| {
+        let mut flags = 0;
+        let jt = properties.joining_type(ch);
+        let ccc = properties.canonical_combining_class(ch);
+        for (flag, value) in [
+            (write::LEFT_JOINING, matches!(jt, 'D' | 'L')),
+            (write::RIGHT_JOINING, matches!(jt, 'D' | 'R')),
+            (write::TRANSPARENT, jt == 'T'),
+            (
+                write::LETTER_XOR_XID_START,
+                properties.is_letter(ch) != properties.is_xid_start(ch),
+            ),
+            (write::NONSPACING_MARK, properties.is_nonspacing_mark(ch)),
+            (write::COMBINING, ccc != 0),
+            (write::VIRAMA, ccc == 9),
+        ] {
+            if value {
+                flags |= flag;
             }
         }
-        false
-    }
+        flags
+    });
+
+    // General_Category, reduced to the groups that programming languages other
+    // than those following UAX #31 define identifiers in terms of. Where the
+    // group follows from XID_Start and XID_Continue the value is 0, which
+    // leaves only a few hundred runs: letters that are XID_Start, marks that
+    // are XID_Continue but not XID_Start, and anything else that is neither.
+    let category = compress::runs(|ch| {
+        let xid_start = properties.is_xid_start(ch);
+        let xid_continue = properties.is_xid_continue(ch);
+        let category = if properties.is_letter(ch) {
+            write::CATEGORY_LETTER
+        } else if properties.is_nonspacing_mark(ch) || properties.is_spacing_mark(ch) {
+            write::CATEGORY_MARK
+        } else if properties.is_decimal_number(ch) {
+            write::CATEGORY_DECIMAL_NUMBER
+        } else if properties.is_letter_number(ch) {
+            write::CATEGORY_LETTER_NUMBER
+        } else if properties.is_connector_punctuation(ch) {
+            write::CATEGORY_CONNECTOR_PUNCTUATION
+        } else if properties.is_currency_symbol(ch) {
+            write::CATEGORY_CURRENCY_SYMBOL
+        } else if properties.is_format(ch) {
+            write::CATEGORY_FORMAT
+        } else {
+            write::CATEGORY_OTHER
+        };
+        let implied = match category {
+            write::CATEGORY_LETTER => xid_start,
+            write::CATEGORY_MARK => xid_continue && !xid_start,
+            write::CATEGORY_OTHER => !xid_continue,
+            _ => false,
+        };
+        if implied {
+            0
+        } else {
+            category
+        }
+    });
 
-    // Try every left vertex. A failed attempt stays failed because later rounds
-    // only shrink the set of free right vertices (Berge's theorem).
-    for i in 0..num_chunks {
-        let mut visited = vec![false; num_chunks];
-        try_kuhn(i, &adj_list, &mut visited, &mut prev_of);
+    // The runtime answers Script and Script_Extensions for ASCII without
+    // consulting the table.
+    let latin = properties
+        .scripts()
+        .iter()
+        .position(|(short, _)| short == "Latn");
+    let common = properties
+        .scripts()
+        .iter()
+        .position(|(short, _)| short == "Zyyy");
+    for ch in '\0'..='\x7F' {
+        let expected = if ch.is_ascii_alphabetic() {
+            latin
+        } else {
+            common
+        };
+        assert_eq!(Some(properties.script(ch) as usize), expected);
+        assert_eq!(properties.script_extensions(ch).len(), 1);
     }
 
-    // Invert the matching into a forward map for chain traversal.
-    let mut next_of: Vec<Option<usize>> = vec![None; num_chunks];
-    for (j, &prev) in prev_of.iter().enumerate() {
-        if let Some(prev) = prev {
-            next_of[prev] = Some(j);
+    let script = compress::runs(|ch| properties.script(ch));
+
+    // Script_Extensions is almost everywhere equal to the singleton set of the
+    // character's Script. Those characters get value 0, and the rest an index
+    // (plus 1) into a list of distinct sets.
+    let mut script_extensions = Vec::new();
+    let script_extensions_runs = compress::runs(|ch| {
+        let set = properties.script_extensions(ch);
+        if set.len() == 1 && set.contains(&properties.script(ch)) {
+            return 0;
         }
-    }
+        let index = script_extensions
+            .iter()
+            .position(|prev| *prev == set)
+            .unwrap_or_else(|| {
+                script_extensions.push(set);
+                script_extensions.len() - 1
+            });
+        u8::try_from(index + 1).expect("exceeded 255 distinct Script_Extensions")
+    });
 
-    // Chunk 0 (all zeros) is special and must be laid out first at halfdense
-    // position 0, because the runtime defaults to index 0 for codepoints beyond
-    // the trie. Remove any incoming edge so chunk 0 becomes a chain start.
-    if let Some(prev) = prev_of[0] {
-        next_of[prev] = None;
-        prev_of[0] = None;
-    }
+    // Normalization. Canonical_Combining_Class has few distinct values, and
+    // decompositions are stored fully decomposed so that the runtime never
+    // needs to apply them recursively.
+    let combining_class = compress::runs(|ch| properties.canonical_combining_class(ch));
+    let canonical_decomposition: Vec<(char, Vec<char>)> = ('\0'..=char::MAX)
+        .filter_map(|ch| Some((ch, properties.canonical_decomposition(ch)?)))
+        .collect();
 
-    // Lay out chains into halfdense, starting with chunk 0's chain.
-    let mut halfdense = Vec::<u8>::new();
-    let mut dense_to_halfdense = Map::<u8, u8>::new();
+    // Compatibility decompositions, for only those characters where they are
+    // not the same as the canonical decomposition.
+    let compatibility_decomposition: Vec<(char, Vec<char>)> = ('\0'..=char::MAX)
+        .filter_map(|ch| {
+            let decomposition = properties.compatibility_decomposition(ch)?;
+            let canonical = properties.canonical_decomposition(ch);
+            (canonical.as_ref() != Some(&decomposition)).then_some((ch, decomposition))
+        })
+        .collect();
 
-    for start in (0..num_chunks).filter(|&i| prev_of[i].is_none()) {
-        dense_to_halfdense.insert(
-            start as u8,
-            u8::try_from(halfdense.len() / (CHUNK / 2)).expect("exceeded 256 half-chunks"),
-        );
-        halfdense.extend_from_slice(&front_of[start]);
-        halfdense.extend_from_slice(&back_of[start]);
-
-        // Write the rest of the chain: each chunk's front half overlaps the
-        // previous chunk's back half, so only append the back half.
-        let mut curr = start;
-        while let Some(next) = next_of[curr] {
-            dense_to_halfdense.insert(
-                next as u8,
-                u8::try_from(halfdense.len() / (CHUNK / 2) - 1).expect("exceeded 256 half-chunks"),
-            );
-            halfdense.extend_from_slice(&back_of[next]);
-            curr = next;
+    // Primary composites, which NFC composes from the pair they canonically
+    // decompose into, sorted by that pair.
+    let mut compositions = Vec::new();
+    for ch in '\0'..=char::MAX {
+        if let Some(mapping) = properties.canonical_decomposition_mapping(ch) {
+            if !properties.is_full_composition_exclusion(ch) {
+                let [first, second] = mapping[..] else {
+                    panic!("primary composite U+{:04X} is not a pair", ch as u32);
+                };
+                compositions.push((first, second, ch));
+            }
         }
     }
+    compositions.sort_unstable();
 
-    // Each chunk can be both a predecessor (back half) and a successor (front
-    // half), so next_of can form cycles with no chain start. We broke chunk 0's
-    // cycle above; verify no others exist.
-    assert_eq!(
-        dense_to_halfdense.len(),
-        num_chunks,
-        "not all chunks were laid out",
-    );
+    let quick_check = compress::runs(|ch| {
+        let mut flags = 0;
+        for (flag, value) in [
+            (write::NFC_NO, properties.nfc_quick_check(ch) == 'N'),
+            (write::NFC_MAYBE, properties.nfc_quick_check(ch) == 'M'),
+            (write::NFKC_NO, properties.nfkc_quick_check(ch) == 'N'),
+            (write::NFKC_MAYBE, properties.nfkc_quick_check(ch) == 'M'),
+        ] {
+            if value {
+                flags |= flag;
+            }
+        }
+        flags
+    });
 
-    for index in &mut index_start {
-        *index = dense_to_halfdense[index];
-    }
-    for index in &mut index_continue {
-        *index = dense_to_halfdense[index];
+    // NFKC_Casefold maps exactly the Default_Ignorable_Code_Points to nothing,
+    // which the runtime identifies without this table, so only nonempty
+    // mappings are stored.
+    let mut nfkc_casefold = Vec::new();
+    for ch in '\0'..=char::MAX {
+        let mapping = properties.nfkc_casefold(ch);
+        let removed = mapping.as_ref().is_some_and(Vec::is_empty);
+        assert_eq!(removed, properties.is_default_ignorable(ch));
+        if let Some(mapping) = mapping.filter(|mapping| !mapping.is_empty()) {
+            nfkc_casefold.push((ch, mapping));
+        }
     }
 
-    let out = write::output(&properties, &index_start, &index_continue, &halfdense);
+    let out = write::output(
+        &properties,
+        &xid,
+        &id_extra,
+        &pattern,
+        &emoji,
+        &join_context,
+        &category,
+        &script,
+        &script_extensions_runs,
+        &script_extensions,
+        &combining_class,
+        &canonical_decomposition,
+        &compatibility_decomposition,
+        &compositions,
+        &quick_check,
+        &nfkc_casefold,
+    );
     let path = unicode_ident_dir.join(TABLES);
     if let Err(err) = fs::write(&path, out) {
         let _ = writeln!(io::stderr(), "{}: {err}", path.display());
@@ -1,5 +1,5 @@
 use regex::Regex;
-use std::collections::BTreeSet as Set;
+use std::collections::{BTreeMap as Map, BTreeSet as Set};
 use std::fs;
 use std::io::{self, Write};
 use std::path::Path;
@@ -9,6 +9,36 @@ pub struct Properties {
     unicode_version: (u8, u8, u8),
     xid_start: Set<u32>,
     xid_continue: Set<u32>,
+    id_start: Set<u32>,
+    id_continue: Set<u32>,
+    default_ignorable: Set<u32>,
+    pattern_syntax: Set<u32>,
+    pattern_white_space: Set<u32>,
+    id_compat_math_start: Set<u32>,
+    id_compat_math_continue: Set<u32>,
+    emoji: Set<u32>,
+    emoji_modifier: Set<u32>,
+    emoji_component: Set<u32>,
+    extended_pictographic: Set<u32>,
+    letter: Set<u32>,
+    nonspacing_mark: Set<u32>,
+    spacing_mark: Set<u32>,
+    decimal_number: Set<u32>,
+    letter_number: Set<u32>,
+    connector_punctuation: Set<u32>,
+    currency_symbol: Set<u32>,
+    format: Set<u32>,
+    canonical_combining_class: Map<u32, u8>,
+    canonical_decomposition: Map<u32, Vec<u32>>,
+    compatibility_decomposition: Map<u32, Vec<u32>>,
+    full_composition_exclusion: Set<u32>,
+    nfc_quick_check: Map<u32, char>,
+    nfkc_quick_check: Map<u32, char>,
+    nfkc_casefold: Map<u32, Vec<u32>>,
+    joining_type: Map<u32, char>,
+    scripts: Vec<(String, String)>,
+    script: Map<u32, u8>,
+    script_extensions: Map<u32, Set<u8>>,
 }
 
 impl Properties {
@@ -23,43 +53,471 @@ impl Properties {
     pub fn is_xid_continue(&self, ch: char) -> bool {
         self.xid_continue.contains(&(ch as u32))
     }
-}
 
-pub fn parse_xid_properties(ucd_dir: &Path) -> Properties {
-    let filename = "DerivedCoreProperties.txt";
-    let path = ucd_dir.join(filename);
-    let contents = fs::read_to_string(path).unwrap_or_else(|err| {
-        let suggestion =
-            "Download from https://www.unicode.org/Public/latest/ucd/UCD.zip and unzip.";
-        let _ = writeln!(io::stderr(), "{}: {err}\n{suggestion}", ucd_dir.display());
-        process::exit(1);
-    });
+    pub fn is_id_start(&self, ch: char) -> bool {
+        self.id_start.contains(&(ch as u32))
+    }
 
+    pub fn is_id_continue(&self, ch: char) -> bool {
+        self.id_continue.contains(&(ch as u32))
+    }
+
+    pub fn is_default_ignorable(&self, ch: char) -> bool {
+        self.default_ignorable.contains(&(ch as u32))
+    }
+
+    pub fn is_pattern_syntax(&self, ch: char) -> bool {
+        self.pattern_syntax.contains(&(ch as u32))
+    }
+
+    pub fn is_pattern_white_space(&self, ch: char) -> bool {
+        self.pattern_white_space.contains(&(ch as u32))
+    }
+
+    pub fn is_id_compat_math_start(&self, ch: char) -> bool {
+        self.id_compat_math_start.contains(&(ch as u32))
+    }
+
+    pub fn is_id_compat_math_continue(&self, ch: char) -> bool {
+        self.id_compat_math_continue.contains(&(ch as u32))
+    }
+
+    pub fn is_emoji(&self, ch: char) -> bool {
+        self.emoji.contains(&(ch as u32))
+    }
+
+    pub fn is_emoji_modifier(&self, ch: char) -> bool {
+        self.emoji_modifier.contains(&(ch as u32))
+    }
+
+    pub fn is_emoji_component(&self, ch: char) -> bool {
+        self.emoji_component.contains(&(ch as u32))
+    }
+
+    pub fn is_extended_pictographic(&self, ch: char) -> bool {
+        self.extended_pictographic.contains(&(ch as u32))
+    }
+
+    // Continue set of the UAX #31 Default Hashtag Identifier Syntax.
+    pub fn is_hashtag_continue(&self, ch: char) -> bool {
+        (self.is_xid_continue(ch)
+            || self.is_extended_pictographic(ch)
+            || self.is_emoji_component(ch)
+            || matches!(ch, '-' | '+' | '_'))
+            && !matches!(ch, '#' | '\u{FE5F}' | '\u{FF03}')
+    }
+
+    // General_Category=Letter, which is Lu, Ll, Lt, Lm or Lo.
+    pub fn is_letter(&self, ch: char) -> bool {
+        self.letter.contains(&(ch as u32))
+    }
+
+    // General_Category=Nonspacing_Mark (Mn).
+    pub fn is_nonspacing_mark(&self, ch: char) -> bool {
+        self.nonspacing_mark.contains(&(ch as u32))
+    }
+
+    // General_Category=Spacing_Mark (Mc).
+    pub fn is_spacing_mark(&self, ch: char) -> bool {
+        self.spacing_mark.contains(&(ch as u32))
+    }
+
+    // General_Category=Decimal_Number (Nd).
+    pub fn is_decimal_number(&self, ch: char) -> bool {
+        self.decimal_number.contains(&(ch as u32))
+    }
+
+    // General_Category=Letter_Number (Nl).
+    pub fn is_letter_number(&self, ch: char) -> bool {
+        self.letter_number.contains(&(ch as u32))
+    }
+
+    // General_Category=Connector_Punctuation (Pc).
+    pub fn is_connector_punctuation(&self, ch: char) -> bool {
+        self.connector_punctuation.contains(&(ch as u32))
+    }
+
+    // General_Category=Currency_Symbol (Sc).
+    pub fn is_currency_symbol(&self, ch: char) -> bool {
+        self.currency_symbol.contains(&(ch as u32))
+    }
+
+    // General_Category=Format (Cf).
+    pub fn is_format(&self, ch: char) -> bool {
+        self.format.contains(&(ch as u32))
+    }
+
+    pub fn canonical_combining_class(&self, ch: char) -> u8 {
+        let ccc = self.canonical_combining_class.get(&(ch as u32));
+        ccc.copied().unwrap_or(0)
+    }
+
+    // Decomposition_Mapping, if it is canonical. Hangul syllables are
+    // decomposed algorithmically and are not included.
+    pub fn canonical_decomposition_mapping(&self, ch: char) -> Option<Vec<char>> {
+        let mapping = self.canonical_decomposition.get(&(ch as u32))?;
+        Some(
+            mapping
+                .iter()
+                .map(|&code| char::from_u32(code).unwrap())
+                .collect(),
+        )
+    }
+
+    // Canonical Decomposition_Mapping, applied recursively.
+    pub fn canonical_decomposition(&self, ch: char) -> Option<Vec<char>> {
+        let mut decomposition = Vec::new();
+        for ch in self.canonical_decomposition_mapping(ch)? {
+            match self.canonical_decomposition(ch) {
+                Some(recursive) => decomposition.extend(recursive),
+                None => decomposition.push(ch),
+            }
+        }
+        Some(decomposition)
+    }
+
+    // Decomposition_Mapping of either kind, applied recursively. This is the
+    // same as the canonical decomposition for characters that have no
+    // compatibility mapping anywhere in the recursion.
+    pub fn compatibility_decomposition(&self, ch: char) -> Option<Vec<char>> {
+        let code = ch as u32;
+        let mapping = match self.compatibility_decomposition.get(&code) {
+            Some(mapping) => mapping,
+            None => self.canonical_decomposition.get(&code)?,
+        };
+        let mut decomposition = Vec::new();
+        for &code in mapping {
+            let ch = char::from_u32(code).unwrap();
+            match self.compatibility_decomposition(ch) {
+                Some(recursive) => decomposition.extend(recursive),
+                None => decomposition.push(ch),
+            }
+        }
+        Some(decomposition)
+    }
+
+    // Canonical decompositions that are not primary composites, so NFC never
+    // recomposes them.
+    pub fn is_full_composition_exclusion(&self, ch: char) -> bool {
+        self.full_composition_exclusion.contains(&(ch as u32))
+    }
+
+    // One of 'Y', 'N' or 'M' (Maybe).
+    pub fn nfc_quick_check(&self, ch: char) -> char {
+        let qc = self.nfc_quick_check.get(&(ch as u32));
+        qc.copied().unwrap_or('Y')
+    }
+
+    // One of 'Y', 'N' or 'M' (Maybe).
+    pub fn nfkc_quick_check(&self, ch: char) -> char {
+        let qc = self.nfkc_quick_check.get(&(ch as u32));
+        qc.copied().unwrap_or('Y')
+    }
+
+    // NFKC_Casefold, for those characters it does not map to themselves.
+    pub fn nfkc_casefold(&self, ch: char) -> Option<Vec<char>> {
+        let mapping = self.nfkc_casefold.get(&(ch as u32))?;
+        Some(
+            mapping
+                .iter()
+                .map(|&code| char::from_u32(code).unwrap())
+                .collect(),
+        )
+    }
+
+    // One of 'C', 'D', 'L', 'R', 'T' or 'U' (Non_Joining).
+    pub fn joining_type(&self, ch: char) -> char {
+        let jt = self.joining_type.get(&(ch as u32));
+        jt.copied().unwrap_or('U')
+    }
+
+    // (short name, long name) of every Script value, in order of short name.
+    pub fn scripts(&self) -> &[(String, String)] {
+        &self.scripts
+    }
+
+    pub fn script(&self, ch: char) -> u8 {
+        match self.script.get(&(ch as u32)) {
+            Some(script) => *script,
+            None => self.unknown_script(),
+        }
+    }
+
+    pub fn script_extensions(&self, ch: char) -> Set<u8> {
+        match self.script_extensions.get(&(ch as u32)) {
+            Some(scripts) => scripts.clone(),
+            None => Set::from([self.script(ch)]),
+        }
+    }
+
+    fn unknown_script(&self) -> u8 {
+        let index = self.scripts.iter().position(|(short, _)| short == "Zzzz");
+        index.unwrap() as u8
+    }
+}
+
+pub fn parse_properties(ucd_dir: &Path) -> Properties {
     let mut properties = Properties {
-        unicode_version: parse_unicode_version(filename, &contents),
+        unicode_version: (0, 0, 0),
         xid_start: Set::new(),
         xid_continue: Set::new(),
+        id_start: Set::new(),
+        id_continue: Set::new(),
+        default_ignorable: Set::new(),
+        pattern_syntax: Set::new(),
+        pattern_white_space: Set::new(),
+        id_compat_math_start: Set::new(),
+        id_compat_math_continue: Set::new(),
+        emoji: Set::new(),
+        emoji_modifier: Set::new(),
+        emoji_component: Set::new(),
+        extended_pictographic: Set::new(),
+        letter: Set::new(),
+        nonspacing_mark: Set::new(),
+        spacing_mark: Set::new(),
+        decimal_number: Set::new(),
+        letter_number: Set::new(),
+        connector_punctuation: Set::new(),
+        currency_symbol: Set::new(),
+        format: Set::new(),
+        canonical_combining_class: Map::new(),
+        canonical_decomposition: Map::new(),
+        compatibility_decomposition: Map::new(),
+        full_composition_exclusion: Set::new(),
+        nfc_quick_check: Map::new(),
+        nfkc_quick_check: Map::new(),
+        nfkc_casefold: Map::new(),
+        joining_type: Map::new(),
+        scripts: Vec::new(),
+        script: Map::new(),
+        script_extensions: Map::new(),
     };
 
-    for (i, line) in contents.lines().enumerate() {
-        if line.starts_with('#') || line.trim().is_empty() {
-            continue;
-        }
-        let (lo, hi, name) = parse_line(line).unwrap_or_else(|| {
-            let _ = writeln!(io::stderr(), "{filename} line {i} is unexpected:\n{line}");
-            process::exit(1);
-        });
+    let filename = "DerivedCoreProperties.txt";
+    let contents = read(ucd_dir, filename);
+    properties.unicode_version = parse_unicode_version(filename, &contents);
+    for (lo, hi, name) in parse_lines(filename, &contents) {
         let set = match name {
             "XID_Start" => &mut properties.xid_start,
             "XID_Continue" => &mut properties.xid_continue,
+            "ID_Start" => &mut properties.id_start,
+            "ID_Continue" => &mut properties.id_continue,
+            "Default_Ignorable_Code_Point" => &mut properties.default_ignorable,
+            _ => continue,
+        };
+        set.extend(lo..=hi);
+    }
+
+    let filename = "PropList.txt";
+    let contents = read(ucd_dir, filename);
+    check_unicode_version(filename, &contents, properties.unicode_version);
+    for (lo, hi, name) in parse_lines(filename, &contents) {
+        let set = match name {
+            "Pattern_Syntax" => &mut properties.pattern_syntax,
+            "Pattern_White_Space" => &mut properties.pattern_white_space,
+            "ID_Compat_Math_Start" => &mut properties.id_compat_math_start,
+            "ID_Compat_Math_Continue" => &mut properties.id_compat_math_continue,
+            _ => continue,
+        };
+        set.extend(lo..=hi);
+    }
+
+    let filename = "emoji/emoji-data.txt";
+    let contents = read(ucd_dir, filename);
+    check_emoji_version(filename, &contents, properties.unicode_version);
+    for (lo, hi, name) in parse_lines(filename, &contents) {
+        let set = match name {
+            "Emoji" => &mut properties.emoji,
+            "Emoji_Modifier" => &mut properties.emoji_modifier,
+            "Emoji_Component" => &mut properties.emoji_component,
+            "Extended_Pictographic" => &mut properties.extended_pictographic,
+            _ => continue,
+        };
+        set.extend(lo..=hi);
+    }
+
+    let filename = "extracted/DerivedGeneralCategory.txt";
+    let contents = read(ucd_dir, filename);
+    check_unicode_version(filename, &contents, properties.unicode_version);
+    for (lo, hi, name) in parse_lines(filename, &contents) {
+        let set = match name {
+            "Lu" | "Ll" | "Lt" | "Lm" | "Lo" => &mut properties.letter,
+            "Mn" => &mut properties.nonspacing_mark,
+            "Mc" => &mut properties.spacing_mark,
+            "Nd" => &mut properties.decimal_number,
+            "Nl" => &mut properties.letter_number,
+            "Pc" => &mut properties.connector_punctuation,
+            "Sc" => &mut properties.currency_symbol,
+            "Cf" => &mut properties.format,
             _ => continue,
         };
         set.extend(lo..=hi);
     }
 
+    let filename = "extracted/DerivedCombiningClass.txt";
+    let contents = read(ucd_dir, filename);
+    check_unicode_version(filename, &contents, properties.unicode_version);
+    for (lo, hi, value) in parse_lines(filename, &contents) {
+        let Ok(ccc) = value.parse::<u8>() else {
+            let _ = writeln!(io::stderr(), "{filename}: unexpected value {value}");
+            process::exit(1);
+        };
+        let map = &mut properties.canonical_combining_class;
+        map.extend((lo..=hi).map(|code| (code, ccc)));
+    }
+
+    // UnicodeData.txt has no version header of its own.
+    let filename = "UnicodeData.txt";
+    let contents = read(ucd_dir, filename);
+    for (i, line) in contents.lines().enumerate() {
+        let fields: Vec<&str> = line.split(';').collect();
+        let (Some(code), Some(decomposition)) = (fields.first(), fields.get(5)) else {
+            let _ = writeln!(io::stderr(), "{filename} line {i} is unexpected:\n{line}");
+            process::exit(1);
+        };
+        if decomposition.is_empty() {
+            continue;
+        }
+        // Compatibility mappings begin with a tag such as <font>.
+        let (map, decomposition) = match decomposition.split_once('>') {
+            Some((_tag, mapping)) => (&mut properties.compatibility_decomposition, mapping),
+            None => (&mut properties.canonical_decomposition, *decomposition),
+        };
+        let (Some(code), Some(mapping)) = (parse_codepoint(code), parse_codepoints(decomposition))
+        else {
+            let _ = writeln!(io::stderr(), "{filename} line {i} is unexpected:\n{line}");
+            process::exit(1);
+        };
+        map.insert(code, mapping);
+    }
+
+    let filename = "DerivedNormalizationProps.txt";
+    let contents = read(ucd_dir, filename);
+    check_unicode_version(filename, &contents, properties.unicode_version);
+    for (lo, hi, value) in parse_lines(filename, &contents) {
+        let (name, mapping) = value.split_once(';').unwrap_or((value, ""));
+        match name {
+            "Full_Composition_Exclusion" => {
+                properties.full_composition_exclusion.extend(lo..=hi);
+            }
+            "NFC_QC" | "NFKC_QC" => {
+                let qc = match mapping.trim() {
+                    "N" => 'N',
+                    "M" => 'M',
+                    _ => {
+                        let _ = writeln!(io::stderr(), "{filename}: unexpected value {value}");
+                        process::exit(1);
+                    }
+                };
+                let map = if name == "NFC_QC" {
+                    &mut properties.nfc_quick_check
+                } else {
+                    &mut properties.nfkc_quick_check
+                };
+                map.extend((lo..=hi).map(|code| (code, qc)));
+            }
+            "NFKC_CF" => {
+                let Some(mapping) = parse_codepoints(mapping) else {
+                    let _ = writeln!(io::stderr(), "{filename}: unexpected value {value}");
+                    process::exit(1);
+                };
+                let map = &mut properties.nfkc_casefold;
+                map.extend((lo..=hi).map(|code| (code, mapping.clone())));
+            }
+            _ => {}
+        }
+    }
+
+    let filename = "extracted/DerivedJoiningType.txt";
+    let contents = read(ucd_dir, filename);
+    check_unicode_version(filename, &contents, properties.unicode_version);
+    for (lo, hi, value) in parse_lines(filename, &contents) {
+        let jt = match value {
+            "C" | "D" | "L" | "R" | "T" | "U" => value.chars().next().unwrap(),
+            _ => {
+                let _ = writeln!(io::stderr(), "{filename}: unexpected value {value}");
+                process::exit(1);
+            }
+        };
+        properties
+            .joining_type
+            .extend((lo..=hi).map(|code| (code, jt)));
+    }
+
+    let filename = "PropertyValueAliases.txt";
+    let contents = read(ucd_dir, filename);
+    check_unicode_version(filename, &contents, properties.unicode_version);
+    for line in contents.lines() {
+        let mut fields = line.split('#').next().unwrap().split(';').map(str::trim);
+        if let (Some("sc"), Some(short), Some(long)) = (fields.next(), fields.next(), fields.next())
+        {
+            properties.scripts.push((short.to_owned(), long.to_owned()));
+        }
+    }
+    properties.scripts.sort();
+    if properties.scripts.len() > 256 {
+        let _ = writeln!(io::stderr(), "{filename}: too many scripts for u8");
+        process::exit(1);
+    }
+    let script_by_name = |name: &str| -> u8 {
+        let index = properties
+            .scripts
+            .iter()
+            .position(|(short, long)| short == name || long == name);
+        index.unwrap_or_else(|| {
+            let _ = writeln!(io::stderr(), "unrecognized script: {name}");
+            process::exit(1);
+        }) as u8
+    };
+
+    let filename = "Scripts.txt";
+    let contents = read(ucd_dir, filename);
+    check_unicode_version(filename, &contents, properties.unicode_version);
+    let mut script = Map::new();
+    for (lo, hi, name) in parse_lines(filename, &contents) {
+        let value = script_by_name(name);
+        script.extend((lo..=hi).map(|code| (code, value)));
+    }
+
+    let filename = "ScriptExtensions.txt";
+    let contents = read(ucd_dir, filename);
+    check_unicode_version(filename, &contents, properties.unicode_version);
+    let mut script_extensions = Map::new();
+    for (lo, hi, names) in parse_lines(filename, &contents) {
+        let set: Set<u8> = names.split_whitespace().map(script_by_name).collect();
+        script_extensions.extend((lo..=hi).map(|code| (code, set.clone())));
+    }
+
+    properties.script = script;
+    properties.script_extensions = script_extensions;
     properties
 }
 
+fn read(ucd_dir: &Path, filename: &str) -> String {
+    let path = ucd_dir.join(filename);
+    fs::read_to_string(path).unwrap_or_else(|err| {
+        let suggestion =
+            "Download from https://www.unicode.org/Public/latest/ucd/UCD.zip and unzip.";
+        let _ = writeln!(io::stderr(), "{}: {err}\n{suggestion}", ucd_dir.display());
+        process::exit(1);
+    })
+}
+
+fn parse_lines<'a>(
+    filename: &'a str,
+    contents: &'a str,
+) -> impl Iterator<Item = (u32, u32, &'a str)> + 'a {
+    contents.lines().enumerate().filter_map(move |(i, line)| {
+        if line.starts_with('#') || line.trim().is_empty() {
+            return None;
+        }
+        Some(parse_line(line).unwrap_or_else(|| {
+            let _ = writeln!(io::stderr(), "{filename} line {i} is unexpected:\n{line}");
+            process::exit(1);
+        }))
+    })
+}
+
 fn parse_line(line: &str) -> Option<(u32, u32, &str)> {
     let (mut codepoint, rest) = line.split_once(';')?;
 
@@ -81,8 +539,50 @@ fn parse_codepoint(s: &str) -> Option<u32> {
     u32::from_str_radix(s, 16).ok()
 }
 
+// Space-separated codepoints, possibly none.
+fn parse_codepoints(s: &str) -> Option<Vec<u32>> {
+    s.split_whitespace().map(parse_codepoint).collect()
+}
+
+fn check_unicode_version(filename: &str, contents: &str, expected: (u8, u8, u8)) {
+    let version = parse_unicode_version(filename, contents);
+    if version != expected {
+        let _ = writeln!(
+            io::stderr(),
+            "{filename} is Unicode version {:?}, but expected {:?}",
+            version,
+            expected,
+        );
+        process::exit(1);
+    }
+}
+
+// Emoji versions have tracked the Unicode version since Unicode 11, but the
+// emoji data files state only the major and minor version.
+fn check_emoji_version(filename: &str, contents: &str, expected: (u8, u8, u8)) {
+    let re = Regex::new(r"(?m)^# Used with Emoji Version (\d+)\.(\d+)\b").unwrap();
+    let caps = re
+        .captures(contents)
+        .expect("Failed to find emoji version in emoji data");
+    let (_, [major, minor]) = caps.extract();
+    let version: (u8, u8) = (
+        major.parse().expect("Failed to parse emoji version"),
+        minor.parse().expect("Failed to parse emoji version"),
+    );
+    if version != (expected.0, expected.1) {
+        let _ = writeln!(
+            io::stderr(),
+            "{filename} is Emoji version {:?}, but expected {:?}",
+            version,
+            (expected.0, expected.1),
+        );
+        process::exit(1);
+    }
+}
+
 fn parse_unicode_version(filename: &str, contents: &str) -> (u8, u8, u8) {
-    let (name, extension) = filename
+    let basename = filename.rsplit('/').next().unwrap();
+    let (name, extension) = basename
         .rsplit_once('.')
         .expect("Failed to split file name into name and extension");
     let re = Regex::new(&format!(r"^# {name}-(\d+)\.(\d+)\.(\d+)\.{extension}\n")).unwrap();
@@ -1,6 +1,8 @@
+use crate::compress::Tries;
 use crate::output::Output;
 use crate::parse::Properties;
 use crate::CHUNK;
+use std::collections::BTreeSet as Set;
 
 const HEAD: &str = "\
 // \x40generated by ../generate. To regenerate, run the following in the repo root:
@@ -15,11 +17,48 @@ pub(crate) struct Align8<T>(pub(crate) T);
 pub(crate) struct Align64<T>(pub(crate) T);
 ";
 
+// Flags of the JOIN_CONTEXT_RUNS table.
+pub const LEFT_JOINING: u8 = 1 << 0;
+pub const RIGHT_JOINING: u8 = 1 << 1;
+pub const TRANSPARENT: u8 = 1 << 2;
+pub const LETTER_XOR_XID_START: u8 = 1 << 3;
+pub const NONSPACING_MARK: u8 = 1 << 4;
+pub const COMBINING: u8 = 1 << 5;
+pub const VIRAMA: u8 = 1 << 6;
+
+// Flags of the QUICK_CHECK_RUNS table. Neither flag means Yes.
+pub const NFC_NO: u8 = 1 << 0;
+pub const NFC_MAYBE: u8 = 1 << 1;
+pub const NFKC_NO: u8 = 1 << 2;
+pub const NFKC_MAYBE: u8 = 1 << 3;
+
+// Values of the CATEGORY_RUNS table, other than 0.
+pub const CATEGORY_LETTER: u8 = 1;
+pub const CATEGORY_MARK: u8 = 2;
+pub const CATEGORY_DECIMAL_NUMBER: u8 = 3;
+pub const CATEGORY_LETTER_NUMBER: u8 = 4;
+pub const CATEGORY_CONNECTOR_PUNCTUATION: u8 = 5;
+pub const CATEGORY_CURRENCY_SYMBOL: u8 = 6;
+pub const CATEGORY_FORMAT: u8 = 7;
+pub const CATEGORY_OTHER: u8 = 8;
+
 pub fn output(
     properties: &Properties,
-    index_start: &[u8],
-    index_continue: &[u8],
-    halfdense: &[u8],
+    xid: &Tries,
+    id_extra: &Tries,
+    pattern: &Tries,
+    emoji: &Tries,
+    join_context: &[u32],
+    category: &[u32],
+    script: &[u32],
+    script_extensions_runs: &[u32],
+    script_extensions: &[Set<u8>],
+    combining_class: &[u32],
+    canonical_decomposition: &[(char, Vec<char>)],
+    compatibility_decomposition: &[(char, Vec<char>)],
+    compositions: &[(char, char, char)],
+    quick_check: &[u32],
+    nfkc_casefold: &[(char, Vec<char>)],
 ) -> Output {
     let mut out = Output::new();
     writeln!(out, "{}", HEAD);
@@ -31,48 +70,309 @@ pub fn output(
     );
     writeln!(out);
 
-    let ascii_start = (0u8..128)
-        .map(|c| (properties.is_xid_start(c as char) as u128) << c)
-        .sum::<u128>();
+    write_ascii(&mut out, "ASCII_START", |ch| properties.is_xid_start(ch));
+    write_ascii(&mut out, "ASCII_CONTINUE", |ch| {
+        properties.is_xid_continue(ch)
+    });
+    write_ascii(&mut out, "ASCII_PATTERN_SYNTAX", |ch| {
+        properties.is_pattern_syntax(ch)
+    });
+    write_ascii(&mut out, "ASCII_PATTERN_WHITE_SPACE", |ch| {
+        properties.is_pattern_white_space(ch)
+    });
+    write_ascii(&mut out, "ASCII_HASHTAG_CONTINUE", |ch| {
+        properties.is_hashtag_continue(ch)
+    });
+    writeln!(out);
+
+    writeln!(out, "pub(crate) const CHUNK: usize = {};", CHUNK);
+    writeln!(out);
+
+    let [index_start, index_continue] = &xid.index[..] else {
+        unreachable!()
+    };
+
+    writeln!(
+        out,
+        "pub(crate) static TRIE_START: Align8<[u8; {}]> = CONST_TRIE_START;",
+        index_start.len(),
+    );
+    writeln!(
+        out,
+        "pub(crate) static TRIE_CONTINUE: Align8<[u8; {}]> = CONST_TRIE_CONTINUE;",
+        index_continue.len(),
+    );
     writeln!(
         out,
-        "pub(crate) const ASCII_START: u128 = 0x{ascii_start:x};",
+        "pub(crate) static LEAF: Align64<[u8; {}]> = CONST_LEAF;",
+        xid.leaf.len(),
     );
+    writeln!(out);
 
-    let ascii_continue = (0u8..128)
-        .map(|c| (properties.is_xid_continue(c as char) as u128) << c)
-        .sum::<u128>();
+    write_array(&mut out, "const CONST_TRIE_START", "Align8", index_start);
+    writeln!(out);
+    write_array(
+        &mut out,
+        "const CONST_TRIE_CONTINUE",
+        "Align8",
+        index_continue,
+    );
+    writeln!(out);
+    write_array(&mut out, "const CONST_LEAF", "Align64", &xid.leaf);
+    writeln!(out);
+
+    let [index_id_start_extra, index_id_continue_extra] = &id_extra.index[..] else {
+        unreachable!()
+    };
+
+    write_array(
+        &mut out,
+        "static TRIE_ID_START_EXTRA",
+        "Align8",
+        index_id_start_extra,
+    );
+    writeln!(out);
+    write_array(
+        &mut out,
+        "static TRIE_ID_CONTINUE_EXTRA",
+        "Align8",
+        index_id_continue_extra,
+    );
+    writeln!(out);
+    write_array(&mut out, "static LEAF_ID", "Align64", &id_extra.leaf);
+    writeln!(out);
+
+    let [index_pattern_syntax, index_pattern_white_space, index_math_start, index_math_continue_extra, index_default_ignorable] =
+        &pattern.index[..]
+    else {
+        unreachable!()
+    };
+
+    write_array(
+        &mut out,
+        "static TRIE_PATTERN_SYNTAX",
+        "Align8",
+        index_pattern_syntax,
+    );
+    writeln!(out);
+    write_array(
+        &mut out,
+        "static TRIE_PATTERN_WHITE_SPACE",
+        "Align8",
+        index_pattern_white_space,
+    );
+    writeln!(out);
+    write_array(
+        &mut out,
+        "static TRIE_ID_COMPAT_MATH_START",
+        "Align8",
+        index_math_start,
+    );
+    writeln!(out);
+    write_array(
+        &mut out,
+        "static TRIE_ID_COMPAT_MATH_CONTINUE_EXTRA",
+        "Align8",
+        index_math_continue_extra,
+    );
+    writeln!(out);
+    write_array(
+        &mut out,
+        "static TRIE_DEFAULT_IGNORABLE",
+        "Align8",
+        index_default_ignorable,
+    );
+    writeln!(out);
+    write_array(&mut out, "static LEAF_PATTERN", "Align64", &pattern.leaf);
+    writeln!(out);
+
+    let [index_emoji, index_hashtag_continue_extra] = &emoji.index[..] else {
+        unreachable!()
+    };
+
+    write_array(&mut out, "static TRIE_EMOJI", "Align8", index_emoji);
+    writeln!(out);
+    write_array(
+        &mut out,
+        "static TRIE_HASHTAG_CONTINUE_EXTRA",
+        "Align8",
+        index_hashtag_continue_extra,
+    );
+    writeln!(out);
+    write_array(&mut out, "static LEAF_EMOJI", "Align64", &emoji.leaf);
+    writeln!(out);
+
+    for (name, flag) in [
+        ("LEFT_JOINING", LEFT_JOINING),
+        ("RIGHT_JOINING", RIGHT_JOINING),
+        ("TRANSPARENT", TRANSPARENT),
+        ("LETTER_XOR_XID_START", LETTER_XOR_XID_START),
+        ("NONSPACING_MARK", NONSPACING_MARK),
+        ("COMBINING", COMBINING),
+        ("VIRAMA", VIRAMA),
+    ] {
+        writeln!(out, "pub(crate) const {name}: u8 = 0x{flag:02x};");
+    }
+    writeln!(out);
+    write_runs(&mut out, "JOIN_CONTEXT_RUNS", join_context);
+    writeln!(out);
+
+    for (name, value) in [
+        ("CATEGORY_LETTER", CATEGORY_LETTER),
+        ("CATEGORY_MARK", CATEGORY_MARK),
+        ("CATEGORY_DECIMAL_NUMBER", CATEGORY_DECIMAL_NUMBER),
+        ("CATEGORY_LETTER_NUMBER", CATEGORY_LETTER_NUMBER),
+        (
+            "CATEGORY_CONNECTOR_PUNCTUATION",
+            CATEGORY_CONNECTOR_PUNCTUATION,
+        ),
+        ("CATEGORY_CURRENCY_SYMBOL", CATEGORY_CURRENCY_SYMBOL),
+        ("CATEGORY_FORMAT", CATEGORY_FORMAT),
+        ("CATEGORY_OTHER", CATEGORY_OTHER),
+    ] {
+        writeln!(out, "pub(crate) const {name}: u8 = {value};");
+    }
+    writeln!(out);
+    write_runs(&mut out, "CATEGORY_RUNS", category);
+    writeln!(out);
+
+    let scripts = properties.scripts();
+    writeln!(out, "/// A value of the Unicode Script property.");
+    writeln!(out, "///");
     writeln!(
         out,
-        "pub(crate) const ASCII_CONTINUE: u128 = 0x{ascii_continue:x};",
+        "/// Most Unicode versions add new scripts, so this enum is non-exhaustive.",
     );
+    writeln!(
+        out,
+        "#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]",
+    );
+    writeln!(out, "#[allow(clippy::enum_variant_names)]");
+    writeln!(out, "#[non_exhaustive]");
+    writeln!(out, "#[repr(u8)]");
+    writeln!(out, "pub enum Script {{");
+    for (short, long) in scripts {
+        writeln!(out, "    /// `{short}`");
+        writeln!(out, "    {},", long.replace('_', ""));
+    }
+    writeln!(out, "}}");
+    writeln!(out);
 
+    writeln!(
+        out,
+        "pub(crate) static SCRIPTS: [Script; {}] = [",
+        scripts.len(),
+    );
+    for (_short, long) in scripts {
+        writeln!(out, "    Script::{},", long.replace('_', ""));
+    }
+    writeln!(out, "];");
     writeln!(out);
 
-    writeln!(out, "pub(crate) const CHUNK: usize = {};", CHUNK);
+    writeln!(
+        out,
+        "pub(crate) static SCRIPT_NAMES: [(&str, &str); {}] = [",
+        scripts.len(),
+    );
+    for (short, long) in scripts {
+        writeln!(out, "    ({short:?}, {long:?}),");
+    }
+    writeln!(out, "];");
     writeln!(out);
 
+    let words = scripts.len().div_ceil(64);
     writeln!(
         out,
-        "pub(crate) static TRIE_START: Align8<[u8; {}]> = Align8([",
-        index_start.len(),
+        "pub(crate) const SCRIPT_COUNT: usize = {};",
+        scripts.len()
+    );
+    writeln!(out, "pub(crate) const SCRIPT_WORDS: usize = {};", words);
+    writeln!(out);
+
+    write_runs(&mut out, "SCRIPT_RUNS", script);
+    writeln!(out);
+    write_runs(&mut out, "SCRIPT_EXTENSIONS_RUNS", script_extensions_runs);
+    writeln!(out);
+
+    writeln!(
+        out,
+        "pub(crate) static SCRIPT_EXTENSIONS: [[u64; SCRIPT_WORDS]; {}] = [",
+        script_extensions.len(),
+    );
+    for set in script_extensions {
+        let mut bits = vec![0u64; words];
+        for &script in set {
+            bits[script as usize / 64] |= 1 << (script % 64);
+        }
+        let words: Vec<String> = bits.iter().map(|word| format!("0x{word:016X}")).collect();
+        writeln!(out, "    [{}],", words.join(", "));
+    }
+    writeln!(out, "];");
+    writeln!(out);
+
+    write_runs(&mut out, "COMBINING_CLASS_RUNS", combining_class);
+    writeln!(out);
+    write_mapping(&mut out, "CANONICAL_DECOMPOSITION", canonical_decomposition);
+    writeln!(out);
+    write_mapping(
+        &mut out,
+        "COMPATIBILITY_DECOMPOSITION",
+        compatibility_decomposition,
+    );
+    writeln!(out);
+
+    writeln!(
+        out,
+        "pub(crate) static COMPOSITIONS: [(char, char, char); {}] = [",
+        compositions.len(),
     );
-    for line in index_start.chunks(16) {
+    for line in compositions.chunks(3) {
         write!(out, "   ");
-        for byte in line {
-            write!(out, " 0x{:02X},", byte);
+        for (first, second, composite) in line {
+            write!(
+                out,
+                " ({}, {}, {}),",
+                char_literal(*first),
+                char_literal(*second),
+                char_literal(*composite),
+            );
         }
         writeln!(out);
     }
-    writeln!(out, "]);");
+    writeln!(out, "];");
+    writeln!(out);
+
+    for (name, flag) in [
+        ("NFC_NO", NFC_NO),
+        ("NFC_MAYBE", NFC_MAYBE),
+        ("NFKC_NO", NFKC_NO),
+        ("NFKC_MAYBE", NFKC_MAYBE),
+    ] {
+        writeln!(out, "pub(crate) const {name}: u8 = 0x{flag:02x};");
+    }
     writeln!(out);
+    write_runs(&mut out, "QUICK_CHECK_RUNS", quick_check);
+    writeln!(out);
+
+    write_mapping(&mut out, "NFKC_CASEFOLD", nfkc_casefold);
 
+    out
+}
+
+fn write_ascii(out: &mut Output, name: &str, property: impl Fn(char) -> bool) {
+    let bits = (0u8..128)
+        .map(|c| (property(c as char) as u128) << c)
+        .sum::<u128>();
+    writeln!(out, "pub(crate) const {name}: u128 = 0x{bits:x};");
+}
+
+fn write_array(out: &mut Output, item: &str, align: &str, bytes: &[u8]) {
     writeln!(
         out,
-        "pub(crate) static TRIE_CONTINUE: Align8<[u8; {}]> = Align8([",
-        index_continue.len(),
+        "pub(crate) {item}: {align}<[u8; {}]> = {align}([",
+        bytes.len(),
     );
-    for line in index_continue.chunks(16) {
+    for line in bytes.chunks(16) {
         write!(out, "   ");
         for byte in line {
             write!(out, " 0x{:02X},", byte);
@@ -80,21 +380,71 @@ pub fn output(
         writeln!(out);
     }
     writeln!(out, "]);");
-    writeln!(out);
+}
+
+// A mapping from characters to strings, as a sorted list of the characters and
+// a parallel list of byte offsets of the end of each one's string in a single
+// concatenated string.
+fn write_mapping(out: &mut Output, name: &str, mapping: &[(char, Vec<char>)]) {
+    let mut strings = String::new();
+    let mut ends = Vec::new();
+    for (_ch, string) in mapping {
+        strings.extend(string);
+        ends.push(u16::try_from(strings.len()).expect("exceeded u16 offsets"));
+    }
 
     writeln!(
         out,
-        "pub(crate) static LEAF: Align64<[u8; {}]> = Align64([",
-        halfdense.len(),
+        "pub(crate) static {name}_KEYS: [char; {}] = [",
+        mapping.len(),
     );
-    for line in halfdense.chunks(16) {
+    for line in mapping.chunks(8) {
         write!(out, "   ");
-        for byte in line {
-            write!(out, " 0x{:02X},", byte);
+        for (ch, _string) in line {
+            write!(out, " {},", char_literal(*ch));
         }
         writeln!(out);
     }
-    writeln!(out, "]);");
+    writeln!(out, "];");
 
-    out
+    writeln!(
+        out,
+        "pub(crate) static {name}_ENDS: [u16; {}] = [",
+        ends.len(),
+    );
+    for line in ends.chunks(12) {
+        write!(out, "   ");
+        for end in line {
+            write!(out, " {end},");
+        }
+        writeln!(out);
+    }
+    writeln!(out, "];");
+
+    writeln!(out, "pub(crate) static {name}_STRINGS: &str = \"\\");
+    let chars: Vec<char> = strings.chars().collect();
+    for line in chars.chunks(12) {
+        write!(out, "    ");
+        for ch in line {
+            write!(out, "\\u{{{:X}}}", *ch as u32);
+        }
+        writeln!(out, "\\");
+    }
+    writeln!(out, "\";");
+}
+
+fn char_literal(ch: char) -> String {
+    format!("'\\u{{{:X}}}'", ch as u32)
+}
+
+fn write_runs(out: &mut Output, name: &str, runs: &[u32]) {
+    writeln!(out, "pub(crate) static {name}: [u32; {}] = [", runs.len());
+    for line in runs.chunks(8) {
+        write!(out, "   ");
+        for run in line {
+            write!(out, " 0x{:08X},", run);
+        }
+        writeln!(out);
+    }
+    writeln!(out, "];");
 }
@@ -0,0 +1,141 @@
+// Fast paths for finding the end of a run of ASCII XID_Continue bytes, i.e.
+// [0-9A-Za-z_], which is what the overwhelming majority of identifiers in
+// source code consist of.
+//
+// On x86 and x86_64 the bytes are classified 16 at a time using SSE2, or 32 at
+// a time if AVX2 is enabled at compile time (for example by building with
+// `-C target-cpu=native`). Everywhere else, and for the tail of the input,
+// they are classified 8 at a time using arithmetic on a u64. Any non-ASCII
+// byte ends the run; the caller is responsible for looking up non-ASCII
+// characters in the trie.
+
+use crate::tables::ASCII_CONTINUE;
+
+// Number of leading bytes that are ASCII XID_Continue.
+pub(crate) fn continue_run(bytes: &[u8]) -> usize {
+    let mut len = 0;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+    ))]
+    while bytes.len() - len >= 32 {
+        let n = unsafe { x86::continue_run_avx2(bytes.as_ptr().add(len)) };
+        len += n;
+        if n < 32 {
+            return len;
+        }
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2",
+    ))]
+    while bytes.len() - len >= 16 {
+        let n = unsafe { x86::continue_run_sse2(bytes.as_ptr().add(len)) };
+        len += n;
+        if n < 16 {
+            return len;
+        }
+    }
+
+    while let [b0, b1, b2, b3, b4, b5, b6, b7, ..] = bytes[len..] {
+        let n = continue_run_swar(u64::from_le_bytes([b0, b1, b2, b3, b4, b5, b6, b7]));
+        len += n;
+        if n < 8 {
+            return len;
+        }
+    }
+
+    while let Some(&byte) = bytes.get(len) {
+        if byte >= 0x80 || ASCII_CONTINUE & (1 << byte) == 0 {
+            break;
+        }
+        len += 1;
+    }
+
+    len
+}
+
+const LO: u64 = 0x0101_0101_0101_0101;
+const HI: u64 = 0x8080_8080_8080_8080;
+
+// Number of leading bytes (in little-endian order) of the 8-byte word that are
+// ASCII XID_Continue. Each byte is classified simultaneously using carry-free
+// bytewise arithmetic on the low 7 bits, which leaves the result in the high
+// bit of each byte.
+const fn continue_run_swar(word: u64) -> usize {
+    let ascii = !word & HI;
+    let low7 = word & !HI;
+    let folded = low7 | (0x20 * LO);
+    let alpha = (folded + (0x80 - b'a' as u64) * LO) & !(folded + (0x7F - b'z' as u64) * LO);
+    let digit = (low7 + (0x80 - b'0' as u64) * LO) & !(low7 + (0x7F - b'9' as u64) * LO);
+    let underscore = !((low7 ^ (b'_' as u64 * LO)) + 0x7F * LO);
+    let matches = (alpha | digit | underscore) & ascii;
+    (!matches & HI).trailing_zeros() as usize / 8
+}
+
+// The bytewise classification above must agree with the generated table.
+const _: () = {
+    let mut byte = 0u8;
+    while byte < 0x80 {
+        let expected = ASCII_CONTINUE & (1 << byte) != 0;
+        let actual = continue_run_swar(byte as u64 * LO) == 8;
+        assert!(expected == actual);
+        byte += 1;
+    }
+};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_ptr_alignment, // loadu does not require alignment
+    clippy::cast_sign_loss,
+    clippy::wildcard_imports,
+)]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    // Signed byte comparisons are fine for classifying ASCII, because every
+    // non-ASCII byte compares less than all of the ASCII bounds used here.
+
+    #[cfg(target_feature = "sse2")]
+    pub(super) unsafe fn continue_run_sse2(ptr: *const u8) -> usize {
+        let bytes = _mm_loadu_si128(ptr.cast::<__m128i>());
+        let folded = _mm_or_si128(bytes, _mm_set1_epi8(0x20));
+        let alpha = _mm_and_si128(
+            _mm_cmpgt_epi8(folded, _mm_set1_epi8(b'a' as i8 - 1)),
+            _mm_cmpgt_epi8(_mm_set1_epi8(b'z' as i8 + 1), folded),
+        );
+        let digit = _mm_and_si128(
+            _mm_cmpgt_epi8(bytes, _mm_set1_epi8(b'0' as i8 - 1)),
+            _mm_cmpgt_epi8(_mm_set1_epi8(b'9' as i8 + 1), bytes),
+        );
+        let underscore = _mm_cmpeq_epi8(bytes, _mm_set1_epi8(b'_' as i8));
+        let matches = _mm_or_si128(_mm_or_si128(alpha, digit), underscore);
+        let mask = _mm_movemask_epi8(matches) as u16;
+        (!mask).trailing_zeros() as usize
+    }
+
+    #[cfg(target_feature = "avx2")]
+    pub(super) unsafe fn continue_run_avx2(ptr: *const u8) -> usize {
+        let bytes = _mm256_loadu_si256(ptr.cast::<__m256i>());
+        let folded = _mm256_or_si256(bytes, _mm256_set1_epi8(0x20));
+        let alpha = _mm256_and_si256(
+            _mm256_cmpgt_epi8(folded, _mm256_set1_epi8(b'a' as i8 - 1)),
+            _mm256_cmpgt_epi8(_mm256_set1_epi8(b'z' as i8 + 1), folded),
+        );
+        let digit = _mm256_and_si256(
+            _mm256_cmpgt_epi8(bytes, _mm256_set1_epi8(b'0' as i8 - 1)),
+            _mm256_cmpgt_epi8(_mm256_set1_epi8(b'9' as i8 + 1), bytes),
+        );
+        let underscore = _mm256_cmpeq_epi8(bytes, _mm256_set1_epi8(b'_' as i8));
+        let matches = _mm256_or_si256(_mm256_or_si256(alpha, digit), underscore);
+        let mask = _mm256_movemask_epi8(matches) as u32;
+        (!mask).trailing_zeros() as usize
+    }
+}
@@ -0,0 +1,62 @@
+use crate::is_default_ignorable;
+use crate::normalize::{lookup_mapping, Normalize};
+use crate::tables::{NFKC_CASEFOLD_ENDS, NFKC_CASEFOLD_KEYS, NFKC_CASEFOLD_STRINGS};
+
+/// Map an identifier to its NFKC\_Casefold form, for case-insensitive
+/// comparison.
+///
+/// This is the comparison that [UAX #31 R5][r5] recommends for
+/// case-insensitive identifiers. Besides case, it ignores compatibility
+/// differences such as ligatures and fullwidth forms, and removes
+/// Default\_Ignorable\_Code\_Point characters. Unlike `to_lowercase`, it
+/// treats `ß` the same as `ss`, final `ς` the same as `σ`, and characters
+/// with no lowercase but a case folding, such as Cherokee, correctly. The
+/// result is in NFC.
+///
+/// [r5]: https://www.unicode.org/reports/tr31/#R5
+///
+/// ```
+/// use unicode_ident::ident_casefold;
+///
+/// let folded: String = ident_casefold("Stra\u{df}e").collect();
+/// assert_eq!(folded, "strasse");
+///
+/// let folded: String = ident_casefold("\u{fb01}le_\u{ff29}\u{ff24}").collect();
+/// assert_eq!(folded, "file_id");
+/// ```
+pub fn ident_casefold(string: &str) -> impl Iterator<Item = char> + '_ {
+    Normalize::new(string.chars(), nfkc_casefold)
+}
+
+/// Whether two identifiers are equal ignoring case, according to
+/// [`ident_casefold`].
+///
+/// ```
+/// use unicode_ident::ident_eq_ignore_case;
+///
+/// assert!(ident_eq_ignore_case("STRASSE", "Stra\u{df}e"));
+/// assert!(ident_eq_ignore_case(
+///     "\u{3a3}\u{38a}\u{3a3}\u{3a5}\u{3a6}\u{39f}\u{3a3}", // ΣΊΣΥΦΟΣ
+///     "\u{3c3}\u{3af}\u{3c3}\u{3c5}\u{3c6}\u{3bf}\u{3c2}", // σίσυφος
+/// ));
+/// assert!(!ident_eq_ignore_case("file", "files"));
+/// ```
+pub fn ident_eq_ignore_case(a: &str, b: &str) -> bool {
+    // NFKC_Casefold maps ASCII to ASCII, and only changes uppercase letters.
+    if a.is_ascii() && b.is_ascii() {
+        return a.eq_ignore_ascii_case(b);
+    }
+    ident_casefold(a).eq(ident_casefold(b))
+}
+
+fn nfkc_casefold(ch: char) -> Option<&'static str> {
+    if is_default_ignorable(ch) {
+        return Some("");
+    }
+    lookup_mapping(
+        &NFKC_CASEFOLD_KEYS,
+        &NFKC_CASEFOLD_ENDS,
+        NFKC_CASEFOLD_STRINGS,
+        ch,
+    )
+}
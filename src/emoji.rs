@@ -0,0 +1,160 @@
+use crate::ident::IdentError;
+use crate::lookup;
+use crate::profile::IdentProfile;
+use crate::tables::{LEAF_EMOJI, TRIE_EMOJI};
+
+const ZWJ: char = '\u{200D}';
+const VS16: char = '\u{FE0F}';
+const KEYCAP: char = '\u{20E3}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+const PROFILE: IdentProfile = IdentProfile::new().with_emoji();
+
+/// The UAX #31 emoji profile, which extends the Default Identifier Syntax to
+/// allow emoji anywhere in an identifier.
+///
+/// In addition to XID\_Start and XID\_Continue characters, identifiers in this
+/// profile may contain emoji sequences of the form given for possible emoji
+/// in [UTS #51][uts51]:
+///
+/// [uts51]: https://www.unicode.org/reports/tr51/#EBNF_and_Regex
+///
+/// - an Emoji or Extended\_Pictographic character, optionally followed by a
+///   skin tone modifier, by U+FE0F VARIATION SELECTOR-16, or by a tag sequence
+///   such as in the flag of Scotland;
+/// - a pair of regional indicators, as in flags;
+/// - a keycap sequence like `1️⃣`, which is an ASCII digit, `#` or `*`,
+///   followed by U+FE0F and U+20E3 COMBINING ENCLOSING KEYCAP;
+/// - any of the above joined by U+200D ZERO WIDTH JOINER.
+///
+/// This checks the shape of the sequence, not whether it is one of the
+/// recommended emoji for general interchange, so that identifiers remain
+/// valid as new emoji are added.
+///
+/// ```
+/// use unicode_ident::EmojiProfile;
+///
+/// let profile = EmojiProfile::new();
+/// assert!(profile.is_valid("\u{1F680}_launch")); // 🚀_launch
+/// assert!(profile.is_valid("done_\u{2705}")); // done_✅
+/// assert!(profile.is_valid("\u{1F469}\u{200D}\u{1F4BB}")); // 👩‍💻
+/// assert!(!profile.is_valid("1"));
+/// assert!(!profile.is_valid("\u{1F3F4}\u{E0067}")); // unterminated tag sequence
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct EmojiProfile {
+    _private: (),
+}
+
+impl EmojiProfile {
+    /// The emoji profile.
+    pub const fn new() -> Self {
+        EmojiProfile { _private: () }
+    }
+
+    /// Whether the string is an identifier in this profile.
+    pub fn is_valid(&self, string: &str) -> bool {
+        PROFILE.is_valid(string)
+    }
+
+    /// Check the string against this profile, reporting the first character
+    /// at which it fails.
+    pub fn validate(&self, string: &str) -> Result<(), IdentError> {
+        PROFILE.validate(string)
+    }
+
+    /// Length in bytes of the longest prefix of the string that is an
+    /// identifier in this profile.
+    pub fn scan(&self, string: &str) -> usize {
+        PROFILE.scan(string)
+    }
+}
+
+// Length of the emoji ZWJ sequence at the start of the string, or 0 if it does
+// not start with one. A trailing ZWJ that is not followed by another emoji is
+// not part of the sequence.
+pub(crate) fn scan_emoji(string: &str) -> usize {
+    let mut len = scan_emoji_element(string);
+    if len == 0 {
+        return 0;
+    }
+    while let Some(rest) = string[len..].strip_prefix(ZWJ) {
+        let element = scan_emoji_element(rest);
+        if element == 0 {
+            break;
+        }
+        len += ZWJ.len_utf8() + element;
+    }
+    len
+}
+
+fn scan_emoji_element(string: &str) -> usize {
+    let mut chars = string.chars();
+    let Some(base) = chars.next() else {
+        return 0;
+    };
+
+    if base.is_ascii() {
+        // Only a keycap sequence can begin with an ASCII character.
+        let is_keycap = matches!(base, '0'..='9' | '#' | '*')
+            && chars.next() == Some(VS16)
+            && chars.next() == Some(KEYCAP);
+        return if is_keycap {
+            base.len_utf8() + VS16.len_utf8() + KEYCAP.len_utf8()
+        } else {
+            0
+        };
+    }
+
+    if !is_emoji(base) {
+        return 0;
+    }
+    let len = base.len_utf8();
+    let rest = chars.as_str();
+
+    if is_regional_indicator(base) {
+        if let Some(second) = rest.chars().next().filter(|&ch| is_regional_indicator(ch)) {
+            return len + second.len_utf8();
+        }
+    }
+
+    match rest.chars().next() {
+        Some(ch) if is_emoji_modifier(ch) => len + ch.len_utf8(),
+        Some(VS16) => match rest[VS16.len_utf8()..].strip_prefix(KEYCAP) {
+            Some(_) => len + VS16.len_utf8() + KEYCAP.len_utf8(),
+            None => len + VS16.len_utf8(),
+        },
+        Some(ch) if is_tag_spec(ch) => len + scan_tag_modifier(rest),
+        _ => len,
+    }
+}
+
+// One or more tag characters followed by CANCEL TAG, or 0 if unterminated.
+fn scan_tag_modifier(string: &str) -> usize {
+    let spec = string
+        .chars()
+        .take_while(|&ch| is_tag_spec(ch))
+        .map(char::len_utf8)
+        .sum::<usize>();
+    match string[spec..].strip_prefix(CANCEL_TAG) {
+        Some(_) => spec + CANCEL_TAG.len_utf8(),
+        None => 0,
+    }
+}
+
+// Emoji or Extended_Pictographic, outside of ASCII.
+fn is_emoji(ch: char) -> bool {
+    lookup(ch, &TRIE_EMOJI.0, &LEAF_EMOJI.0)
+}
+
+fn is_emoji_modifier(ch: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&ch)
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch)
+}
+
+fn is_tag_spec(ch: char) -> bool {
+    ('\u{E0020}'..='\u{E007E}').contains(&ch)
+}
@@ -0,0 +1,71 @@
+//! Hashtag identifiers, as in UAX #31 [section 6][tr31-6].
+//!
+//! [tr31-6]: https://www.unicode.org/reports/tr31/#hashtag_identifiers
+//!
+//! A hashtag is one Hashtag\_Start character, which is `#` or one of its
+//! small and fullwidth forms, followed by any number of Hashtag\_Continue
+//! characters. Hashtag\_Continue is XID\_Continue plus the emoji characters
+//! Extended\_Pictographic and Emoji\_Component, plus `-`, `+` and `_`, but
+//! not the Hashtag\_Start characters themselves.
+//!
+//! ```
+//! use unicode_ident::hashtag;
+//!
+//! assert!(hashtag::is_hashtag("#MötleyCrüe"));
+//! assert!(hashtag::is_hashtag("#C++"));
+//! assert!(hashtag::is_hashtag("#\u{1F680}launch")); // #🚀launch
+//! assert_eq!(hashtag::scan_hashtag("#rust, #unicode"), 5);
+//! ```
+//!
+//! Hashtags are meant to be compared after NFKC\_Casefold normalization, so
+//! that `#MötleyCrüe` matches `#MÖTLEYCRÜE`. This module only determines
+//! their extent.
+
+use crate::ident::{validate_with, IdentError};
+use crate::tables::{ASCII_HASHTAG_CONTINUE, LEAF_EMOJI, TRIE_HASHTAG_CONTINUE_EXTRA};
+use crate::{is_xid_continue, lookup};
+
+/// Whether the character is Hashtag\_Start: U+0023 NUMBER SIGN, U+FE5F SMALL
+/// NUMBER SIGN, or U+FF03 FULLWIDTH NUMBER SIGN.
+pub fn is_hashtag_start(ch: char) -> bool {
+    matches!(ch, '#' | '\u{FE5F}' | '\u{FF03}')
+}
+
+/// Whether the character is Hashtag\_Continue.
+pub fn is_hashtag_continue(ch: char) -> bool {
+    if ch.is_ascii() {
+        return ASCII_HASHTAG_CONTINUE & (1 << ch as u128) != 0;
+    }
+    if is_xid_continue(ch) || ('\u{E0020}'..='\u{E007F}').contains(&ch) {
+        return true;
+    }
+    lookup(ch, &TRIE_HASHTAG_CONTINUE_EXTRA.0, &LEAF_EMOJI.0)
+}
+
+/// Whether the string is a hashtag, including its leading `#`.
+pub fn is_hashtag(string: &str) -> bool {
+    validate_hashtag(string).is_ok()
+}
+
+/// Check that the string is a hashtag, reporting the first character at which
+/// it fails.
+pub fn validate_hashtag(string: &str) -> Result<(), IdentError> {
+    validate_with(string, scan_hashtag(string))
+}
+
+/// Length in bytes of the longest prefix of the string that is a hashtag.
+///
+/// Returns 0 if the string does not begin with a Hashtag\_Start character.
+pub fn scan_hashtag(string: &str) -> usize {
+    let mut chars = string.char_indices();
+    match chars.next() {
+        Some((_, ch)) if is_hashtag_start(ch) => {}
+        _ => return 0,
+    }
+    for (offset, ch) in chars {
+        if !is_hashtag_continue(ch) {
+            return offset;
+        }
+    }
+    string.len()
+}
@@ -0,0 +1,195 @@
+use crate::ascii;
+use crate::{
+    is_xid_continue, is_xid_continue_const, is_xid_continue_utf8, is_xid_start, is_xid_start_const,
+    is_xid_start_utf8,
+};
+use core::fmt::{self, Display};
+
+/// Whether the string is an identifier according to the UAX #31 Default
+/// Identifier Syntax: one XID\_Start character followed by any number of
+/// XID\_Continue characters.
+///
+/// The empty string is not an identifier. Neither is any string that begins
+/// with `_`, because underscore is XID\_Continue but not XID\_Start; languages
+/// that want to allow a leading underscore need to special-case it.
+pub fn is_ident(string: &str) -> bool {
+    validate_ident(string).is_ok()
+}
+
+/// Check the string against the UAX #31 Default Identifier Syntax, reporting
+/// the first character at which it fails.
+///
+/// This accepts exactly the same strings as [`is_ident`].
+pub fn validate_ident(string: &str) -> Result<(), IdentError> {
+    let mut chars = string.char_indices();
+    match chars.next() {
+        None => {
+            return Err(IdentError {
+                offset: 0,
+                ch: None,
+            })
+        }
+        Some((_, ch)) if !is_xid_start(ch) => {
+            return Err(IdentError {
+                offset: 0,
+                ch: Some(ch),
+            });
+        }
+        Some(_) => {}
+    }
+    for (offset, ch) in chars {
+        if !is_xid_continue(ch) {
+            return Err(IdentError {
+                offset,
+                ch: Some(ch),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Whether the string is an identifier according to the UAX #31 Default
+/// Identifier Syntax, in const context.
+///
+/// This is the same as [`is_ident`], but callable in constants and statics,
+/// for example to reject an invalid identifier at compile time:
+///
+/// ```
+/// const _: () = assert!(unicode_ident::is_ident_str("\u{3bb}_expr"));
+/// ```
+///
+/// See [`is_xid_start_const`] for why this should not be preferred at runtime.
+pub const fn is_ident_str(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    if bytes.is_empty() {
+        return false;
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        let (ch, len) = decode_const(bytes, i);
+        let is_valid = if i == 0 {
+            is_xid_start_const(ch)
+        } else {
+            is_xid_continue_const(ch)
+        };
+        if !is_valid {
+            return false;
+        }
+        i += len;
+    }
+    true
+}
+
+// Decode the char starting at byte offset i of a well-formed UTF-8 string.
+const fn decode_const(bytes: &[u8], i: usize) -> (char, usize) {
+    let b0 = bytes[i] as u32;
+    let (code, len) = if b0 < 0x80 {
+        (b0, 1)
+    } else if b0 < 0xE0 {
+        ((b0 & 0x1F) << 6 | (bytes[i + 1] as u32 & 0x3F), 2)
+    } else if b0 < 0xF0 {
+        let code =
+            (b0 & 0x0F) << 12 | (bytes[i + 1] as u32 & 0x3F) << 6 | (bytes[i + 2] as u32 & 0x3F);
+        (code, 3)
+    } else {
+        let code = (b0 & 0x07) << 18
+            | (bytes[i + 1] as u32 & 0x3F) << 12
+            | (bytes[i + 2] as u32 & 0x3F) << 6
+            | (bytes[i + 3] as u32 & 0x3F);
+        (code, 4)
+    };
+    match char::from_u32(code) {
+        Some(ch) => (ch, len),
+        None => unreachable!(),
+    }
+}
+
+/// Length in bytes of the longest prefix of the string that is an identifier
+/// according to the UAX #31 Default Identifier Syntax.
+///
+/// Returns 0 if the string does not begin with an XID\_Start character. This
+/// is intended for tokenizers, which can consume an entire identifier in one
+/// call rather than classifying one `char` at a time.
+///
+/// ```
+/// assert_eq!(unicode_ident::scan_ident("foo_bar + 1"), 7);
+/// assert_eq!(unicode_ident::scan_ident("1 + foo"), 0);
+/// ```
+pub fn scan_ident(string: &str) -> usize {
+    scan_ident_utf8(string.as_bytes())
+}
+
+/// Length in bytes of the longest prefix of a UTF-8 encoded byte string that
+/// is an identifier according to the UAX #31 Default Identifier Syntax.
+///
+/// Scanning stops at the first byte that does not begin a well-formed UTF-8
+/// sequence, as if that were a non-identifier character.
+pub fn scan_ident_utf8(utf8: &[u8]) -> usize {
+    let Some((true, mut len)) = is_xid_start_utf8(utf8) else {
+        return 0;
+    };
+    loop {
+        len += ascii::continue_run(&utf8[len..]);
+        match is_xid_continue_utf8(&utf8[len..]) {
+            Some((true, n)) => len += n,
+            _ => return len,
+        }
+    }
+}
+
+// Validation in terms of a scanner: the string is an identifier if the longest
+// identifier prefix, of length `len`, is nonempty and is the whole string.
+pub(crate) fn validate_with(string: &str, len: usize) -> Result<(), IdentError> {
+    if len == string.len() && len != 0 {
+        return Ok(());
+    }
+    Err(IdentError {
+        offset: len,
+        ch: string[len..].chars().next(),
+    })
+}
+
+/// Error returned by [`validate_ident`] and the `validate` method of the
+/// identifier profiles, such as [`EmojiProfile::validate`].
+///
+/// [`EmojiProfile::validate`]: crate::EmojiProfile::validate
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IdentError {
+    pub(crate) offset: usize,
+    pub(crate) ch: Option<char>,
+}
+
+impl IdentError {
+    /// Byte offset of the offending character within the string.
+    ///
+    /// For an empty string this is 0.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The offending character, or `None` if the string was empty.
+    ///
+    /// When the offset is 0, this is a character that cannot start an
+    /// identifier, such as one that is not XID\_Start. Otherwise it is a
+    /// character that cannot continue one, such as one that is not
+    /// XID\_Continue.
+    pub fn ch(&self) -> Option<char> {
+        self.ch
+    }
+}
+
+impl Display for IdentError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.ch {
+            None => formatter.write_str("empty identifier"),
+            Some(ch) if self.offset == 0 => {
+                write!(formatter, "identifier cannot start with {ch:?}")
+            }
+            Some(ch) => write!(
+                formatter,
+                "identifier cannot contain {ch:?} (at byte offset {})",
+                self.offset,
+            ),
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::ident::{validate_ident, IdentError};
+use crate::{is_xid_continue, Ident};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::fmt::{self, Debug, Display};
+use core::ops::Deref;
+use core::str::FromStr;
+
+/// An owned string that is an identifier according to the UAX #31 Default
+/// Identifier Syntax.
+///
+/// This is to [`Ident`] what `String` is to `str`. Appending to it only
+/// accepts XID\_Continue characters, so it remains an identifier.
+///
+/// ```
+/// use unicode_ident::IdentBuf;
+///
+/// let mut ident: IdentBuf = "field".parse().unwrap();
+/// ident.push('_').unwrap();
+/// ident.push_str("0").unwrap();
+/// assert_eq!(ident, "field_0");
+///
+/// let err = ident.push_str("+1").unwrap_err();
+/// assert_eq!(err.offset(), 7);
+/// assert_eq!(ident, "field_0");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IdentBuf {
+    string: String,
+}
+
+impl IdentBuf {
+    /// Validate that the string is an identifier.
+    pub fn new(string: String) -> Result<IdentBuf, IdentError> {
+        validate_ident(&string)?;
+        Ok(IdentBuf { string })
+    }
+
+    /// Borrow the identifier as an [`Ident`].
+    pub fn as_ident(&self) -> &Ident {
+        unsafe { Ident::new_unchecked(&self.string) }
+    }
+
+    /// Convert into the underlying `String`, without copying.
+    pub fn into_string(self) -> String {
+        self.string
+    }
+
+    /// Append a character, which must be XID\_Continue.
+    pub fn push(&mut self, ch: char) -> Result<(), IdentError> {
+        if !is_xid_continue(ch) {
+            return Err(IdentError {
+                offset: self.string.len(),
+                ch: Some(ch),
+            });
+        }
+        self.string.push(ch);
+        Ok(())
+    }
+
+    /// Append a string, all of whose characters must be XID\_Continue.
+    ///
+    /// On error, the identifier is left unchanged, and the offset in the
+    /// error is where the offending character would have been in the
+    /// identifier.
+    pub fn push_str(&mut self, string: &str) -> Result<(), IdentError> {
+        if let Some((offset, ch)) = string.char_indices().find(|&(_, ch)| !is_xid_continue(ch)) {
+            return Err(IdentError {
+                offset: self.string.len() + offset,
+                ch: Some(ch),
+            });
+        }
+        self.string.push_str(string);
+        Ok(())
+    }
+
+    /// Append another identifier. Every character of an identifier is
+    /// XID\_Continue, so this cannot fail.
+    pub fn push_ident(&mut self, ident: &Ident) {
+        self.string.push_str(ident);
+    }
+}
+
+impl Deref for IdentBuf {
+    type Target = Ident;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ident()
+    }
+}
+
+impl AsRef<Ident> for IdentBuf {
+    fn as_ref(&self) -> &Ident {
+        self.as_ident()
+    }
+}
+
+impl AsRef<str> for IdentBuf {
+    fn as_ref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl Borrow<Ident> for IdentBuf {
+    fn borrow(&self) -> &Ident {
+        self.as_ident()
+    }
+}
+
+impl Borrow<str> for IdentBuf {
+    fn borrow(&self) -> &str {
+        &self.string
+    }
+}
+
+impl ToOwned for Ident {
+    type Owned = IdentBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        IdentBuf {
+            string: self.as_str().to_owned(),
+        }
+    }
+}
+
+impl From<&Ident> for IdentBuf {
+    fn from(ident: &Ident) -> Self {
+        ident.to_owned()
+    }
+}
+
+impl From<IdentBuf> for String {
+    fn from(ident: IdentBuf) -> Self {
+        ident.string
+    }
+}
+
+impl TryFrom<String> for IdentBuf {
+    type Error = IdentError;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        IdentBuf::new(string)
+    }
+}
+
+impl TryFrom<&str> for IdentBuf {
+    type Error = IdentError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        Ident::new(string).map(Ident::to_owned)
+    }
+}
+
+impl FromStr for IdentBuf {
+    type Err = IdentError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        IdentBuf::try_from(string)
+    }
+}
+
+impl PartialEq<str> for IdentBuf {
+    fn eq(&self, other: &str) -> bool {
+        self.string == other
+    }
+}
+
+impl PartialEq<&str> for IdentBuf {
+    fn eq(&self, other: &&str) -> bool {
+        self.string == *other
+    }
+}
+
+impl PartialEq<Ident> for IdentBuf {
+    fn eq(&self, other: &Ident) -> bool {
+        self.as_ident() == other
+    }
+}
+
+impl PartialEq<&Ident> for IdentBuf {
+    fn eq(&self, other: &&Ident) -> bool {
+        self.as_ident() == *other
+    }
+}
+
+impl Display for IdentBuf {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.string, formatter)
+    }
+}
+
+impl Debug for IdentBuf {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.string, formatter)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::ident::{is_ident_str, validate_ident, IdentError};
+use core::borrow::Borrow;
+use core::fmt::{self, Debug, Display};
+use core::ops::Deref;
+
+/// A string slice that is an identifier according to the UAX #31 Default
+/// Identifier Syntax.
+///
+/// This is to [`IdentBuf`] what `str` is to `String`. Constructing one
+/// validates the string once, after which code that receives an `&Ident` can
+/// rely on it being an identifier.
+///
+/// [`IdentBuf`]: crate::IdentBuf
+///
+/// ```
+/// use unicode_ident::Ident;
+///
+/// let ident = Ident::new("\u{3bb}_expr").unwrap();
+/// assert_eq!(ident.len(), 7);
+///
+/// let err = Ident::new("foo-bar").unwrap_err();
+/// assert_eq!(err.offset(), 3);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Ident(str);
+
+impl Ident {
+    /// Validate that the string is an identifier.
+    pub fn new(string: &str) -> Result<&Ident, IdentError> {
+        validate_ident(string)?;
+        Ok(unsafe { Ident::new_unchecked(string) })
+    }
+
+    /// Validate that the string is an identifier, in const context.
+    ///
+    /// ```
+    /// use unicode_ident::Ident;
+    ///
+    /// const NAME: &Ident = Ident::new_const("\u{3bb}_expr");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is not an identifier, which in a constant is a
+    /// compile-time error.
+    pub const fn new_const(string: &str) -> &Ident {
+        assert!(is_ident_str(string), "not an identifier");
+        unsafe { Ident::new_unchecked(string) }
+    }
+
+    /// Convert a string to an `Ident` without validating it.
+    ///
+    /// # Safety
+    ///
+    /// The string must be an identifier according to [`is_ident`].
+    ///
+    /// [`is_ident`]: crate::is_ident
+    pub const unsafe fn new_unchecked(string: &str) -> &Ident {
+        unsafe { &*(string as *const str as *const Ident) }
+    }
+
+    /// The identifier as a string slice.
+    pub const fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Ident {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Ident {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Ident {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a Ident {
+    type Error = IdentError;
+
+    fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+        Ident::new(string)
+    }
+}
+
+impl PartialEq<str> for Ident {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Ident> for str {
+    fn eq(&self, other: &Ident) -> bool {
+        *self == other.0
+    }
+}
+
+impl Display for Ident {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.0, formatter)
+    }
+}
+
+impl Debug for Ident {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.0, formatter)
+    }
+}
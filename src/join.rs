@@ -0,0 +1,121 @@
+use crate::ident::IdentError;
+use crate::is_xid_start;
+use crate::profile::IdentProfile;
+use crate::script::lookup_run;
+use crate::tables::{
+    COMBINING, JOIN_CONTEXT_RUNS, LEFT_JOINING, LETTER_XOR_XID_START, NONSPACING_MARK,
+    RIGHT_JOINING, TRANSPARENT, VIRAMA,
+};
+use core::iter::Rev;
+use core::str::Chars;
+
+pub(crate) const ZWNJ: char = '\u{200C}';
+pub(crate) const ZWJ: char = '\u{200D}';
+
+const PROFILE: IdentProfile = IdentProfile::new().restrict_join_controls();
+
+/// The Default Identifier Syntax, with U+200C ZERO WIDTH NON-JOINER and U+200D
+/// ZERO WIDTH JOINER allowed only in the contexts given by UAX #31
+/// [section 2.3][tr31-2.3].
+///
+/// [tr31-2.3]: https://www.unicode.org/reports/tr31/#Layout_and_Format_Control_Characters
+///
+/// Since Unicode 15.1, ZWNJ and ZWJ are XID\_Continue, so [`is_ident`]
+/// accepts them anywhere after the first character. They are invisible, so
+/// languages that do not want `a\u{200C}b` and `ab` to be two different
+/// identifiers that look the same can restrict them to where they affect
+/// rendering:
+///
+/// - **A1.** ZWNJ breaking a cursive connection, between a left-joining or
+///   dual-joining character and a right-joining or dual-joining character,
+///   with any number of transparent characters on either side. This is how it
+///   is used in Persian, for example.
+/// - **A2.** ZWNJ after a letter and a virama, as in Indic conjuncts.
+/// - **B.** ZWJ after a letter and a virama.
+///
+/// [`is_ident`]: crate::is_ident
+///
+/// ```
+/// use unicode_ident::JoinControlProfile;
+///
+/// let profile = JoinControlProfile::new();
+/// assert!(profile.is_valid("\u{645}\u{6cc}\u{200c}\u{62e}\u{648}\u{627}\u{647}\u{645}")); // می‌خواهم
+/// assert!(profile.is_valid("\u{915}\u{94d}\u{200d}\u{937}")); // क्‍ष
+/// assert!(!profile.is_valid("a\u{200c}b"));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct JoinControlProfile {
+    _private: (),
+}
+
+impl JoinControlProfile {
+    /// The Default Identifier Syntax with contextual ZWNJ and ZWJ.
+    pub const fn new() -> Self {
+        JoinControlProfile { _private: () }
+    }
+
+    /// Whether the string is an identifier in this profile.
+    pub fn is_valid(&self, string: &str) -> bool {
+        PROFILE.is_valid(string)
+    }
+
+    /// Check the string against this profile, reporting the first character
+    /// at which it fails.
+    pub fn validate(&self, string: &str) -> Result<(), IdentError> {
+        PROFILE.validate(string)
+    }
+
+    /// Length in bytes of the longest prefix of the string that is an
+    /// identifier in this profile.
+    pub fn scan(&self, string: &str) -> usize {
+        PROFILE.scan(string)
+    }
+}
+
+// Whether the ZWNJ or ZWJ at the given byte offset is in one of the contexts
+// in which UAX #31 allows it.
+pub(crate) fn is_join_control_allowed(string: &str, offset: usize, ch: char) -> bool {
+    let before = &string[..offset];
+    let after = &string[offset + ch.len_utf8()..];
+    (ch == ZWNJ && breaks_cursive_connection(before, after)) || follows_virama(before)
+}
+
+// A1: /$LJ $T* ZWNJ $T* $RJ/
+fn breaks_cursive_connection(before: &str, after: &str) -> bool {
+    let is_opaque = |&ch: &char| join_context(ch) & TRANSPARENT == 0;
+    let left = before.chars().rev().find(is_opaque);
+    let right = after.chars().find(is_opaque);
+    left.is_some_and(|ch| join_context(ch) & LEFT_JOINING != 0)
+        && right.is_some_and(|ch| join_context(ch) & RIGHT_JOINING != 0)
+}
+
+// A2 and B: /$L $M* $V $M₁* ZWNJ/ and /$L $M* $V $M₁* ZWJ/
+fn follows_virama(before: &str) -> bool {
+    let mut chars = before.chars().rev();
+    while let Some(ch) = chars.next() {
+        let context = join_context(ch);
+        if context & VIRAMA != 0 && follows_letter(chars.clone()) {
+            return true;
+        }
+        // $M₁ is a nonspacing mark with nonzero Canonical_Combining_Class.
+        if context & (NONSPACING_MARK | COMBINING) != NONSPACING_MARK | COMBINING {
+            return false;
+        }
+    }
+    false
+}
+
+// /$L $M*/ immediately before the virama.
+fn follows_letter(chars: Rev<Chars>) -> bool {
+    for ch in chars {
+        let context = join_context(ch);
+        if context & NONSPACING_MARK == 0 {
+            return is_xid_start(ch) != (context & LETTER_XOR_XID_START != 0);
+        }
+    }
+    false
+}
+
+fn join_context(ch: char) -> u8 {
+    lookup_run(&JOIN_CONTEXT_RUNS, ch)
+}
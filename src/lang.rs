@@ -0,0 +1,230 @@
+use crate::ident::{validate_with, IdentError};
+use crate::script::lookup_run;
+use crate::tables::{
+    CATEGORY_CONNECTOR_PUNCTUATION, CATEGORY_CURRENCY_SYMBOL, CATEGORY_DECIMAL_NUMBER,
+    CATEGORY_FORMAT, CATEGORY_LETTER, CATEGORY_LETTER_NUMBER, CATEGORY_MARK, CATEGORY_OTHER,
+    CATEGORY_RUNS,
+};
+use crate::{is_id_continue, is_id_start, is_xid_continue, is_xid_start};
+
+/// The lexical identifier syntax of a programming language.
+///
+/// These check only the shape of an identifier token. Keywords, which most
+/// languages exclude from identifiers at a later stage, are not rejected.
+///
+/// ```
+/// use unicode_ident::Language;
+///
+/// assert!(Language::Rust.is_valid("r#async"));
+/// assert!(Language::EcmaScript.is_valid("$\\u{1d4b3}"));
+/// assert!(Language::Java.is_valid("price\u{a2}")); // price¢
+/// assert!(!Language::Go.is_valid("price\u{a2}"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    /// Rust, as given by the [Reference][rust].
+    ///
+    /// [rust]: https://doc.rust-lang.org/reference/identifiers.html
+    ///
+    /// XID\_Start or `_` followed by XID\_Continue, other than `_` by itself,
+    /// optionally preceded by `r#` to form a raw identifier. See also
+    /// [`rust::is_rust_ident`][crate::rust::is_rust_ident], which excludes
+    /// keywords.
+    Rust,
+    /// ECMAScript (JavaScript), as given by [ECMA-262][ecma262].
+    ///
+    /// [ecma262]: https://tc39.es/ecma262/#sec-names-and-keywords
+    ///
+    /// ID\_Start, `$` or `_` followed by ID\_Continue or `$`, where any of
+    /// these may be written as a `\uXXXX` or `\u{X…}` escape.
+    EcmaScript,
+    /// Python, as given by [PEP 3131].
+    ///
+    /// [PEP 3131]: https://peps.python.org/pep-3131/
+    ///
+    /// XID\_Start or `_` followed by XID\_Continue. Python applies this to the
+    /// NFKC normalization of the identifier, so this agrees with the Python
+    /// lexer for identifiers in NFKC.
+    Python,
+    /// C23, as given by [N2836].
+    ///
+    /// [N2836]: https://www.open-std.org/jtc1/sc22/wg14/www/docs/n2836.pdf
+    ///
+    /// XID\_Start or `_` followed by XID\_Continue, where any of these may be
+    /// written as a `\uXXXX` or `\UXXXXXXXX` universal character name. Those
+    /// below U+00A0, such as `\u0061`, are not allowed.
+    C,
+    /// C++23, as given by [P1949].
+    ///
+    /// [P1949]: https://www.open-std.org/jtc1/sc22/wg21/docs/papers/2021/p1949r7.html
+    ///
+    /// The same as C, with universal character names additionally written as
+    /// `\u{X…}`. Named universal character names, `\N{…}`, are not
+    /// supported.
+    Cpp,
+    /// Go, as given by [the Go specification][go].
+    ///
+    /// [go]: https://go.dev/ref/spec#Identifiers
+    ///
+    /// A letter (General\_Category Lu, Ll, Lt, Lm or Lo) or `_`, followed by
+    /// letters, `_` and decimal digits (General\_Category Nd).
+    Go,
+    /// Java, as given by [`Character.isJavaIdentifierStart`][start] and
+    /// [`Character.isJavaIdentifierPart`][part].
+    ///
+    /// [start]: https://docs.oracle.com/en/java/javase/21/docs/api/java.base/java/lang/Character.html#isJavaIdentifierStart(int)
+    /// [part]: https://docs.oracle.com/en/java/javase/21/docs/api/java.base/java/lang/Character.html#isJavaIdentifierPart(int)
+    ///
+    /// Java translates `\uXXXX` escapes before lexing, so this expects them
+    /// to have been translated already.
+    Java,
+}
+
+impl Language {
+    /// Whether the character may begin an identifier in this language.
+    pub fn is_start(self, ch: char) -> bool {
+        match self {
+            Language::Rust | Language::Python | Language::C | Language::Cpp => {
+                ch == '_' || is_xid_start(ch)
+            }
+            Language::EcmaScript => ch == '$' || ch == '_' || is_id_start(ch),
+            Language::Go => ch == '_' || category(ch) == CATEGORY_LETTER,
+            Language::Java => matches!(
+                category(ch),
+                CATEGORY_LETTER
+                    | CATEGORY_LETTER_NUMBER
+                    | CATEGORY_CURRENCY_SYMBOL
+                    | CATEGORY_CONNECTOR_PUNCTUATION
+            ),
+        }
+    }
+
+    /// Whether the character may appear after the first character of an
+    /// identifier in this language.
+    pub fn is_continue(self, ch: char) -> bool {
+        match self {
+            Language::Rust | Language::Python | Language::C | Language::Cpp => is_xid_continue(ch),
+            Language::EcmaScript => ch == '$' || is_id_continue(ch),
+            Language::Go => {
+                ch == '_' || matches!(category(ch), CATEGORY_LETTER | CATEGORY_DECIMAL_NUMBER)
+            }
+            Language::Java => {
+                // Character.isIdentifierIgnorable
+                let is_ignorable =
+                    matches!(ch, '\0'..='\x08' | '\x0E'..='\x1B' | '\x7F'..='\u{9F}');
+                is_ignorable
+                    || matches!(
+                        category(ch),
+                        CATEGORY_LETTER
+                            | CATEGORY_LETTER_NUMBER
+                            | CATEGORY_CURRENCY_SYMBOL
+                            | CATEGORY_CONNECTOR_PUNCTUATION
+                            | CATEGORY_DECIMAL_NUMBER
+                            | CATEGORY_MARK
+                            | CATEGORY_FORMAT
+                    )
+            }
+        }
+    }
+
+    /// Whether the string is an identifier in this language.
+    pub fn is_valid(self, string: &str) -> bool {
+        self.validate(string).is_ok()
+    }
+
+    /// Check the string against the identifier syntax of this language,
+    /// reporting the first character at which it fails.
+    pub fn validate(self, string: &str) -> Result<(), IdentError> {
+        validate_with(string, self.scan(string))
+    }
+
+    /// Length in bytes of the longest prefix of the string that is an
+    /// identifier in this language.
+    pub fn scan(self, string: &str) -> usize {
+        if self == Language::Rust {
+            if let Some(rest) = string.strip_prefix("r#") {
+                let len = self.scan_chars(rest);
+                if len != 0 {
+                    return "r#".len() + len;
+                }
+            }
+        }
+        self.scan_chars(string)
+    }
+
+    fn scan_chars(self, string: &str) -> usize {
+        let mut len = 0;
+        while len < string.len() {
+            let rest = &string[len..];
+            let (ch, width) = self.scan_escape(rest).unwrap_or_else(|| {
+                let ch = rest.chars().next().unwrap_or_default();
+                (ch, ch.len_utf8())
+            });
+            let is_valid = if len == 0 {
+                self.is_start(ch)
+            } else {
+                self.is_continue(ch)
+            };
+            if !is_valid {
+                break;
+            }
+            len += width;
+        }
+        if self == Language::Rust && &string[..len] == "_" {
+            return 0;
+        }
+        len
+    }
+
+    // The character denoted by the escape sequence at the start of the string,
+    // and the length of the escape sequence, in languages that allow escapes
+    // in identifiers.
+    fn scan_escape(self, string: &str) -> Option<(char, usize)> {
+        if !matches!(self, Language::EcmaScript | Language::C | Language::Cpp) {
+            return None;
+        }
+        let rest = string.strip_prefix('\\')?;
+        let braced = matches!(self, Language::EcmaScript | Language::Cpp);
+        let (digits, len) = if let Some(rest) = rest.strip_prefix("u{").filter(|_| braced) {
+            let end = rest.find('}')?;
+            (&rest[..end], "\\u{}".len() + end)
+        } else if let Some(rest) = rest.strip_prefix('u') {
+            (rest.get(..4)?, "\\u".len() + 4)
+        } else if let Some(rest) = rest
+            .strip_prefix('U')
+            .filter(|_| self != Language::EcmaScript)
+        {
+            (rest.get(..8)?, "\\U".len() + 8)
+        } else {
+            return None;
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let digits = digits.trim_start_matches('0');
+        let value = if digits.is_empty() {
+            0
+        } else {
+            u32::from_str_radix(digits, 16).ok()?
+        };
+        let ch = char::from_u32(value)?;
+        // C and C++ do not allow universal character names for the basic
+        // character set, so apart from $, @ and ` nothing below U+00A0.
+        if self != Language::EcmaScript && ch < '\u{a0}' && !matches!(ch, '$' | '@' | '`') {
+            return None;
+        }
+        Some((ch, len))
+    }
+}
+
+// General_Category, grouped as in CATEGORY_RUNS. A value of 0 in the table
+// means the group follows from the character's XID properties.
+fn category(ch: char) -> u8 {
+    match lookup_run(&CATEGORY_RUNS, ch) {
+        0 if is_xid_start(ch) => CATEGORY_LETTER,
+        0 if is_xid_continue(ch) => CATEGORY_MARK,
+        0 => CATEGORY_OTHER,
+        category => category,
+    }
+}
@@ -243,17 +243,81 @@
 
 #![no_std]
 #![doc(html_root_url = "https://docs.rs/unicode-ident/1.0.24")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(
     clippy::doc_markdown,
+    clippy::missing_errors_doc,
     clippy::must_use_candidate,
     clippy::unreadable_literal
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod ascii;
+#[cfg(feature = "alloc")]
+mod casefold;
+mod emoji;
+pub mod hashtag;
+mod ident;
+#[cfg(feature = "alloc")]
+mod ident_buf;
+mod ident_str;
+mod join;
+mod lang;
+#[cfg(feature = "alloc")]
+mod mangle;
+mod math;
+mod mixed;
+#[cfg(feature = "alloc")]
+mod normalize;
+mod profile;
+mod quick_check;
+pub mod rust;
+#[cfg(feature = "alloc")]
+mod sanitize;
+mod script;
+#[cfg(feature = "serde")]
+mod serde;
 #[rustfmt::skip]
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
 mod tables;
 
-pub use crate::tables::UNICODE_VERSION;
-use crate::tables::{ASCII_CONTINUE, ASCII_START, CHUNK, LEAF, TRIE_CONTINUE, TRIE_START};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::casefold::{ident_casefold, ident_eq_ignore_case};
+pub use crate::emoji::EmojiProfile;
+pub use crate::ident::{
+    is_ident, is_ident_str, scan_ident, scan_ident_utf8, validate_ident, IdentError,
+};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::ident_buf::IdentBuf;
+pub use crate::ident_str::Ident;
+pub use crate::join::JoinControlProfile;
+pub use crate::lang::Language;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::mangle::{decode_ident, encode_ident};
+pub use crate::math::MathProfile;
+pub use crate::mixed::{mixed_script_info, AugmentedScriptSet, MixedScript};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::normalize::{normalize_ident_nfc, normalize_ident_nfkc};
+pub use crate::profile::IdentProfile;
+pub use crate::quick_check::{is_nfc_quick_check, is_nfkc_quick_check, QuickCheck};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::sanitize::sanitize_ident;
+pub use crate::script::{script, script_extensions, ScriptSet, ScriptSetIter};
+pub use crate::tables::{Script, UNICODE_VERSION};
+use crate::tables::{
+    ASCII_CONTINUE, ASCII_PATTERN_SYNTAX, ASCII_PATTERN_WHITE_SPACE, ASCII_START, CHUNK,
+    CONST_LEAF, CONST_TRIE_CONTINUE, CONST_TRIE_START, LEAF, LEAF_ID, LEAF_PATTERN, TRIE_CONTINUE,
+    TRIE_DEFAULT_IGNORABLE, TRIE_ID_COMPAT_MATH_CONTINUE_EXTRA, TRIE_ID_COMPAT_MATH_START,
+    TRIE_ID_CONTINUE_EXTRA, TRIE_ID_START_EXTRA, TRIE_PATTERN_SYNTAX, TRIE_PATTERN_WHITE_SPACE,
+    TRIE_START,
+};
 
 static ZERO: u8 = 0;
 
@@ -262,9 +326,7 @@ pub fn is_xid_start(ch: char) -> bool {
     if ch.is_ascii() {
         return ASCII_START & (1 << ch as u128) != 0;
     }
-    let chunk = *TRIE_START.0.get(ch as usize / 8 / CHUNK).unwrap_or(&ZERO);
-    let offset = chunk as usize * CHUNK / 2 + ch as usize / 8 % CHUNK;
-    unsafe { LEAF.0.get_unchecked(offset) }.wrapping_shr(ch as u32 % 8) & 1 != 0
+    lookup(ch, &TRIE_START.0, &LEAF.0)
 }
 
 /// Whether the character has the Unicode property XID\_Continue.
@@ -272,10 +334,219 @@ pub fn is_xid_continue(ch: char) -> bool {
     if ch.is_ascii() {
         return ASCII_CONTINUE & (1 << ch as u128) != 0;
     }
-    let chunk = *TRIE_CONTINUE
-        .0
-        .get(ch as usize / 8 / CHUNK)
-        .unwrap_or(&ZERO);
+    lookup(ch, &TRIE_CONTINUE.0, &LEAF.0)
+}
+
+/// Whether the character has the Unicode property ID\_Start.
+///
+/// This is a superset of XID\_Start. The two differ only in a handful of
+/// characters which XID\_Start excludes so that identifiers remain closed
+/// under NFKC normalization, such as U+037A GREEK YPOGEGRAMMENI.
+pub fn is_id_start(ch: char) -> bool {
+    if is_xid_start(ch) {
+        return true;
+    }
+    lookup(ch, &TRIE_ID_START_EXTRA.0, &LEAF_ID.0)
+}
+
+/// Whether the character has the Unicode property ID\_Continue.
+///
+/// This is a superset of XID\_Continue. The two differ only in a handful of
+/// characters which XID\_Continue excludes so that identifiers remain closed
+/// under NFKC normalization, such as U+037A GREEK YPOGEGRAMMENI.
+pub fn is_id_continue(ch: char) -> bool {
+    if is_xid_continue(ch) {
+        return true;
+    }
+    lookup(ch, &TRIE_ID_CONTINUE_EXTRA.0, &LEAF_ID.0)
+}
+
+/// Whether the character has the Unicode property Pattern\_Syntax.
+///
+/// UAX #31 recommends this set for the syntax characters of a language, such
+/// as operators and punctuation (requirement R3). It is guaranteed to never
+/// change in future versions of Unicode, and is disjoint from ID\_Continue and
+/// Pattern\_White\_Space.
+pub fn is_pattern_syntax(ch: char) -> bool {
+    if ch.is_ascii() {
+        return ASCII_PATTERN_SYNTAX & (1 << ch as u128) != 0;
+    }
+    lookup(ch, &TRIE_PATTERN_SYNTAX.0, &LEAF_PATTERN.0)
+}
+
+/// Whether the character has the Unicode property Pattern\_White\_Space.
+///
+/// UAX #31 recommends this set for the whitespace of a language (requirement
+/// R3). Like Pattern\_Syntax, it is guaranteed to never change in future
+/// versions of Unicode.
+pub fn is_pattern_white_space(ch: char) -> bool {
+    if ch.is_ascii() {
+        return ASCII_PATTERN_WHITE_SPACE & (1 << ch as u128) != 0;
+    }
+    lookup(ch, &TRIE_PATTERN_WHITE_SPACE.0, &LEAF_PATTERN.0)
+}
+
+/// Whether the character has the Unicode property ID\_Compat\_Math\_Start.
+///
+/// These are the mathematical symbols U+2202 PARTIAL DIFFERENTIAL, U+2207
+/// NABLA, U+221E INFINITY, and their bold and italic variants, which UAX #31
+/// allows to begin an identifier in its mathematical compatibility notation
+/// profile. See [`MathProfile`].
+pub fn is_id_compat_math_start(ch: char) -> bool {
+    lookup(ch, &TRIE_ID_COMPAT_MATH_START.0, &LEAF_PATTERN.0)
+}
+
+/// Whether the character has the Unicode property ID\_Compat\_Math\_Continue.
+///
+/// This is a superset of ID\_Compat\_Math\_Start which additionally contains
+/// superscript and subscript digits and operators, such as U+2081 SUBSCRIPT
+/// ONE.
+pub fn is_id_compat_math_continue(ch: char) -> bool {
+    if is_id_compat_math_start(ch) {
+        return true;
+    }
+    lookup(ch, &TRIE_ID_COMPAT_MATH_CONTINUE_EXTRA.0, &LEAF_PATTERN.0)
+}
+
+/// Whether the character has the Unicode property
+/// Default\_Ignorable\_Code\_Point.
+///
+/// These are characters that are invisible when not supported by a renderer,
+/// such as U+00AD SOFT HYPHEN, U+200B ZERO WIDTH SPACE, variation selectors,
+/// and the tag characters. A few of them are XID\_Continue, so languages that
+/// want every identifier to look distinct may exclude them. See
+/// [`IdentProfile::exclude_default_ignorable`].
+pub fn is_default_ignorable(ch: char) -> bool {
+    if ('\u{E0000}'..='\u{E0FFF}').contains(&ch) {
+        return true;
+    }
+    lookup(ch, &TRIE_DEFAULT_IGNORABLE.0, &LEAF_PATTERN.0)
+}
+
+/// Whether the character has the Unicode property XID\_Start, in const context.
+///
+/// This is the same as [`is_xid_start`], but callable in constants and
+/// statics:
+///
+/// ```
+/// const _: () = assert!(unicode_ident::is_xid_start_const('\u{3c0}'));
+/// ```
+///
+/// Reading a `static` is not allowed in const fn on this crate's minimum
+/// supported compiler, so this function works from a `const` copy of the
+/// lookup tables. Calling it at runtime is correct but may embed an additional
+/// copy of the tables into the binary. Prefer `is_xid_start` outside of const
+/// context.
+pub const fn is_xid_start_const(ch: char) -> bool {
+    lookup_const(ch, ASCII_START, &CONST_TRIE_START.0)
+}
+
+/// Whether the character has the Unicode property XID\_Continue, in const
+/// context.
+///
+/// This is the same as [`is_xid_continue`], but callable in constants and
+/// statics:
+///
+/// ```
+/// const _: () = assert!(unicode_ident::is_xid_continue_const('\u{301}'));
+/// ```
+///
+/// Reading a `static` is not allowed in const fn on this crate's minimum
+/// supported compiler, so this function works from a `const` copy of the
+/// lookup tables. Calling it at runtime is correct but may embed an additional
+/// copy of the tables into the binary. Prefer `is_xid_continue` outside of
+/// const context.
+pub const fn is_xid_continue_const(ch: char) -> bool {
+    lookup_const(ch, ASCII_CONTINUE, &CONST_TRIE_CONTINUE.0)
+}
+
+const fn lookup_const(ch: char, ascii: u128, trie: &[u8]) -> bool {
+    if ch.is_ascii() {
+        return ascii & (1 << ch as u128) != 0;
+    }
+    let index = ch as usize / 8 / CHUNK;
+    let chunk = if index < trie.len() { trie[index] } else { 0 };
     let offset = chunk as usize * CHUNK / 2 + ch as usize / 8 % CHUNK;
-    unsafe { LEAF.0.get_unchecked(offset) }.wrapping_shr(ch as u32 % 8) & 1 != 0
+    let leaf: &[u8] = &CONST_LEAF.0;
+    leaf[offset] >> (ch as u32 % 8) & 1 != 0
+}
+
+/// Whether the first character of a UTF-8 encoded byte string has the Unicode
+/// property XID\_Start, along with the length in bytes of that character.
+///
+/// Returns `None` if `utf8` is empty or does not begin with a well-formed UTF-8
+/// sequence.
+///
+/// This is equivalent to decoding the first `char` and passing it to
+/// [`is_xid_start`], but the trie is traversed directly from the encoded bytes
+/// without first assembling them into a codepoint.
+pub fn is_xid_start_utf8(utf8: &[u8]) -> Option<(bool, usize)> {
+    lookup_utf8(utf8, ASCII_START, &TRIE_START.0)
+}
+
+/// Whether the first character of a UTF-8 encoded byte string has the Unicode
+/// property XID\_Continue, along with the length in bytes of that character.
+///
+/// Returns `None` if `utf8` is empty or does not begin with a well-formed UTF-8
+/// sequence.
+///
+/// This is equivalent to decoding the first `char` and passing it to
+/// [`is_xid_continue`], but the trie is traversed directly from the encoded
+/// bytes without first assembling them into a codepoint.
+pub fn is_xid_continue_utf8(utf8: &[u8]) -> Option<(bool, usize)> {
+    lookup_utf8(utf8, ASCII_CONTINUE, &TRIE_CONTINUE.0)
+}
+
+fn lookup_utf8(utf8: &[u8], ascii: u128, trie: &[u8]) -> Option<(bool, usize)> {
+    // The low 9 bits of the codepoint, which select a bit within a 512-bit
+    // chunk, are always the low 3 bits of the second-to-last byte followed by
+    // the 6 payload bits of the last byte. Everything above that is the index
+    // into the first level of the trie.
+    const _: () = assert!(CHUNK * 8 == 1 << 9);
+
+    let (index, penultimate, last, len) = match *utf8 {
+        [b0 @ 0x00..=0x7F, ..] => return Some((ascii & (1 << b0) != 0, 1)),
+        [b0 @ 0xC2..=0xDF, b1 @ 0x80..=0xBF, ..] => ((b0 as usize & 0x1F) >> 3, b0, b1, 2),
+        [b0 @ 0xE0, b1 @ 0xA0..=0xBF, b2 @ 0x80..=0xBF, ..]
+        | [b0 @ (0xE1..=0xEC | 0xEE..=0xEF), b1 @ 0x80..=0xBF, b2 @ 0x80..=0xBF, ..]
+        | [b0 @ 0xED, b1 @ 0x80..=0x9F, b2 @ 0x80..=0xBF, ..] => {
+            let index = (b0 as usize & 0x0F) << 3 | (b1 as usize & 0x3F) >> 3;
+            (index, b1, b2, 3)
+        }
+        [b0 @ 0xF0, b1 @ 0x90..=0xBF, b2 @ 0x80..=0xBF, b3 @ 0x80..=0xBF, ..]
+        | [b0 @ 0xF1..=0xF3, b1 @ 0x80..=0xBF, b2 @ 0x80..=0xBF, b3 @ 0x80..=0xBF, ..]
+        | [b0 @ 0xF4, b1 @ 0x80..=0x8F, b2 @ 0x80..=0xBF, b3 @ 0x80..=0xBF, ..] => {
+            let index =
+                (b0 as usize & 0x07) << 9 | (b1 as usize & 0x3F) << 3 | (b2 as usize & 0x3F) >> 3;
+            (index, b2, b3, 4)
+        }
+        _ => return None,
+    };
+
+    let bit = (penultimate as usize & 0x07) << 6 | last as usize & 0x3F;
+    Some((lookup_chunk(trie, &LEAF.0, index, bit), len))
+}
+
+#[inline]
+fn lookup(ch: char, trie: &[u8], leaf: &[u8]) -> bool {
+    lookup_chunk(
+        trie,
+        leaf,
+        ch as usize / 8 / CHUNK,
+        ch as usize % (CHUNK * 8),
+    )
+}
+
+// Bit `bit` of the chunk that entry `index` of the trie points to. Out of range
+// indices are all-zero chunks, because the generator trims trailing zeros off
+// of each trie.
+#[inline]
+fn lookup_chunk(trie: &[u8], leaf: &[u8], index: usize, bit: usize) -> bool {
+    let chunk = *trie.get(index).unwrap_or(&ZERO);
+    let offset = chunk as usize * CHUNK / 2 + bit / 8;
+    // SAFETY: every trie emitted by the generator holds half-chunk offsets at
+    // which a whole chunk fits within the leaf that goes with that trie, and
+    // bit / 8 is less than CHUNK.
+    let byte = unsafe { *leaf.get_unchecked(offset) };
+    byte >> (bit % 8) & 1 != 0
 }
@@ -0,0 +1,214 @@
+use crate::{is_xid_continue, is_xid_start};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const PREFIX: &str = "u_";
+
+// Punycode parameters, from RFC 3492 section 5.
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+// ASCII characters that are not XID_Continue are not basic code points, but
+// Punycode can only encode non-basic code points at or above INITIAL_N. They
+// are encoded as if they were these values past the end of Unicode instead.
+const ASCII_OFFSET: u32 = 0x11_0000;
+
+/// Encode any string as an ASCII identifier, reversibly.
+///
+/// ASCII identifiers, other than those beginning with `u_`, are returned
+/// unchanged. Anything else is encoded as `u_` followed by its
+/// [Punycode][rfc3492] encoding, with `_` in place of Punycode's `-`
+/// delimiter. The ASCII characters that are XID\_Continue are kept as is, in
+/// order, before the delimiter.
+///
+/// [rfc3492]: https://www.rfc-editor.org/rfc/rfc3492
+///
+/// For identifiers, which contain no ASCII characters other than letters,
+/// digits and `_`, the part after `u_` is the same as in [Rust's v0 symbol
+/// mangling][v0]. Other ASCII characters are encoded as Punycode deltas
+/// beyond U+10FFFF so that any string can be represented.
+///
+/// [v0]: https://doc.rust-lang.org/rustc/symbol-mangling/v0.html#identifier
+///
+/// ```
+/// use unicode_ident::{decode_ident, encode_ident};
+///
+/// assert_eq!(encode_ident("snake_case"), "snake_case");
+/// assert_eq!(encode_ident("g\u{f6}del"), "u_gdel_5qa"); // gödel
+/// assert_eq!(encode_ident("kebab-case"), "u_kebabcase_fe802g");
+///
+/// let ident = encode_ident("\u{1f980} crab");
+/// assert!(unicode_ident::is_ident(&ident));
+/// assert_eq!(decode_ident(&ident).unwrap(), "\u{1f980} crab");
+/// ```
+#[allow(clippy::many_single_char_names)] // names from RFC 3492
+pub fn encode_ident(string: &str) -> Cow<'_, str> {
+    if is_unescaped(string) {
+        return Cow::Borrowed(string);
+    }
+
+    let input: Vec<u32> = string.chars().map(code_point).collect();
+    let mut output = String::with_capacity(PREFIX.len() + string.len() + 1);
+    output.push_str(PREFIX);
+    output.extend(string.chars().filter(|&ch| is_basic(ch)));
+    let basic = output.len() - PREFIX.len();
+    if basic > 0 {
+        output.push('_');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta = 0u64;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic;
+    while let Some(m) = input.iter().copied().filter(|&c| c >= n).min() {
+        delta += u64::from(m - n) * (handled as u64 + 1);
+        n = m;
+        for &c in &input {
+            if c < n {
+                delta += 1;
+            } else if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = u64::from(threshold(k, bias));
+                    if q < t {
+                        break;
+                    }
+                    let base = u64::from(BASE);
+                    output.push(digit(t + (q - t) % (base - t)));
+                    q = (q - t) / (base - t);
+                    k += BASE;
+                }
+                output.push(digit(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Cow::Owned(output)
+}
+
+/// Decode an identifier produced by [`encode_ident`].
+///
+/// Returns `None` if the identifier is not one that `encode_ident` would
+/// produce.
+#[allow(clippy::many_single_char_names)] // names from RFC 3492
+pub fn decode_ident(ident: &str) -> Option<Cow<'_, str>> {
+    let Some(encoded) = ident.strip_prefix(PREFIX) else {
+        return is_unescaped(ident).then_some(Cow::Borrowed(ident));
+    };
+
+    let (basic, deltas) = match encoded.rfind('_') {
+        Some(delimiter) => (&encoded[..delimiter], &encoded[delimiter + 1..]),
+        None => ("", encoded),
+    };
+    if !basic.chars().all(is_basic) {
+        return None;
+    }
+    let mut output: Vec<u32> = basic.chars().map(u32::from).collect();
+
+    let mut n = INITIAL_N;
+    let mut i = 0u32;
+    let mut bias = INITIAL_BIAS;
+    let mut bytes = deltas.bytes();
+    while bytes.len() > 0 {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let d = decode_digit(bytes.next()?)?;
+            i = i.checked_add(d.checked_mul(w)?)?;
+            let t = threshold(k, bias);
+            if d < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+        let len = u32::try_from(output.len() + 1).ok()?;
+        bias = adapt(u64::from(i - old_i), output.len() + 1, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+        output.insert(i as usize, n);
+        i += 1;
+    }
+
+    let decoded = output
+        .into_iter()
+        .map(|c| match c.checked_sub(ASCII_OFFSET) {
+            Some(ascii) => char::from_u32(ascii).filter(|&ch| ch.is_ascii() && !is_basic(ch)),
+            None => char::from_u32(c),
+        })
+        .collect::<Option<String>>()?;
+
+    // Reject spellings that encode_ident would not produce, such as
+    // uppercase Punycode digits, so that every string has exactly one
+    // encoding.
+    if encode_ident(&decoded) != ident {
+        return None;
+    }
+    Some(Cow::Owned(decoded))
+}
+
+// Whether the string is passed through encode_ident unchanged.
+fn is_unescaped(string: &str) -> bool {
+    let mut chars = string.chars();
+    chars
+        .next()
+        .is_some_and(|ch| ch.is_ascii() && is_xid_start(ch))
+        && chars.all(is_basic)
+        && !string.starts_with(PREFIX)
+}
+
+fn is_basic(ch: char) -> bool {
+    ch.is_ascii() && is_xid_continue(ch)
+}
+
+fn code_point(ch: char) -> u32 {
+    if ch.is_ascii() && !is_basic(ch) {
+        ASCII_OFFSET + ch as u32
+    } else {
+        ch as u32
+    }
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    k.saturating_sub(bias).clamp(TMIN, TMAX)
+}
+
+fn adapt(delta: u64, len: usize, first: bool) -> u32 {
+    let mut delta = if first {
+        delta / u64::from(DAMP)
+    } else {
+        delta / 2
+    };
+    delta += delta / len as u64;
+    let mut k = 0;
+    while delta > u64::from((BASE - TMIN) * TMAX / 2) {
+        delta /= u64::from(BASE - TMIN);
+        k += BASE;
+    }
+    k + u32::try_from(u64::from(BASE) * delta / (delta + u64::from(SKEW))).unwrap()
+}
+
+#[allow(clippy::cast_possible_truncation)] // d < BASE
+fn digit(d: u64) -> char {
+    char::from(b"abcdefghijklmnopqrstuvwxyz0123456789"[d as usize])
+}
+
+fn decode_digit(byte: u8) -> Option<u32> {
+    match byte {
+        b'a'..=b'z' => Some(u32::from(byte - b'a')),
+        b'0'..=b'9' => Some(u32::from(byte - b'0') + 26),
+        _ => None,
+    }
+}
@@ -0,0 +1,51 @@
+use crate::ident::IdentError;
+use crate::profile::IdentProfile;
+
+const PROFILE: IdentProfile = IdentProfile::new().with_math();
+
+/// The UAX #31 mathematical compatibility notation profile, which extends the
+/// Default Identifier Syntax with a few mathematical symbols and super- and
+/// subscripts.
+///
+/// Identifiers in this profile begin with an XID\_Start or
+/// ID\_Compat\_Math\_Start character, followed by any number of XID\_Continue
+/// or ID\_Compat\_Math\_Continue characters.
+///
+/// ```
+/// use unicode_ident::MathProfile;
+///
+/// let profile = MathProfile::new();
+/// assert!(profile.is_valid("\u{2202}x")); // ∂x
+/// assert!(profile.is_valid("\u{2207}\u{3c6}")); // ∇φ
+/// assert!(profile.is_valid("x\u{2081}")); // x₁
+/// assert!(profile.is_valid("r\u{b2}")); // r²
+/// assert!(!profile.is_valid("\u{2081}x"));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MathProfile {
+    _private: (),
+}
+
+impl MathProfile {
+    /// The mathematical compatibility notation profile.
+    pub const fn new() -> Self {
+        MathProfile { _private: () }
+    }
+
+    /// Whether the string is an identifier in this profile.
+    pub fn is_valid(&self, string: &str) -> bool {
+        PROFILE.is_valid(string)
+    }
+
+    /// Check the string against this profile, reporting the first character
+    /// at which it fails.
+    pub fn validate(&self, string: &str) -> Result<(), IdentError> {
+        PROFILE.validate(string)
+    }
+
+    /// Length in bytes of the longest prefix of the string that is an
+    /// identifier in this profile.
+    pub fn scan(&self, string: &str) -> usize {
+        PROFILE.scan(string)
+    }
+}
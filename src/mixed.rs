@@ -0,0 +1,173 @@
+use crate::script::{script_extensions, ScriptSet};
+use crate::tables::Script;
+use core::fmt::{self, Debug};
+
+/// A set of scripts in the sense of [UTS #39 section 5.1][uts39], in which
+/// characters that are used in more than one writing system count toward
+/// each of them.
+///
+/// [uts39]: https://www.unicode.org/reports/tr39/#Mixed_Script_Detection
+///
+/// In addition to Unicode scripts, the set may contain the writing systems
+/// Hanb (Han with Bopomofo), Jpan (Han with Hiragana and Katakana) and Kore
+/// (Han with Hangul), so that for example a Japanese identifier mixing kanji
+/// and kana is considered single-script.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AugmentedScriptSet {
+    scripts: ScriptSet,
+    hanb: bool,
+    jpan: bool,
+    kore: bool,
+}
+
+impl AugmentedScriptSet {
+    /// The set of all scripts. This is the augmented script set of characters
+    /// whose Script\_Extensions are Common or Inherited.
+    pub const ALL: Self = AugmentedScriptSet {
+        scripts: ScriptSet::ALL,
+        hanb: true,
+        jpan: true,
+        kore: true,
+    };
+
+    /// The augmented script set of a character, which is its Script\_Extensions
+    /// plus Hanb, Jpan, or Kore as applicable.
+    pub fn for_char(ch: char) -> Self {
+        let scripts = script_extensions(ch);
+        if scripts.contains(Script::Common) || scripts.contains(Script::Inherited) {
+            return AugmentedScriptSet::ALL;
+        }
+        let han = scripts.contains(Script::Han);
+        AugmentedScriptSet {
+            scripts,
+            hanb: han || scripts.contains(Script::Bopomofo),
+            jpan: han || scripts.contains(Script::Hiragana) || scripts.contains(Script::Katakana),
+            kore: han || scripts.contains(Script::Hangul),
+        }
+    }
+
+    /// Whether this is the set of all scripts.
+    pub fn is_all(&self) -> bool {
+        *self == AugmentedScriptSet::ALL
+    }
+
+    /// Whether no script or writing system is in the set. An identifier whose
+    /// characters resolve to the empty set is mixed-script.
+    pub fn is_empty(&self) -> bool {
+        self.scripts.is_empty() && !self.hanb && !self.jpan && !self.kore
+    }
+
+    /// Whether the Unicode script is in the set.
+    pub fn contains(&self, script: Script) -> bool {
+        self.scripts.contains(script)
+    }
+
+    /// Whether Hanb, Han with Bopomofo, is in the set.
+    pub fn contains_hanb(&self) -> bool {
+        self.hanb
+    }
+
+    /// Whether Jpan, Han with Hiragana and Katakana, is in the set.
+    pub fn contains_jpan(&self) -> bool {
+        self.jpan
+    }
+
+    /// Whether Kore, Han with Hangul, is in the set.
+    pub fn contains_kore(&self) -> bool {
+        self.kore
+    }
+
+    /// The scripts contained in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &AugmentedScriptSet) -> AugmentedScriptSet {
+        AugmentedScriptSet {
+            scripts: self.scripts.intersection(&other.scripts),
+            hanb: self.hanb && other.hanb,
+            jpan: self.jpan && other.jpan,
+            kore: self.kore && other.kore,
+        }
+    }
+}
+
+impl Debug for AugmentedScriptSet {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_all() {
+            return formatter.write_str("ALL");
+        }
+        let mut set = formatter.debug_set();
+        set.entries(self.scripts);
+        if self.hanb {
+            set.entry(&format_args!("Hanb"));
+        }
+        if self.jpan {
+            set.entry(&format_args!("Jpan"));
+        }
+        if self.kore {
+            set.entry(&format_args!("Kore"));
+        }
+        set.finish()
+    }
+}
+
+/// Result of [`mixed_script_info`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MixedScript {
+    resolved: AugmentedScriptSet,
+    scripts: ScriptSet,
+}
+
+impl MixedScript {
+    /// Whether there is a script, or one of the writing systems Hanb, Jpan, or
+    /// Kore, that covers every character of the identifier.
+    pub fn is_single_script(&self) -> bool {
+        !self.resolved.is_empty()
+    }
+
+    /// Whether the identifier mixes scripts, such as Latin and Cyrillic.
+    pub fn is_mixed_script(&self) -> bool {
+        self.resolved.is_empty()
+    }
+
+    /// The resolved script set: the intersection of the augmented script sets
+    /// of every character of the identifier.
+    pub fn resolved(&self) -> AugmentedScriptSet {
+        self.resolved
+    }
+
+    /// Every script that some character of the identifier belongs to,
+    /// according to Script\_Extensions, other than Common and Inherited.
+    ///
+    /// When the identifier is mixed-script, these are the scripts to name in a
+    /// diagnostic.
+    pub fn scripts(&self) -> ScriptSet {
+        self.scripts
+    }
+}
+
+/// Compute the resolved script set of an identifier, according to [UTS #39
+/// section 5.1][uts39].
+///
+/// [uts39]: https://www.unicode.org/reports/tr39/#Mixed_Script_Detection
+///
+/// ```
+/// use unicode_ident::{mixed_script_info, Script};
+///
+/// let info = mixed_script_info("p\u{430}ypal"); // CYRILLIC SMALL LETTER A
+/// assert!(info.is_mixed_script());
+/// assert!(info.scripts().contains(Script::Latin));
+/// assert!(info.scripts().contains(Script::Cyrillic));
+///
+/// assert!(mixed_script_info("東京タワー").is_single_script());
+/// ```
+pub fn mixed_script_info(ident: &str) -> MixedScript {
+    let mut resolved = AugmentedScriptSet::ALL;
+    let mut scripts = ScriptSet::new();
+    for ch in ident.chars() {
+        let augmented = AugmentedScriptSet::for_char(ch);
+        if !augmented.is_all() {
+            scripts = scripts.union(&augmented.scripts);
+        }
+        resolved = resolved.intersection(&augmented);
+    }
+    MixedScript { resolved, scripts }
+}
@@ -0,0 +1,264 @@
+use crate::quick_check::{combining_class, is_nfc_quick_check, is_nfkc_quick_check, QuickCheck};
+use crate::tables::{
+    CANONICAL_DECOMPOSITION_ENDS, CANONICAL_DECOMPOSITION_KEYS, CANONICAL_DECOMPOSITION_STRINGS,
+    COMPATIBILITY_DECOMPOSITION_ENDS, COMPATIBILITY_DECOMPOSITION_KEYS,
+    COMPATIBILITY_DECOMPOSITION_STRINGS, COMPOSITIONS,
+};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Hangul syllables are composed and decomposed algorithmically, as described
+// in section 3.12 of the Unicode Standard.
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Normalize a string, such as an identifier, to Normalization Form C.
+///
+/// NFC composes characters wherever possible, and gives a single
+/// representation to strings that are canonically equivalent, such as `é` as
+/// one character or as `e` followed by a combining acute accent. Rust lints
+/// against identifiers that are not in NFC, and treats identifiers as the same
+/// only if their NFC forms are.
+///
+/// Returns the string unchanged, without allocating, if it is already in NFC.
+///
+/// ```
+/// use std::borrow::Cow;
+/// use unicode_ident::normalize_ident_nfc;
+///
+/// assert_eq!(normalize_ident_nfc("cafe\u{301}"), "caf\u{e9}");
+/// assert!(matches!(normalize_ident_nfc("caf\u{e9}"), Cow::Borrowed(_)));
+/// ```
+pub fn normalize_ident_nfc(string: &str) -> Cow<'_, str> {
+    normalize(string, is_nfc_quick_check, |_ch| None)
+}
+
+/// Normalize a string, such as an identifier, to Normalization Form KC.
+///
+/// NFKC additionally replaces compatibility characters, such as ligatures,
+/// fullwidth forms and mathematical alphanumerics, by their ordinary
+/// equivalents. Python normalizes identifiers to NFKC, so two Python
+/// identifiers are the same name exactly when their NFKC forms are equal.
+///
+/// XID\_Start and XID\_Continue are closed under NFKC, so the normalization
+/// of an identifier is again an identifier.
+///
+/// Returns the string unchanged, without allocating, if it is already in
+/// NFKC.
+///
+/// ```
+/// use unicode_ident::normalize_ident_nfkc;
+///
+/// assert_eq!(normalize_ident_nfkc("\u{fb01}le"), "file");
+/// assert_eq!(normalize_ident_nfkc("\u{1d431}"), "x"); // mathematical bold x
+/// ```
+pub fn normalize_ident_nfkc(string: &str) -> Cow<'_, str> {
+    normalize(string, is_nfkc_quick_check, |ch| {
+        lookup_mapping(
+            &COMPATIBILITY_DECOMPOSITION_KEYS,
+            &COMPATIBILITY_DECOMPOSITION_ENDS,
+            COMPATIBILITY_DECOMPOSITION_STRINGS,
+            ch,
+        )
+    })
+}
+
+fn normalize(
+    string: &str,
+    quick_check: fn(&str) -> QuickCheck,
+    map: fn(char) -> Option<&'static str>,
+) -> Cow<'_, str> {
+    if quick_check(string) == QuickCheck::Yes {
+        return Cow::Borrowed(string);
+    }
+    let normalized: String = Normalize::new(string.chars(), map).collect();
+    if normalized == string {
+        Cow::Borrowed(string)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+// Look up a character in one of the tables of strings emitted by the
+// generator, which are split into keys, end offsets, and the concatenation of
+// the strings.
+pub(crate) fn lookup_mapping(
+    keys: &[char],
+    ends: &[u16],
+    strings: &'static str,
+    ch: char,
+) -> Option<&'static str> {
+    let i = keys.binary_search(&ch).ok()?;
+    let start = if i == 0 { 0 } else { ends[i - 1] as usize };
+    Some(&strings[start..ends[i] as usize])
+}
+
+fn compose(first: char, second: char) -> Option<char> {
+    let (first, second) = (first as u32, second as u32);
+    let l_index = first.wrapping_sub(L_BASE);
+    let v_index = second.wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        return char::from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+    }
+    let s_index = first.wrapping_sub(S_BASE);
+    let t_index = second.wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && 0 < t_index && t_index < T_COUNT {
+        return char::from_u32(first + t_index);
+    }
+    let (first, second) = (char::from_u32(first)?, char::from_u32(second)?);
+    let i = COMPOSITIONS
+        .binary_search_by_key(&(first, second), |&(a, b, _)| (a, b))
+        .ok()?;
+    Some(COMPOSITIONS[i].2)
+}
+
+/// Iterator that applies a mapping to every character of its input and then
+/// normalizes the result to NFC.
+///
+/// The mapping returns `None` for characters that map to themselves. Its
+/// results need not be normalized.
+pub(crate) struct Normalize<I, F> {
+    input: I,
+    map: F,
+    // Decomposed characters from the last starter onward, which characters
+    // still to come may reorder or compose with.
+    segment: Vec<char>,
+    // Normalized characters not yet returned.
+    output: Vec<char>,
+    pos: usize,
+}
+
+impl<I, F> Normalize<I, F> {
+    pub(crate) fn new(input: I, map: F) -> Self {
+        Normalize {
+            input,
+            map,
+            segment: Vec::new(),
+            output: Vec::new(),
+            pos: 0,
+        }
+    }
+}
+
+impl<I, F> Normalize<I, F>
+where
+    F: FnMut(char) -> Option<&'static str>,
+{
+    fn decompose(&mut self, ch: char) {
+        let s_index = (ch as u32).wrapping_sub(S_BASE);
+        if s_index < S_COUNT {
+            self.push(hangul(L_BASE + s_index / N_COUNT));
+            self.push(hangul(V_BASE + s_index % N_COUNT / T_COUNT));
+            if s_index % T_COUNT != 0 {
+                self.push(hangul(T_BASE + s_index % T_COUNT));
+            }
+        } else if let Some(decomposition) = lookup_mapping(
+            &CANONICAL_DECOMPOSITION_KEYS,
+            &CANONICAL_DECOMPOSITION_ENDS,
+            CANONICAL_DECOMPOSITION_STRINGS,
+            ch,
+        ) {
+            for ch in decomposition.chars() {
+                self.push(ch);
+            }
+        } else {
+            self.push(ch);
+        }
+    }
+
+    fn push(&mut self, ch: char) {
+        if combining_class(ch) == 0 && !self.segment.is_empty() {
+            compose_segment(&mut self.segment);
+            // A starter composes with the preceding one only if nothing is
+            // left between them.
+            if let [starter] = self.segment[..] {
+                if combining_class(starter) == 0 {
+                    if let Some(composite) = compose(starter, ch) {
+                        self.segment[0] = composite;
+                        return;
+                    }
+                }
+            }
+            self.output.append(&mut self.segment);
+        }
+        self.segment.push(ch);
+    }
+}
+
+impl<I, F> Iterator for Normalize<I, F>
+where
+    I: Iterator<Item = char>,
+    F: FnMut(char) -> Option<&'static str>,
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos == self.output.len() {
+            self.output.clear();
+            self.pos = 0;
+            let Some(ch) = self.input.next() else {
+                compose_segment(&mut self.segment);
+                self.output.append(&mut self.segment);
+                if self.output.is_empty() {
+                    return None;
+                }
+                break;
+            };
+            match (self.map)(ch) {
+                Some(mapped) => {
+                    for ch in mapped.chars() {
+                        self.decompose(ch);
+                    }
+                }
+                None => self.decompose(ch),
+            }
+        }
+        let ch = self.output[self.pos];
+        self.pos += 1;
+        Some(ch)
+    }
+}
+
+// Put the combining marks of a segment in canonical order, then compose each
+// one that is not blocked into the starter at the front of the segment.
+fn compose_segment(segment: &mut Vec<char>) {
+    let Some(&first) = segment.first() else {
+        return;
+    };
+    if combining_class(first) != 0 {
+        segment.sort_by_key(|&ch| combining_class(ch));
+        return;
+    }
+    segment[1..].sort_by_key(|&ch| combining_class(ch));
+
+    let mut starter = first;
+    let mut last_class = 0;
+    let mut len = 1;
+    for i in 1..segment.len() {
+        let ch = segment[i];
+        let class = combining_class(ch);
+        if last_class < class {
+            if let Some(composite) = compose(starter, ch) {
+                starter = composite;
+                continue;
+            }
+        }
+        last_class = class;
+        segment[len] = ch;
+        len += 1;
+    }
+    segment[0] = starter;
+    segment.truncate(len);
+}
+
+fn hangul(code: u32) -> char {
+    unsafe { char::from_u32_unchecked(code) }
+}
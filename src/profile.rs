@@ -0,0 +1,259 @@
+use crate::emoji::scan_emoji;
+use crate::ident::{validate_with, IdentError};
+use crate::join::{is_join_control_allowed, ZWJ, ZWNJ};
+use crate::{
+    is_default_ignorable, is_id_compat_math_continue, is_id_compat_math_start, is_id_continue,
+    is_id_start, is_xid_continue, is_xid_start,
+};
+
+const ID_PROPERTIES: u8 = 1 << 0;
+const EXCLUDE_DEFAULT_IGNORABLE: u8 = 1 << 1;
+const MATH: u8 = 1 << 2;
+const EMOJI: u8 = 1 << 3;
+const RESTRICT_JOIN_CONTROLS: u8 = 1 << 4;
+
+/// A UAX #31 profile of the Default Identifier Syntax, built from XID\_Start
+/// and XID\_Continue plus explicit additions and removals (requirement R1).
+///
+/// Every method is `const`, so a language's profile can be declared once as
+/// a constant:
+///
+/// ```
+/// use unicode_ident::IdentProfile;
+///
+/// // XID_Start ∪ {'_', '$'} followed by XID_Continue ∪ {'$', '-'}
+/// const PROFILE: IdentProfile = IdentProfile::new()
+///     .allow_start(&['_', '$'])
+///     .allow_continue(&['-']);
+///
+/// assert!(PROFILE.is_valid("$scope"));
+/// assert!(PROFILE.is_valid("kebab-case"));
+/// assert!(!PROFILE.is_valid("-flag"));
+/// ```
+///
+/// Characters given to [`disallow`][IdentProfile::disallow] are rejected
+/// everywhere, and take precedence over every other option. Otherwise
+/// characters given to [`allow_start`][IdentProfile::allow_start] and
+/// [`allow_continue`][IdentProfile::allow_continue] are accepted regardless of
+/// their Unicode properties.
+#[derive(Copy, Clone, Debug)]
+pub struct IdentProfile {
+    start_chars: &'static [char],
+    continue_chars: &'static [char],
+    disallowed_chars: &'static [char],
+    keywords: &'static [&'static str],
+    options: u8,
+}
+
+impl IdentProfile {
+    /// The Default Identifier Syntax: one XID\_Start character followed by
+    /// any number of XID\_Continue characters, the same as [`is_ident`].
+    ///
+    /// [`is_ident`]: crate::is_ident
+    pub const fn new() -> Self {
+        IdentProfile {
+            start_chars: &[],
+            continue_chars: &[],
+            disallowed_chars: &[],
+            keywords: &[],
+            options: 0,
+        }
+    }
+
+    /// Additionally allow these characters at the start of an identifier, and
+    /// anywhere after it.
+    ///
+    /// Replaces the characters given by any previous call.
+    #[must_use]
+    pub const fn allow_start(mut self, chars: &'static [char]) -> Self {
+        self.start_chars = chars;
+        self
+    }
+
+    /// Additionally allow these characters after the first character of an
+    /// identifier.
+    ///
+    /// Replaces the characters given by any previous call.
+    #[must_use]
+    pub const fn allow_continue(mut self, chars: &'static [char]) -> Self {
+        self.continue_chars = chars;
+        self
+    }
+
+    /// Reject these characters anywhere in an identifier, even if they are
+    /// XID\_Start or XID\_Continue.
+    ///
+    /// Replaces the characters given by any previous call.
+    #[must_use]
+    pub const fn disallow(mut self, chars: &'static [char]) -> Self {
+        self.disallowed_chars = chars;
+        self
+    }
+
+    /// Words that [`sanitize_ident`] should not produce, such as the keywords
+    /// of a language.
+    ///
+    /// These do not affect which strings this profile considers identifiers,
+    /// since a lexer scans keywords the same way as identifiers.
+    ///
+    /// Replaces the words given by any previous call.
+    ///
+    /// [`sanitize_ident`]: crate::sanitize_ident
+    #[must_use]
+    pub const fn keywords(mut self, words: &'static [&'static str]) -> Self {
+        self.keywords = words;
+        self
+    }
+
+    /// Use ID\_Start and ID\_Continue instead of XID\_Start and XID\_Continue.
+    ///
+    /// These are what some languages, such as ECMAScript, are specified in
+    /// terms of. Unlike the XID properties they are not closed under NFKC
+    /// normalization. See [`is_id_start`][crate::is_id_start].
+    #[must_use]
+    pub const fn id_properties(mut self) -> Self {
+        self.options |= ID_PROPERTIES;
+        self
+    }
+
+    /// Reject characters that are Default\_Ignorable\_Code\_Point, such as
+    /// U+200D ZERO WIDTH JOINER and variation selectors, which would let two
+    /// different identifiers look the same.
+    ///
+    /// Characters passed to `allow_start` or `allow_continue` are still
+    /// allowed.
+    #[must_use]
+    pub const fn exclude_default_ignorable(mut self) -> Self {
+        self.options |= EXCLUDE_DEFAULT_IGNORABLE;
+        self
+    }
+
+    /// Also allow the characters of the mathematical compatibility notation
+    /// profile, as in [`MathProfile`][crate::MathProfile].
+    #[must_use]
+    pub const fn with_math(mut self) -> Self {
+        self.options |= MATH;
+        self
+    }
+
+    /// Also allow emoji sequences anywhere in an identifier, as in
+    /// [`EmojiProfile`][crate::EmojiProfile].
+    ///
+    /// Within a well-formed emoji sequence, the joiners and variation
+    /// selectors it is made of are allowed even if the profile excludes
+    /// Default\_Ignorable\_Code\_Point.
+    #[must_use]
+    pub const fn with_emoji(mut self) -> Self {
+        self.options |= EMOJI;
+        self
+    }
+
+    /// Allow ZWNJ and ZWJ only in the contexts given by UAX #31 section 2.3,
+    /// as in [`JoinControlProfile`][crate::JoinControlProfile].
+    #[must_use]
+    pub const fn restrict_join_controls(mut self) -> Self {
+        self.options |= RESTRICT_JOIN_CONTROLS;
+        self
+    }
+
+    /// Whether the character may begin an identifier in this profile.
+    pub fn is_start(&self, ch: char) -> bool {
+        if self.disallowed_chars.contains(&ch) {
+            return false;
+        }
+        if self.start_chars.contains(&ch) {
+            return true;
+        }
+        let is_start = if self.options & ID_PROPERTIES == 0 {
+            is_xid_start(ch)
+        } else {
+            is_id_start(ch)
+        };
+        (is_start || (self.options & MATH != 0 && is_id_compat_math_start(ch)))
+            && !(self.options & EXCLUDE_DEFAULT_IGNORABLE != 0 && is_default_ignorable(ch))
+    }
+
+    /// Whether the character may appear after the first character of an
+    /// identifier in this profile.
+    ///
+    /// This does not take into account the context of ZWNJ and ZWJ under
+    /// [`restrict_join_controls`][IdentProfile::restrict_join_controls], or
+    /// emoji sequences.
+    pub fn is_continue(&self, ch: char) -> bool {
+        if self.disallowed_chars.contains(&ch) {
+            return false;
+        }
+        if self.start_chars.contains(&ch) || self.continue_chars.contains(&ch) {
+            return true;
+        }
+        let is_continue = if self.options & ID_PROPERTIES == 0 {
+            is_xid_continue(ch)
+        } else {
+            is_id_continue(ch)
+        };
+        (is_continue || (self.options & MATH != 0 && is_id_compat_math_continue(ch)))
+            && !(self.options & EXCLUDE_DEFAULT_IGNORABLE != 0 && is_default_ignorable(ch))
+    }
+
+    /// Whether the string is an identifier in this profile.
+    pub fn is_valid(&self, string: &str) -> bool {
+        self.validate(string).is_ok()
+    }
+
+    /// Check the string against this profile, reporting the first character
+    /// at which it fails.
+    pub fn validate(&self, string: &str) -> Result<(), IdentError> {
+        validate_with(string, self.scan(string))
+    }
+
+    /// Length in bytes of the longest prefix of the string that is an
+    /// identifier in this profile.
+    pub fn scan(&self, string: &str) -> usize {
+        self.scan_from(string, 0)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn is_keyword(&self, word: &str) -> bool {
+        self.keywords.contains(&word)
+    }
+
+    // Continue scanning a string of which the first `len` bytes are already
+    // known to be a valid identifier prefix.
+    pub(crate) fn scan_from(&self, string: &str, mut len: usize) -> usize {
+        while len < string.len() {
+            let rest = &string[len..];
+            if self.options & EMOJI != 0 {
+                let mut emoji = scan_emoji(rest);
+                // Disallowed characters end the sequence, even inside it.
+                if let Some(end) = rest[..emoji].find(self.disallowed_chars) {
+                    emoji = scan_emoji(&rest[..end]);
+                }
+                if emoji != 0 {
+                    len += emoji;
+                    continue;
+                }
+            }
+            let ch = rest.chars().next().unwrap_or_default();
+            let is_valid = if len == 0 {
+                self.is_start(ch)
+            } else if let ZWNJ | ZWJ = ch {
+                self.is_continue(ch)
+                    && (self.options & RESTRICT_JOIN_CONTROLS == 0
+                        || is_join_control_allowed(string, len, ch))
+            } else {
+                self.is_continue(ch)
+            };
+            if !is_valid {
+                break;
+            }
+            len += ch.len_utf8();
+        }
+        len
+    }
+}
+
+impl Default for IdentProfile {
+    fn default() -> Self {
+        IdentProfile::new()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::script::lookup_run;
+use crate::tables::{
+    COMBINING_CLASS_RUNS, NFC_MAYBE, NFC_NO, NFKC_MAYBE, NFKC_NO, QUICK_CHECK_RUNS,
+};
+
+/// Result of the normalization quick check of [UAX #15][quick-check].
+///
+/// [quick-check]: https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QuickCheck {
+    /// The string is certainly normalized.
+    Yes,
+    /// The string is certainly not normalized.
+    No,
+    /// The quick check could not tell, because the string contains characters
+    /// that are normalized only in some contexts, such as combining marks that
+    /// may compose with a preceding letter.
+    Maybe,
+}
+
+/// Whether the string is in Normalization Form C, according to the quick check
+/// algorithm of [UAX #15][quick-check].
+///
+/// [quick-check]: https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms
+///
+/// The quick check looks at one character at a time, so it is fast but may
+/// answer [`QuickCheck::Maybe`]. [`normalize_ident_nfc`] gives a definite
+/// answer.
+///
+/// [`normalize_ident_nfc`]: crate::normalize_ident_nfc
+///
+/// ```
+/// use unicode_ident::{is_nfc_quick_check, QuickCheck};
+///
+/// assert_eq!(is_nfc_quick_check("caf\u{e9}"), QuickCheck::Yes);
+/// assert_eq!(is_nfc_quick_check("caf\u{e9}\u{301}"), QuickCheck::Maybe);
+/// assert_eq!(is_nfc_quick_check("\u{212b}"), QuickCheck::No); // angstrom sign
+/// ```
+pub fn is_nfc_quick_check(string: &str) -> QuickCheck {
+    quick_check(string, NFC_NO, NFC_MAYBE)
+}
+
+/// Whether the string is in Normalization Form KC, according to the quick
+/// check algorithm of [UAX #15][quick-check].
+///
+/// [quick-check]: https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms
+///
+/// As with [`is_nfc_quick_check`], [`QuickCheck::Maybe`] is possible.
+/// [`normalize_ident_nfkc`] gives a definite answer.
+///
+/// [`normalize_ident_nfkc`]: crate::normalize_ident_nfkc
+///
+/// ```
+/// use unicode_ident::{is_nfc_quick_check, is_nfkc_quick_check, QuickCheck};
+///
+/// // The ligature ﬁ is in NFC, but NFKC replaces it with f and i.
+/// assert_eq!(is_nfc_quick_check("\u{fb01}le"), QuickCheck::Yes);
+/// assert_eq!(is_nfkc_quick_check("\u{fb01}le"), QuickCheck::No);
+/// ```
+pub fn is_nfkc_quick_check(string: &str) -> QuickCheck {
+    quick_check(string, NFKC_NO, NFKC_MAYBE)
+}
+
+pub(crate) fn combining_class(ch: char) -> u8 {
+    lookup_run(&COMBINING_CLASS_RUNS, ch)
+}
+
+fn quick_check(string: &str, no: u8, maybe: u8) -> QuickCheck {
+    let mut result = QuickCheck::Yes;
+    let mut last_class = 0;
+    for ch in string.chars() {
+        // ASCII is in every normalization form, and has no combining marks.
+        if ch.is_ascii() {
+            last_class = 0;
+            continue;
+        }
+        let class = combining_class(ch);
+        if class != 0 && last_class > class {
+            return QuickCheck::No;
+        }
+        let flags = lookup_run(&QUICK_CHECK_RUNS, ch);
+        if flags & no != 0 {
+            return QuickCheck::No;
+        }
+        if flags & maybe != 0 {
+            result = QuickCheck::Maybe;
+        }
+        last_class = class;
+    }
+    result
+}
@@ -0,0 +1,93 @@
+//! Rust identifiers, including keywords and raw identifiers, as given by the
+//! [Reference][identifiers].
+//!
+//! [identifiers]: https://doc.rust-lang.org/reference/identifiers.html
+//!
+//! [`Language::Rust`] determines the lexical extent of an identifier token,
+//! which includes keywords. This module additionally rejects the strict and
+//! reserved keywords of each edition, and the few keywords that cannot be
+//! made into raw identifiers, so that a name accepted by [`is_rust_ident`] can
+//! be used in any position that takes an identifier.
+//!
+//! [`Language::Rust`]: crate::Language::Rust
+//!
+//! ```
+//! use unicode_ident::rust::{is_rust_ident, Edition};
+//!
+//! assert!(is_rust_ident("async", Edition::Edition2015));
+//! assert!(!is_rust_ident("async", Edition::Edition2018));
+//! assert!(is_rust_ident("r#async", Edition::Edition2018));
+//! assert!(!is_rust_ident("r#self", Edition::Edition2018));
+//! assert!(!is_rust_ident("_", Edition::Edition2021));
+//! ```
+
+use crate::Language;
+
+/// A Rust edition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Edition {
+    /// Rust 2015.
+    Edition2015,
+    /// Rust 2018, in which `async`, `await`, `dyn` and `try` became keywords.
+    Edition2018,
+    /// Rust 2021.
+    Edition2021,
+    /// Rust 2024, in which `gen` became a reserved keyword.
+    Edition2024,
+}
+
+/// Whether the string is a Rust identifier in the given edition: either a
+/// raw identifier, or an identifier that is not a strict or reserved keyword.
+///
+/// This matches what `proc_macro::Ident::new` and `proc_macro::Ident::new_raw`
+/// accept, except that `Ident::new` also accepts keywords.
+pub fn is_rust_ident(string: &str, edition: Edition) -> bool {
+    if !Language::Rust.is_valid(string) {
+        return false;
+    }
+    match string.strip_prefix("r#") {
+        Some(raw) => !is_path_segment_keyword(raw),
+        None => !is_keyword(string, edition),
+    }
+}
+
+/// Whether the word is a strict or reserved keyword in the given edition,
+/// which makes it unusable as an identifier unless written as a raw
+/// identifier.
+///
+/// Weak keywords such as `union` and `macro_rules` are keywords only in
+/// certain contexts, and are not included.
+pub fn is_keyword(word: &str, edition: Edition) -> bool {
+    is_strict_keyword(word, edition) || is_reserved_keyword(word, edition)
+}
+
+/// Whether the word is a keyword that has meaning in the language in the
+/// given edition, such as `fn`, or `async` since the 2018 edition.
+pub fn is_strict_keyword(word: &str, edition: Edition) -> bool {
+    match word {
+        "as" | "break" | "const" | "continue" | "crate" | "else" | "enum" | "extern" | "false"
+        | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod" | "move"
+        | "mut" | "pub" | "ref" | "return" | "self" | "Self" | "static" | "struct" | "super"
+        | "trait" | "true" | "type" | "unsafe" | "use" | "where" | "while" => true,
+        "async" | "await" | "dyn" => edition >= Edition::Edition2018,
+        _ => false,
+    }
+}
+
+/// Whether the word is reserved for future use in the given edition, such as
+/// `abstract`, or `gen` since the 2024 edition.
+pub fn is_reserved_keyword(word: &str, edition: Edition) -> bool {
+    match word {
+        "abstract" | "become" | "box" | "do" | "final" | "macro" | "override" | "priv"
+        | "typeof" | "unsized" | "virtual" | "yield" => true,
+        "try" => edition >= Edition::Edition2018,
+        "gen" => edition >= Edition::Edition2024,
+        _ => false,
+    }
+}
+
+// Keywords that begin or make up a path, which cannot be raw identifiers.
+fn is_path_segment_keyword(word: &str) -> bool {
+    matches!(word, "crate" | "self" | "super" | "Self")
+}
@@ -0,0 +1,74 @@
+use crate::IdentProfile;
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+/// Turn arbitrary text, such as a JSON key or SQL column name, into an
+/// identifier in the given profile.
+///
+/// - Each character that is not allowed where it appears is replaced by `_`.
+/// - If the first character cannot begin an identifier but may appear later
+///   in one, as with digits, `_` is inserted before it.
+/// - An empty string becomes `_`.
+/// - If the result is one of the profile's
+///   [`keywords`][IdentProfile::keywords], `_` is appended.
+///
+/// In profiles that do not allow `_` in those positions, `x` is used instead,
+/// and in profiles that allow neither, the first character in code point order
+/// that the profile does allow there. Text that is already a valid identifier,
+/// and not a keyword, is returned unchanged without allocating.
+///
+/// ```
+/// use unicode_ident::{sanitize_ident, IdentProfile};
+///
+/// const PROFILE: IdentProfile = IdentProfile::new()
+///     .allow_start(&['_'])
+///     .keywords(&["type", "match"]);
+///
+/// assert_eq!(sanitize_ident("first name", &PROFILE), "first_name");
+/// assert_eq!(sanitize_ident("2fa-code", &PROFILE), "_2fa_code");
+/// assert_eq!(sanitize_ident("type", &PROFILE), "type_");
+/// assert_eq!(sanitize_ident("größe", &PROFILE), "größe");
+/// ```
+pub fn sanitize_ident<'a>(string: &'a str, profile: &IdentProfile) -> Cow<'a, str> {
+    let len = profile.scan(string);
+    if len == string.len() && len != 0 && !profile.is_keyword(string) {
+        return Cow::Borrowed(string);
+    }
+
+    let mut ident = String::with_capacity(string.len() + 1);
+    ident.push_str(string);
+    let mut len = len;
+    while let Some(ch) = ident[len..].chars().next() {
+        if len == 0 && profile.is_continue(ch) {
+            ident.insert(0, filler(profile, true));
+        } else {
+            let filler = filler(profile, len == 0);
+            ident.replace_range(len..len + ch.len_utf8(), filler.encode_utf8(&mut [0; 4]));
+        }
+        len = profile.scan_from(&ident, len);
+    }
+    if ident.is_empty() {
+        ident.push(filler(profile, true));
+    }
+    while profile.is_keyword(&ident) {
+        ident.push(filler(profile, false));
+    }
+    Cow::Owned(ident)
+}
+
+// The character to insert in place of one that is not allowed. A profile can
+// only disallow finitely many of the XID_Start characters, so there is always
+// one to fall back to.
+fn filler(profile: &IdentProfile, start: bool) -> char {
+    ['_', 'x']
+        .into_iter()
+        .chain('\0'..=char::MAX)
+        .find(|&ch| {
+            if start {
+                profile.is_start(ch)
+            } else {
+                profile.is_continue(ch)
+            }
+        })
+        .unwrap()
+}
@@ -0,0 +1,209 @@
+use crate::tables::{
+    Script, SCRIPTS, SCRIPT_COUNT, SCRIPT_EXTENSIONS, SCRIPT_EXTENSIONS_RUNS, SCRIPT_NAMES,
+    SCRIPT_RUNS, SCRIPT_WORDS,
+};
+use core::fmt::{self, Debug};
+
+/// The Unicode property Script of a character.
+///
+/// Characters that are shared across many scripts, such as punctuation and
+/// digits, are `Script::Common`. Combining marks that take on the script of
+/// their base character are `Script::Inherited`. Unassigned codepoints are
+/// `Script::Unknown`.
+pub fn script(ch: char) -> Script {
+    if ch.is_ascii() {
+        return if ch.is_ascii_alphabetic() {
+            Script::Latin
+        } else {
+            Script::Common
+        };
+    }
+    SCRIPTS[usize::from(lookup_run(&SCRIPT_RUNS, ch))]
+}
+
+/// The Unicode property Script\_Extensions of a character.
+///
+/// For most characters this is the set containing just [`script(ch)`][script].
+/// Characters that are used with a limited number of scripts, such as U+30FC
+/// KATAKANA-HIRAGANA PROLONGED SOUND MARK which is used with Hiragana and
+/// Katakana, have those scripts here instead of `Script::Common` or
+/// `Script::Inherited`.
+pub fn script_extensions(ch: char) -> ScriptSet {
+    if ch.is_ascii() {
+        return ScriptSet::from(script(ch));
+    }
+    match lookup_run(&SCRIPT_EXTENSIONS_RUNS, ch) {
+        0 => ScriptSet::from(script(ch)),
+        index => ScriptSet {
+            bits: SCRIPT_EXTENSIONS[usize::from(index) - 1],
+        },
+    }
+}
+
+pub(crate) fn lookup_run(runs: &[u32], ch: char) -> u8 {
+    // The first run begins at U+0000, so there is always one at or below ch.
+    let i = runs.partition_point(|run| run >> 8 <= ch as u32);
+    runs[i - 1].to_le_bytes()[0]
+}
+
+impl Script {
+    /// The 4-letter ISO 15924 code of the script, such as `"Latn"`.
+    pub fn short_name(self) -> &'static str {
+        SCRIPT_NAMES[self as usize].0
+    }
+
+    /// The Unicode name of the script, such as `"Old_Italic"`.
+    pub fn long_name(self) -> &'static str {
+        SCRIPT_NAMES[self as usize].1
+    }
+}
+
+/// A set of [`Script`] values, such as the Script\_Extensions of a character.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ScriptSet {
+    bits: [u64; SCRIPT_WORDS],
+}
+
+impl ScriptSet {
+    pub(crate) const ALL: Self = {
+        let mut bits = [0; SCRIPT_WORDS];
+        let mut script = 0;
+        while script < SCRIPT_COUNT {
+            bits[script / 64] |= 1 << (script % 64);
+            script += 1;
+        }
+        ScriptSet { bits }
+    };
+
+    /// The empty set.
+    pub const fn new() -> Self {
+        ScriptSet {
+            bits: [0; SCRIPT_WORDS],
+        }
+    }
+
+    /// Whether the script is in the set.
+    pub fn contains(&self, script: Script) -> bool {
+        let script = script as usize;
+        self.bits[script / 64] & (1 << (script % 64)) != 0
+    }
+
+    /// Add the script to the set, if it is not already present.
+    pub fn insert(&mut self, script: Script) {
+        let script = script as usize;
+        self.bits[script / 64] |= 1 << (script % 64);
+    }
+
+    /// Remove the script from the set, if it is present.
+    pub fn remove(&mut self, script: Script) {
+        let script = script as usize;
+        self.bits[script / 64] &= !(1 << (script % 64));
+    }
+
+    /// Whether the set contains no scripts.
+    pub fn is_empty(&self) -> bool {
+        self.bits == [0; SCRIPT_WORDS]
+    }
+
+    /// The number of scripts in the set.
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The scripts contained in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &ScriptSet) -> ScriptSet {
+        let mut bits = self.bits;
+        for (word, other) in bits.iter_mut().zip(other.bits) {
+            *word &= other;
+        }
+        ScriptSet { bits }
+    }
+
+    /// The scripts contained in either set.
+    #[must_use]
+    pub fn union(&self, other: &ScriptSet) -> ScriptSet {
+        let mut bits = self.bits;
+        for (word, other) in bits.iter_mut().zip(other.bits) {
+            *word |= other;
+        }
+        ScriptSet { bits }
+    }
+
+    /// Iterate the scripts in the set, in order of their short name.
+    pub fn iter(&self) -> ScriptSetIter {
+        ScriptSetIter {
+            bits: self.bits,
+            word: 0,
+        }
+    }
+}
+
+impl From<Script> for ScriptSet {
+    fn from(script: Script) -> Self {
+        let mut set = ScriptSet::new();
+        set.insert(script);
+        set
+    }
+}
+
+impl FromIterator<Script> for ScriptSet {
+    fn from_iter<I: IntoIterator<Item = Script>>(iter: I) -> Self {
+        let mut set = ScriptSet::new();
+        for script in iter {
+            set.insert(script);
+        }
+        set
+    }
+}
+
+impl IntoIterator for ScriptSet {
+    type Item = Script;
+    type IntoIter = ScriptSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &ScriptSet {
+    type Item = Script;
+    type IntoIter = ScriptSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Debug for ScriptSet {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_set().entries(self).finish()
+    }
+}
+
+/// Iterator over the scripts in a [`ScriptSet`].
+#[derive(Clone)]
+pub struct ScriptSetIter {
+    bits: [u64; SCRIPT_WORDS],
+    word: usize,
+}
+
+impl Iterator for ScriptSetIter {
+    type Item = Script;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(bits) = self.bits.get_mut(self.word) {
+            if *bits == 0 {
+                self.word += 1;
+                continue;
+            }
+            let bit = bits.trailing_zeros() as usize;
+            *bits &= *bits - 1;
+            return Some(SCRIPTS[self.word * 64 + bit]);
+        }
+        None
+    }
+}
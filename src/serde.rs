@@ -0,0 +1,109 @@
+use crate::ident::IdentError;
+use crate::Ident;
+#[cfg(feature = "alloc")]
+use crate::IdentBuf;
+use core::fmt::{self, Display};
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+impl Serialize for Ident {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for IdentBuf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for &'a Ident {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct IdentVisitor;
+
+        impl<'de> Visitor<'de> for IdentVisitor {
+            type Value = &'de Ident;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a borrowed identifier")
+            }
+
+            fn visit_borrowed_str<E>(self, string: &'de str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ident::new(string).map_err(|err| invalid(string, err))
+            }
+        }
+
+        deserializer.deserialize_str(IdentVisitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for IdentBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct IdentBufVisitor;
+
+        impl Visitor<'_> for IdentBufVisitor {
+            type Value = IdentBuf;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an identifier")
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                IdentBuf::try_from(string).map_err(|err| invalid(string, err))
+            }
+        }
+
+        deserializer.deserialize_str(IdentBufVisitor)
+    }
+}
+
+fn invalid<E: Error>(string: &str, err: IdentError) -> E {
+    E::custom(Invalid { string, err })
+}
+
+struct Invalid<'a> {
+    string: &'a str,
+    err: IdentError,
+}
+
+impl Display for Invalid<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let Some(ch) = self.err.ch() else {
+            return formatter.write_str("invalid identifier: empty string");
+        };
+        let property = if self.err.offset() == 0 {
+            "XID_Start"
+        } else {
+            "XID_Continue"
+        };
+        write!(
+            formatter,
+            "invalid identifier {:?}: {:?} at byte offset {} is not {}",
+            self.string,
+            ch,
+            self.err.offset(),
+            property,
+        )
+    }
+}
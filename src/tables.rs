@@ -13,10 +13,17 @@ pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
 
 pub(crate) const ASCII_START: u128 = 0x7fffffe07fffffe0000000000000000;
 pub(crate) const ASCII_CONTINUE: u128 = 0x7fffffe87fffffe03ff000000000000;
+pub(crate) const ASCII_PATTERN_SYNTAX: u128 = 0x7800000178000001fc00fffe00000000;
+pub(crate) const ASCII_PATTERN_WHITE_SPACE: u128 = 0x100003e00;
+pub(crate) const ASCII_HASHTAG_CONTINUE: u128 = 0x7fffffe87fffffe03ff2c0000000000;
 
 pub(crate) const CHUNK: usize = 64;
 
-pub(crate) static TRIE_START: Align8<[u8; 411]> = Align8([
+pub(crate) static TRIE_START: Align8<[u8; 411]> = CONST_TRIE_START;
+pub(crate) static TRIE_CONTINUE: Align8<[u8; 1793]> = CONST_TRIE_CONTINUE;
+pub(crate) static LEAF: Align64<[u8; 7808]> = CONST_LEAF;
+
+pub(crate) const CONST_TRIE_START: Align8<[u8; 411]> = Align8([
     0x03, 0x09, 0x0D, 0x11, 0x15, 0x19, 0x1D, 0x21, 0x25, 0x2B, 0x2F, 0x32, 0x36, 0x3A, 0x3E, 0xA5,
     0x43, 0x00, 0x00, 0x00, 0x00, 0x00, 0x47, 0x00, 0x4B, 0x00, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF,
     0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0x50, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF,
@@ -45,7 +52,7 @@ pub(crate) static TRIE_START: Align8<[u8; 411]> = Align8([
     0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xF2,
 ]);
 
-pub(crate) static TRIE_CONTINUE: Align8<[u8; 1793]> = Align8([
+pub(crate) const CONST_TRIE_CONTINUE: Align8<[u8; 1793]> = Align8([
     0x06, 0x0B, 0x0F, 0x13, 0x17, 0x1B, 0x1F, 0x23, 0x28, 0x2D, 0x2F, 0x34, 0x38, 0x3C, 0x40, 0xA5,
     0x45, 0x00, 0x00, 0x00, 0x00, 0x00, 0x49, 0x00, 0x4D, 0x00, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF,
     0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0x50, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF, 0xEF,
@@ -161,7 +168,7 @@ pub(crate) static TRIE_CONTINUE: Align8<[u8; 1793]> = Align8([
     0x01,
 ]);
 
-pub(crate) static LEAF: Align64<[u8; 7808]> = Align64([
+pub(crate) const CONST_LEAF: Align64<[u8; 7808]> = Align64([
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -651,3 +658,6160 @@ pub(crate) static LEAF: Align64<[u8; 7808]> = Align64([
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
 ]);
+
+pub(crate) static TRIE_ID_START_EXTRA: Align8<[u8; 128]> = Align8([
+    0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x09,
+]);
+
+pub(crate) static TRIE_ID_CONTINUE_EXTRA: Align8<[u8; 128]> = Align8([
+    0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x0B,
+]);
+
+pub(crate) static LEAF_ID: Align64<[u8; 416]> = Align64([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x0F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+]);
+
+pub(crate) static TRIE_PATTERN_SYNTAX: Align8<[u8; 128]> = Align8([
+    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x14, 0x1F, 0x1E, 0x20, 0x1F, 0x1F, 0x00, 0x22, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x26,
+]);
+
+pub(crate) static TRIE_PATTERN_WHITE_SPACE: Align8<[u8; 17]> = Align8([
+    0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x16,
+]);
+
+pub(crate) static TRIE_ID_COMPAT_MATH_START: Align8<[u8; 236]> = Align8([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2C,
+]);
+
+pub(crate) static TRIE_ID_COMPAT_MATH_CONTINUE_EXTRA: Align8<[u8; 17]> = Align8([
+    0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x18,
+]);
+
+pub(crate) static TRIE_DEFAULT_IGNORABLE: Align8<[u8; 233]> = Align8([
+    0x0C, 0x10, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x0D, 0x00, 0x00, 0x0A, 0x12, 0x00, 0x00, 0x00,
+    0x1A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2A, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+]);
+
+pub(crate) static LEAF_PATTERN: Align64<[u8; 1472]> = Align64([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF8, 0x07,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xFE, 0x5A, 0x43, 0x88, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0xFF, 0x7F, 0xFE, 0xFF, 0xEF, 0x7F, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x00, 0xC0, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF1, 0x7F,
+    0xFF, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xF8, 0x00, 0x00, 0x00, 0x7C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x84, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F, 0x00,
+    0x00, 0x00, 0xF0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0E, 0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x01,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00,
+    0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+]);
+
+pub(crate) static TRIE_EMOJI: Align8<[u8; 256]> = Align8([
+    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x06, 0x0F, 0x0A, 0x0C, 0x04, 0x01, 0x00, 0x00, 0x0E, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x15, 0x17, 0x19, 0x1B, 0x1D, 0x1F, 0x20,
+]);
+
+pub(crate) static TRIE_HASHTAG_CONTINUE_EXTRA: Align8<[u8; 256]> = Align8([
+    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x0F, 0x0A, 0x0C, 0x04, 0x01, 0x00, 0x00, 0x0E, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x15, 0x17, 0x19, 0x1B, 0x1D, 0x1F, 0x20,
+]);
+
+pub(crate) static LEAF_EMOJI: Align64<[u8; 1088]> = Align64([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xE0, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xF0, 0x03, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xF0, 0x03, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x40, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x78,
+    0x1F, 0x40, 0x32, 0x21, 0x4D, 0xC4, 0x00, 0x07, 0x05, 0xFF, 0x0F, 0x80, 0x69, 0x01, 0x00, 0xC8,
+    0x00, 0x00, 0xFC, 0x1A, 0x83, 0x0C, 0x03, 0x60, 0x30, 0xC1, 0x1A, 0x00, 0x00, 0x06, 0xBF, 0x27,
+    0x24, 0xBF, 0x54, 0x20, 0x02, 0x01, 0x18, 0x00, 0x90, 0x50, 0xB8, 0x00, 0x18, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xE0, 0x00, 0x02, 0x00, 0x01, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0C, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xFE, 0x0F, 0x07,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x80, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xF0, 0xFF, 0x00, 0x80, 0x01, 0x00, 0x01, 0x80, 0x01, 0x00, 0x00, 0x00, 0xC0, 0xFF,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xC0,
+    0x00, 0x40, 0xFE, 0x07, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFE, 0xFF, 0x00, 0x04, 0x00, 0x80, 0xFC, 0xF7, 0x00, 0xFE, 0xFF, 0xFF, 0xC0, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xF3, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xCF, 0xCE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xB9, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xBF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F, 0x00, 0x7E, 0xFF, 0xFF, 0xFF, 0x80, 0xF9, 0x07,
+    0x80, 0x3C, 0x61, 0x00, 0x30, 0x01, 0x06, 0x10, 0x1C, 0x00, 0x0E, 0x70, 0x0A, 0x81, 0x08, 0xFC,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F, 0xF8, 0xE7, 0xFF, 0x3F, 0xFA, 0xF9, 0xFF,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x00, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0xFC, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x00, 0x00, 0x00, 0xC0, 0x00, 0xF0, 0xFC, 0xFF, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x00, 0xF0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF7, 0xBF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0xC0, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F,
+]);
+
+pub(crate) const LEFT_JOINING: u8 = 0x01;
+pub(crate) const RIGHT_JOINING: u8 = 0x02;
+pub(crate) const TRANSPARENT: u8 = 0x04;
+pub(crate) const LETTER_XOR_XID_START: u8 = 0x08;
+pub(crate) const NONSPACING_MARK: u8 = 0x10;
+pub(crate) const COMBINING: u8 = 0x20;
+pub(crate) const VIRAMA: u8 = 0x40;
+
+pub(crate) static JOIN_CONTEXT_RUNS: [u32; 1073] = [
+    0x00000000, 0x0000AD04, 0x0000AE00, 0x00030034, 0x00034F14, 0x00035034, 0x00037000, 0x00037A08,
+    0x00037B00, 0x00048334, 0x00048804, 0x00048A00, 0x00059134, 0x0005BE00, 0x0005BF34, 0x0005C000,
+    0x0005C134, 0x0005C300, 0x0005C434, 0x0005C600, 0x0005C734, 0x0005C800, 0x00061034, 0x00061B00,
+    0x00061C04, 0x00061D00, 0x00062003, 0x00062100, 0x00062202, 0x00062603, 0x00062702, 0x00062803,
+    0x00062902, 0x00062A03, 0x00062F02, 0x00063303, 0x00064000, 0x00064103, 0x00064802, 0x00064903,
+    0x00064B34, 0x00066000, 0x00066E03, 0x00067034, 0x00067102, 0x00067400, 0x00067502, 0x00067803,
+    0x00068802, 0x00069A03, 0x0006C002, 0x0006C103, 0x0006C302, 0x0006CC03, 0x0006CD02, 0x0006CE03,
+    0x0006CF02, 0x0006D003, 0x0006D202, 0x0006D400, 0x0006D502, 0x0006D634, 0x0006DD00, 0x0006DF34,
+    0x0006E500, 0x0006E734, 0x0006E900, 0x0006EA34, 0x0006EE02, 0x0006F000, 0x0006FA03, 0x0006FD00,
+    0x0006FF03, 0x00070000, 0x00070F04, 0x00071002, 0x00071134, 0x00071203, 0x00071502, 0x00071A03,
+    0x00071E02, 0x00071F03, 0x00072802, 0x00072903, 0x00072A02, 0x00072B03, 0x00072C02, 0x00072D03,
+    0x00072F02, 0x00073034, 0x00074B00, 0x00074D02, 0x00074E03, 0x00075902, 0x00075C03, 0x00076B02,
+    0x00076D03, 0x00077102, 0x00077203, 0x00077302, 0x00077503, 0x00077802, 0x00077A03, 0x00078000,
+    0x0007A614, 0x0007B100, 0x0007CA03, 0x0007EB34, 0x0007F400, 0x0007FD34, 0x0007FE00, 0x00081634,
+    0x00081A00, 0x00081B34, 0x00082400, 0x00082534, 0x00082800, 0x00082934, 0x00082E00, 0x00084002,
+    0x00084103, 0x00084602, 0x00084803, 0x00084902, 0x00084A03, 0x00085402, 0x00085503, 0x00085602,
+    0x00085934, 0x00085C00, 0x00086003, 0x00086100, 0x00086203, 0x00086600, 0x00086702, 0x00086803,
+    0x00086902, 0x00086B00, 0x00087002, 0x00088300, 0x00088603, 0x00088700, 0x00088903, 0x00088E02,
+    0x00088F03, 0x00089000, 0x00089734, 0x0008A003, 0x0008AA02, 0x0008AD00, 0x0008AE02, 0x0008AF03,
+    0x0008B102, 0x0008B303, 0x0008B902, 0x0008BA03, 0x0008C900, 0x0008CA34, 0x0008E200, 0x0008E334,
+    0x00090014, 0x00090300, 0x00093A14, 0x00093B00, 0x00093C34, 0x00093D00, 0x00094114, 0x00094900,
+    0x00094D74, 0x00094E00, 0x00095134, 0x00095514, 0x00095800, 0x00096214, 0x00096400, 0x00098114,
+    0x00098200, 0x0009BC34, 0x0009BD00, 0x0009C114, 0x0009C500, 0x0009CD74, 0x0009CE00, 0x0009E214,
+    0x0009E400, 0x0009FE34, 0x0009FF00, 0x000A0114, 0x000A0300, 0x000A3C34, 0x000A3D00, 0x000A4114,
+    0x000A4300, 0x000A4714, 0x000A4900, 0x000A4B14, 0x000A4D74, 0x000A4E00, 0x000A5114, 0x000A5200,
+    0x000A7014, 0x000A7200, 0x000A7514, 0x000A7600, 0x000A8114, 0x000A8300, 0x000ABC34, 0x000ABD00,
+    0x000AC114, 0x000AC600, 0x000AC714, 0x000AC900, 0x000ACD74, 0x000ACE00, 0x000AE214, 0x000AE400,
+    0x000AFA14, 0x000B0000, 0x000B0114, 0x000B0200, 0x000B3C34, 0x000B3D00, 0x000B3F14, 0x000B4000,
+    0x000B4114, 0x000B4500, 0x000B4D74, 0x000B4E00, 0x000B5514, 0x000B5700, 0x000B6214, 0x000B6400,
+    0x000B8214, 0x000B8300, 0x000BC014, 0x000BC100, 0x000BCD74, 0x000BCE00, 0x000C0014, 0x000C0100,
+    0x000C0414, 0x000C0500, 0x000C3C34, 0x000C3D00, 0x000C3E14, 0x000C4100, 0x000C4614, 0x000C4900,
+    0x000C4A14, 0x000C4D74, 0x000C4E00, 0x000C5534, 0x000C5700, 0x000C6214, 0x000C6400, 0x000C8114,
+    0x000C8200, 0x000CBC34, 0x000CBD00, 0x000CBF14, 0x000CC000, 0x000CC614, 0x000CC700, 0x000CCC14,
+    0x000CCD74, 0x000CCE00, 0x000CE214, 0x000CE400, 0x000D0014, 0x000D0200, 0x000D3B74, 0x000D3D00,
+    0x000D4114, 0x000D4500, 0x000D4D74, 0x000D4E00, 0x000D6214, 0x000D6400, 0x000D8114, 0x000D8200,
+    0x000DCA74, 0x000DCB00, 0x000DD214, 0x000DD500, 0x000DD614, 0x000DD700, 0x000E3114, 0x000E3200,
+    0x000E3308, 0x000E3414, 0x000E3834, 0x000E3A74, 0x000E3B00, 0x000E4714, 0x000E4834, 0x000E4C14,
+    0x000E4F00, 0x000EB114, 0x000EB200, 0x000EB308, 0x000EB414, 0x000EB834, 0x000EBA74, 0x000EBB14,
+    0x000EBD00, 0x000EC834, 0x000ECC14, 0x000ECF00, 0x000F1834, 0x000F1A00, 0x000F3534, 0x000F3600,
+    0x000F3734, 0x000F3800, 0x000F3934, 0x000F3A00, 0x000F7134, 0x000F7314, 0x000F7434, 0x000F7514,
+    0x000F7A34, 0x000F7E14, 0x000F7F00, 0x000F8034, 0x000F8114, 0x000F8234, 0x000F8474, 0x000F8500,
+    0x000F8634, 0x000F8800, 0x000F8D14, 0x000F9800, 0x000F9914, 0x000FBD00, 0x000FC634, 0x000FC700,
+    0x00102D14, 0x00103100, 0x00103214, 0x00103734, 0x00103800, 0x00103974, 0x00103B00, 0x00103D14,
+    0x00103F00, 0x00105814, 0x00105A00, 0x00105E14, 0x00106100, 0x00107114, 0x00107500, 0x00108214,
+    0x00108300, 0x00108514, 0x00108700, 0x00108D34, 0x00108E00, 0x00109D14, 0x00109E00, 0x00135D34,
+    0x00136000, 0x0016EE08, 0x0016F100, 0x00171214, 0x00171474, 0x00171560, 0x00171600, 0x00173214,
+    0x00173460, 0x00173500, 0x00175214, 0x00175400, 0x00177214, 0x00177400, 0x0017B414, 0x0017B600,
+    0x0017B714, 0x0017BE00, 0x0017C614, 0x0017C700, 0x0017C914, 0x0017D274, 0x0017D314, 0x0017D400,
+    0x0017DD34, 0x0017DE00, 0x00180703, 0x00180800, 0x00180B14, 0x00180E00, 0x00180F14, 0x00181000,
+    0x00182003, 0x00187900, 0x0018851C, 0x00188703, 0x0018A934, 0x0018AA03, 0x0018AB00, 0x00192014,
+    0x00192300, 0x00192714, 0x00192900, 0x00193214, 0x00193300, 0x00193934, 0x00193C00, 0x001A1734,
+    0x001A1900, 0x001A1B14, 0x001A1C00, 0x001A5614, 0x001A5700, 0x001A5814, 0x001A5F00, 0x001A6074,
+    0x001A6100, 0x001A6214, 0x001A6300, 0x001A6514, 0x001A6D00, 0x001A7314, 0x001A7534, 0x001A7D00,
+    0x001A7F34, 0x001A8000, 0x001AB034, 0x001ABE04, 0x001ABF34, 0x001ADE00, 0x001AE034, 0x001AEC00,
+    0x001B0014, 0x001B0400, 0x001B3434, 0x001B3500, 0x001B3614, 0x001B3B00, 0x001B3C14, 0x001B3D00,
+    0x001B4214, 0x001B4300, 0x001B4460, 0x001B4500, 0x001B6B34, 0x001B7400, 0x001B8014, 0x001B8200,
+    0x001BA214, 0x001BA600, 0x001BA814, 0x001BAA60, 0x001BAB74, 0x001BAC14, 0x001BAE00, 0x001BE634,
+    0x001BE700, 0x001BE814, 0x001BEA00, 0x001BED14, 0x001BEE00, 0x001BEF14, 0x001BF260, 0x001BF400,
+    0x001C2C14, 0x001C3400, 0x001C3614, 0x001C3734, 0x001C3800, 0x001CD034, 0x001CD300, 0x001CD434,
+    0x001CE100, 0x001CE234, 0x001CE900, 0x001CED34, 0x001CEE00, 0x001CF434, 0x001CF500, 0x001CF834,
+    0x001CFA00, 0x001DC034, 0x001E0000, 0x00200B04, 0x00200C00, 0x00200E04, 0x00201000, 0x00202A04,
+    0x00202F00, 0x00206004, 0x00206500, 0x00206A04, 0x00207000, 0x0020D034, 0x0020DD04, 0x0020E134,
+    0x0020E204, 0x0020E534, 0x0020F100, 0x00211808, 0x00211900, 0x00212E08, 0x00212F00, 0x00216008,
+    0x00218300, 0x00218508, 0x00218900, 0x002CEF34, 0x002CF200, 0x002D7F74, 0x002D8000, 0x002DE034,
+    0x002E0000, 0x002E2F08, 0x002E3000, 0x00300708, 0x00300800, 0x00302108, 0x00302A34, 0x00302E20,
+    0x00303000, 0x00303808, 0x00303B00, 0x00309934, 0x00309B00, 0x00A66F34, 0x00A67004, 0x00A67300,
+    0x00A67434, 0x00A67E00, 0x00A69E34, 0x00A6A000, 0x00A6E608, 0x00A6F034, 0x00A6F200, 0x00A80214,
+    0x00A80300, 0x00A80674, 0x00A80700, 0x00A80B14, 0x00A80C00, 0x00A82514, 0x00A82700, 0x00A82C74,
+    0x00A82D00, 0x00A84003, 0x00A87201, 0x00A87300, 0x00A8C474, 0x00A8C514, 0x00A8C600, 0x00A8E034,
+    0x00A8F200, 0x00A8FF14, 0x00A90000, 0x00A92614, 0x00A92B34, 0x00A92E00, 0x00A94714, 0x00A95200,
+    0x00A95360, 0x00A95400, 0x00A98014, 0x00A98300, 0x00A9B334, 0x00A9B400, 0x00A9B614, 0x00A9BA00,
+    0x00A9BC14, 0x00A9BE00, 0x00A9C060, 0x00A9C100, 0x00A9E514, 0x00A9E600, 0x00AA2914, 0x00AA2F00,
+    0x00AA3114, 0x00AA3300, 0x00AA3514, 0x00AA3700, 0x00AA4314, 0x00AA4400, 0x00AA4C14, 0x00AA4D00,
+    0x00AA7C14, 0x00AA7D00, 0x00AAB034, 0x00AAB100, 0x00AAB234, 0x00AAB500, 0x00AAB734, 0x00AAB900,
+    0x00AABE34, 0x00AAC000, 0x00AAC134, 0x00AAC200, 0x00AAEC14, 0x00AAEE00, 0x00AAF674, 0x00AAF700,
+    0x00ABE514, 0x00ABE600, 0x00ABE814, 0x00ABE900, 0x00ABED74, 0x00ABEE00, 0x00FB1E34, 0x00FB1F00,
+    0x00FC5E08, 0x00FC6400, 0x00FDFA08, 0x00FDFC00, 0x00FE0014, 0x00FE1000, 0x00FE2034, 0x00FE3000,
+    0x00FE7008, 0x00FE7100, 0x00FE7208, 0x00FE7300, 0x00FE7408, 0x00FE7500, 0x00FE7608, 0x00FE7700,
+    0x00FE7808, 0x00FE7900, 0x00FE7A08, 0x00FE7B00, 0x00FE7C08, 0x00FE7D00, 0x00FE7E08, 0x00FE7F00,
+    0x00FEFF04, 0x00FF0000, 0x00FF9E08, 0x00FFA000, 0x00FFF904, 0x00FFFC00, 0x01014008, 0x01017500,
+    0x0101FD34, 0x0101FE00, 0x0102E034, 0x0102E100, 0x01034108, 0x01034200, 0x01034A08, 0x01034B00,
+    0x01037634, 0x01037B00, 0x0103D108, 0x0103D600, 0x010A0114, 0x010A0400, 0x010A0514, 0x010A0700,
+    0x010A0C14, 0x010A0D34, 0x010A0E14, 0x010A0F34, 0x010A1000, 0x010A3834, 0x010A3B00, 0x010A3F74,
+    0x010A4000, 0x010AC003, 0x010AC502, 0x010AC600, 0x010AC702, 0x010AC800, 0x010AC902, 0x010ACB00,
+    0x010ACD01, 0x010ACE02, 0x010AD303, 0x010AD701, 0x010AD803, 0x010ADD02, 0x010ADE03, 0x010AE102,
+    0x010AE200, 0x010AE402, 0x010AE534, 0x010AE700, 0x010AEB03, 0x010AEF02, 0x010AF000, 0x010B8003,
+    0x010B8102, 0x010B8203, 0x010B8302, 0x010B8603, 0x010B8902, 0x010B8A03, 0x010B8C02, 0x010B8D03,
+    0x010B8E02, 0x010B9003, 0x010B9102, 0x010B9200, 0x010BA902, 0x010BAD03, 0x010BAF00, 0x010D0001,
+    0x010D0103, 0x010D2202, 0x010D2303, 0x010D2434, 0x010D2800, 0x010D6934, 0x010D6E00, 0x010EAB34,
+    0x010EAD00, 0x010EC202, 0x010EC303, 0x010EC500, 0x010EC603, 0x010EC800, 0x010EFA34, 0x010EFC14,
+    0x010EFD34, 0x010F0000, 0x010F3003, 0x010F3302, 0x010F3403, 0x010F4500, 0x010F4634, 0x010F5103,
+    0x010F5402, 0x010F5500, 0x010F7003, 0x010F7402, 0x010F7603, 0x010F8234, 0x010F8600, 0x010FB003,
+    0x010FB100, 0x010FB203, 0x010FB402, 0x010FB700, 0x010FB803, 0x010FB902, 0x010FBB03, 0x010FBD02,
+    0x010FBE03, 0x010FC000, 0x010FC103, 0x010FC202, 0x010FC403, 0x010FC500, 0x010FC902, 0x010FCA03,
+    0x010FCB01, 0x010FCC00, 0x01100114, 0x01100200, 0x01103814, 0x01104674, 0x01104700, 0x01107074,
+    0x01107100, 0x01107314, 0x01107500, 0x01107F74, 0x01108014, 0x01108200, 0x0110B314, 0x0110B700,
+    0x0110B974, 0x0110BA34, 0x0110BB00, 0x0110C214, 0x0110C300, 0x01110034, 0x01110300, 0x01112714,
+    0x01112C00, 0x01112D14, 0x01113374, 0x01113500, 0x01117334, 0x01117400, 0x01118014, 0x01118200,
+    0x0111B614, 0x0111BF00, 0x0111C060, 0x0111C100, 0x0111C914, 0x0111CA34, 0x0111CB14, 0x0111CD00,
+    0x0111CF14, 0x0111D000, 0x01122F14, 0x01123200, 0x01123414, 0x01123560, 0x01123634, 0x01123714,
+    0x01123800, 0x01123E14, 0x01123F00, 0x01124114, 0x01124200, 0x0112DF14, 0x0112E000, 0x0112E314,
+    0x0112E934, 0x0112EA74, 0x0112EB00, 0x01130014, 0x01130200, 0x01133B34, 0x01133D00, 0x01134014,
+    0x01134100, 0x01134D60, 0x01134E00, 0x01136634, 0x01136D00, 0x01137034, 0x01137500, 0x0113BB14,
+    0x0113C100, 0x0113CE74, 0x0113CF60, 0x0113D074, 0x0113D100, 0x0113D214, 0x0113D300, 0x0113E114,
+    0x0113E300, 0x01143814, 0x01144000, 0x01144274, 0x01144314, 0x01144500, 0x01144634, 0x01144700,
+    0x01145E34, 0x01145F00, 0x0114B314, 0x0114B900, 0x0114BA14, 0x0114BB00, 0x0114BF14, 0x0114C100,
+    0x0114C274, 0x0114C334, 0x0114C400, 0x0115B214, 0x0115B600, 0x0115BC14, 0x0115BE00, 0x0115BF74,
+    0x0115C034, 0x0115C100, 0x0115DC14, 0x0115DE00, 0x01163314, 0x01163B00, 0x01163D14, 0x01163E00,
+    0x01163F74, 0x01164014, 0x01164100, 0x0116AB14, 0x0116AC00, 0x0116AD14, 0x0116AE00, 0x0116B014,
+    0x0116B660, 0x0116B734, 0x0116B800, 0x01171D14, 0x01171E00, 0x01171F14, 0x01172000, 0x01172214,
+    0x01172600, 0x01172714, 0x01172B74, 0x01172C00, 0x01182F14, 0x01183800, 0x01183974, 0x01183A34,
+    0x01183B00, 0x01193B14, 0x01193D60, 0x01193E74, 0x01193F00, 0x01194334, 0x01194400, 0x0119D414,
+    0x0119D800, 0x0119DA14, 0x0119DC00, 0x0119E074, 0x0119E100, 0x011A0114, 0x011A0B00, 0x011A3314,
+    0x011A3474, 0x011A3514, 0x011A3900, 0x011A3B14, 0x011A3F00, 0x011A4774, 0x011A4800, 0x011A5114,
+    0x011A5700, 0x011A5914, 0x011A5C00, 0x011A8A14, 0x011A9700, 0x011A9814, 0x011A9974, 0x011A9A00,
+    0x011B6014, 0x011B6100, 0x011B6214, 0x011B6500, 0x011B6614, 0x011B6700, 0x011C3014, 0x011C3700,
+    0x011C3814, 0x011C3E00, 0x011C3F74, 0x011C4000, 0x011C9214, 0x011CA800, 0x011CAA14, 0x011CB100,
+    0x011CB214, 0x011CB400, 0x011CB514, 0x011CB700, 0x011D3114, 0x011D3700, 0x011D3A14, 0x011D3B00,
+    0x011D3C14, 0x011D3E00, 0x011D3F14, 0x011D4234, 0x011D4314, 0x011D4474, 0x011D4600, 0x011D4714,
+    0x011D4800, 0x011D9014, 0x011D9200, 0x011D9514, 0x011D9600, 0x011D9774, 0x011D9800, 0x011EF314,
+    0x011EF500, 0x011F0014, 0x011F0200, 0x011F3614, 0x011F3B00, 0x011F4014, 0x011F4160, 0x011F4274,
+    0x011F4300, 0x011F5A14, 0x011F5B00, 0x01240008, 0x01246F00, 0x01343004, 0x01344014, 0x01344100,
+    0x01344714, 0x01345600, 0x01611E14, 0x01612A00, 0x01612D14, 0x01612F74, 0x01613000, 0x016AF034,
+    0x016AF500, 0x016B3034, 0x016B3700, 0x016F4F14, 0x016F5000, 0x016F8F14, 0x016F9300, 0x016FE414,
+    0x016FE500, 0x016FF020, 0x016FF200, 0x016FF408, 0x016FF700, 0x01BC9D14, 0x01BC9E34, 0x01BC9F00,
+    0x01BCA004, 0x01BCA400, 0x01CF0014, 0x01CF2E00, 0x01CF3014, 0x01CF4700, 0x01D16520, 0x01D16734,
+    0x01D16A00, 0x01D16D20, 0x01D17304, 0x01D17B34, 0x01D18300, 0x01D18534, 0x01D18C00, 0x01D1AA34,
+    0x01D1AE00, 0x01D24234, 0x01D24500, 0x01DA0014, 0x01DA3700, 0x01DA3B14, 0x01DA6D00, 0x01DA7514,
+    0x01DA7600, 0x01DA8414, 0x01DA8500, 0x01DA9B14, 0x01DAA000, 0x01DAA114, 0x01DAB000, 0x01E00034,
+    0x01E00700, 0x01E00834, 0x01E01900, 0x01E01B34, 0x01E02200, 0x01E02334, 0x01E02500, 0x01E02634,
+    0x01E02B00, 0x01E08F34, 0x01E09000, 0x01E13034, 0x01E13700, 0x01E2AE34, 0x01E2AF00, 0x01E2EC34,
+    0x01E2F000, 0x01E4EC34, 0x01E4F000, 0x01E5EE34, 0x01E5F000, 0x01E6E334, 0x01E6E400, 0x01E6E634,
+    0x01E6E700, 0x01E6EE34, 0x01E6F000, 0x01E6F534, 0x01E6F600, 0x01E8D034, 0x01E8D700, 0x01E90003,
+    0x01E94434, 0x01E94B04, 0x01E94C00, 0x0E000104, 0x0E000200, 0x0E002004, 0x0E008000, 0x0E010014,
+    0x0E01F000,
+];
+
+pub(crate) const CATEGORY_LETTER: u8 = 1;
+pub(crate) const CATEGORY_MARK: u8 = 2;
+pub(crate) const CATEGORY_DECIMAL_NUMBER: u8 = 3;
+pub(crate) const CATEGORY_LETTER_NUMBER: u8 = 4;
+pub(crate) const CATEGORY_CONNECTOR_PUNCTUATION: u8 = 5;
+pub(crate) const CATEGORY_CURRENCY_SYMBOL: u8 = 6;
+pub(crate) const CATEGORY_FORMAT: u8 = 7;
+pub(crate) const CATEGORY_OTHER: u8 = 8;
+
+pub(crate) static CATEGORY_RUNS: [u32; 313] = [
+    0x00000000, 0x00002406, 0x00002500, 0x00003003, 0x00003A00, 0x00005F05, 0x00006000, 0x0000A206,
+    0x0000A600, 0x0000AD07, 0x0000AE00, 0x0000B708, 0x0000B800, 0x00037A01, 0x00037B00, 0x00038708,
+    0x00038800, 0x00058F06, 0x00059000, 0x00060007, 0x00060600, 0x00060B06, 0x00060C00, 0x00061C07,
+    0x00061D00, 0x00066003, 0x00066A00, 0x0006DD07, 0x0006DE00, 0x0006F003, 0x0006FA00, 0x00070F07,
+    0x00071000, 0x0007C003, 0x0007CA00, 0x0007FE06, 0x00080000, 0x00089007, 0x00089200, 0x0008E207,
+    0x0008E300, 0x00096603, 0x00097000, 0x0009E603, 0x0009F000, 0x0009F206, 0x0009F400, 0x0009FB06,
+    0x0009FC00, 0x000A6603, 0x000A7000, 0x000AE603, 0x000AF000, 0x000AF106, 0x000AF200, 0x000B6603,
+    0x000B7000, 0x000BE603, 0x000BF000, 0x000BF906, 0x000BFA00, 0x000C6603, 0x000C7000, 0x000CE603,
+    0x000CF000, 0x000D6603, 0x000D7000, 0x000DE603, 0x000DF000, 0x000E3301, 0x000E3400, 0x000E3F06,
+    0x000E4000, 0x000E5003, 0x000E5A00, 0x000EB301, 0x000EB400, 0x000ED003, 0x000EDA00, 0x000F2003,
+    0x000F2A00, 0x00104003, 0x00104A00, 0x00109003, 0x00109A00, 0x00136908, 0x00137200, 0x0016EE04,
+    0x0016F100, 0x0017DB06, 0x0017DC00, 0x0017E003, 0x0017EA00, 0x00180E07, 0x00180F00, 0x00181003,
+    0x00181A00, 0x00188502, 0x00188700, 0x00194603, 0x00195000, 0x0019D003, 0x0019DA08, 0x0019DB00,
+    0x001A8003, 0x001A8A00, 0x001A9003, 0x001A9A00, 0x001B5003, 0x001B5A00, 0x001BB003, 0x001BBA00,
+    0x001C4003, 0x001C4A00, 0x001C5003, 0x001C5A00, 0x00200B07, 0x00201000, 0x00202A07, 0x00202F00,
+    0x00203F05, 0x00204100, 0x00205405, 0x00205500, 0x00206007, 0x00206500, 0x00206607, 0x00207000,
+    0x0020A006, 0x0020C200, 0x00211808, 0x00211900, 0x00212E08, 0x00212F00, 0x00216004, 0x00218300,
+    0x00218504, 0x00218900, 0x002E2F01, 0x002E3000, 0x00300704, 0x00300800, 0x00302104, 0x00302A00,
+    0x00303804, 0x00303B00, 0x0030FB08, 0x0030FC00, 0x00A62003, 0x00A62A00, 0x00A6E604, 0x00A6F000,
+    0x00A83806, 0x00A83900, 0x00A8D003, 0x00A8DA00, 0x00A90003, 0x00A90A00, 0x00A9D003, 0x00A9DA00,
+    0x00A9F003, 0x00A9FA00, 0x00AA5003, 0x00AA5A00, 0x00ABF003, 0x00ABFA00, 0x00FC5E01, 0x00FC6400,
+    0x00FDFA01, 0x00FDFC06, 0x00FDFD00, 0x00FE3305, 0x00FE3500, 0x00FE4D05, 0x00FE5000, 0x00FE6906,
+    0x00FE6A00, 0x00FE7001, 0x00FE7100, 0x00FE7201, 0x00FE7300, 0x00FE7401, 0x00FE7500, 0x00FE7601,
+    0x00FE7700, 0x00FE7801, 0x00FE7900, 0x00FE7A01, 0x00FE7B00, 0x00FE7C01, 0x00FE7D00, 0x00FE7E01,
+    0x00FE7F00, 0x00FEFF07, 0x00FF0000, 0x00FF0406, 0x00FF0500, 0x00FF1003, 0x00FF1A00, 0x00FF3F05,
+    0x00FF4000, 0x00FF6508, 0x00FF6600, 0x00FF9E01, 0x00FFA000, 0x00FFE006, 0x00FFE200, 0x00FFE506,
+    0x00FFE700, 0x00FFF907, 0x00FFFC00, 0x01014004, 0x01017500, 0x01034104, 0x01034200, 0x01034A04,
+    0x01034B00, 0x0103D104, 0x0103D600, 0x0104A003, 0x0104AA00, 0x010D3003, 0x010D3A00, 0x010D4003,
+    0x010D4A00, 0x01106603, 0x01107000, 0x0110BD07, 0x0110BE00, 0x0110CD07, 0x0110CE00, 0x0110F003,
+    0x0110FA00, 0x01113603, 0x01114000, 0x0111D003, 0x0111DA00, 0x0112F003, 0x0112FA00, 0x01145003,
+    0x01145A00, 0x0114D003, 0x0114DA00, 0x01165003, 0x01165A00, 0x0116C003, 0x0116CA00, 0x0116D003,
+    0x0116E400, 0x01173003, 0x01173A00, 0x0118E003, 0x0118EA00, 0x01195003, 0x01195A00, 0x011BF003,
+    0x011BFA00, 0x011C5003, 0x011C5A00, 0x011D5003, 0x011D5A00, 0x011DA003, 0x011DAA00, 0x011DE003,
+    0x011DEA00, 0x011F5003, 0x011F5A00, 0x011FDD06, 0x011FE100, 0x01240004, 0x01246F00, 0x01343007,
+    0x01344000, 0x01613003, 0x01613A00, 0x016A6003, 0x016A6A00, 0x016AC003, 0x016ACA00, 0x016B5003,
+    0x016B5A00, 0x016D7003, 0x016D7A00, 0x016FF404, 0x016FF700, 0x01BCA007, 0x01BCA400, 0x01CCF003,
+    0x01CCFA00, 0x01D17307, 0x01D17B00, 0x01D7CE03, 0x01D80000, 0x01E14003, 0x01E14A00, 0x01E2F003,
+    0x01E2FA00, 0x01E2FF06, 0x01E30000, 0x01E4F003, 0x01E4FA00, 0x01E5F103, 0x01E5FB00, 0x01E95003,
+    0x01E95A00, 0x01ECB006, 0x01ECB100, 0x01FBF003, 0x01FBFA00, 0x0E000107, 0x0E000200, 0x0E002007,
+    0x0E008000,
+];
+
+/// A value of the Unicode Script property.
+///
+/// Most Unicode versions add new scripts, so this enum is non-exhaustive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::enum_variant_names)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Script {
+    /// `Adlm`
+    Adlam,
+    /// `Aghb`
+    CaucasianAlbanian,
+    /// `Ahom`
+    Ahom,
+    /// `Arab`
+    Arabic,
+    /// `Armi`
+    ImperialAramaic,
+    /// `Armn`
+    Armenian,
+    /// `Avst`
+    Avestan,
+    /// `Bali`
+    Balinese,
+    /// `Bamu`
+    Bamum,
+    /// `Bass`
+    BassaVah,
+    /// `Batk`
+    Batak,
+    /// `Beng`
+    Bengali,
+    /// `Berf`
+    BeriaErfe,
+    /// `Bhks`
+    Bhaiksuki,
+    /// `Bopo`
+    Bopomofo,
+    /// `Brah`
+    Brahmi,
+    /// `Brai`
+    Braille,
+    /// `Bugi`
+    Buginese,
+    /// `Buhd`
+    Buhid,
+    /// `Cakm`
+    Chakma,
+    /// `Cans`
+    CanadianAboriginal,
+    /// `Cari`
+    Carian,
+    /// `Cham`
+    Cham,
+    /// `Cher`
+    Cherokee,
+    /// `Chrs`
+    Chorasmian,
+    /// `Copt`
+    Coptic,
+    /// `Cpmn`
+    CyproMinoan,
+    /// `Cprt`
+    Cypriot,
+    /// `Cyrl`
+    Cyrillic,
+    /// `Deva`
+    Devanagari,
+    /// `Diak`
+    DivesAkuru,
+    /// `Dogr`
+    Dogra,
+    /// `Dsrt`
+    Deseret,
+    /// `Dupl`
+    Duployan,
+    /// `Egyp`
+    EgyptianHieroglyphs,
+    /// `Elba`
+    Elbasan,
+    /// `Elym`
+    Elymaic,
+    /// `Ethi`
+    Ethiopic,
+    /// `Gara`
+    Garay,
+    /// `Geor`
+    Georgian,
+    /// `Glag`
+    Glagolitic,
+    /// `Gong`
+    GunjalaGondi,
+    /// `Gonm`
+    MasaramGondi,
+    /// `Goth`
+    Gothic,
+    /// `Gran`
+    Grantha,
+    /// `Grek`
+    Greek,
+    /// `Gujr`
+    Gujarati,
+    /// `Gukh`
+    GurungKhema,
+    /// `Guru`
+    Gurmukhi,
+    /// `Hang`
+    Hangul,
+    /// `Hani`
+    Han,
+    /// `Hano`
+    Hanunoo,
+    /// `Hatr`
+    Hatran,
+    /// `Hebr`
+    Hebrew,
+    /// `Hira`
+    Hiragana,
+    /// `Hluw`
+    AnatolianHieroglyphs,
+    /// `Hmng`
+    PahawhHmong,
+    /// `Hmnp`
+    NyiakengPuachueHmong,
+    /// `Hung`
+    OldHungarian,
+    /// `Ital`
+    OldItalic,
+    /// `Java`
+    Javanese,
+    /// `Kali`
+    KayahLi,
+    /// `Kana`
+    Katakana,
+    /// `Kawi`
+    Kawi,
+    /// `Khar`
+    Kharoshthi,
+    /// `Khmr`
+    Khmer,
+    /// `Khoj`
+    Khojki,
+    /// `Kits`
+    KhitanSmallScript,
+    /// `Knda`
+    Kannada,
+    /// `Krai`
+    KiratRai,
+    /// `Kthi`
+    Kaithi,
+    /// `Lana`
+    TaiTham,
+    /// `Laoo`
+    Lao,
+    /// `Latn`
+    Latin,
+    /// `Lepc`
+    Lepcha,
+    /// `Limb`
+    Limbu,
+    /// `Lina`
+    LinearA,
+    /// `Linb`
+    LinearB,
+    /// `Lisu`
+    Lisu,
+    /// `Lyci`
+    Lycian,
+    /// `Lydi`
+    Lydian,
+    /// `Mahj`
+    Mahajani,
+    /// `Maka`
+    Makasar,
+    /// `Mand`
+    Mandaic,
+    /// `Mani`
+    Manichaean,
+    /// `Marc`
+    Marchen,
+    /// `Medf`
+    Medefaidrin,
+    /// `Mend`
+    MendeKikakui,
+    /// `Merc`
+    MeroiticCursive,
+    /// `Mero`
+    MeroiticHieroglyphs,
+    /// `Mlym`
+    Malayalam,
+    /// `Modi`
+    Modi,
+    /// `Mong`
+    Mongolian,
+    /// `Mroo`
+    Mro,
+    /// `Mtei`
+    MeeteiMayek,
+    /// `Mult`
+    Multani,
+    /// `Mymr`
+    Myanmar,
+    /// `Nagm`
+    NagMundari,
+    /// `Nand`
+    Nandinagari,
+    /// `Narb`
+    OldNorthArabian,
+    /// `Nbat`
+    Nabataean,
+    /// `Newa`
+    Newa,
+    /// `Nkoo`
+    Nko,
+    /// `Nshu`
+    Nushu,
+    /// `Ogam`
+    Ogham,
+    /// `Olck`
+    OlChiki,
+    /// `Onao`
+    OlOnal,
+    /// `Orkh`
+    OldTurkic,
+    /// `Orya`
+    Oriya,
+    /// `Osge`
+    Osage,
+    /// `Osma`
+    Osmanya,
+    /// `Ougr`
+    OldUyghur,
+    /// `Palm`
+    Palmyrene,
+    /// `Pauc`
+    PauCinHau,
+    /// `Perm`
+    OldPermic,
+    /// `Phag`
+    PhagsPa,
+    /// `Phli`
+    InscriptionalPahlavi,
+    /// `Phlp`
+    PsalterPahlavi,
+    /// `Phnx`
+    Phoenician,
+    /// `Plrd`
+    Miao,
+    /// `Prti`
+    InscriptionalParthian,
+    /// `Rjng`
+    Rejang,
+    /// `Rohg`
+    HanifiRohingya,
+    /// `Runr`
+    Runic,
+    /// `Samr`
+    Samaritan,
+    /// `Sarb`
+    OldSouthArabian,
+    /// `Saur`
+    Saurashtra,
+    /// `Sgnw`
+    SignWriting,
+    /// `Shaw`
+    Shavian,
+    /// `Shrd`
+    Sharada,
+    /// `Sidd`
+    Siddham,
+    /// `Sidt`
+    Sidetic,
+    /// `Sind`
+    Khudawadi,
+    /// `Sinh`
+    Sinhala,
+    /// `Sogd`
+    Sogdian,
+    /// `Sogo`
+    OldSogdian,
+    /// `Sora`
+    SoraSompeng,
+    /// `Soyo`
+    Soyombo,
+    /// `Sund`
+    Sundanese,
+    /// `Sunu`
+    Sunuwar,
+    /// `Sylo`
+    SylotiNagri,
+    /// `Syrc`
+    Syriac,
+    /// `Tagb`
+    Tagbanwa,
+    /// `Takr`
+    Takri,
+    /// `Tale`
+    TaiLe,
+    /// `Talu`
+    NewTaiLue,
+    /// `Taml`
+    Tamil,
+    /// `Tang`
+    Tangut,
+    /// `Tavt`
+    TaiViet,
+    /// `Tayo`
+    TaiYo,
+    /// `Telu`
+    Telugu,
+    /// `Tfng`
+    Tifinagh,
+    /// `Tglg`
+    Tagalog,
+    /// `Thaa`
+    Thaana,
+    /// `Thai`
+    Thai,
+    /// `Tibt`
+    Tibetan,
+    /// `Tirh`
+    Tirhuta,
+    /// `Tnsa`
+    Tangsa,
+    /// `Todr`
+    Todhri,
+    /// `Tols`
+    TolongSiki,
+    /// `Toto`
+    Toto,
+    /// `Tutg`
+    TuluTigalari,
+    /// `Ugar`
+    Ugaritic,
+    /// `Vaii`
+    Vai,
+    /// `Vith`
+    Vithkuqi,
+    /// `Wara`
+    WarangCiti,
+    /// `Wcho`
+    Wancho,
+    /// `Xpeo`
+    OldPersian,
+    /// `Xsux`
+    Cuneiform,
+    /// `Yezi`
+    Yezidi,
+    /// `Yiii`
+    Yi,
+    /// `Zanb`
+    ZanabazarSquare,
+    /// `Zinh`
+    Inherited,
+    /// `Zyyy`
+    Common,
+    /// `Zzzz`
+    Unknown,
+}
+
+pub(crate) static SCRIPTS: [Script; 175] = [
+    Script::Adlam,
+    Script::CaucasianAlbanian,
+    Script::Ahom,
+    Script::Arabic,
+    Script::ImperialAramaic,
+    Script::Armenian,
+    Script::Avestan,
+    Script::Balinese,
+    Script::Bamum,
+    Script::BassaVah,
+    Script::Batak,
+    Script::Bengali,
+    Script::BeriaErfe,
+    Script::Bhaiksuki,
+    Script::Bopomofo,
+    Script::Brahmi,
+    Script::Braille,
+    Script::Buginese,
+    Script::Buhid,
+    Script::Chakma,
+    Script::CanadianAboriginal,
+    Script::Carian,
+    Script::Cham,
+    Script::Cherokee,
+    Script::Chorasmian,
+    Script::Coptic,
+    Script::CyproMinoan,
+    Script::Cypriot,
+    Script::Cyrillic,
+    Script::Devanagari,
+    Script::DivesAkuru,
+    Script::Dogra,
+    Script::Deseret,
+    Script::Duployan,
+    Script::EgyptianHieroglyphs,
+    Script::Elbasan,
+    Script::Elymaic,
+    Script::Ethiopic,
+    Script::Garay,
+    Script::Georgian,
+    Script::Glagolitic,
+    Script::GunjalaGondi,
+    Script::MasaramGondi,
+    Script::Gothic,
+    Script::Grantha,
+    Script::Greek,
+    Script::Gujarati,
+    Script::GurungKhema,
+    Script::Gurmukhi,
+    Script::Hangul,
+    Script::Han,
+    Script::Hanunoo,
+    Script::Hatran,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::AnatolianHieroglyphs,
+    Script::PahawhHmong,
+    Script::NyiakengPuachueHmong,
+    Script::OldHungarian,
+    Script::OldItalic,
+    Script::Javanese,
+    Script::KayahLi,
+    Script::Katakana,
+    Script::Kawi,
+    Script::Kharoshthi,
+    Script::Khmer,
+    Script::Khojki,
+    Script::KhitanSmallScript,
+    Script::Kannada,
+    Script::KiratRai,
+    Script::Kaithi,
+    Script::TaiTham,
+    Script::Lao,
+    Script::Latin,
+    Script::Lepcha,
+    Script::Limbu,
+    Script::LinearA,
+    Script::LinearB,
+    Script::Lisu,
+    Script::Lycian,
+    Script::Lydian,
+    Script::Mahajani,
+    Script::Makasar,
+    Script::Mandaic,
+    Script::Manichaean,
+    Script::Marchen,
+    Script::Medefaidrin,
+    Script::MendeKikakui,
+    Script::MeroiticCursive,
+    Script::MeroiticHieroglyphs,
+    Script::Malayalam,
+    Script::Modi,
+    Script::Mongolian,
+    Script::Mro,
+    Script::MeeteiMayek,
+    Script::Multani,
+    Script::Myanmar,
+    Script::NagMundari,
+    Script::Nandinagari,
+    Script::OldNorthArabian,
+    Script::Nabataean,
+    Script::Newa,
+    Script::Nko,
+    Script::Nushu,
+    Script::Ogham,
+    Script::OlChiki,
+    Script::OlOnal,
+    Script::OldTurkic,
+    Script::Oriya,
+    Script::Osage,
+    Script::Osmanya,
+    Script::OldUyghur,
+    Script::Palmyrene,
+    Script::PauCinHau,
+    Script::OldPermic,
+    Script::PhagsPa,
+    Script::InscriptionalPahlavi,
+    Script::PsalterPahlavi,
+    Script::Phoenician,
+    Script::Miao,
+    Script::InscriptionalParthian,
+    Script::Rejang,
+    Script::HanifiRohingya,
+    Script::Runic,
+    Script::Samaritan,
+    Script::OldSouthArabian,
+    Script::Saurashtra,
+    Script::SignWriting,
+    Script::Shavian,
+    Script::Sharada,
+    Script::Siddham,
+    Script::Sidetic,
+    Script::Khudawadi,
+    Script::Sinhala,
+    Script::Sogdian,
+    Script::OldSogdian,
+    Script::SoraSompeng,
+    Script::Soyombo,
+    Script::Sundanese,
+    Script::Sunuwar,
+    Script::SylotiNagri,
+    Script::Syriac,
+    Script::Tagbanwa,
+    Script::Takri,
+    Script::TaiLe,
+    Script::NewTaiLue,
+    Script::Tamil,
+    Script::Tangut,
+    Script::TaiViet,
+    Script::TaiYo,
+    Script::Telugu,
+    Script::Tifinagh,
+    Script::Tagalog,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+    Script::Tirhuta,
+    Script::Tangsa,
+    Script::Todhri,
+    Script::TolongSiki,
+    Script::Toto,
+    Script::TuluTigalari,
+    Script::Ugaritic,
+    Script::Vai,
+    Script::Vithkuqi,
+    Script::WarangCiti,
+    Script::Wancho,
+    Script::OldPersian,
+    Script::Cuneiform,
+    Script::Yezidi,
+    Script::Yi,
+    Script::ZanabazarSquare,
+    Script::Inherited,
+    Script::Common,
+    Script::Unknown,
+];
+
+pub(crate) static SCRIPT_NAMES: [(&str, &str); 175] = [
+    ("Adlm", "Adlam"),
+    ("Aghb", "Caucasian_Albanian"),
+    ("Ahom", "Ahom"),
+    ("Arab", "Arabic"),
+    ("Armi", "Imperial_Aramaic"),
+    ("Armn", "Armenian"),
+    ("Avst", "Avestan"),
+    ("Bali", "Balinese"),
+    ("Bamu", "Bamum"),
+    ("Bass", "Bassa_Vah"),
+    ("Batk", "Batak"),
+    ("Beng", "Bengali"),
+    ("Berf", "Beria_Erfe"),
+    ("Bhks", "Bhaiksuki"),
+    ("Bopo", "Bopomofo"),
+    ("Brah", "Brahmi"),
+    ("Brai", "Braille"),
+    ("Bugi", "Buginese"),
+    ("Buhd", "Buhid"),
+    ("Cakm", "Chakma"),
+    ("Cans", "Canadian_Aboriginal"),
+    ("Cari", "Carian"),
+    ("Cham", "Cham"),
+    ("Cher", "Cherokee"),
+    ("Chrs", "Chorasmian"),
+    ("Copt", "Coptic"),
+    ("Cpmn", "Cypro_Minoan"),
+    ("Cprt", "Cypriot"),
+    ("Cyrl", "Cyrillic"),
+    ("Deva", "Devanagari"),
+    ("Diak", "Dives_Akuru"),
+    ("Dogr", "Dogra"),
+    ("Dsrt", "Deseret"),
+    ("Dupl", "Duployan"),
+    ("Egyp", "Egyptian_Hieroglyphs"),
+    ("Elba", "Elbasan"),
+    ("Elym", "Elymaic"),
+    ("Ethi", "Ethiopic"),
+    ("Gara", "Garay"),
+    ("Geor", "Georgian"),
+    ("Glag", "Glagolitic"),
+    ("Gong", "Gunjala_Gondi"),
+    ("Gonm", "Masaram_Gondi"),
+    ("Goth", "Gothic"),
+    ("Gran", "Grantha"),
+    ("Grek", "Greek"),
+    ("Gujr", "Gujarati"),
+    ("Gukh", "Gurung_Khema"),
+    ("Guru", "Gurmukhi"),
+    ("Hang", "Hangul"),
+    ("Hani", "Han"),
+    ("Hano", "Hanunoo"),
+    ("Hatr", "Hatran"),
+    ("Hebr", "Hebrew"),
+    ("Hira", "Hiragana"),
+    ("Hluw", "Anatolian_Hieroglyphs"),
+    ("Hmng", "Pahawh_Hmong"),
+    ("Hmnp", "Nyiakeng_Puachue_Hmong"),
+    ("Hung", "Old_Hungarian"),
+    ("Ital", "Old_Italic"),
+    ("Java", "Javanese"),
+    ("Kali", "Kayah_Li"),
+    ("Kana", "Katakana"),
+    ("Kawi", "Kawi"),
+    ("Khar", "Kharoshthi"),
+    ("Khmr", "Khmer"),
+    ("Khoj", "Khojki"),
+    ("Kits", "Khitan_Small_Script"),
+    ("Knda", "Kannada"),
+    ("Krai", "Kirat_Rai"),
+    ("Kthi", "Kaithi"),
+    ("Lana", "Tai_Tham"),
+    ("Laoo", "Lao"),
+    ("Latn", "Latin"),
+    ("Lepc", "Lepcha"),
+    ("Limb", "Limbu"),
+    ("Lina", "Linear_A"),
+    ("Linb", "Linear_B"),
+    ("Lisu", "Lisu"),
+    ("Lyci", "Lycian"),
+    ("Lydi", "Lydian"),
+    ("Mahj", "Mahajani"),
+    ("Maka", "Makasar"),
+    ("Mand", "Mandaic"),
+    ("Mani", "Manichaean"),
+    ("Marc", "Marchen"),
+    ("Medf", "Medefaidrin"),
+    ("Mend", "Mende_Kikakui"),
+    ("Merc", "Meroitic_Cursive"),
+    ("Mero", "Meroitic_Hieroglyphs"),
+    ("Mlym", "Malayalam"),
+    ("Modi", "Modi"),
+    ("Mong", "Mongolian"),
+    ("Mroo", "Mro"),
+    ("Mtei", "Meetei_Mayek"),
+    ("Mult", "Multani"),
+    ("Mymr", "Myanmar"),
+    ("Nagm", "Nag_Mundari"),
+    ("Nand", "Nandinagari"),
+    ("Narb", "Old_North_Arabian"),
+    ("Nbat", "Nabataean"),
+    ("Newa", "Newa"),
+    ("Nkoo", "Nko"),
+    ("Nshu", "Nushu"),
+    ("Ogam", "Ogham"),
+    ("Olck", "Ol_Chiki"),
+    ("Onao", "Ol_Onal"),
+    ("Orkh", "Old_Turkic"),
+    ("Orya", "Oriya"),
+    ("Osge", "Osage"),
+    ("Osma", "Osmanya"),
+    ("Ougr", "Old_Uyghur"),
+    ("Palm", "Palmyrene"),
+    ("Pauc", "Pau_Cin_Hau"),
+    ("Perm", "Old_Permic"),
+    ("Phag", "Phags_Pa"),
+    ("Phli", "Inscriptional_Pahlavi"),
+    ("Phlp", "Psalter_Pahlavi"),
+    ("Phnx", "Phoenician"),
+    ("Plrd", "Miao"),
+    ("Prti", "Inscriptional_Parthian"),
+    ("Rjng", "Rejang"),
+    ("Rohg", "Hanifi_Rohingya"),
+    ("Runr", "Runic"),
+    ("Samr", "Samaritan"),
+    ("Sarb", "Old_South_Arabian"),
+    ("Saur", "Saurashtra"),
+    ("Sgnw", "SignWriting"),
+    ("Shaw", "Shavian"),
+    ("Shrd", "Sharada"),
+    ("Sidd", "Siddham"),
+    ("Sidt", "Sidetic"),
+    ("Sind", "Khudawadi"),
+    ("Sinh", "Sinhala"),
+    ("Sogd", "Sogdian"),
+    ("Sogo", "Old_Sogdian"),
+    ("Sora", "Sora_Sompeng"),
+    ("Soyo", "Soyombo"),
+    ("Sund", "Sundanese"),
+    ("Sunu", "Sunuwar"),
+    ("Sylo", "Syloti_Nagri"),
+    ("Syrc", "Syriac"),
+    ("Tagb", "Tagbanwa"),
+    ("Takr", "Takri"),
+    ("Tale", "Tai_Le"),
+    ("Talu", "New_Tai_Lue"),
+    ("Taml", "Tamil"),
+    ("Tang", "Tangut"),
+    ("Tavt", "Tai_Viet"),
+    ("Tayo", "Tai_Yo"),
+    ("Telu", "Telugu"),
+    ("Tfng", "Tifinagh"),
+    ("Tglg", "Tagalog"),
+    ("Thaa", "Thaana"),
+    ("Thai", "Thai"),
+    ("Tibt", "Tibetan"),
+    ("Tirh", "Tirhuta"),
+    ("Tnsa", "Tangsa"),
+    ("Todr", "Todhri"),
+    ("Tols", "Tolong_Siki"),
+    ("Toto", "Toto"),
+    ("Tutg", "Tulu_Tigalari"),
+    ("Ugar", "Ugaritic"),
+    ("Vaii", "Vai"),
+    ("Vith", "Vithkuqi"),
+    ("Wara", "Warang_Citi"),
+    ("Wcho", "Wancho"),
+    ("Xpeo", "Old_Persian"),
+    ("Xsux", "Cuneiform"),
+    ("Yezi", "Yezidi"),
+    ("Yiii", "Yi"),
+    ("Zanb", "Zanabazar_Square"),
+    ("Zinh", "Inherited"),
+    ("Zyyy", "Common"),
+    ("Zzzz", "Unknown"),
+];
+
+pub(crate) const SCRIPT_COUNT: usize = 175;
+pub(crate) const SCRIPT_WORDS: usize = 3;
+
+pub(crate) static SCRIPT_RUNS: [u32; 1717] = [
+    0x000000AD, 0x00004149, 0x00005BAD, 0x00006149, 0x00007BAD, 0x0000AA49, 0x0000ABAD, 0x0000BA49,
+    0x0000BBAD, 0x0000C049, 0x0000D7AD, 0x0000D849, 0x0000F7AD, 0x0000F849, 0x0002B9AD, 0x0002E049,
+    0x0002E5AD, 0x0002EA0E, 0x0002ECAD, 0x000300AC, 0x0003702D, 0x000374AD, 0x0003752D, 0x000378AE,
+    0x00037A2D, 0x00037EAD, 0x00037F2D, 0x000380AE, 0x0003842D, 0x000385AD, 0x0003862D, 0x000387AD,
+    0x0003882D, 0x00038BAE, 0x00038C2D, 0x00038DAE, 0x00038E2D, 0x0003A2AE, 0x0003A32D, 0x0003E219,
+    0x0003F02D, 0x0004001C, 0x000485AC, 0x0004871C, 0x000530AE, 0x00053105, 0x000557AE, 0x00055905,
+    0x00058BAE, 0x00058D05, 0x000590AE, 0x00059135, 0x0005C8AE, 0x0005D035, 0x0005EBAE, 0x0005EF35,
+    0x0005F5AE, 0x00060003, 0x000605AD, 0x00060603, 0x00060CAD, 0x00060D03, 0x00061BAD, 0x00061C03,
+    0x00061FAD, 0x00062003, 0x000640AD, 0x00064103, 0x00064BAC, 0x00065603, 0x000670AC, 0x00067103,
+    0x0006DDAD, 0x0006DE03, 0x0007008D, 0x00070EAE, 0x00070F8D, 0x00074BAE, 0x00074D8D, 0x00075003,
+    0x00078099, 0x0007B2AE, 0x0007C066, 0x0007FBAE, 0x0007FD66, 0x0008007C, 0x00082EAE, 0x0008307C,
+    0x00083FAE, 0x00084053, 0x00085CAE, 0x00085E53, 0x00085FAE, 0x0008608D, 0x00086BAE, 0x00087003,
+    0x000892AE, 0x00089703, 0x0008E2AD, 0x0008E303, 0x0009001D, 0x000951AC, 0x0009551D, 0x000964AD,
+    0x0009661D, 0x0009800B, 0x000984AE, 0x0009850B, 0x00098DAE, 0x00098F0B, 0x000991AE, 0x0009930B,
+    0x0009A9AE, 0x0009AA0B, 0x0009B1AE, 0x0009B20B, 0x0009B3AE, 0x0009B60B, 0x0009BAAE, 0x0009BC0B,
+    0x0009C5AE, 0x0009C70B, 0x0009C9AE, 0x0009CB0B, 0x0009CFAE, 0x0009D70B, 0x0009D8AE, 0x0009DC0B,
+    0x0009DEAE, 0x0009DF0B, 0x0009E4AE, 0x0009E60B, 0x0009FFAE, 0x000A0130, 0x000A04AE, 0x000A0530,
+    0x000A0BAE, 0x000A0F30, 0x000A11AE, 0x000A1330, 0x000A29AE, 0x000A2A30, 0x000A31AE, 0x000A3230,
+    0x000A34AE, 0x000A3530, 0x000A37AE, 0x000A3830, 0x000A3AAE, 0x000A3C30, 0x000A3DAE, 0x000A3E30,
+    0x000A43AE, 0x000A4730, 0x000A49AE, 0x000A4B30, 0x000A4EAE, 0x000A5130, 0x000A52AE, 0x000A5930,
+    0x000A5DAE, 0x000A5E30, 0x000A5FAE, 0x000A6630, 0x000A77AE, 0x000A812E, 0x000A84AE, 0x000A852E,
+    0x000A8EAE, 0x000A8F2E, 0x000A92AE, 0x000A932E, 0x000AA9AE, 0x000AAA2E, 0x000AB1AE, 0x000AB22E,
+    0x000AB4AE, 0x000AB52E, 0x000ABAAE, 0x000ABC2E, 0x000AC6AE, 0x000AC72E, 0x000ACAAE, 0x000ACB2E,
+    0x000ACEAE, 0x000AD02E, 0x000AD1AE, 0x000AE02E, 0x000AE4AE, 0x000AE62E, 0x000AF2AE, 0x000AF92E,
+    0x000B00AE, 0x000B016C, 0x000B04AE, 0x000B056C, 0x000B0DAE, 0x000B0F6C, 0x000B11AE, 0x000B136C,
+    0x000B29AE, 0x000B2A6C, 0x000B31AE, 0x000B326C, 0x000B34AE, 0x000B356C, 0x000B3AAE, 0x000B3C6C,
+    0x000B45AE, 0x000B476C, 0x000B49AE, 0x000B4B6C, 0x000B4EAE, 0x000B556C, 0x000B58AE, 0x000B5C6C,
+    0x000B5EAE, 0x000B5F6C, 0x000B64AE, 0x000B666C, 0x000B78AE, 0x000B8292, 0x000B84AE, 0x000B8592,
+    0x000B8BAE, 0x000B8E92, 0x000B91AE, 0x000B9292, 0x000B96AE, 0x000B9992, 0x000B9BAE, 0x000B9C92,
+    0x000B9DAE, 0x000B9E92, 0x000BA0AE, 0x000BA392, 0x000BA5AE, 0x000BA892, 0x000BABAE, 0x000BAE92,
+    0x000BBAAE, 0x000BBE92, 0x000BC3AE, 0x000BC692, 0x000BC9AE, 0x000BCA92, 0x000BCEAE, 0x000BD092,
+    0x000BD1AE, 0x000BD792, 0x000BD8AE, 0x000BE692, 0x000BFBAE, 0x000C0096, 0x000C0DAE, 0x000C0E96,
+    0x000C11AE, 0x000C1296, 0x000C29AE, 0x000C2A96, 0x000C3AAE, 0x000C3C96, 0x000C45AE, 0x000C4696,
+    0x000C49AE, 0x000C4A96, 0x000C4EAE, 0x000C5596, 0x000C57AE, 0x000C5896, 0x000C5BAE, 0x000C5C96,
+    0x000C5EAE, 0x000C6096, 0x000C64AE, 0x000C6696, 0x000C70AE, 0x000C7796, 0x000C8044, 0x000C8DAE,
+    0x000C8E44, 0x000C91AE, 0x000C9244, 0x000CA9AE, 0x000CAA44, 0x000CB4AE, 0x000CB544, 0x000CBAAE,
+    0x000CBC44, 0x000CC5AE, 0x000CC644, 0x000CC9AE, 0x000CCA44, 0x000CCEAE, 0x000CD544, 0x000CD7AE,
+    0x000CDC44, 0x000CDFAE, 0x000CE044, 0x000CE4AE, 0x000CE644, 0x000CF0AE, 0x000CF144, 0x000CF4AE,
+    0x000D005A, 0x000D0DAE, 0x000D0E5A, 0x000D11AE, 0x000D125A, 0x000D45AE, 0x000D465A, 0x000D49AE,
+    0x000D4A5A, 0x000D50AE, 0x000D545A, 0x000D64AE, 0x000D665A, 0x000D80AE, 0x000D8185, 0x000D84AE,
+    0x000D8585, 0x000D97AE, 0x000D9A85, 0x000DB2AE, 0x000DB385, 0x000DBCAE, 0x000DBD85, 0x000DBEAE,
+    0x000DC085, 0x000DC7AE, 0x000DCA85, 0x000DCBAE, 0x000DCF85, 0x000DD5AE, 0x000DD685, 0x000DD7AE,
+    0x000DD885, 0x000DE0AE, 0x000DE685, 0x000DF0AE, 0x000DF285, 0x000DF5AE, 0x000E019A, 0x000E3BAE,
+    0x000E3FAD, 0x000E409A, 0x000E5CAE, 0x000E8148, 0x000E83AE, 0x000E8448, 0x000E85AE, 0x000E8648,
+    0x000E8BAE, 0x000E8C48, 0x000EA4AE, 0x000EA548, 0x000EA6AE, 0x000EA748, 0x000EBEAE, 0x000EC048,
+    0x000EC5AE, 0x000EC648, 0x000EC7AE, 0x000EC848, 0x000ECFAE, 0x000ED048, 0x000EDAAE, 0x000EDC48,
+    0x000EE0AE, 0x000F009B, 0x000F48AE, 0x000F499B, 0x000F6DAE, 0x000F719B, 0x000F98AE, 0x000F999B,
+    0x000FBDAE, 0x000FBE9B, 0x000FCDAE, 0x000FCE9B, 0x000FD5AD, 0x000FD99B, 0x000FDBAE, 0x00100060,
+    0x0010A027, 0x0010C6AE, 0x0010C727, 0x0010C8AE, 0x0010CD27, 0x0010CEAE, 0x0010D027, 0x0010FBAD,
+    0x0010FC27, 0x00110031, 0x00120025, 0x001249AE, 0x00124A25, 0x00124EAE, 0x00125025, 0x001257AE,
+    0x00125825, 0x001259AE, 0x00125A25, 0x00125EAE, 0x00126025, 0x001289AE, 0x00128A25, 0x00128EAE,
+    0x00129025, 0x0012B1AE, 0x0012B225, 0x0012B6AE, 0x0012B825, 0x0012BFAE, 0x0012C025, 0x0012C1AE,
+    0x0012C225, 0x0012C6AE, 0x0012C825, 0x0012D7AE, 0x0012D825, 0x001311AE, 0x00131225, 0x001316AE,
+    0x00131825, 0x00135BAE, 0x00135D25, 0x00137DAE, 0x00138025, 0x00139AAE, 0x0013A017, 0x0013F6AE,
+    0x0013F817, 0x0013FEAE, 0x00140014, 0x00168068, 0x00169DAE, 0x0016A07B, 0x0016EBAD, 0x0016EE7B,
+    0x0016F9AE, 0x00170098, 0x001716AE, 0x00171F98, 0x00172033, 0x001735AD, 0x001737AE, 0x00174012,
+    0x001754AE, 0x0017608E, 0x00176DAE, 0x00176E8E, 0x001771AE, 0x0017728E, 0x001774AE, 0x00178041,
+    0x0017DEAE, 0x0017E041, 0x0017EAAE, 0x0017F041, 0x0017FAAE, 0x0018005C, 0x001802AD, 0x0018045C,
+    0x001805AD, 0x0018065C, 0x00181AAE, 0x0018205C, 0x001879AE, 0x0018805C, 0x0018ABAE, 0x0018B014,
+    0x0018F6AE, 0x0019004B, 0x00191FAE, 0x0019204B, 0x00192CAE, 0x0019304B, 0x00193CAE, 0x0019404B,
+    0x001941AE, 0x0019444B, 0x00195090, 0x00196EAE, 0x00197090, 0x001975AE, 0x00198091, 0x0019ACAE,
+    0x0019B091, 0x0019CAAE, 0x0019D091, 0x0019DBAE, 0x0019DE91, 0x0019E041, 0x001A0011, 0x001A1CAE,
+    0x001A1E11, 0x001A2047, 0x001A5FAE, 0x001A6047, 0x001A7DAE, 0x001A7F47, 0x001A8AAE, 0x001A9047,
+    0x001A9AAE, 0x001AA047, 0x001AAEAE, 0x001AB0AC, 0x001ADEAE, 0x001AE0AC, 0x001AECAE, 0x001B0007,
+    0x001B4DAE, 0x001B4E07, 0x001B808A, 0x001BC00A, 0x001BF4AE, 0x001BFC0A, 0x001C004A, 0x001C38AE,
+    0x001C3B4A, 0x001C4AAE, 0x001C4D4A, 0x001C5069, 0x001C801C, 0x001C8BAE, 0x001C9027, 0x001CBBAE,
+    0x001CBD27, 0x001CC08A, 0x001CC8AE, 0x001CD0AC, 0x001CD3AD, 0x001CD4AC, 0x001CE1AD, 0x001CE2AC,
+    0x001CE9AD, 0x001CEDAC, 0x001CEEAD, 0x001CF4AC, 0x001CF5AD, 0x001CF8AC, 0x001CFAAD, 0x001CFBAE,
+    0x001D0049, 0x001D262D, 0x001D2B1C, 0x001D2C49, 0x001D5D2D, 0x001D6249, 0x001D662D, 0x001D6B49,
+    0x001D781C, 0x001D7949, 0x001DBF2D, 0x001DC0AC, 0x001E0049, 0x001F002D, 0x001F16AE, 0x001F182D,
+    0x001F1EAE, 0x001F202D, 0x001F46AE, 0x001F482D, 0x001F4EAE, 0x001F502D, 0x001F58AE, 0x001F592D,
+    0x001F5AAE, 0x001F5B2D, 0x001F5CAE, 0x001F5D2D, 0x001F5EAE, 0x001F5F2D, 0x001F7EAE, 0x001F802D,
+    0x001FB5AE, 0x001FB62D, 0x001FC5AE, 0x001FC62D, 0x001FD4AE, 0x001FD62D, 0x001FDCAE, 0x001FDD2D,
+    0x001FF0AE, 0x001FF22D, 0x001FF5AE, 0x001FF62D, 0x001FFFAE, 0x002000AD, 0x00200CAC, 0x00200EAD,
+    0x002065AE, 0x002066AD, 0x00207149, 0x002072AE, 0x002074AD, 0x00207F49, 0x002080AD, 0x00208FAE,
+    0x00209049, 0x00209DAE, 0x0020A0AD, 0x0020C2AE, 0x0020D0AC, 0x0020F1AE, 0x002100AD, 0x0021262D,
+    0x002127AD, 0x00212A49, 0x00212CAD, 0x00213249, 0x002133AD, 0x00214E49, 0x00214FAD, 0x00216049,
+    0x002189AD, 0x00218CAE, 0x002190AD, 0x00242AAE, 0x002440AD, 0x00244BAE, 0x002460AD, 0x00280010,
+    0x002900AD, 0x002B74AE, 0x002B76AD, 0x002C0028, 0x002C6049, 0x002C8019, 0x002CF4AE, 0x002CF919,
+    0x002D0027, 0x002D26AE, 0x002D2727, 0x002D28AE, 0x002D2D27, 0x002D2EAE, 0x002D3097, 0x002D68AE,
+    0x002D6F97, 0x002D71AE, 0x002D7F97, 0x002D8025, 0x002D97AE, 0x002DA025, 0x002DA7AE, 0x002DA825,
+    0x002DAFAE, 0x002DB025, 0x002DB7AE, 0x002DB825, 0x002DBFAE, 0x002DC025, 0x002DC7AE, 0x002DC825,
+    0x002DCFAE, 0x002DD025, 0x002DD7AE, 0x002DD825, 0x002DDFAE, 0x002DE01C, 0x002E00AD, 0x002E5EAE,
+    0x002E8032, 0x002E9AAE, 0x002E9B32, 0x002EF4AE, 0x002F0032, 0x002FD6AE, 0x002FF0AD, 0x00300532,
+    0x003006AD, 0x00300732, 0x003008AD, 0x00302132, 0x00302AAC, 0x00302E31, 0x003030AD, 0x00303832,
+    0x00303CAD, 0x003040AE, 0x00304136, 0x003097AE, 0x003099AC, 0x00309BAD, 0x00309D36, 0x0030A0AD,
+    0x0030A13E, 0x0030FBAD, 0x0030FD3E, 0x003100AE, 0x0031050E, 0x003130AE, 0x00313131, 0x00318FAE,
+    0x003190AD, 0x0031A00E, 0x0031C0AD, 0x0031E6AE, 0x0031EFAD, 0x0031F03E, 0x00320031, 0x00321FAE,
+    0x003220AD, 0x00326031, 0x00327FAD, 0x0032D03E, 0x0032FFAD, 0x0033003E, 0x003358AD, 0x00340032,
+    0x004DC0AD, 0x004E0032, 0x00A000AA, 0x00A48DAE, 0x00A490AA, 0x00A4C7AE, 0x00A4D04E, 0x00A500A3,
+    0x00A62CAE, 0x00A6401C, 0x00A6A008, 0x00A6F8AE, 0x00A700AD, 0x00A72249, 0x00A788AD, 0x00A78B49,
+    0x00A7DDAE, 0x00A7F149, 0x00A8008C, 0x00A82DAE, 0x00A830AD, 0x00A83AAE, 0x00A84073, 0x00A878AE,
+    0x00A8807E, 0x00A8C6AE, 0x00A8CE7E, 0x00A8DAAE, 0x00A8E01D, 0x00A9003D, 0x00A92EAD, 0x00A92F3D,
+    0x00A93079, 0x00A954AE, 0x00A95F79, 0x00A96031, 0x00A97DAE, 0x00A9803C, 0x00A9CEAE, 0x00A9CFAD,
+    0x00A9D03C, 0x00A9DAAE, 0x00A9DE3C, 0x00A9E060, 0x00A9FFAE, 0x00AA0016, 0x00AA37AE, 0x00AA4016,
+    0x00AA4EAE, 0x00AA5016, 0x00AA5AAE, 0x00AA5C16, 0x00AA6060, 0x00AA8094, 0x00AAC3AE, 0x00AADB94,
+    0x00AAE05E, 0x00AAF7AE, 0x00AB0125, 0x00AB07AE, 0x00AB0925, 0x00AB0FAE, 0x00AB1125, 0x00AB17AE,
+    0x00AB2025, 0x00AB27AE, 0x00AB2825, 0x00AB2FAE, 0x00AB3049, 0x00AB5BAD, 0x00AB5C49, 0x00AB652D,
+    0x00AB6649, 0x00AB6AAD, 0x00AB6CAE, 0x00AB7017, 0x00ABC05E, 0x00ABEEAE, 0x00ABF05E, 0x00ABFAAE,
+    0x00AC0031, 0x00D7A4AE, 0x00D7B031, 0x00D7C7AE, 0x00D7CB31, 0x00D7FCAE, 0x00F90032, 0x00FA6EAE,
+    0x00FA7032, 0x00FADAAE, 0x00FB0049, 0x00FB07AE, 0x00FB1305, 0x00FB18AE, 0x00FB1D35, 0x00FB37AE,
+    0x00FB3835, 0x00FB3DAE, 0x00FB3E35, 0x00FB3FAE, 0x00FB4035, 0x00FB42AE, 0x00FB4335, 0x00FB45AE,
+    0x00FB4635, 0x00FB5003, 0x00FD3EAD, 0x00FD4003, 0x00FDD0AE, 0x00FDF003, 0x00FE00AC, 0x00FE10AD,
+    0x00FE1AAE, 0x00FE20AC, 0x00FE2E1C, 0x00FE30AD, 0x00FE53AE, 0x00FE54AD, 0x00FE67AE, 0x00FE68AD,
+    0x00FE6CAE, 0x00FE7003, 0x00FE75AE, 0x00FE7603, 0x00FEFDAE, 0x00FEFFAD, 0x00FF00AE, 0x00FF01AD,
+    0x00FF2149, 0x00FF3BAD, 0x00FF4149, 0x00FF5BAD, 0x00FF663E, 0x00FF70AD, 0x00FF713E, 0x00FF9EAD,
+    0x00FFA031, 0x00FFBFAE, 0x00FFC231, 0x00FFC8AE, 0x00FFCA31, 0x00FFD0AE, 0x00FFD231, 0x00FFD8AE,
+    0x00FFDA31, 0x00FFDDAE, 0x00FFE0AD, 0x00FFE7AE, 0x00FFE8AD, 0x00FFEFAE, 0x00FFF9AD, 0x00FFFEAE,
+    0x0100004D, 0x01000CAE, 0x01000D4D, 0x010027AE, 0x0100284D, 0x01003BAE, 0x01003C4D, 0x01003EAE,
+    0x01003F4D, 0x01004EAE, 0x0100504D, 0x01005EAE, 0x0100804D, 0x0100FBAE, 0x010100AD, 0x010103AE,
+    0x010107AD, 0x010134AE, 0x010137AD, 0x0101402D, 0x01018FAE, 0x010190AD, 0x01019DAE, 0x0101A02D,
+    0x0101A1AE, 0x0101D0AD, 0x0101FDAC, 0x0101FEAE, 0x0102804F, 0x01029DAE, 0x0102A015, 0x0102D1AE,
+    0x0102E0AC, 0x0102E1AD, 0x0102FCAE, 0x0103003B, 0x010324AE, 0x01032D3B, 0x0103302B, 0x01034BAE,
+    0x01035072, 0x01037BAE, 0x010380A2, 0x01039EAE, 0x01039FA2, 0x0103A0A7, 0x0103C4AE, 0x0103C8A7,
+    0x0103D6AE, 0x01040020, 0x01045080, 0x0104806E, 0x01049EAE, 0x0104A06E, 0x0104AAAE, 0x0104B06D,
+    0x0104D4AE, 0x0104D86D, 0x0104FCAE, 0x01050023, 0x010528AE, 0x01053001, 0x010564AE, 0x01056F01,
+    0x010570A4, 0x01057BAE, 0x01057CA4, 0x01058BAE, 0x01058CA4, 0x010593AE, 0x010594A4, 0x010596AE,
+    0x010597A4, 0x0105A2AE, 0x0105A3A4, 0x0105B2AE, 0x0105B3A4, 0x0105BAAE, 0x0105BBA4, 0x0105BDAE,
+    0x0105C09E, 0x0105F4AE, 0x0106004C, 0x010737AE, 0x0107404C, 0x010756AE, 0x0107604C, 0x010768AE,
+    0x01078049, 0x010786AE, 0x01078749, 0x0107B1AE, 0x0107B249, 0x0107BBAE, 0x0108001B, 0x010806AE,
+    0x0108081B, 0x010809AE, 0x01080A1B, 0x010836AE, 0x0108371B, 0x010839AE, 0x01083C1B, 0x01083DAE,
+    0x01083F1B, 0x01084004, 0x010856AE, 0x01085704, 0x01086070, 0x01088064, 0x01089FAE, 0x0108A764,
+    0x0108B0AE, 0x0108E034, 0x0108F3AE, 0x0108F434, 0x0108F6AE, 0x0108FB34, 0x01090076, 0x01091CAE,
+    0x01091F76, 0x01092050, 0x01093AAE, 0x01093F50, 0x01094083, 0x01095AAE, 0x01098059, 0x0109A058,
+    0x0109B8AE, 0x0109BC58, 0x0109D0AE, 0x0109D258, 0x010A0040, 0x010A04AE, 0x010A0540, 0x010A07AE,
+    0x010A0C40, 0x010A14AE, 0x010A1540, 0x010A18AE, 0x010A1940, 0x010A36AE, 0x010A3840, 0x010A3BAE,
+    0x010A3F40, 0x010A49AE, 0x010A5040, 0x010A59AE, 0x010A607D, 0x010A8063, 0x010AA0AE, 0x010AC054,
+    0x010AE7AE, 0x010AEB54, 0x010AF7AE, 0x010B0006, 0x010B36AE, 0x010B3906, 0x010B4078, 0x010B56AE,
+    0x010B5878, 0x010B6074, 0x010B73AE, 0x010B7874, 0x010B8075, 0x010B92AE, 0x010B9975, 0x010B9DAE,
+    0x010BA975, 0x010BB0AE, 0x010C006B, 0x010C49AE, 0x010C803A, 0x010CB3AE, 0x010CC03A, 0x010CF3AE,
+    0x010CFA3A, 0x010D007A, 0x010D28AE, 0x010D307A, 0x010D3AAE, 0x010D4026, 0x010D66AE, 0x010D6926,
+    0x010D86AE, 0x010D8E26, 0x010D90AE, 0x010E6003, 0x010E7FAE, 0x010E80A9, 0x010EAAAE, 0x010EABA9,
+    0x010EAEAE, 0x010EB0A9, 0x010EB2AE, 0x010EC203, 0x010EC8AE, 0x010ED003, 0x010ED9AE, 0x010EFA03,
+    0x010F0087, 0x010F28AE, 0x010F3086, 0x010F5AAE, 0x010F706F, 0x010F8AAE, 0x010FB018, 0x010FCCAE,
+    0x010FE024, 0x010FF7AE, 0x0110000F, 0x01104EAE, 0x0110520F, 0x011076AE, 0x01107F0F, 0x01108046,
+    0x0110C3AE, 0x0110CD46, 0x0110CEAE, 0x0110D088, 0x0110E9AE, 0x0110F088, 0x0110FAAE, 0x01110013,
+    0x011135AE, 0x01113613, 0x011148AE, 0x01115051, 0x011177AE, 0x01118081, 0x0111E0AE, 0x0111E185,
+    0x0111F5AE, 0x01120042, 0x011212AE, 0x01121342, 0x011242AE, 0x0112805F, 0x011287AE, 0x0112885F,
+    0x011289AE, 0x01128A5F, 0x01128EAE, 0x01128F5F, 0x01129EAE, 0x01129F5F, 0x0112AAAE, 0x0112B084,
+    0x0112EBAE, 0x0112F084, 0x0112FAAE, 0x0113002C, 0x011304AE, 0x0113052C, 0x01130DAE, 0x01130F2C,
+    0x011311AE, 0x0113132C, 0x011329AE, 0x01132A2C, 0x011331AE, 0x0113322C, 0x011334AE, 0x0113352C,
+    0x01133AAE, 0x01133BAC, 0x01133C2C, 0x011345AE, 0x0113472C, 0x011349AE, 0x01134B2C, 0x01134EAE,
+    0x0113502C, 0x011351AE, 0x0113572C, 0x011358AE, 0x01135D2C, 0x011364AE, 0x0113662C, 0x01136DAE,
+    0x0113702C, 0x011375AE, 0x011380A1, 0x01138AAE, 0x01138BA1, 0x01138CAE, 0x01138EA1, 0x01138FAE,
+    0x011390A1, 0x0113B6AE, 0x0113B7A1, 0x0113C1AE, 0x0113C2A1, 0x0113C3AE, 0x0113C5A1, 0x0113C6AE,
+    0x0113C7A1, 0x0113CBAE, 0x0113CCA1, 0x0113D6AE, 0x0113D7A1, 0x0113D9AE, 0x0113E1A1, 0x0113E3AE,
+    0x01140065, 0x01145CAE, 0x01145D65, 0x011462AE, 0x0114809C, 0x0114C8AE, 0x0114D09C, 0x0114DAAE,
+    0x01158082, 0x0115B6AE, 0x0115B882, 0x0115DEAE, 0x0116005B, 0x011645AE, 0x0116505B, 0x01165AAE,
+    0x0116605C, 0x01166DAE, 0x0116808F, 0x0116BAAE, 0x0116C08F, 0x0116CAAE, 0x0116D060, 0x0116E4AE,
+    0x01170002, 0x01171BAE, 0x01171D02, 0x01172CAE, 0x01173002, 0x011747AE, 0x0118001F, 0x01183CAE,
+    0x0118A0A5, 0x0118F3AE, 0x0118FFA5, 0x0119001E, 0x011907AE, 0x0119091E, 0x01190AAE, 0x01190C1E,
+    0x011914AE, 0x0119151E, 0x011917AE, 0x0119181E, 0x011936AE, 0x0119371E, 0x011939AE, 0x01193B1E,
+    0x011947AE, 0x0119501E, 0x01195AAE, 0x0119A062, 0x0119A8AE, 0x0119AA62, 0x0119D8AE, 0x0119DA62,
+    0x0119E5AE, 0x011A00AB, 0x011A48AE, 0x011A5089, 0x011AA3AE, 0x011AB014, 0x011AC071, 0x011AF9AE,
+    0x011B001D, 0x011B0AAE, 0x011B6081, 0x011B68AE, 0x011BC08B, 0x011BE2AE, 0x011BF08B, 0x011BFAAE,
+    0x011C000D, 0x011C09AE, 0x011C0A0D, 0x011C37AE, 0x011C380D, 0x011C46AE, 0x011C500D, 0x011C6DAE,
+    0x011C7055, 0x011C90AE, 0x011C9255, 0x011CA8AE, 0x011CA955, 0x011CB7AE, 0x011D002A, 0x011D07AE,
+    0x011D082A, 0x011D0AAE, 0x011D0B2A, 0x011D37AE, 0x011D3A2A, 0x011D3BAE, 0x011D3C2A, 0x011D3EAE,
+    0x011D3F2A, 0x011D48AE, 0x011D502A, 0x011D5AAE, 0x011D6029, 0x011D66AE, 0x011D6729, 0x011D69AE,
+    0x011D6A29, 0x011D8FAE, 0x011D9029, 0x011D92AE, 0x011D9329, 0x011D99AE, 0x011DA029, 0x011DAAAE,
+    0x011DB09F, 0x011DDCAE, 0x011DE09F, 0x011DEAAE, 0x011EE052, 0x011EF9AE, 0x011F003F, 0x011F11AE,
+    0x011F123F, 0x011F3BAE, 0x011F3E3F, 0x011F5BAE, 0x011FB04E, 0x011FB1AE, 0x011FC092, 0x011FF2AE,
+    0x011FFF92, 0x012000A8, 0x01239AAE, 0x012400A8, 0x01246FAE, 0x012470A8, 0x012475AE, 0x012480A8,
+    0x012544AE, 0x012F901A, 0x012FF3AE, 0x01300022, 0x013456AE, 0x01346022, 0x0143FBAE, 0x01440037,
+    0x014647AE, 0x0161002F, 0x01613AAE, 0x01680008, 0x016A39AE, 0x016A405D, 0x016A5FAE, 0x016A605D,
+    0x016A6AAE, 0x016A6E5D, 0x016A709D, 0x016ABFAE, 0x016AC09D, 0x016ACAAE, 0x016AD009, 0x016AEEAE,
+    0x016AF009, 0x016AF6AE, 0x016B0038, 0x016B46AE, 0x016B5038, 0x016B5AAE, 0x016B5B38, 0x016B62AE,
+    0x016B6338, 0x016B78AE, 0x016B7D38, 0x016B90AE, 0x016D4045, 0x016D7AAE, 0x016E4056, 0x016E9BAE,
+    0x016EA00C, 0x016EB9AE, 0x016EBB0C, 0x016ED4AE, 0x016F0077, 0x016F4BAE, 0x016F4F77, 0x016F88AE,
+    0x016F8F77, 0x016FA0AE, 0x016FE093, 0x016FE167, 0x016FE232, 0x016FE443, 0x016FE5AE, 0x016FF032,
+    0x016FF7AE, 0x01700093, 0x018B0043, 0x018CD6AE, 0x018CFF43, 0x018D0093, 0x018D1FAE, 0x018D8093,
+    0x018DF3AE, 0x01AFF03E, 0x01AFF4AE, 0x01AFF53E, 0x01AFFCAE, 0x01AFFD3E, 0x01AFFFAE, 0x01B0003E,
+    0x01B00136, 0x01B1203E, 0x01B123AE, 0x01B13236, 0x01B133AE, 0x01B15036, 0x01B153AE, 0x01B1553E,
+    0x01B156AE, 0x01B1643E, 0x01B168AE, 0x01B17067, 0x01B2FCAE, 0x01BC0021, 0x01BC6BAE, 0x01BC7021,
+    0x01BC7DAE, 0x01BC8021, 0x01BC89AE, 0x01BC9021, 0x01BC9AAE, 0x01BC9C21, 0x01BCA0AD, 0x01BCA4AE,
+    0x01CC00AD, 0x01CCFDAE, 0x01CD00AD, 0x01CEB4AE, 0x01CEBAAD, 0x01CED1AE, 0x01CEE0AD, 0x01CEF1AE,
+    0x01CF00AC, 0x01CF2EAE, 0x01CF30AC, 0x01CF47AE, 0x01CF50AD, 0x01CFC4AE, 0x01D000AD, 0x01D0F6AE,
+    0x01D100AD, 0x01D127AE, 0x01D129AD, 0x01D167AC, 0x01D16AAD, 0x01D17BAC, 0x01D183AD, 0x01D185AC,
+    0x01D18CAD, 0x01D1AAAC, 0x01D1AEAD, 0x01D1EBAE, 0x01D2002D, 0x01D246AE, 0x01D2C0AD, 0x01D2D4AE,
+    0x01D2E0AD, 0x01D2F4AE, 0x01D300AD, 0x01D357AE, 0x01D360AD, 0x01D379AE, 0x01D400AD, 0x01D455AE,
+    0x01D456AD, 0x01D49DAE, 0x01D49EAD, 0x01D4A0AE, 0x01D4A2AD, 0x01D4A3AE, 0x01D4A5AD, 0x01D4A7AE,
+    0x01D4A9AD, 0x01D4ADAE, 0x01D4AEAD, 0x01D4BAAE, 0x01D4BBAD, 0x01D4BCAE, 0x01D4BDAD, 0x01D4C4AE,
+    0x01D4C5AD, 0x01D506AE, 0x01D507AD, 0x01D50BAE, 0x01D50DAD, 0x01D515AE, 0x01D516AD, 0x01D51DAE,
+    0x01D51EAD, 0x01D53AAE, 0x01D53BAD, 0x01D53FAE, 0x01D540AD, 0x01D545AE, 0x01D546AD, 0x01D547AE,
+    0x01D54AAD, 0x01D551AE, 0x01D552AD, 0x01D6A6AE, 0x01D6A8AD, 0x01D7CCAE, 0x01D7CEAD, 0x01D8007F,
+    0x01DA8CAE, 0x01DA9B7F, 0x01DAA0AE, 0x01DAA17F, 0x01DAB0AE, 0x01DF0049, 0x01DF1FAE, 0x01DF2549,
+    0x01DF2BAE, 0x01E00028, 0x01E007AE, 0x01E00828, 0x01E019AE, 0x01E01B28, 0x01E022AE, 0x01E02328,
+    0x01E025AE, 0x01E02628, 0x01E02BAE, 0x01E0301C, 0x01E06EAE, 0x01E08F1C, 0x01E090AE, 0x01E10039,
+    0x01E12DAE, 0x01E13039, 0x01E13EAE, 0x01E14039, 0x01E14AAE, 0x01E14E39, 0x01E150AE, 0x01E290A0,
+    0x01E2AFAE, 0x01E2C0A6, 0x01E2FAAE, 0x01E2FFA6, 0x01E300AE, 0x01E4D061, 0x01E4FAAE, 0x01E5D06A,
+    0x01E5FBAE, 0x01E5FF6A, 0x01E600AE, 0x01E6C095, 0x01E6DFAE, 0x01E6E095, 0x01E6F6AE, 0x01E6FE95,
+    0x01E700AE, 0x01E7E025, 0x01E7E7AE, 0x01E7E825, 0x01E7ECAE, 0x01E7ED25, 0x01E7EFAE, 0x01E7F025,
+    0x01E7FFAE, 0x01E80057, 0x01E8C5AE, 0x01E8C757, 0x01E8D7AE, 0x01E90000, 0x01E94CAE, 0x01E95000,
+    0x01E95AAE, 0x01E95E00, 0x01E960AE, 0x01EC71AD, 0x01ECB5AE, 0x01ED01AD, 0x01ED3EAE, 0x01EE0003,
+    0x01EE04AE, 0x01EE0503, 0x01EE20AE, 0x01EE2103, 0x01EE23AE, 0x01EE2403, 0x01EE25AE, 0x01EE2703,
+    0x01EE28AE, 0x01EE2903, 0x01EE33AE, 0x01EE3403, 0x01EE38AE, 0x01EE3903, 0x01EE3AAE, 0x01EE3B03,
+    0x01EE3CAE, 0x01EE4203, 0x01EE43AE, 0x01EE4703, 0x01EE48AE, 0x01EE4903, 0x01EE4AAE, 0x01EE4B03,
+    0x01EE4CAE, 0x01EE4D03, 0x01EE50AE, 0x01EE5103, 0x01EE53AE, 0x01EE5403, 0x01EE55AE, 0x01EE5703,
+    0x01EE58AE, 0x01EE5903, 0x01EE5AAE, 0x01EE5B03, 0x01EE5CAE, 0x01EE5D03, 0x01EE5EAE, 0x01EE5F03,
+    0x01EE60AE, 0x01EE6103, 0x01EE63AE, 0x01EE6403, 0x01EE65AE, 0x01EE6703, 0x01EE6BAE, 0x01EE6C03,
+    0x01EE73AE, 0x01EE7403, 0x01EE78AE, 0x01EE7903, 0x01EE7DAE, 0x01EE7E03, 0x01EE7FAE, 0x01EE8003,
+    0x01EE8AAE, 0x01EE8B03, 0x01EE9CAE, 0x01EEA103, 0x01EEA4AE, 0x01EEA503, 0x01EEAAAE, 0x01EEAB03,
+    0x01EEBCAE, 0x01EEF003, 0x01EEF2AE, 0x01F000AD, 0x01F02CAE, 0x01F030AD, 0x01F094AE, 0x01F0A0AD,
+    0x01F0AFAE, 0x01F0B1AD, 0x01F0C0AE, 0x01F0C1AD, 0x01F0D0AE, 0x01F0D1AD, 0x01F0F6AE, 0x01F100AD,
+    0x01F1AEAE, 0x01F1E6AD, 0x01F20036, 0x01F201AD, 0x01F203AE, 0x01F210AD, 0x01F23CAE, 0x01F240AD,
+    0x01F249AE, 0x01F250AD, 0x01F252AE, 0x01F260AD, 0x01F266AE, 0x01F300AD, 0x01F6D9AE, 0x01F6DCAD,
+    0x01F6EDAE, 0x01F6F0AD, 0x01F6FDAE, 0x01F700AD, 0x01F7DAAE, 0x01F7E0AD, 0x01F7ECAE, 0x01F7F0AD,
+    0x01F7F1AE, 0x01F800AD, 0x01F80CAE, 0x01F810AD, 0x01F848AE, 0x01F850AD, 0x01F85AAE, 0x01F860AD,
+    0x01F888AE, 0x01F890AD, 0x01F8AEAE, 0x01F8B0AD, 0x01F8BCAE, 0x01F8C0AD, 0x01F8C2AE, 0x01F8D0AD,
+    0x01F8D9AE, 0x01F900AD, 0x01FA58AE, 0x01FA60AD, 0x01FA6EAE, 0x01FA70AD, 0x01FA7DAE, 0x01FA80AD,
+    0x01FA8BAE, 0x01FA8EAD, 0x01FAC7AE, 0x01FAC8AD, 0x01FAC9AE, 0x01FACDAD, 0x01FADDAE, 0x01FADFAD,
+    0x01FAEBAE, 0x01FAEFAD, 0x01FAF9AE, 0x01FB00AD, 0x01FB93AE, 0x01FB94AD, 0x01FBFBAE, 0x02000032,
+    0x02A6E0AE, 0x02A70032, 0x02B81EAE, 0x02B82032, 0x02CEAEAE, 0x02CEB032, 0x02EBE1AE, 0x02EBF032,
+    0x02EE5EAE, 0x02F80032, 0x02FA1EAE, 0x03000032, 0x03134BAE, 0x03135032, 0x03347AAE, 0x0E0001AD,
+    0x0E0002AE, 0x0E0020AD, 0x0E0080AE, 0x0E0100AC, 0x0E01F0AE,
+];
+
+pub(crate) static SCRIPT_EXTENSIONS_RUNS: [u32; 282] = [
+    0x00000000, 0x0000B701, 0x0000B800, 0x0002BC02, 0x0002BD00, 0x0002C703, 0x0002C800, 0x0002C903,
+    0x0002CC00, 0x0002CD04, 0x0002CE00, 0x0002D705, 0x0002D800, 0x0002D903, 0x0002DA00, 0x00030006,
+    0x00030107, 0x00030208, 0x00030309, 0x0003040A, 0x0003050B, 0x0003060C, 0x0003070D, 0x0003080E,
+    0x0003090F, 0x00030A10, 0x00030B11, 0x00030C12, 0x00030D13, 0x00030E14, 0x00030F00, 0x00031013,
+    0x00031115, 0x00031200, 0x00031316, 0x00031400, 0x00032317, 0x00032418, 0x00032519, 0x00032600,
+    0x00032D1A, 0x00032E19, 0x00032F00, 0x0003301B, 0x0003311C, 0x00033200, 0x0003421D, 0x00034300,
+    0x0003451D, 0x00034600, 0x0003581E, 0x00035900, 0x00035E1F, 0x00035F00, 0x00036320, 0x00037000,
+    0x00037421, 0x00037600, 0x00048322, 0x00048423, 0x00048524, 0x00048723, 0x00048800, 0x00058925,
+    0x00058A00, 0x00060C26, 0x00060D00, 0x00061B26, 0x00061C27, 0x00061D00, 0x00061F28, 0x00062000,
+    0x00064029, 0x00064100, 0x00064B2A, 0x00065600, 0x0006602B, 0x00066A00, 0x0006702A, 0x00067100,
+    0x0006D42C, 0x0006D500, 0x0009512D, 0x0009522E, 0x00095300, 0x0009642F, 0x00096530, 0x00096631,
+    0x00097000, 0x0009E632, 0x0009F000, 0x000A6633, 0x000A7000, 0x000AE634, 0x000AF000, 0x000BE635,
+    0x000BF400, 0x000CE636, 0x000CF000, 0x00104037, 0x00104A00, 0x0010FB38, 0x0010FC00, 0x0016EB39,
+    0x0016EE00, 0x0017353A, 0x00173700, 0x0018023B, 0x00180400, 0x0018053B, 0x00180600, 0x001CD03C,
+    0x001CD13D, 0x001CD23C, 0x001CD33E, 0x001CD43D, 0x001CD53F, 0x001CD640, 0x001CD741, 0x001CD842,
+    0x001CD943, 0x001CDA44, 0x001CDB3D, 0x001CDC43, 0x001CDE3D, 0x001CE043, 0x001CE145, 0x001CE246,
+    0x001CE33D, 0x001CE947, 0x001CEA48, 0x001CEB49, 0x001CEC3D, 0x001CED4A, 0x001CEE3D, 0x001CF24B,
+    0x001CF34C, 0x001CF44D, 0x001CF545, 0x001CF74E, 0x001CF84C, 0x001CFA4F, 0x001CFB00, 0x001DC01D,
+    0x001DC200, 0x001DF850, 0x001DF900, 0x001DFA51, 0x001DFB00, 0x00202F52, 0x00203000, 0x00204F53,
+    0x00205000, 0x00205A54, 0x00205B00, 0x00205D55, 0x00205E00, 0x0020F056, 0x0020F100, 0x002E1757,
+    0x002E1800, 0x002E3058, 0x002E3159, 0x002E3200, 0x002E3C5A, 0x002E3D00, 0x002E415B, 0x002E4200,
+    0x002E4323, 0x002E4400, 0x002FF05C, 0x00300000, 0x0030015D, 0x0030025E, 0x0030035F, 0x00300400,
+    0x00300660, 0x00300700, 0x00300861, 0x00300A62, 0x00300C63, 0x00301200, 0x0030135F, 0x00301463,
+    0x00301C5F, 0x00302000, 0x00302A64, 0x00302E00, 0x0030305F, 0x00303165, 0x00303600, 0x0030375F,
+    0x00303800, 0x00303C66, 0x00303E60, 0x00304000, 0x00309965, 0x00309D00, 0x0030A065, 0x0030A100,
+    0x0030FB63, 0x0030FC65, 0x0030FD00, 0x00319060, 0x0031A000, 0x0031C060, 0x0031E600, 0x0031EF5C,
+    0x0031F000, 0x00322060, 0x00324800, 0x00328060, 0x0032B100, 0x0032C060, 0x0032CC00, 0x0032FF60,
+    0x00330000, 0x00335860, 0x00337100, 0x00337B60, 0x00338000, 0x0033E060, 0x0033FF00, 0x00A66F23,
+    0x00A67000, 0x00A70067, 0x00A70800, 0x00A83068, 0x00A83369, 0x00A8366A, 0x00A8386B, 0x00A8396A,
+    0x00A83A00, 0x00A8F16C, 0x00A8F200, 0x00A8F36D, 0x00A8F400, 0x00A92E6E, 0x00A92F00, 0x00A9CF6F,
+    0x00A9D000, 0x00FD3E70, 0x00FD4000, 0x00FDF271, 0x00FDF300, 0x00FDFD71, 0x00FDFE00, 0x00FE455F,
+    0x00FE4700, 0x00FF6163, 0x00FF6600, 0x00FF7065, 0x00FF7100, 0x00FF9E65, 0x00FFA000, 0x01010072,
+    0x01010273, 0x01010300, 0x01010774, 0x01013400, 0x01013773, 0x01014000, 0x0102E075, 0x0102FC00,
+    0x010AF276, 0x010AF300, 0x01130135, 0x01130200, 0x01130335, 0x01130400, 0x01133B35, 0x01133D00,
+    0x011FD035, 0x011FD200, 0x011FD335, 0x011FD400, 0x01BCA05A, 0x01BCA400, 0x01D36060, 0x01D37200,
+    0x01F25060, 0x01F25200,
+];
+
+pub(crate) static SCRIPT_EXTENSIONS: [[u64; SCRIPT_WORDS]; 118] = [
+    [0x00042B8A02200040, 0x0004000000030200, 0x0000000000000001],
+    [0x0000000030000800, 0x0000000000004200, 0x0000000104000000],
+    [0x0000000000004000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000004200, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000200, 0x0000000004000000],
+    [0x0000200012800000, 0x0004000000000200, 0x0000000000010800],
+    [0x0000200010800000, 0x0000200000000200, 0x0000000040010800],
+    [0x0000000010800000, 0x0000000000000200, 0x0000000000800000],
+    [0x0000010000000000, 0x0000000000000200, 0x0000000004002800],
+    [0x0000280012800002, 0x0000200000000200, 0x0000000040802000],
+    [0x4000090802000000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000200010000000, 0x0004000000000200, 0x0000000000800000],
+    [0x0020000202000000, 0x0004000000000200, 0x0000000040812000],
+    [0x0020280210000020, 0x0004000000000200, 0x0000000000812000],
+    [0x0000000000000000, 0x0000000000000200, 0x0000000000800000],
+    [0x0000000200000000, 0x0000000000000200, 0x0000000000002000],
+    [0x0000000010800000, 0x0000200000000200, 0x0000000000000000],
+    [0x0000000000800000, 0x0000000000000200, 0x0000000000010000],
+    [0x0000000000000000, 0x0000000000000200, 0x0000000000000800],
+    [0x0000002000000000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000000010000000, 0x0000000000000200, 0x0000000040000000],
+    [0x0000200000000000, 0x0004000000000200, 0x0000000040000000],
+    [0x4000000200800000, 0x0000000000000200, 0x0000000000802000],
+    [0x0000000200800000, 0x0000000000000200, 0x0000000000002000],
+    [0x0000000000000000, 0x0000000000000200, 0x0000000000002000],
+    [0x0000000000000000, 0x0000000000000200, 0x0000000000002800],
+    [0x0000000000800000, 0x0000000000000200, 0x0000000000002000],
+    [0x0000080000800002, 0x0000000000000200, 0x0000000004002800],
+    [0x0000200000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000200000000200, 0x0000000000000000],
+    [0x0000000000000002, 0x0000000000000200, 0x0000000040000000],
+    [0x0000000000000000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000200002000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000010000000, 0x0004000000000000, 0x0000000000000000],
+    [0x0000010010000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000010000000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000018000000020, 0x0000000000000000, 0x0000000000000000],
+    [0x0000004000000008, 0x0400004000000000, 0x0000020002002000],
+    [0x0000000000000008, 0x0000000000000000, 0x0000000002002000],
+    [0x0000004000000009, 0x0400004000000000, 0x0000020002002000],
+    [0x0000000000000009, 0x0420800000180000, 0x0000000000002040],
+    [0x0000000000000008, 0x0000000000000000, 0x0000000000002000],
+    [0x0000000000000008, 0x0000000000000000, 0x0000020002000000],
+    [0x0000000000000008, 0x0400000000000000, 0x0000000000000000],
+    [0x0001500020000800, 0x0000102404000210, 0x0000000010440002],
+    [0x0001500020000800, 0x0000102004000210, 0x0000000010440000],
+    [0x00015600A0000800, 0x0000140404020010, 0x0000000010449030],
+    [0x0001D600A0000800, 0x0000140404020810, 0x0000000010449030],
+    [0x00000000A0000000, 0x0000000000020040, 0x0000000000000000],
+    [0x0000000000080800, 0x0000000000000000, 0x0000000000001000],
+    [0x0001000000000000, 0x0000000080000000, 0x0000000000000000],
+    [0x0000400000000000, 0x0000000000000004, 0x0000000000000000],
+    [0x0000100000000000, 0x0000000000000000, 0x0000000000040000],
+    [0x0000000000000000, 0x0000000400000010, 0x0000000200000000],
+    [0x0000000000080000, 0x0000000100000000, 0x0000000000010000],
+    [0x0000018000000000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000000000000000, 0x0800000000000000, 0x0000000000000000],
+    [0x0008000000040000, 0x0000000000000000, 0x0000000001004000],
+    [0x0000000000000000, 0x0008000010000000, 0x0000000000000000],
+    [0x0000100020000800, 0x0000000000000010, 0x0000000000000000],
+    [0x0000000020000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000100020000000, 0x0000000000000010, 0x0000000000000000],
+    [0x0000000020000800, 0x0000002000000000, 0x0000000010400000],
+    [0x0000000020000800, 0x0000000000000000, 0x0000000000400000],
+    [0x0000000020000000, 0x0000002000000000, 0x0000000000000002],
+    [0x0000000020000800, 0x0000002000000000, 0x0000000000400000],
+    [0x0000000020000000, 0x0000000000000000, 0x0000000000000002],
+    [0x0000000020000000, 0x0000100004000010, 0x0000000000440000],
+    [0x0000000020000800, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000020000000, 0x0000002000000000, 0x0000000010000000],
+    [0x0000000020000000, 0x0000002400000000, 0x0000000000000000],
+    [0x0000000020000800, 0x0000000000000000, 0x0000000000000002],
+    [0x0000000020000000, 0x0000002000000000, 0x0000000000000000],
+    [0x0000000020000800, 0x0000002000000000, 0x0000000000000002],
+    [0x0000100020000800, 0x0000100404000010, 0x0000000210400020],
+    [0x0000100020000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000100020000000, 0x0000000000000010, 0x0000000200000000],
+    [0x0000000000000800, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000400000000, 0x0000000000000000],
+    [0x0000000010000000, 0x0000000000000200, 0x0000000000002000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000002000],
+    [0x0000000000000000, 0x0008000010000200, 0x0000000000000000],
+    [0x0000000000000009, 0x0000000000000000, 0x0000000000000000],
+    [0x0400018000200000, 0x0000080000008000, 0x0000000000000000],
+    [0x0400200000200000, 0x0000000002000000, 0x0000000000000000],
+    [0x0000100020000000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000000002000000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000000000000040, 0x0000080000000000, 0x0000000000000000],
+    [0x0400008000200040, 0x1000000000010040, 0x0000000000000000],
+    [0x0000000200000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0400000000000009, 0x0000000000000000, 0x0000000000000000],
+    [0x0004000000000000, 0x0000000000000000, 0x0000000000080000],
+    [0x4046000000004000, 0x0000000010000000, 0x0000040000000000],
+    [0x4046000000004000, 0x0008000010000000, 0x0000040000000000],
+    [0x4046000000004000, 0x0000000000000000, 0x0000000000000000],
+    [0x0004000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x4046000000004000, 0x0000000010000000, 0x0000040008000000],
+    [0x4046000000004000, 0x0000000010004000, 0x0000040008000000],
+    [0x4046000000004000, 0x0000000000000000, 0x0000040000000000],
+    [0x0004000000004000, 0x0000000000000000, 0x0000000000000000],
+    [0x4040000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x4044000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0004000000000000, 0x0000000000000200, 0x0000000000000000],
+    [0x00014000A0000000, 0x000000040C020054, 0x0000000210008012],
+    [0x00014000A0000000, 0x0000000408020054, 0x0000000210008012],
+    [0x00014000A0000000, 0x0000000008020044, 0x0000000010008010],
+    [0x00014000A0000000, 0x0000000008020044, 0x0000000010008012],
+    [0x0000000020000800, 0x0000000000000000, 0x0000000200000000],
+    [0x0000000020000000, 0x0000000000000000, 0x0000000000040000],
+    [0x2000000000000000, 0x0000000100000200, 0x0000000000000000],
+    [0x1000000000020000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000008, 0x0000004000000000, 0x0000000000000000],
+    [0x0000000000000008, 0x0000000000000000, 0x0000000002000000],
+    [0x000000000C000000, 0x0000000000002000, 0x0000000000000000],
+    [0x0000000008000000, 0x0000000000002000, 0x0000000000000000],
+    [0x0000000008000000, 0x0000000000003000, 0x0000000000000000],
+    [0x0000000002000008, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000800000100000, 0x0000000000000000],
+];
+
+pub(crate) static COMBINING_CLASS_RUNS: [u32; 606] = [
+    0x00000000, 0x000300E6, 0x000315E8, 0x000316DC, 0x00031AE8, 0x00031BD8, 0x00031CDC, 0x000321CA,
+    0x000323DC, 0x000327CA, 0x000329DC, 0x00033401, 0x000339DC, 0x00033DE6, 0x000345F0, 0x000346E6,
+    0x000347DC, 0x00034AE6, 0x00034DDC, 0x00034F00, 0x000350E6, 0x000353DC, 0x000357E6, 0x000358E8,
+    0x000359DC, 0x00035BE6, 0x00035CE9, 0x00035DEA, 0x00035FE9, 0x000360EA, 0x000362E9, 0x000363E6,
+    0x00037000, 0x000483E6, 0x00048800, 0x000591DC, 0x000592E6, 0x000596DC, 0x000597E6, 0x00059ADE,
+    0x00059BDC, 0x00059CE6, 0x0005A2DC, 0x0005A8E6, 0x0005AADC, 0x0005ABE6, 0x0005ADDE, 0x0005AEE4,
+    0x0005AFE6, 0x0005B00A, 0x0005B10B, 0x0005B20C, 0x0005B30D, 0x0005B40E, 0x0005B50F, 0x0005B610,
+    0x0005B711, 0x0005B812, 0x0005B913, 0x0005BB14, 0x0005BC15, 0x0005BD16, 0x0005BE00, 0x0005BF17,
+    0x0005C000, 0x0005C118, 0x0005C219, 0x0005C300, 0x0005C4E6, 0x0005C5DC, 0x0005C600, 0x0005C712,
+    0x0005C800, 0x000610E6, 0x0006181E, 0x0006191F, 0x00061A20, 0x00061B00, 0x00064B1B, 0x00064C1C,
+    0x00064D1D, 0x00064E1E, 0x00064F1F, 0x00065020, 0x00065121, 0x00065222, 0x000653E6, 0x000655DC,
+    0x000657E6, 0x00065CDC, 0x00065DE6, 0x00065FDC, 0x00066000, 0x00067023, 0x00067100, 0x0006D6E6,
+    0x0006DD00, 0x0006DFE6, 0x0006E3DC, 0x0006E4E6, 0x0006E500, 0x0006E7E6, 0x0006E900, 0x0006EADC,
+    0x0006EBE6, 0x0006EDDC, 0x0006EE00, 0x00071124, 0x00071200, 0x000730E6, 0x000731DC, 0x000732E6,
+    0x000734DC, 0x000735E6, 0x000737DC, 0x00073AE6, 0x00073BDC, 0x00073DE6, 0x00073EDC, 0x00073FE6,
+    0x000742DC, 0x000743E6, 0x000744DC, 0x000745E6, 0x000746DC, 0x000747E6, 0x000748DC, 0x000749E6,
+    0x00074B00, 0x0007EBE6, 0x0007F2DC, 0x0007F3E6, 0x0007F400, 0x0007FDDC, 0x0007FE00, 0x000816E6,
+    0x00081A00, 0x00081BE6, 0x00082400, 0x000825E6, 0x00082800, 0x000829E6, 0x00082E00, 0x000859DC,
+    0x00085C00, 0x000897E6, 0x000899DC, 0x00089CE6, 0x0008A000, 0x0008CAE6, 0x0008CFDC, 0x0008D4E6,
+    0x0008E200, 0x0008E3DC, 0x0008E4E6, 0x0008E6DC, 0x0008E7E6, 0x0008E9DC, 0x0008EAE6, 0x0008EDDC,
+    0x0008F01B, 0x0008F11C, 0x0008F21D, 0x0008F3E6, 0x0008F6DC, 0x0008F7E6, 0x0008F9DC, 0x0008FBE6,
+    0x00090000, 0x00093C07, 0x00093D00, 0x00094D09, 0x00094E00, 0x000951E6, 0x000952DC, 0x000953E6,
+    0x00095500, 0x0009BC07, 0x0009BD00, 0x0009CD09, 0x0009CE00, 0x0009FEE6, 0x0009FF00, 0x000A3C07,
+    0x000A3D00, 0x000A4D09, 0x000A4E00, 0x000ABC07, 0x000ABD00, 0x000ACD09, 0x000ACE00, 0x000B3C07,
+    0x000B3D00, 0x000B4D09, 0x000B4E00, 0x000BCD09, 0x000BCE00, 0x000C3C07, 0x000C3D00, 0x000C4D09,
+    0x000C4E00, 0x000C5554, 0x000C565B, 0x000C5700, 0x000CBC07, 0x000CBD00, 0x000CCD09, 0x000CCE00,
+    0x000D3B09, 0x000D3D00, 0x000D4D09, 0x000D4E00, 0x000DCA09, 0x000DCB00, 0x000E3867, 0x000E3A09,
+    0x000E3B00, 0x000E486B, 0x000E4C00, 0x000EB876, 0x000EBA09, 0x000EBB00, 0x000EC87A, 0x000ECC00,
+    0x000F18DC, 0x000F1A00, 0x000F35DC, 0x000F3600, 0x000F37DC, 0x000F3800, 0x000F39D8, 0x000F3A00,
+    0x000F7181, 0x000F7282, 0x000F7300, 0x000F7484, 0x000F7500, 0x000F7A82, 0x000F7E00, 0x000F8082,
+    0x000F8100, 0x000F82E6, 0x000F8409, 0x000F8500, 0x000F86E6, 0x000F8800, 0x000FC6DC, 0x000FC700,
+    0x00103707, 0x00103800, 0x00103909, 0x00103B00, 0x00108DDC, 0x00108E00, 0x00135DE6, 0x00136000,
+    0x00171409, 0x00171600, 0x00173409, 0x00173500, 0x0017D209, 0x0017D300, 0x0017DDE6, 0x0017DE00,
+    0x0018A9E4, 0x0018AA00, 0x001939DE, 0x00193AE6, 0x00193BDC, 0x00193C00, 0x001A17E6, 0x001A18DC,
+    0x001A1900, 0x001A6009, 0x001A6100, 0x001A75E6, 0x001A7D00, 0x001A7FDC, 0x001A8000, 0x001AB0E6,
+    0x001AB5DC, 0x001ABBE6, 0x001ABDDC, 0x001ABE00, 0x001ABFDC, 0x001AC1E6, 0x001AC3DC, 0x001AC5E6,
+    0x001ACADC, 0x001ACBE6, 0x001ADDDC, 0x001ADE00, 0x001AE0E6, 0x001AE6DC, 0x001AE7E6, 0x001AEBEA,
+    0x001AEC00, 0x001B3407, 0x001B3500, 0x001B4409, 0x001B4500, 0x001B6BE6, 0x001B6CDC, 0x001B6DE6,
+    0x001B7400, 0x001BAA09, 0x001BAC00, 0x001BE607, 0x001BE700, 0x001BF209, 0x001BF400, 0x001C3707,
+    0x001C3800, 0x001CD0E6, 0x001CD300, 0x001CD401, 0x001CD5DC, 0x001CDAE6, 0x001CDCDC, 0x001CE0E6,
+    0x001CE100, 0x001CE201, 0x001CE900, 0x001CEDDC, 0x001CEE00, 0x001CF4E6, 0x001CF500, 0x001CF8E6,
+    0x001CFA00, 0x001DC0E6, 0x001DC2DC, 0x001DC3E6, 0x001DCADC, 0x001DCBE6, 0x001DCDEA, 0x001DCED6,
+    0x001DCFDC, 0x001DD0CA, 0x001DD1E6, 0x001DF6E8, 0x001DF7E4, 0x001DF9DC, 0x001DFADA, 0x001DFBE6,
+    0x001DFCE9, 0x001DFDDC, 0x001DFEE6, 0x001DFFDC, 0x001E0000, 0x0020D0E6, 0x0020D201, 0x0020D4E6,
+    0x0020D801, 0x0020DBE6, 0x0020DD00, 0x0020E1E6, 0x0020E200, 0x0020E501, 0x0020E7E6, 0x0020E8DC,
+    0x0020E9E6, 0x0020EA01, 0x0020ECDC, 0x0020F0E6, 0x0020F100, 0x002CEFE6, 0x002CF200, 0x002D7F09,
+    0x002D8000, 0x002DE0E6, 0x002E0000, 0x00302ADA, 0x00302BE4, 0x00302CE8, 0x00302DDE, 0x00302EE0,
+    0x00303000, 0x00309908, 0x00309B00, 0x00A66FE6, 0x00A67000, 0x00A674E6, 0x00A67E00, 0x00A69EE6,
+    0x00A6A000, 0x00A6F0E6, 0x00A6F200, 0x00A80609, 0x00A80700, 0x00A82C09, 0x00A82D00, 0x00A8C409,
+    0x00A8C500, 0x00A8E0E6, 0x00A8F200, 0x00A92BDC, 0x00A92E00, 0x00A95309, 0x00A95400, 0x00A9B307,
+    0x00A9B400, 0x00A9C009, 0x00A9C100, 0x00AAB0E6, 0x00AAB100, 0x00AAB2E6, 0x00AAB4DC, 0x00AAB500,
+    0x00AAB7E6, 0x00AAB900, 0x00AABEE6, 0x00AAC000, 0x00AAC1E6, 0x00AAC200, 0x00AAF609, 0x00AAF700,
+    0x00ABED09, 0x00ABEE00, 0x00FB1E1A, 0x00FB1F00, 0x00FE20E6, 0x00FE27DC, 0x00FE2EE6, 0x00FE3000,
+    0x0101FDDC, 0x0101FE00, 0x0102E0DC, 0x0102E100, 0x010376E6, 0x01037B00, 0x010A0DDC, 0x010A0E00,
+    0x010A0FE6, 0x010A1000, 0x010A38E6, 0x010A3901, 0x010A3ADC, 0x010A3B00, 0x010A3F09, 0x010A4000,
+    0x010AE5E6, 0x010AE6DC, 0x010AE700, 0x010D24E6, 0x010D2800, 0x010D69E6, 0x010D6E00, 0x010EABE6,
+    0x010EAD00, 0x010EFADC, 0x010EFC00, 0x010EFDDC, 0x010F0000, 0x010F46DC, 0x010F48E6, 0x010F4BDC,
+    0x010F4CE6, 0x010F4DDC, 0x010F5100, 0x010F82E6, 0x010F83DC, 0x010F84E6, 0x010F85DC, 0x010F8600,
+    0x01104609, 0x01104700, 0x01107009, 0x01107100, 0x01107F09, 0x01108000, 0x0110B909, 0x0110BA07,
+    0x0110BB00, 0x011100E6, 0x01110300, 0x01113309, 0x01113500, 0x01117307, 0x01117400, 0x0111C009,
+    0x0111C100, 0x0111CA07, 0x0111CB00, 0x01123509, 0x01123607, 0x01123700, 0x0112E907, 0x0112EA09,
+    0x0112EB00, 0x01133B07, 0x01133D00, 0x01134D09, 0x01134E00, 0x011366E6, 0x01136D00, 0x011370E6,
+    0x01137500, 0x0113CE09, 0x0113D100, 0x01144209, 0x01144300, 0x01144607, 0x01144700, 0x01145EE6,
+    0x01145F00, 0x0114C209, 0x0114C307, 0x0114C400, 0x0115BF09, 0x0115C007, 0x0115C100, 0x01163F09,
+    0x01164000, 0x0116B609, 0x0116B707, 0x0116B800, 0x01172B09, 0x01172C00, 0x01183909, 0x01183A07,
+    0x01183B00, 0x01193D09, 0x01193F00, 0x01194307, 0x01194400, 0x0119E009, 0x0119E100, 0x011A3409,
+    0x011A3500, 0x011A4709, 0x011A4800, 0x011A9909, 0x011A9A00, 0x011C3F09, 0x011C4000, 0x011D4207,
+    0x011D4300, 0x011D4409, 0x011D4600, 0x011D9709, 0x011D9800, 0x011F4109, 0x011F4300, 0x01612F09,
+    0x01613000, 0x016AF001, 0x016AF500, 0x016B30E6, 0x016B3700, 0x016FF006, 0x016FF200, 0x01BC9E01,
+    0x01BC9F00, 0x01D165D8, 0x01D16701, 0x01D16A00, 0x01D16DE2, 0x01D16ED8, 0x01D17300, 0x01D17BDC,
+    0x01D18300, 0x01D185E6, 0x01D18ADC, 0x01D18C00, 0x01D1AAE6, 0x01D1AE00, 0x01D242E6, 0x01D24500,
+    0x01E000E6, 0x01E00700, 0x01E008E6, 0x01E01900, 0x01E01BE6, 0x01E02200, 0x01E023E6, 0x01E02500,
+    0x01E026E6, 0x01E02B00, 0x01E08FE6, 0x01E09000, 0x01E130E6, 0x01E13700, 0x01E2AEE6, 0x01E2AF00,
+    0x01E2ECE6, 0x01E2F000, 0x01E4ECE8, 0x01E4EEDC, 0x01E4EFE6, 0x01E4F000, 0x01E5EEE6, 0x01E5EFDC,
+    0x01E5F000, 0x01E6E3E6, 0x01E6E400, 0x01E6E6E6, 0x01E6E700, 0x01E6EEE6, 0x01E6F000, 0x01E6F5E6,
+    0x01E6F600, 0x01E8D0DC, 0x01E8D700, 0x01E944E6, 0x01E94A07, 0x01E94B00,
+];
+
+pub(crate) static CANONICAL_DECOMPOSITION_KEYS: [char; 2081] = [
+    '\u{C0}', '\u{C1}', '\u{C2}', '\u{C3}', '\u{C4}', '\u{C5}', '\u{C7}', '\u{C8}',
+    '\u{C9}', '\u{CA}', '\u{CB}', '\u{CC}', '\u{CD}', '\u{CE}', '\u{CF}', '\u{D1}',
+    '\u{D2}', '\u{D3}', '\u{D4}', '\u{D5}', '\u{D6}', '\u{D9}', '\u{DA}', '\u{DB}',
+    '\u{DC}', '\u{DD}', '\u{E0}', '\u{E1}', '\u{E2}', '\u{E3}', '\u{E4}', '\u{E5}',
+    '\u{E7}', '\u{E8}', '\u{E9}', '\u{EA}', '\u{EB}', '\u{EC}', '\u{ED}', '\u{EE}',
+    '\u{EF}', '\u{F1}', '\u{F2}', '\u{F3}', '\u{F4}', '\u{F5}', '\u{F6}', '\u{F9}',
+    '\u{FA}', '\u{FB}', '\u{FC}', '\u{FD}', '\u{FF}', '\u{100}', '\u{101}', '\u{102}',
+    '\u{103}', '\u{104}', '\u{105}', '\u{106}', '\u{107}', '\u{108}', '\u{109}', '\u{10A}',
+    '\u{10B}', '\u{10C}', '\u{10D}', '\u{10E}', '\u{10F}', '\u{112}', '\u{113}', '\u{114}',
+    '\u{115}', '\u{116}', '\u{117}', '\u{118}', '\u{119}', '\u{11A}', '\u{11B}', '\u{11C}',
+    '\u{11D}', '\u{11E}', '\u{11F}', '\u{120}', '\u{121}', '\u{122}', '\u{123}', '\u{124}',
+    '\u{125}', '\u{128}', '\u{129}', '\u{12A}', '\u{12B}', '\u{12C}', '\u{12D}', '\u{12E}',
+    '\u{12F}', '\u{130}', '\u{134}', '\u{135}', '\u{136}', '\u{137}', '\u{139}', '\u{13A}',
+    '\u{13B}', '\u{13C}', '\u{13D}', '\u{13E}', '\u{143}', '\u{144}', '\u{145}', '\u{146}',
+    '\u{147}', '\u{148}', '\u{14C}', '\u{14D}', '\u{14E}', '\u{14F}', '\u{150}', '\u{151}',
+    '\u{154}', '\u{155}', '\u{156}', '\u{157}', '\u{158}', '\u{159}', '\u{15A}', '\u{15B}',
+    '\u{15C}', '\u{15D}', '\u{15E}', '\u{15F}', '\u{160}', '\u{161}', '\u{162}', '\u{163}',
+    '\u{164}', '\u{165}', '\u{168}', '\u{169}', '\u{16A}', '\u{16B}', '\u{16C}', '\u{16D}',
+    '\u{16E}', '\u{16F}', '\u{170}', '\u{171}', '\u{172}', '\u{173}', '\u{174}', '\u{175}',
+    '\u{176}', '\u{177}', '\u{178}', '\u{179}', '\u{17A}', '\u{17B}', '\u{17C}', '\u{17D}',
+    '\u{17E}', '\u{1A0}', '\u{1A1}', '\u{1AF}', '\u{1B0}', '\u{1CD}', '\u{1CE}', '\u{1CF}',
+    '\u{1D0}', '\u{1D1}', '\u{1D2}', '\u{1D3}', '\u{1D4}', '\u{1D5}', '\u{1D6}', '\u{1D7}',
+    '\u{1D8}', '\u{1D9}', '\u{1DA}', '\u{1DB}', '\u{1DC}', '\u{1DE}', '\u{1DF}', '\u{1E0}',
+    '\u{1E1}', '\u{1E2}', '\u{1E3}', '\u{1E6}', '\u{1E7}', '\u{1E8}', '\u{1E9}', '\u{1EA}',
+    '\u{1EB}', '\u{1EC}', '\u{1ED}', '\u{1EE}', '\u{1EF}', '\u{1F0}', '\u{1F4}', '\u{1F5}',
+    '\u{1F8}', '\u{1F9}', '\u{1FA}', '\u{1FB}', '\u{1FC}', '\u{1FD}', '\u{1FE}', '\u{1FF}',
+    '\u{200}', '\u{201}', '\u{202}', '\u{203}', '\u{204}', '\u{205}', '\u{206}', '\u{207}',
+    '\u{208}', '\u{209}', '\u{20A}', '\u{20B}', '\u{20C}', '\u{20D}', '\u{20E}', '\u{20F}',
+    '\u{210}', '\u{211}', '\u{212}', '\u{213}', '\u{214}', '\u{215}', '\u{216}', '\u{217}',
+    '\u{218}', '\u{219}', '\u{21A}', '\u{21B}', '\u{21E}', '\u{21F}', '\u{226}', '\u{227}',
+    '\u{228}', '\u{229}', '\u{22A}', '\u{22B}', '\u{22C}', '\u{22D}', '\u{22E}', '\u{22F}',
+    '\u{230}', '\u{231}', '\u{232}', '\u{233}', '\u{340}', '\u{341}', '\u{343}', '\u{344}',
+    '\u{374}', '\u{37E}', '\u{385}', '\u{386}', '\u{387}', '\u{388}', '\u{389}', '\u{38A}',
+    '\u{38C}', '\u{38E}', '\u{38F}', '\u{390}', '\u{3AA}', '\u{3AB}', '\u{3AC}', '\u{3AD}',
+    '\u{3AE}', '\u{3AF}', '\u{3B0}', '\u{3CA}', '\u{3CB}', '\u{3CC}', '\u{3CD}', '\u{3CE}',
+    '\u{3D3}', '\u{3D4}', '\u{400}', '\u{401}', '\u{403}', '\u{407}', '\u{40C}', '\u{40D}',
+    '\u{40E}', '\u{419}', '\u{439}', '\u{450}', '\u{451}', '\u{453}', '\u{457}', '\u{45C}',
+    '\u{45D}', '\u{45E}', '\u{476}', '\u{477}', '\u{4C1}', '\u{4C2}', '\u{4D0}', '\u{4D1}',
+    '\u{4D2}', '\u{4D3}', '\u{4D6}', '\u{4D7}', '\u{4DA}', '\u{4DB}', '\u{4DC}', '\u{4DD}',
+    '\u{4DE}', '\u{4DF}', '\u{4E2}', '\u{4E3}', '\u{4E4}', '\u{4E5}', '\u{4E6}', '\u{4E7}',
+    '\u{4EA}', '\u{4EB}', '\u{4EC}', '\u{4ED}', '\u{4EE}', '\u{4EF}', '\u{4F0}', '\u{4F1}',
+    '\u{4F2}', '\u{4F3}', '\u{4F4}', '\u{4F5}', '\u{4F8}', '\u{4F9}', '\u{622}', '\u{623}',
+    '\u{624}', '\u{625}', '\u{626}', '\u{6C0}', '\u{6C2}', '\u{6D3}', '\u{929}', '\u{931}',
+    '\u{934}', '\u{958}', '\u{959}', '\u{95A}', '\u{95B}', '\u{95C}', '\u{95D}', '\u{95E}',
+    '\u{95F}', '\u{9CB}', '\u{9CC}', '\u{9DC}', '\u{9DD}', '\u{9DF}', '\u{A33}', '\u{A36}',
+    '\u{A59}', '\u{A5A}', '\u{A5B}', '\u{A5E}', '\u{B48}', '\u{B4B}', '\u{B4C}', '\u{B5C}',
+    '\u{B5D}', '\u{B94}', '\u{BCA}', '\u{BCB}', '\u{BCC}', '\u{C48}', '\u{CC0}', '\u{CC7}',
+    '\u{CC8}', '\u{CCA}', '\u{CCB}', '\u{D4A}', '\u{D4B}', '\u{D4C}', '\u{DDA}', '\u{DDC}',
+    '\u{DDD}', '\u{DDE}', '\u{F43}', '\u{F4D}', '\u{F52}', '\u{F57}', '\u{F5C}', '\u{F69}',
+    '\u{F73}', '\u{F75}', '\u{F76}', '\u{F78}', '\u{F81}', '\u{F93}', '\u{F9D}', '\u{FA2}',
+    '\u{FA7}', '\u{FAC}', '\u{FB9}', '\u{1026}', '\u{1B06}', '\u{1B08}', '\u{1B0A}', '\u{1B0C}',
+    '\u{1B0E}', '\u{1B12}', '\u{1B3B}', '\u{1B3D}', '\u{1B40}', '\u{1B41}', '\u{1B43}', '\u{1E00}',
+    '\u{1E01}', '\u{1E02}', '\u{1E03}', '\u{1E04}', '\u{1E05}', '\u{1E06}', '\u{1E07}', '\u{1E08}',
+    '\u{1E09}', '\u{1E0A}', '\u{1E0B}', '\u{1E0C}', '\u{1E0D}', '\u{1E0E}', '\u{1E0F}', '\u{1E10}',
+    '\u{1E11}', '\u{1E12}', '\u{1E13}', '\u{1E14}', '\u{1E15}', '\u{1E16}', '\u{1E17}', '\u{1E18}',
+    '\u{1E19}', '\u{1E1A}', '\u{1E1B}', '\u{1E1C}', '\u{1E1D}', '\u{1E1E}', '\u{1E1F}', '\u{1E20}',
+    '\u{1E21}', '\u{1E22}', '\u{1E23}', '\u{1E24}', '\u{1E25}', '\u{1E26}', '\u{1E27}', '\u{1E28}',
+    '\u{1E29}', '\u{1E2A}', '\u{1E2B}', '\u{1E2C}', '\u{1E2D}', '\u{1E2E}', '\u{1E2F}', '\u{1E30}',
+    '\u{1E31}', '\u{1E32}', '\u{1E33}', '\u{1E34}', '\u{1E35}', '\u{1E36}', '\u{1E37}', '\u{1E38}',
+    '\u{1E39}', '\u{1E3A}', '\u{1E3B}', '\u{1E3C}', '\u{1E3D}', '\u{1E3E}', '\u{1E3F}', '\u{1E40}',
+    '\u{1E41}', '\u{1E42}', '\u{1E43}', '\u{1E44}', '\u{1E45}', '\u{1E46}', '\u{1E47}', '\u{1E48}',
+    '\u{1E49}', '\u{1E4A}', '\u{1E4B}', '\u{1E4C}', '\u{1E4D}', '\u{1E4E}', '\u{1E4F}', '\u{1E50}',
+    '\u{1E51}', '\u{1E52}', '\u{1E53}', '\u{1E54}', '\u{1E55}', '\u{1E56}', '\u{1E57}', '\u{1E58}',
+    '\u{1E59}', '\u{1E5A}', '\u{1E5B}', '\u{1E5C}', '\u{1E5D}', '\u{1E5E}', '\u{1E5F}', '\u{1E60}',
+    '\u{1E61}', '\u{1E62}', '\u{1E63}', '\u{1E64}', '\u{1E65}', '\u{1E66}', '\u{1E67}', '\u{1E68}',
+    '\u{1E69}', '\u{1E6A}', '\u{1E6B}', '\u{1E6C}', '\u{1E6D}', '\u{1E6E}', '\u{1E6F}', '\u{1E70}',
+    '\u{1E71}', '\u{1E72}', '\u{1E73}', '\u{1E74}', '\u{1E75}', '\u{1E76}', '\u{1E77}', '\u{1E78}',
+    '\u{1E79}', '\u{1E7A}', '\u{1E7B}', '\u{1E7C}', '\u{1E7D}', '\u{1E7E}', '\u{1E7F}', '\u{1E80}',
+    '\u{1E81}', '\u{1E82}', '\u{1E83}', '\u{1E84}', '\u{1E85}', '\u{1E86}', '\u{1E87}', '\u{1E88}',
+    '\u{1E89}', '\u{1E8A}', '\u{1E8B}', '\u{1E8C}', '\u{1E8D}', '\u{1E8E}', '\u{1E8F}', '\u{1E90}',
+    '\u{1E91}', '\u{1E92}', '\u{1E93}', '\u{1E94}', '\u{1E95}', '\u{1E96}', '\u{1E97}', '\u{1E98}',
+    '\u{1E99}', '\u{1E9B}', '\u{1EA0}', '\u{1EA1}', '\u{1EA2}', '\u{1EA3}', '\u{1EA4}', '\u{1EA5}',
+    '\u{1EA6}', '\u{1EA7}', '\u{1EA8}', '\u{1EA9}', '\u{1EAA}', '\u{1EAB}', '\u{1EAC}', '\u{1EAD}',
+    '\u{1EAE}', '\u{1EAF}', '\u{1EB0}', '\u{1EB1}', '\u{1EB2}', '\u{1EB3}', '\u{1EB4}', '\u{1EB5}',
+    '\u{1EB6}', '\u{1EB7}', '\u{1EB8}', '\u{1EB9}', '\u{1EBA}', '\u{1EBB}', '\u{1EBC}', '\u{1EBD}',
+    '\u{1EBE}', '\u{1EBF}', '\u{1EC0}', '\u{1EC1}', '\u{1EC2}', '\u{1EC3}', '\u{1EC4}', '\u{1EC5}',
+    '\u{1EC6}', '\u{1EC7}', '\u{1EC8}', '\u{1EC9}', '\u{1ECA}', '\u{1ECB}', '\u{1ECC}', '\u{1ECD}',
+    '\u{1ECE}', '\u{1ECF}', '\u{1ED0}', '\u{1ED1}', '\u{1ED2}', '\u{1ED3}', '\u{1ED4}', '\u{1ED5}',
+    '\u{1ED6}', '\u{1ED7}', '\u{1ED8}', '\u{1ED9}', '\u{1EDA}', '\u{1EDB}', '\u{1EDC}', '\u{1EDD}',
+    '\u{1EDE}', '\u{1EDF}', '\u{1EE0}', '\u{1EE1}', '\u{1EE2}', '\u{1EE3}', '\u{1EE4}', '\u{1EE5}',
+    '\u{1EE6}', '\u{1EE7}', '\u{1EE8}', '\u{1EE9}', '\u{1EEA}', '\u{1EEB}', '\u{1EEC}', '\u{1EED}',
+    '\u{1EEE}', '\u{1EEF}', '\u{1EF0}', '\u{1EF1}', '\u{1EF2}', '\u{1EF3}', '\u{1EF4}', '\u{1EF5}',
+    '\u{1EF6}', '\u{1EF7}', '\u{1EF8}', '\u{1EF9}', '\u{1F00}', '\u{1F01}', '\u{1F02}', '\u{1F03}',
+    '\u{1F04}', '\u{1F05}', '\u{1F06}', '\u{1F07}', '\u{1F08}', '\u{1F09}', '\u{1F0A}', '\u{1F0B}',
+    '\u{1F0C}', '\u{1F0D}', '\u{1F0E}', '\u{1F0F}', '\u{1F10}', '\u{1F11}', '\u{1F12}', '\u{1F13}',
+    '\u{1F14}', '\u{1F15}', '\u{1F18}', '\u{1F19}', '\u{1F1A}', '\u{1F1B}', '\u{1F1C}', '\u{1F1D}',
+    '\u{1F20}', '\u{1F21}', '\u{1F22}', '\u{1F23}', '\u{1F24}', '\u{1F25}', '\u{1F26}', '\u{1F27}',
+    '\u{1F28}', '\u{1F29}', '\u{1F2A}', '\u{1F2B}', '\u{1F2C}', '\u{1F2D}', '\u{1F2E}', '\u{1F2F}',
+    '\u{1F30}', '\u{1F31}', '\u{1F32}', '\u{1F33}', '\u{1F34}', '\u{1F35}', '\u{1F36}', '\u{1F37}',
+    '\u{1F38}', '\u{1F39}', '\u{1F3A}', '\u{1F3B}', '\u{1F3C}', '\u{1F3D}', '\u{1F3E}', '\u{1F3F}',
+    '\u{1F40}', '\u{1F41}', '\u{1F42}', '\u{1F43}', '\u{1F44}', '\u{1F45}', '\u{1F48}', '\u{1F49}',
+    '\u{1F4A}', '\u{1F4B}', '\u{1F4C}', '\u{1F4D}', '\u{1F50}', '\u{1F51}', '\u{1F52}', '\u{1F53}',
+    '\u{1F54}', '\u{1F55}', '\u{1F56}', '\u{1F57}', '\u{1F59}', '\u{1F5B}', '\u{1F5D}', '\u{1F5F}',
+    '\u{1F60}', '\u{1F61}', '\u{1F62}', '\u{1F63}', '\u{1F64}', '\u{1F65}', '\u{1F66}', '\u{1F67}',
+    '\u{1F68}', '\u{1F69}', '\u{1F6A}', '\u{1F6B}', '\u{1F6C}', '\u{1F6D}', '\u{1F6E}', '\u{1F6F}',
+    '\u{1F70}', '\u{1F71}', '\u{1F72}', '\u{1F73}', '\u{1F74}', '\u{1F75}', '\u{1F76}', '\u{1F77}',
+    '\u{1F78}', '\u{1F79}', '\u{1F7A}', '\u{1F7B}', '\u{1F7C}', '\u{1F7D}', '\u{1F80}', '\u{1F81}',
+    '\u{1F82}', '\u{1F83}', '\u{1F84}', '\u{1F85}', '\u{1F86}', '\u{1F87}', '\u{1F88}', '\u{1F89}',
+    '\u{1F8A}', '\u{1F8B}', '\u{1F8C}', '\u{1F8D}', '\u{1F8E}', '\u{1F8F}', '\u{1F90}', '\u{1F91}',
+    '\u{1F92}', '\u{1F93}', '\u{1F94}', '\u{1F95}', '\u{1F96}', '\u{1F97}', '\u{1F98}', '\u{1F99}',
+    '\u{1F9A}', '\u{1F9B}', '\u{1F9C}', '\u{1F9D}', '\u{1F9E}', '\u{1F9F}', '\u{1FA0}', '\u{1FA1}',
+    '\u{1FA2}', '\u{1FA3}', '\u{1FA4}', '\u{1FA5}', '\u{1FA6}', '\u{1FA7}', '\u{1FA8}', '\u{1FA9}',
+    '\u{1FAA}', '\u{1FAB}', '\u{1FAC}', '\u{1FAD}', '\u{1FAE}', '\u{1FAF}', '\u{1FB0}', '\u{1FB1}',
+    '\u{1FB2}', '\u{1FB3}', '\u{1FB4}', '\u{1FB6}', '\u{1FB7}', '\u{1FB8}', '\u{1FB9}', '\u{1FBA}',
+    '\u{1FBB}', '\u{1FBC}', '\u{1FBE}', '\u{1FC1}', '\u{1FC2}', '\u{1FC3}', '\u{1FC4}', '\u{1FC6}',
+    '\u{1FC7}', '\u{1FC8}', '\u{1FC9}', '\u{1FCA}', '\u{1FCB}', '\u{1FCC}', '\u{1FCD}', '\u{1FCE}',
+    '\u{1FCF}', '\u{1FD0}', '\u{1FD1}', '\u{1FD2}', '\u{1FD3}', '\u{1FD6}', '\u{1FD7}', '\u{1FD8}',
+    '\u{1FD9}', '\u{1FDA}', '\u{1FDB}', '\u{1FDD}', '\u{1FDE}', '\u{1FDF}', '\u{1FE0}', '\u{1FE1}',
+    '\u{1FE2}', '\u{1FE3}', '\u{1FE4}', '\u{1FE5}', '\u{1FE6}', '\u{1FE7}', '\u{1FE8}', '\u{1FE9}',
+    '\u{1FEA}', '\u{1FEB}', '\u{1FEC}', '\u{1FED}', '\u{1FEE}', '\u{1FEF}', '\u{1FF2}', '\u{1FF3}',
+    '\u{1FF4}', '\u{1FF6}', '\u{1FF7}', '\u{1FF8}', '\u{1FF9}', '\u{1FFA}', '\u{1FFB}', '\u{1FFC}',
+    '\u{1FFD}', '\u{2000}', '\u{2001}', '\u{2126}', '\u{212A}', '\u{212B}', '\u{219A}', '\u{219B}',
+    '\u{21AE}', '\u{21CD}', '\u{21CE}', '\u{21CF}', '\u{2204}', '\u{2209}', '\u{220C}', '\u{2224}',
+    '\u{2226}', '\u{2241}', '\u{2244}', '\u{2247}', '\u{2249}', '\u{2260}', '\u{2262}', '\u{226D}',
+    '\u{226E}', '\u{226F}', '\u{2270}', '\u{2271}', '\u{2274}', '\u{2275}', '\u{2278}', '\u{2279}',
+    '\u{2280}', '\u{2281}', '\u{2284}', '\u{2285}', '\u{2288}', '\u{2289}', '\u{22AC}', '\u{22AD}',
+    '\u{22AE}', '\u{22AF}', '\u{22E0}', '\u{22E1}', '\u{22E2}', '\u{22E3}', '\u{22EA}', '\u{22EB}',
+    '\u{22EC}', '\u{22ED}', '\u{2329}', '\u{232A}', '\u{2ADC}', '\u{304C}', '\u{304E}', '\u{3050}',
+    '\u{3052}', '\u{3054}', '\u{3056}', '\u{3058}', '\u{305A}', '\u{305C}', '\u{305E}', '\u{3060}',
+    '\u{3062}', '\u{3065}', '\u{3067}', '\u{3069}', '\u{3070}', '\u{3071}', '\u{3073}', '\u{3074}',
+    '\u{3076}', '\u{3077}', '\u{3079}', '\u{307A}', '\u{307C}', '\u{307D}', '\u{3094}', '\u{309E}',
+    '\u{30AC}', '\u{30AE}', '\u{30B0}', '\u{30B2}', '\u{30B4}', '\u{30B6}', '\u{30B8}', '\u{30BA}',
+    '\u{30BC}', '\u{30BE}', '\u{30C0}', '\u{30C2}', '\u{30C5}', '\u{30C7}', '\u{30C9}', '\u{30D0}',
+    '\u{30D1}', '\u{30D3}', '\u{30D4}', '\u{30D6}', '\u{30D7}', '\u{30D9}', '\u{30DA}', '\u{30DC}',
+    '\u{30DD}', '\u{30F4}', '\u{30F7}', '\u{30F8}', '\u{30F9}', '\u{30FA}', '\u{30FE}', '\u{F900}',
+    '\u{F901}', '\u{F902}', '\u{F903}', '\u{F904}', '\u{F905}', '\u{F906}', '\u{F907}', '\u{F908}',
+    '\u{F909}', '\u{F90A}', '\u{F90B}', '\u{F90C}', '\u{F90D}', '\u{F90E}', '\u{F90F}', '\u{F910}',
+    '\u{F911}', '\u{F912}', '\u{F913}', '\u{F914}', '\u{F915}', '\u{F916}', '\u{F917}', '\u{F918}',
+    '\u{F919}', '\u{F91A}', '\u{F91B}', '\u{F91C}', '\u{F91D}', '\u{F91E}', '\u{F91F}', '\u{F920}',
+    '\u{F921}', '\u{F922}', '\u{F923}', '\u{F924}', '\u{F925}', '\u{F926}', '\u{F927}', '\u{F928}',
+    '\u{F929}', '\u{F92A}', '\u{F92B}', '\u{F92C}', '\u{F92D}', '\u{F92E}', '\u{F92F}', '\u{F930}',
+    '\u{F931}', '\u{F932}', '\u{F933}', '\u{F934}', '\u{F935}', '\u{F936}', '\u{F937}', '\u{F938}',
+    '\u{F939}', '\u{F93A}', '\u{F93B}', '\u{F93C}', '\u{F93D}', '\u{F93E}', '\u{F93F}', '\u{F940}',
+    '\u{F941}', '\u{F942}', '\u{F943}', '\u{F944}', '\u{F945}', '\u{F946}', '\u{F947}', '\u{F948}',
+    '\u{F949}', '\u{F94A}', '\u{F94B}', '\u{F94C}', '\u{F94D}', '\u{F94E}', '\u{F94F}', '\u{F950}',
+    '\u{F951}', '\u{F952}', '\u{F953}', '\u{F954}', '\u{F955}', '\u{F956}', '\u{F957}', '\u{F958}',
+    '\u{F959}', '\u{F95A}', '\u{F95B}', '\u{F95C}', '\u{F95D}', '\u{F95E}', '\u{F95F}', '\u{F960}',
+    '\u{F961}', '\u{F962}', '\u{F963}', '\u{F964}', '\u{F965}', '\u{F966}', '\u{F967}', '\u{F968}',
+    '\u{F969}', '\u{F96A}', '\u{F96B}', '\u{F96C}', '\u{F96D}', '\u{F96E}', '\u{F96F}', '\u{F970}',
+    '\u{F971}', '\u{F972}', '\u{F973}', '\u{F974}', '\u{F975}', '\u{F976}', '\u{F977}', '\u{F978}',
+    '\u{F979}', '\u{F97A}', '\u{F97B}', '\u{F97C}', '\u{F97D}', '\u{F97E}', '\u{F97F}', '\u{F980}',
+    '\u{F981}', '\u{F982}', '\u{F983}', '\u{F984}', '\u{F985}', '\u{F986}', '\u{F987}', '\u{F988}',
+    '\u{F989}', '\u{F98A}', '\u{F98B}', '\u{F98C}', '\u{F98D}', '\u{F98E}', '\u{F98F}', '\u{F990}',
+    '\u{F991}', '\u{F992}', '\u{F993}', '\u{F994}', '\u{F995}', '\u{F996}', '\u{F997}', '\u{F998}',
+    '\u{F999}', '\u{F99A}', '\u{F99B}', '\u{F99C}', '\u{F99D}', '\u{F99E}', '\u{F99F}', '\u{F9A0}',
+    '\u{F9A1}', '\u{F9A2}', '\u{F9A3}', '\u{F9A4}', '\u{F9A5}', '\u{F9A6}', '\u{F9A7}', '\u{F9A8}',
+    '\u{F9A9}', '\u{F9AA}', '\u{F9AB}', '\u{F9AC}', '\u{F9AD}', '\u{F9AE}', '\u{F9AF}', '\u{F9B0}',
+    '\u{F9B1}', '\u{F9B2}', '\u{F9B3}', '\u{F9B4}', '\u{F9B5}', '\u{F9B6}', '\u{F9B7}', '\u{F9B8}',
+    '\u{F9B9}', '\u{F9BA}', '\u{F9BB}', '\u{F9BC}', '\u{F9BD}', '\u{F9BE}', '\u{F9BF}', '\u{F9C0}',
+    '\u{F9C1}', '\u{F9C2}', '\u{F9C3}', '\u{F9C4}', '\u{F9C5}', '\u{F9C6}', '\u{F9C7}', '\u{F9C8}',
+    '\u{F9C9}', '\u{F9CA}', '\u{F9CB}', '\u{F9CC}', '\u{F9CD}', '\u{F9CE}', '\u{F9CF}', '\u{F9D0}',
+    '\u{F9D1}', '\u{F9D2}', '\u{F9D3}', '\u{F9D4}', '\u{F9D5}', '\u{F9D6}', '\u{F9D7}', '\u{F9D8}',
+    '\u{F9D9}', '\u{F9DA}', '\u{F9DB}', '\u{F9DC}', '\u{F9DD}', '\u{F9DE}', '\u{F9DF}', '\u{F9E0}',
+    '\u{F9E1}', '\u{F9E2}', '\u{F9E3}', '\u{F9E4}', '\u{F9E5}', '\u{F9E6}', '\u{F9E7}', '\u{F9E8}',
+    '\u{F9E9}', '\u{F9EA}', '\u{F9EB}', '\u{F9EC}', '\u{F9ED}', '\u{F9EE}', '\u{F9EF}', '\u{F9F0}',
+    '\u{F9F1}', '\u{F9F2}', '\u{F9F3}', '\u{F9F4}', '\u{F9F5}', '\u{F9F6}', '\u{F9F7}', '\u{F9F8}',
+    '\u{F9F9}', '\u{F9FA}', '\u{F9FB}', '\u{F9FC}', '\u{F9FD}', '\u{F9FE}', '\u{F9FF}', '\u{FA00}',
+    '\u{FA01}', '\u{FA02}', '\u{FA03}', '\u{FA04}', '\u{FA05}', '\u{FA06}', '\u{FA07}', '\u{FA08}',
+    '\u{FA09}', '\u{FA0A}', '\u{FA0B}', '\u{FA0C}', '\u{FA0D}', '\u{FA10}', '\u{FA12}', '\u{FA15}',
+    '\u{FA16}', '\u{FA17}', '\u{FA18}', '\u{FA19}', '\u{FA1A}', '\u{FA1B}', '\u{FA1C}', '\u{FA1D}',
+    '\u{FA1E}', '\u{FA20}', '\u{FA22}', '\u{FA25}', '\u{FA26}', '\u{FA2A}', '\u{FA2B}', '\u{FA2C}',
+    '\u{FA2D}', '\u{FA2E}', '\u{FA2F}', '\u{FA30}', '\u{FA31}', '\u{FA32}', '\u{FA33}', '\u{FA34}',
+    '\u{FA35}', '\u{FA36}', '\u{FA37}', '\u{FA38}', '\u{FA39}', '\u{FA3A}', '\u{FA3B}', '\u{FA3C}',
+    '\u{FA3D}', '\u{FA3E}', '\u{FA3F}', '\u{FA40}', '\u{FA41}', '\u{FA42}', '\u{FA43}', '\u{FA44}',
+    '\u{FA45}', '\u{FA46}', '\u{FA47}', '\u{FA48}', '\u{FA49}', '\u{FA4A}', '\u{FA4B}', '\u{FA4C}',
+    '\u{FA4D}', '\u{FA4E}', '\u{FA4F}', '\u{FA50}', '\u{FA51}', '\u{FA52}', '\u{FA53}', '\u{FA54}',
+    '\u{FA55}', '\u{FA56}', '\u{FA57}', '\u{FA58}', '\u{FA59}', '\u{FA5A}', '\u{FA5B}', '\u{FA5C}',
+    '\u{FA5D}', '\u{FA5E}', '\u{FA5F}', '\u{FA60}', '\u{FA61}', '\u{FA62}', '\u{FA63}', '\u{FA64}',
+    '\u{FA65}', '\u{FA66}', '\u{FA67}', '\u{FA68}', '\u{FA69}', '\u{FA6A}', '\u{FA6B}', '\u{FA6C}',
+    '\u{FA6D}', '\u{FA70}', '\u{FA71}', '\u{FA72}', '\u{FA73}', '\u{FA74}', '\u{FA75}', '\u{FA76}',
+    '\u{FA77}', '\u{FA78}', '\u{FA79}', '\u{FA7A}', '\u{FA7B}', '\u{FA7C}', '\u{FA7D}', '\u{FA7E}',
+    '\u{FA7F}', '\u{FA80}', '\u{FA81}', '\u{FA82}', '\u{FA83}', '\u{FA84}', '\u{FA85}', '\u{FA86}',
+    '\u{FA87}', '\u{FA88}', '\u{FA89}', '\u{FA8A}', '\u{FA8B}', '\u{FA8C}', '\u{FA8D}', '\u{FA8E}',
+    '\u{FA8F}', '\u{FA90}', '\u{FA91}', '\u{FA92}', '\u{FA93}', '\u{FA94}', '\u{FA95}', '\u{FA96}',
+    '\u{FA97}', '\u{FA98}', '\u{FA99}', '\u{FA9A}', '\u{FA9B}', '\u{FA9C}', '\u{FA9D}', '\u{FA9E}',
+    '\u{FA9F}', '\u{FAA0}', '\u{FAA1}', '\u{FAA2}', '\u{FAA3}', '\u{FAA4}', '\u{FAA5}', '\u{FAA6}',
+    '\u{FAA7}', '\u{FAA8}', '\u{FAA9}', '\u{FAAA}', '\u{FAAB}', '\u{FAAC}', '\u{FAAD}', '\u{FAAE}',
+    '\u{FAAF}', '\u{FAB0}', '\u{FAB1}', '\u{FAB2}', '\u{FAB3}', '\u{FAB4}', '\u{FAB5}', '\u{FAB6}',
+    '\u{FAB7}', '\u{FAB8}', '\u{FAB9}', '\u{FABA}', '\u{FABB}', '\u{FABC}', '\u{FABD}', '\u{FABE}',
+    '\u{FABF}', '\u{FAC0}', '\u{FAC1}', '\u{FAC2}', '\u{FAC3}', '\u{FAC4}', '\u{FAC5}', '\u{FAC6}',
+    '\u{FAC7}', '\u{FAC8}', '\u{FAC9}', '\u{FACA}', '\u{FACB}', '\u{FACC}', '\u{FACD}', '\u{FACE}',
+    '\u{FACF}', '\u{FAD0}', '\u{FAD1}', '\u{FAD2}', '\u{FAD3}', '\u{FAD4}', '\u{FAD5}', '\u{FAD6}',
+    '\u{FAD7}', '\u{FAD8}', '\u{FAD9}', '\u{FB1D}', '\u{FB1F}', '\u{FB2A}', '\u{FB2B}', '\u{FB2C}',
+    '\u{FB2D}', '\u{FB2E}', '\u{FB2F}', '\u{FB30}', '\u{FB31}', '\u{FB32}', '\u{FB33}', '\u{FB34}',
+    '\u{FB35}', '\u{FB36}', '\u{FB38}', '\u{FB39}', '\u{FB3A}', '\u{FB3B}', '\u{FB3C}', '\u{FB3E}',
+    '\u{FB40}', '\u{FB41}', '\u{FB43}', '\u{FB44}', '\u{FB46}', '\u{FB47}', '\u{FB48}', '\u{FB49}',
+    '\u{FB4A}', '\u{FB4B}', '\u{FB4C}', '\u{FB4D}', '\u{FB4E}', '\u{105C9}', '\u{105E4}', '\u{1109A}',
+    '\u{1109C}', '\u{110AB}', '\u{1112E}', '\u{1112F}', '\u{1134B}', '\u{1134C}', '\u{11383}', '\u{11385}',
+    '\u{1138E}', '\u{11391}', '\u{113C5}', '\u{113C7}', '\u{113C8}', '\u{114BB}', '\u{114BC}', '\u{114BE}',
+    '\u{115BA}', '\u{115BB}', '\u{11938}', '\u{16121}', '\u{16122}', '\u{16123}', '\u{16124}', '\u{16125}',
+    '\u{16126}', '\u{16127}', '\u{16128}', '\u{16D68}', '\u{16D69}', '\u{16D6A}', '\u{1D15E}', '\u{1D15F}',
+    '\u{1D160}', '\u{1D161}', '\u{1D162}', '\u{1D163}', '\u{1D164}', '\u{1D1BB}', '\u{1D1BC}', '\u{1D1BD}',
+    '\u{1D1BE}', '\u{1D1BF}', '\u{1D1C0}', '\u{2F800}', '\u{2F801}', '\u{2F802}', '\u{2F803}', '\u{2F804}',
+    '\u{2F805}', '\u{2F806}', '\u{2F807}', '\u{2F808}', '\u{2F809}', '\u{2F80A}', '\u{2F80B}', '\u{2F80C}',
+    '\u{2F80D}', '\u{2F80E}', '\u{2F80F}', '\u{2F810}', '\u{2F811}', '\u{2F812}', '\u{2F813}', '\u{2F814}',
+    '\u{2F815}', '\u{2F816}', '\u{2F817}', '\u{2F818}', '\u{2F819}', '\u{2F81A}', '\u{2F81B}', '\u{2F81C}',
+    '\u{2F81D}', '\u{2F81E}', '\u{2F81F}', '\u{2F820}', '\u{2F821}', '\u{2F822}', '\u{2F823}', '\u{2F824}',
+    '\u{2F825}', '\u{2F826}', '\u{2F827}', '\u{2F828}', '\u{2F829}', '\u{2F82A}', '\u{2F82B}', '\u{2F82C}',
+    '\u{2F82D}', '\u{2F82E}', '\u{2F82F}', '\u{2F830}', '\u{2F831}', '\u{2F832}', '\u{2F833}', '\u{2F834}',
+    '\u{2F835}', '\u{2F836}', '\u{2F837}', '\u{2F838}', '\u{2F839}', '\u{2F83A}', '\u{2F83B}', '\u{2F83C}',
+    '\u{2F83D}', '\u{2F83E}', '\u{2F83F}', '\u{2F840}', '\u{2F841}', '\u{2F842}', '\u{2F843}', '\u{2F844}',
+    '\u{2F845}', '\u{2F846}', '\u{2F847}', '\u{2F848}', '\u{2F849}', '\u{2F84A}', '\u{2F84B}', '\u{2F84C}',
+    '\u{2F84D}', '\u{2F84E}', '\u{2F84F}', '\u{2F850}', '\u{2F851}', '\u{2F852}', '\u{2F853}', '\u{2F854}',
+    '\u{2F855}', '\u{2F856}', '\u{2F857}', '\u{2F858}', '\u{2F859}', '\u{2F85A}', '\u{2F85B}', '\u{2F85C}',
+    '\u{2F85D}', '\u{2F85E}', '\u{2F85F}', '\u{2F860}', '\u{2F861}', '\u{2F862}', '\u{2F863}', '\u{2F864}',
+    '\u{2F865}', '\u{2F866}', '\u{2F867}', '\u{2F868}', '\u{2F869}', '\u{2F86A}', '\u{2F86B}', '\u{2F86C}',
+    '\u{2F86D}', '\u{2F86E}', '\u{2F86F}', '\u{2F870}', '\u{2F871}', '\u{2F872}', '\u{2F873}', '\u{2F874}',
+    '\u{2F875}', '\u{2F876}', '\u{2F877}', '\u{2F878}', '\u{2F879}', '\u{2F87A}', '\u{2F87B}', '\u{2F87C}',
+    '\u{2F87D}', '\u{2F87E}', '\u{2F87F}', '\u{2F880}', '\u{2F881}', '\u{2F882}', '\u{2F883}', '\u{2F884}',
+    '\u{2F885}', '\u{2F886}', '\u{2F887}', '\u{2F888}', '\u{2F889}', '\u{2F88A}', '\u{2F88B}', '\u{2F88C}',
+    '\u{2F88D}', '\u{2F88E}', '\u{2F88F}', '\u{2F890}', '\u{2F891}', '\u{2F892}', '\u{2F893}', '\u{2F894}',
+    '\u{2F895}', '\u{2F896}', '\u{2F897}', '\u{2F898}', '\u{2F899}', '\u{2F89A}', '\u{2F89B}', '\u{2F89C}',
+    '\u{2F89D}', '\u{2F89E}', '\u{2F89F}', '\u{2F8A0}', '\u{2F8A1}', '\u{2F8A2}', '\u{2F8A3}', '\u{2F8A4}',
+    '\u{2F8A5}', '\u{2F8A6}', '\u{2F8A7}', '\u{2F8A8}', '\u{2F8A9}', '\u{2F8AA}', '\u{2F8AB}', '\u{2F8AC}',
+    '\u{2F8AD}', '\u{2F8AE}', '\u{2F8AF}', '\u{2F8B0}', '\u{2F8B1}', '\u{2F8B2}', '\u{2F8B3}', '\u{2F8B4}',
+    '\u{2F8B5}', '\u{2F8B6}', '\u{2F8B7}', '\u{2F8B8}', '\u{2F8B9}', '\u{2F8BA}', '\u{2F8BB}', '\u{2F8BC}',
+    '\u{2F8BD}', '\u{2F8BE}', '\u{2F8BF}', '\u{2F8C0}', '\u{2F8C1}', '\u{2F8C2}', '\u{2F8C3}', '\u{2F8C4}',
+    '\u{2F8C5}', '\u{2F8C6}', '\u{2F8C7}', '\u{2F8C8}', '\u{2F8C9}', '\u{2F8CA}', '\u{2F8CB}', '\u{2F8CC}',
+    '\u{2F8CD}', '\u{2F8CE}', '\u{2F8CF}', '\u{2F8D0}', '\u{2F8D1}', '\u{2F8D2}', '\u{2F8D3}', '\u{2F8D4}',
+    '\u{2F8D5}', '\u{2F8D6}', '\u{2F8D7}', '\u{2F8D8}', '\u{2F8D9}', '\u{2F8DA}', '\u{2F8DB}', '\u{2F8DC}',
+    '\u{2F8DD}', '\u{2F8DE}', '\u{2F8DF}', '\u{2F8E0}', '\u{2F8E1}', '\u{2F8E2}', '\u{2F8E3}', '\u{2F8E4}',
+    '\u{2F8E5}', '\u{2F8E6}', '\u{2F8E7}', '\u{2F8E8}', '\u{2F8E9}', '\u{2F8EA}', '\u{2F8EB}', '\u{2F8EC}',
+    '\u{2F8ED}', '\u{2F8EE}', '\u{2F8EF}', '\u{2F8F0}', '\u{2F8F1}', '\u{2F8F2}', '\u{2F8F3}', '\u{2F8F4}',
+    '\u{2F8F5}', '\u{2F8F6}', '\u{2F8F7}', '\u{2F8F8}', '\u{2F8F9}', '\u{2F8FA}', '\u{2F8FB}', '\u{2F8FC}',
+    '\u{2F8FD}', '\u{2F8FE}', '\u{2F8FF}', '\u{2F900}', '\u{2F901}', '\u{2F902}', '\u{2F903}', '\u{2F904}',
+    '\u{2F905}', '\u{2F906}', '\u{2F907}', '\u{2F908}', '\u{2F909}', '\u{2F90A}', '\u{2F90B}', '\u{2F90C}',
+    '\u{2F90D}', '\u{2F90E}', '\u{2F90F}', '\u{2F910}', '\u{2F911}', '\u{2F912}', '\u{2F913}', '\u{2F914}',
+    '\u{2F915}', '\u{2F916}', '\u{2F917}', '\u{2F918}', '\u{2F919}', '\u{2F91A}', '\u{2F91B}', '\u{2F91C}',
+    '\u{2F91D}', '\u{2F91E}', '\u{2F91F}', '\u{2F920}', '\u{2F921}', '\u{2F922}', '\u{2F923}', '\u{2F924}',
+    '\u{2F925}', '\u{2F926}', '\u{2F927}', '\u{2F928}', '\u{2F929}', '\u{2F92A}', '\u{2F92B}', '\u{2F92C}',
+    '\u{2F92D}', '\u{2F92E}', '\u{2F92F}', '\u{2F930}', '\u{2F931}', '\u{2F932}', '\u{2F933}', '\u{2F934}',
+    '\u{2F935}', '\u{2F936}', '\u{2F937}', '\u{2F938}', '\u{2F939}', '\u{2F93A}', '\u{2F93B}', '\u{2F93C}',
+    '\u{2F93D}', '\u{2F93E}', '\u{2F93F}', '\u{2F940}', '\u{2F941}', '\u{2F942}', '\u{2F943}', '\u{2F944}',
+    '\u{2F945}', '\u{2F946}', '\u{2F947}', '\u{2F948}', '\u{2F949}', '\u{2F94A}', '\u{2F94B}', '\u{2F94C}',
+    '\u{2F94D}', '\u{2F94E}', '\u{2F94F}', '\u{2F950}', '\u{2F951}', '\u{2F952}', '\u{2F953}', '\u{2F954}',
+    '\u{2F955}', '\u{2F956}', '\u{2F957}', '\u{2F958}', '\u{2F959}', '\u{2F95A}', '\u{2F95B}', '\u{2F95C}',
+    '\u{2F95D}', '\u{2F95E}', '\u{2F95F}', '\u{2F960}', '\u{2F961}', '\u{2F962}', '\u{2F963}', '\u{2F964}',
+    '\u{2F965}', '\u{2F966}', '\u{2F967}', '\u{2F968}', '\u{2F969}', '\u{2F96A}', '\u{2F96B}', '\u{2F96C}',
+    '\u{2F96D}', '\u{2F96E}', '\u{2F96F}', '\u{2F970}', '\u{2F971}', '\u{2F972}', '\u{2F973}', '\u{2F974}',
+    '\u{2F975}', '\u{2F976}', '\u{2F977}', '\u{2F978}', '\u{2F979}', '\u{2F97A}', '\u{2F97B}', '\u{2F97C}',
+    '\u{2F97D}', '\u{2F97E}', '\u{2F97F}', '\u{2F980}', '\u{2F981}', '\u{2F982}', '\u{2F983}', '\u{2F984}',
+    '\u{2F985}', '\u{2F986}', '\u{2F987}', '\u{2F988}', '\u{2F989}', '\u{2F98A}', '\u{2F98B}', '\u{2F98C}',
+    '\u{2F98D}', '\u{2F98E}', '\u{2F98F}', '\u{2F990}', '\u{2F991}', '\u{2F992}', '\u{2F993}', '\u{2F994}',
+    '\u{2F995}', '\u{2F996}', '\u{2F997}', '\u{2F998}', '\u{2F999}', '\u{2F99A}', '\u{2F99B}', '\u{2F99C}',
+    '\u{2F99D}', '\u{2F99E}', '\u{2F99F}', '\u{2F9A0}', '\u{2F9A1}', '\u{2F9A2}', '\u{2F9A3}', '\u{2F9A4}',
+    '\u{2F9A5}', '\u{2F9A6}', '\u{2F9A7}', '\u{2F9A8}', '\u{2F9A9}', '\u{2F9AA}', '\u{2F9AB}', '\u{2F9AC}',
+    '\u{2F9AD}', '\u{2F9AE}', '\u{2F9AF}', '\u{2F9B0}', '\u{2F9B1}', '\u{2F9B2}', '\u{2F9B3}', '\u{2F9B4}',
+    '\u{2F9B5}', '\u{2F9B6}', '\u{2F9B7}', '\u{2F9B8}', '\u{2F9B9}', '\u{2F9BA}', '\u{2F9BB}', '\u{2F9BC}',
+    '\u{2F9BD}', '\u{2F9BE}', '\u{2F9BF}', '\u{2F9C0}', '\u{2F9C1}', '\u{2F9C2}', '\u{2F9C3}', '\u{2F9C4}',
+    '\u{2F9C5}', '\u{2F9C6}', '\u{2F9C7}', '\u{2F9C8}', '\u{2F9C9}', '\u{2F9CA}', '\u{2F9CB}', '\u{2F9CC}',
+    '\u{2F9CD}', '\u{2F9CE}', '\u{2F9CF}', '\u{2F9D0}', '\u{2F9D1}', '\u{2F9D2}', '\u{2F9D3}', '\u{2F9D4}',
+    '\u{2F9D5}', '\u{2F9D6}', '\u{2F9D7}', '\u{2F9D8}', '\u{2F9D9}', '\u{2F9DA}', '\u{2F9DB}', '\u{2F9DC}',
+    '\u{2F9DD}', '\u{2F9DE}', '\u{2F9DF}', '\u{2F9E0}', '\u{2F9E1}', '\u{2F9E2}', '\u{2F9E3}', '\u{2F9E4}',
+    '\u{2F9E5}', '\u{2F9E6}', '\u{2F9E7}', '\u{2F9E8}', '\u{2F9E9}', '\u{2F9EA}', '\u{2F9EB}', '\u{2F9EC}',
+    '\u{2F9ED}', '\u{2F9EE}', '\u{2F9EF}', '\u{2F9F0}', '\u{2F9F1}', '\u{2F9F2}', '\u{2F9F3}', '\u{2F9F4}',
+    '\u{2F9F5}', '\u{2F9F6}', '\u{2F9F7}', '\u{2F9F8}', '\u{2F9F9}', '\u{2F9FA}', '\u{2F9FB}', '\u{2F9FC}',
+    '\u{2F9FD}', '\u{2F9FE}', '\u{2F9FF}', '\u{2FA00}', '\u{2FA01}', '\u{2FA02}', '\u{2FA03}', '\u{2FA04}',
+    '\u{2FA05}', '\u{2FA06}', '\u{2FA07}', '\u{2FA08}', '\u{2FA09}', '\u{2FA0A}', '\u{2FA0B}', '\u{2FA0C}',
+    '\u{2FA0D}', '\u{2FA0E}', '\u{2FA0F}', '\u{2FA10}', '\u{2FA11}', '\u{2FA12}', '\u{2FA13}', '\u{2FA14}',
+    '\u{2FA15}', '\u{2FA16}', '\u{2FA17}', '\u{2FA18}', '\u{2FA19}', '\u{2FA1A}', '\u{2FA1B}', '\u{2FA1C}',
+    '\u{2FA1D}',
+];
+pub(crate) static CANONICAL_DECOMPOSITION_ENDS: [u16; 2081] = [
+    3, 6, 9, 12, 15, 18, 21, 24, 27, 30, 33, 36,
+    39, 42, 45, 48, 51, 54, 57, 60, 63, 66, 69, 72,
+    75, 78, 81, 84, 87, 90, 93, 96, 99, 102, 105, 108,
+    111, 114, 117, 120, 123, 126, 129, 132, 135, 138, 141, 144,
+    147, 150, 153, 156, 159, 162, 165, 168, 171, 174, 177, 180,
+    183, 186, 189, 192, 195, 198, 201, 204, 207, 210, 213, 216,
+    219, 222, 225, 228, 231, 234, 237, 240, 243, 246, 249, 252,
+    255, 258, 261, 264, 267, 270, 273, 276, 279, 282, 285, 288,
+    291, 294, 297, 300, 303, 306, 309, 312, 315, 318, 321, 324,
+    327, 330, 333, 336, 339, 342, 345, 348, 351, 354, 357, 360,
+    363, 366, 369, 372, 375, 378, 381, 384, 387, 390, 393, 396,
+    399, 402, 405, 408, 411, 414, 417, 420, 423, 426, 429, 432,
+    435, 438, 441, 444, 447, 450, 453, 456, 459, 462, 465, 468,
+    471, 474, 477, 480, 483, 486, 489, 492, 495, 498, 501, 504,
+    507, 510, 513, 516, 519, 524, 529, 534, 539, 544, 549, 554,
+    559, 564, 569, 574, 579, 583, 587, 590, 593, 596, 599, 602,
+    605, 610, 615, 619, 623, 626, 629, 632, 635, 638, 643, 648,
+    652, 656, 660, 664, 667, 670, 673, 676, 679, 682, 685, 688,
+    691, 694, 697, 700, 703, 706, 709, 712, 715, 718, 721, 724,
+    727, 730, 733, 736, 739, 742, 745, 748, 751, 754, 757, 760,
+    763, 766, 771, 776, 781, 786, 789, 792, 797, 802, 805, 808,
+    810, 812, 814, 818, 820, 821, 825, 829, 831, 835, 839, 843,
+    847, 851, 855, 861, 865, 869, 873, 877, 881, 885, 891, 895,
+    899, 903, 907, 911, 915, 919, 923, 927, 931, 935, 939, 943,
+    947, 951, 955, 959, 963, 967, 971, 975, 979, 983, 987, 991,
+    995, 999, 1003, 1007, 1011, 1015, 1019, 1023, 1027, 1031, 1035, 1039,
+    1043, 1047, 1051, 1055, 1059, 1063, 1067, 1071, 1075, 1079, 1083, 1087,
+    1091, 1095, 1099, 1103, 1107, 1111, 1115, 1119, 1123, 1127, 1131, 1135,
+    1139, 1143, 1147, 1151, 1155, 1159, 1165, 1171, 1177, 1183, 1189, 1195,
+    1201, 1207, 1213, 1219, 1225, 1231, 1237, 1243, 1249, 1255, 1261, 1267,
+    1273, 1279, 1285, 1291, 1297, 1303, 1309, 1315, 1321, 1327, 1333, 1339,
+    1345, 1351, 1357, 1363, 1369, 1375, 1384, 1390, 1396, 1402, 1408, 1414,
+    1423, 1429, 1435, 1441, 1447, 1453, 1459, 1465, 1471, 1477, 1483, 1489,
+    1495, 1501, 1507, 1513, 1519, 1525, 1531, 1537, 1543, 1549, 1555, 1561,
+    1567, 1573, 1579, 1585, 1591, 1597, 1603, 1606, 1609, 1612, 1615, 1618,
+    1621, 1624, 1627, 1632, 1637, 1640, 1643, 1646, 1649, 1652, 1655, 1658,
+    1661, 1664, 1667, 1672, 1677, 1682, 1687, 1690, 1693, 1696, 1699, 1704,
+    1709, 1712, 1715, 1718, 1721, 1724, 1727, 1730, 1733, 1736, 1739, 1742,
+    1745, 1748, 1751, 1754, 1757, 1762, 1767, 1770, 1773, 1776, 1779, 1782,
+    1785, 1788, 1791, 1796, 1801, 1804, 1807, 1810, 1813, 1816, 1819, 1822,
+    1825, 1828, 1831, 1834, 1837, 1840, 1843, 1846, 1849, 1852, 1855, 1860,
+    1865, 1870, 1875, 1880, 1885, 1890, 1895, 1898, 1901, 1904, 1907, 1910,
+    1913, 1916, 1919, 1924, 1929, 1932, 1935, 1938, 1941, 1944, 1947, 1952,
+    1957, 1962, 1967, 1972, 1977, 1980, 1983, 1986, 1989, 1992, 1995, 1998,
+    2001, 2004, 2007, 2010, 2013, 2016, 2019, 2024, 2029, 2034, 2039, 2042,
+    2045, 2048, 2051, 2054, 2057, 2060, 2063, 2066, 2069, 2072, 2075, 2078,
+    2081, 2084, 2087, 2090, 2093, 2096, 2099, 2102, 2105, 2108, 2111, 2114,
+    2117, 2120, 2123, 2126, 2129, 2133, 2136, 2139, 2142, 2145, 2150, 2155,
+    2160, 2165, 2170, 2175, 2180, 2185, 2190, 2195, 2200, 2205, 2210, 2215,
+    2220, 2225, 2230, 2235, 2240, 2245, 2248, 2251, 2254, 2257, 2260, 2263,
+    2268, 2273, 2278, 2283, 2288, 2293, 2298, 2303, 2308, 2313, 2316, 2319,
+    2322, 2325, 2328, 2331, 2334, 2337, 2342, 2347, 2352, 2357, 2362, 2367,
+    2372, 2377, 2382, 2387, 2392, 2397, 2402, 2407, 2412, 2417, 2422, 2427,
+    2432, 2437, 2440, 2443, 2446, 2449, 2454, 2459, 2464, 2469, 2474, 2479,
+    2484, 2489, 2494, 2499, 2502, 2505, 2508, 2511, 2514, 2517, 2520, 2523,
+    2527, 2531, 2537, 2543, 2549, 2555, 2561, 2567, 2571, 2575, 2581, 2587,
+    2593, 2599, 2605, 2611, 2615, 2619, 2625, 2631, 2637, 2643, 2647, 2651,
+    2657, 2663, 2669, 2675, 2679, 2683, 2689, 2695, 2701, 2707, 2713, 2719,
+    2723, 2727, 2733, 2739, 2745, 2751, 2757, 2763, 2767, 2771, 2777, 2783,
+    2789, 2795, 2801, 2807, 2811, 2815, 2821, 2827, 2833, 2839, 2845, 2851,
+    2855, 2859, 2865, 2871, 2877, 2883, 2887, 2891, 2897, 2903, 2909, 2915,
+    2919, 2923, 2929, 2935, 2941, 2947, 2953, 2959, 2963, 2969, 2975, 2981,
+    2985, 2989, 2995, 3001, 3007, 3013, 3019, 3025, 3029, 3033, 3039, 3045,
+    3051, 3057, 3063, 3069, 3073, 3077, 3081, 3085, 3089, 3093, 3097, 3101,
+    3105, 3109, 3113, 3117, 3121, 3125, 3131, 3137, 3145, 3153, 3161, 3169,
+    3177, 3185, 3191, 3197, 3205, 3213, 3221, 3229, 3237, 3245, 3251, 3257,
+    3265, 3273, 3281, 3289, 3297, 3305, 3311, 3317, 3325, 3333, 3341, 3349,
+    3357, 3365, 3371, 3377, 3385, 3393, 3401, 3409, 3417, 3425, 3431, 3437,
+    3445, 3453, 3461, 3469, 3477, 3485, 3489, 3493, 3499, 3503, 3509, 3513,
+    3519, 3523, 3527, 3531, 3535, 3539, 3541, 3545, 3551, 3555, 3561, 3565,
+    3571, 3575, 3579, 3583, 3587, 3591, 3596, 3601, 3606, 3610, 3614, 3620,
+    3626, 3630, 3636, 3640, 3644, 3648, 3652, 3657, 3662, 3667, 3671, 3675,
+    3681, 3687, 3691, 3695, 3699, 3705, 3709, 3713, 3717, 3721, 3725, 3729,
+    3733, 3734, 3740, 3744, 3750, 3754, 3760, 3764, 3768, 3772, 3776, 3780,
+    3782, 3785, 3788, 3790, 3791, 3794, 3799, 3804, 3809, 3814, 3819, 3824,
+    3829, 3834, 3839, 3844, 3849, 3854, 3859, 3864, 3869, 3872, 3877, 3882,
+    3885, 3888, 3893, 3898, 3903, 3908, 3913, 3918, 3923, 3928, 3933, 3938,
+    3943, 3948, 3953, 3958, 3963, 3968, 3973, 3978, 3983, 3988, 3993, 3998,
+    4003, 4008, 4011, 4014, 4019, 4025, 4031, 4037, 4043, 4049, 4055, 4061,
+    4067, 4073, 4079, 4085, 4091, 4097, 4103, 4109, 4115, 4121, 4127, 4133,
+    4139, 4145, 4151, 4157, 4163, 4169, 4175, 4181, 4187, 4193, 4199, 4205,
+    4211, 4217, 4223, 4229, 4235, 4241, 4247, 4253, 4259, 4265, 4271, 4277,
+    4283, 4289, 4295, 4301, 4307, 4313, 4319, 4325, 4331, 4337, 4343, 4349,
+    4355, 4361, 4367, 4370, 4373, 4376, 4379, 4382, 4385, 4388, 4391, 4394,
+    4397, 4400, 4403, 4406, 4409, 4412, 4415, 4418, 4421, 4424, 4427, 4430,
+    4433, 4436, 4439, 4442, 4445, 4448, 4451, 4454, 4457, 4460, 4463, 4466,
+    4469, 4472, 4475, 4478, 4481, 4484, 4487, 4490, 4493, 4496, 4499, 4502,
+    4505, 4508, 4511, 4514, 4517, 4520, 4523, 4526, 4529, 4532, 4535, 4538,
+    4541, 4544, 4547, 4550, 4553, 4556, 4559, 4562, 4565, 4568, 4571, 4574,
+    4577, 4580, 4583, 4586, 4589, 4592, 4595, 4598, 4601, 4604, 4607, 4610,
+    4613, 4616, 4619, 4622, 4625, 4628, 4631, 4634, 4637, 4640, 4643, 4646,
+    4649, 4652, 4655, 4658, 4661, 4664, 4667, 4670, 4673, 4676, 4679, 4682,
+    4685, 4688, 4691, 4694, 4697, 4700, 4703, 4706, 4709, 4712, 4715, 4718,
+    4721, 4724, 4727, 4730, 4733, 4736, 4739, 4742, 4745, 4748, 4751, 4754,
+    4757, 4760, 4763, 4766, 4769, 4772, 4775, 4778, 4781, 4784, 4787, 4790,
+    4793, 4796, 4799, 4802, 4805, 4808, 4811, 4814, 4817, 4820, 4823, 4826,
+    4829, 4832, 4835, 4838, 4841, 4844, 4847, 4850, 4853, 4856, 4859, 4862,
+    4865, 4868, 4871, 4874, 4877, 4880, 4883, 4886, 4889, 4892, 4895, 4898,
+    4901, 4904, 4907, 4910, 4913, 4916, 4919, 4922, 4925, 4928, 4931, 4934,
+    4937, 4940, 4943, 4946, 4949, 4952, 4955, 4958, 4961, 4964, 4967, 4970,
+    4973, 4976, 4979, 4982, 4985, 4988, 4991, 4994, 4997, 5000, 5003, 5006,
+    5009, 5012, 5015, 5018, 5021, 5024, 5027, 5030, 5033, 5036, 5039, 5042,
+    5045, 5048, 5051, 5054, 5057, 5060, 5063, 5066, 5069, 5072, 5075, 5078,
+    5081, 5084, 5087, 5090, 5093, 5096, 5099, 5102, 5105, 5108, 5111, 5114,
+    5117, 5120, 5123, 5126, 5129, 5132, 5135, 5138, 5141, 5144, 5147, 5150,
+    5153, 5156, 5159, 5162, 5165, 5168, 5171, 5174, 5177, 5180, 5183, 5186,
+    5189, 5192, 5195, 5198, 5201, 5204, 5207, 5210, 5213, 5216, 5219, 5222,
+    5225, 5228, 5231, 5234, 5237, 5240, 5243, 5246, 5249, 5252, 5255, 5258,
+    5261, 5264, 5267, 5270, 5273, 5276, 5279, 5282, 5285, 5288, 5291, 5294,
+    5297, 5300, 5303, 5306, 5309, 5312, 5315, 5318, 5321, 5324, 5327, 5330,
+    5333, 5336, 5339, 5342, 5345, 5348, 5351, 5354, 5357, 5360, 5363, 5366,
+    5369, 5372, 5375, 5378, 5381, 5384, 5387, 5390, 5393, 5396, 5399, 5402,
+    5405, 5408, 5411, 5414, 5417, 5420, 5423, 5427, 5430, 5433, 5436, 5439,
+    5442, 5445, 5448, 5451, 5454, 5457, 5460, 5463, 5466, 5469, 5472, 5475,
+    5478, 5481, 5484, 5487, 5490, 5493, 5496, 5499, 5502, 5505, 5508, 5511,
+    5514, 5517, 5520, 5523, 5526, 5529, 5532, 5535, 5538, 5541, 5544, 5547,
+    5550, 5553, 5556, 5559, 5562, 5565, 5568, 5571, 5574, 5577, 5580, 5583,
+    5586, 5589, 5592, 5595, 5598, 5601, 5604, 5607, 5610, 5613, 5616, 5619,
+    5622, 5625, 5628, 5631, 5634, 5637, 5640, 5643, 5646, 5649, 5652, 5655,
+    5658, 5661, 5664, 5667, 5670, 5673, 5676, 5679, 5682, 5685, 5688, 5691,
+    5694, 5697, 5700, 5703, 5706, 5709, 5712, 5715, 5719, 5723, 5727, 5730,
+    5733, 5736, 5740, 5744, 5748, 5751, 5754, 5758, 5762, 5766, 5770, 5776,
+    5782, 5786, 5790, 5794, 5798, 5802, 5806, 5810, 5814, 5818, 5822, 5826,
+    5830, 5834, 5838, 5842, 5846, 5850, 5854, 5858, 5862, 5866, 5870, 5874,
+    5878, 5882, 5886, 5890, 5894, 5900, 5906, 5914, 5922, 5930, 5938, 5946,
+    5954, 5962, 5970, 5978, 5986, 5994, 6002, 6010, 6018, 6026, 6034, 6042,
+    6050, 6058, 6066, 6074, 6082, 6090, 6098, 6106, 6118, 6130, 6142, 6150,
+    6158, 6170, 6178, 6186, 6198, 6210, 6222, 6234, 6246, 6254, 6262, 6274,
+    6286, 6298, 6310, 6313, 6316, 6319, 6323, 6326, 6329, 6332, 6335, 6338,
+    6341, 6344, 6347, 6350, 6354, 6357, 6360, 6363, 6366, 6370, 6373, 6376,
+    6379, 6383, 6386, 6389, 6392, 6395, 6398, 6402, 6405, 6408, 6411, 6414,
+    6417, 6420, 6423, 6426, 6429, 6432, 6435, 6438, 6441, 6444, 6447, 6450,
+    6453, 6456, 6459, 6462, 6465, 6468, 6471, 6475, 6478, 6481, 6484, 6488,
+    6491, 6494, 6497, 6500, 6503, 6506, 6509, 6512, 6515, 6518, 6521, 6524,
+    6527, 6530, 6533, 6536, 6539, 6542, 6545, 6548, 6551, 6554, 6557, 6560,
+    6563, 6566, 6569, 6572, 6575, 6578, 6581, 6584, 6588, 6591, 6594, 6597,
+    6600, 6603, 6606, 6610, 6614, 6617, 6620, 6623, 6626, 6629, 6632, 6635,
+    6638, 6641, 6644, 6648, 6651, 6654, 6657, 6660, 6664, 6667, 6670, 6673,
+    6676, 6679, 6682, 6685, 6688, 6691, 6695, 6698, 6702, 6705, 6708, 6711,
+    6714, 6717, 6720, 6723, 6726, 6729, 6732, 6735, 6739, 6742, 6745, 6748,
+    6751, 6754, 6758, 6761, 6765, 6769, 6772, 6775, 6778, 6781, 6785, 6789,
+    6792, 6795, 6798, 6801, 6804, 6807, 6810, 6813, 6816, 6819, 6822, 6826,
+    6829, 6832, 6835, 6838, 6841, 6844, 6847, 6850, 6853, 6856, 6859, 6862,
+    6865, 6868, 6871, 6874, 6877, 6880, 6883, 6887, 6890, 6893, 6896, 6899,
+    6902, 6906, 6909, 6912, 6915, 6918, 6921, 6924, 6927, 6930, 6933, 6936,
+    6939, 6943, 6946, 6949, 6952, 6955, 6958, 6961, 6964, 6967, 6970, 6973,
+    6976, 6979, 6982, 6985, 6988, 6991, 6994, 6997, 7001, 7004, 7007, 7010,
+    7013, 7016, 7020, 7023, 7026, 7029, 7032, 7035, 7038, 7041, 7044, 7048,
+    7051, 7054, 7057, 7061, 7064, 7067, 7070, 7073, 7076, 7079, 7083, 7087,
+    7091, 7094, 7098, 7101, 7104, 7107, 7110, 7113, 7116, 7119, 7122, 7125,
+    7128, 7132, 7135, 7138, 7141, 7144, 7147, 7150, 7154, 7157, 7160, 7164,
+    7168, 7171, 7174, 7177, 7180, 7183, 7186, 7189, 7192, 7195, 7199, 7202,
+    7206, 7209, 7213, 7216, 7219, 7222, 7226, 7229, 7232, 7236, 7240, 7243,
+    7246, 7249, 7252, 7255, 7258, 7261, 7264, 7267, 7270, 7273, 7276, 7279,
+    7283, 7286, 7290, 7293, 7297, 7300, 7304, 7308, 7312, 7315, 7318, 7321,
+    7325, 7329, 7333, 7337, 7340, 7343, 7346, 7349, 7352, 7355, 7358, 7361,
+    7365, 7368, 7371, 7374, 7377, 7381, 7384, 7388, 7392, 7395, 7398, 7401,
+    7404, 7407, 7410, 7414, 7418, 7422, 7425, 7428, 7432, 7435, 7438, 7441,
+    7445, 7448, 7451, 7454, 7457, 7460, 7464, 7467, 7470, 7473, 7476, 7479,
+    7482, 7486, 7490, 7493, 7497, 7500, 7504, 7507, 7510, 7513, 7517, 7521,
+    7524, 7528, 7531, 7535, 7538, 7541, 7544, 7547, 7550, 7553, 7557, 7561,
+    7565, 7569, 7572, 7575, 7578, 7581, 7584, 7587, 7590, 7593, 7596, 7599,
+    7602, 7605, 7609, 7612, 7615, 7618, 7621, 7624, 7627, 7630, 7633, 7636,
+    7639, 7642, 7645, 7649, 7653, 7657, 7660, 7663, 7666, 7669, 7673, 7676,
+    7680, 7683, 7686, 7690, 7694, 7697, 7700, 7703, 7706, 7709, 7712, 7715,
+    7718, 7721, 7724, 7727, 7730, 7733, 7736, 7739, 7742, 7745, 7748, 7751,
+    7755, 7758, 7761, 7764, 7767, 7770, 7774, 7778, 7781, 7784, 7787, 7790,
+    7793, 7796, 7800, 7803, 7806, 7809, 7812, 7816, 7820, 7823, 7826, 7829,
+    7833, 7836, 7839, 7843, 7847, 7850, 7853, 7856, 7860, 7863, 7866, 7869,
+    7872, 7875, 7878, 7881, 7885, 7888, 7891, 7894, 7898, 7901, 7904, 7907,
+    7910, 7914, 7918, 7921, 7924, 7927, 7931, 7934, 7938, 7941, 7944, 7947,
+    7951, 7954, 7957, 7960, 7963, 7966, 7969, 7972, 7976, 7979, 7982, 7985,
+    7988, 7991, 7994, 7998, 8001, 8005, 8009, 8013, 8016, 8019, 8022, 8025,
+    8028, 8031, 8034, 8037, 8041,
+];
+pub(crate) static CANONICAL_DECOMPOSITION_STRINGS: &str = "\
+    \u{41}\u{300}\u{41}\u{301}\u{41}\u{302}\u{41}\u{303}\u{41}\u{308}\u{41}\u{30A}\
+    \u{43}\u{327}\u{45}\u{300}\u{45}\u{301}\u{45}\u{302}\u{45}\u{308}\u{49}\u{300}\
+    \u{49}\u{301}\u{49}\u{302}\u{49}\u{308}\u{4E}\u{303}\u{4F}\u{300}\u{4F}\u{301}\
+    \u{4F}\u{302}\u{4F}\u{303}\u{4F}\u{308}\u{55}\u{300}\u{55}\u{301}\u{55}\u{302}\
+    \u{55}\u{308}\u{59}\u{301}\u{61}\u{300}\u{61}\u{301}\u{61}\u{302}\u{61}\u{303}\
+    \u{61}\u{308}\u{61}\u{30A}\u{63}\u{327}\u{65}\u{300}\u{65}\u{301}\u{65}\u{302}\
+    \u{65}\u{308}\u{69}\u{300}\u{69}\u{301}\u{69}\u{302}\u{69}\u{308}\u{6E}\u{303}\
+    \u{6F}\u{300}\u{6F}\u{301}\u{6F}\u{302}\u{6F}\u{303}\u{6F}\u{308}\u{75}\u{300}\
+    \u{75}\u{301}\u{75}\u{302}\u{75}\u{308}\u{79}\u{301}\u{79}\u{308}\u{41}\u{304}\
+    \u{61}\u{304}\u{41}\u{306}\u{61}\u{306}\u{41}\u{328}\u{61}\u{328}\u{43}\u{301}\
+    \u{63}\u{301}\u{43}\u{302}\u{63}\u{302}\u{43}\u{307}\u{63}\u{307}\u{43}\u{30C}\
+    \u{63}\u{30C}\u{44}\u{30C}\u{64}\u{30C}\u{45}\u{304}\u{65}\u{304}\u{45}\u{306}\
+    \u{65}\u{306}\u{45}\u{307}\u{65}\u{307}\u{45}\u{328}\u{65}\u{328}\u{45}\u{30C}\
+    \u{65}\u{30C}\u{47}\u{302}\u{67}\u{302}\u{47}\u{306}\u{67}\u{306}\u{47}\u{307}\
+    \u{67}\u{307}\u{47}\u{327}\u{67}\u{327}\u{48}\u{302}\u{68}\u{302}\u{49}\u{303}\
+    \u{69}\u{303}\u{49}\u{304}\u{69}\u{304}\u{49}\u{306}\u{69}\u{306}\u{49}\u{328}\
+    \u{69}\u{328}\u{49}\u{307}\u{4A}\u{302}\u{6A}\u{302}\u{4B}\u{327}\u{6B}\u{327}\
+    \u{4C}\u{301}\u{6C}\u{301}\u{4C}\u{327}\u{6C}\u{327}\u{4C}\u{30C}\u{6C}\u{30C}\
+    \u{4E}\u{301}\u{6E}\u{301}\u{4E}\u{327}\u{6E}\u{327}\u{4E}\u{30C}\u{6E}\u{30C}\
+    \u{4F}\u{304}\u{6F}\u{304}\u{4F}\u{306}\u{6F}\u{306}\u{4F}\u{30B}\u{6F}\u{30B}\
+    \u{52}\u{301}\u{72}\u{301}\u{52}\u{327}\u{72}\u{327}\u{52}\u{30C}\u{72}\u{30C}\
+    \u{53}\u{301}\u{73}\u{301}\u{53}\u{302}\u{73}\u{302}\u{53}\u{327}\u{73}\u{327}\
+    \u{53}\u{30C}\u{73}\u{30C}\u{54}\u{327}\u{74}\u{327}\u{54}\u{30C}\u{74}\u{30C}\
+    \u{55}\u{303}\u{75}\u{303}\u{55}\u{304}\u{75}\u{304}\u{55}\u{306}\u{75}\u{306}\
+    \u{55}\u{30A}\u{75}\u{30A}\u{55}\u{30B}\u{75}\u{30B}\u{55}\u{328}\u{75}\u{328}\
+    \u{57}\u{302}\u{77}\u{302}\u{59}\u{302}\u{79}\u{302}\u{59}\u{308}\u{5A}\u{301}\
+    \u{7A}\u{301}\u{5A}\u{307}\u{7A}\u{307}\u{5A}\u{30C}\u{7A}\u{30C}\u{4F}\u{31B}\
+    \u{6F}\u{31B}\u{55}\u{31B}\u{75}\u{31B}\u{41}\u{30C}\u{61}\u{30C}\u{49}\u{30C}\
+    \u{69}\u{30C}\u{4F}\u{30C}\u{6F}\u{30C}\u{55}\u{30C}\u{75}\u{30C}\u{55}\u{308}\
+    \u{304}\u{75}\u{308}\u{304}\u{55}\u{308}\u{301}\u{75}\u{308}\u{301}\u{55}\u{308}\
+    \u{30C}\u{75}\u{308}\u{30C}\u{55}\u{308}\u{300}\u{75}\u{308}\u{300}\u{41}\u{308}\
+    \u{304}\u{61}\u{308}\u{304}\u{41}\u{307}\u{304}\u{61}\u{307}\u{304}\u{C6}\u{304}\
+    \u{E6}\u{304}\u{47}\u{30C}\u{67}\u{30C}\u{4B}\u{30C}\u{6B}\u{30C}\u{4F}\u{328}\
+    \u{6F}\u{328}\u{4F}\u{328}\u{304}\u{6F}\u{328}\u{304}\u{1B7}\u{30C}\u{292}\u{30C}\
+    \u{6A}\u{30C}\u{47}\u{301}\u{67}\u{301}\u{4E}\u{300}\u{6E}\u{300}\u{41}\u{30A}\
+    \u{301}\u{61}\u{30A}\u{301}\u{C6}\u{301}\u{E6}\u{301}\u{D8}\u{301}\u{F8}\u{301}\
+    \u{41}\u{30F}\u{61}\u{30F}\u{41}\u{311}\u{61}\u{311}\u{45}\u{30F}\u{65}\u{30F}\
+    \u{45}\u{311}\u{65}\u{311}\u{49}\u{30F}\u{69}\u{30F}\u{49}\u{311}\u{69}\u{311}\
+    \u{4F}\u{30F}\u{6F}\u{30F}\u{4F}\u{311}\u{6F}\u{311}\u{52}\u{30F}\u{72}\u{30F}\
+    \u{52}\u{311}\u{72}\u{311}\u{55}\u{30F}\u{75}\u{30F}\u{55}\u{311}\u{75}\u{311}\
+    \u{53}\u{326}\u{73}\u{326}\u{54}\u{326}\u{74}\u{326}\u{48}\u{30C}\u{68}\u{30C}\
+    \u{41}\u{307}\u{61}\u{307}\u{45}\u{327}\u{65}\u{327}\u{4F}\u{308}\u{304}\u{6F}\
+    \u{308}\u{304}\u{4F}\u{303}\u{304}\u{6F}\u{303}\u{304}\u{4F}\u{307}\u{6F}\u{307}\
+    \u{4F}\u{307}\u{304}\u{6F}\u{307}\u{304}\u{59}\u{304}\u{79}\u{304}\u{300}\u{301}\
+    \u{313}\u{308}\u{301}\u{2B9}\u{3B}\u{A8}\u{301}\u{391}\u{301}\u{B7}\u{395}\u{301}\
+    \u{397}\u{301}\u{399}\u{301}\u{39F}\u{301}\u{3A5}\u{301}\u{3A9}\u{301}\u{3B9}\u{308}\
+    \u{301}\u{399}\u{308}\u{3A5}\u{308}\u{3B1}\u{301}\u{3B5}\u{301}\u{3B7}\u{301}\u{3B9}\
+    \u{301}\u{3C5}\u{308}\u{301}\u{3B9}\u{308}\u{3C5}\u{308}\u{3BF}\u{301}\u{3C5}\u{301}\
+    \u{3C9}\u{301}\u{3D2}\u{301}\u{3D2}\u{308}\u{415}\u{300}\u{415}\u{308}\u{413}\u{301}\
+    \u{406}\u{308}\u{41A}\u{301}\u{418}\u{300}\u{423}\u{306}\u{418}\u{306}\u{438}\u{306}\
+    \u{435}\u{300}\u{435}\u{308}\u{433}\u{301}\u{456}\u{308}\u{43A}\u{301}\u{438}\u{300}\
+    \u{443}\u{306}\u{474}\u{30F}\u{475}\u{30F}\u{416}\u{306}\u{436}\u{306}\u{410}\u{306}\
+    \u{430}\u{306}\u{410}\u{308}\u{430}\u{308}\u{415}\u{306}\u{435}\u{306}\u{4D8}\u{308}\
+    \u{4D9}\u{308}\u{416}\u{308}\u{436}\u{308}\u{417}\u{308}\u{437}\u{308}\u{418}\u{304}\
+    \u{438}\u{304}\u{418}\u{308}\u{438}\u{308}\u{41E}\u{308}\u{43E}\u{308}\u{4E8}\u{308}\
+    \u{4E9}\u{308}\u{42D}\u{308}\u{44D}\u{308}\u{423}\u{304}\u{443}\u{304}\u{423}\u{308}\
+    \u{443}\u{308}\u{423}\u{30B}\u{443}\u{30B}\u{427}\u{308}\u{447}\u{308}\u{42B}\u{308}\
+    \u{44B}\u{308}\u{627}\u{653}\u{627}\u{654}\u{648}\u{654}\u{627}\u{655}\u{64A}\u{654}\
+    \u{6D5}\u{654}\u{6C1}\u{654}\u{6D2}\u{654}\u{928}\u{93C}\u{930}\u{93C}\u{933}\u{93C}\
+    \u{915}\u{93C}\u{916}\u{93C}\u{917}\u{93C}\u{91C}\u{93C}\u{921}\u{93C}\u{922}\u{93C}\
+    \u{92B}\u{93C}\u{92F}\u{93C}\u{9C7}\u{9BE}\u{9C7}\u{9D7}\u{9A1}\u{9BC}\u{9A2}\u{9BC}\
+    \u{9AF}\u{9BC}\u{A32}\u{A3C}\u{A38}\u{A3C}\u{A16}\u{A3C}\u{A17}\u{A3C}\u{A1C}\u{A3C}\
+    \u{A2B}\u{A3C}\u{B47}\u{B56}\u{B47}\u{B3E}\u{B47}\u{B57}\u{B21}\u{B3C}\u{B22}\u{B3C}\
+    \u{B92}\u{BD7}\u{BC6}\u{BBE}\u{BC7}\u{BBE}\u{BC6}\u{BD7}\u{C46}\u{C56}\u{CBF}\u{CD5}\
+    \u{CC6}\u{CD5}\u{CC6}\u{CD6}\u{CC6}\u{CC2}\u{CC6}\u{CC2}\u{CD5}\u{D46}\u{D3E}\u{D47}\
+    \u{D3E}\u{D46}\u{D57}\u{DD9}\u{DCA}\u{DD9}\u{DCF}\u{DD9}\u{DCF}\u{DCA}\u{DD9}\u{DDF}\
+    \u{F42}\u{FB7}\u{F4C}\u{FB7}\u{F51}\u{FB7}\u{F56}\u{FB7}\u{F5B}\u{FB7}\u{F40}\u{FB5}\
+    \u{F71}\u{F72}\u{F71}\u{F74}\u{FB2}\u{F80}\u{FB3}\u{F80}\u{F71}\u{F80}\u{F92}\u{FB7}\
+    \u{F9C}\u{FB7}\u{FA1}\u{FB7}\u{FA6}\u{FB7}\u{FAB}\u{FB7}\u{F90}\u{FB5}\u{1025}\u{102E}\
+    \u{1B05}\u{1B35}\u{1B07}\u{1B35}\u{1B09}\u{1B35}\u{1B0B}\u{1B35}\u{1B0D}\u{1B35}\u{1B11}\u{1B35}\
+    \u{1B3A}\u{1B35}\u{1B3C}\u{1B35}\u{1B3E}\u{1B35}\u{1B3F}\u{1B35}\u{1B42}\u{1B35}\u{41}\u{325}\
+    \u{61}\u{325}\u{42}\u{307}\u{62}\u{307}\u{42}\u{323}\u{62}\u{323}\u{42}\u{331}\
+    \u{62}\u{331}\u{43}\u{327}\u{301}\u{63}\u{327}\u{301}\u{44}\u{307}\u{64}\u{307}\
+    \u{44}\u{323}\u{64}\u{323}\u{44}\u{331}\u{64}\u{331}\u{44}\u{327}\u{64}\u{327}\
+    \u{44}\u{32D}\u{64}\u{32D}\u{45}\u{304}\u{300}\u{65}\u{304}\u{300}\u{45}\u{304}\
+    \u{301}\u{65}\u{304}\u{301}\u{45}\u{32D}\u{65}\u{32D}\u{45}\u{330}\u{65}\u{330}\
+    \u{45}\u{327}\u{306}\u{65}\u{327}\u{306}\u{46}\u{307}\u{66}\u{307}\u{47}\u{304}\
+    \u{67}\u{304}\u{48}\u{307}\u{68}\u{307}\u{48}\u{323}\u{68}\u{323}\u{48}\u{308}\
+    \u{68}\u{308}\u{48}\u{327}\u{68}\u{327}\u{48}\u{32E}\u{68}\u{32E}\u{49}\u{330}\
+    \u{69}\u{330}\u{49}\u{308}\u{301}\u{69}\u{308}\u{301}\u{4B}\u{301}\u{6B}\u{301}\
+    \u{4B}\u{323}\u{6B}\u{323}\u{4B}\u{331}\u{6B}\u{331}\u{4C}\u{323}\u{6C}\u{323}\
+    \u{4C}\u{323}\u{304}\u{6C}\u{323}\u{304}\u{4C}\u{331}\u{6C}\u{331}\u{4C}\u{32D}\
+    \u{6C}\u{32D}\u{4D}\u{301}\u{6D}\u{301}\u{4D}\u{307}\u{6D}\u{307}\u{4D}\u{323}\
+    \u{6D}\u{323}\u{4E}\u{307}\u{6E}\u{307}\u{4E}\u{323}\u{6E}\u{323}\u{4E}\u{331}\
+    \u{6E}\u{331}\u{4E}\u{32D}\u{6E}\u{32D}\u{4F}\u{303}\u{301}\u{6F}\u{303}\u{301}\
+    \u{4F}\u{303}\u{308}\u{6F}\u{303}\u{308}\u{4F}\u{304}\u{300}\u{6F}\u{304}\u{300}\
+    \u{4F}\u{304}\u{301}\u{6F}\u{304}\u{301}\u{50}\u{301}\u{70}\u{301}\u{50}\u{307}\
+    \u{70}\u{307}\u{52}\u{307}\u{72}\u{307}\u{52}\u{323}\u{72}\u{323}\u{52}\u{323}\
+    \u{304}\u{72}\u{323}\u{304}\u{52}\u{331}\u{72}\u{331}\u{53}\u{307}\u{73}\u{307}\
+    \u{53}\u{323}\u{73}\u{323}\u{53}\u{301}\u{307}\u{73}\u{301}\u{307}\u{53}\u{30C}\
+    \u{307}\u{73}\u{30C}\u{307}\u{53}\u{323}\u{307}\u{73}\u{323}\u{307}\u{54}\u{307}\
+    \u{74}\u{307}\u{54}\u{323}\u{74}\u{323}\u{54}\u{331}\u{74}\u{331}\u{54}\u{32D}\
+    \u{74}\u{32D}\u{55}\u{324}\u{75}\u{324}\u{55}\u{330}\u{75}\u{330}\u{55}\u{32D}\
+    \u{75}\u{32D}\u{55}\u{303}\u{301}\u{75}\u{303}\u{301}\u{55}\u{304}\u{308}\u{75}\
+    \u{304}\u{308}\u{56}\u{303}\u{76}\u{303}\u{56}\u{323}\u{76}\u{323}\u{57}\u{300}\
+    \u{77}\u{300}\u{57}\u{301}\u{77}\u{301}\u{57}\u{308}\u{77}\u{308}\u{57}\u{307}\
+    \u{77}\u{307}\u{57}\u{323}\u{77}\u{323}\u{58}\u{307}\u{78}\u{307}\u{58}\u{308}\
+    \u{78}\u{308}\u{59}\u{307}\u{79}\u{307}\u{5A}\u{302}\u{7A}\u{302}\u{5A}\u{323}\
+    \u{7A}\u{323}\u{5A}\u{331}\u{7A}\u{331}\u{68}\u{331}\u{74}\u{308}\u{77}\u{30A}\
+    \u{79}\u{30A}\u{17F}\u{307}\u{41}\u{323}\u{61}\u{323}\u{41}\u{309}\u{61}\u{309}\
+    \u{41}\u{302}\u{301}\u{61}\u{302}\u{301}\u{41}\u{302}\u{300}\u{61}\u{302}\u{300}\
+    \u{41}\u{302}\u{309}\u{61}\u{302}\u{309}\u{41}\u{302}\u{303}\u{61}\u{302}\u{303}\
+    \u{41}\u{323}\u{302}\u{61}\u{323}\u{302}\u{41}\u{306}\u{301}\u{61}\u{306}\u{301}\
+    \u{41}\u{306}\u{300}\u{61}\u{306}\u{300}\u{41}\u{306}\u{309}\u{61}\u{306}\u{309}\
+    \u{41}\u{306}\u{303}\u{61}\u{306}\u{303}\u{41}\u{323}\u{306}\u{61}\u{323}\u{306}\
+    \u{45}\u{323}\u{65}\u{323}\u{45}\u{309}\u{65}\u{309}\u{45}\u{303}\u{65}\u{303}\
+    \u{45}\u{302}\u{301}\u{65}\u{302}\u{301}\u{45}\u{302}\u{300}\u{65}\u{302}\u{300}\
+    \u{45}\u{302}\u{309}\u{65}\u{302}\u{309}\u{45}\u{302}\u{303}\u{65}\u{302}\u{303}\
+    \u{45}\u{323}\u{302}\u{65}\u{323}\u{302}\u{49}\u{309}\u{69}\u{309}\u{49}\u{323}\
+    \u{69}\u{323}\u{4F}\u{323}\u{6F}\u{323}\u{4F}\u{309}\u{6F}\u{309}\u{4F}\u{302}\
+    \u{301}\u{6F}\u{302}\u{301}\u{4F}\u{302}\u{300}\u{6F}\u{302}\u{300}\u{4F}\u{302}\
+    \u{309}\u{6F}\u{302}\u{309}\u{4F}\u{302}\u{303}\u{6F}\u{302}\u{303}\u{4F}\u{323}\
+    \u{302}\u{6F}\u{323}\u{302}\u{4F}\u{31B}\u{301}\u{6F}\u{31B}\u{301}\u{4F}\u{31B}\
+    \u{300}\u{6F}\u{31B}\u{300}\u{4F}\u{31B}\u{309}\u{6F}\u{31B}\u{309}\u{4F}\u{31B}\
+    \u{303}\u{6F}\u{31B}\u{303}\u{4F}\u{31B}\u{323}\u{6F}\u{31B}\u{323}\u{55}\u{323}\
+    \u{75}\u{323}\u{55}\u{309}\u{75}\u{309}\u{55}\u{31B}\u{301}\u{75}\u{31B}\u{301}\
+    \u{55}\u{31B}\u{300}\u{75}\u{31B}\u{300}\u{55}\u{31B}\u{309}\u{75}\u{31B}\u{309}\
+    \u{55}\u{31B}\u{303}\u{75}\u{31B}\u{303}\u{55}\u{31B}\u{323}\u{75}\u{31B}\u{323}\
+    \u{59}\u{300}\u{79}\u{300}\u{59}\u{323}\u{79}\u{323}\u{59}\u{309}\u{79}\u{309}\
+    \u{59}\u{303}\u{79}\u{303}\u{3B1}\u{313}\u{3B1}\u{314}\u{3B1}\u{313}\u{300}\u{3B1}\
+    \u{314}\u{300}\u{3B1}\u{313}\u{301}\u{3B1}\u{314}\u{301}\u{3B1}\u{313}\u{342}\u{3B1}\
+    \u{314}\u{342}\u{391}\u{313}\u{391}\u{314}\u{391}\u{313}\u{300}\u{391}\u{314}\u{300}\
+    \u{391}\u{313}\u{301}\u{391}\u{314}\u{301}\u{391}\u{313}\u{342}\u{391}\u{314}\u{342}\
+    \u{3B5}\u{313}\u{3B5}\u{314}\u{3B5}\u{313}\u{300}\u{3B5}\u{314}\u{300}\u{3B5}\u{313}\
+    \u{301}\u{3B5}\u{314}\u{301}\u{395}\u{313}\u{395}\u{314}\u{395}\u{313}\u{300}\u{395}\
+    \u{314}\u{300}\u{395}\u{313}\u{301}\u{395}\u{314}\u{301}\u{3B7}\u{313}\u{3B7}\u{314}\
+    \u{3B7}\u{313}\u{300}\u{3B7}\u{314}\u{300}\u{3B7}\u{313}\u{301}\u{3B7}\u{314}\u{301}\
+    \u{3B7}\u{313}\u{342}\u{3B7}\u{314}\u{342}\u{397}\u{313}\u{397}\u{314}\u{397}\u{313}\
+    \u{300}\u{397}\u{314}\u{300}\u{397}\u{313}\u{301}\u{397}\u{314}\u{301}\u{397}\u{313}\
+    \u{342}\u{397}\u{314}\u{342}\u{3B9}\u{313}\u{3B9}\u{314}\u{3B9}\u{313}\u{300}\u{3B9}\
+    \u{314}\u{300}\u{3B9}\u{313}\u{301}\u{3B9}\u{314}\u{301}\u{3B9}\u{313}\u{342}\u{3B9}\
+    \u{314}\u{342}\u{399}\u{313}\u{399}\u{314}\u{399}\u{313}\u{300}\u{399}\u{314}\u{300}\
+    \u{399}\u{313}\u{301}\u{399}\u{314}\u{301}\u{399}\u{313}\u{342}\u{399}\u{314}\u{342}\
+    \u{3BF}\u{313}\u{3BF}\u{314}\u{3BF}\u{313}\u{300}\u{3BF}\u{314}\u{300}\u{3BF}\u{313}\
+    \u{301}\u{3BF}\u{314}\u{301}\u{39F}\u{313}\u{39F}\u{314}\u{39F}\u{313}\u{300}\u{39F}\
+    \u{314}\u{300}\u{39F}\u{313}\u{301}\u{39F}\u{314}\u{301}\u{3C5}\u{313}\u{3C5}\u{314}\
+    \u{3C5}\u{313}\u{300}\u{3C5}\u{314}\u{300}\u{3C5}\u{313}\u{301}\u{3C5}\u{314}\u{301}\
+    \u{3C5}\u{313}\u{342}\u{3C5}\u{314}\u{342}\u{3A5}\u{314}\u{3A5}\u{314}\u{300}\u{3A5}\
+    \u{314}\u{301}\u{3A5}\u{314}\u{342}\u{3C9}\u{313}\u{3C9}\u{314}\u{3C9}\u{313}\u{300}\
+    \u{3C9}\u{314}\u{300}\u{3C9}\u{313}\u{301}\u{3C9}\u{314}\u{301}\u{3C9}\u{313}\u{342}\
+    \u{3C9}\u{314}\u{342}\u{3A9}\u{313}\u{3A9}\u{314}\u{3A9}\u{313}\u{300}\u{3A9}\u{314}\
+    \u{300}\u{3A9}\u{313}\u{301}\u{3A9}\u{314}\u{301}\u{3A9}\u{313}\u{342}\u{3A9}\u{314}\
+    \u{342}\u{3B1}\u{300}\u{3B1}\u{301}\u{3B5}\u{300}\u{3B5}\u{301}\u{3B7}\u{300}\u{3B7}\
+    \u{301}\u{3B9}\u{300}\u{3B9}\u{301}\u{3BF}\u{300}\u{3BF}\u{301}\u{3C5}\u{300}\u{3C5}\
+    \u{301}\u{3C9}\u{300}\u{3C9}\u{301}\u{3B1}\u{313}\u{345}\u{3B1}\u{314}\u{345}\u{3B1}\
+    \u{313}\u{300}\u{345}\u{3B1}\u{314}\u{300}\u{345}\u{3B1}\u{313}\u{301}\u{345}\u{3B1}\
+    \u{314}\u{301}\u{345}\u{3B1}\u{313}\u{342}\u{345}\u{3B1}\u{314}\u{342}\u{345}\u{391}\
+    \u{313}\u{345}\u{391}\u{314}\u{345}\u{391}\u{313}\u{300}\u{345}\u{391}\u{314}\u{300}\
+    \u{345}\u{391}\u{313}\u{301}\u{345}\u{391}\u{314}\u{301}\u{345}\u{391}\u{313}\u{342}\
+    \u{345}\u{391}\u{314}\u{342}\u{345}\u{3B7}\u{313}\u{345}\u{3B7}\u{314}\u{345}\u{3B7}\
+    \u{313}\u{300}\u{345}\u{3B7}\u{314}\u{300}\u{345}\u{3B7}\u{313}\u{301}\u{345}\u{3B7}\
+    \u{314}\u{301}\u{345}\u{3B7}\u{313}\u{342}\u{345}\u{3B7}\u{314}\u{342}\u{345}\u{397}\
+    \u{313}\u{345}\u{397}\u{314}\u{345}\u{397}\u{313}\u{300}\u{345}\u{397}\u{314}\u{300}\
+    \u{345}\u{397}\u{313}\u{301}\u{345}\u{397}\u{314}\u{301}\u{345}\u{397}\u{313}\u{342}\
+    \u{345}\u{397}\u{314}\u{342}\u{345}\u{3C9}\u{313}\u{345}\u{3C9}\u{314}\u{345}\u{3C9}\
+    \u{313}\u{300}\u{345}\u{3C9}\u{314}\u{300}\u{345}\u{3C9}\u{313}\u{301}\u{345}\u{3C9}\
+    \u{314}\u{301}\u{345}\u{3C9}\u{313}\u{342}\u{345}\u{3C9}\u{314}\u{342}\u{345}\u{3A9}\
+    \u{313}\u{345}\u{3A9}\u{314}\u{345}\u{3A9}\u{313}\u{300}\u{345}\u{3A9}\u{314}\u{300}\
+    \u{345}\u{3A9}\u{313}\u{301}\u{345}\u{3A9}\u{314}\u{301}\u{345}\u{3A9}\u{313}\u{342}\
+    \u{345}\u{3A9}\u{314}\u{342}\u{345}\u{3B1}\u{306}\u{3B1}\u{304}\u{3B1}\u{300}\u{345}\
+    \u{3B1}\u{345}\u{3B1}\u{301}\u{345}\u{3B1}\u{342}\u{3B1}\u{342}\u{345}\u{391}\u{306}\
+    \u{391}\u{304}\u{391}\u{300}\u{391}\u{301}\u{391}\u{345}\u{3B9}\u{A8}\u{342}\u{3B7}\
+    \u{300}\u{345}\u{3B7}\u{345}\u{3B7}\u{301}\u{345}\u{3B7}\u{342}\u{3B7}\u{342}\u{345}\
+    \u{395}\u{300}\u{395}\u{301}\u{397}\u{300}\u{397}\u{301}\u{397}\u{345}\u{1FBF}\u{300}\
+    \u{1FBF}\u{301}\u{1FBF}\u{342}\u{3B9}\u{306}\u{3B9}\u{304}\u{3B9}\u{308}\u{300}\u{3B9}\
+    \u{308}\u{301}\u{3B9}\u{342}\u{3B9}\u{308}\u{342}\u{399}\u{306}\u{399}\u{304}\u{399}\
+    \u{300}\u{399}\u{301}\u{1FFE}\u{300}\u{1FFE}\u{301}\u{1FFE}\u{342}\u{3C5}\u{306}\u{3C5}\
+    \u{304}\u{3C5}\u{308}\u{300}\u{3C5}\u{308}\u{301}\u{3C1}\u{313}\u{3C1}\u{314}\u{3C5}\
+    \u{342}\u{3C5}\u{308}\u{342}\u{3A5}\u{306}\u{3A5}\u{304}\u{3A5}\u{300}\u{3A5}\u{301}\
+    \u{3A1}\u{314}\u{A8}\u{300}\u{A8}\u{301}\u{60}\u{3C9}\u{300}\u{345}\u{3C9}\u{345}\
+    \u{3C9}\u{301}\u{345}\u{3C9}\u{342}\u{3C9}\u{342}\u{345}\u{39F}\u{300}\u{39F}\u{301}\
+    \u{3A9}\u{300}\u{3A9}\u{301}\u{3A9}\u{345}\u{B4}\u{2002}\u{2003}\u{3A9}\u{4B}\u{41}\
+    \u{30A}\u{2190}\u{338}\u{2192}\u{338}\u{2194}\u{338}\u{21D0}\u{338}\u{21D4}\u{338}\u{21D2}\
+    \u{338}\u{2203}\u{338}\u{2208}\u{338}\u{220B}\u{338}\u{2223}\u{338}\u{2225}\u{338}\u{223C}\
+    \u{338}\u{2243}\u{338}\u{2245}\u{338}\u{2248}\u{338}\u{3D}\u{338}\u{2261}\u{338}\u{224D}\
+    \u{338}\u{3C}\u{338}\u{3E}\u{338}\u{2264}\u{338}\u{2265}\u{338}\u{2272}\u{338}\u{2273}\
+    \u{338}\u{2276}\u{338}\u{2277}\u{338}\u{227A}\u{338}\u{227B}\u{338}\u{2282}\u{338}\u{2283}\
+    \u{338}\u{2286}\u{338}\u{2287}\u{338}\u{22A2}\u{338}\u{22A8}\u{338}\u{22A9}\u{338}\u{22AB}\
+    \u{338}\u{227C}\u{338}\u{227D}\u{338}\u{2291}\u{338}\u{2292}\u{338}\u{22B2}\u{338}\u{22B3}\
+    \u{338}\u{22B4}\u{338}\u{22B5}\u{338}\u{3008}\u{3009}\u{2ADD}\u{338}\u{304B}\u{3099}\u{304D}\
+    \u{3099}\u{304F}\u{3099}\u{3051}\u{3099}\u{3053}\u{3099}\u{3055}\u{3099}\u{3057}\u{3099}\u{3059}\
+    \u{3099}\u{305B}\u{3099}\u{305D}\u{3099}\u{305F}\u{3099}\u{3061}\u{3099}\u{3064}\u{3099}\u{3066}\
+    \u{3099}\u{3068}\u{3099}\u{306F}\u{3099}\u{306F}\u{309A}\u{3072}\u{3099}\u{3072}\u{309A}\u{3075}\
+    \u{3099}\u{3075}\u{309A}\u{3078}\u{3099}\u{3078}\u{309A}\u{307B}\u{3099}\u{307B}\u{309A}\u{3046}\
+    \u{3099}\u{309D}\u{3099}\u{30AB}\u{3099}\u{30AD}\u{3099}\u{30AF}\u{3099}\u{30B1}\u{3099}\u{30B3}\
+    \u{3099}\u{30B5}\u{3099}\u{30B7}\u{3099}\u{30B9}\u{3099}\u{30BB}\u{3099}\u{30BD}\u{3099}\u{30BF}\
+    \u{3099}\u{30C1}\u{3099}\u{30C4}\u{3099}\u{30C6}\u{3099}\u{30C8}\u{3099}\u{30CF}\u{3099}\u{30CF}\
+    \u{309A}\u{30D2}\u{3099}\u{30D2}\u{309A}\u{30D5}\u{3099}\u{30D5}\u{309A}\u{30D8}\u{3099}\u{30D8}\
+    \u{309A}\u{30DB}\u{3099}\u{30DB}\u{309A}\u{30A6}\u{3099}\u{30EF}\u{3099}\u{30F0}\u{3099}\u{30F1}\
+    \u{3099}\u{30F2}\u{3099}\u{30FD}\u{3099}\u{8C48}\u{66F4}\u{8ECA}\u{8CC8}\u{6ED1}\u{4E32}\u{53E5}\
+    \u{9F9C}\u{9F9C}\u{5951}\u{91D1}\u{5587}\u{5948}\u{61F6}\u{7669}\u{7F85}\u{863F}\u{87BA}\u{88F8}\
+    \u{908F}\u{6A02}\u{6D1B}\u{70D9}\u{73DE}\u{843D}\u{916A}\u{99F1}\u{4E82}\u{5375}\u{6B04}\u{721B}\
+    \u{862D}\u{9E1E}\u{5D50}\u{6FEB}\u{85CD}\u{8964}\u{62C9}\u{81D8}\u{881F}\u{5ECA}\u{6717}\u{6D6A}\
+    \u{72FC}\u{90CE}\u{4F86}\u{51B7}\u{52DE}\u{64C4}\u{6AD3}\u{7210}\u{76E7}\u{8001}\u{8606}\u{865C}\
+    \u{8DEF}\u{9732}\u{9B6F}\u{9DFA}\u{788C}\u{797F}\u{7DA0}\u{83C9}\u{9304}\u{9E7F}\u{8AD6}\u{58DF}\
+    \u{5F04}\u{7C60}\u{807E}\u{7262}\u{78CA}\u{8CC2}\u{96F7}\u{58D8}\u{5C62}\u{6A13}\u{6DDA}\u{6F0F}\
+    \u{7D2F}\u{7E37}\u{964B}\u{52D2}\u{808B}\u{51DC}\u{51CC}\u{7A1C}\u{7DBE}\u{83F1}\u{9675}\u{8B80}\
+    \u{62CF}\u{6A02}\u{8AFE}\u{4E39}\u{5BE7}\u{6012}\u{7387}\u{7570}\u{5317}\u{78FB}\u{4FBF}\u{5FA9}\
+    \u{4E0D}\u{6CCC}\u{6578}\u{7D22}\u{53C3}\u{585E}\u{7701}\u{8449}\u{8AAA}\u{6BBA}\u{8FB0}\u{6C88}\
+    \u{62FE}\u{82E5}\u{63A0}\u{7565}\u{4EAE}\u{5169}\u{51C9}\u{6881}\u{7CE7}\u{826F}\u{8AD2}\u{91CF}\
+    \u{52F5}\u{5442}\u{5973}\u{5EEC}\u{65C5}\u{6FFE}\u{792A}\u{95AD}\u{9A6A}\u{9E97}\u{9ECE}\u{529B}\
+    \u{66C6}\u{6B77}\u{8F62}\u{5E74}\u{6190}\u{6200}\u{649A}\u{6F23}\u{7149}\u{7489}\u{79CA}\u{7DF4}\
+    \u{806F}\u{8F26}\u{84EE}\u{9023}\u{934A}\u{5217}\u{52A3}\u{54BD}\u{70C8}\u{88C2}\u{8AAA}\u{5EC9}\
+    \u{5FF5}\u{637B}\u{6BAE}\u{7C3E}\u{7375}\u{4EE4}\u{56F9}\u{5BE7}\u{5DBA}\u{601C}\u{73B2}\u{7469}\
+    \u{7F9A}\u{8046}\u{9234}\u{96F6}\u{9748}\u{9818}\u{4F8B}\u{79AE}\u{91B4}\u{96B8}\u{60E1}\u{4E86}\
+    \u{50DA}\u{5BEE}\u{5C3F}\u{6599}\u{6A02}\u{71CE}\u{7642}\u{84FC}\u{907C}\u{9F8D}\u{6688}\u{962E}\
+    \u{5289}\u{677B}\u{67F3}\u{6D41}\u{6E9C}\u{7409}\u{7559}\u{786B}\u{7D10}\u{985E}\u{516D}\u{622E}\
+    \u{9678}\u{502B}\u{5D19}\u{6DEA}\u{8F2A}\u{5F8B}\u{6144}\u{6817}\u{7387}\u{9686}\u{5229}\u{540F}\
+    \u{5C65}\u{6613}\u{674E}\u{68A8}\u{6CE5}\u{7406}\u{75E2}\u{7F79}\u{88CF}\u{88E1}\u{91CC}\u{96E2}\
+    \u{533F}\u{6EBA}\u{541D}\u{71D0}\u{7498}\u{85FA}\u{96A3}\u{9C57}\u{9E9F}\u{6797}\u{6DCB}\u{81E8}\
+    \u{7ACB}\u{7B20}\u{7C92}\u{72C0}\u{7099}\u{8B58}\u{4EC0}\u{8336}\u{523A}\u{5207}\u{5EA6}\u{62D3}\
+    \u{7CD6}\u{5B85}\u{6D1E}\u{66B4}\u{8F3B}\u{884C}\u{964D}\u{898B}\u{5ED3}\u{5140}\u{55C0}\u{585A}\
+    \u{6674}\u{51DE}\u{732A}\u{76CA}\u{793C}\u{795E}\u{7965}\u{798F}\u{9756}\u{7CBE}\u{7FBD}\u{8612}\
+    \u{8AF8}\u{9038}\u{90FD}\u{98EF}\u{98FC}\u{9928}\u{9DB4}\u{90DE}\u{96B7}\u{4FAE}\u{50E7}\u{514D}\
+    \u{52C9}\u{52E4}\u{5351}\u{559D}\u{5606}\u{5668}\u{5840}\u{58A8}\u{5C64}\u{5C6E}\u{6094}\u{6168}\
+    \u{618E}\u{61F2}\u{654F}\u{65E2}\u{6691}\u{6885}\u{6D77}\u{6E1A}\u{6F22}\u{716E}\u{722B}\u{7422}\
+    \u{7891}\u{793E}\u{7949}\u{7948}\u{7950}\u{7956}\u{795D}\u{798D}\u{798E}\u{7A40}\u{7A81}\u{7BC0}\
+    \u{7DF4}\u{7E09}\u{7E41}\u{7F72}\u{8005}\u{81ED}\u{8279}\u{8279}\u{8457}\u{8910}\u{8996}\u{8B01}\
+    \u{8B39}\u{8CD3}\u{8D08}\u{8FB6}\u{9038}\u{96E3}\u{97FF}\u{983B}\u{6075}\u{242EE}\u{8218}\u{4E26}\
+    \u{51B5}\u{5168}\u{4F80}\u{5145}\u{5180}\u{52C7}\u{52FA}\u{559D}\u{5555}\u{5599}\u{55E2}\u{585A}\
+    \u{58B3}\u{5944}\u{5954}\u{5A62}\u{5B28}\u{5ED2}\u{5ED9}\u{5F69}\u{5FAD}\u{60D8}\u{614E}\u{6108}\
+    \u{618E}\u{6160}\u{61F2}\u{6234}\u{63C4}\u{641C}\u{6452}\u{6556}\u{6674}\u{6717}\u{671B}\u{6756}\
+    \u{6B79}\u{6BBA}\u{6D41}\u{6EDB}\u{6ECB}\u{6F22}\u{701E}\u{716E}\u{77A7}\u{7235}\u{72AF}\u{732A}\
+    \u{7471}\u{7506}\u{753B}\u{761D}\u{761F}\u{76CA}\u{76DB}\u{76F4}\u{774A}\u{7740}\u{78CC}\u{7AB1}\
+    \u{7BC0}\u{7C7B}\u{7D5B}\u{7DF4}\u{7F3E}\u{8005}\u{8352}\u{83EF}\u{8779}\u{8941}\u{8986}\u{8996}\
+    \u{8ABF}\u{8AF8}\u{8ACB}\u{8B01}\u{8AFE}\u{8AED}\u{8B39}\u{8B8A}\u{8D08}\u{8F38}\u{9072}\u{9199}\
+    \u{9276}\u{967C}\u{96E3}\u{9756}\u{97DB}\u{97FF}\u{980B}\u{983B}\u{9B12}\u{9F9C}\u{2284A}\u{22844}\
+    \u{233D5}\u{3B9D}\u{4018}\u{4039}\u{25249}\u{25CD0}\u{27ED3}\u{9F43}\u{9F8E}\u{5D9}\u{5B4}\u{5F2}\
+    \u{5B7}\u{5E9}\u{5C1}\u{5E9}\u{5C2}\u{5E9}\u{5BC}\u{5C1}\u{5E9}\u{5BC}\u{5C2}\u{5D0}\
+    \u{5B7}\u{5D0}\u{5B8}\u{5D0}\u{5BC}\u{5D1}\u{5BC}\u{5D2}\u{5BC}\u{5D3}\u{5BC}\u{5D4}\
+    \u{5BC}\u{5D5}\u{5BC}\u{5D6}\u{5BC}\u{5D8}\u{5BC}\u{5D9}\u{5BC}\u{5DA}\u{5BC}\u{5DB}\
+    \u{5BC}\u{5DC}\u{5BC}\u{5DE}\u{5BC}\u{5E0}\u{5BC}\u{5E1}\u{5BC}\u{5E3}\u{5BC}\u{5E4}\
+    \u{5BC}\u{5E6}\u{5BC}\u{5E7}\u{5BC}\u{5E8}\u{5BC}\u{5E9}\u{5BC}\u{5EA}\u{5BC}\u{5D5}\
+    \u{5B9}\u{5D1}\u{5BF}\u{5DB}\u{5BF}\u{5E4}\u{5BF}\u{105D2}\u{307}\u{105DA}\u{307}\u{11099}\
+    \u{110BA}\u{1109B}\u{110BA}\u{110A5}\u{110BA}\u{11131}\u{11127}\u{11132}\u{11127}\u{11347}\u{1133E}\u{11347}\
+    \u{11357}\u{11382}\u{113C9}\u{11384}\u{113BB}\u{1138B}\u{113C2}\u{11390}\u{113C9}\u{113C2}\u{113C2}\u{113C2}\
+    \u{113B8}\u{113C2}\u{113C9}\u{114B9}\u{114BA}\u{114B9}\u{114B0}\u{114B9}\u{114BD}\u{115B8}\u{115AF}\u{115B9}\
+    \u{115AF}\u{11935}\u{11930}\u{1611E}\u{1611E}\u{1611E}\u{16129}\u{1611E}\u{1611F}\u{16129}\u{1611F}\u{1611E}\
+    \u{16120}\u{1611E}\u{1611E}\u{1611F}\u{1611E}\u{16129}\u{1611F}\u{1611E}\u{1611E}\u{16120}\u{16D67}\u{16D67}\
+    \u{16D63}\u{16D67}\u{16D63}\u{16D67}\u{16D67}\u{1D157}\u{1D165}\u{1D158}\u{1D165}\u{1D158}\u{1D165}\u{1D16E}\
+    \u{1D158}\u{1D165}\u{1D16F}\u{1D158}\u{1D165}\u{1D170}\u{1D158}\u{1D165}\u{1D171}\u{1D158}\u{1D165}\u{1D172}\
+    \u{1D1B9}\u{1D165}\u{1D1BA}\u{1D165}\u{1D1B9}\u{1D165}\u{1D16E}\u{1D1BA}\u{1D165}\u{1D16E}\u{1D1B9}\u{1D165}\
+    \u{1D16F}\u{1D1BA}\u{1D165}\u{1D16F}\u{4E3D}\u{4E38}\u{4E41}\u{20122}\u{4F60}\u{4FAE}\u{4FBB}\u{5002}\
+    \u{507A}\u{5099}\u{50E7}\u{50CF}\u{349E}\u{2063A}\u{514D}\u{5154}\u{5164}\u{5177}\u{2051C}\u{34B9}\
+    \u{5167}\u{518D}\u{2054B}\u{5197}\u{51A4}\u{4ECC}\u{51AC}\u{51B5}\u{291DF}\u{51F5}\u{5203}\u{34DF}\
+    \u{523B}\u{5246}\u{5272}\u{5277}\u{3515}\u{52C7}\u{52C9}\u{52E4}\u{52FA}\u{5305}\u{5306}\u{5317}\
+    \u{5349}\u{5351}\u{535A}\u{5373}\u{537D}\u{537F}\u{537F}\u{537F}\u{20A2C}\u{7070}\u{53CA}\u{53DF}\
+    \u{20B63}\u{53EB}\u{53F1}\u{5406}\u{549E}\u{5438}\u{5448}\u{5468}\u{54A2}\u{54F6}\u{5510}\u{5553}\
+    \u{5563}\u{5584}\u{5584}\u{5599}\u{55AB}\u{55B3}\u{55C2}\u{5716}\u{5606}\u{5717}\u{5651}\u{5674}\
+    \u{5207}\u{58EE}\u{57CE}\u{57F4}\u{580D}\u{578B}\u{5832}\u{5831}\u{58AC}\u{214E4}\u{58F2}\u{58F7}\
+    \u{5906}\u{591A}\u{5922}\u{5962}\u{216A8}\u{216EA}\u{59EC}\u{5A1B}\u{5A27}\u{59D8}\u{5A66}\u{36EE}\
+    \u{36FC}\u{5B08}\u{5B3E}\u{5B3E}\u{219C8}\u{5BC3}\u{5BD8}\u{5BE7}\u{5BF3}\u{21B18}\u{5BFF}\u{5C06}\
+    \u{5F53}\u{5C22}\u{3781}\u{5C60}\u{5C6E}\u{5CC0}\u{5C8D}\u{21DE4}\u{5D43}\u{21DE6}\u{5D6E}\u{5D6B}\
+    \u{5D7C}\u{5DE1}\u{5DE2}\u{382F}\u{5DFD}\u{5E28}\u{5E3D}\u{5E69}\u{3862}\u{22183}\u{387C}\u{5EB0}\
+    \u{5EB3}\u{5EB6}\u{5ECA}\u{2A392}\u{5EFE}\u{22331}\u{22331}\u{8201}\u{5F22}\u{5F22}\u{38C7}\u{232B8}\
+    \u{261DA}\u{5F62}\u{5F6B}\u{38E3}\u{5F9A}\u{5FCD}\u{5FD7}\u{5FF9}\u{6081}\u{393A}\u{391C}\u{6094}\
+    \u{226D4}\u{60C7}\u{6148}\u{614C}\u{614E}\u{614C}\u{617A}\u{618E}\u{61B2}\u{61A4}\u{61AF}\u{61DE}\
+    \u{61F2}\u{61F6}\u{6210}\u{621B}\u{625D}\u{62B1}\u{62D4}\u{6350}\u{22B0C}\u{633D}\u{62FC}\u{6368}\
+    \u{6383}\u{63E4}\u{22BF1}\u{6422}\u{63C5}\u{63A9}\u{3A2E}\u{6469}\u{647E}\u{649D}\u{6477}\u{3A6C}\
+    \u{654F}\u{656C}\u{2300A}\u{65E3}\u{66F8}\u{6649}\u{3B19}\u{6691}\u{3B08}\u{3AE4}\u{5192}\u{5195}\
+    \u{6700}\u{669C}\u{80AD}\u{43D9}\u{6717}\u{671B}\u{6721}\u{675E}\u{6753}\u{233C3}\u{3B49}\u{67FA}\
+    \u{6785}\u{6852}\u{6885}\u{2346D}\u{688E}\u{681F}\u{6914}\u{3B9D}\u{6942}\u{69A3}\u{69EA}\u{6AA8}\
+    \u{236A3}\u{6ADB}\u{3C18}\u{6B21}\u{238A7}\u{6B54}\u{3C4E}\u{6B72}\u{6B9F}\u{6BBA}\u{6BBB}\u{23A8D}\
+    \u{21D0B}\u{23AFA}\u{6C4E}\u{23CBC}\u{6CBF}\u{6CCD}\u{6C67}\u{6D16}\u{6D3E}\u{6D77}\u{6D41}\u{6D69}\
+    \u{6D78}\u{6D85}\u{23D1E}\u{6D34}\u{6E2F}\u{6E6E}\u{3D33}\u{6ECB}\u{6EC7}\u{23ED1}\u{6DF9}\u{6F6E}\
+    \u{23F5E}\u{23F8E}\u{6FC6}\u{7039}\u{701E}\u{701B}\u{3D96}\u{704A}\u{707D}\u{7077}\u{70AD}\u{20525}\
+    \u{7145}\u{24263}\u{719C}\u{243AB}\u{7228}\u{7235}\u{7250}\u{24608}\u{7280}\u{7295}\u{24735}\u{24814}\
+    \u{737A}\u{738B}\u{3EAC}\u{73A5}\u{3EB8}\u{3EB8}\u{7447}\u{745C}\u{7471}\u{7485}\u{74CA}\u{3F1B}\
+    \u{7524}\u{24C36}\u{753E}\u{24C92}\u{7570}\u{2219F}\u{7610}\u{24FA1}\u{24FB8}\u{25044}\u{3FFC}\u{4008}\
+    \u{76F4}\u{250F3}\u{250F2}\u{25119}\u{25133}\u{771E}\u{771F}\u{771F}\u{774A}\u{4039}\u{778B}\u{4046}\
+    \u{4096}\u{2541D}\u{784E}\u{788C}\u{78CC}\u{40E3}\u{25626}\u{7956}\u{2569A}\u{256C5}\u{798F}\u{79EB}\
+    \u{412F}\u{7A40}\u{7A4A}\u{7A4F}\u{2597C}\u{25AA7}\u{25AA7}\u{7AEE}\u{4202}\u{25BAB}\u{7BC6}\u{7BC9}\
+    \u{4227}\u{25C80}\u{7CD2}\u{42A0}\u{7CE8}\u{7CE3}\u{7D00}\u{25F86}\u{7D63}\u{4301}\u{7DC7}\u{7E02}\
+    \u{7E45}\u{4334}\u{26228}\u{26247}\u{4359}\u{262D9}\u{7F7A}\u{2633E}\u{7F95}\u{7FFA}\u{8005}\u{264DA}\
+    \u{26523}\u{8060}\u{265A8}\u{8070}\u{2335F}\u{43D5}\u{80B2}\u{8103}\u{440B}\u{813E}\u{5AB5}\u{267A7}\
+    \u{267B5}\u{23393}\u{2339C}\u{8201}\u{8204}\u{8F9E}\u{446B}\u{8291}\u{828B}\u{829D}\u{52B3}\u{82B1}\
+    \u{82B3}\u{82BD}\u{82E6}\u{26B3C}\u{82E5}\u{831D}\u{8363}\u{83AD}\u{8323}\u{83BD}\u{83E7}\u{8457}\
+    \u{8353}\u{83CA}\u{83CC}\u{83DC}\u{26C36}\u{26D6B}\u{26CD5}\u{452B}\u{84F1}\u{84F3}\u{8516}\u{273CA}\
+    \u{8564}\u{26F2C}\u{455D}\u{4561}\u{26FB1}\u{270D2}\u{456B}\u{8650}\u{865C}\u{8667}\u{8669}\u{86A9}\
+    \u{8688}\u{870E}\u{86E2}\u{8779}\u{8728}\u{876B}\u{8786}\u{45D7}\u{87E1}\u{8801}\u{45F9}\u{8860}\
+    \u{8863}\u{27667}\u{88D7}\u{88DE}\u{4635}\u{88FA}\u{34BB}\u{278AE}\u{27966}\u{46BE}\u{46C7}\u{8AA0}\
+    \u{8AED}\u{8B8A}\u{8C55}\u{27CA8}\u{8CAB}\u{8CC1}\u{8D1B}\u{8D77}\u{27F2F}\u{20804}\u{8DCB}\u{8DBC}\
+    \u{8DF0}\u{208DE}\u{8ED4}\u{8F38}\u{285D2}\u{285ED}\u{9094}\u{90F1}\u{9111}\u{2872E}\u{911B}\u{9238}\
+    \u{92D7}\u{92D8}\u{927C}\u{93F9}\u{9415}\u{28BFA}\u{958B}\u{4995}\u{95B7}\u{28D77}\u{49E6}\u{96C3}\
+    \u{5DB2}\u{9723}\u{29145}\u{2921A}\u{4A6E}\u{4A76}\u{97E0}\u{2940A}\u{4AB2}\u{29496}\u{980B}\u{980B}\
+    \u{9829}\u{295B6}\u{98E2}\u{4B33}\u{9929}\u{99A7}\u{99C2}\u{99FE}\u{4BCE}\u{29B30}\u{9B12}\u{9C40}\
+    \u{9CFD}\u{4CCE}\u{4CED}\u{9D67}\u{2A0CE}\u{4CF8}\u{2A105}\u{2A20E}\u{2A291}\u{9EBB}\u{4D56}\u{9EF9}\
+    \u{9EFE}\u{9F05}\u{9F0F}\u{9F16}\u{9F3B}\u{2A600}\
+";
+
+pub(crate) static COMPATIBILITY_DECOMPOSITION_KEYS: [char; 3849] = [
+    '\u{A0}', '\u{A8}', '\u{AA}', '\u{AF}', '\u{B2}', '\u{B3}', '\u{B4}', '\u{B5}',
+    '\u{B8}', '\u{B9}', '\u{BA}', '\u{BC}', '\u{BD}', '\u{BE}', '\u{132}', '\u{133}',
+    '\u{13F}', '\u{140}', '\u{149}', '\u{17F}', '\u{1C4}', '\u{1C5}', '\u{1C6}', '\u{1C7}',
+    '\u{1C8}', '\u{1C9}', '\u{1CA}', '\u{1CB}', '\u{1CC}', '\u{1F1}', '\u{1F2}', '\u{1F3}',
+    '\u{2B0}', '\u{2B1}', '\u{2B2}', '\u{2B3}', '\u{2B4}', '\u{2B5}', '\u{2B6}', '\u{2B7}',
+    '\u{2B8}', '\u{2D8}', '\u{2D9}', '\u{2DA}', '\u{2DB}', '\u{2DC}', '\u{2DD}', '\u{2E0}',
+    '\u{2E1}', '\u{2E2}', '\u{2E3}', '\u{2E4}', '\u{37A}', '\u{384}', '\u{385}', '\u{3D0}',
+    '\u{3D1}', '\u{3D2}', '\u{3D3}', '\u{3D4}', '\u{3D5}', '\u{3D6}', '\u{3F0}', '\u{3F1}',
+    '\u{3F2}', '\u{3F4}', '\u{3F5}', '\u{3F9}', '\u{587}', '\u{675}', '\u{676}', '\u{677}',
+    '\u{678}', '\u{E33}', '\u{EB3}', '\u{EDC}', '\u{EDD}', '\u{F0C}', '\u{F77}', '\u{F79}',
+    '\u{10FC}', '\u{1D2C}', '\u{1D2D}', '\u{1D2E}', '\u{1D30}', '\u{1D31}', '\u{1D32}', '\u{1D33}',
+    '\u{1D34}', '\u{1D35}', '\u{1D36}', '\u{1D37}', '\u{1D38}', '\u{1D39}', '\u{1D3A}', '\u{1D3C}',
+    '\u{1D3D}', '\u{1D3E}', '\u{1D3F}', '\u{1D40}', '\u{1D41}', '\u{1D42}', '\u{1D43}', '\u{1D44}',
+    '\u{1D45}', '\u{1D46}', '\u{1D47}', '\u{1D48}', '\u{1D49}', '\u{1D4A}', '\u{1D4B}', '\u{1D4C}',
+    '\u{1D4D}', '\u{1D4F}', '\u{1D50}', '\u{1D51}', '\u{1D52}', '\u{1D53}', '\u{1D54}', '\u{1D55}',
+    '\u{1D56}', '\u{1D57}', '\u{1D58}', '\u{1D59}', '\u{1D5A}', '\u{1D5B}', '\u{1D5C}', '\u{1D5D}',
+    '\u{1D5E}', '\u{1D5F}', '\u{1D60}', '\u{1D61}', '\u{1D62}', '\u{1D63}', '\u{1D64}', '\u{1D65}',
+    '\u{1D66}', '\u{1D67}', '\u{1D68}', '\u{1D69}', '\u{1D6A}', '\u{1D78}', '\u{1D9B}', '\u{1D9C}',
+    '\u{1D9D}', '\u{1D9E}', '\u{1D9F}', '\u{1DA0}', '\u{1DA1}', '\u{1DA2}', '\u{1DA3}', '\u{1DA4}',
+    '\u{1DA5}', '\u{1DA6}', '\u{1DA7}', '\u{1DA8}', '\u{1DA9}', '\u{1DAA}', '\u{1DAB}', '\u{1DAC}',
+    '\u{1DAD}', '\u{1DAE}', '\u{1DAF}', '\u{1DB0}', '\u{1DB1}', '\u{1DB2}', '\u{1DB3}', '\u{1DB4}',
+    '\u{1DB5}', '\u{1DB6}', '\u{1DB7}', '\u{1DB8}', '\u{1DB9}', '\u{1DBA}', '\u{1DBB}', '\u{1DBC}',
+    '\u{1DBD}', '\u{1DBE}', '\u{1DBF}', '\u{1E9A}', '\u{1E9B}', '\u{1FBD}', '\u{1FBF}', '\u{1FC0}',
+    '\u{1FC1}', '\u{1FCD}', '\u{1FCE}', '\u{1FCF}', '\u{1FDD}', '\u{1FDE}', '\u{1FDF}', '\u{1FED}',
+    '\u{1FEE}', '\u{1FFD}', '\u{1FFE}', '\u{2000}', '\u{2001}', '\u{2002}', '\u{2003}', '\u{2004}',
+    '\u{2005}', '\u{2006}', '\u{2007}', '\u{2008}', '\u{2009}', '\u{200A}', '\u{2011}', '\u{2017}',
+    '\u{2024}', '\u{2025}', '\u{2026}', '\u{202F}', '\u{2033}', '\u{2034}', '\u{2036}', '\u{2037}',
+    '\u{203C}', '\u{203E}', '\u{2047}', '\u{2048}', '\u{2049}', '\u{2057}', '\u{205F}', '\u{2070}',
+    '\u{2071}', '\u{2074}', '\u{2075}', '\u{2076}', '\u{2077}', '\u{2078}', '\u{2079}', '\u{207A}',
+    '\u{207B}', '\u{207C}', '\u{207D}', '\u{207E}', '\u{207F}', '\u{2080}', '\u{2081}', '\u{2082}',
+    '\u{2083}', '\u{2084}', '\u{2085}', '\u{2086}', '\u{2087}', '\u{2088}', '\u{2089}', '\u{208A}',
+    '\u{208B}', '\u{208C}', '\u{208D}', '\u{208E}', '\u{2090}', '\u{2091}', '\u{2092}', '\u{2093}',
+    '\u{2094}', '\u{2095}', '\u{2096}', '\u{2097}', '\u{2098}', '\u{2099}', '\u{209A}', '\u{209B}',
+    '\u{209C}', '\u{20A8}', '\u{2100}', '\u{2101}', '\u{2102}', '\u{2103}', '\u{2105}', '\u{2106}',
+    '\u{2107}', '\u{2109}', '\u{210A}', '\u{210B}', '\u{210C}', '\u{210D}', '\u{210E}', '\u{210F}',
+    '\u{2110}', '\u{2111}', '\u{2112}', '\u{2113}', '\u{2115}', '\u{2116}', '\u{2119}', '\u{211A}',
+    '\u{211B}', '\u{211C}', '\u{211D}', '\u{2120}', '\u{2121}', '\u{2122}', '\u{2124}', '\u{2128}',
+    '\u{212C}', '\u{212D}', '\u{212F}', '\u{2130}', '\u{2131}', '\u{2133}', '\u{2134}', '\u{2135}',
+    '\u{2136}', '\u{2137}', '\u{2138}', '\u{2139}', '\u{213B}', '\u{213C}', '\u{213D}', '\u{213E}',
+    '\u{213F}', '\u{2140}', '\u{2145}', '\u{2146}', '\u{2147}', '\u{2148}', '\u{2149}', '\u{2150}',
+    '\u{2151}', '\u{2152}', '\u{2153}', '\u{2154}', '\u{2155}', '\u{2156}', '\u{2157}', '\u{2158}',
+    '\u{2159}', '\u{215A}', '\u{215B}', '\u{215C}', '\u{215D}', '\u{215E}', '\u{215F}', '\u{2160}',
+    '\u{2161}', '\u{2162}', '\u{2163}', '\u{2164}', '\u{2165}', '\u{2166}', '\u{2167}', '\u{2168}',
+    '\u{2169}', '\u{216A}', '\u{216B}', '\u{216C}', '\u{216D}', '\u{216E}', '\u{216F}', '\u{2170}',
+    '\u{2171}', '\u{2172}', '\u{2173}', '\u{2174}', '\u{2175}', '\u{2176}', '\u{2177}', '\u{2178}',
+    '\u{2179}', '\u{217A}', '\u{217B}', '\u{217C}', '\u{217D}', '\u{217E}', '\u{217F}', '\u{2189}',
+    '\u{222C}', '\u{222D}', '\u{222F}', '\u{2230}', '\u{2460}', '\u{2461}', '\u{2462}', '\u{2463}',
+    '\u{2464}', '\u{2465}', '\u{2466}', '\u{2467}', '\u{2468}', '\u{2469}', '\u{246A}', '\u{246B}',
+    '\u{246C}', '\u{246D}', '\u{246E}', '\u{246F}', '\u{2470}', '\u{2471}', '\u{2472}', '\u{2473}',
+    '\u{2474}', '\u{2475}', '\u{2476}', '\u{2477}', '\u{2478}', '\u{2479}', '\u{247A}', '\u{247B}',
+    '\u{247C}', '\u{247D}', '\u{247E}', '\u{247F}', '\u{2480}', '\u{2481}', '\u{2482}', '\u{2483}',
+    '\u{2484}', '\u{2485}', '\u{2486}', '\u{2487}', '\u{2488}', '\u{2489}', '\u{248A}', '\u{248B}',
+    '\u{248C}', '\u{248D}', '\u{248E}', '\u{248F}', '\u{2490}', '\u{2491}', '\u{2492}', '\u{2493}',
+    '\u{2494}', '\u{2495}', '\u{2496}', '\u{2497}', '\u{2498}', '\u{2499}', '\u{249A}', '\u{249B}',
+    '\u{249C}', '\u{249D}', '\u{249E}', '\u{249F}', '\u{24A0}', '\u{24A1}', '\u{24A2}', '\u{24A3}',
+    '\u{24A4}', '\u{24A5}', '\u{24A6}', '\u{24A7}', '\u{24A8}', '\u{24A9}', '\u{24AA}', '\u{24AB}',
+    '\u{24AC}', '\u{24AD}', '\u{24AE}', '\u{24AF}', '\u{24B0}', '\u{24B1}', '\u{24B2}', '\u{24B3}',
+    '\u{24B4}', '\u{24B5}', '\u{24B6}', '\u{24B7}', '\u{24B8}', '\u{24B9}', '\u{24BA}', '\u{24BB}',
+    '\u{24BC}', '\u{24BD}', '\u{24BE}', '\u{24BF}', '\u{24C0}', '\u{24C1}', '\u{24C2}', '\u{24C3}',
+    '\u{24C4}', '\u{24C5}', '\u{24C6}', '\u{24C7}', '\u{24C8}', '\u{24C9}', '\u{24CA}', '\u{24CB}',
+    '\u{24CC}', '\u{24CD}', '\u{24CE}', '\u{24CF}', '\u{24D0}', '\u{24D1}', '\u{24D2}', '\u{24D3}',
+    '\u{24D4}', '\u{24D5}', '\u{24D6}', '\u{24D7}', '\u{24D8}', '\u{24D9}', '\u{24DA}', '\u{24DB}',
+    '\u{24DC}', '\u{24DD}', '\u{24DE}', '\u{24DF}', '\u{24E0}', '\u{24E1}', '\u{24E2}', '\u{24E3}',
+    '\u{24E4}', '\u{24E5}', '\u{24E6}', '\u{24E7}', '\u{24E8}', '\u{24E9}', '\u{24EA}', '\u{2A0C}',
+    '\u{2A74}', '\u{2A75}', '\u{2A76}', '\u{2C7C}', '\u{2C7D}', '\u{2D6F}', '\u{2E9F}', '\u{2EF3}',
+    '\u{2F00}', '\u{2F01}', '\u{2F02}', '\u{2F03}', '\u{2F04}', '\u{2F05}', '\u{2F06}', '\u{2F07}',
+    '\u{2F08}', '\u{2F09}', '\u{2F0A}', '\u{2F0B}', '\u{2F0C}', '\u{2F0D}', '\u{2F0E}', '\u{2F0F}',
+    '\u{2F10}', '\u{2F11}', '\u{2F12}', '\u{2F13}', '\u{2F14}', '\u{2F15}', '\u{2F16}', '\u{2F17}',
+    '\u{2F18}', '\u{2F19}', '\u{2F1A}', '\u{2F1B}', '\u{2F1C}', '\u{2F1D}', '\u{2F1E}', '\u{2F1F}',
+    '\u{2F20}', '\u{2F21}', '\u{2F22}', '\u{2F23}', '\u{2F24}', '\u{2F25}', '\u{2F26}', '\u{2F27}',
+    '\u{2F28}', '\u{2F29}', '\u{2F2A}', '\u{2F2B}', '\u{2F2C}', '\u{2F2D}', '\u{2F2E}', '\u{2F2F}',
+    '\u{2F30}', '\u{2F31}', '\u{2F32}', '\u{2F33}', '\u{2F34}', '\u{2F35}', '\u{2F36}', '\u{2F37}',
+    '\u{2F38}', '\u{2F39}', '\u{2F3A}', '\u{2F3B}', '\u{2F3C}', '\u{2F3D}', '\u{2F3E}', '\u{2F3F}',
+    '\u{2F40}', '\u{2F41}', '\u{2F42}', '\u{2F43}', '\u{2F44}', '\u{2F45}', '\u{2F46}', '\u{2F47}',
+    '\u{2F48}', '\u{2F49}', '\u{2F4A}', '\u{2F4B}', '\u{2F4C}', '\u{2F4D}', '\u{2F4E}', '\u{2F4F}',
+    '\u{2F50}', '\u{2F51}', '\u{2F52}', '\u{2F53}', '\u{2F54}', '\u{2F55}', '\u{2F56}', '\u{2F57}',
+    '\u{2F58}', '\u{2F59}', '\u{2F5A}', '\u{2F5B}', '\u{2F5C}', '\u{2F5D}', '\u{2F5E}', '\u{2F5F}',
+    '\u{2F60}', '\u{2F61}', '\u{2F62}', '\u{2F63}', '\u{2F64}', '\u{2F65}', '\u{2F66}', '\u{2F67}',
+    '\u{2F68}', '\u{2F69}', '\u{2F6A}', '\u{2F6B}', '\u{2F6C}', '\u{2F6D}', '\u{2F6E}', '\u{2F6F}',
+    '\u{2F70}', '\u{2F71}', '\u{2F72}', '\u{2F73}', '\u{2F74}', '\u{2F75}', '\u{2F76}', '\u{2F77}',
+    '\u{2F78}', '\u{2F79}', '\u{2F7A}', '\u{2F7B}', '\u{2F7C}', '\u{2F7D}', '\u{2F7E}', '\u{2F7F}',
+    '\u{2F80}', '\u{2F81}', '\u{2F82}', '\u{2F83}', '\u{2F84}', '\u{2F85}', '\u{2F86}', '\u{2F87}',
+    '\u{2F88}', '\u{2F89}', '\u{2F8A}', '\u{2F8B}', '\u{2F8C}', '\u{2F8D}', '\u{2F8E}', '\u{2F8F}',
+    '\u{2F90}', '\u{2F91}', '\u{2F92}', '\u{2F93}', '\u{2F94}', '\u{2F95}', '\u{2F96}', '\u{2F97}',
+    '\u{2F98}', '\u{2F99}', '\u{2F9A}', '\u{2F9B}', '\u{2F9C}', '\u{2F9D}', '\u{2F9E}', '\u{2F9F}',
+    '\u{2FA0}', '\u{2FA1}', '\u{2FA2}', '\u{2FA3}', '\u{2FA4}', '\u{2FA5}', '\u{2FA6}', '\u{2FA7}',
+    '\u{2FA8}', '\u{2FA9}', '\u{2FAA}', '\u{2FAB}', '\u{2FAC}', '\u{2FAD}', '\u{2FAE}', '\u{2FAF}',
+    '\u{2FB0}', '\u{2FB1}', '\u{2FB2}', '\u{2FB3}', '\u{2FB4}', '\u{2FB5}', '\u{2FB6}', '\u{2FB7}',
+    '\u{2FB8}', '\u{2FB9}', '\u{2FBA}', '\u{2FBB}', '\u{2FBC}', '\u{2FBD}', '\u{2FBE}', '\u{2FBF}',
+    '\u{2FC0}', '\u{2FC1}', '\u{2FC2}', '\u{2FC3}', '\u{2FC4}', '\u{2FC5}', '\u{2FC6}', '\u{2FC7}',
+    '\u{2FC8}', '\u{2FC9}', '\u{2FCA}', '\u{2FCB}', '\u{2FCC}', '\u{2FCD}', '\u{2FCE}', '\u{2FCF}',
+    '\u{2FD0}', '\u{2FD1}', '\u{2FD2}', '\u{2FD3}', '\u{2FD4}', '\u{2FD5}', '\u{3000}', '\u{3036}',
+    '\u{3038}', '\u{3039}', '\u{303A}', '\u{309B}', '\u{309C}', '\u{309F}', '\u{30FF}', '\u{3131}',
+    '\u{3132}', '\u{3133}', '\u{3134}', '\u{3135}', '\u{3136}', '\u{3137}', '\u{3138}', '\u{3139}',
+    '\u{313A}', '\u{313B}', '\u{313C}', '\u{313D}', '\u{313E}', '\u{313F}', '\u{3140}', '\u{3141}',
+    '\u{3142}', '\u{3143}', '\u{3144}', '\u{3145}', '\u{3146}', '\u{3147}', '\u{3148}', '\u{3149}',
+    '\u{314A}', '\u{314B}', '\u{314C}', '\u{314D}', '\u{314E}', '\u{314F}', '\u{3150}', '\u{3151}',
+    '\u{3152}', '\u{3153}', '\u{3154}', '\u{3155}', '\u{3156}', '\u{3157}', '\u{3158}', '\u{3159}',
+    '\u{315A}', '\u{315B}', '\u{315C}', '\u{315D}', '\u{315E}', '\u{315F}', '\u{3160}', '\u{3161}',
+    '\u{3162}', '\u{3163}', '\u{3164}', '\u{3165}', '\u{3166}', '\u{3167}', '\u{3168}', '\u{3169}',
+    '\u{316A}', '\u{316B}', '\u{316C}', '\u{316D}', '\u{316E}', '\u{316F}', '\u{3170}', '\u{3171}',
+    '\u{3172}', '\u{3173}', '\u{3174}', '\u{3175}', '\u{3176}', '\u{3177}', '\u{3178}', '\u{3179}',
+    '\u{317A}', '\u{317B}', '\u{317C}', '\u{317D}', '\u{317E}', '\u{317F}', '\u{3180}', '\u{3181}',
+    '\u{3182}', '\u{3183}', '\u{3184}', '\u{3185}', '\u{3186}', '\u{3187}', '\u{3188}', '\u{3189}',
+    '\u{318A}', '\u{318B}', '\u{318C}', '\u{318D}', '\u{318E}', '\u{3192}', '\u{3193}', '\u{3194}',
+    '\u{3195}', '\u{3196}', '\u{3197}', '\u{3198}', '\u{3199}', '\u{319A}', '\u{319B}', '\u{319C}',
+    '\u{319D}', '\u{319E}', '\u{319F}', '\u{3200}', '\u{3201}', '\u{3202}', '\u{3203}', '\u{3204}',
+    '\u{3205}', '\u{3206}', '\u{3207}', '\u{3208}', '\u{3209}', '\u{320A}', '\u{320B}', '\u{320C}',
+    '\u{320D}', '\u{320E}', '\u{320F}', '\u{3210}', '\u{3211}', '\u{3212}', '\u{3213}', '\u{3214}',
+    '\u{3215}', '\u{3216}', '\u{3217}', '\u{3218}', '\u{3219}', '\u{321A}', '\u{321B}', '\u{321C}',
+    '\u{321D}', '\u{321E}', '\u{3220}', '\u{3221}', '\u{3222}', '\u{3223}', '\u{3224}', '\u{3225}',
+    '\u{3226}', '\u{3227}', '\u{3228}', '\u{3229}', '\u{322A}', '\u{322B}', '\u{322C}', '\u{322D}',
+    '\u{322E}', '\u{322F}', '\u{3230}', '\u{3231}', '\u{3232}', '\u{3233}', '\u{3234}', '\u{3235}',
+    '\u{3236}', '\u{3237}', '\u{3238}', '\u{3239}', '\u{323A}', '\u{323B}', '\u{323C}', '\u{323D}',
+    '\u{323E}', '\u{323F}', '\u{3240}', '\u{3241}', '\u{3242}', '\u{3243}', '\u{3244}', '\u{3245}',
+    '\u{3246}', '\u{3247}', '\u{3250}', '\u{3251}', '\u{3252}', '\u{3253}', '\u{3254}', '\u{3255}',
+    '\u{3256}', '\u{3257}', '\u{3258}', '\u{3259}', '\u{325A}', '\u{325B}', '\u{325C}', '\u{325D}',
+    '\u{325E}', '\u{325F}', '\u{3260}', '\u{3261}', '\u{3262}', '\u{3263}', '\u{3264}', '\u{3265}',
+    '\u{3266}', '\u{3267}', '\u{3268}', '\u{3269}', '\u{326A}', '\u{326B}', '\u{326C}', '\u{326D}',
+    '\u{326E}', '\u{326F}', '\u{3270}', '\u{3271}', '\u{3272}', '\u{3273}', '\u{3274}', '\u{3275}',
+    '\u{3276}', '\u{3277}', '\u{3278}', '\u{3279}', '\u{327A}', '\u{327B}', '\u{327C}', '\u{327D}',
+    '\u{327E}', '\u{3280}', '\u{3281}', '\u{3282}', '\u{3283}', '\u{3284}', '\u{3285}', '\u{3286}',
+    '\u{3287}', '\u{3288}', '\u{3289}', '\u{328A}', '\u{328B}', '\u{328C}', '\u{328D}', '\u{328E}',
+    '\u{328F}', '\u{3290}', '\u{3291}', '\u{3292}', '\u{3293}', '\u{3294}', '\u{3295}', '\u{3296}',
+    '\u{3297}', '\u{3298}', '\u{3299}', '\u{329A}', '\u{329B}', '\u{329C}', '\u{329D}', '\u{329E}',
+    '\u{329F}', '\u{32A0}', '\u{32A1}', '\u{32A2}', '\u{32A3}', '\u{32A4}', '\u{32A5}', '\u{32A6}',
+    '\u{32A7}', '\u{32A8}', '\u{32A9}', '\u{32AA}', '\u{32AB}', '\u{32AC}', '\u{32AD}', '\u{32AE}',
+    '\u{32AF}', '\u{32B0}', '\u{32B1}', '\u{32B2}', '\u{32B3}', '\u{32B4}', '\u{32B5}', '\u{32B6}',
+    '\u{32B7}', '\u{32B8}', '\u{32B9}', '\u{32BA}', '\u{32BB}', '\u{32BC}', '\u{32BD}', '\u{32BE}',
+    '\u{32BF}', '\u{32C0}', '\u{32C1}', '\u{32C2}', '\u{32C3}', '\u{32C4}', '\u{32C5}', '\u{32C6}',
+    '\u{32C7}', '\u{32C8}', '\u{32C9}', '\u{32CA}', '\u{32CB}', '\u{32CC}', '\u{32CD}', '\u{32CE}',
+    '\u{32CF}', '\u{32D0}', '\u{32D1}', '\u{32D2}', '\u{32D3}', '\u{32D4}', '\u{32D5}', '\u{32D6}',
+    '\u{32D7}', '\u{32D8}', '\u{32D9}', '\u{32DA}', '\u{32DB}', '\u{32DC}', '\u{32DD}', '\u{32DE}',
+    '\u{32DF}', '\u{32E0}', '\u{32E1}', '\u{32E2}', '\u{32E3}', '\u{32E4}', '\u{32E5}', '\u{32E6}',
+    '\u{32E7}', '\u{32E8}', '\u{32E9}', '\u{32EA}', '\u{32EB}', '\u{32EC}', '\u{32ED}', '\u{32EE}',
+    '\u{32EF}', '\u{32F0}', '\u{32F1}', '\u{32F2}', '\u{32F3}', '\u{32F4}', '\u{32F5}', '\u{32F6}',
+    '\u{32F7}', '\u{32F8}', '\u{32F9}', '\u{32FA}', '\u{32FB}', '\u{32FC}', '\u{32FD}', '\u{32FE}',
+    '\u{32FF}', '\u{3300}', '\u{3301}', '\u{3302}', '\u{3303}', '\u{3304}', '\u{3305}', '\u{3306}',
+    '\u{3307}', '\u{3308}', '\u{3309}', '\u{330A}', '\u{330B}', '\u{330C}', '\u{330D}', '\u{330E}',
+    '\u{330F}', '\u{3310}', '\u{3311}', '\u{3312}', '\u{3313}', '\u{3314}', '\u{3315}', '\u{3316}',
+    '\u{3317}', '\u{3318}', '\u{3319}', '\u{331A}', '\u{331B}', '\u{331C}', '\u{331D}', '\u{331E}',
+    '\u{331F}', '\u{3320}', '\u{3321}', '\u{3322}', '\u{3323}', '\u{3324}', '\u{3325}', '\u{3326}',
+    '\u{3327}', '\u{3328}', '\u{3329}', '\u{332A}', '\u{332B}', '\u{332C}', '\u{332D}', '\u{332E}',
+    '\u{332F}', '\u{3330}', '\u{3331}', '\u{3332}', '\u{3333}', '\u{3334}', '\u{3335}', '\u{3336}',
+    '\u{3337}', '\u{3338}', '\u{3339}', '\u{333A}', '\u{333B}', '\u{333C}', '\u{333D}', '\u{333E}',
+    '\u{333F}', '\u{3340}', '\u{3341}', '\u{3342}', '\u{3343}', '\u{3344}', '\u{3345}', '\u{3346}',
+    '\u{3347}', '\u{3348}', '\u{3349}', '\u{334A}', '\u{334B}', '\u{334C}', '\u{334D}', '\u{334E}',
+    '\u{334F}', '\u{3350}', '\u{3351}', '\u{3352}', '\u{3353}', '\u{3354}', '\u{3355}', '\u{3356}',
+    '\u{3357}', '\u{3358}', '\u{3359}', '\u{335A}', '\u{335B}', '\u{335C}', '\u{335D}', '\u{335E}',
+    '\u{335F}', '\u{3360}', '\u{3361}', '\u{3362}', '\u{3363}', '\u{3364}', '\u{3365}', '\u{3366}',
+    '\u{3367}', '\u{3368}', '\u{3369}', '\u{336A}', '\u{336B}', '\u{336C}', '\u{336D}', '\u{336E}',
+    '\u{336F}', '\u{3370}', '\u{3371}', '\u{3372}', '\u{3373}', '\u{3374}', '\u{3375}', '\u{3376}',
+    '\u{3377}', '\u{3378}', '\u{3379}', '\u{337A}', '\u{337B}', '\u{337C}', '\u{337D}', '\u{337E}',
+    '\u{337F}', '\u{3380}', '\u{3381}', '\u{3382}', '\u{3383}', '\u{3384}', '\u{3385}', '\u{3386}',
+    '\u{3387}', '\u{3388}', '\u{3389}', '\u{338A}', '\u{338B}', '\u{338C}', '\u{338D}', '\u{338E}',
+    '\u{338F}', '\u{3390}', '\u{3391}', '\u{3392}', '\u{3393}', '\u{3394}', '\u{3395}', '\u{3396}',
+    '\u{3397}', '\u{3398}', '\u{3399}', '\u{339A}', '\u{339B}', '\u{339C}', '\u{339D}', '\u{339E}',
+    '\u{339F}', '\u{33A0}', '\u{33A1}', '\u{33A2}', '\u{33A3}', '\u{33A4}', '\u{33A5}', '\u{33A6}',
+    '\u{33A7}', '\u{33A8}', '\u{33A9}', '\u{33AA}', '\u{33AB}', '\u{33AC}', '\u{33AD}', '\u{33AE}',
+    '\u{33AF}', '\u{33B0}', '\u{33B1}', '\u{33B2}', '\u{33B3}', '\u{33B4}', '\u{33B5}', '\u{33B6}',
+    '\u{33B7}', '\u{33B8}', '\u{33B9}', '\u{33BA}', '\u{33BB}', '\u{33BC}', '\u{33BD}', '\u{33BE}',
+    '\u{33BF}', '\u{33C0}', '\u{33C1}', '\u{33C2}', '\u{33C3}', '\u{33C4}', '\u{33C5}', '\u{33C6}',
+    '\u{33C7}', '\u{33C8}', '\u{33C9}', '\u{33CA}', '\u{33CB}', '\u{33CC}', '\u{33CD}', '\u{33CE}',
+    '\u{33CF}', '\u{33D0}', '\u{33D1}', '\u{33D2}', '\u{33D3}', '\u{33D4}', '\u{33D5}', '\u{33D6}',
+    '\u{33D7}', '\u{33D8}', '\u{33D9}', '\u{33DA}', '\u{33DB}', '\u{33DC}', '\u{33DD}', '\u{33DE}',
+    '\u{33DF}', '\u{33E0}', '\u{33E1}', '\u{33E2}', '\u{33E3}', '\u{33E4}', '\u{33E5}', '\u{33E6}',
+    '\u{33E7}', '\u{33E8}', '\u{33E9}', '\u{33EA}', '\u{33EB}', '\u{33EC}', '\u{33ED}', '\u{33EE}',
+    '\u{33EF}', '\u{33F0}', '\u{33F1}', '\u{33F2}', '\u{33F3}', '\u{33F4}', '\u{33F5}', '\u{33F6}',
+    '\u{33F7}', '\u{33F8}', '\u{33F9}', '\u{33FA}', '\u{33FB}', '\u{33FC}', '\u{33FD}', '\u{33FE}',
+    '\u{33FF}', '\u{A69C}', '\u{A69D}', '\u{A770}', '\u{A7F1}', '\u{A7F2}', '\u{A7F3}', '\u{A7F4}',
+    '\u{A7F8}', '\u{A7F9}', '\u{AB5C}', '\u{AB5D}', '\u{AB5E}', '\u{AB5F}', '\u{AB69}', '\u{FB00}',
+    '\u{FB01}', '\u{FB02}', '\u{FB03}', '\u{FB04}', '\u{FB05}', '\u{FB06}', '\u{FB13}', '\u{FB14}',
+    '\u{FB15}', '\u{FB16}', '\u{FB17}', '\u{FB20}', '\u{FB21}', '\u{FB22}', '\u{FB23}', '\u{FB24}',
+    '\u{FB25}', '\u{FB26}', '\u{FB27}', '\u{FB28}', '\u{FB29}', '\u{FB4F}', '\u{FB50}', '\u{FB51}',
+    '\u{FB52}', '\u{FB53}', '\u{FB54}', '\u{FB55}', '\u{FB56}', '\u{FB57}', '\u{FB58}', '\u{FB59}',
+    '\u{FB5A}', '\u{FB5B}', '\u{FB5C}', '\u{FB5D}', '\u{FB5E}', '\u{FB5F}', '\u{FB60}', '\u{FB61}',
+    '\u{FB62}', '\u{FB63}', '\u{FB64}', '\u{FB65}', '\u{FB66}', '\u{FB67}', '\u{FB68}', '\u{FB69}',
+    '\u{FB6A}', '\u{FB6B}', '\u{FB6C}', '\u{FB6D}', '\u{FB6E}', '\u{FB6F}', '\u{FB70}', '\u{FB71}',
+    '\u{FB72}', '\u{FB73}', '\u{FB74}', '\u{FB75}', '\u{FB76}', '\u{FB77}', '\u{FB78}', '\u{FB79}',
+    '\u{FB7A}', '\u{FB7B}', '\u{FB7C}', '\u{FB7D}', '\u{FB7E}', '\u{FB7F}', '\u{FB80}', '\u{FB81}',
+    '\u{FB82}', '\u{FB83}', '\u{FB84}', '\u{FB85}', '\u{FB86}', '\u{FB87}', '\u{FB88}', '\u{FB89}',
+    '\u{FB8A}', '\u{FB8B}', '\u{FB8C}', '\u{FB8D}', '\u{FB8E}', '\u{FB8F}', '\u{FB90}', '\u{FB91}',
+    '\u{FB92}', '\u{FB93}', '\u{FB94}', '\u{FB95}', '\u{FB96}', '\u{FB97}', '\u{FB98}', '\u{FB99}',
+    '\u{FB9A}', '\u{FB9B}', '\u{FB9C}', '\u{FB9D}', '\u{FB9E}', '\u{FB9F}', '\u{FBA0}', '\u{FBA1}',
+    '\u{FBA2}', '\u{FBA3}', '\u{FBA4}', '\u{FBA5}', '\u{FBA6}', '\u{FBA7}', '\u{FBA8}', '\u{FBA9}',
+    '\u{FBAA}', '\u{FBAB}', '\u{FBAC}', '\u{FBAD}', '\u{FBAE}', '\u{FBAF}', '\u{FBB0}', '\u{FBB1}',
+    '\u{FBD3}', '\u{FBD4}', '\u{FBD5}', '\u{FBD6}', '\u{FBD7}', '\u{FBD8}', '\u{FBD9}', '\u{FBDA}',
+    '\u{FBDB}', '\u{FBDC}', '\u{FBDD}', '\u{FBDE}', '\u{FBDF}', '\u{FBE0}', '\u{FBE1}', '\u{FBE2}',
+    '\u{FBE3}', '\u{FBE4}', '\u{FBE5}', '\u{FBE6}', '\u{FBE7}', '\u{FBE8}', '\u{FBE9}', '\u{FBEA}',
+    '\u{FBEB}', '\u{FBEC}', '\u{FBED}', '\u{FBEE}', '\u{FBEF}', '\u{FBF0}', '\u{FBF1}', '\u{FBF2}',
+    '\u{FBF3}', '\u{FBF4}', '\u{FBF5}', '\u{FBF6}', '\u{FBF7}', '\u{FBF8}', '\u{FBF9}', '\u{FBFA}',
+    '\u{FBFB}', '\u{FBFC}', '\u{FBFD}', '\u{FBFE}', '\u{FBFF}', '\u{FC00}', '\u{FC01}', '\u{FC02}',
+    '\u{FC03}', '\u{FC04}', '\u{FC05}', '\u{FC06}', '\u{FC07}', '\u{FC08}', '\u{FC09}', '\u{FC0A}',
+    '\u{FC0B}', '\u{FC0C}', '\u{FC0D}', '\u{FC0E}', '\u{FC0F}', '\u{FC10}', '\u{FC11}', '\u{FC12}',
+    '\u{FC13}', '\u{FC14}', '\u{FC15}', '\u{FC16}', '\u{FC17}', '\u{FC18}', '\u{FC19}', '\u{FC1A}',
+    '\u{FC1B}', '\u{FC1C}', '\u{FC1D}', '\u{FC1E}', '\u{FC1F}', '\u{FC20}', '\u{FC21}', '\u{FC22}',
+    '\u{FC23}', '\u{FC24}', '\u{FC25}', '\u{FC26}', '\u{FC27}', '\u{FC28}', '\u{FC29}', '\u{FC2A}',
+    '\u{FC2B}', '\u{FC2C}', '\u{FC2D}', '\u{FC2E}', '\u{FC2F}', '\u{FC30}', '\u{FC31}', '\u{FC32}',
+    '\u{FC33}', '\u{FC34}', '\u{FC35}', '\u{FC36}', '\u{FC37}', '\u{FC38}', '\u{FC39}', '\u{FC3A}',
+    '\u{FC3B}', '\u{FC3C}', '\u{FC3D}', '\u{FC3E}', '\u{FC3F}', '\u{FC40}', '\u{FC41}', '\u{FC42}',
+    '\u{FC43}', '\u{FC44}', '\u{FC45}', '\u{FC46}', '\u{FC47}', '\u{FC48}', '\u{FC49}', '\u{FC4A}',
+    '\u{FC4B}', '\u{FC4C}', '\u{FC4D}', '\u{FC4E}', '\u{FC4F}', '\u{FC50}', '\u{FC51}', '\u{FC52}',
+    '\u{FC53}', '\u{FC54}', '\u{FC55}', '\u{FC56}', '\u{FC57}', '\u{FC58}', '\u{FC59}', '\u{FC5A}',
+    '\u{FC5B}', '\u{FC5C}', '\u{FC5D}', '\u{FC5E}', '\u{FC5F}', '\u{FC60}', '\u{FC61}', '\u{FC62}',
+    '\u{FC63}', '\u{FC64}', '\u{FC65}', '\u{FC66}', '\u{FC67}', '\u{FC68}', '\u{FC69}', '\u{FC6A}',
+    '\u{FC6B}', '\u{FC6C}', '\u{FC6D}', '\u{FC6E}', '\u{FC6F}', '\u{FC70}', '\u{FC71}', '\u{FC72}',
+    '\u{FC73}', '\u{FC74}', '\u{FC75}', '\u{FC76}', '\u{FC77}', '\u{FC78}', '\u{FC79}', '\u{FC7A}',
+    '\u{FC7B}', '\u{FC7C}', '\u{FC7D}', '\u{FC7E}', '\u{FC7F}', '\u{FC80}', '\u{FC81}', '\u{FC82}',
+    '\u{FC83}', '\u{FC84}', '\u{FC85}', '\u{FC86}', '\u{FC87}', '\u{FC88}', '\u{FC89}', '\u{FC8A}',
+    '\u{FC8B}', '\u{FC8C}', '\u{FC8D}', '\u{FC8E}', '\u{FC8F}', '\u{FC90}', '\u{FC91}', '\u{FC92}',
+    '\u{FC93}', '\u{FC94}', '\u{FC95}', '\u{FC96}', '\u{FC97}', '\u{FC98}', '\u{FC99}', '\u{FC9A}',
+    '\u{FC9B}', '\u{FC9C}', '\u{FC9D}', '\u{FC9E}', '\u{FC9F}', '\u{FCA0}', '\u{FCA1}', '\u{FCA2}',
+    '\u{FCA3}', '\u{FCA4}', '\u{FCA5}', '\u{FCA6}', '\u{FCA7}', '\u{FCA8}', '\u{FCA9}', '\u{FCAA}',
+    '\u{FCAB}', '\u{FCAC}', '\u{FCAD}', '\u{FCAE}', '\u{FCAF}', '\u{FCB0}', '\u{FCB1}', '\u{FCB2}',
+    '\u{FCB3}', '\u{FCB4}', '\u{FCB5}', '\u{FCB6}', '\u{FCB7}', '\u{FCB8}', '\u{FCB9}', '\u{FCBA}',
+    '\u{FCBB}', '\u{FCBC}', '\u{FCBD}', '\u{FCBE}', '\u{FCBF}', '\u{FCC0}', '\u{FCC1}', '\u{FCC2}',
+    '\u{FCC3}', '\u{FCC4}', '\u{FCC5}', '\u{FCC6}', '\u{FCC7}', '\u{FCC8}', '\u{FCC9}', '\u{FCCA}',
+    '\u{FCCB}', '\u{FCCC}', '\u{FCCD}', '\u{FCCE}', '\u{FCCF}', '\u{FCD0}', '\u{FCD1}', '\u{FCD2}',
+    '\u{FCD3}', '\u{FCD4}', '\u{FCD5}', '\u{FCD6}', '\u{FCD7}', '\u{FCD8}', '\u{FCD9}', '\u{FCDA}',
+    '\u{FCDB}', '\u{FCDC}', '\u{FCDD}', '\u{FCDE}', '\u{FCDF}', '\u{FCE0}', '\u{FCE1}', '\u{FCE2}',
+    '\u{FCE3}', '\u{FCE4}', '\u{FCE5}', '\u{FCE6}', '\u{FCE7}', '\u{FCE8}', '\u{FCE9}', '\u{FCEA}',
+    '\u{FCEB}', '\u{FCEC}', '\u{FCED}', '\u{FCEE}', '\u{FCEF}', '\u{FCF0}', '\u{FCF1}', '\u{FCF2}',
+    '\u{FCF3}', '\u{FCF4}', '\u{FCF5}', '\u{FCF6}', '\u{FCF7}', '\u{FCF8}', '\u{FCF9}', '\u{FCFA}',
+    '\u{FCFB}', '\u{FCFC}', '\u{FCFD}', '\u{FCFE}', '\u{FCFF}', '\u{FD00}', '\u{FD01}', '\u{FD02}',
+    '\u{FD03}', '\u{FD04}', '\u{FD05}', '\u{FD06}', '\u{FD07}', '\u{FD08}', '\u{FD09}', '\u{FD0A}',
+    '\u{FD0B}', '\u{FD0C}', '\u{FD0D}', '\u{FD0E}', '\u{FD0F}', '\u{FD10}', '\u{FD11}', '\u{FD12}',
+    '\u{FD13}', '\u{FD14}', '\u{FD15}', '\u{FD16}', '\u{FD17}', '\u{FD18}', '\u{FD19}', '\u{FD1A}',
+    '\u{FD1B}', '\u{FD1C}', '\u{FD1D}', '\u{FD1E}', '\u{FD1F}', '\u{FD20}', '\u{FD21}', '\u{FD22}',
+    '\u{FD23}', '\u{FD24}', '\u{FD25}', '\u{FD26}', '\u{FD27}', '\u{FD28}', '\u{FD29}', '\u{FD2A}',
+    '\u{FD2B}', '\u{FD2C}', '\u{FD2D}', '\u{FD2E}', '\u{FD2F}', '\u{FD30}', '\u{FD31}', '\u{FD32}',
+    '\u{FD33}', '\u{FD34}', '\u{FD35}', '\u{FD36}', '\u{FD37}', '\u{FD38}', '\u{FD39}', '\u{FD3A}',
+    '\u{FD3B}', '\u{FD3C}', '\u{FD3D}', '\u{FD50}', '\u{FD51}', '\u{FD52}', '\u{FD53}', '\u{FD54}',
+    '\u{FD55}', '\u{FD56}', '\u{FD57}', '\u{FD58}', '\u{FD59}', '\u{FD5A}', '\u{FD5B}', '\u{FD5C}',
+    '\u{FD5D}', '\u{FD5E}', '\u{FD5F}', '\u{FD60}', '\u{FD61}', '\u{FD62}', '\u{FD63}', '\u{FD64}',
+    '\u{FD65}', '\u{FD66}', '\u{FD67}', '\u{FD68}', '\u{FD69}', '\u{FD6A}', '\u{FD6B}', '\u{FD6C}',
+    '\u{FD6D}', '\u{FD6E}', '\u{FD6F}', '\u{FD70}', '\u{FD71}', '\u{FD72}', '\u{FD73}', '\u{FD74}',
+    '\u{FD75}', '\u{FD76}', '\u{FD77}', '\u{FD78}', '\u{FD79}', '\u{FD7A}', '\u{FD7B}', '\u{FD7C}',
+    '\u{FD7D}', '\u{FD7E}', '\u{FD7F}', '\u{FD80}', '\u{FD81}', '\u{FD82}', '\u{FD83}', '\u{FD84}',
+    '\u{FD85}', '\u{FD86}', '\u{FD87}', '\u{FD88}', '\u{FD89}', '\u{FD8A}', '\u{FD8B}', '\u{FD8C}',
+    '\u{FD8D}', '\u{FD8E}', '\u{FD8F}', '\u{FD92}', '\u{FD93}', '\u{FD94}', '\u{FD95}', '\u{FD96}',
+    '\u{FD97}', '\u{FD98}', '\u{FD99}', '\u{FD9A}', '\u{FD9B}', '\u{FD9C}', '\u{FD9D}', '\u{FD9E}',
+    '\u{FD9F}', '\u{FDA0}', '\u{FDA1}', '\u{FDA2}', '\u{FDA3}', '\u{FDA4}', '\u{FDA5}', '\u{FDA6}',
+    '\u{FDA7}', '\u{FDA8}', '\u{FDA9}', '\u{FDAA}', '\u{FDAB}', '\u{FDAC}', '\u{FDAD}', '\u{FDAE}',
+    '\u{FDAF}', '\u{FDB0}', '\u{FDB1}', '\u{FDB2}', '\u{FDB3}', '\u{FDB4}', '\u{FDB5}', '\u{FDB6}',
+    '\u{FDB7}', '\u{FDB8}', '\u{FDB9}', '\u{FDBA}', '\u{FDBB}', '\u{FDBC}', '\u{FDBD}', '\u{FDBE}',
+    '\u{FDBF}', '\u{FDC0}', '\u{FDC1}', '\u{FDC2}', '\u{FDC3}', '\u{FDC4}', '\u{FDC5}', '\u{FDC6}',
+    '\u{FDC7}', '\u{FDF0}', '\u{FDF1}', '\u{FDF2}', '\u{FDF3}', '\u{FDF4}', '\u{FDF5}', '\u{FDF6}',
+    '\u{FDF7}', '\u{FDF8}', '\u{FDF9}', '\u{FDFA}', '\u{FDFB}', '\u{FDFC}', '\u{FE10}', '\u{FE11}',
+    '\u{FE12}', '\u{FE13}', '\u{FE14}', '\u{FE15}', '\u{FE16}', '\u{FE17}', '\u{FE18}', '\u{FE19}',
+    '\u{FE30}', '\u{FE31}', '\u{FE32}', '\u{FE33}', '\u{FE34}', '\u{FE35}', '\u{FE36}', '\u{FE37}',
+    '\u{FE38}', '\u{FE39}', '\u{FE3A}', '\u{FE3B}', '\u{FE3C}', '\u{FE3D}', '\u{FE3E}', '\u{FE3F}',
+    '\u{FE40}', '\u{FE41}', '\u{FE42}', '\u{FE43}', '\u{FE44}', '\u{FE47}', '\u{FE48}', '\u{FE49}',
+    '\u{FE4A}', '\u{FE4B}', '\u{FE4C}', '\u{FE4D}', '\u{FE4E}', '\u{FE4F}', '\u{FE50}', '\u{FE51}',
+    '\u{FE52}', '\u{FE54}', '\u{FE55}', '\u{FE56}', '\u{FE57}', '\u{FE58}', '\u{FE59}', '\u{FE5A}',
+    '\u{FE5B}', '\u{FE5C}', '\u{FE5D}', '\u{FE5E}', '\u{FE5F}', '\u{FE60}', '\u{FE61}', '\u{FE62}',
+    '\u{FE63}', '\u{FE64}', '\u{FE65}', '\u{FE66}', '\u{FE68}', '\u{FE69}', '\u{FE6A}', '\u{FE6B}',
+    '\u{FE70}', '\u{FE71}', '\u{FE72}', '\u{FE74}', '\u{FE76}', '\u{FE77}', '\u{FE78}', '\u{FE79}',
+    '\u{FE7A}', '\u{FE7B}', '\u{FE7C}', '\u{FE7D}', '\u{FE7E}', '\u{FE7F}', '\u{FE80}', '\u{FE81}',
+    '\u{FE82}', '\u{FE83}', '\u{FE84}', '\u{FE85}', '\u{FE86}', '\u{FE87}', '\u{FE88}', '\u{FE89}',
+    '\u{FE8A}', '\u{FE8B}', '\u{FE8C}', '\u{FE8D}', '\u{FE8E}', '\u{FE8F}', '\u{FE90}', '\u{FE91}',
+    '\u{FE92}', '\u{FE93}', '\u{FE94}', '\u{FE95}', '\u{FE96}', '\u{FE97}', '\u{FE98}', '\u{FE99}',
+    '\u{FE9A}', '\u{FE9B}', '\u{FE9C}', '\u{FE9D}', '\u{FE9E}', '\u{FE9F}', '\u{FEA0}', '\u{FEA1}',
+    '\u{FEA2}', '\u{FEA3}', '\u{FEA4}', '\u{FEA5}', '\u{FEA6}', '\u{FEA7}', '\u{FEA8}', '\u{FEA9}',
+    '\u{FEAA}', '\u{FEAB}', '\u{FEAC}', '\u{FEAD}', '\u{FEAE}', '\u{FEAF}', '\u{FEB0}', '\u{FEB1}',
+    '\u{FEB2}', '\u{FEB3}', '\u{FEB4}', '\u{FEB5}', '\u{FEB6}', '\u{FEB7}', '\u{FEB8}', '\u{FEB9}',
+    '\u{FEBA}', '\u{FEBB}', '\u{FEBC}', '\u{FEBD}', '\u{FEBE}', '\u{FEBF}', '\u{FEC0}', '\u{FEC1}',
+    '\u{FEC2}', '\u{FEC3}', '\u{FEC4}', '\u{FEC5}', '\u{FEC6}', '\u{FEC7}', '\u{FEC8}', '\u{FEC9}',
+    '\u{FECA}', '\u{FECB}', '\u{FECC}', '\u{FECD}', '\u{FECE}', '\u{FECF}', '\u{FED0}', '\u{FED1}',
+    '\u{FED2}', '\u{FED3}', '\u{FED4}', '\u{FED5}', '\u{FED6}', '\u{FED7}', '\u{FED8}', '\u{FED9}',
+    '\u{FEDA}', '\u{FEDB}', '\u{FEDC}', '\u{FEDD}', '\u{FEDE}', '\u{FEDF}', '\u{FEE0}', '\u{FEE1}',
+    '\u{FEE2}', '\u{FEE3}', '\u{FEE4}', '\u{FEE5}', '\u{FEE6}', '\u{FEE7}', '\u{FEE8}', '\u{FEE9}',
+    '\u{FEEA}', '\u{FEEB}', '\u{FEEC}', '\u{FEED}', '\u{FEEE}', '\u{FEEF}', '\u{FEF0}', '\u{FEF1}',
+    '\u{FEF2}', '\u{FEF3}', '\u{FEF4}', '\u{FEF5}', '\u{FEF6}', '\u{FEF7}', '\u{FEF8}', '\u{FEF9}',
+    '\u{FEFA}', '\u{FEFB}', '\u{FEFC}', '\u{FF01}', '\u{FF02}', '\u{FF03}', '\u{FF04}', '\u{FF05}',
+    '\u{FF06}', '\u{FF07}', '\u{FF08}', '\u{FF09}', '\u{FF0A}', '\u{FF0B}', '\u{FF0C}', '\u{FF0D}',
+    '\u{FF0E}', '\u{FF0F}', '\u{FF10}', '\u{FF11}', '\u{FF12}', '\u{FF13}', '\u{FF14}', '\u{FF15}',
+    '\u{FF16}', '\u{FF17}', '\u{FF18}', '\u{FF19}', '\u{FF1A}', '\u{FF1B}', '\u{FF1C}', '\u{FF1D}',
+    '\u{FF1E}', '\u{FF1F}', '\u{FF20}', '\u{FF21}', '\u{FF22}', '\u{FF23}', '\u{FF24}', '\u{FF25}',
+    '\u{FF26}', '\u{FF27}', '\u{FF28}', '\u{FF29}', '\u{FF2A}', '\u{FF2B}', '\u{FF2C}', '\u{FF2D}',
+    '\u{FF2E}', '\u{FF2F}', '\u{FF30}', '\u{FF31}', '\u{FF32}', '\u{FF33}', '\u{FF34}', '\u{FF35}',
+    '\u{FF36}', '\u{FF37}', '\u{FF38}', '\u{FF39}', '\u{FF3A}', '\u{FF3B}', '\u{FF3C}', '\u{FF3D}',
+    '\u{FF3E}', '\u{FF3F}', '\u{FF40}', '\u{FF41}', '\u{FF42}', '\u{FF43}', '\u{FF44}', '\u{FF45}',
+    '\u{FF46}', '\u{FF47}', '\u{FF48}', '\u{FF49}', '\u{FF4A}', '\u{FF4B}', '\u{FF4C}', '\u{FF4D}',
+    '\u{FF4E}', '\u{FF4F}', '\u{FF50}', '\u{FF51}', '\u{FF52}', '\u{FF53}', '\u{FF54}', '\u{FF55}',
+    '\u{FF56}', '\u{FF57}', '\u{FF58}', '\u{FF59}', '\u{FF5A}', '\u{FF5B}', '\u{FF5C}', '\u{FF5D}',
+    '\u{FF5E}', '\u{FF5F}', '\u{FF60}', '\u{FF61}', '\u{FF62}', '\u{FF63}', '\u{FF64}', '\u{FF65}',
+    '\u{FF66}', '\u{FF67}', '\u{FF68}', '\u{FF69}', '\u{FF6A}', '\u{FF6B}', '\u{FF6C}', '\u{FF6D}',
+    '\u{FF6E}', '\u{FF6F}', '\u{FF70}', '\u{FF71}', '\u{FF72}', '\u{FF73}', '\u{FF74}', '\u{FF75}',
+    '\u{FF76}', '\u{FF77}', '\u{FF78}', '\u{FF79}', '\u{FF7A}', '\u{FF7B}', '\u{FF7C}', '\u{FF7D}',
+    '\u{FF7E}', '\u{FF7F}', '\u{FF80}', '\u{FF81}', '\u{FF82}', '\u{FF83}', '\u{FF84}', '\u{FF85}',
+    '\u{FF86}', '\u{FF87}', '\u{FF88}', '\u{FF89}', '\u{FF8A}', '\u{FF8B}', '\u{FF8C}', '\u{FF8D}',
+    '\u{FF8E}', '\u{FF8F}', '\u{FF90}', '\u{FF91}', '\u{FF92}', '\u{FF93}', '\u{FF94}', '\u{FF95}',
+    '\u{FF96}', '\u{FF97}', '\u{FF98}', '\u{FF99}', '\u{FF9A}', '\u{FF9B}', '\u{FF9C}', '\u{FF9D}',
+    '\u{FF9E}', '\u{FF9F}', '\u{FFA0}', '\u{FFA1}', '\u{FFA2}', '\u{FFA3}', '\u{FFA4}', '\u{FFA5}',
+    '\u{FFA6}', '\u{FFA7}', '\u{FFA8}', '\u{FFA9}', '\u{FFAA}', '\u{FFAB}', '\u{FFAC}', '\u{FFAD}',
+    '\u{FFAE}', '\u{FFAF}', '\u{FFB0}', '\u{FFB1}', '\u{FFB2}', '\u{FFB3}', '\u{FFB4}', '\u{FFB5}',
+    '\u{FFB6}', '\u{FFB7}', '\u{FFB8}', '\u{FFB9}', '\u{FFBA}', '\u{FFBB}', '\u{FFBC}', '\u{FFBD}',
+    '\u{FFBE}', '\u{FFC2}', '\u{FFC3}', '\u{FFC4}', '\u{FFC5}', '\u{FFC6}', '\u{FFC7}', '\u{FFCA}',
+    '\u{FFCB}', '\u{FFCC}', '\u{FFCD}', '\u{FFCE}', '\u{FFCF}', '\u{FFD2}', '\u{FFD3}', '\u{FFD4}',
+    '\u{FFD5}', '\u{FFD6}', '\u{FFD7}', '\u{FFDA}', '\u{FFDB}', '\u{FFDC}', '\u{FFE0}', '\u{FFE1}',
+    '\u{FFE2}', '\u{FFE3}', '\u{FFE4}', '\u{FFE5}', '\u{FFE6}', '\u{FFE8}', '\u{FFE9}', '\u{FFEA}',
+    '\u{FFEB}', '\u{FFEC}', '\u{FFED}', '\u{FFEE}', '\u{10781}', '\u{10782}', '\u{10783}', '\u{10784}',
+    '\u{10785}', '\u{10787}', '\u{10788}', '\u{10789}', '\u{1078A}', '\u{1078B}', '\u{1078C}', '\u{1078D}',
+    '\u{1078E}', '\u{1078F}', '\u{10790}', '\u{10791}', '\u{10792}', '\u{10793}', '\u{10794}', '\u{10795}',
+    '\u{10796}', '\u{10797}', '\u{10798}', '\u{10799}', '\u{1079A}', '\u{1079B}', '\u{1079C}', '\u{1079D}',
+    '\u{1079E}', '\u{1079F}', '\u{107A0}', '\u{107A1}', '\u{107A2}', '\u{107A3}', '\u{107A4}', '\u{107A5}',
+    '\u{107A6}', '\u{107A7}', '\u{107A8}', '\u{107A9}', '\u{107AA}', '\u{107AB}', '\u{107AC}', '\u{107AD}',
+    '\u{107AE}', '\u{107AF}', '\u{107B0}', '\u{107B2}', '\u{107B3}', '\u{107B4}', '\u{107B5}', '\u{107B6}',
+    '\u{107B7}', '\u{107B8}', '\u{107B9}', '\u{107BA}', '\u{1CCD6}', '\u{1CCD7}', '\u{1CCD8}', '\u{1CCD9}',
+    '\u{1CCDA}', '\u{1CCDB}', '\u{1CCDC}', '\u{1CCDD}', '\u{1CCDE}', '\u{1CCDF}', '\u{1CCE0}', '\u{1CCE1}',
+    '\u{1CCE2}', '\u{1CCE3}', '\u{1CCE4}', '\u{1CCE5}', '\u{1CCE6}', '\u{1CCE7}', '\u{1CCE8}', '\u{1CCE9}',
+    '\u{1CCEA}', '\u{1CCEB}', '\u{1CCEC}', '\u{1CCED}', '\u{1CCEE}', '\u{1CCEF}', '\u{1CCF0}', '\u{1CCF1}',
+    '\u{1CCF2}', '\u{1CCF3}', '\u{1CCF4}', '\u{1CCF5}', '\u{1CCF6}', '\u{1CCF7}', '\u{1CCF8}', '\u{1CCF9}',
+    '\u{1D400}', '\u{1D401}', '\u{1D402}', '\u{1D403}', '\u{1D404}', '\u{1D405}', '\u{1D406}', '\u{1D407}',
+    '\u{1D408}', '\u{1D409}', '\u{1D40A}', '\u{1D40B}', '\u{1D40C}', '\u{1D40D}', '\u{1D40E}', '\u{1D40F}',
+    '\u{1D410}', '\u{1D411}', '\u{1D412}', '\u{1D413}', '\u{1D414}', '\u{1D415}', '\u{1D416}', '\u{1D417}',
+    '\u{1D418}', '\u{1D419}', '\u{1D41A}', '\u{1D41B}', '\u{1D41C}', '\u{1D41D}', '\u{1D41E}', '\u{1D41F}',
+    '\u{1D420}', '\u{1D421}', '\u{1D422}', '\u{1D423}', '\u{1D424}', '\u{1D425}', '\u{1D426}', '\u{1D427}',
+    '\u{1D428}', '\u{1D429}', '\u{1D42A}', '\u{1D42B}', '\u{1D42C}', '\u{1D42D}', '\u{1D42E}', '\u{1D42F}',
+    '\u{1D430}', '\u{1D431}', '\u{1D432}', '\u{1D433}', '\u{1D434}', '\u{1D435}', '\u{1D436}', '\u{1D437}',
+    '\u{1D438}', '\u{1D439}', '\u{1D43A}', '\u{1D43B}', '\u{1D43C}', '\u{1D43D}', '\u{1D43E}', '\u{1D43F}',
+    '\u{1D440}', '\u{1D441}', '\u{1D442}', '\u{1D443}', '\u{1D444}', '\u{1D445}', '\u{1D446}', '\u{1D447}',
+    '\u{1D448}', '\u{1D449}', '\u{1D44A}', '\u{1D44B}', '\u{1D44C}', '\u{1D44D}', '\u{1D44E}', '\u{1D44F}',
+    '\u{1D450}', '\u{1D451}', '\u{1D452}', '\u{1D453}', '\u{1D454}', '\u{1D456}', '\u{1D457}', '\u{1D458}',
+    '\u{1D459}', '\u{1D45A}', '\u{1D45B}', '\u{1D45C}', '\u{1D45D}', '\u{1D45E}', '\u{1D45F}', '\u{1D460}',
+    '\u{1D461}', '\u{1D462}', '\u{1D463}', '\u{1D464}', '\u{1D465}', '\u{1D466}', '\u{1D467}', '\u{1D468}',
+    '\u{1D469}', '\u{1D46A}', '\u{1D46B}', '\u{1D46C}', '\u{1D46D}', '\u{1D46E}', '\u{1D46F}', '\u{1D470}',
+    '\u{1D471}', '\u{1D472}', '\u{1D473}', '\u{1D474}', '\u{1D475}', '\u{1D476}', '\u{1D477}', '\u{1D478}',
+    '\u{1D479}', '\u{1D47A}', '\u{1D47B}', '\u{1D47C}', '\u{1D47D}', '\u{1D47E}', '\u{1D47F}', '\u{1D480}',
+    '\u{1D481}', '\u{1D482}', '\u{1D483}', '\u{1D484}', '\u{1D485}', '\u{1D486}', '\u{1D487}', '\u{1D488}',
+    '\u{1D489}', '\u{1D48A}', '\u{1D48B}', '\u{1D48C}', '\u{1D48D}', '\u{1D48E}', '\u{1D48F}', '\u{1D490}',
+    '\u{1D491}', '\u{1D492}', '\u{1D493}', '\u{1D494}', '\u{1D495}', '\u{1D496}', '\u{1D497}', '\u{1D498}',
+    '\u{1D499}', '\u{1D49A}', '\u{1D49B}', '\u{1D49C}', '\u{1D49E}', '\u{1D49F}', '\u{1D4A2}', '\u{1D4A5}',
+    '\u{1D4A6}', '\u{1D4A9}', '\u{1D4AA}', '\u{1D4AB}', '\u{1D4AC}', '\u{1D4AE}', '\u{1D4AF}', '\u{1D4B0}',
+    '\u{1D4B1}', '\u{1D4B2}', '\u{1D4B3}', '\u{1D4B4}', '\u{1D4B5}', '\u{1D4B6}', '\u{1D4B7}', '\u{1D4B8}',
+    '\u{1D4B9}', '\u{1D4BB}', '\u{1D4BD}', '\u{1D4BE}', '\u{1D4BF}', '\u{1D4C0}', '\u{1D4C1}', '\u{1D4C2}',
+    '\u{1D4C3}', '\u{1D4C5}', '\u{1D4C6}', '\u{1D4C7}', '\u{1D4C8}', '\u{1D4C9}', '\u{1D4CA}', '\u{1D4CB}',
+    '\u{1D4CC}', '\u{1D4CD}', '\u{1D4CE}', '\u{1D4CF}', '\u{1D4D0}', '\u{1D4D1}', '\u{1D4D2}', '\u{1D4D3}',
+    '\u{1D4D4}', '\u{1D4D5}', '\u{1D4D6}', '\u{1D4D7}', '\u{1D4D8}', '\u{1D4D9}', '\u{1D4DA}', '\u{1D4DB}',
+    '\u{1D4DC}', '\u{1D4DD}', '\u{1D4DE}', '\u{1D4DF}', '\u{1D4E0}', '\u{1D4E1}', '\u{1D4E2}', '\u{1D4E3}',
+    '\u{1D4E4}', '\u{1D4E5}', '\u{1D4E6}', '\u{1D4E7}', '\u{1D4E8}', '\u{1D4E9}', '\u{1D4EA}', '\u{1D4EB}',
+    '\u{1D4EC}', '\u{1D4ED}', '\u{1D4EE}', '\u{1D4EF}', '\u{1D4F0}', '\u{1D4F1}', '\u{1D4F2}', '\u{1D4F3}',
+    '\u{1D4F4}', '\u{1D4F5}', '\u{1D4F6}', '\u{1D4F7}', '\u{1D4F8}', '\u{1D4F9}', '\u{1D4FA}', '\u{1D4FB}',
+    '\u{1D4FC}', '\u{1D4FD}', '\u{1D4FE}', '\u{1D4FF}', '\u{1D500}', '\u{1D501}', '\u{1D502}', '\u{1D503}',
+    '\u{1D504}', '\u{1D505}', '\u{1D507}', '\u{1D508}', '\u{1D509}', '\u{1D50A}', '\u{1D50D}', '\u{1D50E}',
+    '\u{1D50F}', '\u{1D510}', '\u{1D511}', '\u{1D512}', '\u{1D513}', '\u{1D514}', '\u{1D516}', '\u{1D517}',
+    '\u{1D518}', '\u{1D519}', '\u{1D51A}', '\u{1D51B}', '\u{1D51C}', '\u{1D51E}', '\u{1D51F}', '\u{1D520}',
+    '\u{1D521}', '\u{1D522}', '\u{1D523}', '\u{1D524}', '\u{1D525}', '\u{1D526}', '\u{1D527}', '\u{1D528}',
+    '\u{1D529}', '\u{1D52A}', '\u{1D52B}', '\u{1D52C}', '\u{1D52D}', '\u{1D52E}', '\u{1D52F}', '\u{1D530}',
+    '\u{1D531}', '\u{1D532}', '\u{1D533}', '\u{1D534}', '\u{1D535}', '\u{1D536}', '\u{1D537}', '\u{1D538}',
+    '\u{1D539}', '\u{1D53B}', '\u{1D53C}', '\u{1D53D}', '\u{1D53E}', '\u{1D540}', '\u{1D541}', '\u{1D542}',
+    '\u{1D543}', '\u{1D544}', '\u{1D546}', '\u{1D54A}', '\u{1D54B}', '\u{1D54C}', '\u{1D54D}', '\u{1D54E}',
+    '\u{1D54F}', '\u{1D550}', '\u{1D552}', '\u{1D553}', '\u{1D554}', '\u{1D555}', '\u{1D556}', '\u{1D557}',
+    '\u{1D558}', '\u{1D559}', '\u{1D55A}', '\u{1D55B}', '\u{1D55C}', '\u{1D55D}', '\u{1D55E}', '\u{1D55F}',
+    '\u{1D560}', '\u{1D561}', '\u{1D562}', '\u{1D563}', '\u{1D564}', '\u{1D565}', '\u{1D566}', '\u{1D567}',
+    '\u{1D568}', '\u{1D569}', '\u{1D56A}', '\u{1D56B}', '\u{1D56C}', '\u{1D56D}', '\u{1D56E}', '\u{1D56F}',
+    '\u{1D570}', '\u{1D571}', '\u{1D572}', '\u{1D573}', '\u{1D574}', '\u{1D575}', '\u{1D576}', '\u{1D577}',
+    '\u{1D578}', '\u{1D579}', '\u{1D57A}', '\u{1D57B}', '\u{1D57C}', '\u{1D57D}', '\u{1D57E}', '\u{1D57F}',
+    '\u{1D580}', '\u{1D581}', '\u{1D582}', '\u{1D583}', '\u{1D584}', '\u{1D585}', '\u{1D586}', '\u{1D587}',
+    '\u{1D588}', '\u{1D589}', '\u{1D58A}', '\u{1D58B}', '\u{1D58C}', '\u{1D58D}', '\u{1D58E}', '\u{1D58F}',
+    '\u{1D590}', '\u{1D591}', '\u{1D592}', '\u{1D593}', '\u{1D594}', '\u{1D595}', '\u{1D596}', '\u{1D597}',
+    '\u{1D598}', '\u{1D599}', '\u{1D59A}', '\u{1D59B}', '\u{1D59C}', '\u{1D59D}', '\u{1D59E}', '\u{1D59F}',
+    '\u{1D5A0}', '\u{1D5A1}', '\u{1D5A2}', '\u{1D5A3}', '\u{1D5A4}', '\u{1D5A5}', '\u{1D5A6}', '\u{1D5A7}',
+    '\u{1D5A8}', '\u{1D5A9}', '\u{1D5AA}', '\u{1D5AB}', '\u{1D5AC}', '\u{1D5AD}', '\u{1D5AE}', '\u{1D5AF}',
+    '\u{1D5B0}', '\u{1D5B1}', '\u{1D5B2}', '\u{1D5B3}', '\u{1D5B4}', '\u{1D5B5}', '\u{1D5B6}', '\u{1D5B7}',
+    '\u{1D5B8}', '\u{1D5B9}', '\u{1D5BA}', '\u{1D5BB}', '\u{1D5BC}', '\u{1D5BD}', '\u{1D5BE}', '\u{1D5BF}',
+    '\u{1D5C0}', '\u{1D5C1}', '\u{1D5C2}', '\u{1D5C3}', '\u{1D5C4}', '\u{1D5C5}', '\u{1D5C6}', '\u{1D5C7}',
+    '\u{1D5C8}', '\u{1D5C9}', '\u{1D5CA}', '\u{1D5CB}', '\u{1D5CC}', '\u{1D5CD}', '\u{1D5CE}', '\u{1D5CF}',
+    '\u{1D5D0}', '\u{1D5D1}', '\u{1D5D2}', '\u{1D5D3}', '\u{1D5D4}', '\u{1D5D5}', '\u{1D5D6}', '\u{1D5D7}',
+    '\u{1D5D8}', '\u{1D5D9}', '\u{1D5DA}', '\u{1D5DB}', '\u{1D5DC}', '\u{1D5DD}', '\u{1D5DE}', '\u{1D5DF}',
+    '\u{1D5E0}', '\u{1D5E1}', '\u{1D5E2}', '\u{1D5E3}', '\u{1D5E4}', '\u{1D5E5}', '\u{1D5E6}', '\u{1D5E7}',
+    '\u{1D5E8}', '\u{1D5E9}', '\u{1D5EA}', '\u{1D5EB}', '\u{1D5EC}', '\u{1D5ED}', '\u{1D5EE}', '\u{1D5EF}',
+    '\u{1D5F0}', '\u{1D5F1}', '\u{1D5F2}', '\u{1D5F3}', '\u{1D5F4}', '\u{1D5F5}', '\u{1D5F6}', '\u{1D5F7}',
+    '\u{1D5F8}', '\u{1D5F9}', '\u{1D5FA}', '\u{1D5FB}', '\u{1D5FC}', '\u{1D5FD}', '\u{1D5FE}', '\u{1D5FF}',
+    '\u{1D600}', '\u{1D601}', '\u{1D602}', '\u{1D603}', '\u{1D604}', '\u{1D605}', '\u{1D606}', '\u{1D607}',
+    '\u{1D608}', '\u{1D609}', '\u{1D60A}', '\u{1D60B}', '\u{1D60C}', '\u{1D60D}', '\u{1D60E}', '\u{1D60F}',
+    '\u{1D610}', '\u{1D611}', '\u{1D612}', '\u{1D613}', '\u{1D614}', '\u{1D615}', '\u{1D616}', '\u{1D617}',
+    '\u{1D618}', '\u{1D619}', '\u{1D61A}', '\u{1D61B}', '\u{1D61C}', '\u{1D61D}', '\u{1D61E}', '\u{1D61F}',
+    '\u{1D620}', '\u{1D621}', '\u{1D622}', '\u{1D623}', '\u{1D624}', '\u{1D625}', '\u{1D626}', '\u{1D627}',
+    '\u{1D628}', '\u{1D629}', '\u{1D62A}', '\u{1D62B}', '\u{1D62C}', '\u{1D62D}', '\u{1D62E}', '\u{1D62F}',
+    '\u{1D630}', '\u{1D631}', '\u{1D632}', '\u{1D633}', '\u{1D634}', '\u{1D635}', '\u{1D636}', '\u{1D637}',
+    '\u{1D638}', '\u{1D639}', '\u{1D63A}', '\u{1D63B}', '\u{1D63C}', '\u{1D63D}', '\u{1D63E}', '\u{1D63F}',
+    '\u{1D640}', '\u{1D641}', '\u{1D642}', '\u{1D643}', '\u{1D644}', '\u{1D645}', '\u{1D646}', '\u{1D647}',
+    '\u{1D648}', '\u{1D649}', '\u{1D64A}', '\u{1D64B}', '\u{1D64C}', '\u{1D64D}', '\u{1D64E}', '\u{1D64F}',
+    '\u{1D650}', '\u{1D651}', '\u{1D652}', '\u{1D653}', '\u{1D654}', '\u{1D655}', '\u{1D656}', '\u{1D657}',
+    '\u{1D658}', '\u{1D659}', '\u{1D65A}', '\u{1D65B}', '\u{1D65C}', '\u{1D65D}', '\u{1D65E}', '\u{1D65F}',
+    '\u{1D660}', '\u{1D661}', '\u{1D662}', '\u{1D663}', '\u{1D664}', '\u{1D665}', '\u{1D666}', '\u{1D667}',
+    '\u{1D668}', '\u{1D669}', '\u{1D66A}', '\u{1D66B}', '\u{1D66C}', '\u{1D66D}', '\u{1D66E}', '\u{1D66F}',
+    '\u{1D670}', '\u{1D671}', '\u{1D672}', '\u{1D673}', '\u{1D674}', '\u{1D675}', '\u{1D676}', '\u{1D677}',
+    '\u{1D678}', '\u{1D679}', '\u{1D67A}', '\u{1D67B}', '\u{1D67C}', '\u{1D67D}', '\u{1D67E}', '\u{1D67F}',
+    '\u{1D680}', '\u{1D681}', '\u{1D682}', '\u{1D683}', '\u{1D684}', '\u{1D685}', '\u{1D686}', '\u{1D687}',
+    '\u{1D688}', '\u{1D689}', '\u{1D68A}', '\u{1D68B}', '\u{1D68C}', '\u{1D68D}', '\u{1D68E}', '\u{1D68F}',
+    '\u{1D690}', '\u{1D691}', '\u{1D692}', '\u{1D693}', '\u{1D694}', '\u{1D695}', '\u{1D696}', '\u{1D697}',
+    '\u{1D698}', '\u{1D699}', '\u{1D69A}', '\u{1D69B}', '\u{1D69C}', '\u{1D69D}', '\u{1D69E}', '\u{1D69F}',
+    '\u{1D6A0}', '\u{1D6A1}', '\u{1D6A2}', '\u{1D6A3}', '\u{1D6A4}', '\u{1D6A5}', '\u{1D6A8}', '\u{1D6A9}',
+    '\u{1D6AA}', '\u{1D6AB}', '\u{1D6AC}', '\u{1D6AD}', '\u{1D6AE}', '\u{1D6AF}', '\u{1D6B0}', '\u{1D6B1}',
+    '\u{1D6B2}', '\u{1D6B3}', '\u{1D6B4}', '\u{1D6B5}', '\u{1D6B6}', '\u{1D6B7}', '\u{1D6B8}', '\u{1D6B9}',
+    '\u{1D6BA}', '\u{1D6BB}', '\u{1D6BC}', '\u{1D6BD}', '\u{1D6BE}', '\u{1D6BF}', '\u{1D6C0}', '\u{1D6C1}',
+    '\u{1D6C2}', '\u{1D6C3}', '\u{1D6C4}', '\u{1D6C5}', '\u{1D6C6}', '\u{1D6C7}', '\u{1D6C8}', '\u{1D6C9}',
+    '\u{1D6CA}', '\u{1D6CB}', '\u{1D6CC}', '\u{1D6CD}', '\u{1D6CE}', '\u{1D6CF}', '\u{1D6D0}', '\u{1D6D1}',
+    '\u{1D6D2}', '\u{1D6D3}', '\u{1D6D4}', '\u{1D6D5}', '\u{1D6D6}', '\u{1D6D7}', '\u{1D6D8}', '\u{1D6D9}',
+    '\u{1D6DA}', '\u{1D6DB}', '\u{1D6DC}', '\u{1D6DD}', '\u{1D6DE}', '\u{1D6DF}', '\u{1D6E0}', '\u{1D6E1}',
+    '\u{1D6E2}', '\u{1D6E3}', '\u{1D6E4}', '\u{1D6E5}', '\u{1D6E6}', '\u{1D6E7}', '\u{1D6E8}', '\u{1D6E9}',
+    '\u{1D6EA}', '\u{1D6EB}', '\u{1D6EC}', '\u{1D6ED}', '\u{1D6EE}', '\u{1D6EF}', '\u{1D6F0}', '\u{1D6F1}',
+    '\u{1D6F2}', '\u{1D6F3}', '\u{1D6F4}', '\u{1D6F5}', '\u{1D6F6}', '\u{1D6F7}', '\u{1D6F8}', '\u{1D6F9}',
+    '\u{1D6FA}', '\u{1D6FB}', '\u{1D6FC}', '\u{1D6FD}', '\u{1D6FE}', '\u{1D6FF}', '\u{1D700}', '\u{1D701}',
+    '\u{1D702}', '\u{1D703}', '\u{1D704}', '\u{1D705}', '\u{1D706}', '\u{1D707}', '\u{1D708}', '\u{1D709}',
+    '\u{1D70A}', '\u{1D70B}', '\u{1D70C}', '\u{1D70D}', '\u{1D70E}', '\u{1D70F}', '\u{1D710}', '\u{1D711}',
+    '\u{1D712}', '\u{1D713}', '\u{1D714}', '\u{1D715}', '\u{1D716}', '\u{1D717}', '\u{1D718}', '\u{1D719}',
+    '\u{1D71A}', '\u{1D71B}', '\u{1D71C}', '\u{1D71D}', '\u{1D71E}', '\u{1D71F}', '\u{1D720}', '\u{1D721}',
+    '\u{1D722}', '\u{1D723}', '\u{1D724}', '\u{1D725}', '\u{1D726}', '\u{1D727}', '\u{1D728}', '\u{1D729}',
+    '\u{1D72A}', '\u{1D72B}', '\u{1D72C}', '\u{1D72D}', '\u{1D72E}', '\u{1D72F}', '\u{1D730}', '\u{1D731}',
+    '\u{1D732}', '\u{1D733}', '\u{1D734}', '\u{1D735}', '\u{1D736}', '\u{1D737}', '\u{1D738}', '\u{1D739}',
+    '\u{1D73A}', '\u{1D73B}', '\u{1D73C}', '\u{1D73D}', '\u{1D73E}', '\u{1D73F}', '\u{1D740}', '\u{1D741}',
+    '\u{1D742}', '\u{1D743}', '\u{1D744}', '\u{1D745}', '\u{1D746}', '\u{1D747}', '\u{1D748}', '\u{1D749}',
+    '\u{1D74A}', '\u{1D74B}', '\u{1D74C}', '\u{1D74D}', '\u{1D74E}', '\u{1D74F}', '\u{1D750}', '\u{1D751}',
+    '\u{1D752}', '\u{1D753}', '\u{1D754}', '\u{1D755}', '\u{1D756}', '\u{1D757}', '\u{1D758}', '\u{1D759}',
+    '\u{1D75A}', '\u{1D75B}', '\u{1D75C}', '\u{1D75D}', '\u{1D75E}', '\u{1D75F}', '\u{1D760}', '\u{1D761}',
+    '\u{1D762}', '\u{1D763}', '\u{1D764}', '\u{1D765}', '\u{1D766}', '\u{1D767}', '\u{1D768}', '\u{1D769}',
+    '\u{1D76A}', '\u{1D76B}', '\u{1D76C}', '\u{1D76D}', '\u{1D76E}', '\u{1D76F}', '\u{1D770}', '\u{1D771}',
+    '\u{1D772}', '\u{1D773}', '\u{1D774}', '\u{1D775}', '\u{1D776}', '\u{1D777}', '\u{1D778}', '\u{1D779}',
+    '\u{1D77A}', '\u{1D77B}', '\u{1D77C}', '\u{1D77D}', '\u{1D77E}', '\u{1D77F}', '\u{1D780}', '\u{1D781}',
+    '\u{1D782}', '\u{1D783}', '\u{1D784}', '\u{1D785}', '\u{1D786}', '\u{1D787}', '\u{1D788}', '\u{1D789}',
+    '\u{1D78A}', '\u{1D78B}', '\u{1D78C}', '\u{1D78D}', '\u{1D78E}', '\u{1D78F}', '\u{1D790}', '\u{1D791}',
+    '\u{1D792}', '\u{1D793}', '\u{1D794}', '\u{1D795}', '\u{1D796}', '\u{1D797}', '\u{1D798}', '\u{1D799}',
+    '\u{1D79A}', '\u{1D79B}', '\u{1D79C}', '\u{1D79D}', '\u{1D79E}', '\u{1D79F}', '\u{1D7A0}', '\u{1D7A1}',
+    '\u{1D7A2}', '\u{1D7A3}', '\u{1D7A4}', '\u{1D7A5}', '\u{1D7A6}', '\u{1D7A7}', '\u{1D7A8}', '\u{1D7A9}',
+    '\u{1D7AA}', '\u{1D7AB}', '\u{1D7AC}', '\u{1D7AD}', '\u{1D7AE}', '\u{1D7AF}', '\u{1D7B0}', '\u{1D7B1}',
+    '\u{1D7B2}', '\u{1D7B3}', '\u{1D7B4}', '\u{1D7B5}', '\u{1D7B6}', '\u{1D7B7}', '\u{1D7B8}', '\u{1D7B9}',
+    '\u{1D7BA}', '\u{1D7BB}', '\u{1D7BC}', '\u{1D7BD}', '\u{1D7BE}', '\u{1D7BF}', '\u{1D7C0}', '\u{1D7C1}',
+    '\u{1D7C2}', '\u{1D7C3}', '\u{1D7C4}', '\u{1D7C5}', '\u{1D7C6}', '\u{1D7C7}', '\u{1D7C8}', '\u{1D7C9}',
+    '\u{1D7CA}', '\u{1D7CB}', '\u{1D7CE}', '\u{1D7CF}', '\u{1D7D0}', '\u{1D7D1}', '\u{1D7D2}', '\u{1D7D3}',
+    '\u{1D7D4}', '\u{1D7D5}', '\u{1D7D6}', '\u{1D7D7}', '\u{1D7D8}', '\u{1D7D9}', '\u{1D7DA}', '\u{1D7DB}',
+    '\u{1D7DC}', '\u{1D7DD}', '\u{1D7DE}', '\u{1D7DF}', '\u{1D7E0}', '\u{1D7E1}', '\u{1D7E2}', '\u{1D7E3}',
+    '\u{1D7E4}', '\u{1D7E5}', '\u{1D7E6}', '\u{1D7E7}', '\u{1D7E8}', '\u{1D7E9}', '\u{1D7EA}', '\u{1D7EB}',
+    '\u{1D7EC}', '\u{1D7ED}', '\u{1D7EE}', '\u{1D7EF}', '\u{1D7F0}', '\u{1D7F1}', '\u{1D7F2}', '\u{1D7F3}',
+    '\u{1D7F4}', '\u{1D7F5}', '\u{1D7F6}', '\u{1D7F7}', '\u{1D7F8}', '\u{1D7F9}', '\u{1D7FA}', '\u{1D7FB}',
+    '\u{1D7FC}', '\u{1D7FD}', '\u{1D7FE}', '\u{1D7FF}', '\u{1E030}', '\u{1E031}', '\u{1E032}', '\u{1E033}',
+    '\u{1E034}', '\u{1E035}', '\u{1E036}', '\u{1E037}', '\u{1E038}', '\u{1E039}', '\u{1E03A}', '\u{1E03B}',
+    '\u{1E03C}', '\u{1E03D}', '\u{1E03E}', '\u{1E03F}', '\u{1E040}', '\u{1E041}', '\u{1E042}', '\u{1E043}',
+    '\u{1E044}', '\u{1E045}', '\u{1E046}', '\u{1E047}', '\u{1E048}', '\u{1E049}', '\u{1E04A}', '\u{1E04B}',
+    '\u{1E04C}', '\u{1E04D}', '\u{1E04E}', '\u{1E04F}', '\u{1E050}', '\u{1E051}', '\u{1E052}', '\u{1E053}',
+    '\u{1E054}', '\u{1E055}', '\u{1E056}', '\u{1E057}', '\u{1E058}', '\u{1E059}', '\u{1E05A}', '\u{1E05B}',
+    '\u{1E05C}', '\u{1E05D}', '\u{1E05E}', '\u{1E05F}', '\u{1E060}', '\u{1E061}', '\u{1E062}', '\u{1E063}',
+    '\u{1E064}', '\u{1E065}', '\u{1E066}', '\u{1E067}', '\u{1E068}', '\u{1E069}', '\u{1E06A}', '\u{1E06B}',
+    '\u{1E06C}', '\u{1E06D}', '\u{1EE00}', '\u{1EE01}', '\u{1EE02}', '\u{1EE03}', '\u{1EE05}', '\u{1EE06}',
+    '\u{1EE07}', '\u{1EE08}', '\u{1EE09}', '\u{1EE0A}', '\u{1EE0B}', '\u{1EE0C}', '\u{1EE0D}', '\u{1EE0E}',
+    '\u{1EE0F}', '\u{1EE10}', '\u{1EE11}', '\u{1EE12}', '\u{1EE13}', '\u{1EE14}', '\u{1EE15}', '\u{1EE16}',
+    '\u{1EE17}', '\u{1EE18}', '\u{1EE19}', '\u{1EE1A}', '\u{1EE1B}', '\u{1EE1C}', '\u{1EE1D}', '\u{1EE1E}',
+    '\u{1EE1F}', '\u{1EE21}', '\u{1EE22}', '\u{1EE24}', '\u{1EE27}', '\u{1EE29}', '\u{1EE2A}', '\u{1EE2B}',
+    '\u{1EE2C}', '\u{1EE2D}', '\u{1EE2E}', '\u{1EE2F}', '\u{1EE30}', '\u{1EE31}', '\u{1EE32}', '\u{1EE34}',
+    '\u{1EE35}', '\u{1EE36}', '\u{1EE37}', '\u{1EE39}', '\u{1EE3B}', '\u{1EE42}', '\u{1EE47}', '\u{1EE49}',
+    '\u{1EE4B}', '\u{1EE4D}', '\u{1EE4E}', '\u{1EE4F}', '\u{1EE51}', '\u{1EE52}', '\u{1EE54}', '\u{1EE57}',
+    '\u{1EE59}', '\u{1EE5B}', '\u{1EE5D}', '\u{1EE5F}', '\u{1EE61}', '\u{1EE62}', '\u{1EE64}', '\u{1EE67}',
+    '\u{1EE68}', '\u{1EE69}', '\u{1EE6A}', '\u{1EE6C}', '\u{1EE6D}', '\u{1EE6E}', '\u{1EE6F}', '\u{1EE70}',
+    '\u{1EE71}', '\u{1EE72}', '\u{1EE74}', '\u{1EE75}', '\u{1EE76}', '\u{1EE77}', '\u{1EE79}', '\u{1EE7A}',
+    '\u{1EE7B}', '\u{1EE7C}', '\u{1EE7E}', '\u{1EE80}', '\u{1EE81}', '\u{1EE82}', '\u{1EE83}', '\u{1EE84}',
+    '\u{1EE85}', '\u{1EE86}', '\u{1EE87}', '\u{1EE88}', '\u{1EE89}', '\u{1EE8B}', '\u{1EE8C}', '\u{1EE8D}',
+    '\u{1EE8E}', '\u{1EE8F}', '\u{1EE90}', '\u{1EE91}', '\u{1EE92}', '\u{1EE93}', '\u{1EE94}', '\u{1EE95}',
+    '\u{1EE96}', '\u{1EE97}', '\u{1EE98}', '\u{1EE99}', '\u{1EE9A}', '\u{1EE9B}', '\u{1EEA1}', '\u{1EEA2}',
+    '\u{1EEA3}', '\u{1EEA5}', '\u{1EEA6}', '\u{1EEA7}', '\u{1EEA8}', '\u{1EEA9}', '\u{1EEAB}', '\u{1EEAC}',
+    '\u{1EEAD}', '\u{1EEAE}', '\u{1EEAF}', '\u{1EEB0}', '\u{1EEB1}', '\u{1EEB2}', '\u{1EEB3}', '\u{1EEB4}',
+    '\u{1EEB5}', '\u{1EEB6}', '\u{1EEB7}', '\u{1EEB8}', '\u{1EEB9}', '\u{1EEBA}', '\u{1EEBB}', '\u{1F100}',
+    '\u{1F101}', '\u{1F102}', '\u{1F103}', '\u{1F104}', '\u{1F105}', '\u{1F106}', '\u{1F107}', '\u{1F108}',
+    '\u{1F109}', '\u{1F10A}', '\u{1F110}', '\u{1F111}', '\u{1F112}', '\u{1F113}', '\u{1F114}', '\u{1F115}',
+    '\u{1F116}', '\u{1F117}', '\u{1F118}', '\u{1F119}', '\u{1F11A}', '\u{1F11B}', '\u{1F11C}', '\u{1F11D}',
+    '\u{1F11E}', '\u{1F11F}', '\u{1F120}', '\u{1F121}', '\u{1F122}', '\u{1F123}', '\u{1F124}', '\u{1F125}',
+    '\u{1F126}', '\u{1F127}', '\u{1F128}', '\u{1F129}', '\u{1F12A}', '\u{1F12B}', '\u{1F12C}', '\u{1F12D}',
+    '\u{1F12E}', '\u{1F130}', '\u{1F131}', '\u{1F132}', '\u{1F133}', '\u{1F134}', '\u{1F135}', '\u{1F136}',
+    '\u{1F137}', '\u{1F138}', '\u{1F139}', '\u{1F13A}', '\u{1F13B}', '\u{1F13C}', '\u{1F13D}', '\u{1F13E}',
+    '\u{1F13F}', '\u{1F140}', '\u{1F141}', '\u{1F142}', '\u{1F143}', '\u{1F144}', '\u{1F145}', '\u{1F146}',
+    '\u{1F147}', '\u{1F148}', '\u{1F149}', '\u{1F14A}', '\u{1F14B}', '\u{1F14C}', '\u{1F14D}', '\u{1F14E}',
+    '\u{1F14F}', '\u{1F16A}', '\u{1F16B}', '\u{1F16C}', '\u{1F190}', '\u{1F200}', '\u{1F201}', '\u{1F202}',
+    '\u{1F210}', '\u{1F211}', '\u{1F212}', '\u{1F213}', '\u{1F214}', '\u{1F215}', '\u{1F216}', '\u{1F217}',
+    '\u{1F218}', '\u{1F219}', '\u{1F21A}', '\u{1F21B}', '\u{1F21C}', '\u{1F21D}', '\u{1F21E}', '\u{1F21F}',
+    '\u{1F220}', '\u{1F221}', '\u{1F222}', '\u{1F223}', '\u{1F224}', '\u{1F225}', '\u{1F226}', '\u{1F227}',
+    '\u{1F228}', '\u{1F229}', '\u{1F22A}', '\u{1F22B}', '\u{1F22C}', '\u{1F22D}', '\u{1F22E}', '\u{1F22F}',
+    '\u{1F230}', '\u{1F231}', '\u{1F232}', '\u{1F233}', '\u{1F234}', '\u{1F235}', '\u{1F236}', '\u{1F237}',
+    '\u{1F238}', '\u{1F239}', '\u{1F23A}', '\u{1F23B}', '\u{1F240}', '\u{1F241}', '\u{1F242}', '\u{1F243}',
+    '\u{1F244}', '\u{1F245}', '\u{1F246}', '\u{1F247}', '\u{1F248}', '\u{1F250}', '\u{1F251}', '\u{1FBF0}',
+    '\u{1FBF1}', '\u{1FBF2}', '\u{1FBF3}', '\u{1FBF4}', '\u{1FBF5}', '\u{1FBF6}', '\u{1FBF7}', '\u{1FBF8}',
+    '\u{1FBF9}',
+];
+pub(crate) static COMPATIBILITY_DECOMPOSITION_ENDS: [u16; 3849] = [
+    1, 4, 5, 8, 9, 10, 13, 15, 18, 19, 20, 25,
+    30, 35, 37, 39, 42, 45, 48, 49, 53, 57, 61, 63,
+    65, 67, 69, 71, 73, 75, 77, 79, 80, 82, 83, 84,
+    86, 88, 90, 91, 92, 95, 98, 101, 104, 107, 110, 112,
+    113, 114, 115, 117, 120, 123, 128, 130, 132, 134, 138, 142,
+    144, 146, 148, 150, 152, 154, 156, 158, 162, 166, 170, 174,
+    178, 184, 190, 196, 202, 205, 214, 223, 226, 227, 229, 230,
+    231, 232, 234, 235, 236, 237, 238, 239, 240, 241, 242, 243,
+    245, 246, 247, 248, 249, 250, 251, 253, 255, 258, 259, 260,
+    261, 263, 265, 267, 268, 269, 270, 272, 273, 275, 278, 281,
+    282, 283, 284, 287, 289, 290, 293, 295, 297, 299, 301, 303,
+    304, 305, 306, 307, 309, 311, 313, 315, 317, 319, 321, 322,
+    324, 326, 328, 329, 331, 333, 335, 337, 339, 341, 344, 346,
+    348, 351, 353, 355, 357, 359, 361, 363, 365, 367, 369, 371,
+    373, 375, 377, 380, 382, 384, 385, 387, 389, 391, 393, 396,
+    399, 402, 405, 408, 413, 418, 423, 428, 433, 438, 443, 448,
+    453, 456, 459, 460, 461, 462, 463, 464, 465, 466, 467, 468,
+    469, 470, 473, 476, 477, 479, 482, 483, 489, 498, 504, 513,
+    515, 518, 520, 522, 524, 536, 537, 538, 539, 540, 541, 542,
+    543, 544, 545, 546, 549, 550, 551, 552, 553, 554, 555, 556,
+    557, 558, 559, 560, 561, 562, 563, 564, 567, 568, 569, 570,
+    571, 572, 573, 574, 576, 577, 578, 579, 580, 581, 582, 583,
+    584, 586, 589, 592, 593, 596, 599, 602, 604, 607, 608, 609,
+    610, 611, 612, 614, 615, 616, 617, 618, 619, 621, 622, 623,
+    624, 625, 626, 628, 631, 633, 634, 635, 636, 637, 638, 639,
+    640, 641, 642, 644, 646, 648, 650, 651, 654, 656, 658, 660,
+    662, 665, 666, 667, 668, 669, 670, 675, 680, 686, 691, 696,
+    701, 706, 711, 716, 721, 726, 731, 736, 741, 746, 750, 751,
+    753, 756, 758, 759, 761, 764, 768, 770, 771, 773, 776, 777,
+    778, 779, 780, 781, 783, 786, 788, 789, 791, 794, 798, 800,
+    801, 803, 806, 807, 808, 809, 810, 815, 821, 830, 836, 845,
+    846, 847, 848, 849, 850, 851, 852, 853, 854, 856, 858, 860,
+    862, 864, 866, 868, 870, 872, 874, 876, 879, 882, 885, 888,
+    891, 894, 897, 900, 903, 907, 911, 915, 919, 923, 927, 931,
+    935, 939, 943, 947, 949, 951, 953, 955, 957, 959, 961, 963,
+    965, 968, 971, 974, 977, 980, 983, 986, 989, 992, 995, 998,
+    1001, 1004, 1007, 1010, 1013, 1016, 1019, 1022, 1025, 1028, 1031, 1034,
+    1037, 1040, 1043, 1046, 1049, 1052, 1055, 1058, 1061, 1064, 1067, 1070,
+    1073, 1076, 1077, 1078, 1079, 1080, 1081, 1082, 1083, 1084, 1085, 1086,
+    1087, 1088, 1089, 1090, 1091, 1092, 1093, 1094, 1095, 1096, 1097, 1098,
+    1099, 1100, 1101, 1102, 1103, 1104, 1105, 1106, 1107, 1108, 1109, 1110,
+    1111, 1112, 1113, 1114, 1115, 1116, 1117, 1118, 1119, 1120, 1121, 1122,
+    1123, 1124, 1125, 1126, 1127, 1128, 1129, 1141, 1144, 1146, 1149, 1150,
+    1151, 1154, 1157, 1160, 1163, 1166, 1169, 1172, 1175, 1178, 1181, 1184,
+    1187, 1190, 1193, 1196, 1199, 1202, 1205, 1208, 1211, 1214, 1217, 1220,
+    1223, 1226, 1229, 1232, 1235, 1238, 1241, 1244, 1247, 1250, 1253, 1256,
+    1259, 1262, 1265, 1268, 1271, 1274, 1277, 1280, 1283, 1286, 1289, 1292,
+    1295, 1298, 1301, 1304, 1307, 1310, 1313, 1316, 1319, 1322, 1325, 1328,
+    1331, 1334, 1337, 1340, 1343, 1346, 1349, 1352, 1355, 1358, 1361, 1364,
+    1367, 1370, 1373, 1376, 1379, 1382, 1385, 1388, 1391, 1394, 1397, 1400,
+    1403, 1406, 1409, 1412, 1415, 1418, 1421, 1424, 1427, 1430, 1433, 1436,
+    1439, 1442, 1445, 1448, 1451, 1454, 1457, 1460, 1463, 1466, 1469, 1472,
+    1475, 1478, 1481, 1484, 1487, 1490, 1493, 1496, 1499, 1502, 1505, 1508,
+    1511, 1514, 1517, 1520, 1523, 1526, 1529, 1532, 1535, 1538, 1541, 1544,
+    1547, 1550, 1553, 1556, 1559, 1562, 1565, 1568, 1571, 1574, 1577, 1580,
+    1583, 1586, 1589, 1592, 1595, 1598, 1601, 1604, 1607, 1610, 1613, 1616,
+    1619, 1622, 1625, 1628, 1631, 1634, 1637, 1640, 1643, 1646, 1649, 1652,
+    1655, 1658, 1661, 1664, 1667, 1670, 1673, 1676, 1679, 1682, 1685, 1688,
+    1691, 1694, 1697, 1700, 1703, 1706, 1709, 1712, 1715, 1718, 1721, 1724,
+    1727, 1730, 1733, 1736, 1739, 1742, 1745, 1748, 1751, 1754, 1757, 1760,
+    1763, 1766, 1769, 1772, 1775, 1778, 1781, 1784, 1787, 1790, 1793, 1796,
+    1799, 1802, 1803, 1806, 1809, 1812, 1815, 1819, 1823, 1829, 1835, 1838,
+    1841, 1844, 1847, 1850, 1853, 1856, 1859, 1862, 1865, 1868, 1871, 1874,
+    1877, 1880, 1883, 1886, 1889, 1892, 1895, 1898, 1901, 1904, 1907, 1910,
+    1913, 1916, 1919, 1922, 1925, 1928, 1931, 1934, 1937, 1940, 1943, 1946,
+    1949, 1952, 1955, 1958, 1961, 1964, 1967, 1970, 1973, 1976, 1979, 1982,
+    1985, 1988, 1991, 1994, 1997, 2000, 2003, 2006, 2009, 2012, 2015, 2018,
+    2021, 2024, 2027, 2030, 2033, 2036, 2039, 2042, 2045, 2048, 2051, 2054,
+    2057, 2060, 2063, 2066, 2069, 2072, 2075, 2078, 2081, 2084, 2087, 2090,
+    2093, 2096, 2099, 2102, 2105, 2108, 2111, 2114, 2117, 2120, 2123, 2126,
+    2129, 2132, 2135, 2138, 2141, 2144, 2147, 2150, 2153, 2156, 2159, 2164,
+    2169, 2174, 2179, 2184, 2189, 2194, 2199, 2204, 2209, 2214, 2219, 2224,
+    2229, 2237, 2245, 2253, 2261, 2269, 2277, 2285, 2293, 2301, 2309, 2317,
+    2325, 2333, 2341, 2349, 2366, 2380, 2385, 2390, 2395, 2400, 2405, 2410,
+    2415, 2420, 2425, 2430, 2435, 2440, 2445, 2450, 2455, 2460, 2465, 2470,
+    2475, 2480, 2485, 2490, 2495, 2500, 2505, 2510, 2515, 2520, 2525, 2530,
+    2535, 2540, 2545, 2550, 2555, 2560, 2563, 2566, 2569, 2572, 2575, 2577,
+    2579, 2581, 2583, 2585, 2587, 2589, 2591, 2593, 2595, 2597, 2599, 2601,
+    2603, 2605, 2608, 2611, 2614, 2617, 2620, 2623, 2626, 2629, 2632, 2635,
+    2638, 2641, 2644, 2647, 2653, 2659, 2665, 2671, 2677, 2683, 2689, 2695,
+    2701, 2707, 2713, 2719, 2725, 2731, 2746, 2758, 2764, 2767, 2770, 2773,
+    2776, 2779, 2782, 2785, 2788, 2791, 2794, 2797, 2800, 2803, 2806, 2809,
+    2812, 2815, 2818, 2821, 2824, 2827, 2830, 2833, 2836, 2839, 2842, 2845,
+    2848, 2851, 2854, 2857, 2860, 2863, 2866, 2869, 2872, 2875, 2878, 2881,
+    2884, 2887, 2890, 2893, 2896, 2899, 2902, 2905, 2908, 2911, 2913, 2915,
+    2917, 2919, 2921, 2923, 2925, 2927, 2929, 2931, 2933, 2935, 2937, 2939,
+    2941, 2945, 2949, 2953, 2957, 2961, 2965, 2969, 2973, 2977, 2982, 2987,
+    2992, 2994, 2997, 2999, 3002, 3005, 3008, 3011, 3014, 3017, 3020, 3023,
+    3026, 3029, 3032, 3035, 3038, 3041, 3044, 3047, 3050, 3053, 3056, 3059,
+    3062, 3065, 3068, 3071, 3074, 3077, 3080, 3083, 3086, 3089, 3092, 3095,
+    3098, 3101, 3104, 3107, 3110, 3113, 3116, 3119, 3122, 3125, 3128, 3131,
+    3134, 3137, 3140, 3143, 3149, 3164, 3176, 3191, 3200, 3215, 3224, 3233,
+    3251, 3263, 3272, 3281, 3290, 3302, 3314, 3326, 3338, 3350, 3362, 3374,
+    3392, 3398, 3416, 3434, 3449, 3461, 3479, 3497, 3509, 3518, 3527, 3539,
+    3551, 3566, 3581, 3590, 3599, 3611, 3620, 3629, 3635, 3641, 3650, 3659,
+    3677, 3689, 3704, 3722, 3734, 3743, 3752, 3770, 3782, 3800, 3809, 3824,
+    3833, 3845, 3854, 3866, 3881, 3893, 3908, 3920, 3926, 3941, 3950, 3959,
+    3971, 3980, 3989, 3998, 4013, 4025, 4031, 4049, 4058, 4073, 4085, 4097,
+    4106, 4115, 4127, 4133, 4145, 4160, 4166, 4184, 4193, 4197, 4201, 4205,
+    4209, 4213, 4217, 4221, 4225, 4229, 4233, 4238, 4243, 4248, 4253, 4258,
+    4263, 4268, 4273, 4278, 4283, 4288, 4293, 4298, 4303, 4308, 4311, 4313,
+    4315, 4318, 4320, 4322, 4324, 4327, 4330, 4332, 4338, 4344, 4350, 4356,
+    4368, 4370, 4372, 4375, 4377, 4379, 4381, 4383, 4385, 4388, 4392, 4394,
+    4396, 4399, 4402, 4404, 4406, 4408, 4411, 4414, 4417, 4420, 4423, 4425,
+    4427, 4429, 4431, 4433, 4436, 4438, 4440, 4442, 4445, 4448, 4450, 4453,
+    4456, 4459, 4461, 4464, 4469, 4475, 4477, 4480, 4483, 4486, 4489, 4496,
+    4504, 4506, 4508, 4511, 4513, 4515, 4517, 4520, 4522, 4524, 4526, 4528,
+    4530, 4533, 4535, 4537, 4539, 4542, 4545, 4549, 4551, 4553, 4555, 4561,
+    4564, 4566, 4568, 4570, 4572, 4574, 4576, 4578, 4580, 4582, 4584, 4587,
+    4589, 4591, 4594, 4597, 4599, 4603, 4606, 4608, 4610, 4612, 4614, 4619,
+    4624, 4628, 4632, 4636, 4640, 4644, 4648, 4652, 4656, 4660, 4665, 4670,
+    4675, 4680, 4685, 4690, 4695, 4700, 4705, 4710, 4715, 4720, 4725, 4730,
+    4735, 4740, 4745, 4750, 4755, 4760, 4765, 4770, 4773, 4775, 4777, 4780,
+    4781, 4782, 4783, 4784, 4786, 4788, 4791, 4794, 4796, 4799, 4801, 4803,
+    4805, 4807, 4810, 4813, 4815, 4817, 4821, 4825, 4829, 4833, 4837, 4839,
+    4841, 4843, 4845, 4847, 4849, 4851, 4853, 4855, 4856, 4860, 4862, 4864,
+    4866, 4868, 4870, 4872, 4874, 4876, 4878, 4880, 4882, 4884, 4886, 4888,
+    4890, 4892, 4894, 4896, 4898, 4900, 4902, 4904, 4906, 4908, 4910, 4912,
+    4914, 4916, 4918, 4920, 4922, 4924, 4926, 4928, 4930, 4932, 4934, 4936,
+    4938, 4940, 4942, 4944, 4946, 4948, 4950, 4952, 4954, 4956, 4958, 4960,
+    4962, 4964, 4966, 4968, 4970, 4972, 4974, 4976, 4978, 4980, 4982, 4984,
+    4986, 4988, 4990, 4992, 4994, 4996, 4998, 5000, 5002, 5004, 5006, 5008,
+    5010, 5012, 5014, 5016, 5018, 5020, 5022, 5024, 5026, 5028, 5032, 5036,
+    5038, 5040, 5042, 5044, 5046, 5048, 5050, 5052, 5054, 5056, 5060, 5064,
+    5066, 5068, 5070, 5072, 5074, 5076, 5078, 5080, 5082, 5084, 5088, 5090,
+    5092, 5094, 5096, 5098, 5100, 5102, 5104, 5106, 5108, 5110, 5112, 5118,
+    5124, 5130, 5136, 5142, 5148, 5154, 5160, 5166, 5172, 5178, 5184, 5190,
+    5196, 5202, 5208, 5214, 5220, 5222, 5224, 5226, 5228, 5234, 5240, 5246,
+    5252, 5258, 5262, 5266, 5270, 5274, 5278, 5282, 5286, 5290, 5294, 5298,
+    5302, 5306, 5310, 5314, 5318, 5322, 5326, 5330, 5334, 5338, 5342, 5346,
+    5350, 5354, 5358, 5362, 5366, 5370, 5374, 5378, 5382, 5386, 5390, 5394,
+    5398, 5402, 5406, 5410, 5414, 5418, 5422, 5426, 5430, 5434, 5438, 5442,
+    5446, 5450, 5454, 5458, 5462, 5466, 5470, 5474, 5478, 5482, 5486, 5490,
+    5494, 5498, 5502, 5506, 5510, 5514, 5518, 5522, 5526, 5530, 5534, 5538,
+    5542, 5546, 5550, 5554, 5558, 5562, 5566, 5570, 5574, 5578, 5582, 5586,
+    5590, 5594, 5598, 5602, 5606, 5610, 5614, 5619, 5624, 5629, 5634, 5639,
+    5644, 5650, 5656, 5662, 5668, 5674, 5680, 5684, 5688, 5692, 5696, 5700,
+    5704, 5708, 5712, 5716, 5720, 5724, 5728, 5732, 5736, 5740, 5744, 5748,
+    5752, 5756, 5760, 5764, 5768, 5772, 5776, 5780, 5784, 5788, 5792, 5796,
+    5800, 5804, 5808, 5812, 5816, 5820, 5824, 5828, 5832, 5836, 5840, 5844,
+    5848, 5852, 5856, 5860, 5866, 5872, 5878, 5884, 5890, 5894, 5898, 5902,
+    5906, 5910, 5914, 5918, 5922, 5926, 5930, 5934, 5938, 5942, 5946, 5950,
+    5954, 5958, 5962, 5966, 5970, 5974, 5978, 5982, 5986, 5990, 5994, 5998,
+    6002, 6006, 6010, 6014, 6018, 6022, 6026, 6030, 6034, 6038, 6042, 6046,
+    6050, 6054, 6058, 6062, 6066, 6070, 6074, 6078, 6082, 6086, 6090, 6094,
+    6098, 6102, 6106, 6110, 6114, 6118, 6122, 6126, 6130, 6134, 6138, 6142,
+    6146, 6150, 6154, 6158, 6164, 6170, 6174, 6178, 6182, 6186, 6190, 6194,
+    6198, 6202, 6206, 6210, 6214, 6218, 6222, 6226, 6230, 6234, 6238, 6244,
+    6250, 6256, 6260, 6264, 6268, 6272, 6276, 6280, 6284, 6288, 6292, 6296,
+    6300, 6304, 6308, 6312, 6316, 6320, 6324, 6328, 6332, 6336, 6340, 6344,
+    6348, 6352, 6356, 6360, 6364, 6368, 6372, 6376, 6380, 6384, 6388, 6392,
+    6396, 6400, 6404, 6408, 6412, 6416, 6420, 6424, 6428, 6432, 6436, 6440,
+    6444, 6448, 6452, 6456, 6460, 6464, 6468, 6472, 6476, 6480, 6484, 6488,
+    6492, 6496, 6500, 6504, 6508, 6512, 6516, 6520, 6524, 6528, 6532, 6536,
+    6540, 6544, 6548, 6554, 6560, 6566, 6572, 6578, 6584, 6590, 6596, 6602,
+    6608, 6614, 6620, 6626, 6632, 6638, 6644, 6650, 6656, 6662, 6668, 6674,
+    6680, 6686, 6692, 6698, 6704, 6710, 6716, 6722, 6728, 6734, 6740, 6746,
+    6752, 6758, 6764, 6770, 6776, 6782, 6788, 6794, 6800, 6806, 6812, 6818,
+    6824, 6830, 6836, 6842, 6848, 6854, 6860, 6866, 6872, 6878, 6884, 6890,
+    6896, 6902, 6908, 6914, 6920, 6926, 6932, 6938, 6944, 6950, 6956, 6962,
+    6968, 6974, 6980, 6986, 6992, 6998, 7004, 7010, 7016, 7022, 7028, 7034,
+    7040, 7046, 7052, 7058, 7064, 7070, 7076, 7082, 7088, 7094, 7100, 7106,
+    7112, 7118, 7124, 7130, 7136, 7142, 7148, 7154, 7160, 7166, 7172, 7178,
+    7184, 7190, 7196, 7202, 7208, 7214, 7220, 7226, 7232, 7238, 7244, 7250,
+    7256, 7262, 7268, 7276, 7284, 7292, 7300, 7308, 7316, 7324, 7330, 7363,
+    7378, 7386, 7387, 7390, 7393, 7394, 7395, 7396, 7397, 7400, 7403, 7406,
+    7408, 7411, 7414, 7415, 7416, 7417, 7418, 7419, 7420, 7423, 7426, 7429,
+    7432, 7435, 7438, 7441, 7444, 7447, 7450, 7453, 7456, 7457, 7458, 7461,
+    7464, 7467, 7470, 7471, 7472, 7473, 7474, 7477, 7478, 7479, 7480, 7481,
+    7482, 7485, 7486, 7487, 7488, 7489, 7492, 7495, 7496, 7497, 7498, 7499,
+    7500, 7501, 7502, 7503, 7504, 7505, 7506, 7507, 7510, 7514, 7517, 7520,
+    7523, 7527, 7530, 7534, 7537, 7541, 7544, 7548, 7551, 7555, 7557, 7561,
+    7565, 7569, 7573, 7577, 7581, 7585, 7589, 7593, 7597, 7601, 7605, 7607,
+    7609, 7611, 7613, 7615, 7617, 7619, 7621, 7623, 7625, 7627, 7629, 7631,
+    7633, 7635, 7637, 7639, 7641, 7643, 7645, 7647, 7649, 7651, 7653, 7655,
+    7657, 7659, 7661, 7663, 7665, 7667, 7669, 7671, 7673, 7675, 7677, 7679,
+    7681, 7683, 7685, 7687, 7689, 7691, 7693, 7695, 7697, 7699, 7701, 7703,
+    7705, 7707, 7709, 7711, 7713, 7715, 7717, 7719, 7721, 7723, 7725, 7727,
+    7729, 7731, 7733, 7735, 7737, 7739, 7741, 7743, 7745, 7747, 7749, 7751,
+    7753, 7755, 7757, 7759, 7761, 7763, 7765, 7767, 7769, 7771, 7773, 7775,
+    7777, 7779, 7781, 7783, 7785, 7787, 7789, 7791, 7793, 7795, 7797, 7799,
+    7801, 7803, 7805, 7807, 7809, 7811, 7813, 7819, 7825, 7831, 7837, 7843,
+    7849, 7853, 7857, 7858, 7859, 7860, 7861, 7862, 7863, 7864, 7865, 7866,
+    7867, 7868, 7869, 7870, 7871, 7872, 7873, 7874, 7875, 7876, 7877, 7878,
+    7879, 7880, 7881, 7882, 7883, 7884, 7885, 7886, 7887, 7888, 7889, 7890,
+    7891, 7892, 7893, 7894, 7895, 7896, 7897, 7898, 7899, 7900, 7901, 7902,
+    7903, 7904, 7905, 7906, 7907, 7908, 7909, 7910, 7911, 7912, 7913, 7914,
+    7915, 7916, 7917, 7918, 7919, 7920, 7921, 7922, 7923, 7924, 7925, 7926,
+    7927, 7928, 7929, 7930, 7931, 7932, 7933, 7934, 7935, 7936, 7937, 7938,
+    7939, 7940, 7941, 7942, 7943, 7944, 7945, 7946, 7947, 7948, 7949, 7950,
+    7951, 7954, 7957, 7960, 7963, 7966, 7969, 7972, 7975, 7978, 7981, 7984,
+    7987, 7990, 7993, 7996, 7999, 8002, 8005, 8008, 8011, 8014, 8017, 8020,
+    8023, 8026, 8029, 8032, 8035, 8038, 8041, 8044, 8047, 8050, 8053, 8056,
+    8059, 8062, 8065, 8068, 8071, 8074, 8077, 8080, 8083, 8086, 8089, 8092,
+    8095, 8098, 8101, 8104, 8107, 8110, 8113, 8116, 8119, 8122, 8125, 8128,
+    8131, 8134, 8137, 8140, 8143, 8146, 8149, 8152, 8155, 8158, 8161, 8164,
+    8167, 8170, 8173, 8176, 8179, 8182, 8185, 8188, 8191, 8194, 8197, 8200,
+    8203, 8206, 8209, 8212, 8215, 8218, 8221, 8224, 8227, 8230, 8233, 8236,
+    8239, 8242, 8245, 8248, 8251, 8254, 8257, 8260, 8263, 8266, 8269, 8272,
+    8275, 8278, 8281, 8284, 8287, 8290, 8293, 8296, 8299, 8302, 8304, 8306,
+    8308, 8311, 8313, 8315, 8318, 8321, 8324, 8327, 8330, 8333, 8336, 8339,
+    8341, 8343, 8345, 8347, 8349, 8351, 8354, 8356, 8358, 8360, 8362, 8365,
+    8367, 8369, 8371, 8373, 8375, 8377, 8379, 8381, 8383, 8385, 8387, 8389,
+    8391, 8393, 8397, 8400, 8402, 8406, 8408, 8412, 8414, 8416, 8418, 8419,
+    8421, 8425, 8427, 8429, 8431, 8433, 8435, 8438, 8440, 8442, 8445, 8447,
+    8449, 8451, 8453, 8455, 8457, 8459, 8463, 8467, 8468, 8469, 8470, 8471,
+    8472, 8473, 8474, 8475, 8476, 8477, 8478, 8479, 8480, 8481, 8482, 8483,
+    8484, 8485, 8486, 8487, 8488, 8489, 8490, 8491, 8492, 8493, 8494, 8495,
+    8496, 8497, 8498, 8499, 8500, 8501, 8502, 8503, 8504, 8505, 8506, 8507,
+    8508, 8509, 8510, 8511, 8512, 8513, 8514, 8515, 8516, 8517, 8518, 8519,
+    8520, 8521, 8522, 8523, 8524, 8525, 8526, 8527, 8528, 8529, 8530, 8531,
+    8532, 8533, 8534, 8535, 8536, 8537, 8538, 8539, 8540, 8541, 8542, 8543,
+    8544, 8545, 8546, 8547, 8548, 8549, 8550, 8551, 8552, 8553, 8554, 8555,
+    8556, 8557, 8558, 8559, 8560, 8561, 8562, 8563, 8564, 8565, 8566, 8567,
+    8568, 8569, 8570, 8571, 8572, 8573, 8574, 8575, 8576, 8577, 8578, 8579,
+    8580, 8581, 8582, 8583, 8584, 8585, 8586, 8587, 8588, 8589, 8590, 8591,
+    8592, 8593, 8594, 8595, 8596, 8597, 8598, 8599, 8600, 8601, 8602, 8603,
+    8604, 8605, 8606, 8607, 8608, 8609, 8610, 8611, 8612, 8613, 8614, 8615,
+    8616, 8617, 8618, 8619, 8620, 8621, 8622, 8623, 8624, 8625, 8626, 8627,
+    8628, 8629, 8630, 8631, 8632, 8633, 8634, 8635, 8636, 8637, 8638, 8639,
+    8640, 8641, 8642, 8643, 8644, 8645, 8646, 8647, 8648, 8649, 8650, 8651,
+    8652, 8653, 8654, 8655, 8656, 8657, 8658, 8659, 8660, 8661, 8662, 8663,
+    8664, 8665, 8666, 8667, 8668, 8669, 8670, 8671, 8672, 8673, 8674, 8675,
+    8676, 8677, 8678, 8679, 8680, 8681, 8682, 8683, 8684, 8685, 8686, 8687,
+    8688, 8689, 8690, 8691, 8692, 8693, 8694, 8695, 8696, 8697, 8698, 8699,
+    8700, 8701, 8702, 8703, 8704, 8705, 8706, 8707, 8708, 8709, 8710, 8711,
+    8712, 8713, 8714, 8715, 8716, 8717, 8718, 8719, 8720, 8721, 8722, 8723,
+    8724, 8725, 8726, 8727, 8728, 8729, 8730, 8731, 8732, 8733, 8734, 8735,
+    8736, 8737, 8738, 8739, 8740, 8741, 8742, 8743, 8744, 8745, 8746, 8747,
+    8748, 8749, 8750, 8751, 8752, 8753, 8754, 8755, 8756, 8757, 8758, 8759,
+    8760, 8761, 8762, 8763, 8764, 8765, 8766, 8767, 8768, 8769, 8770, 8771,
+    8772, 8773, 8774, 8775, 8776, 8777, 8778, 8779, 8780, 8781, 8782, 8783,
+    8784, 8785, 8786, 8787, 8788, 8789, 8790, 8791, 8792, 8793, 8794, 8795,
+    8796, 8797, 8798, 8799, 8800, 8801, 8802, 8803, 8804, 8805, 8806, 8807,
+    8808, 8809, 8810, 8811, 8812, 8813, 8814, 8815, 8816, 8817, 8818, 8819,
+    8820, 8821, 8822, 8823, 8824, 8825, 8826, 8827, 8828, 8829, 8830, 8831,
+    8832, 8833, 8834, 8835, 8836, 8837, 8838, 8839, 8840, 8841, 8842, 8843,
+    8844, 8845, 8846, 8847, 8848, 8849, 8850, 8851, 8852, 8853, 8854, 8855,
+    8856, 8857, 8858, 8859, 8860, 8861, 8862, 8863, 8864, 8865, 8866, 8867,
+    8868, 8869, 8870, 8871, 8872, 8873, 8874, 8875, 8876, 8877, 8878, 8879,
+    8880, 8881, 8882, 8883, 8884, 8885, 8886, 8887, 8888, 8889, 8890, 8891,
+    8892, 8893, 8894, 8895, 8896, 8897, 8898, 8899, 8900, 8901, 8902, 8903,
+    8904, 8905, 8906, 8907, 8908, 8909, 8910, 8911, 8912, 8913, 8914, 8915,
+    8916, 8917, 8918, 8919, 8920, 8921, 8922, 8923, 8924, 8925, 8926, 8927,
+    8928, 8929, 8930, 8931, 8932, 8933, 8934, 8935, 8936, 8937, 8938, 8939,
+    8940, 8941, 8942, 8943, 8944, 8945, 8946, 8947, 8948, 8949, 8950, 8951,
+    8952, 8953, 8954, 8955, 8956, 8957, 8958, 8959, 8960, 8961, 8962, 8963,
+    8964, 8965, 8966, 8967, 8968, 8969, 8970, 8971, 8972, 8973, 8974, 8975,
+    8976, 8977, 8978, 8979, 8980, 8981, 8982, 8983, 8984, 8985, 8986, 8987,
+    8988, 8989, 8990, 8991, 8992, 8993, 8994, 8995, 8996, 8997, 8998, 8999,
+    9000, 9001, 9002, 9003, 9004, 9005, 9006, 9007, 9008, 9009, 9010, 9011,
+    9012, 9013, 9014, 9015, 9016, 9017, 9018, 9019, 9020, 9021, 9022, 9023,
+    9024, 9025, 9026, 9027, 9028, 9029, 9030, 9031, 9032, 9033, 9034, 9035,
+    9036, 9037, 9038, 9039, 9040, 9041, 9042, 9043, 9044, 9045, 9046, 9047,
+    9048, 9049, 9050, 9051, 9052, 9053, 9054, 9055, 9056, 9057, 9058, 9059,
+    9060, 9061, 9062, 9063, 9064, 9065, 9066, 9067, 9068, 9069, 9070, 9071,
+    9072, 9073, 9074, 9075, 9076, 9077, 9078, 9079, 9080, 9081, 9082, 9083,
+    9084, 9085, 9086, 9087, 9088, 9089, 9090, 9091, 9092, 9093, 9094, 9095,
+    9096, 9097, 9098, 9099, 9100, 9101, 9102, 9103, 9104, 9105, 9106, 9107,
+    9108, 9109, 9110, 9111, 9112, 9113, 9114, 9115, 9116, 9117, 9118, 9119,
+    9120, 9121, 9122, 9123, 9124, 9125, 9126, 9127, 9128, 9129, 9130, 9131,
+    9132, 9133, 9134, 9135, 9136, 9137, 9138, 9139, 9140, 9141, 9142, 9143,
+    9144, 9145, 9146, 9147, 9148, 9149, 9150, 9151, 9152, 9153, 9154, 9155,
+    9157, 9159, 9161, 9163, 9165, 9167, 9169, 9171, 9173, 9175, 9177, 9179,
+    9181, 9183, 9185, 9187, 9189, 9191, 9193, 9195, 9197, 9199, 9201, 9203,
+    9205, 9207, 9209, 9212, 9214, 9216, 9218, 9220, 9222, 9224, 9226, 9228,
+    9230, 9232, 9234, 9236, 9238, 9240, 9242, 9244, 9246, 9248, 9250, 9252,
+    9254, 9256, 9258, 9260, 9262, 9265, 9267, 9269, 9271, 9273, 9275, 9277,
+    9279, 9281, 9283, 9285, 9287, 9289, 9291, 9293, 9295, 9297, 9299, 9301,
+    9303, 9305, 9307, 9309, 9311, 9313, 9315, 9317, 9319, 9321, 9323, 9325,
+    9327, 9330, 9332, 9334, 9336, 9338, 9340, 9342, 9344, 9346, 9348, 9350,
+    9352, 9354, 9356, 9358, 9360, 9362, 9364, 9366, 9368, 9370, 9372, 9374,
+    9376, 9378, 9380, 9383, 9385, 9387, 9389, 9391, 9393, 9395, 9397, 9399,
+    9401, 9403, 9405, 9407, 9409, 9411, 9413, 9415, 9417, 9419, 9421, 9423,
+    9425, 9427, 9429, 9431, 9433, 9435, 9437, 9439, 9441, 9443, 9445, 9448,
+    9450, 9452, 9454, 9456, 9458, 9460, 9462, 9464, 9466, 9468, 9470, 9472,
+    9474, 9476, 9478, 9480, 9482, 9484, 9486, 9488, 9490, 9492, 9494, 9496,
+    9498, 9501, 9503, 9505, 9507, 9509, 9511, 9513, 9515, 9517, 9519, 9521,
+    9523, 9525, 9527, 9529, 9531, 9533, 9535, 9537, 9539, 9541, 9543, 9545,
+    9547, 9549, 9551, 9553, 9555, 9557, 9559, 9561, 9563, 9566, 9568, 9570,
+    9572, 9574, 9576, 9578, 9580, 9582, 9584, 9586, 9588, 9590, 9592, 9594,
+    9596, 9598, 9600, 9602, 9604, 9606, 9608, 9610, 9612, 9614, 9616, 9619,
+    9621, 9623, 9625, 9627, 9629, 9631, 9633, 9635, 9637, 9639, 9641, 9643,
+    9645, 9647, 9649, 9651, 9653, 9655, 9657, 9659, 9661, 9663, 9665, 9667,
+    9669, 9671, 9673, 9675, 9677, 9679, 9681, 9684, 9686, 9688, 9690, 9692,
+    9694, 9696, 9698, 9700, 9702, 9704, 9706, 9708, 9710, 9712, 9714, 9716,
+    9718, 9720, 9722, 9724, 9726, 9728, 9730, 9732, 9734, 9737, 9739, 9741,
+    9743, 9745, 9747, 9749, 9751, 9753, 9754, 9755, 9756, 9757, 9758, 9759,
+    9760, 9761, 9762, 9763, 9764, 9765, 9766, 9767, 9768, 9769, 9770, 9771,
+    9772, 9773, 9774, 9775, 9776, 9777, 9778, 9779, 9780, 9781, 9782, 9783,
+    9784, 9785, 9786, 9787, 9788, 9789, 9790, 9791, 9792, 9793, 9794, 9795,
+    9796, 9797, 9798, 9799, 9800, 9801, 9802, 9803, 9805, 9807, 9809, 9811,
+    9813, 9815, 9817, 9819, 9821, 9823, 9825, 9827, 9829, 9831, 9833, 9835,
+    9837, 9839, 9841, 9843, 9845, 9847, 9849, 9851, 9853, 9855, 9858, 9860,
+    9862, 9864, 9866, 9868, 9870, 9872, 9874, 9876, 9878, 9880, 9882, 9884,
+    9886, 9888, 9890, 9892, 9894, 9896, 9898, 9900, 9902, 9904, 9906, 9908,
+    9910, 9912, 9914, 9916, 9918, 9920, 9922, 9924, 9927, 9929, 9931, 9933,
+    9935, 9937, 9939, 9941, 9943, 9945, 9947, 9949, 9951, 9953, 9955, 9957,
+    9959, 9961, 9963, 9965, 9967, 9969, 9971, 9973, 9975, 9977, 9979, 9981,
+    9983, 9985, 9987, 9989, 9991, 9993, 9995, 9997, 9999, 10001, 10003, 10005,
+    10007, 10009, 10011, 10013, 10015, 10017, 10019, 10021, 10023, 10025, 10027, 10029,
+    10031, 10033, 10035, 10037, 10039, 10041, 10043, 10045, 10047, 10049, 10051, 10053,
+    10055, 10057, 10059, 10061, 10063, 10065, 10067, 10069, 10071, 10073, 10075, 10077,
+    10079, 10081, 10083, 10085, 10087, 10089, 10091, 10093, 10095, 10097, 10099, 10101,
+    10103, 10105, 10107, 10109, 10111, 10113, 10115, 10117, 10119, 10121, 10123, 10125,
+    10127, 10129, 10131, 10133, 10135, 10137, 10139, 10141, 10143, 10145, 10147, 10149,
+    10151, 10153, 10155, 10157, 10159, 10161, 10163, 10165, 10167, 10169, 10171, 10173,
+    10175, 10177, 10179, 10181, 10183, 10185, 10187, 10189, 10191, 10193, 10195, 10197,
+    10199, 10201, 10203, 10205, 10207, 10209, 10211, 10213, 10215, 10217, 10219, 10221,
+    10223, 10225, 10227, 10229, 10231, 10233, 10236, 10239, 10242, 10245, 10248, 10251,
+    10254, 10257, 10260, 10263, 10266, 10269, 10272, 10275, 10278, 10281, 10284, 10287,
+    10290, 10293, 10296, 10299, 10302, 10305, 10308, 10311, 10318, 10319, 10320, 10322,
+    10324, 10325, 10326, 10327, 10328, 10329, 10330, 10331, 10332, 10333, 10334, 10335,
+    10336, 10337, 10338, 10339, 10340, 10341, 10342, 10343, 10344, 10345, 10346, 10347,
+    10348, 10349, 10350, 10352, 10354, 10356, 10358, 10361, 10363, 10365, 10367, 10369,
+    10371, 10377, 10383, 10386, 10389, 10392, 10395, 10401, 10404, 10407, 10410, 10413,
+    10416, 10419, 10422, 10425, 10428, 10431, 10434, 10437, 10440, 10443, 10446, 10449,
+    10452, 10455, 10458, 10461, 10464, 10467, 10470, 10473, 10476, 10479, 10482, 10485,
+    10488, 10491, 10494, 10497, 10500, 10503, 10506, 10509, 10512, 10515, 10518, 10521,
+    10530, 10539, 10548, 10557, 10566, 10575, 10584, 10593, 10602, 10605, 10608, 10609,
+    10610, 10611, 10612, 10613, 10614, 10615, 10616, 10617, 10618,
+];
+pub(crate) static COMPATIBILITY_DECOMPOSITION_STRINGS: &str = "\
+    \u{20}\u{20}\u{308}\u{61}\u{20}\u{304}\u{32}\u{33}\u{20}\u{301}\u{3BC}\u{20}\
+    \u{327}\u{31}\u{6F}\u{31}\u{2044}\u{34}\u{31}\u{2044}\u{32}\u{33}\u{2044}\u{34}\
+    \u{49}\u{4A}\u{69}\u{6A}\u{4C}\u{B7}\u{6C}\u{B7}\u{2BC}\u{6E}\u{73}\u{44}\
+    \u{5A}\u{30C}\u{44}\u{7A}\u{30C}\u{64}\u{7A}\u{30C}\u{4C}\u{4A}\u{4C}\u{6A}\
+    \u{6C}\u{6A}\u{4E}\u{4A}\u{4E}\u{6A}\u{6E}\u{6A}\u{44}\u{5A}\u{44}\u{7A}\
+    \u{64}\u{7A}\u{68}\u{266}\u{6A}\u{72}\u{279}\u{27B}\u{281}\u{77}\u{79}\u{20}\
+    \u{306}\u{20}\u{307}\u{20}\u{30A}\u{20}\u{328}\u{20}\u{303}\u{20}\u{30B}\u{263}\
+    \u{6C}\u{73}\u{78}\u{295}\u{20}\u{345}\u{20}\u{301}\u{20}\u{308}\u{301}\u{3B2}\
+    \u{3B8}\u{3A5}\u{3A5}\u{301}\u{3A5}\u{308}\u{3C6}\u{3C0}\u{3BA}\u{3C1}\u{3C2}\u{398}\
+    \u{3B5}\u{3A3}\u{565}\u{582}\u{627}\u{674}\u{648}\u{674}\u{6C7}\u{674}\u{64A}\u{674}\
+    \u{E4D}\u{E32}\u{ECD}\u{EB2}\u{EAB}\u{E99}\u{EAB}\u{EA1}\u{F0B}\u{FB2}\u{F71}\u{F80}\
+    \u{FB3}\u{F71}\u{F80}\u{10DC}\u{41}\u{C6}\u{42}\u{44}\u{45}\u{18E}\u{47}\u{48}\
+    \u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{222}\u{50}\u{52}\u{54}\u{55}\
+    \u{57}\u{61}\u{250}\u{251}\u{1D02}\u{62}\u{64}\u{65}\u{259}\u{25B}\u{25C}\u{67}\
+    \u{6B}\u{6D}\u{14B}\u{6F}\u{254}\u{1D16}\u{1D17}\u{70}\u{74}\u{75}\u{1D1D}\u{26F}\
+    \u{76}\u{1D25}\u{3B2}\u{3B3}\u{3B4}\u{3C6}\u{3C7}\u{69}\u{72}\u{75}\u{76}\u{3B2}\
+    \u{3B3}\u{3C1}\u{3C6}\u{3C7}\u{43D}\u{252}\u{63}\u{255}\u{F0}\u{25C}\u{66}\u{25F}\
+    \u{261}\u{265}\u{268}\u{269}\u{26A}\u{1D7B}\u{29D}\u{26D}\u{1D85}\u{29F}\u{271}\u{270}\
+    \u{272}\u{273}\u{274}\u{275}\u{278}\u{282}\u{283}\u{1AB}\u{289}\u{28A}\u{1D1C}\u{28B}\
+    \u{28C}\u{7A}\u{290}\u{291}\u{292}\u{3B8}\u{61}\u{2BE}\u{73}\u{307}\u{20}\u{313}\
+    \u{20}\u{313}\u{20}\u{342}\u{20}\u{308}\u{342}\u{20}\u{313}\u{300}\u{20}\u{313}\
+    \u{301}\u{20}\u{313}\u{342}\u{20}\u{314}\u{300}\u{20}\u{314}\u{301}\u{20}\u{314}\
+    \u{342}\u{20}\u{308}\u{300}\u{20}\u{308}\u{301}\u{20}\u{301}\u{20}\u{314}\u{20}\
+    \u{20}\u{20}\u{20}\u{20}\u{20}\u{20}\u{20}\u{20}\u{20}\u{20}\u{2010}\u{20}\
+    \u{333}\u{2E}\u{2E}\u{2E}\u{2E}\u{2E}\u{2E}\u{20}\u{2032}\u{2032}\u{2032}\u{2032}\
+    \u{2032}\u{2035}\u{2035}\u{2035}\u{2035}\u{2035}\u{21}\u{21}\u{20}\u{305}\u{3F}\u{3F}\
+    \u{3F}\u{21}\u{21}\u{3F}\u{2032}\u{2032}\u{2032}\u{2032}\u{20}\u{30}\u{69}\u{34}\
+    \u{35}\u{36}\u{37}\u{38}\u{39}\u{2B}\u{2212}\u{3D}\u{28}\u{29}\u{6E}\u{30}\
+    \u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\u{2B}\u{2212}\u{3D}\
+    \u{28}\u{29}\u{61}\u{65}\u{6F}\u{78}\u{259}\u{68}\u{6B}\u{6C}\u{6D}\u{6E}\
+    \u{70}\u{73}\u{74}\u{52}\u{73}\u{61}\u{2F}\u{63}\u{61}\u{2F}\u{73}\u{43}\
+    \u{B0}\u{43}\u{63}\u{2F}\u{6F}\u{63}\u{2F}\u{75}\u{190}\u{B0}\u{46}\u{67}\
+    \u{48}\u{48}\u{48}\u{68}\u{127}\u{49}\u{49}\u{4C}\u{6C}\u{4E}\u{4E}\u{6F}\
+    \u{50}\u{51}\u{52}\u{52}\u{52}\u{53}\u{4D}\u{54}\u{45}\u{4C}\u{54}\u{4D}\
+    \u{5A}\u{5A}\u{42}\u{43}\u{65}\u{45}\u{46}\u{4D}\u{6F}\u{5D0}\u{5D1}\u{5D2}\
+    \u{5D3}\u{69}\u{46}\u{41}\u{58}\u{3C0}\u{3B3}\u{393}\u{3A0}\u{2211}\u{44}\u{64}\
+    \u{65}\u{69}\u{6A}\u{31}\u{2044}\u{37}\u{31}\u{2044}\u{39}\u{31}\u{2044}\u{31}\
+    \u{30}\u{31}\u{2044}\u{33}\u{32}\u{2044}\u{33}\u{31}\u{2044}\u{35}\u{32}\u{2044}\
+    \u{35}\u{33}\u{2044}\u{35}\u{34}\u{2044}\u{35}\u{31}\u{2044}\u{36}\u{35}\u{2044}\
+    \u{36}\u{31}\u{2044}\u{38}\u{33}\u{2044}\u{38}\u{35}\u{2044}\u{38}\u{37}\u{2044}\
+    \u{38}\u{31}\u{2044}\u{49}\u{49}\u{49}\u{49}\u{49}\u{49}\u{49}\u{56}\u{56}\
+    \u{56}\u{49}\u{56}\u{49}\u{49}\u{56}\u{49}\u{49}\u{49}\u{49}\u{58}\u{58}\
+    \u{58}\u{49}\u{58}\u{49}\u{49}\u{4C}\u{43}\u{44}\u{4D}\u{69}\u{69}\u{69}\
+    \u{69}\u{69}\u{69}\u{69}\u{76}\u{76}\u{76}\u{69}\u{76}\u{69}\u{69}\u{76}\
+    \u{69}\u{69}\u{69}\u{69}\u{78}\u{78}\u{78}\u{69}\u{78}\u{69}\u{69}\u{6C}\
+    \u{63}\u{64}\u{6D}\u{30}\u{2044}\u{33}\u{222B}\u{222B}\u{222B}\u{222B}\u{222B}\u{222E}\
+    \u{222E}\u{222E}\u{222E}\u{222E}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\
+    \u{39}\u{31}\u{30}\u{31}\u{31}\u{31}\u{32}\u{31}\u{33}\u{31}\u{34}\u{31}\
+    \u{35}\u{31}\u{36}\u{31}\u{37}\u{31}\u{38}\u{31}\u{39}\u{32}\u{30}\u{28}\
+    \u{31}\u{29}\u{28}\u{32}\u{29}\u{28}\u{33}\u{29}\u{28}\u{34}\u{29}\u{28}\
+    \u{35}\u{29}\u{28}\u{36}\u{29}\u{28}\u{37}\u{29}\u{28}\u{38}\u{29}\u{28}\
+    \u{39}\u{29}\u{28}\u{31}\u{30}\u{29}\u{28}\u{31}\u{31}\u{29}\u{28}\u{31}\
+    \u{32}\u{29}\u{28}\u{31}\u{33}\u{29}\u{28}\u{31}\u{34}\u{29}\u{28}\u{31}\
+    \u{35}\u{29}\u{28}\u{31}\u{36}\u{29}\u{28}\u{31}\u{37}\u{29}\u{28}\u{31}\
+    \u{38}\u{29}\u{28}\u{31}\u{39}\u{29}\u{28}\u{32}\u{30}\u{29}\u{31}\u{2E}\
+    \u{32}\u{2E}\u{33}\u{2E}\u{34}\u{2E}\u{35}\u{2E}\u{36}\u{2E}\u{37}\u{2E}\
+    \u{38}\u{2E}\u{39}\u{2E}\u{31}\u{30}\u{2E}\u{31}\u{31}\u{2E}\u{31}\u{32}\
+    \u{2E}\u{31}\u{33}\u{2E}\u{31}\u{34}\u{2E}\u{31}\u{35}\u{2E}\u{31}\u{36}\
+    \u{2E}\u{31}\u{37}\u{2E}\u{31}\u{38}\u{2E}\u{31}\u{39}\u{2E}\u{32}\u{30}\
+    \u{2E}\u{28}\u{61}\u{29}\u{28}\u{62}\u{29}\u{28}\u{63}\u{29}\u{28}\u{64}\
+    \u{29}\u{28}\u{65}\u{29}\u{28}\u{66}\u{29}\u{28}\u{67}\u{29}\u{28}\u{68}\
+    \u{29}\u{28}\u{69}\u{29}\u{28}\u{6A}\u{29}\u{28}\u{6B}\u{29}\u{28}\u{6C}\
+    \u{29}\u{28}\u{6D}\u{29}\u{28}\u{6E}\u{29}\u{28}\u{6F}\u{29}\u{28}\u{70}\
+    \u{29}\u{28}\u{71}\u{29}\u{28}\u{72}\u{29}\u{28}\u{73}\u{29}\u{28}\u{74}\
+    \u{29}\u{28}\u{75}\u{29}\u{28}\u{76}\u{29}\u{28}\u{77}\u{29}\u{28}\u{78}\
+    \u{29}\u{28}\u{79}\u{29}\u{28}\u{7A}\u{29}\u{41}\u{42}\u{43}\u{44}\u{45}\
+    \u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\u{51}\
+    \u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\u{61}\u{62}\u{63}\
+    \u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\
+    \u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{30}\
+    \u{222B}\u{222B}\u{222B}\u{222B}\u{3A}\u{3A}\u{3D}\u{3D}\u{3D}\u{3D}\u{3D}\u{3D}\
+    \u{6A}\u{56}\u{2D61}\u{6BCD}\u{9F9F}\u{4E00}\u{4E28}\u{4E36}\u{4E3F}\u{4E59}\u{4E85}\u{4E8C}\
+    \u{4EA0}\u{4EBA}\u{513F}\u{5165}\u{516B}\u{5182}\u{5196}\u{51AB}\u{51E0}\u{51F5}\u{5200}\u{529B}\
+    \u{52F9}\u{5315}\u{531A}\u{5338}\u{5341}\u{535C}\u{5369}\u{5382}\u{53B6}\u{53C8}\u{53E3}\u{56D7}\
+    \u{571F}\u{58EB}\u{5902}\u{590A}\u{5915}\u{5927}\u{5973}\u{5B50}\u{5B80}\u{5BF8}\u{5C0F}\u{5C22}\
+    \u{5C38}\u{5C6E}\u{5C71}\u{5DDB}\u{5DE5}\u{5DF1}\u{5DFE}\u{5E72}\u{5E7A}\u{5E7F}\u{5EF4}\u{5EFE}\
+    \u{5F0B}\u{5F13}\u{5F50}\u{5F61}\u{5F73}\u{5FC3}\u{6208}\u{6236}\u{624B}\u{652F}\u{6534}\u{6587}\
+    \u{6597}\u{65A4}\u{65B9}\u{65E0}\u{65E5}\u{66F0}\u{6708}\u{6728}\u{6B20}\u{6B62}\u{6B79}\u{6BB3}\
+    \u{6BCB}\u{6BD4}\u{6BDB}\u{6C0F}\u{6C14}\u{6C34}\u{706B}\u{722A}\u{7236}\u{723B}\u{723F}\u{7247}\
+    \u{7259}\u{725B}\u{72AC}\u{7384}\u{7389}\u{74DC}\u{74E6}\u{7518}\u{751F}\u{7528}\u{7530}\u{758B}\
+    \u{7592}\u{7676}\u{767D}\u{76AE}\u{76BF}\u{76EE}\u{77DB}\u{77E2}\u{77F3}\u{793A}\u{79B8}\u{79BE}\
+    \u{7A74}\u{7ACB}\u{7AF9}\u{7C73}\u{7CF8}\u{7F36}\u{7F51}\u{7F8A}\u{7FBD}\u{8001}\u{800C}\u{8012}\
+    \u{8033}\u{807F}\u{8089}\u{81E3}\u{81EA}\u{81F3}\u{81FC}\u{820C}\u{821B}\u{821F}\u{826E}\u{8272}\
+    \u{8278}\u{864D}\u{866B}\u{8840}\u{884C}\u{8863}\u{897E}\u{898B}\u{89D2}\u{8A00}\u{8C37}\u{8C46}\
+    \u{8C55}\u{8C78}\u{8C9D}\u{8D64}\u{8D70}\u{8DB3}\u{8EAB}\u{8ECA}\u{8F9B}\u{8FB0}\u{8FB5}\u{9091}\
+    \u{9149}\u{91C6}\u{91CC}\u{91D1}\u{9577}\u{9580}\u{961C}\u{96B6}\u{96B9}\u{96E8}\u{9751}\u{975E}\
+    \u{9762}\u{9769}\u{97CB}\u{97ED}\u{97F3}\u{9801}\u{98A8}\u{98DB}\u{98DF}\u{9996}\u{9999}\u{99AC}\
+    \u{9AA8}\u{9AD8}\u{9ADF}\u{9B25}\u{9B2F}\u{9B32}\u{9B3C}\u{9B5A}\u{9CE5}\u{9E75}\u{9E7F}\u{9EA5}\
+    \u{9EBB}\u{9EC3}\u{9ECD}\u{9ED1}\u{9EF9}\u{9EFD}\u{9F0E}\u{9F13}\u{9F20}\u{9F3B}\u{9F4A}\u{9F52}\
+    \u{9F8D}\u{9F9C}\u{9FA0}\u{20}\u{3012}\u{5341}\u{5344}\u{5345}\u{20}\u{3099}\u{20}\u{309A}\
+    \u{3088}\u{308A}\u{30B3}\u{30C8}\u{1100}\u{1101}\u{11AA}\u{1102}\u{11AC}\u{11AD}\u{1103}\u{1104}\
+    \u{1105}\u{11B0}\u{11B1}\u{11B2}\u{11B3}\u{11B4}\u{11B5}\u{111A}\u{1106}\u{1107}\u{1108}\u{1121}\
+    \u{1109}\u{110A}\u{110B}\u{110C}\u{110D}\u{110E}\u{110F}\u{1110}\u{1111}\u{1112}\u{1161}\u{1162}\
+    \u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116A}\u{116B}\u{116C}\u{116D}\u{116E}\
+    \u{116F}\u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}\u{1160}\u{1114}\u{1115}\u{11C7}\u{11C8}\
+    \u{11CC}\u{11CE}\u{11D3}\u{11D7}\u{11D9}\u{111C}\u{11DD}\u{11DF}\u{111D}\u{111E}\u{1120}\u{1122}\
+    \u{1123}\u{1127}\u{1129}\u{112B}\u{112C}\u{112D}\u{112E}\u{112F}\u{1132}\u{1136}\u{1140}\u{1147}\
+    \u{114C}\u{11F1}\u{11F2}\u{1157}\u{1158}\u{1159}\u{1184}\u{1185}\u{1188}\u{1191}\u{1192}\u{1194}\
+    \u{119E}\u{11A1}\u{4E00}\u{4E8C}\u{4E09}\u{56DB}\u{4E0A}\u{4E2D}\u{4E0B}\u{7532}\u{4E59}\u{4E19}\
+    \u{4E01}\u{5929}\u{5730}\u{4EBA}\u{28}\u{1100}\u{29}\u{28}\u{1102}\u{29}\u{28}\u{1103}\
+    \u{29}\u{28}\u{1105}\u{29}\u{28}\u{1106}\u{29}\u{28}\u{1107}\u{29}\u{28}\u{1109}\
+    \u{29}\u{28}\u{110B}\u{29}\u{28}\u{110C}\u{29}\u{28}\u{110E}\u{29}\u{28}\u{110F}\
+    \u{29}\u{28}\u{1110}\u{29}\u{28}\u{1111}\u{29}\u{28}\u{1112}\u{29}\u{28}\u{1100}\
+    \u{1161}\u{29}\u{28}\u{1102}\u{1161}\u{29}\u{28}\u{1103}\u{1161}\u{29}\u{28}\u{1105}\
+    \u{1161}\u{29}\u{28}\u{1106}\u{1161}\u{29}\u{28}\u{1107}\u{1161}\u{29}\u{28}\u{1109}\
+    \u{1161}\u{29}\u{28}\u{110B}\u{1161}\u{29}\u{28}\u{110C}\u{1161}\u{29}\u{28}\u{110E}\
+    \u{1161}\u{29}\u{28}\u{110F}\u{1161}\u{29}\u{28}\u{1110}\u{1161}\u{29}\u{28}\u{1111}\
+    \u{1161}\u{29}\u{28}\u{1112}\u{1161}\u{29}\u{28}\u{110C}\u{116E}\u{29}\u{28}\u{110B}\
+    \u{1169}\u{110C}\u{1165}\u{11AB}\u{29}\u{28}\u{110B}\u{1169}\u{1112}\u{116E}\u{29}\u{28}\
+    \u{4E00}\u{29}\u{28}\u{4E8C}\u{29}\u{28}\u{4E09}\u{29}\u{28}\u{56DB}\u{29}\u{28}\
+    \u{4E94}\u{29}\u{28}\u{516D}\u{29}\u{28}\u{4E03}\u{29}\u{28}\u{516B}\u{29}\u{28}\
+    \u{4E5D}\u{29}\u{28}\u{5341}\u{29}\u{28}\u{6708}\u{29}\u{28}\u{706B}\u{29}\u{28}\
+    \u{6C34}\u{29}\u{28}\u{6728}\u{29}\u{28}\u{91D1}\u{29}\u{28}\u{571F}\u{29}\u{28}\
+    \u{65E5}\u{29}\u{28}\u{682A}\u{29}\u{28}\u{6709}\u{29}\u{28}\u{793E}\u{29}\u{28}\
+    \u{540D}\u{29}\u{28}\u{7279}\u{29}\u{28}\u{8CA1}\u{29}\u{28}\u{795D}\u{29}\u{28}\
+    \u{52B4}\u{29}\u{28}\u{4EE3}\u{29}\u{28}\u{547C}\u{29}\u{28}\u{5B66}\u{29}\u{28}\
+    \u{76E3}\u{29}\u{28}\u{4F01}\u{29}\u{28}\u{8CC7}\u{29}\u{28}\u{5354}\u{29}\u{28}\
+    \u{796D}\u{29}\u{28}\u{4F11}\u{29}\u{28}\u{81EA}\u{29}\u{28}\u{81F3}\u{29}\u{554F}\
+    \u{5E7C}\u{6587}\u{7B8F}\u{50}\u{54}\u{45}\u{32}\u{31}\u{32}\u{32}\u{32}\u{33}\
+    \u{32}\u{34}\u{32}\u{35}\u{32}\u{36}\u{32}\u{37}\u{32}\u{38}\u{32}\u{39}\
+    \u{33}\u{30}\u{33}\u{31}\u{33}\u{32}\u{33}\u{33}\u{33}\u{34}\u{33}\u{35}\
+    \u{1100}\u{1102}\u{1103}\u{1105}\u{1106}\u{1107}\u{1109}\u{110B}\u{110C}\u{110E}\u{110F}\u{1110}\
+    \u{1111}\u{1112}\u{1100}\u{1161}\u{1102}\u{1161}\u{1103}\u{1161}\u{1105}\u{1161}\u{1106}\u{1161}\
+    \u{1107}\u{1161}\u{1109}\u{1161}\u{110B}\u{1161}\u{110C}\u{1161}\u{110E}\u{1161}\u{110F}\u{1161}\
+    \u{1110}\u{1161}\u{1111}\u{1161}\u{1112}\u{1161}\u{110E}\u{1161}\u{11B7}\u{1100}\u{1169}\u{110C}\
+    \u{116E}\u{110B}\u{1174}\u{110B}\u{116E}\u{4E00}\u{4E8C}\u{4E09}\u{56DB}\u{4E94}\u{516D}\u{4E03}\
+    \u{516B}\u{4E5D}\u{5341}\u{6708}\u{706B}\u{6C34}\u{6728}\u{91D1}\u{571F}\u{65E5}\u{682A}\u{6709}\
+    \u{793E}\u{540D}\u{7279}\u{8CA1}\u{795D}\u{52B4}\u{79D8}\u{7537}\u{5973}\u{9069}\u{512A}\u{5370}\
+    \u{6CE8}\u{9805}\u{4F11}\u{5199}\u{6B63}\u{4E0A}\u{4E2D}\u{4E0B}\u{5DE6}\u{53F3}\u{533B}\u{5B97}\
+    \u{5B66}\u{76E3}\u{4F01}\u{8CC7}\u{5354}\u{591C}\u{33}\u{36}\u{33}\u{37}\u{33}\u{38}\
+    \u{33}\u{39}\u{34}\u{30}\u{34}\u{31}\u{34}\u{32}\u{34}\u{33}\u{34}\u{34}\
+    \u{34}\u{35}\u{34}\u{36}\u{34}\u{37}\u{34}\u{38}\u{34}\u{39}\u{35}\u{30}\
+    \u{31}\u{6708}\u{32}\u{6708}\u{33}\u{6708}\u{34}\u{6708}\u{35}\u{6708}\u{36}\u{6708}\
+    \u{37}\u{6708}\u{38}\u{6708}\u{39}\u{6708}\u{31}\u{30}\u{6708}\u{31}\u{31}\u{6708}\
+    \u{31}\u{32}\u{6708}\u{48}\u{67}\u{65}\u{72}\u{67}\u{65}\u{56}\u{4C}\u{54}\
+    \u{44}\u{30A2}\u{30A4}\u{30A6}\u{30A8}\u{30AA}\u{30AB}\u{30AD}\u{30AF}\u{30B1}\u{30B3}\u{30B5}\
+    \u{30B7}\u{30B9}\u{30BB}\u{30BD}\u{30BF}\u{30C1}\u{30C4}\u{30C6}\u{30C8}\u{30CA}\u{30CB}\u{30CC}\
+    \u{30CD}\u{30CE}\u{30CF}\u{30D2}\u{30D5}\u{30D8}\u{30DB}\u{30DE}\u{30DF}\u{30E0}\u{30E1}\u{30E2}\
+    \u{30E4}\u{30E6}\u{30E8}\u{30E9}\u{30EA}\u{30EB}\u{30EC}\u{30ED}\u{30EF}\u{30F0}\u{30F1}\u{30F2}\
+    \u{4EE4}\u{548C}\u{30A2}\u{30CF}\u{309A}\u{30FC}\u{30C8}\u{30A2}\u{30EB}\u{30D5}\u{30A1}\u{30A2}\
+    \u{30F3}\u{30D8}\u{309A}\u{30A2}\u{30A2}\u{30FC}\u{30EB}\u{30A4}\u{30CB}\u{30F3}\u{30AF}\u{3099}\
+    \u{30A4}\u{30F3}\u{30C1}\u{30A6}\u{30A9}\u{30F3}\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C8}\u{3099}\
+    \u{30A8}\u{30FC}\u{30AB}\u{30FC}\u{30AA}\u{30F3}\u{30B9}\u{30AA}\u{30FC}\u{30E0}\u{30AB}\u{30A4}\
+    \u{30EA}\u{30AB}\u{30E9}\u{30C3}\u{30C8}\u{30AB}\u{30ED}\u{30EA}\u{30FC}\u{30AB}\u{3099}\u{30ED}\
+    \u{30F3}\u{30AB}\u{3099}\u{30F3}\u{30DE}\u{30AD}\u{3099}\u{30AB}\u{3099}\u{30AD}\u{3099}\u{30CB}\
+    \u{30FC}\u{30AD}\u{30E5}\u{30EA}\u{30FC}\u{30AD}\u{3099}\u{30EB}\u{30BF}\u{3099}\u{30FC}\u{30AD}\
+    \u{30ED}\u{30AD}\u{30ED}\u{30AF}\u{3099}\u{30E9}\u{30E0}\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\
+    \u{30EB}\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}\u{30AF}\u{3099}\u{30E9}\u{30E0}\u{30AF}\u{3099}\
+    \u{30E9}\u{30E0}\u{30C8}\u{30F3}\u{30AF}\u{30EB}\u{30BB}\u{3099}\u{30A4}\u{30ED}\u{30AF}\u{30ED}\
+    \u{30FC}\u{30CD}\u{30B1}\u{30FC}\u{30B9}\u{30B3}\u{30EB}\u{30CA}\u{30B3}\u{30FC}\u{30DB}\u{309A}\
+    \u{30B5}\u{30A4}\u{30AF}\u{30EB}\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}\u{30B7}\u{30EA}\u{30F3}\
+    \u{30AF}\u{3099}\u{30BB}\u{30F3}\u{30C1}\u{30BB}\u{30F3}\u{30C8}\u{30BF}\u{3099}\u{30FC}\u{30B9}\
+    \u{30C6}\u{3099}\u{30B7}\u{30C8}\u{3099}\u{30EB}\u{30C8}\u{30F3}\u{30CA}\u{30CE}\u{30CE}\u{30C3}\
+    \u{30C8}\u{30CF}\u{30A4}\u{30C4}\u{30CF}\u{309A}\u{30FC}\u{30BB}\u{30F3}\u{30C8}\u{30CF}\u{309A}\
+    \u{30FC}\u{30C4}\u{30CF}\u{3099}\u{30FC}\u{30EC}\u{30EB}\u{30D2}\u{309A}\u{30A2}\u{30B9}\u{30C8}\
+    \u{30EB}\u{30D2}\u{309A}\u{30AF}\u{30EB}\u{30D2}\u{309A}\u{30B3}\u{30D2}\u{3099}\u{30EB}\u{30D5}\
+    \u{30A1}\u{30E9}\u{30C3}\u{30C8}\u{3099}\u{30D5}\u{30A3}\u{30FC}\u{30C8}\u{30D5}\u{3099}\u{30C3}\
+    \u{30B7}\u{30A7}\u{30EB}\u{30D5}\u{30E9}\u{30F3}\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}\u{30D8}\
+    \u{309A}\u{30BD}\u{30D8}\u{309A}\u{30CB}\u{30D2}\u{30D8}\u{30EB}\u{30C4}\u{30D8}\u{309A}\u{30F3}\
+    \u{30B9}\u{30D8}\u{309A}\u{30FC}\u{30B7}\u{3099}\u{30D8}\u{3099}\u{30FC}\u{30BF}\u{30DB}\u{309A}\
+    \u{30A4}\u{30F3}\u{30C8}\u{30DB}\u{3099}\u{30EB}\u{30C8}\u{30DB}\u{30F3}\u{30DB}\u{309A}\u{30F3}\
+    \u{30C8}\u{3099}\u{30DB}\u{30FC}\u{30EB}\u{30DB}\u{30FC}\u{30F3}\u{30DE}\u{30A4}\u{30AF}\u{30ED}\
+    \u{30DE}\u{30A4}\u{30EB}\u{30DE}\u{30C3}\u{30CF}\u{30DE}\u{30EB}\u{30AF}\u{30DE}\u{30F3}\u{30B7}\
+    \u{30E7}\u{30F3}\u{30DF}\u{30AF}\u{30ED}\u{30F3}\u{30DF}\u{30EA}\u{30DF}\u{30EA}\u{30CF}\u{3099}\
+    \u{30FC}\u{30EB}\u{30E1}\u{30AB}\u{3099}\u{30E1}\u{30AB}\u{3099}\u{30C8}\u{30F3}\u{30E1}\u{30FC}\
+    \u{30C8}\u{30EB}\u{30E4}\u{30FC}\u{30C8}\u{3099}\u{30E4}\u{30FC}\u{30EB}\u{30E6}\u{30A2}\u{30F3}\
+    \u{30EA}\u{30C3}\u{30C8}\u{30EB}\u{30EA}\u{30E9}\u{30EB}\u{30D2}\u{309A}\u{30FC}\u{30EB}\u{30FC}\
+    \u{30D5}\u{3099}\u{30EB}\u{30EC}\u{30E0}\u{30EC}\u{30F3}\u{30C8}\u{30B1}\u{3099}\u{30F3}\u{30EF}\
+    \u{30C3}\u{30C8}\u{30}\u{70B9}\u{31}\u{70B9}\u{32}\u{70B9}\u{33}\u{70B9}\u{34}\u{70B9}\
+    \u{35}\u{70B9}\u{36}\u{70B9}\u{37}\u{70B9}\u{38}\u{70B9}\u{39}\u{70B9}\u{31}\u{30}\
+    \u{70B9}\u{31}\u{31}\u{70B9}\u{31}\u{32}\u{70B9}\u{31}\u{33}\u{70B9}\u{31}\u{34}\
+    \u{70B9}\u{31}\u{35}\u{70B9}\u{31}\u{36}\u{70B9}\u{31}\u{37}\u{70B9}\u{31}\u{38}\
+    \u{70B9}\u{31}\u{39}\u{70B9}\u{32}\u{30}\u{70B9}\u{32}\u{31}\u{70B9}\u{32}\u{32}\
+    \u{70B9}\u{32}\u{33}\u{70B9}\u{32}\u{34}\u{70B9}\u{68}\u{50}\u{61}\u{64}\u{61}\
+    \u{41}\u{55}\u{62}\u{61}\u{72}\u{6F}\u{56}\u{70}\u{63}\u{64}\u{6D}\u{64}\
+    \u{6D}\u{32}\u{64}\u{6D}\u{33}\u{49}\u{55}\u{5E73}\u{6210}\u{662D}\u{548C}\u{5927}\
+    \u{6B63}\u{660E}\u{6CBB}\u{682A}\u{5F0F}\u{4F1A}\u{793E}\u{70}\u{41}\u{6E}\u{41}\u{3BC}\
+    \u{41}\u{6D}\u{41}\u{6B}\u{41}\u{4B}\u{42}\u{4D}\u{42}\u{47}\u{42}\u{63}\
+    \u{61}\u{6C}\u{6B}\u{63}\u{61}\u{6C}\u{70}\u{46}\u{6E}\u{46}\u{3BC}\u{46}\
+    \u{3BC}\u{67}\u{6D}\u{67}\u{6B}\u{67}\u{48}\u{7A}\u{6B}\u{48}\u{7A}\u{4D}\
+    \u{48}\u{7A}\u{47}\u{48}\u{7A}\u{54}\u{48}\u{7A}\u{3BC}\u{6C}\u{6D}\u{6C}\
+    \u{64}\u{6C}\u{6B}\u{6C}\u{66}\u{6D}\u{6E}\u{6D}\u{3BC}\u{6D}\u{6D}\u{6D}\
+    \u{63}\u{6D}\u{6B}\u{6D}\u{6D}\u{6D}\u{32}\u{63}\u{6D}\u{32}\u{6D}\u{32}\
+    \u{6B}\u{6D}\u{32}\u{6D}\u{6D}\u{33}\u{63}\u{6D}\u{33}\u{6D}\u{33}\u{6B}\
+    \u{6D}\u{33}\u{6D}\u{2215}\u{73}\u{6D}\u{2215}\u{73}\u{32}\u{50}\u{61}\u{6B}\
+    \u{50}\u{61}\u{4D}\u{50}\u{61}\u{47}\u{50}\u{61}\u{72}\u{61}\u{64}\u{72}\
+    \u{61}\u{64}\u{2215}\u{73}\u{72}\u{61}\u{64}\u{2215}\u{73}\u{32}\u{70}\u{73}\
+    \u{6E}\u{73}\u{3BC}\u{73}\u{6D}\u{73}\u{70}\u{56}\u{6E}\u{56}\u{3BC}\u{56}\
+    \u{6D}\u{56}\u{6B}\u{56}\u{4D}\u{56}\u{70}\u{57}\u{6E}\u{57}\u{3BC}\u{57}\
+    \u{6D}\u{57}\u{6B}\u{57}\u{4D}\u{57}\u{6B}\u{3A9}\u{4D}\u{3A9}\u{61}\u{2E}\
+    \u{6D}\u{2E}\u{42}\u{71}\u{63}\u{63}\u{63}\u{64}\u{43}\u{2215}\u{6B}\u{67}\
+    \u{43}\u{6F}\u{2E}\u{64}\u{42}\u{47}\u{79}\u{68}\u{61}\u{48}\u{50}\u{69}\
+    \u{6E}\u{4B}\u{4B}\u{4B}\u{4D}\u{6B}\u{74}\u{6C}\u{6D}\u{6C}\u{6E}\u{6C}\
+    \u{6F}\u{67}\u{6C}\u{78}\u{6D}\u{62}\u{6D}\u{69}\u{6C}\u{6D}\u{6F}\u{6C}\
+    \u{50}\u{48}\u{70}\u{2E}\u{6D}\u{2E}\u{50}\u{50}\u{4D}\u{50}\u{52}\u{73}\
+    \u{72}\u{53}\u{76}\u{57}\u{62}\u{56}\u{2215}\u{6D}\u{41}\u{2215}\u{6D}\u{31}\
+    \u{65E5}\u{32}\u{65E5}\u{33}\u{65E5}\u{34}\u{65E5}\u{35}\u{65E5}\u{36}\u{65E5}\u{37}\
+    \u{65E5}\u{38}\u{65E5}\u{39}\u{65E5}\u{31}\u{30}\u{65E5}\u{31}\u{31}\u{65E5}\u{31}\
+    \u{32}\u{65E5}\u{31}\u{33}\u{65E5}\u{31}\u{34}\u{65E5}\u{31}\u{35}\u{65E5}\u{31}\
+    \u{36}\u{65E5}\u{31}\u{37}\u{65E5}\u{31}\u{38}\u{65E5}\u{31}\u{39}\u{65E5}\u{32}\
+    \u{30}\u{65E5}\u{32}\u{31}\u{65E5}\u{32}\u{32}\u{65E5}\u{32}\u{33}\u{65E5}\u{32}\
+    \u{34}\u{65E5}\u{32}\u{35}\u{65E5}\u{32}\u{36}\u{65E5}\u{32}\u{37}\u{65E5}\u{32}\
+    \u{38}\u{65E5}\u{32}\u{39}\u{65E5}\u{33}\u{30}\u{65E5}\u{33}\u{31}\u{65E5}\u{67}\
+    \u{61}\u{6C}\u{44A}\u{44C}\u{A76F}\u{53}\u{43}\u{46}\u{51}\u{126}\u{153}\u{A727}\
+    \u{AB37}\u{26B}\u{AB52}\u{28D}\u{66}\u{66}\u{66}\u{69}\u{66}\u{6C}\u{66}\u{66}\
+    \u{69}\u{66}\u{66}\u{6C}\u{73}\u{74}\u{73}\u{74}\u{574}\u{576}\u{574}\u{565}\
+    \u{574}\u{56B}\u{57E}\u{576}\u{574}\u{56D}\u{5E2}\u{5D0}\u{5D3}\u{5D4}\u{5DB}\u{5DC}\
+    \u{5DD}\u{5E8}\u{5EA}\u{2B}\u{5D0}\u{5DC}\u{671}\u{671}\u{67B}\u{67B}\u{67B}\u{67B}\
+    \u{67E}\u{67E}\u{67E}\u{67E}\u{680}\u{680}\u{680}\u{680}\u{67A}\u{67A}\u{67A}\u{67A}\
+    \u{67F}\u{67F}\u{67F}\u{67F}\u{679}\u{679}\u{679}\u{679}\u{6A4}\u{6A4}\u{6A4}\u{6A4}\
+    \u{6A6}\u{6A6}\u{6A6}\u{6A6}\u{684}\u{684}\u{684}\u{684}\u{683}\u{683}\u{683}\u{683}\
+    \u{686}\u{686}\u{686}\u{686}\u{687}\u{687}\u{687}\u{687}\u{68D}\u{68D}\u{68C}\u{68C}\
+    \u{68E}\u{68E}\u{688}\u{688}\u{698}\u{698}\u{691}\u{691}\u{6A9}\u{6A9}\u{6A9}\u{6A9}\
+    \u{6AF}\u{6AF}\u{6AF}\u{6AF}\u{6B3}\u{6B3}\u{6B3}\u{6B3}\u{6B1}\u{6B1}\u{6B1}\u{6B1}\
+    \u{6BA}\u{6BA}\u{6BB}\u{6BB}\u{6BB}\u{6BB}\u{6D5}\u{654}\u{6D5}\u{654}\u{6C1}\u{6C1}\
+    \u{6C1}\u{6C1}\u{6BE}\u{6BE}\u{6BE}\u{6BE}\u{6D2}\u{6D2}\u{6D2}\u{654}\u{6D2}\u{654}\
+    \u{6AD}\u{6AD}\u{6AD}\u{6AD}\u{6C7}\u{6C7}\u{6C6}\u{6C6}\u{6C8}\u{6C8}\u{6C7}\u{674}\
+    \u{6CB}\u{6CB}\u{6C5}\u{6C5}\u{6C9}\u{6C9}\u{6D0}\u{6D0}\u{6D0}\u{6D0}\u{649}\u{649}\
+    \u{64A}\u{654}\u{627}\u{64A}\u{654}\u{627}\u{64A}\u{654}\u{6D5}\u{64A}\u{654}\u{6D5}\
+    \u{64A}\u{654}\u{648}\u{64A}\u{654}\u{648}\u{64A}\u{654}\u{6C7}\u{64A}\u{654}\u{6C7}\
+    \u{64A}\u{654}\u{6C6}\u{64A}\u{654}\u{6C6}\u{64A}\u{654}\u{6C8}\u{64A}\u{654}\u{6C8}\
+    \u{64A}\u{654}\u{6D0}\u{64A}\u{654}\u{6D0}\u{64A}\u{654}\u{6D0}\u{64A}\u{654}\u{649}\
+    \u{64A}\u{654}\u{649}\u{64A}\u{654}\u{649}\u{6CC}\u{6CC}\u{6CC}\u{6CC}\u{64A}\u{654}\
+    \u{62C}\u{64A}\u{654}\u{62D}\u{64A}\u{654}\u{645}\u{64A}\u{654}\u{649}\u{64A}\u{654}\
+    \u{64A}\u{628}\u{62C}\u{628}\u{62D}\u{628}\u{62E}\u{628}\u{645}\u{628}\u{649}\u{628}\
+    \u{64A}\u{62A}\u{62C}\u{62A}\u{62D}\u{62A}\u{62E}\u{62A}\u{645}\u{62A}\u{649}\u{62A}\
+    \u{64A}\u{62B}\u{62C}\u{62B}\u{645}\u{62B}\u{649}\u{62B}\u{64A}\u{62C}\u{62D}\u{62C}\
+    \u{645}\u{62D}\u{62C}\u{62D}\u{645}\u{62E}\u{62C}\u{62E}\u{62D}\u{62E}\u{645}\u{633}\
+    \u{62C}\u{633}\u{62D}\u{633}\u{62E}\u{633}\u{645}\u{635}\u{62D}\u{635}\u{645}\u{636}\
+    \u{62C}\u{636}\u{62D}\u{636}\u{62E}\u{636}\u{645}\u{637}\u{62D}\u{637}\u{645}\u{638}\
+    \u{645}\u{639}\u{62C}\u{639}\u{645}\u{63A}\u{62C}\u{63A}\u{645}\u{641}\u{62C}\u{641}\
+    \u{62D}\u{641}\u{62E}\u{641}\u{645}\u{641}\u{649}\u{641}\u{64A}\u{642}\u{62D}\u{642}\
+    \u{645}\u{642}\u{649}\u{642}\u{64A}\u{643}\u{627}\u{643}\u{62C}\u{643}\u{62D}\u{643}\
+    \u{62E}\u{643}\u{644}\u{643}\u{645}\u{643}\u{649}\u{643}\u{64A}\u{644}\u{62C}\u{644}\
+    \u{62D}\u{644}\u{62E}\u{644}\u{645}\u{644}\u{649}\u{644}\u{64A}\u{645}\u{62C}\u{645}\
+    \u{62D}\u{645}\u{62E}\u{645}\u{645}\u{645}\u{649}\u{645}\u{64A}\u{646}\u{62C}\u{646}\
+    \u{62D}\u{646}\u{62E}\u{646}\u{645}\u{646}\u{649}\u{646}\u{64A}\u{647}\u{62C}\u{647}\
+    \u{645}\u{647}\u{649}\u{647}\u{64A}\u{64A}\u{62C}\u{64A}\u{62D}\u{64A}\u{62E}\u{64A}\
+    \u{645}\u{64A}\u{649}\u{64A}\u{64A}\u{630}\u{670}\u{631}\u{670}\u{649}\u{670}\u{20}\
+    \u{64C}\u{651}\u{20}\u{64D}\u{651}\u{20}\u{64E}\u{651}\u{20}\u{64F}\u{651}\u{20}\
+    \u{650}\u{651}\u{20}\u{651}\u{670}\u{64A}\u{654}\u{631}\u{64A}\u{654}\u{632}\u{64A}\
+    \u{654}\u{645}\u{64A}\u{654}\u{646}\u{64A}\u{654}\u{649}\u{64A}\u{654}\u{64A}\u{628}\
+    \u{631}\u{628}\u{632}\u{628}\u{645}\u{628}\u{646}\u{628}\u{649}\u{628}\u{64A}\u{62A}\
+    \u{631}\u{62A}\u{632}\u{62A}\u{645}\u{62A}\u{646}\u{62A}\u{649}\u{62A}\u{64A}\u{62B}\
+    \u{631}\u{62B}\u{632}\u{62B}\u{645}\u{62B}\u{646}\u{62B}\u{649}\u{62B}\u{64A}\u{641}\
+    \u{649}\u{641}\u{64A}\u{642}\u{649}\u{642}\u{64A}\u{643}\u{627}\u{643}\u{644}\u{643}\
+    \u{645}\u{643}\u{649}\u{643}\u{64A}\u{644}\u{645}\u{644}\u{649}\u{644}\u{64A}\u{645}\
+    \u{627}\u{645}\u{645}\u{646}\u{631}\u{646}\u{632}\u{646}\u{645}\u{646}\u{646}\u{646}\
+    \u{649}\u{646}\u{64A}\u{649}\u{670}\u{64A}\u{631}\u{64A}\u{632}\u{64A}\u{645}\u{64A}\
+    \u{646}\u{64A}\u{649}\u{64A}\u{64A}\u{64A}\u{654}\u{62C}\u{64A}\u{654}\u{62D}\u{64A}\
+    \u{654}\u{62E}\u{64A}\u{654}\u{645}\u{64A}\u{654}\u{647}\u{628}\u{62C}\u{628}\u{62D}\
+    \u{628}\u{62E}\u{628}\u{645}\u{628}\u{647}\u{62A}\u{62C}\u{62A}\u{62D}\u{62A}\u{62E}\
+    \u{62A}\u{645}\u{62A}\u{647}\u{62B}\u{645}\u{62C}\u{62D}\u{62C}\u{645}\u{62D}\u{62C}\
+    \u{62D}\u{645}\u{62E}\u{62C}\u{62E}\u{645}\u{633}\u{62C}\u{633}\u{62D}\u{633}\u{62E}\
+    \u{633}\u{645}\u{635}\u{62D}\u{635}\u{62E}\u{635}\u{645}\u{636}\u{62C}\u{636}\u{62D}\
+    \u{636}\u{62E}\u{636}\u{645}\u{637}\u{62D}\u{638}\u{645}\u{639}\u{62C}\u{639}\u{645}\
+    \u{63A}\u{62C}\u{63A}\u{645}\u{641}\u{62C}\u{641}\u{62D}\u{641}\u{62E}\u{641}\u{645}\
+    \u{642}\u{62D}\u{642}\u{645}\u{643}\u{62C}\u{643}\u{62D}\u{643}\u{62E}\u{643}\u{644}\
+    \u{643}\u{645}\u{644}\u{62C}\u{644}\u{62D}\u{644}\u{62E}\u{644}\u{645}\u{644}\u{647}\
+    \u{645}\u{62C}\u{645}\u{62D}\u{645}\u{62E}\u{645}\u{645}\u{646}\u{62C}\u{646}\u{62D}\
+    \u{646}\u{62E}\u{646}\u{645}\u{646}\u{647}\u{647}\u{62C}\u{647}\u{645}\u{647}\u{670}\
+    \u{64A}\u{62C}\u{64A}\u{62D}\u{64A}\u{62E}\u{64A}\u{645}\u{64A}\u{647}\u{64A}\u{654}\
+    \u{645}\u{64A}\u{654}\u{647}\u{628}\u{645}\u{628}\u{647}\u{62A}\u{645}\u{62A}\u{647}\
+    \u{62B}\u{645}\u{62B}\u{647}\u{633}\u{645}\u{633}\u{647}\u{634}\u{645}\u{634}\u{647}\
+    \u{643}\u{644}\u{643}\u{645}\u{644}\u{645}\u{646}\u{645}\u{646}\u{647}\u{64A}\u{645}\
+    \u{64A}\u{647}\u{640}\u{64E}\u{651}\u{640}\u{64F}\u{651}\u{640}\u{650}\u{651}\u{637}\
+    \u{649}\u{637}\u{64A}\u{639}\u{649}\u{639}\u{64A}\u{63A}\u{649}\u{63A}\u{64A}\u{633}\
+    \u{649}\u{633}\u{64A}\u{634}\u{649}\u{634}\u{64A}\u{62D}\u{649}\u{62D}\u{64A}\u{62C}\
+    \u{649}\u{62C}\u{64A}\u{62E}\u{649}\u{62E}\u{64A}\u{635}\u{649}\u{635}\u{64A}\u{636}\
+    \u{649}\u{636}\u{64A}\u{634}\u{62C}\u{634}\u{62D}\u{634}\u{62E}\u{634}\u{645}\u{634}\
+    \u{631}\u{633}\u{631}\u{635}\u{631}\u{636}\u{631}\u{637}\u{649}\u{637}\u{64A}\u{639}\
+    \u{649}\u{639}\u{64A}\u{63A}\u{649}\u{63A}\u{64A}\u{633}\u{649}\u{633}\u{64A}\u{634}\
+    \u{649}\u{634}\u{64A}\u{62D}\u{649}\u{62D}\u{64A}\u{62C}\u{649}\u{62C}\u{64A}\u{62E}\
+    \u{649}\u{62E}\u{64A}\u{635}\u{649}\u{635}\u{64A}\u{636}\u{649}\u{636}\u{64A}\u{634}\
+    \u{62C}\u{634}\u{62D}\u{634}\u{62E}\u{634}\u{645}\u{634}\u{631}\u{633}\u{631}\u{635}\
+    \u{631}\u{636}\u{631}\u{634}\u{62C}\u{634}\u{62D}\u{634}\u{62E}\u{634}\u{645}\u{633}\
+    \u{647}\u{634}\u{647}\u{637}\u{645}\u{633}\u{62C}\u{633}\u{62D}\u{633}\u{62E}\u{634}\
+    \u{62C}\u{634}\u{62D}\u{634}\u{62E}\u{637}\u{645}\u{638}\u{645}\u{627}\u{64B}\u{627}\
+    \u{64B}\u{62A}\u{62C}\u{645}\u{62A}\u{62D}\u{62C}\u{62A}\u{62D}\u{62C}\u{62A}\u{62D}\
+    \u{645}\u{62A}\u{62E}\u{645}\u{62A}\u{645}\u{62C}\u{62A}\u{645}\u{62D}\u{62A}\u{645}\
+    \u{62E}\u{62C}\u{645}\u{62D}\u{62C}\u{645}\u{62D}\u{62D}\u{645}\u{64A}\u{62D}\u{645}\
+    \u{649}\u{633}\u{62D}\u{62C}\u{633}\u{62C}\u{62D}\u{633}\u{62C}\u{649}\u{633}\u{645}\
+    \u{62D}\u{633}\u{645}\u{62D}\u{633}\u{645}\u{62C}\u{633}\u{645}\u{645}\u{633}\u{645}\
+    \u{645}\u{635}\u{62D}\u{62D}\u{635}\u{62D}\u{62D}\u{635}\u{645}\u{645}\u{634}\u{62D}\
+    \u{645}\u{634}\u{62D}\u{645}\u{634}\u{62C}\u{64A}\u{634}\u{645}\u{62E}\u{634}\u{645}\
+    \u{62E}\u{634}\u{645}\u{645}\u{634}\u{645}\u{645}\u{636}\u{62D}\u{649}\u{636}\u{62E}\
+    \u{645}\u{636}\u{62E}\u{645}\u{637}\u{645}\u{62D}\u{637}\u{645}\u{62D}\u{637}\u{645}\
+    \u{645}\u{637}\u{645}\u{64A}\u{639}\u{62C}\u{645}\u{639}\u{645}\u{645}\u{639}\u{645}\
+    \u{645}\u{639}\u{645}\u{649}\u{63A}\u{645}\u{645}\u{63A}\u{645}\u{64A}\u{63A}\u{645}\
+    \u{649}\u{641}\u{62E}\u{645}\u{641}\u{62E}\u{645}\u{642}\u{645}\u{62D}\u{642}\u{645}\
+    \u{645}\u{644}\u{62D}\u{645}\u{644}\u{62D}\u{64A}\u{644}\u{62D}\u{649}\u{644}\u{62C}\
+    \u{62C}\u{644}\u{62C}\u{62C}\u{644}\u{62E}\u{645}\u{644}\u{62E}\u{645}\u{644}\u{645}\
+    \u{62D}\u{644}\u{645}\u{62D}\u{645}\u{62D}\u{62C}\u{645}\u{62D}\u{645}\u{645}\u{62D}\
+    \u{64A}\u{645}\u{62C}\u{62D}\u{645}\u{62C}\u{645}\u{645}\u{62E}\u{62C}\u{645}\u{62E}\
+    \u{645}\u{645}\u{62C}\u{62E}\u{647}\u{645}\u{62C}\u{647}\u{645}\u{645}\u{646}\u{62D}\
+    \u{645}\u{646}\u{62D}\u{649}\u{646}\u{62C}\u{645}\u{646}\u{62C}\u{645}\u{646}\u{62C}\
+    \u{649}\u{646}\u{645}\u{64A}\u{646}\u{645}\u{649}\u{64A}\u{645}\u{645}\u{64A}\u{645}\
+    \u{645}\u{628}\u{62E}\u{64A}\u{62A}\u{62C}\u{64A}\u{62A}\u{62C}\u{649}\u{62A}\u{62E}\
+    \u{64A}\u{62A}\u{62E}\u{649}\u{62A}\u{645}\u{64A}\u{62A}\u{645}\u{649}\u{62C}\u{645}\
+    \u{64A}\u{62C}\u{62D}\u{649}\u{62C}\u{645}\u{649}\u{633}\u{62E}\u{649}\u{635}\u{62D}\
+    \u{64A}\u{634}\u{62D}\u{64A}\u{636}\u{62D}\u{64A}\u{644}\u{62C}\u{64A}\u{644}\u{645}\
+    \u{64A}\u{64A}\u{62D}\u{64A}\u{64A}\u{62C}\u{64A}\u{64A}\u{645}\u{64A}\u{645}\u{645}\
+    \u{64A}\u{642}\u{645}\u{64A}\u{646}\u{62D}\u{64A}\u{642}\u{645}\u{62D}\u{644}\u{62D}\
+    \u{645}\u{639}\u{645}\u{64A}\u{643}\u{645}\u{64A}\u{646}\u{62C}\u{62D}\u{645}\u{62E}\
+    \u{64A}\u{644}\u{62C}\u{645}\u{643}\u{645}\u{645}\u{644}\u{62C}\u{645}\u{646}\u{62C}\
+    \u{62D}\u{62C}\u{62D}\u{64A}\u{62D}\u{62C}\u{64A}\u{645}\u{62C}\u{64A}\u{641}\u{645}\
+    \u{64A}\u{628}\u{62D}\u{64A}\u{643}\u{645}\u{645}\u{639}\u{62C}\u{645}\u{635}\u{645}\
+    \u{645}\u{633}\u{62E}\u{64A}\u{646}\u{62C}\u{64A}\u{635}\u{644}\u{6D2}\u{642}\u{644}\
+    \u{6D2}\u{627}\u{644}\u{644}\u{647}\u{627}\u{643}\u{628}\u{631}\u{645}\u{62D}\u{645}\
+    \u{62F}\u{635}\u{644}\u{639}\u{645}\u{631}\u{633}\u{648}\u{644}\u{639}\u{644}\u{64A}\
+    \u{647}\u{648}\u{633}\u{644}\u{645}\u{635}\u{644}\u{649}\u{635}\u{644}\u{649}\u{20}\
+    \u{627}\u{644}\u{644}\u{647}\u{20}\u{639}\u{644}\u{64A}\u{647}\u{20}\u{648}\u{633}\
+    \u{644}\u{645}\u{62C}\u{644}\u{20}\u{62C}\u{644}\u{627}\u{644}\u{647}\u{631}\u{6CC}\
+    \u{627}\u{644}\u{2C}\u{3001}\u{3002}\u{3A}\u{3B}\u{21}\u{3F}\u{3016}\u{3017}\u{2E}\
+    \u{2E}\u{2E}\u{2E}\u{2E}\u{2014}\u{2013}\u{5F}\u{5F}\u{28}\u{29}\u{7B}\u{7D}\
+    \u{3014}\u{3015}\u{3010}\u{3011}\u{300A}\u{300B}\u{3008}\u{3009}\u{300C}\u{300D}\u{300E}\u{300F}\
+    \u{5B}\u{5D}\u{20}\u{305}\u{20}\u{305}\u{20}\u{305}\u{20}\u{305}\u{5F}\u{5F}\
+    \u{5F}\u{2C}\u{3001}\u{2E}\u{3B}\u{3A}\u{3F}\u{21}\u{2014}\u{28}\u{29}\u{7B}\
+    \u{7D}\u{3014}\u{3015}\u{23}\u{26}\u{2A}\u{2B}\u{2D}\u{3C}\u{3E}\u{3D}\u{5C}\
+    \u{24}\u{25}\u{40}\u{20}\u{64B}\u{640}\u{64B}\u{20}\u{64C}\u{20}\u{64D}\u{20}\
+    \u{64E}\u{640}\u{64E}\u{20}\u{64F}\u{640}\u{64F}\u{20}\u{650}\u{640}\u{650}\u{20}\
+    \u{651}\u{640}\u{651}\u{20}\u{652}\u{640}\u{652}\u{621}\u{627}\u{653}\u{627}\u{653}\
+    \u{627}\u{654}\u{627}\u{654}\u{648}\u{654}\u{648}\u{654}\u{627}\u{655}\u{627}\u{655}\
+    \u{64A}\u{654}\u{64A}\u{654}\u{64A}\u{654}\u{64A}\u{654}\u{627}\u{627}\u{628}\u{628}\
+    \u{628}\u{628}\u{629}\u{629}\u{62A}\u{62A}\u{62A}\u{62A}\u{62B}\u{62B}\u{62B}\u{62B}\
+    \u{62C}\u{62C}\u{62C}\u{62C}\u{62D}\u{62D}\u{62D}\u{62D}\u{62E}\u{62E}\u{62E}\u{62E}\
+    \u{62F}\u{62F}\u{630}\u{630}\u{631}\u{631}\u{632}\u{632}\u{633}\u{633}\u{633}\u{633}\
+    \u{634}\u{634}\u{634}\u{634}\u{635}\u{635}\u{635}\u{635}\u{636}\u{636}\u{636}\u{636}\
+    \u{637}\u{637}\u{637}\u{637}\u{638}\u{638}\u{638}\u{638}\u{639}\u{639}\u{639}\u{639}\
+    \u{63A}\u{63A}\u{63A}\u{63A}\u{641}\u{641}\u{641}\u{641}\u{642}\u{642}\u{642}\u{642}\
+    \u{643}\u{643}\u{643}\u{643}\u{644}\u{644}\u{644}\u{644}\u{645}\u{645}\u{645}\u{645}\
+    \u{646}\u{646}\u{646}\u{646}\u{647}\u{647}\u{647}\u{647}\u{648}\u{648}\u{649}\u{649}\
+    \u{64A}\u{64A}\u{64A}\u{64A}\u{644}\u{627}\u{653}\u{644}\u{627}\u{653}\u{644}\u{627}\
+    \u{654}\u{644}\u{627}\u{654}\u{644}\u{627}\u{655}\u{644}\u{627}\u{655}\u{644}\u{627}\
+    \u{644}\u{627}\u{21}\u{22}\u{23}\u{24}\u{25}\u{26}\u{27}\u{28}\u{29}\u{2A}\
+    \u{2B}\u{2C}\u{2D}\u{2E}\u{2F}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\
+    \u{37}\u{38}\u{39}\u{3A}\u{3B}\u{3C}\u{3D}\u{3E}\u{3F}\u{40}\u{41}\u{42}\
+    \u{43}\u{44}\u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\
+    \u{4F}\u{50}\u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\
+    \u{5B}\u{5C}\u{5D}\u{5E}\u{5F}\u{60}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\
+    \u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\
+    \u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{7B}\u{7C}\u{7D}\u{7E}\
+    \u{2985}\u{2986}\u{3002}\u{300C}\u{300D}\u{3001}\u{30FB}\u{30F2}\u{30A1}\u{30A3}\u{30A5}\u{30A7}\
+    \u{30A9}\u{30E3}\u{30E5}\u{30E7}\u{30C3}\u{30FC}\u{30A2}\u{30A4}\u{30A6}\u{30A8}\u{30AA}\u{30AB}\
+    \u{30AD}\u{30AF}\u{30B1}\u{30B3}\u{30B5}\u{30B7}\u{30B9}\u{30BB}\u{30BD}\u{30BF}\u{30C1}\u{30C4}\
+    \u{30C6}\u{30C8}\u{30CA}\u{30CB}\u{30CC}\u{30CD}\u{30CE}\u{30CF}\u{30D2}\u{30D5}\u{30D8}\u{30DB}\
+    \u{30DE}\u{30DF}\u{30E0}\u{30E1}\u{30E2}\u{30E4}\u{30E6}\u{30E8}\u{30E9}\u{30EA}\u{30EB}\u{30EC}\
+    \u{30ED}\u{30EF}\u{30F3}\u{3099}\u{309A}\u{1160}\u{1100}\u{1101}\u{11AA}\u{1102}\u{11AC}\u{11AD}\
+    \u{1103}\u{1104}\u{1105}\u{11B0}\u{11B1}\u{11B2}\u{11B3}\u{11B4}\u{11B5}\u{111A}\u{1106}\u{1107}\
+    \u{1108}\u{1121}\u{1109}\u{110A}\u{110B}\u{110C}\u{110D}\u{110E}\u{110F}\u{1110}\u{1111}\u{1112}\
+    \u{1161}\u{1162}\u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116A}\u{116B}\u{116C}\
+    \u{116D}\u{116E}\u{116F}\u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}\u{A2}\u{A3}\u{AC}\
+    \u{20}\u{304}\u{A6}\u{A5}\u{20A9}\u{2502}\u{2190}\u{2191}\u{2192}\u{2193}\u{25A0}\u{25CB}\
+    \u{2D0}\u{2D1}\u{E6}\u{299}\u{253}\u{2A3}\u{AB66}\u{2A5}\u{2A4}\u{256}\u{257}\u{1D91}\
+    \u{258}\u{25E}\u{2A9}\u{264}\u{262}\u{260}\u{29B}\u{127}\u{29C}\u{267}\u{284}\u{2AA}\
+    \u{2AB}\u{26C}\u{1DF04}\u{A78E}\u{26E}\u{1DF05}\u{28E}\u{1DF06}\u{F8}\u{276}\u{277}\u{71}\
+    \u{27A}\u{1DF08}\u{27D}\u{27E}\u{280}\u{2A8}\u{2A6}\u{AB67}\u{2A7}\u{288}\u{2C71}\u{28F}\
+    \u{2A1}\u{2A2}\u{298}\u{1C0}\u{1C1}\u{1C2}\u{1DF0A}\u{1DF1E}\u{41}\u{42}\u{43}\u{44}\
+    \u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\
+    \u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\u{30}\u{31}\
+    \u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\u{41}\u{42}\u{43}\u{44}\
+    \u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\
+    \u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\u{61}\u{62}\
+    \u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\
+    \u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\
+    \u{41}\u{42}\u{43}\u{44}\u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\
+    \u{4D}\u{4E}\u{4F}\u{50}\u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\
+    \u{59}\u{5A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{69}\u{6A}\u{6B}\
+    \u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\
+    \u{78}\u{79}\u{7A}\u{41}\u{42}\u{43}\u{44}\u{45}\u{46}\u{47}\u{48}\u{49}\
+    \u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\u{51}\u{52}\u{53}\u{54}\u{55}\
+    \u{56}\u{57}\u{58}\u{59}\u{5A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\
+    \u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\
+    \u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{41}\u{43}\u{44}\u{47}\u{4A}\
+    \u{4B}\u{4E}\u{4F}\u{50}\u{51}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\
+    \u{5A}\u{61}\u{62}\u{63}\u{64}\u{66}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\
+    \u{6E}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\
+    \u{41}\u{42}\u{43}\u{44}\u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\
+    \u{4D}\u{4E}\u{4F}\u{50}\u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\
+    \u{59}\u{5A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\
+    \u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\
+    \u{77}\u{78}\u{79}\u{7A}\u{41}\u{42}\u{44}\u{45}\u{46}\u{47}\u{4A}\u{4B}\
+    \u{4C}\u{4D}\u{4E}\u{4F}\u{50}\u{51}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\
+    \u{59}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\
+    \u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\
+    \u{78}\u{79}\u{7A}\u{41}\u{42}\u{44}\u{45}\u{46}\u{47}\u{49}\u{4A}\u{4B}\
+    \u{4C}\u{4D}\u{4F}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\u{61}\u{62}\
+    \u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\
+    \u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\
+    \u{41}\u{42}\u{43}\u{44}\u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\
+    \u{4D}\u{4E}\u{4F}\u{50}\u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\
+    \u{59}\u{5A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\
+    \u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\
+    \u{77}\u{78}\u{79}\u{7A}\u{41}\u{42}\u{43}\u{44}\u{45}\u{46}\u{47}\u{48}\
+    \u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\u{51}\u{52}\u{53}\u{54}\
+    \u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\
+    \u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\
+    \u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{41}\u{42}\u{43}\u{44}\
+    \u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\
+    \u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\u{61}\u{62}\
+    \u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\
+    \u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\
+    \u{41}\u{42}\u{43}\u{44}\u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\
+    \u{4D}\u{4E}\u{4F}\u{50}\u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\
+    \u{59}\u{5A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\
+    \u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\
+    \u{77}\u{78}\u{79}\u{7A}\u{41}\u{42}\u{43}\u{44}\u{45}\u{46}\u{47}\u{48}\
+    \u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\u{51}\u{52}\u{53}\u{54}\
+    \u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\
+    \u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\
+    \u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{41}\u{42}\u{43}\u{44}\
+    \u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\
+    \u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\u{61}\u{62}\
+    \u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\
+    \u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\
+    \u{131}\u{237}\u{391}\u{392}\u{393}\u{394}\u{395}\u{396}\u{397}\u{398}\u{399}\u{39A}\
+    \u{39B}\u{39C}\u{39D}\u{39E}\u{39F}\u{3A0}\u{3A1}\u{398}\u{3A3}\u{3A4}\u{3A5}\u{3A6}\
+    \u{3A7}\u{3A8}\u{3A9}\u{2207}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\
+    \u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3C2}\u{3C3}\u{3C4}\
+    \u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2202}\u{3B5}\u{3B8}\u{3BA}\u{3C6}\u{3C1}\u{3C0}\
+    \u{391}\u{392}\u{393}\u{394}\u{395}\u{396}\u{397}\u{398}\u{399}\u{39A}\u{39B}\u{39C}\
+    \u{39D}\u{39E}\u{39F}\u{3A0}\u{3A1}\u{398}\u{3A3}\u{3A4}\u{3A5}\u{3A6}\u{3A7}\u{3A8}\
+    \u{3A9}\u{2207}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\
+    \u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3C2}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\
+    \u{3C7}\u{3C8}\u{3C9}\u{2202}\u{3B5}\u{3B8}\u{3BA}\u{3C6}\u{3C1}\u{3C0}\u{391}\u{392}\
+    \u{393}\u{394}\u{395}\u{396}\u{397}\u{398}\u{399}\u{39A}\u{39B}\u{39C}\u{39D}\u{39E}\
+    \u{39F}\u{3A0}\u{3A1}\u{398}\u{3A3}\u{3A4}\u{3A5}\u{3A6}\u{3A7}\u{3A8}\u{3A9}\u{2207}\
+    \u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\
+    \u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3C2}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\
+    \u{3C9}\u{2202}\u{3B5}\u{3B8}\u{3BA}\u{3C6}\u{3C1}\u{3C0}\u{391}\u{392}\u{393}\u{394}\
+    \u{395}\u{396}\u{397}\u{398}\u{399}\u{39A}\u{39B}\u{39C}\u{39D}\u{39E}\u{39F}\u{3A0}\
+    \u{3A1}\u{398}\u{3A3}\u{3A4}\u{3A5}\u{3A6}\u{3A7}\u{3A8}\u{3A9}\u{2207}\u{3B1}\u{3B2}\
+    \u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\
+    \u{3BF}\u{3C0}\u{3C1}\u{3C2}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2202}\
+    \u{3B5}\u{3B8}\u{3BA}\u{3C6}\u{3C1}\u{3C0}\u{391}\u{392}\u{393}\u{394}\u{395}\u{396}\
+    \u{397}\u{398}\u{399}\u{39A}\u{39B}\u{39C}\u{39D}\u{39E}\u{39F}\u{3A0}\u{3A1}\u{398}\
+    \u{3A3}\u{3A4}\u{3A5}\u{3A6}\u{3A7}\u{3A8}\u{3A9}\u{2207}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\
+    \u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\
+    \u{3C1}\u{3C2}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2202}\u{3B5}\u{3B8}\
+    \u{3BA}\u{3C6}\u{3C1}\u{3C0}\u{3DC}\u{3DD}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\
+    \u{36}\u{37}\u{38}\u{39}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\
+    \u{38}\u{39}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\
+    \u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\u{30}\u{31}\
+    \u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\u{430}\u{431}\u{432}\u{433}\
+    \u{434}\u{435}\u{436}\u{437}\u{438}\u{43A}\u{43B}\u{43C}\u{43E}\u{43F}\u{440}\u{441}\
+    \u{442}\u{443}\u{444}\u{445}\u{446}\u{447}\u{448}\u{44B}\u{44D}\u{44E}\u{A689}\u{4D9}\
+    \u{456}\u{458}\u{4E9}\u{4AF}\u{4CF}\u{430}\u{431}\u{432}\u{433}\u{434}\u{435}\u{436}\
+    \u{437}\u{438}\u{43A}\u{43B}\u{43E}\u{43F}\u{441}\u{443}\u{444}\u{445}\u{446}\u{447}\
+    \u{448}\u{44A}\u{44B}\u{491}\u{456}\u{455}\u{45F}\u{4AB}\u{A651}\u{4B1}\u{627}\u{628}\
+    \u{62C}\u{62F}\u{648}\u{632}\u{62D}\u{637}\u{64A}\u{643}\u{644}\u{645}\u{646}\u{633}\
+    \u{639}\u{641}\u{635}\u{642}\u{631}\u{634}\u{62A}\u{62B}\u{62E}\u{630}\u{636}\u{638}\
+    \u{63A}\u{66E}\u{6BA}\u{6A1}\u{66F}\u{628}\u{62C}\u{647}\u{62D}\u{64A}\u{643}\u{644}\
+    \u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{634}\u{62A}\u{62B}\u{62E}\u{636}\
+    \u{63A}\u{62C}\u{62D}\u{64A}\u{644}\u{646}\u{633}\u{639}\u{635}\u{642}\u{634}\u{62E}\
+    \u{636}\u{63A}\u{6BA}\u{66F}\u{628}\u{62C}\u{647}\u{62D}\u{637}\u{64A}\u{643}\u{645}\
+    \u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{634}\u{62A}\u{62B}\u{62E}\u{636}\u{638}\
+    \u{63A}\u{66E}\u{6A1}\u{627}\u{628}\u{62C}\u{62F}\u{647}\u{648}\u{632}\u{62D}\u{637}\
+    \u{64A}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{631}\u{634}\u{62A}\
+    \u{62B}\u{62E}\u{630}\u{636}\u{638}\u{63A}\u{628}\u{62C}\u{62F}\u{648}\u{632}\u{62D}\
+    \u{637}\u{64A}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{631}\u{634}\
+    \u{62A}\u{62B}\u{62E}\u{630}\u{636}\u{638}\u{63A}\u{30}\u{2E}\u{30}\u{2C}\u{31}\
+    \u{2C}\u{32}\u{2C}\u{33}\u{2C}\u{34}\u{2C}\u{35}\u{2C}\u{36}\u{2C}\u{37}\
+    \u{2C}\u{38}\u{2C}\u{39}\u{2C}\u{28}\u{41}\u{29}\u{28}\u{42}\u{29}\u{28}\
+    \u{43}\u{29}\u{28}\u{44}\u{29}\u{28}\u{45}\u{29}\u{28}\u{46}\u{29}\u{28}\
+    \u{47}\u{29}\u{28}\u{48}\u{29}\u{28}\u{49}\u{29}\u{28}\u{4A}\u{29}\u{28}\
+    \u{4B}\u{29}\u{28}\u{4C}\u{29}\u{28}\u{4D}\u{29}\u{28}\u{4E}\u{29}\u{28}\
+    \u{4F}\u{29}\u{28}\u{50}\u{29}\u{28}\u{51}\u{29}\u{28}\u{52}\u{29}\u{28}\
+    \u{53}\u{29}\u{28}\u{54}\u{29}\u{28}\u{55}\u{29}\u{28}\u{56}\u{29}\u{28}\
+    \u{57}\u{29}\u{28}\u{58}\u{29}\u{28}\u{59}\u{29}\u{28}\u{5A}\u{29}\u{3014}\
+    \u{53}\u{3015}\u{43}\u{52}\u{43}\u{44}\u{57}\u{5A}\u{41}\u{42}\u{43}\u{44}\
+    \u{45}\u{46}\u{47}\u{48}\u{49}\u{4A}\u{4B}\u{4C}\u{4D}\u{4E}\u{4F}\u{50}\
+    \u{51}\u{52}\u{53}\u{54}\u{55}\u{56}\u{57}\u{58}\u{59}\u{5A}\u{48}\u{56}\
+    \u{4D}\u{56}\u{53}\u{44}\u{53}\u{53}\u{50}\u{50}\u{56}\u{57}\u{43}\u{4D}\
+    \u{43}\u{4D}\u{44}\u{4D}\u{52}\u{44}\u{4A}\u{307B}\u{304B}\u{30B3}\u{30B3}\u{30B5}\
+    \u{624B}\u{5B57}\u{53CC}\u{30C6}\u{3099}\u{4E8C}\u{591A}\u{89E3}\u{5929}\u{4EA4}\u{6620}\u{7121}\
+    \u{6599}\u{524D}\u{5F8C}\u{518D}\u{65B0}\u{521D}\u{7D42}\u{751F}\u{8CA9}\u{58F0}\u{5439}\u{6F14}\
+    \u{6295}\u{6355}\u{4E00}\u{4E09}\u{904A}\u{5DE6}\u{4E2D}\u{53F3}\u{6307}\u{8D70}\u{6253}\u{7981}\
+    \u{7A7A}\u{5408}\u{6E80}\u{6709}\u{6708}\u{7533}\u{5272}\u{55B6}\u{914D}\u{3014}\u{672C}\u{3015}\
+    \u{3014}\u{4E09}\u{3015}\u{3014}\u{4E8C}\u{3015}\u{3014}\u{5B89}\u{3015}\u{3014}\u{70B9}\u{3015}\
+    \u{3014}\u{6253}\u{3015}\u{3014}\u{76D7}\u{3015}\u{3014}\u{52DD}\u{3015}\u{3014}\u{6557}\u{3015}\
+    \u{5F97}\u{53EF}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\
+";
+
+pub(crate) static COMPOSITIONS: [(char, char, char); 961] = [
+    ('\u{3C}', '\u{338}', '\u{226E}'), ('\u{3D}', '\u{338}', '\u{2260}'), ('\u{3E}', '\u{338}', '\u{226F}'),
+    ('\u{41}', '\u{300}', '\u{C0}'), ('\u{41}', '\u{301}', '\u{C1}'), ('\u{41}', '\u{302}', '\u{C2}'),
+    ('\u{41}', '\u{303}', '\u{C3}'), ('\u{41}', '\u{304}', '\u{100}'), ('\u{41}', '\u{306}', '\u{102}'),
+    ('\u{41}', '\u{307}', '\u{226}'), ('\u{41}', '\u{308}', '\u{C4}'), ('\u{41}', '\u{309}', '\u{1EA2}'),
+    ('\u{41}', '\u{30A}', '\u{C5}'), ('\u{41}', '\u{30C}', '\u{1CD}'), ('\u{41}', '\u{30F}', '\u{200}'),
+    ('\u{41}', '\u{311}', '\u{202}'), ('\u{41}', '\u{323}', '\u{1EA0}'), ('\u{41}', '\u{325}', '\u{1E00}'),
+    ('\u{41}', '\u{328}', '\u{104}'), ('\u{42}', '\u{307}', '\u{1E02}'), ('\u{42}', '\u{323}', '\u{1E04}'),
+    ('\u{42}', '\u{331}', '\u{1E06}'), ('\u{43}', '\u{301}', '\u{106}'), ('\u{43}', '\u{302}', '\u{108}'),
+    ('\u{43}', '\u{307}', '\u{10A}'), ('\u{43}', '\u{30C}', '\u{10C}'), ('\u{43}', '\u{327}', '\u{C7}'),
+    ('\u{44}', '\u{307}', '\u{1E0A}'), ('\u{44}', '\u{30C}', '\u{10E}'), ('\u{44}', '\u{323}', '\u{1E0C}'),
+    ('\u{44}', '\u{327}', '\u{1E10}'), ('\u{44}', '\u{32D}', '\u{1E12}'), ('\u{44}', '\u{331}', '\u{1E0E}'),
+    ('\u{45}', '\u{300}', '\u{C8}'), ('\u{45}', '\u{301}', '\u{C9}'), ('\u{45}', '\u{302}', '\u{CA}'),
+    ('\u{45}', '\u{303}', '\u{1EBC}'), ('\u{45}', '\u{304}', '\u{112}'), ('\u{45}', '\u{306}', '\u{114}'),
+    ('\u{45}', '\u{307}', '\u{116}'), ('\u{45}', '\u{308}', '\u{CB}'), ('\u{45}', '\u{309}', '\u{1EBA}'),
+    ('\u{45}', '\u{30C}', '\u{11A}'), ('\u{45}', '\u{30F}', '\u{204}'), ('\u{45}', '\u{311}', '\u{206}'),
+    ('\u{45}', '\u{323}', '\u{1EB8}'), ('\u{45}', '\u{327}', '\u{228}'), ('\u{45}', '\u{328}', '\u{118}'),
+    ('\u{45}', '\u{32D}', '\u{1E18}'), ('\u{45}', '\u{330}', '\u{1E1A}'), ('\u{46}', '\u{307}', '\u{1E1E}'),
+    ('\u{47}', '\u{301}', '\u{1F4}'), ('\u{47}', '\u{302}', '\u{11C}'), ('\u{47}', '\u{304}', '\u{1E20}'),
+    ('\u{47}', '\u{306}', '\u{11E}'), ('\u{47}', '\u{307}', '\u{120}'), ('\u{47}', '\u{30C}', '\u{1E6}'),
+    ('\u{47}', '\u{327}', '\u{122}'), ('\u{48}', '\u{302}', '\u{124}'), ('\u{48}', '\u{307}', '\u{1E22}'),
+    ('\u{48}', '\u{308}', '\u{1E26}'), ('\u{48}', '\u{30C}', '\u{21E}'), ('\u{48}', '\u{323}', '\u{1E24}'),
+    ('\u{48}', '\u{327}', '\u{1E28}'), ('\u{48}', '\u{32E}', '\u{1E2A}'), ('\u{49}', '\u{300}', '\u{CC}'),
+    ('\u{49}', '\u{301}', '\u{CD}'), ('\u{49}', '\u{302}', '\u{CE}'), ('\u{49}', '\u{303}', '\u{128}'),
+    ('\u{49}', '\u{304}', '\u{12A}'), ('\u{49}', '\u{306}', '\u{12C}'), ('\u{49}', '\u{307}', '\u{130}'),
+    ('\u{49}', '\u{308}', '\u{CF}'), ('\u{49}', '\u{309}', '\u{1EC8}'), ('\u{49}', '\u{30C}', '\u{1CF}'),
+    ('\u{49}', '\u{30F}', '\u{208}'), ('\u{49}', '\u{311}', '\u{20A}'), ('\u{49}', '\u{323}', '\u{1ECA}'),
+    ('\u{49}', '\u{328}', '\u{12E}'), ('\u{49}', '\u{330}', '\u{1E2C}'), ('\u{4A}', '\u{302}', '\u{134}'),
+    ('\u{4B}', '\u{301}', '\u{1E30}'), ('\u{4B}', '\u{30C}', '\u{1E8}'), ('\u{4B}', '\u{323}', '\u{1E32}'),
+    ('\u{4B}', '\u{327}', '\u{136}'), ('\u{4B}', '\u{331}', '\u{1E34}'), ('\u{4C}', '\u{301}', '\u{139}'),
+    ('\u{4C}', '\u{30C}', '\u{13D}'), ('\u{4C}', '\u{323}', '\u{1E36}'), ('\u{4C}', '\u{327}', '\u{13B}'),
+    ('\u{4C}', '\u{32D}', '\u{1E3C}'), ('\u{4C}', '\u{331}', '\u{1E3A}'), ('\u{4D}', '\u{301}', '\u{1E3E}'),
+    ('\u{4D}', '\u{307}', '\u{1E40}'), ('\u{4D}', '\u{323}', '\u{1E42}'), ('\u{4E}', '\u{300}', '\u{1F8}'),
+    ('\u{4E}', '\u{301}', '\u{143}'), ('\u{4E}', '\u{303}', '\u{D1}'), ('\u{4E}', '\u{307}', '\u{1E44}'),
+    ('\u{4E}', '\u{30C}', '\u{147}'), ('\u{4E}', '\u{323}', '\u{1E46}'), ('\u{4E}', '\u{327}', '\u{145}'),
+    ('\u{4E}', '\u{32D}', '\u{1E4A}'), ('\u{4E}', '\u{331}', '\u{1E48}'), ('\u{4F}', '\u{300}', '\u{D2}'),
+    ('\u{4F}', '\u{301}', '\u{D3}'), ('\u{4F}', '\u{302}', '\u{D4}'), ('\u{4F}', '\u{303}', '\u{D5}'),
+    ('\u{4F}', '\u{304}', '\u{14C}'), ('\u{4F}', '\u{306}', '\u{14E}'), ('\u{4F}', '\u{307}', '\u{22E}'),
+    ('\u{4F}', '\u{308}', '\u{D6}'), ('\u{4F}', '\u{309}', '\u{1ECE}'), ('\u{4F}', '\u{30B}', '\u{150}'),
+    ('\u{4F}', '\u{30C}', '\u{1D1}'), ('\u{4F}', '\u{30F}', '\u{20C}'), ('\u{4F}', '\u{311}', '\u{20E}'),
+    ('\u{4F}', '\u{31B}', '\u{1A0}'), ('\u{4F}', '\u{323}', '\u{1ECC}'), ('\u{4F}', '\u{328}', '\u{1EA}'),
+    ('\u{50}', '\u{301}', '\u{1E54}'), ('\u{50}', '\u{307}', '\u{1E56}'), ('\u{52}', '\u{301}', '\u{154}'),
+    ('\u{52}', '\u{307}', '\u{1E58}'), ('\u{52}', '\u{30C}', '\u{158}'), ('\u{52}', '\u{30F}', '\u{210}'),
+    ('\u{52}', '\u{311}', '\u{212}'), ('\u{52}', '\u{323}', '\u{1E5A}'), ('\u{52}', '\u{327}', '\u{156}'),
+    ('\u{52}', '\u{331}', '\u{1E5E}'), ('\u{53}', '\u{301}', '\u{15A}'), ('\u{53}', '\u{302}', '\u{15C}'),
+    ('\u{53}', '\u{307}', '\u{1E60}'), ('\u{53}', '\u{30C}', '\u{160}'), ('\u{53}', '\u{323}', '\u{1E62}'),
+    ('\u{53}', '\u{326}', '\u{218}'), ('\u{53}', '\u{327}', '\u{15E}'), ('\u{54}', '\u{307}', '\u{1E6A}'),
+    ('\u{54}', '\u{30C}', '\u{164}'), ('\u{54}', '\u{323}', '\u{1E6C}'), ('\u{54}', '\u{326}', '\u{21A}'),
+    ('\u{54}', '\u{327}', '\u{162}'), ('\u{54}', '\u{32D}', '\u{1E70}'), ('\u{54}', '\u{331}', '\u{1E6E}'),
+    ('\u{55}', '\u{300}', '\u{D9}'), ('\u{55}', '\u{301}', '\u{DA}'), ('\u{55}', '\u{302}', '\u{DB}'),
+    ('\u{55}', '\u{303}', '\u{168}'), ('\u{55}', '\u{304}', '\u{16A}'), ('\u{55}', '\u{306}', '\u{16C}'),
+    ('\u{55}', '\u{308}', '\u{DC}'), ('\u{55}', '\u{309}', '\u{1EE6}'), ('\u{55}', '\u{30A}', '\u{16E}'),
+    ('\u{55}', '\u{30B}', '\u{170}'), ('\u{55}', '\u{30C}', '\u{1D3}'), ('\u{55}', '\u{30F}', '\u{214}'),
+    ('\u{55}', '\u{311}', '\u{216}'), ('\u{55}', '\u{31B}', '\u{1AF}'), ('\u{55}', '\u{323}', '\u{1EE4}'),
+    ('\u{55}', '\u{324}', '\u{1E72}'), ('\u{55}', '\u{328}', '\u{172}'), ('\u{55}', '\u{32D}', '\u{1E76}'),
+    ('\u{55}', '\u{330}', '\u{1E74}'), ('\u{56}', '\u{303}', '\u{1E7C}'), ('\u{56}', '\u{323}', '\u{1E7E}'),
+    ('\u{57}', '\u{300}', '\u{1E80}'), ('\u{57}', '\u{301}', '\u{1E82}'), ('\u{57}', '\u{302}', '\u{174}'),
+    ('\u{57}', '\u{307}', '\u{1E86}'), ('\u{57}', '\u{308}', '\u{1E84}'), ('\u{57}', '\u{323}', '\u{1E88}'),
+    ('\u{58}', '\u{307}', '\u{1E8A}'), ('\u{58}', '\u{308}', '\u{1E8C}'), ('\u{59}', '\u{300}', '\u{1EF2}'),
+    ('\u{59}', '\u{301}', '\u{DD}'), ('\u{59}', '\u{302}', '\u{176}'), ('\u{59}', '\u{303}', '\u{1EF8}'),
+    ('\u{59}', '\u{304}', '\u{232}'), ('\u{59}', '\u{307}', '\u{1E8E}'), ('\u{59}', '\u{308}', '\u{178}'),
+    ('\u{59}', '\u{309}', '\u{1EF6}'), ('\u{59}', '\u{323}', '\u{1EF4}'), ('\u{5A}', '\u{301}', '\u{179}'),
+    ('\u{5A}', '\u{302}', '\u{1E90}'), ('\u{5A}', '\u{307}', '\u{17B}'), ('\u{5A}', '\u{30C}', '\u{17D}'),
+    ('\u{5A}', '\u{323}', '\u{1E92}'), ('\u{5A}', '\u{331}', '\u{1E94}'), ('\u{61}', '\u{300}', '\u{E0}'),
+    ('\u{61}', '\u{301}', '\u{E1}'), ('\u{61}', '\u{302}', '\u{E2}'), ('\u{61}', '\u{303}', '\u{E3}'),
+    ('\u{61}', '\u{304}', '\u{101}'), ('\u{61}', '\u{306}', '\u{103}'), ('\u{61}', '\u{307}', '\u{227}'),
+    ('\u{61}', '\u{308}', '\u{E4}'), ('\u{61}', '\u{309}', '\u{1EA3}'), ('\u{61}', '\u{30A}', '\u{E5}'),
+    ('\u{61}', '\u{30C}', '\u{1CE}'), ('\u{61}', '\u{30F}', '\u{201}'), ('\u{61}', '\u{311}', '\u{203}'),
+    ('\u{61}', '\u{323}', '\u{1EA1}'), ('\u{61}', '\u{325}', '\u{1E01}'), ('\u{61}', '\u{328}', '\u{105}'),
+    ('\u{62}', '\u{307}', '\u{1E03}'), ('\u{62}', '\u{323}', '\u{1E05}'), ('\u{62}', '\u{331}', '\u{1E07}'),
+    ('\u{63}', '\u{301}', '\u{107}'), ('\u{63}', '\u{302}', '\u{109}'), ('\u{63}', '\u{307}', '\u{10B}'),
+    ('\u{63}', '\u{30C}', '\u{10D}'), ('\u{63}', '\u{327}', '\u{E7}'), ('\u{64}', '\u{307}', '\u{1E0B}'),
+    ('\u{64}', '\u{30C}', '\u{10F}'), ('\u{64}', '\u{323}', '\u{1E0D}'), ('\u{64}', '\u{327}', '\u{1E11}'),
+    ('\u{64}', '\u{32D}', '\u{1E13}'), ('\u{64}', '\u{331}', '\u{1E0F}'), ('\u{65}', '\u{300}', '\u{E8}'),
+    ('\u{65}', '\u{301}', '\u{E9}'), ('\u{65}', '\u{302}', '\u{EA}'), ('\u{65}', '\u{303}', '\u{1EBD}'),
+    ('\u{65}', '\u{304}', '\u{113}'), ('\u{65}', '\u{306}', '\u{115}'), ('\u{65}', '\u{307}', '\u{117}'),
+    ('\u{65}', '\u{308}', '\u{EB}'), ('\u{65}', '\u{309}', '\u{1EBB}'), ('\u{65}', '\u{30C}', '\u{11B}'),
+    ('\u{65}', '\u{30F}', '\u{205}'), ('\u{65}', '\u{311}', '\u{207}'), ('\u{65}', '\u{323}', '\u{1EB9}'),
+    ('\u{65}', '\u{327}', '\u{229}'), ('\u{65}', '\u{328}', '\u{119}'), ('\u{65}', '\u{32D}', '\u{1E19}'),
+    ('\u{65}', '\u{330}', '\u{1E1B}'), ('\u{66}', '\u{307}', '\u{1E1F}'), ('\u{67}', '\u{301}', '\u{1F5}'),
+    ('\u{67}', '\u{302}', '\u{11D}'), ('\u{67}', '\u{304}', '\u{1E21}'), ('\u{67}', '\u{306}', '\u{11F}'),
+    ('\u{67}', '\u{307}', '\u{121}'), ('\u{67}', '\u{30C}', '\u{1E7}'), ('\u{67}', '\u{327}', '\u{123}'),
+    ('\u{68}', '\u{302}', '\u{125}'), ('\u{68}', '\u{307}', '\u{1E23}'), ('\u{68}', '\u{308}', '\u{1E27}'),
+    ('\u{68}', '\u{30C}', '\u{21F}'), ('\u{68}', '\u{323}', '\u{1E25}'), ('\u{68}', '\u{327}', '\u{1E29}'),
+    ('\u{68}', '\u{32E}', '\u{1E2B}'), ('\u{68}', '\u{331}', '\u{1E96}'), ('\u{69}', '\u{300}', '\u{EC}'),
+    ('\u{69}', '\u{301}', '\u{ED}'), ('\u{69}', '\u{302}', '\u{EE}'), ('\u{69}', '\u{303}', '\u{129}'),
+    ('\u{69}', '\u{304}', '\u{12B}'), ('\u{69}', '\u{306}', '\u{12D}'), ('\u{69}', '\u{308}', '\u{EF}'),
+    ('\u{69}', '\u{309}', '\u{1EC9}'), ('\u{69}', '\u{30C}', '\u{1D0}'), ('\u{69}', '\u{30F}', '\u{209}'),
+    ('\u{69}', '\u{311}', '\u{20B}'), ('\u{69}', '\u{323}', '\u{1ECB}'), ('\u{69}', '\u{328}', '\u{12F}'),
+    ('\u{69}', '\u{330}', '\u{1E2D}'), ('\u{6A}', '\u{302}', '\u{135}'), ('\u{6A}', '\u{30C}', '\u{1F0}'),
+    ('\u{6B}', '\u{301}', '\u{1E31}'), ('\u{6B}', '\u{30C}', '\u{1E9}'), ('\u{6B}', '\u{323}', '\u{1E33}'),
+    ('\u{6B}', '\u{327}', '\u{137}'), ('\u{6B}', '\u{331}', '\u{1E35}'), ('\u{6C}', '\u{301}', '\u{13A}'),
+    ('\u{6C}', '\u{30C}', '\u{13E}'), ('\u{6C}', '\u{323}', '\u{1E37}'), ('\u{6C}', '\u{327}', '\u{13C}'),
+    ('\u{6C}', '\u{32D}', '\u{1E3D}'), ('\u{6C}', '\u{331}', '\u{1E3B}'), ('\u{6D}', '\u{301}', '\u{1E3F}'),
+    ('\u{6D}', '\u{307}', '\u{1E41}'), ('\u{6D}', '\u{323}', '\u{1E43}'), ('\u{6E}', '\u{300}', '\u{1F9}'),
+    ('\u{6E}', '\u{301}', '\u{144}'), ('\u{6E}', '\u{303}', '\u{F1}'), ('\u{6E}', '\u{307}', '\u{1E45}'),
+    ('\u{6E}', '\u{30C}', '\u{148}'), ('\u{6E}', '\u{323}', '\u{1E47}'), ('\u{6E}', '\u{327}', '\u{146}'),
+    ('\u{6E}', '\u{32D}', '\u{1E4B}'), ('\u{6E}', '\u{331}', '\u{1E49}'), ('\u{6F}', '\u{300}', '\u{F2}'),
+    ('\u{6F}', '\u{301}', '\u{F3}'), ('\u{6F}', '\u{302}', '\u{F4}'), ('\u{6F}', '\u{303}', '\u{F5}'),
+    ('\u{6F}', '\u{304}', '\u{14D}'), ('\u{6F}', '\u{306}', '\u{14F}'), ('\u{6F}', '\u{307}', '\u{22F}'),
+    ('\u{6F}', '\u{308}', '\u{F6}'), ('\u{6F}', '\u{309}', '\u{1ECF}'), ('\u{6F}', '\u{30B}', '\u{151}'),
+    ('\u{6F}', '\u{30C}', '\u{1D2}'), ('\u{6F}', '\u{30F}', '\u{20D}'), ('\u{6F}', '\u{311}', '\u{20F}'),
+    ('\u{6F}', '\u{31B}', '\u{1A1}'), ('\u{6F}', '\u{323}', '\u{1ECD}'), ('\u{6F}', '\u{328}', '\u{1EB}'),
+    ('\u{70}', '\u{301}', '\u{1E55}'), ('\u{70}', '\u{307}', '\u{1E57}'), ('\u{72}', '\u{301}', '\u{155}'),
+    ('\u{72}', '\u{307}', '\u{1E59}'), ('\u{72}', '\u{30C}', '\u{159}'), ('\u{72}', '\u{30F}', '\u{211}'),
+    ('\u{72}', '\u{311}', '\u{213}'), ('\u{72}', '\u{323}', '\u{1E5B}'), ('\u{72}', '\u{327}', '\u{157}'),
+    ('\u{72}', '\u{331}', '\u{1E5F}'), ('\u{73}', '\u{301}', '\u{15B}'), ('\u{73}', '\u{302}', '\u{15D}'),
+    ('\u{73}', '\u{307}', '\u{1E61}'), ('\u{73}', '\u{30C}', '\u{161}'), ('\u{73}', '\u{323}', '\u{1E63}'),
+    ('\u{73}', '\u{326}', '\u{219}'), ('\u{73}', '\u{327}', '\u{15F}'), ('\u{74}', '\u{307}', '\u{1E6B}'),
+    ('\u{74}', '\u{308}', '\u{1E97}'), ('\u{74}', '\u{30C}', '\u{165}'), ('\u{74}', '\u{323}', '\u{1E6D}'),
+    ('\u{74}', '\u{326}', '\u{21B}'), ('\u{74}', '\u{327}', '\u{163}'), ('\u{74}', '\u{32D}', '\u{1E71}'),
+    ('\u{74}', '\u{331}', '\u{1E6F}'), ('\u{75}', '\u{300}', '\u{F9}'), ('\u{75}', '\u{301}', '\u{FA}'),
+    ('\u{75}', '\u{302}', '\u{FB}'), ('\u{75}', '\u{303}', '\u{169}'), ('\u{75}', '\u{304}', '\u{16B}'),
+    ('\u{75}', '\u{306}', '\u{16D}'), ('\u{75}', '\u{308}', '\u{FC}'), ('\u{75}', '\u{309}', '\u{1EE7}'),
+    ('\u{75}', '\u{30A}', '\u{16F}'), ('\u{75}', '\u{30B}', '\u{171}'), ('\u{75}', '\u{30C}', '\u{1D4}'),
+    ('\u{75}', '\u{30F}', '\u{215}'), ('\u{75}', '\u{311}', '\u{217}'), ('\u{75}', '\u{31B}', '\u{1B0}'),
+    ('\u{75}', '\u{323}', '\u{1EE5}'), ('\u{75}', '\u{324}', '\u{1E73}'), ('\u{75}', '\u{328}', '\u{173}'),
+    ('\u{75}', '\u{32D}', '\u{1E77}'), ('\u{75}', '\u{330}', '\u{1E75}'), ('\u{76}', '\u{303}', '\u{1E7D}'),
+    ('\u{76}', '\u{323}', '\u{1E7F}'), ('\u{77}', '\u{300}', '\u{1E81}'), ('\u{77}', '\u{301}', '\u{1E83}'),
+    ('\u{77}', '\u{302}', '\u{175}'), ('\u{77}', '\u{307}', '\u{1E87}'), ('\u{77}', '\u{308}', '\u{1E85}'),
+    ('\u{77}', '\u{30A}', '\u{1E98}'), ('\u{77}', '\u{323}', '\u{1E89}'), ('\u{78}', '\u{307}', '\u{1E8B}'),
+    ('\u{78}', '\u{308}', '\u{1E8D}'), ('\u{79}', '\u{300}', '\u{1EF3}'), ('\u{79}', '\u{301}', '\u{FD}'),
+    ('\u{79}', '\u{302}', '\u{177}'), ('\u{79}', '\u{303}', '\u{1EF9}'), ('\u{79}', '\u{304}', '\u{233}'),
+    ('\u{79}', '\u{307}', '\u{1E8F}'), ('\u{79}', '\u{308}', '\u{FF}'), ('\u{79}', '\u{309}', '\u{1EF7}'),
+    ('\u{79}', '\u{30A}', '\u{1E99}'), ('\u{79}', '\u{323}', '\u{1EF5}'), ('\u{7A}', '\u{301}', '\u{17A}'),
+    ('\u{7A}', '\u{302}', '\u{1E91}'), ('\u{7A}', '\u{307}', '\u{17C}'), ('\u{7A}', '\u{30C}', '\u{17E}'),
+    ('\u{7A}', '\u{323}', '\u{1E93}'), ('\u{7A}', '\u{331}', '\u{1E95}'), ('\u{A8}', '\u{300}', '\u{1FED}'),
+    ('\u{A8}', '\u{301}', '\u{385}'), ('\u{A8}', '\u{342}', '\u{1FC1}'), ('\u{C2}', '\u{300}', '\u{1EA6}'),
+    ('\u{C2}', '\u{301}', '\u{1EA4}'), ('\u{C2}', '\u{303}', '\u{1EAA}'), ('\u{C2}', '\u{309}', '\u{1EA8}'),
+    ('\u{C4}', '\u{304}', '\u{1DE}'), ('\u{C5}', '\u{301}', '\u{1FA}'), ('\u{C6}', '\u{301}', '\u{1FC}'),
+    ('\u{C6}', '\u{304}', '\u{1E2}'), ('\u{C7}', '\u{301}', '\u{1E08}'), ('\u{CA}', '\u{300}', '\u{1EC0}'),
+    ('\u{CA}', '\u{301}', '\u{1EBE}'), ('\u{CA}', '\u{303}', '\u{1EC4}'), ('\u{CA}', '\u{309}', '\u{1EC2}'),
+    ('\u{CF}', '\u{301}', '\u{1E2E}'), ('\u{D4}', '\u{300}', '\u{1ED2}'), ('\u{D4}', '\u{301}', '\u{1ED0}'),
+    ('\u{D4}', '\u{303}', '\u{1ED6}'), ('\u{D4}', '\u{309}', '\u{1ED4}'), ('\u{D5}', '\u{301}', '\u{1E4C}'),
+    ('\u{D5}', '\u{304}', '\u{22C}'), ('\u{D5}', '\u{308}', '\u{1E4E}'), ('\u{D6}', '\u{304}', '\u{22A}'),
+    ('\u{D8}', '\u{301}', '\u{1FE}'), ('\u{DC}', '\u{300}', '\u{1DB}'), ('\u{DC}', '\u{301}', '\u{1D7}'),
+    ('\u{DC}', '\u{304}', '\u{1D5}'), ('\u{DC}', '\u{30C}', '\u{1D9}'), ('\u{E2}', '\u{300}', '\u{1EA7}'),
+    ('\u{E2}', '\u{301}', '\u{1EA5}'), ('\u{E2}', '\u{303}', '\u{1EAB}'), ('\u{E2}', '\u{309}', '\u{1EA9}'),
+    ('\u{E4}', '\u{304}', '\u{1DF}'), ('\u{E5}', '\u{301}', '\u{1FB}'), ('\u{E6}', '\u{301}', '\u{1FD}'),
+    ('\u{E6}', '\u{304}', '\u{1E3}'), ('\u{E7}', '\u{301}', '\u{1E09}'), ('\u{EA}', '\u{300}', '\u{1EC1}'),
+    ('\u{EA}', '\u{301}', '\u{1EBF}'), ('\u{EA}', '\u{303}', '\u{1EC5}'), ('\u{EA}', '\u{309}', '\u{1EC3}'),
+    ('\u{EF}', '\u{301}', '\u{1E2F}'), ('\u{F4}', '\u{300}', '\u{1ED3}'), ('\u{F4}', '\u{301}', '\u{1ED1}'),
+    ('\u{F4}', '\u{303}', '\u{1ED7}'), ('\u{F4}', '\u{309}', '\u{1ED5}'), ('\u{F5}', '\u{301}', '\u{1E4D}'),
+    ('\u{F5}', '\u{304}', '\u{22D}'), ('\u{F5}', '\u{308}', '\u{1E4F}'), ('\u{F6}', '\u{304}', '\u{22B}'),
+    ('\u{F8}', '\u{301}', '\u{1FF}'), ('\u{FC}', '\u{300}', '\u{1DC}'), ('\u{FC}', '\u{301}', '\u{1D8}'),
+    ('\u{FC}', '\u{304}', '\u{1D6}'), ('\u{FC}', '\u{30C}', '\u{1DA}'), ('\u{102}', '\u{300}', '\u{1EB0}'),
+    ('\u{102}', '\u{301}', '\u{1EAE}'), ('\u{102}', '\u{303}', '\u{1EB4}'), ('\u{102}', '\u{309}', '\u{1EB2}'),
+    ('\u{103}', '\u{300}', '\u{1EB1}'), ('\u{103}', '\u{301}', '\u{1EAF}'), ('\u{103}', '\u{303}', '\u{1EB5}'),
+    ('\u{103}', '\u{309}', '\u{1EB3}'), ('\u{112}', '\u{300}', '\u{1E14}'), ('\u{112}', '\u{301}', '\u{1E16}'),
+    ('\u{113}', '\u{300}', '\u{1E15}'), ('\u{113}', '\u{301}', '\u{1E17}'), ('\u{14C}', '\u{300}', '\u{1E50}'),
+    ('\u{14C}', '\u{301}', '\u{1E52}'), ('\u{14D}', '\u{300}', '\u{1E51}'), ('\u{14D}', '\u{301}', '\u{1E53}'),
+    ('\u{15A}', '\u{307}', '\u{1E64}'), ('\u{15B}', '\u{307}', '\u{1E65}'), ('\u{160}', '\u{307}', '\u{1E66}'),
+    ('\u{161}', '\u{307}', '\u{1E67}'), ('\u{168}', '\u{301}', '\u{1E78}'), ('\u{169}', '\u{301}', '\u{1E79}'),
+    ('\u{16A}', '\u{308}', '\u{1E7A}'), ('\u{16B}', '\u{308}', '\u{1E7B}'), ('\u{17F}', '\u{307}', '\u{1E9B}'),
+    ('\u{1A0}', '\u{300}', '\u{1EDC}'), ('\u{1A0}', '\u{301}', '\u{1EDA}'), ('\u{1A0}', '\u{303}', '\u{1EE0}'),
+    ('\u{1A0}', '\u{309}', '\u{1EDE}'), ('\u{1A0}', '\u{323}', '\u{1EE2}'), ('\u{1A1}', '\u{300}', '\u{1EDD}'),
+    ('\u{1A1}', '\u{301}', '\u{1EDB}'), ('\u{1A1}', '\u{303}', '\u{1EE1}'), ('\u{1A1}', '\u{309}', '\u{1EDF}'),
+    ('\u{1A1}', '\u{323}', '\u{1EE3}'), ('\u{1AF}', '\u{300}', '\u{1EEA}'), ('\u{1AF}', '\u{301}', '\u{1EE8}'),
+    ('\u{1AF}', '\u{303}', '\u{1EEE}'), ('\u{1AF}', '\u{309}', '\u{1EEC}'), ('\u{1AF}', '\u{323}', '\u{1EF0}'),
+    ('\u{1B0}', '\u{300}', '\u{1EEB}'), ('\u{1B0}', '\u{301}', '\u{1EE9}'), ('\u{1B0}', '\u{303}', '\u{1EEF}'),
+    ('\u{1B0}', '\u{309}', '\u{1EED}'), ('\u{1B0}', '\u{323}', '\u{1EF1}'), ('\u{1B7}', '\u{30C}', '\u{1EE}'),
+    ('\u{1EA}', '\u{304}', '\u{1EC}'), ('\u{1EB}', '\u{304}', '\u{1ED}'), ('\u{226}', '\u{304}', '\u{1E0}'),
+    ('\u{227}', '\u{304}', '\u{1E1}'), ('\u{228}', '\u{306}', '\u{1E1C}'), ('\u{229}', '\u{306}', '\u{1E1D}'),
+    ('\u{22E}', '\u{304}', '\u{230}'), ('\u{22F}', '\u{304}', '\u{231}'), ('\u{292}', '\u{30C}', '\u{1EF}'),
+    ('\u{391}', '\u{300}', '\u{1FBA}'), ('\u{391}', '\u{301}', '\u{386}'), ('\u{391}', '\u{304}', '\u{1FB9}'),
+    ('\u{391}', '\u{306}', '\u{1FB8}'), ('\u{391}', '\u{313}', '\u{1F08}'), ('\u{391}', '\u{314}', '\u{1F09}'),
+    ('\u{391}', '\u{345}', '\u{1FBC}'), ('\u{395}', '\u{300}', '\u{1FC8}'), ('\u{395}', '\u{301}', '\u{388}'),
+    ('\u{395}', '\u{313}', '\u{1F18}'), ('\u{395}', '\u{314}', '\u{1F19}'), ('\u{397}', '\u{300}', '\u{1FCA}'),
+    ('\u{397}', '\u{301}', '\u{389}'), ('\u{397}', '\u{313}', '\u{1F28}'), ('\u{397}', '\u{314}', '\u{1F29}'),
+    ('\u{397}', '\u{345}', '\u{1FCC}'), ('\u{399}', '\u{300}', '\u{1FDA}'), ('\u{399}', '\u{301}', '\u{38A}'),
+    ('\u{399}', '\u{304}', '\u{1FD9}'), ('\u{399}', '\u{306}', '\u{1FD8}'), ('\u{399}', '\u{308}', '\u{3AA}'),
+    ('\u{399}', '\u{313}', '\u{1F38}'), ('\u{399}', '\u{314}', '\u{1F39}'), ('\u{39F}', '\u{300}', '\u{1FF8}'),
+    ('\u{39F}', '\u{301}', '\u{38C}'), ('\u{39F}', '\u{313}', '\u{1F48}'), ('\u{39F}', '\u{314}', '\u{1F49}'),
+    ('\u{3A1}', '\u{314}', '\u{1FEC}'), ('\u{3A5}', '\u{300}', '\u{1FEA}'), ('\u{3A5}', '\u{301}', '\u{38E}'),
+    ('\u{3A5}', '\u{304}', '\u{1FE9}'), ('\u{3A5}', '\u{306}', '\u{1FE8}'), ('\u{3A5}', '\u{308}', '\u{3AB}'),
+    ('\u{3A5}', '\u{314}', '\u{1F59}'), ('\u{3A9}', '\u{300}', '\u{1FFA}'), ('\u{3A9}', '\u{301}', '\u{38F}'),
+    ('\u{3A9}', '\u{313}', '\u{1F68}'), ('\u{3A9}', '\u{314}', '\u{1F69}'), ('\u{3A9}', '\u{345}', '\u{1FFC}'),
+    ('\u{3AC}', '\u{345}', '\u{1FB4}'), ('\u{3AE}', '\u{345}', '\u{1FC4}'), ('\u{3B1}', '\u{300}', '\u{1F70}'),
+    ('\u{3B1}', '\u{301}', '\u{3AC}'), ('\u{3B1}', '\u{304}', '\u{1FB1}'), ('\u{3B1}', '\u{306}', '\u{1FB0}'),
+    ('\u{3B1}', '\u{313}', '\u{1F00}'), ('\u{3B1}', '\u{314}', '\u{1F01}'), ('\u{3B1}', '\u{342}', '\u{1FB6}'),
+    ('\u{3B1}', '\u{345}', '\u{1FB3}'), ('\u{3B5}', '\u{300}', '\u{1F72}'), ('\u{3B5}', '\u{301}', '\u{3AD}'),
+    ('\u{3B5}', '\u{313}', '\u{1F10}'), ('\u{3B5}', '\u{314}', '\u{1F11}'), ('\u{3B7}', '\u{300}', '\u{1F74}'),
+    ('\u{3B7}', '\u{301}', '\u{3AE}'), ('\u{3B7}', '\u{313}', '\u{1F20}'), ('\u{3B7}', '\u{314}', '\u{1F21}'),
+    ('\u{3B7}', '\u{342}', '\u{1FC6}'), ('\u{3B7}', '\u{345}', '\u{1FC3}'), ('\u{3B9}', '\u{300}', '\u{1F76}'),
+    ('\u{3B9}', '\u{301}', '\u{3AF}'), ('\u{3B9}', '\u{304}', '\u{1FD1}'), ('\u{3B9}', '\u{306}', '\u{1FD0}'),
+    ('\u{3B9}', '\u{308}', '\u{3CA}'), ('\u{3B9}', '\u{313}', '\u{1F30}'), ('\u{3B9}', '\u{314}', '\u{1F31}'),
+    ('\u{3B9}', '\u{342}', '\u{1FD6}'), ('\u{3BF}', '\u{300}', '\u{1F78}'), ('\u{3BF}', '\u{301}', '\u{3CC}'),
+    ('\u{3BF}', '\u{313}', '\u{1F40}'), ('\u{3BF}', '\u{314}', '\u{1F41}'), ('\u{3C1}', '\u{313}', '\u{1FE4}'),
+    ('\u{3C1}', '\u{314}', '\u{1FE5}'), ('\u{3C5}', '\u{300}', '\u{1F7A}'), ('\u{3C5}', '\u{301}', '\u{3CD}'),
+    ('\u{3C5}', '\u{304}', '\u{1FE1}'), ('\u{3C5}', '\u{306}', '\u{1FE0}'), ('\u{3C5}', '\u{308}', '\u{3CB}'),
+    ('\u{3C5}', '\u{313}', '\u{1F50}'), ('\u{3C5}', '\u{314}', '\u{1F51}'), ('\u{3C5}', '\u{342}', '\u{1FE6}'),
+    ('\u{3C9}', '\u{300}', '\u{1F7C}'), ('\u{3C9}', '\u{301}', '\u{3CE}'), ('\u{3C9}', '\u{313}', '\u{1F60}'),
+    ('\u{3C9}', '\u{314}', '\u{1F61}'), ('\u{3C9}', '\u{342}', '\u{1FF6}'), ('\u{3C9}', '\u{345}', '\u{1FF3}'),
+    ('\u{3CA}', '\u{300}', '\u{1FD2}'), ('\u{3CA}', '\u{301}', '\u{390}'), ('\u{3CA}', '\u{342}', '\u{1FD7}'),
+    ('\u{3CB}', '\u{300}', '\u{1FE2}'), ('\u{3CB}', '\u{301}', '\u{3B0}'), ('\u{3CB}', '\u{342}', '\u{1FE7}'),
+    ('\u{3CE}', '\u{345}', '\u{1FF4}'), ('\u{3D2}', '\u{301}', '\u{3D3}'), ('\u{3D2}', '\u{308}', '\u{3D4}'),
+    ('\u{406}', '\u{308}', '\u{407}'), ('\u{410}', '\u{306}', '\u{4D0}'), ('\u{410}', '\u{308}', '\u{4D2}'),
+    ('\u{413}', '\u{301}', '\u{403}'), ('\u{415}', '\u{300}', '\u{400}'), ('\u{415}', '\u{306}', '\u{4D6}'),
+    ('\u{415}', '\u{308}', '\u{401}'), ('\u{416}', '\u{306}', '\u{4C1}'), ('\u{416}', '\u{308}', '\u{4DC}'),
+    ('\u{417}', '\u{308}', '\u{4DE}'), ('\u{418}', '\u{300}', '\u{40D}'), ('\u{418}', '\u{304}', '\u{4E2}'),
+    ('\u{418}', '\u{306}', '\u{419}'), ('\u{418}', '\u{308}', '\u{4E4}'), ('\u{41A}', '\u{301}', '\u{40C}'),
+    ('\u{41E}', '\u{308}', '\u{4E6}'), ('\u{423}', '\u{304}', '\u{4EE}'), ('\u{423}', '\u{306}', '\u{40E}'),
+    ('\u{423}', '\u{308}', '\u{4F0}'), ('\u{423}', '\u{30B}', '\u{4F2}'), ('\u{427}', '\u{308}', '\u{4F4}'),
+    ('\u{42B}', '\u{308}', '\u{4F8}'), ('\u{42D}', '\u{308}', '\u{4EC}'), ('\u{430}', '\u{306}', '\u{4D1}'),
+    ('\u{430}', '\u{308}', '\u{4D3}'), ('\u{433}', '\u{301}', '\u{453}'), ('\u{435}', '\u{300}', '\u{450}'),
+    ('\u{435}', '\u{306}', '\u{4D7}'), ('\u{435}', '\u{308}', '\u{451}'), ('\u{436}', '\u{306}', '\u{4C2}'),
+    ('\u{436}', '\u{308}', '\u{4DD}'), ('\u{437}', '\u{308}', '\u{4DF}'), ('\u{438}', '\u{300}', '\u{45D}'),
+    ('\u{438}', '\u{304}', '\u{4E3}'), ('\u{438}', '\u{306}', '\u{439}'), ('\u{438}', '\u{308}', '\u{4E5}'),
+    ('\u{43A}', '\u{301}', '\u{45C}'), ('\u{43E}', '\u{308}', '\u{4E7}'), ('\u{443}', '\u{304}', '\u{4EF}'),
+    ('\u{443}', '\u{306}', '\u{45E}'), ('\u{443}', '\u{308}', '\u{4F1}'), ('\u{443}', '\u{30B}', '\u{4F3}'),
+    ('\u{447}', '\u{308}', '\u{4F5}'), ('\u{44B}', '\u{308}', '\u{4F9}'), ('\u{44D}', '\u{308}', '\u{4ED}'),
+    ('\u{456}', '\u{308}', '\u{457}'), ('\u{474}', '\u{30F}', '\u{476}'), ('\u{475}', '\u{30F}', '\u{477}'),
+    ('\u{4D8}', '\u{308}', '\u{4DA}'), ('\u{4D9}', '\u{308}', '\u{4DB}'), ('\u{4E8}', '\u{308}', '\u{4EA}'),
+    ('\u{4E9}', '\u{308}', '\u{4EB}'), ('\u{627}', '\u{653}', '\u{622}'), ('\u{627}', '\u{654}', '\u{623}'),
+    ('\u{627}', '\u{655}', '\u{625}'), ('\u{648}', '\u{654}', '\u{624}'), ('\u{64A}', '\u{654}', '\u{626}'),
+    ('\u{6C1}', '\u{654}', '\u{6C2}'), ('\u{6D2}', '\u{654}', '\u{6D3}'), ('\u{6D5}', '\u{654}', '\u{6C0}'),
+    ('\u{928}', '\u{93C}', '\u{929}'), ('\u{930}', '\u{93C}', '\u{931}'), ('\u{933}', '\u{93C}', '\u{934}'),
+    ('\u{9C7}', '\u{9BE}', '\u{9CB}'), ('\u{9C7}', '\u{9D7}', '\u{9CC}'), ('\u{B47}', '\u{B3E}', '\u{B4B}'),
+    ('\u{B47}', '\u{B56}', '\u{B48}'), ('\u{B47}', '\u{B57}', '\u{B4C}'), ('\u{B92}', '\u{BD7}', '\u{B94}'),
+    ('\u{BC6}', '\u{BBE}', '\u{BCA}'), ('\u{BC6}', '\u{BD7}', '\u{BCC}'), ('\u{BC7}', '\u{BBE}', '\u{BCB}'),
+    ('\u{C46}', '\u{C56}', '\u{C48}'), ('\u{CBF}', '\u{CD5}', '\u{CC0}'), ('\u{CC6}', '\u{CC2}', '\u{CCA}'),
+    ('\u{CC6}', '\u{CD5}', '\u{CC7}'), ('\u{CC6}', '\u{CD6}', '\u{CC8}'), ('\u{CCA}', '\u{CD5}', '\u{CCB}'),
+    ('\u{D46}', '\u{D3E}', '\u{D4A}'), ('\u{D46}', '\u{D57}', '\u{D4C}'), ('\u{D47}', '\u{D3E}', '\u{D4B}'),
+    ('\u{DD9}', '\u{DCA}', '\u{DDA}'), ('\u{DD9}', '\u{DCF}', '\u{DDC}'), ('\u{DD9}', '\u{DDF}', '\u{DDE}'),
+    ('\u{DDC}', '\u{DCA}', '\u{DDD}'), ('\u{1025}', '\u{102E}', '\u{1026}'), ('\u{1B05}', '\u{1B35}', '\u{1B06}'),
+    ('\u{1B07}', '\u{1B35}', '\u{1B08}'), ('\u{1B09}', '\u{1B35}', '\u{1B0A}'), ('\u{1B0B}', '\u{1B35}', '\u{1B0C}'),
+    ('\u{1B0D}', '\u{1B35}', '\u{1B0E}'), ('\u{1B11}', '\u{1B35}', '\u{1B12}'), ('\u{1B3A}', '\u{1B35}', '\u{1B3B}'),
+    ('\u{1B3C}', '\u{1B35}', '\u{1B3D}'), ('\u{1B3E}', '\u{1B35}', '\u{1B40}'), ('\u{1B3F}', '\u{1B35}', '\u{1B41}'),
+    ('\u{1B42}', '\u{1B35}', '\u{1B43}'), ('\u{1E36}', '\u{304}', '\u{1E38}'), ('\u{1E37}', '\u{304}', '\u{1E39}'),
+    ('\u{1E5A}', '\u{304}', '\u{1E5C}'), ('\u{1E5B}', '\u{304}', '\u{1E5D}'), ('\u{1E62}', '\u{307}', '\u{1E68}'),
+    ('\u{1E63}', '\u{307}', '\u{1E69}'), ('\u{1EA0}', '\u{302}', '\u{1EAC}'), ('\u{1EA0}', '\u{306}', '\u{1EB6}'),
+    ('\u{1EA1}', '\u{302}', '\u{1EAD}'), ('\u{1EA1}', '\u{306}', '\u{1EB7}'), ('\u{1EB8}', '\u{302}', '\u{1EC6}'),
+    ('\u{1EB9}', '\u{302}', '\u{1EC7}'), ('\u{1ECC}', '\u{302}', '\u{1ED8}'), ('\u{1ECD}', '\u{302}', '\u{1ED9}'),
+    ('\u{1F00}', '\u{300}', '\u{1F02}'), ('\u{1F00}', '\u{301}', '\u{1F04}'), ('\u{1F00}', '\u{342}', '\u{1F06}'),
+    ('\u{1F00}', '\u{345}', '\u{1F80}'), ('\u{1F01}', '\u{300}', '\u{1F03}'), ('\u{1F01}', '\u{301}', '\u{1F05}'),
+    ('\u{1F01}', '\u{342}', '\u{1F07}'), ('\u{1F01}', '\u{345}', '\u{1F81}'), ('\u{1F02}', '\u{345}', '\u{1F82}'),
+    ('\u{1F03}', '\u{345}', '\u{1F83}'), ('\u{1F04}', '\u{345}', '\u{1F84}'), ('\u{1F05}', '\u{345}', '\u{1F85}'),
+    ('\u{1F06}', '\u{345}', '\u{1F86}'), ('\u{1F07}', '\u{345}', '\u{1F87}'), ('\u{1F08}', '\u{300}', '\u{1F0A}'),
+    ('\u{1F08}', '\u{301}', '\u{1F0C}'), ('\u{1F08}', '\u{342}', '\u{1F0E}'), ('\u{1F08}', '\u{345}', '\u{1F88}'),
+    ('\u{1F09}', '\u{300}', '\u{1F0B}'), ('\u{1F09}', '\u{301}', '\u{1F0D}'), ('\u{1F09}', '\u{342}', '\u{1F0F}'),
+    ('\u{1F09}', '\u{345}', '\u{1F89}'), ('\u{1F0A}', '\u{345}', '\u{1F8A}'), ('\u{1F0B}', '\u{345}', '\u{1F8B}'),
+    ('\u{1F0C}', '\u{345}', '\u{1F8C}'), ('\u{1F0D}', '\u{345}', '\u{1F8D}'), ('\u{1F0E}', '\u{345}', '\u{1F8E}'),
+    ('\u{1F0F}', '\u{345}', '\u{1F8F}'), ('\u{1F10}', '\u{300}', '\u{1F12}'), ('\u{1F10}', '\u{301}', '\u{1F14}'),
+    ('\u{1F11}', '\u{300}', '\u{1F13}'), ('\u{1F11}', '\u{301}', '\u{1F15}'), ('\u{1F18}', '\u{300}', '\u{1F1A}'),
+    ('\u{1F18}', '\u{301}', '\u{1F1C}'), ('\u{1F19}', '\u{300}', '\u{1F1B}'), ('\u{1F19}', '\u{301}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{300}', '\u{1F22}'), ('\u{1F20}', '\u{301}', '\u{1F24}'), ('\u{1F20}', '\u{342}', '\u{1F26}'),
+    ('\u{1F20}', '\u{345}', '\u{1F90}'), ('\u{1F21}', '\u{300}', '\u{1F23}'), ('\u{1F21}', '\u{301}', '\u{1F25}'),
+    ('\u{1F21}', '\u{342}', '\u{1F27}'), ('\u{1F21}', '\u{345}', '\u{1F91}'), ('\u{1F22}', '\u{345}', '\u{1F92}'),
+    ('\u{1F23}', '\u{345}', '\u{1F93}'), ('\u{1F24}', '\u{345}', '\u{1F94}'), ('\u{1F25}', '\u{345}', '\u{1F95}'),
+    ('\u{1F26}', '\u{345}', '\u{1F96}'), ('\u{1F27}', '\u{345}', '\u{1F97}'), ('\u{1F28}', '\u{300}', '\u{1F2A}'),
+    ('\u{1F28}', '\u{301}', '\u{1F2C}'), ('\u{1F28}', '\u{342}', '\u{1F2E}'), ('\u{1F28}', '\u{345}', '\u{1F98}'),
+    ('\u{1F29}', '\u{300}', '\u{1F2B}'), ('\u{1F29}', '\u{301}', '\u{1F2D}'), ('\u{1F29}', '\u{342}', '\u{1F2F}'),
+    ('\u{1F29}', '\u{345}', '\u{1F99}'), ('\u{1F2A}', '\u{345}', '\u{1F9A}'), ('\u{1F2B}', '\u{345}', '\u{1F9B}'),
+    ('\u{1F2C}', '\u{345}', '\u{1F9C}'), ('\u{1F2D}', '\u{345}', '\u{1F9D}'), ('\u{1F2E}', '\u{345}', '\u{1F9E}'),
+    ('\u{1F2F}', '\u{345}', '\u{1F9F}'), ('\u{1F30}', '\u{300}', '\u{1F32}'), ('\u{1F30}', '\u{301}', '\u{1F34}'),
+    ('\u{1F30}', '\u{342}', '\u{1F36}'), ('\u{1F31}', '\u{300}', '\u{1F33}'), ('\u{1F31}', '\u{301}', '\u{1F35}'),
+    ('\u{1F31}', '\u{342}', '\u{1F37}'), ('\u{1F38}', '\u{300}', '\u{1F3A}'), ('\u{1F38}', '\u{301}', '\u{1F3C}'),
+    ('\u{1F38}', '\u{342}', '\u{1F3E}'), ('\u{1F39}', '\u{300}', '\u{1F3B}'), ('\u{1F39}', '\u{301}', '\u{1F3D}'),
+    ('\u{1F39}', '\u{342}', '\u{1F3F}'), ('\u{1F40}', '\u{300}', '\u{1F42}'), ('\u{1F40}', '\u{301}', '\u{1F44}'),
+    ('\u{1F41}', '\u{300}', '\u{1F43}'), ('\u{1F41}', '\u{301}', '\u{1F45}'), ('\u{1F48}', '\u{300}', '\u{1F4A}'),
+    ('\u{1F48}', '\u{301}', '\u{1F4C}'), ('\u{1F49}', '\u{300}', '\u{1F4B}'), ('\u{1F49}', '\u{301}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{300}', '\u{1F52}'), ('\u{1F50}', '\u{301}', '\u{1F54}'), ('\u{1F50}', '\u{342}', '\u{1F56}'),
+    ('\u{1F51}', '\u{300}', '\u{1F53}'), ('\u{1F51}', '\u{301}', '\u{1F55}'), ('\u{1F51}', '\u{342}', '\u{1F57}'),
+    ('\u{1F59}', '\u{300}', '\u{1F5B}'), ('\u{1F59}', '\u{301}', '\u{1F5D}'), ('\u{1F59}', '\u{342}', '\u{1F5F}'),
+    ('\u{1F60}', '\u{300}', '\u{1F62}'), ('\u{1F60}', '\u{301}', '\u{1F64}'), ('\u{1F60}', '\u{342}', '\u{1F66}'),
+    ('\u{1F60}', '\u{345}', '\u{1FA0}'), ('\u{1F61}', '\u{300}', '\u{1F63}'), ('\u{1F61}', '\u{301}', '\u{1F65}'),
+    ('\u{1F61}', '\u{342}', '\u{1F67}'), ('\u{1F61}', '\u{345}', '\u{1FA1}'), ('\u{1F62}', '\u{345}', '\u{1FA2}'),
+    ('\u{1F63}', '\u{345}', '\u{1FA3}'), ('\u{1F64}', '\u{345}', '\u{1FA4}'), ('\u{1F65}', '\u{345}', '\u{1FA5}'),
+    ('\u{1F66}', '\u{345}', '\u{1FA6}'), ('\u{1F67}', '\u{345}', '\u{1FA7}'), ('\u{1F68}', '\u{300}', '\u{1F6A}'),
+    ('\u{1F68}', '\u{301}', '\u{1F6C}'), ('\u{1F68}', '\u{342}', '\u{1F6E}'), ('\u{1F68}', '\u{345}', '\u{1FA8}'),
+    ('\u{1F69}', '\u{300}', '\u{1F6B}'), ('\u{1F69}', '\u{301}', '\u{1F6D}'), ('\u{1F69}', '\u{342}', '\u{1F6F}'),
+    ('\u{1F69}', '\u{345}', '\u{1FA9}'), ('\u{1F6A}', '\u{345}', '\u{1FAA}'), ('\u{1F6B}', '\u{345}', '\u{1FAB}'),
+    ('\u{1F6C}', '\u{345}', '\u{1FAC}'), ('\u{1F6D}', '\u{345}', '\u{1FAD}'), ('\u{1F6E}', '\u{345}', '\u{1FAE}'),
+    ('\u{1F6F}', '\u{345}', '\u{1FAF}'), ('\u{1F70}', '\u{345}', '\u{1FB2}'), ('\u{1F74}', '\u{345}', '\u{1FC2}'),
+    ('\u{1F7C}', '\u{345}', '\u{1FF2}'), ('\u{1FB6}', '\u{345}', '\u{1FB7}'), ('\u{1FBF}', '\u{300}', '\u{1FCD}'),
+    ('\u{1FBF}', '\u{301}', '\u{1FCE}'), ('\u{1FBF}', '\u{342}', '\u{1FCF}'), ('\u{1FC6}', '\u{345}', '\u{1FC7}'),
+    ('\u{1FF6}', '\u{345}', '\u{1FF7}'), ('\u{1FFE}', '\u{300}', '\u{1FDD}'), ('\u{1FFE}', '\u{301}', '\u{1FDE}'),
+    ('\u{1FFE}', '\u{342}', '\u{1FDF}'), ('\u{2190}', '\u{338}', '\u{219A}'), ('\u{2192}', '\u{338}', '\u{219B}'),
+    ('\u{2194}', '\u{338}', '\u{21AE}'), ('\u{21D0}', '\u{338}', '\u{21CD}'), ('\u{21D2}', '\u{338}', '\u{21CF}'),
+    ('\u{21D4}', '\u{338}', '\u{21CE}'), ('\u{2203}', '\u{338}', '\u{2204}'), ('\u{2208}', '\u{338}', '\u{2209}'),
+    ('\u{220B}', '\u{338}', '\u{220C}'), ('\u{2223}', '\u{338}', '\u{2224}'), ('\u{2225}', '\u{338}', '\u{2226}'),
+    ('\u{223C}', '\u{338}', '\u{2241}'), ('\u{2243}', '\u{338}', '\u{2244}'), ('\u{2245}', '\u{338}', '\u{2247}'),
+    ('\u{2248}', '\u{338}', '\u{2249}'), ('\u{224D}', '\u{338}', '\u{226D}'), ('\u{2261}', '\u{338}', '\u{2262}'),
+    ('\u{2264}', '\u{338}', '\u{2270}'), ('\u{2265}', '\u{338}', '\u{2271}'), ('\u{2272}', '\u{338}', '\u{2274}'),
+    ('\u{2273}', '\u{338}', '\u{2275}'), ('\u{2276}', '\u{338}', '\u{2278}'), ('\u{2277}', '\u{338}', '\u{2279}'),
+    ('\u{227A}', '\u{338}', '\u{2280}'), ('\u{227B}', '\u{338}', '\u{2281}'), ('\u{227C}', '\u{338}', '\u{22E0}'),
+    ('\u{227D}', '\u{338}', '\u{22E1}'), ('\u{2282}', '\u{338}', '\u{2284}'), ('\u{2283}', '\u{338}', '\u{2285}'),
+    ('\u{2286}', '\u{338}', '\u{2288}'), ('\u{2287}', '\u{338}', '\u{2289}'), ('\u{2291}', '\u{338}', '\u{22E2}'),
+    ('\u{2292}', '\u{338}', '\u{22E3}'), ('\u{22A2}', '\u{338}', '\u{22AC}'), ('\u{22A8}', '\u{338}', '\u{22AD}'),
+    ('\u{22A9}', '\u{338}', '\u{22AE}'), ('\u{22AB}', '\u{338}', '\u{22AF}'), ('\u{22B2}', '\u{338}', '\u{22EA}'),
+    ('\u{22B3}', '\u{338}', '\u{22EB}'), ('\u{22B4}', '\u{338}', '\u{22EC}'), ('\u{22B5}', '\u{338}', '\u{22ED}'),
+    ('\u{3046}', '\u{3099}', '\u{3094}'), ('\u{304B}', '\u{3099}', '\u{304C}'), ('\u{304D}', '\u{3099}', '\u{304E}'),
+    ('\u{304F}', '\u{3099}', '\u{3050}'), ('\u{3051}', '\u{3099}', '\u{3052}'), ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'), ('\u{3057}', '\u{3099}', '\u{3058}'), ('\u{3059}', '\u{3099}', '\u{305A}'),
+    ('\u{305B}', '\u{3099}', '\u{305C}'), ('\u{305D}', '\u{3099}', '\u{305E}'), ('\u{305F}', '\u{3099}', '\u{3060}'),
+    ('\u{3061}', '\u{3099}', '\u{3062}'), ('\u{3064}', '\u{3099}', '\u{3065}'), ('\u{3066}', '\u{3099}', '\u{3067}'),
+    ('\u{3068}', '\u{3099}', '\u{3069}'), ('\u{306F}', '\u{3099}', '\u{3070}'), ('\u{306F}', '\u{309A}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'), ('\u{3072}', '\u{309A}', '\u{3074}'), ('\u{3075}', '\u{3099}', '\u{3076}'),
+    ('\u{3075}', '\u{309A}', '\u{3077}'), ('\u{3078}', '\u{3099}', '\u{3079}'), ('\u{3078}', '\u{309A}', '\u{307A}'),
+    ('\u{307B}', '\u{3099}', '\u{307C}'), ('\u{307B}', '\u{309A}', '\u{307D}'), ('\u{309D}', '\u{3099}', '\u{309E}'),
+    ('\u{30A6}', '\u{3099}', '\u{30F4}'), ('\u{30AB}', '\u{3099}', '\u{30AC}'), ('\u{30AD}', '\u{3099}', '\u{30AE}'),
+    ('\u{30AF}', '\u{3099}', '\u{30B0}'), ('\u{30B1}', '\u{3099}', '\u{30B2}'), ('\u{30B3}', '\u{3099}', '\u{30B4}'),
+    ('\u{30B5}', '\u{3099}', '\u{30B6}'), ('\u{30B7}', '\u{3099}', '\u{30B8}'), ('\u{30B9}', '\u{3099}', '\u{30BA}'),
+    ('\u{30BB}', '\u{3099}', '\u{30BC}'), ('\u{30BD}', '\u{3099}', '\u{30BE}'), ('\u{30BF}', '\u{3099}', '\u{30C0}'),
+    ('\u{30C1}', '\u{3099}', '\u{30C2}'), ('\u{30C4}', '\u{3099}', '\u{30C5}'), ('\u{30C6}', '\u{3099}', '\u{30C7}'),
+    ('\u{30C8}', '\u{3099}', '\u{30C9}'), ('\u{30CF}', '\u{3099}', '\u{30D0}'), ('\u{30CF}', '\u{309A}', '\u{30D1}'),
+    ('\u{30D2}', '\u{3099}', '\u{30D3}'), ('\u{30D2}', '\u{309A}', '\u{30D4}'), ('\u{30D5}', '\u{3099}', '\u{30D6}'),
+    ('\u{30D5}', '\u{309A}', '\u{30D7}'), ('\u{30D8}', '\u{3099}', '\u{30D9}'), ('\u{30D8}', '\u{309A}', '\u{30DA}'),
+    ('\u{30DB}', '\u{3099}', '\u{30DC}'), ('\u{30DB}', '\u{309A}', '\u{30DD}'), ('\u{30EF}', '\u{3099}', '\u{30F7}'),
+    ('\u{30F0}', '\u{3099}', '\u{30F8}'), ('\u{30F1}', '\u{3099}', '\u{30F9}'), ('\u{30F2}', '\u{3099}', '\u{30FA}'),
+    ('\u{30FD}', '\u{3099}', '\u{30FE}'), ('\u{105D2}', '\u{307}', '\u{105C9}'), ('\u{105DA}', '\u{307}', '\u{105E4}'),
+    ('\u{11099}', '\u{110BA}', '\u{1109A}'), ('\u{1109B}', '\u{110BA}', '\u{1109C}'), ('\u{110A5}', '\u{110BA}', '\u{110AB}'),
+    ('\u{11131}', '\u{11127}', '\u{1112E}'), ('\u{11132}', '\u{11127}', '\u{1112F}'), ('\u{11347}', '\u{1133E}', '\u{1134B}'),
+    ('\u{11347}', '\u{11357}', '\u{1134C}'), ('\u{11382}', '\u{113C9}', '\u{11383}'), ('\u{11384}', '\u{113BB}', '\u{11385}'),
+    ('\u{1138B}', '\u{113C2}', '\u{1138E}'), ('\u{11390}', '\u{113C9}', '\u{11391}'), ('\u{113C2}', '\u{113B8}', '\u{113C7}'),
+    ('\u{113C2}', '\u{113C2}', '\u{113C5}'), ('\u{113C2}', '\u{113C9}', '\u{113C8}'), ('\u{114B9}', '\u{114B0}', '\u{114BC}'),
+    ('\u{114B9}', '\u{114BA}', '\u{114BB}'), ('\u{114B9}', '\u{114BD}', '\u{114BE}'), ('\u{115B8}', '\u{115AF}', '\u{115BA}'),
+    ('\u{115B9}', '\u{115AF}', '\u{115BB}'), ('\u{11935}', '\u{11930}', '\u{11938}'), ('\u{1611E}', '\u{1611E}', '\u{16121}'),
+    ('\u{1611E}', '\u{1611F}', '\u{16123}'), ('\u{1611E}', '\u{16120}', '\u{16125}'), ('\u{1611E}', '\u{16129}', '\u{16122}'),
+    ('\u{16121}', '\u{1611F}', '\u{16126}'), ('\u{16121}', '\u{16120}', '\u{16128}'), ('\u{16122}', '\u{1611F}', '\u{16127}'),
+    ('\u{16129}', '\u{1611F}', '\u{16124}'), ('\u{16D63}', '\u{16D67}', '\u{16D69}'), ('\u{16D67}', '\u{16D67}', '\u{16D68}'),
+    ('\u{16D69}', '\u{16D67}', '\u{16D6A}'),
+];
+
+pub(crate) const NFC_NO: u8 = 0x01;
+pub(crate) const NFC_MAYBE: u8 = 0x02;
+pub(crate) const NFKC_NO: u8 = 0x04;
+pub(crate) const NFKC_MAYBE: u8 = 0x08;
+
+pub(crate) static QUICK_CHECK_RUNS: [u32; 619] = [
+    0x00000000, 0x0000A004, 0x0000A100, 0x0000A804, 0x0000A900, 0x0000AA04, 0x0000AB00, 0x0000AF04,
+    0x0000B000, 0x0000B204, 0x0000B600, 0x0000B804, 0x0000BB00, 0x0000BC04, 0x0000BF00, 0x00013204,
+    0x00013400, 0x00013F04, 0x00014100, 0x00014904, 0x00014A00, 0x00017F04, 0x00018000, 0x0001C404,
+    0x0001CD00, 0x0001F104, 0x0001F400, 0x0002B004, 0x0002B900, 0x0002D804, 0x0002DE00, 0x0002E004,
+    0x0002E500, 0x0003000A, 0x00030500, 0x0003060A, 0x00030D00, 0x00030F0A, 0x00031000, 0x0003110A,
+    0x00031200, 0x0003130A, 0x00031500, 0x00031B0A, 0x00031C00, 0x0003230A, 0x00032900, 0x00032D0A,
+    0x00032F00, 0x0003300A, 0x00033200, 0x0003380A, 0x00033900, 0x00034005, 0x0003420A, 0x00034305,
+    0x0003450A, 0x00034600, 0x00037405, 0x00037500, 0x00037A04, 0x00037B00, 0x00037E05, 0x00037F00,
+    0x00038404, 0x00038600, 0x00038705, 0x00038800, 0x0003D004, 0x0003D700, 0x0003F004, 0x0003F300,
+    0x0003F404, 0x0003F600, 0x0003F904, 0x0003FA00, 0x00058704, 0x00058800, 0x0006530A, 0x00065600,
+    0x00067504, 0x00067900, 0x00093C0A, 0x00093D00, 0x00095805, 0x00096000, 0x0009BE0A, 0x0009BF00,
+    0x0009D70A, 0x0009D800, 0x0009DC05, 0x0009DE00, 0x0009DF05, 0x0009E000, 0x000A3305, 0x000A3400,
+    0x000A3605, 0x000A3700, 0x000A5905, 0x000A5C00, 0x000A5E05, 0x000A5F00, 0x000B3E0A, 0x000B3F00,
+    0x000B560A, 0x000B5800, 0x000B5C05, 0x000B5E00, 0x000BBE0A, 0x000BBF00, 0x000BD70A, 0x000BD800,
+    0x000C560A, 0x000C5700, 0x000CC20A, 0x000CC300, 0x000CD50A, 0x000CD700, 0x000D3E0A, 0x000D3F00,
+    0x000D570A, 0x000D5800, 0x000DCA0A, 0x000DCB00, 0x000DCF0A, 0x000DD000, 0x000DDF0A, 0x000DE000,
+    0x000E3304, 0x000E3400, 0x000EB304, 0x000EB400, 0x000EDC04, 0x000EDE00, 0x000F0C04, 0x000F0D00,
+    0x000F4305, 0x000F4400, 0x000F4D05, 0x000F4E00, 0x000F5205, 0x000F5300, 0x000F5705, 0x000F5800,
+    0x000F5C05, 0x000F5D00, 0x000F6905, 0x000F6A00, 0x000F7305, 0x000F7400, 0x000F7505, 0x000F7704,
+    0x000F7805, 0x000F7904, 0x000F7A00, 0x000F8105, 0x000F8200, 0x000F9305, 0x000F9400, 0x000F9D05,
+    0x000F9E00, 0x000FA205, 0x000FA300, 0x000FA705, 0x000FA800, 0x000FAC05, 0x000FAD00, 0x000FB905,
+    0x000FBA00, 0x00102E0A, 0x00102F00, 0x0010FC04, 0x0010FD00, 0x0011610A, 0x00117600, 0x0011A80A,
+    0x0011C300, 0x001B350A, 0x001B3600, 0x001D2C04, 0x001D2F00, 0x001D3004, 0x001D3B00, 0x001D3C04,
+    0x001D4E00, 0x001D4F04, 0x001D6B00, 0x001D7804, 0x001D7900, 0x001D9B04, 0x001DC000, 0x001E9A04,
+    0x001E9C00, 0x001F7105, 0x001F7200, 0x001F7305, 0x001F7400, 0x001F7505, 0x001F7600, 0x001F7705,
+    0x001F7800, 0x001F7905, 0x001F7A00, 0x001F7B05, 0x001F7C00, 0x001F7D05, 0x001F7E00, 0x001FBB05,
+    0x001FBC00, 0x001FBD04, 0x001FBE05, 0x001FBF04, 0x001FC200, 0x001FC905, 0x001FCA00, 0x001FCB05,
+    0x001FCC00, 0x001FCD04, 0x001FD000, 0x001FD305, 0x001FD400, 0x001FDB05, 0x001FDC00, 0x001FDD04,
+    0x001FE000, 0x001FE305, 0x001FE400, 0x001FEB05, 0x001FEC00, 0x001FED04, 0x001FEE05, 0x001FF000,
+    0x001FF905, 0x001FFA00, 0x001FFB05, 0x001FFC00, 0x001FFD05, 0x001FFE04, 0x001FFF00, 0x00200005,
+    0x00200204, 0x00200B00, 0x00201104, 0x00201200, 0x00201704, 0x00201800, 0x00202404, 0x00202700,
+    0x00202F04, 0x00203000, 0x00203304, 0x00203500, 0x00203604, 0x00203800, 0x00203C04, 0x00203D00,
+    0x00203E04, 0x00203F00, 0x00204704, 0x00204A00, 0x00205704, 0x00205800, 0x00205F04, 0x00206000,
+    0x00207004, 0x00207200, 0x00207404, 0x00208F00, 0x00209004, 0x00209D00, 0x0020A804, 0x0020A900,
+    0x00210004, 0x00210400, 0x00210504, 0x00210800, 0x00210904, 0x00211400, 0x00211504, 0x00211700,
+    0x00211904, 0x00211E00, 0x00212004, 0x00212300, 0x00212404, 0x00212500, 0x00212605, 0x00212700,
+    0x00212804, 0x00212900, 0x00212A05, 0x00212C04, 0x00212E00, 0x00212F04, 0x00213200, 0x00213304,
+    0x00213A00, 0x00213B04, 0x00214100, 0x00214504, 0x00214A00, 0x00215004, 0x00218000, 0x00218904,
+    0x00218A00, 0x00222C04, 0x00222E00, 0x00222F04, 0x00223100, 0x00232905, 0x00232B00, 0x00246004,
+    0x0024EB00, 0x002A0C04, 0x002A0D00, 0x002A7404, 0x002A7700, 0x002ADC05, 0x002ADD00, 0x002C7C04,
+    0x002C7E00, 0x002D6F04, 0x002D7000, 0x002E9F04, 0x002EA000, 0x002EF304, 0x002EF400, 0x002F0004,
+    0x002FD600, 0x00300004, 0x00300100, 0x00303604, 0x00303700, 0x00303804, 0x00303B00, 0x0030990A,
+    0x00309B04, 0x00309D00, 0x00309F04, 0x0030A000, 0x0030FF04, 0x00310000, 0x00313104, 0x00318F00,
+    0x00319204, 0x0031A000, 0x00320004, 0x00321F00, 0x00322004, 0x00324800, 0x00325004, 0x00327F00,
+    0x00328004, 0x00340000, 0x00A69C04, 0x00A69E00, 0x00A77004, 0x00A77100, 0x00A7F104, 0x00A7F500,
+    0x00A7F804, 0x00A7FA00, 0x00AB5C04, 0x00AB6000, 0x00AB6904, 0x00AB6A00, 0x00F90005, 0x00FA0E00,
+    0x00FA1005, 0x00FA1100, 0x00FA1205, 0x00FA1300, 0x00FA1505, 0x00FA1F00, 0x00FA2005, 0x00FA2100,
+    0x00FA2205, 0x00FA2300, 0x00FA2505, 0x00FA2700, 0x00FA2A05, 0x00FA6E00, 0x00FA7005, 0x00FADA00,
+    0x00FB0004, 0x00FB0700, 0x00FB1304, 0x00FB1800, 0x00FB1D05, 0x00FB1E00, 0x00FB1F05, 0x00FB2004,
+    0x00FB2A05, 0x00FB3700, 0x00FB3805, 0x00FB3D00, 0x00FB3E05, 0x00FB3F00, 0x00FB4005, 0x00FB4200,
+    0x00FB4305, 0x00FB4500, 0x00FB4605, 0x00FB4F04, 0x00FBB200, 0x00FBD304, 0x00FD3E00, 0x00FD5004,
+    0x00FD9000, 0x00FD9204, 0x00FDC800, 0x00FDF004, 0x00FDFD00, 0x00FE1004, 0x00FE1A00, 0x00FE3004,
+    0x00FE4500, 0x00FE4704, 0x00FE5300, 0x00FE5404, 0x00FE6700, 0x00FE6804, 0x00FE6C00, 0x00FE7004,
+    0x00FE7300, 0x00FE7404, 0x00FE7500, 0x00FE7604, 0x00FEFD00, 0x00FF0104, 0x00FFBF00, 0x00FFC204,
+    0x00FFC800, 0x00FFCA04, 0x00FFD000, 0x00FFD204, 0x00FFD800, 0x00FFDA04, 0x00FFDD00, 0x00FFE004,
+    0x00FFE700, 0x00FFE804, 0x00FFEF00, 0x01078104, 0x01078600, 0x01078704, 0x0107B100, 0x0107B204,
+    0x0107BB00, 0x0110BA0A, 0x0110BB00, 0x0111270A, 0x01112800, 0x01133E0A, 0x01133F00, 0x0113570A,
+    0x01135800, 0x0113B80A, 0x0113B900, 0x0113BB0A, 0x0113BC00, 0x0113C20A, 0x0113C300, 0x0113C50A,
+    0x0113C600, 0x0113C70A, 0x0113CA00, 0x0114B00A, 0x0114B100, 0x0114BA0A, 0x0114BB00, 0x0114BD0A,
+    0x0114BE00, 0x0115AF0A, 0x0115B000, 0x0119300A, 0x01193100, 0x01611E0A, 0x01612A00, 0x016D670A,
+    0x016D6900, 0x01CCD604, 0x01CCFA00, 0x01D15E05, 0x01D16500, 0x01D1BB05, 0x01D1C100, 0x01D40004,
+    0x01D45500, 0x01D45604, 0x01D49D00, 0x01D49E04, 0x01D4A000, 0x01D4A204, 0x01D4A300, 0x01D4A504,
+    0x01D4A700, 0x01D4A904, 0x01D4AD00, 0x01D4AE04, 0x01D4BA00, 0x01D4BB04, 0x01D4BC00, 0x01D4BD04,
+    0x01D4C400, 0x01D4C504, 0x01D50600, 0x01D50704, 0x01D50B00, 0x01D50D04, 0x01D51500, 0x01D51604,
+    0x01D51D00, 0x01D51E04, 0x01D53A00, 0x01D53B04, 0x01D53F00, 0x01D54004, 0x01D54500, 0x01D54604,
+    0x01D54700, 0x01D54A04, 0x01D55100, 0x01D55204, 0x01D6A600, 0x01D6A804, 0x01D7CC00, 0x01D7CE04,
+    0x01D80000, 0x01E03004, 0x01E06E00, 0x01EE0004, 0x01EE0400, 0x01EE0504, 0x01EE2000, 0x01EE2104,
+    0x01EE2300, 0x01EE2404, 0x01EE2500, 0x01EE2704, 0x01EE2800, 0x01EE2904, 0x01EE3300, 0x01EE3404,
+    0x01EE3800, 0x01EE3904, 0x01EE3A00, 0x01EE3B04, 0x01EE3C00, 0x01EE4204, 0x01EE4300, 0x01EE4704,
+    0x01EE4800, 0x01EE4904, 0x01EE4A00, 0x01EE4B04, 0x01EE4C00, 0x01EE4D04, 0x01EE5000, 0x01EE5104,
+    0x01EE5300, 0x01EE5404, 0x01EE5500, 0x01EE5704, 0x01EE5800, 0x01EE5904, 0x01EE5A00, 0x01EE5B04,
+    0x01EE5C00, 0x01EE5D04, 0x01EE5E00, 0x01EE5F04, 0x01EE6000, 0x01EE6104, 0x01EE6300, 0x01EE6404,
+    0x01EE6500, 0x01EE6704, 0x01EE6B00, 0x01EE6C04, 0x01EE7300, 0x01EE7404, 0x01EE7800, 0x01EE7904,
+    0x01EE7D00, 0x01EE7E04, 0x01EE7F00, 0x01EE8004, 0x01EE8A00, 0x01EE8B04, 0x01EE9C00, 0x01EEA104,
+    0x01EEA400, 0x01EEA504, 0x01EEAA00, 0x01EEAB04, 0x01EEBC00, 0x01F10004, 0x01F10B00, 0x01F11004,
+    0x01F12F00, 0x01F13004, 0x01F15000, 0x01F16A04, 0x01F16D00, 0x01F19004, 0x01F19100, 0x01F20004,
+    0x01F20300, 0x01F21004, 0x01F23C00, 0x01F24004, 0x01F24900, 0x01F25004, 0x01F25200, 0x01FBF004,
+    0x01FBFA00, 0x02F80005, 0x02FA1E00,
+];
+
+pub(crate) static NFKC_CASEFOLD_KEYS: [char; 6409] = [
+    '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}',
+    '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}',
+    '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}',
+    '\u{59}', '\u{5A}', '\u{A0}', '\u{A8}', '\u{AA}', '\u{AF}', '\u{B2}', '\u{B3}',
+    '\u{B4}', '\u{B5}', '\u{B8}', '\u{B9}', '\u{BA}', '\u{BC}', '\u{BD}', '\u{BE}',
+    '\u{C0}', '\u{C1}', '\u{C2}', '\u{C3}', '\u{C4}', '\u{C5}', '\u{C6}', '\u{C7}',
+    '\u{C8}', '\u{C9}', '\u{CA}', '\u{CB}', '\u{CC}', '\u{CD}', '\u{CE}', '\u{CF}',
+    '\u{D0}', '\u{D1}', '\u{D2}', '\u{D3}', '\u{D4}', '\u{D5}', '\u{D6}', '\u{D8}',
+    '\u{D9}', '\u{DA}', '\u{DB}', '\u{DC}', '\u{DD}', '\u{DE}', '\u{DF}', '\u{100}',
+    '\u{102}', '\u{104}', '\u{106}', '\u{108}', '\u{10A}', '\u{10C}', '\u{10E}', '\u{110}',
+    '\u{112}', '\u{114}', '\u{116}', '\u{118}', '\u{11A}', '\u{11C}', '\u{11E}', '\u{120}',
+    '\u{122}', '\u{124}', '\u{126}', '\u{128}', '\u{12A}', '\u{12C}', '\u{12E}', '\u{130}',
+    '\u{132}', '\u{133}', '\u{134}', '\u{136}', '\u{139}', '\u{13B}', '\u{13D}', '\u{13F}',
+    '\u{140}', '\u{141}', '\u{143}', '\u{145}', '\u{147}', '\u{149}', '\u{14A}', '\u{14C}',
+    '\u{14E}', '\u{150}', '\u{152}', '\u{154}', '\u{156}', '\u{158}', '\u{15A}', '\u{15C}',
+    '\u{15E}', '\u{160}', '\u{162}', '\u{164}', '\u{166}', '\u{168}', '\u{16A}', '\u{16C}',
+    '\u{16E}', '\u{170}', '\u{172}', '\u{174}', '\u{176}', '\u{178}', '\u{179}', '\u{17B}',
+    '\u{17D}', '\u{17F}', '\u{181}', '\u{182}', '\u{184}', '\u{186}', '\u{187}', '\u{189}',
+    '\u{18A}', '\u{18B}', '\u{18E}', '\u{18F}', '\u{190}', '\u{191}', '\u{193}', '\u{194}',
+    '\u{196}', '\u{197}', '\u{198}', '\u{19C}', '\u{19D}', '\u{19F}', '\u{1A0}', '\u{1A2}',
+    '\u{1A4}', '\u{1A6}', '\u{1A7}', '\u{1A9}', '\u{1AC}', '\u{1AE}', '\u{1AF}', '\u{1B1}',
+    '\u{1B2}', '\u{1B3}', '\u{1B5}', '\u{1B7}', '\u{1B8}', '\u{1BC}', '\u{1C4}', '\u{1C5}',
+    '\u{1C6}', '\u{1C7}', '\u{1C8}', '\u{1C9}', '\u{1CA}', '\u{1CB}', '\u{1CC}', '\u{1CD}',
+    '\u{1CF}', '\u{1D1}', '\u{1D3}', '\u{1D5}', '\u{1D7}', '\u{1D9}', '\u{1DB}', '\u{1DE}',
+    '\u{1E0}', '\u{1E2}', '\u{1E4}', '\u{1E6}', '\u{1E8}', '\u{1EA}', '\u{1EC}', '\u{1EE}',
+    '\u{1F1}', '\u{1F2}', '\u{1F3}', '\u{1F4}', '\u{1F6}', '\u{1F7}', '\u{1F8}', '\u{1FA}',
+    '\u{1FC}', '\u{1FE}', '\u{200}', '\u{202}', '\u{204}', '\u{206}', '\u{208}', '\u{20A}',
+    '\u{20C}', '\u{20E}', '\u{210}', '\u{212}', '\u{214}', '\u{216}', '\u{218}', '\u{21A}',
+    '\u{21C}', '\u{21E}', '\u{220}', '\u{222}', '\u{224}', '\u{226}', '\u{228}', '\u{22A}',
+    '\u{22C}', '\u{22E}', '\u{230}', '\u{232}', '\u{23A}', '\u{23B}', '\u{23D}', '\u{23E}',
+    '\u{241}', '\u{243}', '\u{244}', '\u{245}', '\u{246}', '\u{248}', '\u{24A}', '\u{24C}',
+    '\u{24E}', '\u{2B0}', '\u{2B1}', '\u{2B2}', '\u{2B3}', '\u{2B4}', '\u{2B5}', '\u{2B6}',
+    '\u{2B7}', '\u{2B8}', '\u{2D8}', '\u{2D9}', '\u{2DA}', '\u{2DB}', '\u{2DC}', '\u{2DD}',
+    '\u{2E0}', '\u{2E1}', '\u{2E2}', '\u{2E3}', '\u{2E4}', '\u{340}', '\u{341}', '\u{343}',
+    '\u{344}', '\u{345}', '\u{370}', '\u{372}', '\u{374}', '\u{376}', '\u{37A}', '\u{37E}',
+    '\u{37F}', '\u{384}', '\u{385}', '\u{386}', '\u{387}', '\u{388}', '\u{389}', '\u{38A}',
+    '\u{38C}', '\u{38E}', '\u{38F}', '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}',
+    '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39A}', '\u{39B}', '\u{39C}', '\u{39D}',
+    '\u{39E}', '\u{39F}', '\u{3A0}', '\u{3A1}', '\u{3A3}', '\u{3A4}', '\u{3A5}', '\u{3A6}',
+    '\u{3A7}', '\u{3A8}', '\u{3A9}', '\u{3AA}', '\u{3AB}', '\u{3C2}', '\u{3CF}', '\u{3D0}',
+    '\u{3D1}', '\u{3D2}', '\u{3D3}', '\u{3D4}', '\u{3D5}', '\u{3D6}', '\u{3D8}', '\u{3DA}',
+    '\u{3DC}', '\u{3DE}', '\u{3E0}', '\u{3E2}', '\u{3E4}', '\u{3E6}', '\u{3E8}', '\u{3EA}',
+    '\u{3EC}', '\u{3EE}', '\u{3F0}', '\u{3F1}', '\u{3F2}', '\u{3F4}', '\u{3F5}', '\u{3F7}',
+    '\u{3F9}', '\u{3FA}', '\u{3FD}', '\u{3FE}', '\u{3FF}', '\u{400}', '\u{401}', '\u{402}',
+    '\u{403}', '\u{404}', '\u{405}', '\u{406}', '\u{407}', '\u{408}', '\u{409}', '\u{40A}',
+    '\u{40B}', '\u{40C}', '\u{40D}', '\u{40E}', '\u{40F}', '\u{410}', '\u{411}', '\u{412}',
+    '\u{413}', '\u{414}', '\u{415}', '\u{416}', '\u{417}', '\u{418}', '\u{419}', '\u{41A}',
+    '\u{41B}', '\u{41C}', '\u{41D}', '\u{41E}', '\u{41F}', '\u{420}', '\u{421}', '\u{422}',
+    '\u{423}', '\u{424}', '\u{425}', '\u{426}', '\u{427}', '\u{428}', '\u{429}', '\u{42A}',
+    '\u{42B}', '\u{42C}', '\u{42D}', '\u{42E}', '\u{42F}', '\u{460}', '\u{462}', '\u{464}',
+    '\u{466}', '\u{468}', '\u{46A}', '\u{46C}', '\u{46E}', '\u{470}', '\u{472}', '\u{474}',
+    '\u{476}', '\u{478}', '\u{47A}', '\u{47C}', '\u{47E}', '\u{480}', '\u{48A}', '\u{48C}',
+    '\u{48E}', '\u{490}', '\u{492}', '\u{494}', '\u{496}', '\u{498}', '\u{49A}', '\u{49C}',
+    '\u{49E}', '\u{4A0}', '\u{4A2}', '\u{4A4}', '\u{4A6}', '\u{4A8}', '\u{4AA}', '\u{4AC}',
+    '\u{4AE}', '\u{4B0}', '\u{4B2}', '\u{4B4}', '\u{4B6}', '\u{4B8}', '\u{4BA}', '\u{4BC}',
+    '\u{4BE}', '\u{4C0}', '\u{4C1}', '\u{4C3}', '\u{4C5}', '\u{4C7}', '\u{4C9}', '\u{4CB}',
+    '\u{4CD}', '\u{4D0}', '\u{4D2}', '\u{4D4}', '\u{4D6}', '\u{4D8}', '\u{4DA}', '\u{4DC}',
+    '\u{4DE}', '\u{4E0}', '\u{4E2}', '\u{4E4}', '\u{4E6}', '\u{4E8}', '\u{4EA}', '\u{4EC}',
+    '\u{4EE}', '\u{4F0}', '\u{4F2}', '\u{4F4}', '\u{4F6}', '\u{4F8}', '\u{4FA}', '\u{4FC}',
+    '\u{4FE}', '\u{500}', '\u{502}', '\u{504}', '\u{506}', '\u{508}', '\u{50A}', '\u{50C}',
+    '\u{50E}', '\u{510}', '\u{512}', '\u{514}', '\u{516}', '\u{518}', '\u{51A}', '\u{51C}',
+    '\u{51E}', '\u{520}', '\u{522}', '\u{524}', '\u{526}', '\u{528}', '\u{52A}', '\u{52C}',
+    '\u{52E}', '\u{531}', '\u{532}', '\u{533}', '\u{534}', '\u{535}', '\u{536}', '\u{537}',
+    '\u{538}', '\u{539}', '\u{53A}', '\u{53B}', '\u{53C}', '\u{53D}', '\u{53E}', '\u{53F}',
+    '\u{540}', '\u{541}', '\u{542}', '\u{543}', '\u{544}', '\u{545}', '\u{546}', '\u{547}',
+    '\u{548}', '\u{549}', '\u{54A}', '\u{54B}', '\u{54C}', '\u{54D}', '\u{54E}', '\u{54F}',
+    '\u{550}', '\u{551}', '\u{552}', '\u{553}', '\u{554}', '\u{555}', '\u{556}', '\u{587}',
+    '\u{675}', '\u{676}', '\u{677}', '\u{678}', '\u{958}', '\u{959}', '\u{95A}', '\u{95B}',
+    '\u{95C}', '\u{95D}', '\u{95E}', '\u{95F}', '\u{9DC}', '\u{9DD}', '\u{9DF}', '\u{A33}',
+    '\u{A36}', '\u{A59}', '\u{A5A}', '\u{A5B}', '\u{A5E}', '\u{B5C}', '\u{B5D}', '\u{E33}',
+    '\u{EB3}', '\u{EDC}', '\u{EDD}', '\u{F0C}', '\u{F43}', '\u{F4D}', '\u{F52}', '\u{F57}',
+    '\u{F5C}', '\u{F69}', '\u{F73}', '\u{F75}', '\u{F76}', '\u{F77}', '\u{F78}', '\u{F79}',
+    '\u{F81}', '\u{F93}', '\u{F9D}', '\u{FA2}', '\u{FA7}', '\u{FAC}', '\u{FB9}', '\u{10A0}',
+    '\u{10A1}', '\u{10A2}', '\u{10A3}', '\u{10A4}', '\u{10A5}', '\u{10A6}', '\u{10A7}', '\u{10A8}',
+    '\u{10A9}', '\u{10AA}', '\u{10AB}', '\u{10AC}', '\u{10AD}', '\u{10AE}', '\u{10AF}', '\u{10B0}',
+    '\u{10B1}', '\u{10B2}', '\u{10B3}', '\u{10B4}', '\u{10B5}', '\u{10B6}', '\u{10B7}', '\u{10B8}',
+    '\u{10B9}', '\u{10BA}', '\u{10BB}', '\u{10BC}', '\u{10BD}', '\u{10BE}', '\u{10BF}', '\u{10C0}',
+    '\u{10C1}', '\u{10C2}', '\u{10C3}', '\u{10C4}', '\u{10C5}', '\u{10C7}', '\u{10CD}', '\u{10FC}',
+    '\u{13F8}', '\u{13F9}', '\u{13FA}', '\u{13FB}', '\u{13FC}', '\u{13FD}', '\u{1C80}', '\u{1C81}',
+    '\u{1C82}', '\u{1C83}', '\u{1C84}', '\u{1C85}', '\u{1C86}', '\u{1C87}', '\u{1C88}', '\u{1C89}',
+    '\u{1C90}', '\u{1C91}', '\u{1C92}', '\u{1C93}', '\u{1C94}', '\u{1C95}', '\u{1C96}', '\u{1C97}',
+    '\u{1C98}', '\u{1C99}', '\u{1C9A}', '\u{1C9B}', '\u{1C9C}', '\u{1C9D}', '\u{1C9E}', '\u{1C9F}',
+    '\u{1CA0}', '\u{1CA1}', '\u{1CA2}', '\u{1CA3}', '\u{1CA4}', '\u{1CA5}', '\u{1CA6}', '\u{1CA7}',
+    '\u{1CA8}', '\u{1CA9}', '\u{1CAA}', '\u{1CAB}', '\u{1CAC}', '\u{1CAD}', '\u{1CAE}', '\u{1CAF}',
+    '\u{1CB0}', '\u{1CB1}', '\u{1CB2}', '\u{1CB3}', '\u{1CB4}', '\u{1CB5}', '\u{1CB6}', '\u{1CB7}',
+    '\u{1CB8}', '\u{1CB9}', '\u{1CBA}', '\u{1CBD}', '\u{1CBE}', '\u{1CBF}', '\u{1D2C}', '\u{1D2D}',
+    '\u{1D2E}', '\u{1D30}', '\u{1D31}', '\u{1D32}', '\u{1D33}', '\u{1D34}', '\u{1D35}', '\u{1D36}',
+    '\u{1D37}', '\u{1D38}', '\u{1D39}', '\u{1D3A}', '\u{1D3C}', '\u{1D3D}', '\u{1D3E}', '\u{1D3F}',
+    '\u{1D40}', '\u{1D41}', '\u{1D42}', '\u{1D43}', '\u{1D44}', '\u{1D45}', '\u{1D46}', '\u{1D47}',
+    '\u{1D48}', '\u{1D49}', '\u{1D4A}', '\u{1D4B}', '\u{1D4C}', '\u{1D4D}', '\u{1D4F}', '\u{1D50}',
+    '\u{1D51}', '\u{1D52}', '\u{1D53}', '\u{1D54}', '\u{1D55}', '\u{1D56}', '\u{1D57}', '\u{1D58}',
+    '\u{1D59}', '\u{1D5A}', '\u{1D5B}', '\u{1D5C}', '\u{1D5D}', '\u{1D5E}', '\u{1D5F}', '\u{1D60}',
+    '\u{1D61}', '\u{1D62}', '\u{1D63}', '\u{1D64}', '\u{1D65}', '\u{1D66}', '\u{1D67}', '\u{1D68}',
+    '\u{1D69}', '\u{1D6A}', '\u{1D78}', '\u{1D9B}', '\u{1D9C}', '\u{1D9D}', '\u{1D9E}', '\u{1D9F}',
+    '\u{1DA0}', '\u{1DA1}', '\u{1DA2}', '\u{1DA3}', '\u{1DA4}', '\u{1DA5}', '\u{1DA6}', '\u{1DA7}',
+    '\u{1DA8}', '\u{1DA9}', '\u{1DAA}', '\u{1DAB}', '\u{1DAC}', '\u{1DAD}', '\u{1DAE}', '\u{1DAF}',
+    '\u{1DB0}', '\u{1DB1}', '\u{1DB2}', '\u{1DB3}', '\u{1DB4}', '\u{1DB5}', '\u{1DB6}', '\u{1DB7}',
+    '\u{1DB8}', '\u{1DB9}', '\u{1DBA}', '\u{1DBB}', '\u{1DBC}', '\u{1DBD}', '\u{1DBE}', '\u{1DBF}',
+    '\u{1E00}', '\u{1E02}', '\u{1E04}', '\u{1E06}', '\u{1E08}', '\u{1E0A}', '\u{1E0C}', '\u{1E0E}',
+    '\u{1E10}', '\u{1E12}', '\u{1E14}', '\u{1E16}', '\u{1E18}', '\u{1E1A}', '\u{1E1C}', '\u{1E1E}',
+    '\u{1E20}', '\u{1E22}', '\u{1E24}', '\u{1E26}', '\u{1E28}', '\u{1E2A}', '\u{1E2C}', '\u{1E2E}',
+    '\u{1E30}', '\u{1E32}', '\u{1E34}', '\u{1E36}', '\u{1E38}', '\u{1E3A}', '\u{1E3C}', '\u{1E3E}',
+    '\u{1E40}', '\u{1E42}', '\u{1E44}', '\u{1E46}', '\u{1E48}', '\u{1E4A}', '\u{1E4C}', '\u{1E4E}',
+    '\u{1E50}', '\u{1E52}', '\u{1E54}', '\u{1E56}', '\u{1E58}', '\u{1E5A}', '\u{1E5C}', '\u{1E5E}',
+    '\u{1E60}', '\u{1E62}', '\u{1E64}', '\u{1E66}', '\u{1E68}', '\u{1E6A}', '\u{1E6C}', '\u{1E6E}',
+    '\u{1E70}', '\u{1E72}', '\u{1E74}', '\u{1E76}', '\u{1E78}', '\u{1E7A}', '\u{1E7C}', '\u{1E7E}',
+    '\u{1E80}', '\u{1E82}', '\u{1E84}', '\u{1E86}', '\u{1E88}', '\u{1E8A}', '\u{1E8C}', '\u{1E8E}',
+    '\u{1E90}', '\u{1E92}', '\u{1E94}', '\u{1E9A}', '\u{1E9B}', '\u{1E9E}', '\u{1EA0}', '\u{1EA2}',
+    '\u{1EA4}', '\u{1EA6}', '\u{1EA8}', '\u{1EAA}', '\u{1EAC}', '\u{1EAE}', '\u{1EB0}', '\u{1EB2}',
+    '\u{1EB4}', '\u{1EB6}', '\u{1EB8}', '\u{1EBA}', '\u{1EBC}', '\u{1EBE}', '\u{1EC0}', '\u{1EC2}',
+    '\u{1EC4}', '\u{1EC6}', '\u{1EC8}', '\u{1ECA}', '\u{1ECC}', '\u{1ECE}', '\u{1ED0}', '\u{1ED2}',
+    '\u{1ED4}', '\u{1ED6}', '\u{1ED8}', '\u{1EDA}', '\u{1EDC}', '\u{1EDE}', '\u{1EE0}', '\u{1EE2}',
+    '\u{1EE4}', '\u{1EE6}', '\u{1EE8}', '\u{1EEA}', '\u{1EEC}', '\u{1EEE}', '\u{1EF0}', '\u{1EF2}',
+    '\u{1EF4}', '\u{1EF6}', '\u{1EF8}', '\u{1EFA}', '\u{1EFC}', '\u{1EFE}', '\u{1F08}', '\u{1F09}',
+    '\u{1F0A}', '\u{1F0B}', '\u{1F0C}', '\u{1F0D}', '\u{1F0E}', '\u{1F0F}', '\u{1F18}', '\u{1F19}',
+    '\u{1F1A}', '\u{1F1B}', '\u{1F1C}', '\u{1F1D}', '\u{1F28}', '\u{1F29}', '\u{1F2A}', '\u{1F2B}',
+    '\u{1F2C}', '\u{1F2D}', '\u{1F2E}', '\u{1F2F}', '\u{1F38}', '\u{1F39}', '\u{1F3A}', '\u{1F3B}',
+    '\u{1F3C}', '\u{1F3D}', '\u{1F3E}', '\u{1F3F}', '\u{1F48}', '\u{1F49}', '\u{1F4A}', '\u{1F4B}',
+    '\u{1F4C}', '\u{1F4D}', '\u{1F59}', '\u{1F5B}', '\u{1F5D}', '\u{1F5F}', '\u{1F68}', '\u{1F69}',
+    '\u{1F6A}', '\u{1F6B}', '\u{1F6C}', '\u{1F6D}', '\u{1F6E}', '\u{1F6F}', '\u{1F71}', '\u{1F73}',
+    '\u{1F75}', '\u{1F77}', '\u{1F79}', '\u{1F7B}', '\u{1F7D}', '\u{1F80}', '\u{1F81}', '\u{1F82}',
+    '\u{1F83}', '\u{1F84}', '\u{1F85}', '\u{1F86}', '\u{1F87}', '\u{1F88}', '\u{1F89}', '\u{1F8A}',
+    '\u{1F8B}', '\u{1F8C}', '\u{1F8D}', '\u{1F8E}', '\u{1F8F}', '\u{1F90}', '\u{1F91}', '\u{1F92}',
+    '\u{1F93}', '\u{1F94}', '\u{1F95}', '\u{1F96}', '\u{1F97}', '\u{1F98}', '\u{1F99}', '\u{1F9A}',
+    '\u{1F9B}', '\u{1F9C}', '\u{1F9D}', '\u{1F9E}', '\u{1F9F}', '\u{1FA0}', '\u{1FA1}', '\u{1FA2}',
+    '\u{1FA3}', '\u{1FA4}', '\u{1FA5}', '\u{1FA6}', '\u{1FA7}', '\u{1FA8}', '\u{1FA9}', '\u{1FAA}',
+    '\u{1FAB}', '\u{1FAC}', '\u{1FAD}', '\u{1FAE}', '\u{1FAF}', '\u{1FB2}', '\u{1FB3}', '\u{1FB4}',
+    '\u{1FB7}', '\u{1FB8}', '\u{1FB9}', '\u{1FBA}', '\u{1FBB}', '\u{1FBC}', '\u{1FBD}', '\u{1FBE}',
+    '\u{1FBF}', '\u{1FC0}', '\u{1FC1}', '\u{1FC2}', '\u{1FC3}', '\u{1FC4}', '\u{1FC7}', '\u{1FC8}',
+    '\u{1FC9}', '\u{1FCA}', '\u{1FCB}', '\u{1FCC}', '\u{1FCD}', '\u{1FCE}', '\u{1FCF}', '\u{1FD3}',
+    '\u{1FD8}', '\u{1FD9}', '\u{1FDA}', '\u{1FDB}', '\u{1FDD}', '\u{1FDE}', '\u{1FDF}', '\u{1FE3}',
+    '\u{1FE8}', '\u{1FE9}', '\u{1FEA}', '\u{1FEB}', '\u{1FEC}', '\u{1FED}', '\u{1FEE}', '\u{1FEF}',
+    '\u{1FF2}', '\u{1FF3}', '\u{1FF4}', '\u{1FF7}', '\u{1FF8}', '\u{1FF9}', '\u{1FFA}', '\u{1FFB}',
+    '\u{1FFC}', '\u{1FFD}', '\u{1FFE}', '\u{2000}', '\u{2001}', '\u{2002}', '\u{2003}', '\u{2004}',
+    '\u{2005}', '\u{2006}', '\u{2007}', '\u{2008}', '\u{2009}', '\u{200A}', '\u{2011}', '\u{2017}',
+    '\u{2024}', '\u{2025}', '\u{2026}', '\u{202F}', '\u{2033}', '\u{2034}', '\u{2036}', '\u{2037}',
+    '\u{203C}', '\u{203E}', '\u{2047}', '\u{2048}', '\u{2049}', '\u{2057}', '\u{205F}', '\u{2070}',
+    '\u{2071}', '\u{2074}', '\u{2075}', '\u{2076}', '\u{2077}', '\u{2078}', '\u{2079}', '\u{207A}',
+    '\u{207B}', '\u{207C}', '\u{207D}', '\u{207E}', '\u{207F}', '\u{2080}', '\u{2081}', '\u{2082}',
+    '\u{2083}', '\u{2084}', '\u{2085}', '\u{2086}', '\u{2087}', '\u{2088}', '\u{2089}', '\u{208A}',
+    '\u{208B}', '\u{208C}', '\u{208D}', '\u{208E}', '\u{2090}', '\u{2091}', '\u{2092}', '\u{2093}',
+    '\u{2094}', '\u{2095}', '\u{2096}', '\u{2097}', '\u{2098}', '\u{2099}', '\u{209A}', '\u{209B}',
+    '\u{209C}', '\u{20A8}', '\u{2100}', '\u{2101}', '\u{2102}', '\u{2103}', '\u{2105}', '\u{2106}',
+    '\u{2107}', '\u{2109}', '\u{210A}', '\u{210B}', '\u{210C}', '\u{210D}', '\u{210E}', '\u{210F}',
+    '\u{2110}', '\u{2111}', '\u{2112}', '\u{2113}', '\u{2115}', '\u{2116}', '\u{2119}', '\u{211A}',
+    '\u{211B}', '\u{211C}', '\u{211D}', '\u{2120}', '\u{2121}', '\u{2122}', '\u{2124}', '\u{2126}',
+    '\u{2128}', '\u{212A}', '\u{212B}', '\u{212C}', '\u{212D}', '\u{212F}', '\u{2130}', '\u{2131}',
+    '\u{2132}', '\u{2133}', '\u{2134}', '\u{2135}', '\u{2136}', '\u{2137}', '\u{2138}', '\u{2139}',
+    '\u{213B}', '\u{213C}', '\u{213D}', '\u{213E}', '\u{213F}', '\u{2140}', '\u{2145}', '\u{2146}',
+    '\u{2147}', '\u{2148}', '\u{2149}', '\u{2150}', '\u{2151}', '\u{2152}', '\u{2153}', '\u{2154}',
+    '\u{2155}', '\u{2156}', '\u{2157}', '\u{2158}', '\u{2159}', '\u{215A}', '\u{215B}', '\u{215C}',
+    '\u{215D}', '\u{215E}', '\u{215F}', '\u{2160}', '\u{2161}', '\u{2162}', '\u{2163}', '\u{2164}',
+    '\u{2165}', '\u{2166}', '\u{2167}', '\u{2168}', '\u{2169}', '\u{216A}', '\u{216B}', '\u{216C}',
+    '\u{216D}', '\u{216E}', '\u{216F}', '\u{2170}', '\u{2171}', '\u{2172}', '\u{2173}', '\u{2174}',
+    '\u{2175}', '\u{2176}', '\u{2177}', '\u{2178}', '\u{2179}', '\u{217A}', '\u{217B}', '\u{217C}',
+    '\u{217D}', '\u{217E}', '\u{217F}', '\u{2183}', '\u{2189}', '\u{222C}', '\u{222D}', '\u{222F}',
+    '\u{2230}', '\u{2329}', '\u{232A}', '\u{2460}', '\u{2461}', '\u{2462}', '\u{2463}', '\u{2464}',
+    '\u{2465}', '\u{2466}', '\u{2467}', '\u{2468}', '\u{2469}', '\u{246A}', '\u{246B}', '\u{246C}',
+    '\u{246D}', '\u{246E}', '\u{246F}', '\u{2470}', '\u{2471}', '\u{2472}', '\u{2473}', '\u{2474}',
+    '\u{2475}', '\u{2476}', '\u{2477}', '\u{2478}', '\u{2479}', '\u{247A}', '\u{247B}', '\u{247C}',
+    '\u{247D}', '\u{247E}', '\u{247F}', '\u{2480}', '\u{2481}', '\u{2482}', '\u{2483}', '\u{2484}',
+    '\u{2485}', '\u{2486}', '\u{2487}', '\u{2488}', '\u{2489}', '\u{248A}', '\u{248B}', '\u{248C}',
+    '\u{248D}', '\u{248E}', '\u{248F}', '\u{2490}', '\u{2491}', '\u{2492}', '\u{2493}', '\u{2494}',
+    '\u{2495}', '\u{2496}', '\u{2497}', '\u{2498}', '\u{2499}', '\u{249A}', '\u{249B}', '\u{249C}',
+    '\u{249D}', '\u{249E}', '\u{249F}', '\u{24A0}', '\u{24A1}', '\u{24A2}', '\u{24A3}', '\u{24A4}',
+    '\u{24A5}', '\u{24A6}', '\u{24A7}', '\u{24A8}', '\u{24A9}', '\u{24AA}', '\u{24AB}', '\u{24AC}',
+    '\u{24AD}', '\u{24AE}', '\u{24AF}', '\u{24B0}', '\u{24B1}', '\u{24B2}', '\u{24B3}', '\u{24B4}',
+    '\u{24B5}', '\u{24B6}', '\u{24B7}', '\u{24B8}', '\u{24B9}', '\u{24BA}', '\u{24BB}', '\u{24BC}',
+    '\u{24BD}', '\u{24BE}', '\u{24BF}', '\u{24C0}', '\u{24C1}', '\u{24C2}', '\u{24C3}', '\u{24C4}',
+    '\u{24C5}', '\u{24C6}', '\u{24C7}', '\u{24C8}', '\u{24C9}', '\u{24CA}', '\u{24CB}', '\u{24CC}',
+    '\u{24CD}', '\u{24CE}', '\u{24CF}', '\u{24D0}', '\u{24D1}', '\u{24D2}', '\u{24D3}', '\u{24D4}',
+    '\u{24D5}', '\u{24D6}', '\u{24D7}', '\u{24D8}', '\u{24D9}', '\u{24DA}', '\u{24DB}', '\u{24DC}',
+    '\u{24DD}', '\u{24DE}', '\u{24DF}', '\u{24E0}', '\u{24E1}', '\u{24E2}', '\u{24E3}', '\u{24E4}',
+    '\u{24E5}', '\u{24E6}', '\u{24E7}', '\u{24E8}', '\u{24E9}', '\u{24EA}', '\u{2A0C}', '\u{2A74}',
+    '\u{2A75}', '\u{2A76}', '\u{2ADC}', '\u{2C00}', '\u{2C01}', '\u{2C02}', '\u{2C03}', '\u{2C04}',
+    '\u{2C05}', '\u{2C06}', '\u{2C07}', '\u{2C08}', '\u{2C09}', '\u{2C0A}', '\u{2C0B}', '\u{2C0C}',
+    '\u{2C0D}', '\u{2C0E}', '\u{2C0F}', '\u{2C10}', '\u{2C11}', '\u{2C12}', '\u{2C13}', '\u{2C14}',
+    '\u{2C15}', '\u{2C16}', '\u{2C17}', '\u{2C18}', '\u{2C19}', '\u{2C1A}', '\u{2C1B}', '\u{2C1C}',
+    '\u{2C1D}', '\u{2C1E}', '\u{2C1F}', '\u{2C20}', '\u{2C21}', '\u{2C22}', '\u{2C23}', '\u{2C24}',
+    '\u{2C25}', '\u{2C26}', '\u{2C27}', '\u{2C28}', '\u{2C29}', '\u{2C2A}', '\u{2C2B}', '\u{2C2C}',
+    '\u{2C2D}', '\u{2C2E}', '\u{2C2F}', '\u{2C60}', '\u{2C62}', '\u{2C63}', '\u{2C64}', '\u{2C67}',
+    '\u{2C69}', '\u{2C6B}', '\u{2C6D}', '\u{2C6E}', '\u{2C6F}', '\u{2C70}', '\u{2C72}', '\u{2C75}',
+    '\u{2C7C}', '\u{2C7D}', '\u{2C7E}', '\u{2C7F}', '\u{2C80}', '\u{2C82}', '\u{2C84}', '\u{2C86}',
+    '\u{2C88}', '\u{2C8A}', '\u{2C8C}', '\u{2C8E}', '\u{2C90}', '\u{2C92}', '\u{2C94}', '\u{2C96}',
+    '\u{2C98}', '\u{2C9A}', '\u{2C9C}', '\u{2C9E}', '\u{2CA0}', '\u{2CA2}', '\u{2CA4}', '\u{2CA6}',
+    '\u{2CA8}', '\u{2CAA}', '\u{2CAC}', '\u{2CAE}', '\u{2CB0}', '\u{2CB2}', '\u{2CB4}', '\u{2CB6}',
+    '\u{2CB8}', '\u{2CBA}', '\u{2CBC}', '\u{2CBE}', '\u{2CC0}', '\u{2CC2}', '\u{2CC4}', '\u{2CC6}',
+    '\u{2CC8}', '\u{2CCA}', '\u{2CCC}', '\u{2CCE}', '\u{2CD0}', '\u{2CD2}', '\u{2CD4}', '\u{2CD6}',
+    '\u{2CD8}', '\u{2CDA}', '\u{2CDC}', '\u{2CDE}', '\u{2CE0}', '\u{2CE2}', '\u{2CEB}', '\u{2CED}',
+    '\u{2CF2}', '\u{2D6F}', '\u{2E9F}', '\u{2EF3}', '\u{2F00}', '\u{2F01}', '\u{2F02}', '\u{2F03}',
+    '\u{2F04}', '\u{2F05}', '\u{2F06}', '\u{2F07}', '\u{2F08}', '\u{2F09}', '\u{2F0A}', '\u{2F0B}',
+    '\u{2F0C}', '\u{2F0D}', '\u{2F0E}', '\u{2F0F}', '\u{2F10}', '\u{2F11}', '\u{2F12}', '\u{2F13}',
+    '\u{2F14}', '\u{2F15}', '\u{2F16}', '\u{2F17}', '\u{2F18}', '\u{2F19}', '\u{2F1A}', '\u{2F1B}',
+    '\u{2F1C}', '\u{2F1D}', '\u{2F1E}', '\u{2F1F}', '\u{2F20}', '\u{2F21}', '\u{2F22}', '\u{2F23}',
+    '\u{2F24}', '\u{2F25}', '\u{2F26}', '\u{2F27}', '\u{2F28}', '\u{2F29}', '\u{2F2A}', '\u{2F2B}',
+    '\u{2F2C}', '\u{2F2D}', '\u{2F2E}', '\u{2F2F}', '\u{2F30}', '\u{2F31}', '\u{2F32}', '\u{2F33}',
+    '\u{2F34}', '\u{2F35}', '\u{2F36}', '\u{2F37}', '\u{2F38}', '\u{2F39}', '\u{2F3A}', '\u{2F3B}',
+    '\u{2F3C}', '\u{2F3D}', '\u{2F3E}', '\u{2F3F}', '\u{2F40}', '\u{2F41}', '\u{2F42}', '\u{2F43}',
+    '\u{2F44}', '\u{2F45}', '\u{2F46}', '\u{2F47}', '\u{2F48}', '\u{2F49}', '\u{2F4A}', '\u{2F4B}',
+    '\u{2F4C}', '\u{2F4D}', '\u{2F4E}', '\u{2F4F}', '\u{2F50}', '\u{2F51}', '\u{2F52}', '\u{2F53}',
+    '\u{2F54}', '\u{2F55}', '\u{2F56}', '\u{2F57}', '\u{2F58}', '\u{2F59}', '\u{2F5A}', '\u{2F5B}',
+    '\u{2F5C}', '\u{2F5D}', '\u{2F5E}', '\u{2F5F}', '\u{2F60}', '\u{2F61}', '\u{2F62}', '\u{2F63}',
+    '\u{2F64}', '\u{2F65}', '\u{2F66}', '\u{2F67}', '\u{2F68}', '\u{2F69}', '\u{2F6A}', '\u{2F6B}',
+    '\u{2F6C}', '\u{2F6D}', '\u{2F6E}', '\u{2F6F}', '\u{2F70}', '\u{2F71}', '\u{2F72}', '\u{2F73}',
+    '\u{2F74}', '\u{2F75}', '\u{2F76}', '\u{2F77}', '\u{2F78}', '\u{2F79}', '\u{2F7A}', '\u{2F7B}',
+    '\u{2F7C}', '\u{2F7D}', '\u{2F7E}', '\u{2F7F}', '\u{2F80}', '\u{2F81}', '\u{2F82}', '\u{2F83}',
+    '\u{2F84}', '\u{2F85}', '\u{2F86}', '\u{2F87}', '\u{2F88}', '\u{2F89}', '\u{2F8A}', '\u{2F8B}',
+    '\u{2F8C}', '\u{2F8D}', '\u{2F8E}', '\u{2F8F}', '\u{2F90}', '\u{2F91}', '\u{2F92}', '\u{2F93}',
+    '\u{2F94}', '\u{2F95}', '\u{2F96}', '\u{2F97}', '\u{2F98}', '\u{2F99}', '\u{2F9A}', '\u{2F9B}',
+    '\u{2F9C}', '\u{2F9D}', '\u{2F9E}', '\u{2F9F}', '\u{2FA0}', '\u{2FA1}', '\u{2FA2}', '\u{2FA3}',
+    '\u{2FA4}', '\u{2FA5}', '\u{2FA6}', '\u{2FA7}', '\u{2FA8}', '\u{2FA9}', '\u{2FAA}', '\u{2FAB}',
+    '\u{2FAC}', '\u{2FAD}', '\u{2FAE}', '\u{2FAF}', '\u{2FB0}', '\u{2FB1}', '\u{2FB2}', '\u{2FB3}',
+    '\u{2FB4}', '\u{2FB5}', '\u{2FB6}', '\u{2FB7}', '\u{2FB8}', '\u{2FB9}', '\u{2FBA}', '\u{2FBB}',
+    '\u{2FBC}', '\u{2FBD}', '\u{2FBE}', '\u{2FBF}', '\u{2FC0}', '\u{2FC1}', '\u{2FC2}', '\u{2FC3}',
+    '\u{2FC4}', '\u{2FC5}', '\u{2FC6}', '\u{2FC7}', '\u{2FC8}', '\u{2FC9}', '\u{2FCA}', '\u{2FCB}',
+    '\u{2FCC}', '\u{2FCD}', '\u{2FCE}', '\u{2FCF}', '\u{2FD0}', '\u{2FD1}', '\u{2FD2}', '\u{2FD3}',
+    '\u{2FD4}', '\u{2FD5}', '\u{3000}', '\u{3036}', '\u{3038}', '\u{3039}', '\u{303A}', '\u{309B}',
+    '\u{309C}', '\u{309F}', '\u{30FF}', '\u{3131}', '\u{3132}', '\u{3133}', '\u{3134}', '\u{3135}',
+    '\u{3136}', '\u{3137}', '\u{3138}', '\u{3139}', '\u{313A}', '\u{313B}', '\u{313C}', '\u{313D}',
+    '\u{313E}', '\u{313F}', '\u{3140}', '\u{3141}', '\u{3142}', '\u{3143}', '\u{3144}', '\u{3145}',
+    '\u{3146}', '\u{3147}', '\u{3148}', '\u{3149}', '\u{314A}', '\u{314B}', '\u{314C}', '\u{314D}',
+    '\u{314E}', '\u{314F}', '\u{3150}', '\u{3151}', '\u{3152}', '\u{3153}', '\u{3154}', '\u{3155}',
+    '\u{3156}', '\u{3157}', '\u{3158}', '\u{3159}', '\u{315A}', '\u{315B}', '\u{315C}', '\u{315D}',
+    '\u{315E}', '\u{315F}', '\u{3160}', '\u{3161}', '\u{3162}', '\u{3163}', '\u{3165}', '\u{3166}',
+    '\u{3167}', '\u{3168}', '\u{3169}', '\u{316A}', '\u{316B}', '\u{316C}', '\u{316D}', '\u{316E}',
+    '\u{316F}', '\u{3170}', '\u{3171}', '\u{3172}', '\u{3173}', '\u{3174}', '\u{3175}', '\u{3176}',
+    '\u{3177}', '\u{3178}', '\u{3179}', '\u{317A}', '\u{317B}', '\u{317C}', '\u{317D}', '\u{317E}',
+    '\u{317F}', '\u{3180}', '\u{3181}', '\u{3182}', '\u{3183}', '\u{3184}', '\u{3185}', '\u{3186}',
+    '\u{3187}', '\u{3188}', '\u{3189}', '\u{318A}', '\u{318B}', '\u{318C}', '\u{318D}', '\u{318E}',
+    '\u{3192}', '\u{3193}', '\u{3194}', '\u{3195}', '\u{3196}', '\u{3197}', '\u{3198}', '\u{3199}',
+    '\u{319A}', '\u{319B}', '\u{319C}', '\u{319D}', '\u{319E}', '\u{319F}', '\u{3200}', '\u{3201}',
+    '\u{3202}', '\u{3203}', '\u{3204}', '\u{3205}', '\u{3206}', '\u{3207}', '\u{3208}', '\u{3209}',
+    '\u{320A}', '\u{320B}', '\u{320C}', '\u{320D}', '\u{320E}', '\u{320F}', '\u{3210}', '\u{3211}',
+    '\u{3212}', '\u{3213}', '\u{3214}', '\u{3215}', '\u{3216}', '\u{3217}', '\u{3218}', '\u{3219}',
+    '\u{321A}', '\u{321B}', '\u{321C}', '\u{321D}', '\u{321E}', '\u{3220}', '\u{3221}', '\u{3222}',
+    '\u{3223}', '\u{3224}', '\u{3225}', '\u{3226}', '\u{3227}', '\u{3228}', '\u{3229}', '\u{322A}',
+    '\u{322B}', '\u{322C}', '\u{322D}', '\u{322E}', '\u{322F}', '\u{3230}', '\u{3231}', '\u{3232}',
+    '\u{3233}', '\u{3234}', '\u{3235}', '\u{3236}', '\u{3237}', '\u{3238}', '\u{3239}', '\u{323A}',
+    '\u{323B}', '\u{323C}', '\u{323D}', '\u{323E}', '\u{323F}', '\u{3240}', '\u{3241}', '\u{3242}',
+    '\u{3243}', '\u{3244}', '\u{3245}', '\u{3246}', '\u{3247}', '\u{3250}', '\u{3251}', '\u{3252}',
+    '\u{3253}', '\u{3254}', '\u{3255}', '\u{3256}', '\u{3257}', '\u{3258}', '\u{3259}', '\u{325A}',
+    '\u{325B}', '\u{325C}', '\u{325D}', '\u{325E}', '\u{325F}', '\u{3260}', '\u{3261}', '\u{3262}',
+    '\u{3263}', '\u{3264}', '\u{3265}', '\u{3266}', '\u{3267}', '\u{3268}', '\u{3269}', '\u{326A}',
+    '\u{326B}', '\u{326C}', '\u{326D}', '\u{326E}', '\u{326F}', '\u{3270}', '\u{3271}', '\u{3272}',
+    '\u{3273}', '\u{3274}', '\u{3275}', '\u{3276}', '\u{3277}', '\u{3278}', '\u{3279}', '\u{327A}',
+    '\u{327B}', '\u{327C}', '\u{327D}', '\u{327E}', '\u{3280}', '\u{3281}', '\u{3282}', '\u{3283}',
+    '\u{3284}', '\u{3285}', '\u{3286}', '\u{3287}', '\u{3288}', '\u{3289}', '\u{328A}', '\u{328B}',
+    '\u{328C}', '\u{328D}', '\u{328E}', '\u{328F}', '\u{3290}', '\u{3291}', '\u{3292}', '\u{3293}',
+    '\u{3294}', '\u{3295}', '\u{3296}', '\u{3297}', '\u{3298}', '\u{3299}', '\u{329A}', '\u{329B}',
+    '\u{329C}', '\u{329D}', '\u{329E}', '\u{329F}', '\u{32A0}', '\u{32A1}', '\u{32A2}', '\u{32A3}',
+    '\u{32A4}', '\u{32A5}', '\u{32A6}', '\u{32A7}', '\u{32A8}', '\u{32A9}', '\u{32AA}', '\u{32AB}',
+    '\u{32AC}', '\u{32AD}', '\u{32AE}', '\u{32AF}', '\u{32B0}', '\u{32B1}', '\u{32B2}', '\u{32B3}',
+    '\u{32B4}', '\u{32B5}', '\u{32B6}', '\u{32B7}', '\u{32B8}', '\u{32B9}', '\u{32BA}', '\u{32BB}',
+    '\u{32BC}', '\u{32BD}', '\u{32BE}', '\u{32BF}', '\u{32C0}', '\u{32C1}', '\u{32C2}', '\u{32C3}',
+    '\u{32C4}', '\u{32C5}', '\u{32C6}', '\u{32C7}', '\u{32C8}', '\u{32C9}', '\u{32CA}', '\u{32CB}',
+    '\u{32CC}', '\u{32CD}', '\u{32CE}', '\u{32CF}', '\u{32D0}', '\u{32D1}', '\u{32D2}', '\u{32D3}',
+    '\u{32D4}', '\u{32D5}', '\u{32D6}', '\u{32D7}', '\u{32D8}', '\u{32D9}', '\u{32DA}', '\u{32DB}',
+    '\u{32DC}', '\u{32DD}', '\u{32DE}', '\u{32DF}', '\u{32E0}', '\u{32E1}', '\u{32E2}', '\u{32E3}',
+    '\u{32E4}', '\u{32E5}', '\u{32E6}', '\u{32E7}', '\u{32E8}', '\u{32E9}', '\u{32EA}', '\u{32EB}',
+    '\u{32EC}', '\u{32ED}', '\u{32EE}', '\u{32EF}', '\u{32F0}', '\u{32F1}', '\u{32F2}', '\u{32F3}',
+    '\u{32F4}', '\u{32F5}', '\u{32F6}', '\u{32F7}', '\u{32F8}', '\u{32F9}', '\u{32FA}', '\u{32FB}',
+    '\u{32FC}', '\u{32FD}', '\u{32FE}', '\u{32FF}', '\u{3300}', '\u{3301}', '\u{3302}', '\u{3303}',
+    '\u{3304}', '\u{3305}', '\u{3306}', '\u{3307}', '\u{3308}', '\u{3309}', '\u{330A}', '\u{330B}',
+    '\u{330C}', '\u{330D}', '\u{330E}', '\u{330F}', '\u{3310}', '\u{3311}', '\u{3312}', '\u{3313}',
+    '\u{3314}', '\u{3315}', '\u{3316}', '\u{3317}', '\u{3318}', '\u{3319}', '\u{331A}', '\u{331B}',
+    '\u{331C}', '\u{331D}', '\u{331E}', '\u{331F}', '\u{3320}', '\u{3321}', '\u{3322}', '\u{3323}',
+    '\u{3324}', '\u{3325}', '\u{3326}', '\u{3327}', '\u{3328}', '\u{3329}', '\u{332A}', '\u{332B}',
+    '\u{332C}', '\u{332D}', '\u{332E}', '\u{332F}', '\u{3330}', '\u{3331}', '\u{3332}', '\u{3333}',
+    '\u{3334}', '\u{3335}', '\u{3336}', '\u{3337}', '\u{3338}', '\u{3339}', '\u{333A}', '\u{333B}',
+    '\u{333C}', '\u{333D}', '\u{333E}', '\u{333F}', '\u{3340}', '\u{3341}', '\u{3342}', '\u{3343}',
+    '\u{3344}', '\u{3345}', '\u{3346}', '\u{3347}', '\u{3348}', '\u{3349}', '\u{334A}', '\u{334B}',
+    '\u{334C}', '\u{334D}', '\u{334E}', '\u{334F}', '\u{3350}', '\u{3351}', '\u{3352}', '\u{3353}',
+    '\u{3354}', '\u{3355}', '\u{3356}', '\u{3357}', '\u{3358}', '\u{3359}', '\u{335A}', '\u{335B}',
+    '\u{335C}', '\u{335D}', '\u{335E}', '\u{335F}', '\u{3360}', '\u{3361}', '\u{3362}', '\u{3363}',
+    '\u{3364}', '\u{3365}', '\u{3366}', '\u{3367}', '\u{3368}', '\u{3369}', '\u{336A}', '\u{336B}',
+    '\u{336C}', '\u{336D}', '\u{336E}', '\u{336F}', '\u{3370}', '\u{3371}', '\u{3372}', '\u{3373}',
+    '\u{3374}', '\u{3375}', '\u{3376}', '\u{3377}', '\u{3378}', '\u{3379}', '\u{337A}', '\u{337B}',
+    '\u{337C}', '\u{337D}', '\u{337E}', '\u{337F}', '\u{3380}', '\u{3381}', '\u{3382}', '\u{3383}',
+    '\u{3384}', '\u{3385}', '\u{3386}', '\u{3387}', '\u{3388}', '\u{3389}', '\u{338A}', '\u{338B}',
+    '\u{338C}', '\u{338D}', '\u{338E}', '\u{338F}', '\u{3390}', '\u{3391}', '\u{3392}', '\u{3393}',
+    '\u{3394}', '\u{3395}', '\u{3396}', '\u{3397}', '\u{3398}', '\u{3399}', '\u{339A}', '\u{339B}',
+    '\u{339C}', '\u{339D}', '\u{339E}', '\u{339F}', '\u{33A0}', '\u{33A1}', '\u{33A2}', '\u{33A3}',
+    '\u{33A4}', '\u{33A5}', '\u{33A6}', '\u{33A7}', '\u{33A8}', '\u{33A9}', '\u{33AA}', '\u{33AB}',
+    '\u{33AC}', '\u{33AD}', '\u{33AE}', '\u{33AF}', '\u{33B0}', '\u{33B1}', '\u{33B2}', '\u{33B3}',
+    '\u{33B4}', '\u{33B5}', '\u{33B6}', '\u{33B7}', '\u{33B8}', '\u{33B9}', '\u{33BA}', '\u{33BB}',
+    '\u{33BC}', '\u{33BD}', '\u{33BE}', '\u{33BF}', '\u{33C0}', '\u{33C1}', '\u{33C2}', '\u{33C3}',
+    '\u{33C4}', '\u{33C5}', '\u{33C6}', '\u{33C7}', '\u{33C8}', '\u{33C9}', '\u{33CA}', '\u{33CB}',
+    '\u{33CC}', '\u{33CD}', '\u{33CE}', '\u{33CF}', '\u{33D0}', '\u{33D1}', '\u{33D2}', '\u{33D3}',
+    '\u{33D4}', '\u{33D5}', '\u{33D6}', '\u{33D7}', '\u{33D8}', '\u{33D9}', '\u{33DA}', '\u{33DB}',
+    '\u{33DC}', '\u{33DD}', '\u{33DE}', '\u{33DF}', '\u{33E0}', '\u{33E1}', '\u{33E2}', '\u{33E3}',
+    '\u{33E4}', '\u{33E5}', '\u{33E6}', '\u{33E7}', '\u{33E8}', '\u{33E9}', '\u{33EA}', '\u{33EB}',
+    '\u{33EC}', '\u{33ED}', '\u{33EE}', '\u{33EF}', '\u{33F0}', '\u{33F1}', '\u{33F2}', '\u{33F3}',
+    '\u{33F4}', '\u{33F5}', '\u{33F6}', '\u{33F7}', '\u{33F8}', '\u{33F9}', '\u{33FA}', '\u{33FB}',
+    '\u{33FC}', '\u{33FD}', '\u{33FE}', '\u{33FF}', '\u{A640}', '\u{A642}', '\u{A644}', '\u{A646}',
+    '\u{A648}', '\u{A64A}', '\u{A64C}', '\u{A64E}', '\u{A650}', '\u{A652}', '\u{A654}', '\u{A656}',
+    '\u{A658}', '\u{A65A}', '\u{A65C}', '\u{A65E}', '\u{A660}', '\u{A662}', '\u{A664}', '\u{A666}',
+    '\u{A668}', '\u{A66A}', '\u{A66C}', '\u{A680}', '\u{A682}', '\u{A684}', '\u{A686}', '\u{A688}',
+    '\u{A68A}', '\u{A68C}', '\u{A68E}', '\u{A690}', '\u{A692}', '\u{A694}', '\u{A696}', '\u{A698}',
+    '\u{A69A}', '\u{A69C}', '\u{A69D}', '\u{A722}', '\u{A724}', '\u{A726}', '\u{A728}', '\u{A72A}',
+    '\u{A72C}', '\u{A72E}', '\u{A732}', '\u{A734}', '\u{A736}', '\u{A738}', '\u{A73A}', '\u{A73C}',
+    '\u{A73E}', '\u{A740}', '\u{A742}', '\u{A744}', '\u{A746}', '\u{A748}', '\u{A74A}', '\u{A74C}',
+    '\u{A74E}', '\u{A750}', '\u{A752}', '\u{A754}', '\u{A756}', '\u{A758}', '\u{A75A}', '\u{A75C}',
+    '\u{A75E}', '\u{A760}', '\u{A762}', '\u{A764}', '\u{A766}', '\u{A768}', '\u{A76A}', '\u{A76C}',
+    '\u{A76E}', '\u{A770}', '\u{A779}', '\u{A77B}', '\u{A77D}', '\u{A77E}', '\u{A780}', '\u{A782}',
+    '\u{A784}', '\u{A786}', '\u{A78B}', '\u{A78D}', '\u{A790}', '\u{A792}', '\u{A796}', '\u{A798}',
+    '\u{A79A}', '\u{A79C}', '\u{A79E}', '\u{A7A0}', '\u{A7A2}', '\u{A7A4}', '\u{A7A6}', '\u{A7A8}',
+    '\u{A7AA}', '\u{A7AB}', '\u{A7AC}', '\u{A7AD}', '\u{A7AE}', '\u{A7B0}', '\u{A7B1}', '\u{A7B2}',
+    '\u{A7B3}', '\u{A7B4}', '\u{A7B6}', '\u{A7B8}', '\u{A7BA}', '\u{A7BC}', '\u{A7BE}', '\u{A7C0}',
+    '\u{A7C2}', '\u{A7C4}', '\u{A7C5}', '\u{A7C6}', '\u{A7C7}', '\u{A7C9}', '\u{A7CB}', '\u{A7CC}',
+    '\u{A7CE}', '\u{A7D0}', '\u{A7D2}', '\u{A7D4}', '\u{A7D6}', '\u{A7D8}', '\u{A7DA}', '\u{A7DC}',
+    '\u{A7F1}', '\u{A7F2}', '\u{A7F3}', '\u{A7F4}', '\u{A7F5}', '\u{A7F8}', '\u{A7F9}', '\u{AB5C}',
+    '\u{AB5D}', '\u{AB5E}', '\u{AB5F}', '\u{AB69}', '\u{AB70}', '\u{AB71}', '\u{AB72}', '\u{AB73}',
+    '\u{AB74}', '\u{AB75}', '\u{AB76}', '\u{AB77}', '\u{AB78}', '\u{AB79}', '\u{AB7A}', '\u{AB7B}',
+    '\u{AB7C}', '\u{AB7D}', '\u{AB7E}', '\u{AB7F}', '\u{AB80}', '\u{AB81}', '\u{AB82}', '\u{AB83}',
+    '\u{AB84}', '\u{AB85}', '\u{AB86}', '\u{AB87}', '\u{AB88}', '\u{AB89}', '\u{AB8A}', '\u{AB8B}',
+    '\u{AB8C}', '\u{AB8D}', '\u{AB8E}', '\u{AB8F}', '\u{AB90}', '\u{AB91}', '\u{AB92}', '\u{AB93}',
+    '\u{AB94}', '\u{AB95}', '\u{AB96}', '\u{AB97}', '\u{AB98}', '\u{AB99}', '\u{AB9A}', '\u{AB9B}',
+    '\u{AB9C}', '\u{AB9D}', '\u{AB9E}', '\u{AB9F}', '\u{ABA0}', '\u{ABA1}', '\u{ABA2}', '\u{ABA3}',
+    '\u{ABA4}', '\u{ABA5}', '\u{ABA6}', '\u{ABA7}', '\u{ABA8}', '\u{ABA9}', '\u{ABAA}', '\u{ABAB}',
+    '\u{ABAC}', '\u{ABAD}', '\u{ABAE}', '\u{ABAF}', '\u{ABB0}', '\u{ABB1}', '\u{ABB2}', '\u{ABB3}',
+    '\u{ABB4}', '\u{ABB5}', '\u{ABB6}', '\u{ABB7}', '\u{ABB8}', '\u{ABB9}', '\u{ABBA}', '\u{ABBB}',
+    '\u{ABBC}', '\u{ABBD}', '\u{ABBE}', '\u{ABBF}', '\u{F900}', '\u{F901}', '\u{F902}', '\u{F903}',
+    '\u{F904}', '\u{F905}', '\u{F906}', '\u{F907}', '\u{F908}', '\u{F909}', '\u{F90A}', '\u{F90B}',
+    '\u{F90C}', '\u{F90D}', '\u{F90E}', '\u{F90F}', '\u{F910}', '\u{F911}', '\u{F912}', '\u{F913}',
+    '\u{F914}', '\u{F915}', '\u{F916}', '\u{F917}', '\u{F918}', '\u{F919}', '\u{F91A}', '\u{F91B}',
+    '\u{F91C}', '\u{F91D}', '\u{F91E}', '\u{F91F}', '\u{F920}', '\u{F921}', '\u{F922}', '\u{F923}',
+    '\u{F924}', '\u{F925}', '\u{F926}', '\u{F927}', '\u{F928}', '\u{F929}', '\u{F92A}', '\u{F92B}',
+    '\u{F92C}', '\u{F92D}', '\u{F92E}', '\u{F92F}', '\u{F930}', '\u{F931}', '\u{F932}', '\u{F933}',
+    '\u{F934}', '\u{F935}', '\u{F936}', '\u{F937}', '\u{F938}', '\u{F939}', '\u{F93A}', '\u{F93B}',
+    '\u{F93C}', '\u{F93D}', '\u{F93E}', '\u{F93F}', '\u{F940}', '\u{F941}', '\u{F942}', '\u{F943}',
+    '\u{F944}', '\u{F945}', '\u{F946}', '\u{F947}', '\u{F948}', '\u{F949}', '\u{F94A}', '\u{F94B}',
+    '\u{F94C}', '\u{F94D}', '\u{F94E}', '\u{F94F}', '\u{F950}', '\u{F951}', '\u{F952}', '\u{F953}',
+    '\u{F954}', '\u{F955}', '\u{F956}', '\u{F957}', '\u{F958}', '\u{F959}', '\u{F95A}', '\u{F95B}',
+    '\u{F95C}', '\u{F95D}', '\u{F95E}', '\u{F95F}', '\u{F960}', '\u{F961}', '\u{F962}', '\u{F963}',
+    '\u{F964}', '\u{F965}', '\u{F966}', '\u{F967}', '\u{F968}', '\u{F969}', '\u{F96A}', '\u{F96B}',
+    '\u{F96C}', '\u{F96D}', '\u{F96E}', '\u{F96F}', '\u{F970}', '\u{F971}', '\u{F972}', '\u{F973}',
+    '\u{F974}', '\u{F975}', '\u{F976}', '\u{F977}', '\u{F978}', '\u{F979}', '\u{F97A}', '\u{F97B}',
+    '\u{F97C}', '\u{F97D}', '\u{F97E}', '\u{F97F}', '\u{F980}', '\u{F981}', '\u{F982}', '\u{F983}',
+    '\u{F984}', '\u{F985}', '\u{F986}', '\u{F987}', '\u{F988}', '\u{F989}', '\u{F98A}', '\u{F98B}',
+    '\u{F98C}', '\u{F98D}', '\u{F98E}', '\u{F98F}', '\u{F990}', '\u{F991}', '\u{F992}', '\u{F993}',
+    '\u{F994}', '\u{F995}', '\u{F996}', '\u{F997}', '\u{F998}', '\u{F999}', '\u{F99A}', '\u{F99B}',
+    '\u{F99C}', '\u{F99D}', '\u{F99E}', '\u{F99F}', '\u{F9A0}', '\u{F9A1}', '\u{F9A2}', '\u{F9A3}',
+    '\u{F9A4}', '\u{F9A5}', '\u{F9A6}', '\u{F9A7}', '\u{F9A8}', '\u{F9A9}', '\u{F9AA}', '\u{F9AB}',
+    '\u{F9AC}', '\u{F9AD}', '\u{F9AE}', '\u{F9AF}', '\u{F9B0}', '\u{F9B1}', '\u{F9B2}', '\u{F9B3}',
+    '\u{F9B4}', '\u{F9B5}', '\u{F9B6}', '\u{F9B7}', '\u{F9B8}', '\u{F9B9}', '\u{F9BA}', '\u{F9BB}',
+    '\u{F9BC}', '\u{F9BD}', '\u{F9BE}', '\u{F9BF}', '\u{F9C0}', '\u{F9C1}', '\u{F9C2}', '\u{F9C3}',
+    '\u{F9C4}', '\u{F9C5}', '\u{F9C6}', '\u{F9C7}', '\u{F9C8}', '\u{F9C9}', '\u{F9CA}', '\u{F9CB}',
+    '\u{F9CC}', '\u{F9CD}', '\u{F9CE}', '\u{F9CF}', '\u{F9D0}', '\u{F9D1}', '\u{F9D2}', '\u{F9D3}',
+    '\u{F9D4}', '\u{F9D5}', '\u{F9D6}', '\u{F9D7}', '\u{F9D8}', '\u{F9D9}', '\u{F9DA}', '\u{F9DB}',
+    '\u{F9DC}', '\u{F9DD}', '\u{F9DE}', '\u{F9DF}', '\u{F9E0}', '\u{F9E1}', '\u{F9E2}', '\u{F9E3}',
+    '\u{F9E4}', '\u{F9E5}', '\u{F9E6}', '\u{F9E7}', '\u{F9E8}', '\u{F9E9}', '\u{F9EA}', '\u{F9EB}',
+    '\u{F9EC}', '\u{F9ED}', '\u{F9EE}', '\u{F9EF}', '\u{F9F0}', '\u{F9F1}', '\u{F9F2}', '\u{F9F3}',
+    '\u{F9F4}', '\u{F9F5}', '\u{F9F6}', '\u{F9F7}', '\u{F9F8}', '\u{F9F9}', '\u{F9FA}', '\u{F9FB}',
+    '\u{F9FC}', '\u{F9FD}', '\u{F9FE}', '\u{F9FF}', '\u{FA00}', '\u{FA01}', '\u{FA02}', '\u{FA03}',
+    '\u{FA04}', '\u{FA05}', '\u{FA06}', '\u{FA07}', '\u{FA08}', '\u{FA09}', '\u{FA0A}', '\u{FA0B}',
+    '\u{FA0C}', '\u{FA0D}', '\u{FA10}', '\u{FA12}', '\u{FA15}', '\u{FA16}', '\u{FA17}', '\u{FA18}',
+    '\u{FA19}', '\u{FA1A}', '\u{FA1B}', '\u{FA1C}', '\u{FA1D}', '\u{FA1E}', '\u{FA20}', '\u{FA22}',
+    '\u{FA25}', '\u{FA26}', '\u{FA2A}', '\u{FA2B}', '\u{FA2C}', '\u{FA2D}', '\u{FA2E}', '\u{FA2F}',
+    '\u{FA30}', '\u{FA31}', '\u{FA32}', '\u{FA33}', '\u{FA34}', '\u{FA35}', '\u{FA36}', '\u{FA37}',
+    '\u{FA38}', '\u{FA39}', '\u{FA3A}', '\u{FA3B}', '\u{FA3C}', '\u{FA3D}', '\u{FA3E}', '\u{FA3F}',
+    '\u{FA40}', '\u{FA41}', '\u{FA42}', '\u{FA43}', '\u{FA44}', '\u{FA45}', '\u{FA46}', '\u{FA47}',
+    '\u{FA48}', '\u{FA49}', '\u{FA4A}', '\u{FA4B}', '\u{FA4C}', '\u{FA4D}', '\u{FA4E}', '\u{FA4F}',
+    '\u{FA50}', '\u{FA51}', '\u{FA52}', '\u{FA53}', '\u{FA54}', '\u{FA55}', '\u{FA56}', '\u{FA57}',
+    '\u{FA58}', '\u{FA59}', '\u{FA5A}', '\u{FA5B}', '\u{FA5C}', '\u{FA5D}', '\u{FA5E}', '\u{FA5F}',
+    '\u{FA60}', '\u{FA61}', '\u{FA62}', '\u{FA63}', '\u{FA64}', '\u{FA65}', '\u{FA66}', '\u{FA67}',
+    '\u{FA68}', '\u{FA69}', '\u{FA6A}', '\u{FA6B}', '\u{FA6C}', '\u{FA6D}', '\u{FA70}', '\u{FA71}',
+    '\u{FA72}', '\u{FA73}', '\u{FA74}', '\u{FA75}', '\u{FA76}', '\u{FA77}', '\u{FA78}', '\u{FA79}',
+    '\u{FA7A}', '\u{FA7B}', '\u{FA7C}', '\u{FA7D}', '\u{FA7E}', '\u{FA7F}', '\u{FA80}', '\u{FA81}',
+    '\u{FA82}', '\u{FA83}', '\u{FA84}', '\u{FA85}', '\u{FA86}', '\u{FA87}', '\u{FA88}', '\u{FA89}',
+    '\u{FA8A}', '\u{FA8B}', '\u{FA8C}', '\u{FA8D}', '\u{FA8E}', '\u{FA8F}', '\u{FA90}', '\u{FA91}',
+    '\u{FA92}', '\u{FA93}', '\u{FA94}', '\u{FA95}', '\u{FA96}', '\u{FA97}', '\u{FA98}', '\u{FA99}',
+    '\u{FA9A}', '\u{FA9B}', '\u{FA9C}', '\u{FA9D}', '\u{FA9E}', '\u{FA9F}', '\u{FAA0}', '\u{FAA1}',
+    '\u{FAA2}', '\u{FAA3}', '\u{FAA4}', '\u{FAA5}', '\u{FAA6}', '\u{FAA7}', '\u{FAA8}', '\u{FAA9}',
+    '\u{FAAA}', '\u{FAAB}', '\u{FAAC}', '\u{FAAD}', '\u{FAAE}', '\u{FAAF}', '\u{FAB0}', '\u{FAB1}',
+    '\u{FAB2}', '\u{FAB3}', '\u{FAB4}', '\u{FAB5}', '\u{FAB6}', '\u{FAB7}', '\u{FAB8}', '\u{FAB9}',
+    '\u{FABA}', '\u{FABB}', '\u{FABC}', '\u{FABD}', '\u{FABE}', '\u{FABF}', '\u{FAC0}', '\u{FAC1}',
+    '\u{FAC2}', '\u{FAC3}', '\u{FAC4}', '\u{FAC5}', '\u{FAC6}', '\u{FAC7}', '\u{FAC8}', '\u{FAC9}',
+    '\u{FACA}', '\u{FACB}', '\u{FACC}', '\u{FACD}', '\u{FACE}', '\u{FACF}', '\u{FAD0}', '\u{FAD1}',
+    '\u{FAD2}', '\u{FAD3}', '\u{FAD4}', '\u{FAD5}', '\u{FAD6}', '\u{FAD7}', '\u{FAD8}', '\u{FAD9}',
+    '\u{FB00}', '\u{FB01}', '\u{FB02}', '\u{FB03}', '\u{FB04}', '\u{FB05}', '\u{FB06}', '\u{FB13}',
+    '\u{FB14}', '\u{FB15}', '\u{FB16}', '\u{FB17}', '\u{FB1D}', '\u{FB1F}', '\u{FB20}', '\u{FB21}',
+    '\u{FB22}', '\u{FB23}', '\u{FB24}', '\u{FB25}', '\u{FB26}', '\u{FB27}', '\u{FB28}', '\u{FB29}',
+    '\u{FB2A}', '\u{FB2B}', '\u{FB2C}', '\u{FB2D}', '\u{FB2E}', '\u{FB2F}', '\u{FB30}', '\u{FB31}',
+    '\u{FB32}', '\u{FB33}', '\u{FB34}', '\u{FB35}', '\u{FB36}', '\u{FB38}', '\u{FB39}', '\u{FB3A}',
+    '\u{FB3B}', '\u{FB3C}', '\u{FB3E}', '\u{FB40}', '\u{FB41}', '\u{FB43}', '\u{FB44}', '\u{FB46}',
+    '\u{FB47}', '\u{FB48}', '\u{FB49}', '\u{FB4A}', '\u{FB4B}', '\u{FB4C}', '\u{FB4D}', '\u{FB4E}',
+    '\u{FB4F}', '\u{FB50}', '\u{FB51}', '\u{FB52}', '\u{FB53}', '\u{FB54}', '\u{FB55}', '\u{FB56}',
+    '\u{FB57}', '\u{FB58}', '\u{FB59}', '\u{FB5A}', '\u{FB5B}', '\u{FB5C}', '\u{FB5D}', '\u{FB5E}',
+    '\u{FB5F}', '\u{FB60}', '\u{FB61}', '\u{FB62}', '\u{FB63}', '\u{FB64}', '\u{FB65}', '\u{FB66}',
+    '\u{FB67}', '\u{FB68}', '\u{FB69}', '\u{FB6A}', '\u{FB6B}', '\u{FB6C}', '\u{FB6D}', '\u{FB6E}',
+    '\u{FB6F}', '\u{FB70}', '\u{FB71}', '\u{FB72}', '\u{FB73}', '\u{FB74}', '\u{FB75}', '\u{FB76}',
+    '\u{FB77}', '\u{FB78}', '\u{FB79}', '\u{FB7A}', '\u{FB7B}', '\u{FB7C}', '\u{FB7D}', '\u{FB7E}',
+    '\u{FB7F}', '\u{FB80}', '\u{FB81}', '\u{FB82}', '\u{FB83}', '\u{FB84}', '\u{FB85}', '\u{FB86}',
+    '\u{FB87}', '\u{FB88}', '\u{FB89}', '\u{FB8A}', '\u{FB8B}', '\u{FB8C}', '\u{FB8D}', '\u{FB8E}',
+    '\u{FB8F}', '\u{FB90}', '\u{FB91}', '\u{FB92}', '\u{FB93}', '\u{FB94}', '\u{FB95}', '\u{FB96}',
+    '\u{FB97}', '\u{FB98}', '\u{FB99}', '\u{FB9A}', '\u{FB9B}', '\u{FB9C}', '\u{FB9D}', '\u{FB9E}',
+    '\u{FB9F}', '\u{FBA0}', '\u{FBA1}', '\u{FBA2}', '\u{FBA3}', '\u{FBA4}', '\u{FBA5}', '\u{FBA6}',
+    '\u{FBA7}', '\u{FBA8}', '\u{FBA9}', '\u{FBAA}', '\u{FBAB}', '\u{FBAC}', '\u{FBAD}', '\u{FBAE}',
+    '\u{FBAF}', '\u{FBB0}', '\u{FBB1}', '\u{FBD3}', '\u{FBD4}', '\u{FBD5}', '\u{FBD6}', '\u{FBD7}',
+    '\u{FBD8}', '\u{FBD9}', '\u{FBDA}', '\u{FBDB}', '\u{FBDC}', '\u{FBDD}', '\u{FBDE}', '\u{FBDF}',
+    '\u{FBE0}', '\u{FBE1}', '\u{FBE2}', '\u{FBE3}', '\u{FBE4}', '\u{FBE5}', '\u{FBE6}', '\u{FBE7}',
+    '\u{FBE8}', '\u{FBE9}', '\u{FBEA}', '\u{FBEB}', '\u{FBEC}', '\u{FBED}', '\u{FBEE}', '\u{FBEF}',
+    '\u{FBF0}', '\u{FBF1}', '\u{FBF2}', '\u{FBF3}', '\u{FBF4}', '\u{FBF5}', '\u{FBF6}', '\u{FBF7}',
+    '\u{FBF8}', '\u{FBF9}', '\u{FBFA}', '\u{FBFB}', '\u{FBFC}', '\u{FBFD}', '\u{FBFE}', '\u{FBFF}',
+    '\u{FC00}', '\u{FC01}', '\u{FC02}', '\u{FC03}', '\u{FC04}', '\u{FC05}', '\u{FC06}', '\u{FC07}',
+    '\u{FC08}', '\u{FC09}', '\u{FC0A}', '\u{FC0B}', '\u{FC0C}', '\u{FC0D}', '\u{FC0E}', '\u{FC0F}',
+    '\u{FC10}', '\u{FC11}', '\u{FC12}', '\u{FC13}', '\u{FC14}', '\u{FC15}', '\u{FC16}', '\u{FC17}',
+    '\u{FC18}', '\u{FC19}', '\u{FC1A}', '\u{FC1B}', '\u{FC1C}', '\u{FC1D}', '\u{FC1E}', '\u{FC1F}',
+    '\u{FC20}', '\u{FC21}', '\u{FC22}', '\u{FC23}', '\u{FC24}', '\u{FC25}', '\u{FC26}', '\u{FC27}',
+    '\u{FC28}', '\u{FC29}', '\u{FC2A}', '\u{FC2B}', '\u{FC2C}', '\u{FC2D}', '\u{FC2E}', '\u{FC2F}',
+    '\u{FC30}', '\u{FC31}', '\u{FC32}', '\u{FC33}', '\u{FC34}', '\u{FC35}', '\u{FC36}', '\u{FC37}',
+    '\u{FC38}', '\u{FC39}', '\u{FC3A}', '\u{FC3B}', '\u{FC3C}', '\u{FC3D}', '\u{FC3E}', '\u{FC3F}',
+    '\u{FC40}', '\u{FC41}', '\u{FC42}', '\u{FC43}', '\u{FC44}', '\u{FC45}', '\u{FC46}', '\u{FC47}',
+    '\u{FC48}', '\u{FC49}', '\u{FC4A}', '\u{FC4B}', '\u{FC4C}', '\u{FC4D}', '\u{FC4E}', '\u{FC4F}',
+    '\u{FC50}', '\u{FC51}', '\u{FC52}', '\u{FC53}', '\u{FC54}', '\u{FC55}', '\u{FC56}', '\u{FC57}',
+    '\u{FC58}', '\u{FC59}', '\u{FC5A}', '\u{FC5B}', '\u{FC5C}', '\u{FC5D}', '\u{FC5E}', '\u{FC5F}',
+    '\u{FC60}', '\u{FC61}', '\u{FC62}', '\u{FC63}', '\u{FC64}', '\u{FC65}', '\u{FC66}', '\u{FC67}',
+    '\u{FC68}', '\u{FC69}', '\u{FC6A}', '\u{FC6B}', '\u{FC6C}', '\u{FC6D}', '\u{FC6E}', '\u{FC6F}',
+    '\u{FC70}', '\u{FC71}', '\u{FC72}', '\u{FC73}', '\u{FC74}', '\u{FC75}', '\u{FC76}', '\u{FC77}',
+    '\u{FC78}', '\u{FC79}', '\u{FC7A}', '\u{FC7B}', '\u{FC7C}', '\u{FC7D}', '\u{FC7E}', '\u{FC7F}',
+    '\u{FC80}', '\u{FC81}', '\u{FC82}', '\u{FC83}', '\u{FC84}', '\u{FC85}', '\u{FC86}', '\u{FC87}',
+    '\u{FC88}', '\u{FC89}', '\u{FC8A}', '\u{FC8B}', '\u{FC8C}', '\u{FC8D}', '\u{FC8E}', '\u{FC8F}',
+    '\u{FC90}', '\u{FC91}', '\u{FC92}', '\u{FC93}', '\u{FC94}', '\u{FC95}', '\u{FC96}', '\u{FC97}',
+    '\u{FC98}', '\u{FC99}', '\u{FC9A}', '\u{FC9B}', '\u{FC9C}', '\u{FC9D}', '\u{FC9E}', '\u{FC9F}',
+    '\u{FCA0}', '\u{FCA1}', '\u{FCA2}', '\u{FCA3}', '\u{FCA4}', '\u{FCA5}', '\u{FCA6}', '\u{FCA7}',
+    '\u{FCA8}', '\u{FCA9}', '\u{FCAA}', '\u{FCAB}', '\u{FCAC}', '\u{FCAD}', '\u{FCAE}', '\u{FCAF}',
+    '\u{FCB0}', '\u{FCB1}', '\u{FCB2}', '\u{FCB3}', '\u{FCB4}', '\u{FCB5}', '\u{FCB6}', '\u{FCB7}',
+    '\u{FCB8}', '\u{FCB9}', '\u{FCBA}', '\u{FCBB}', '\u{FCBC}', '\u{FCBD}', '\u{FCBE}', '\u{FCBF}',
+    '\u{FCC0}', '\u{FCC1}', '\u{FCC2}', '\u{FCC3}', '\u{FCC4}', '\u{FCC5}', '\u{FCC6}', '\u{FCC7}',
+    '\u{FCC8}', '\u{FCC9}', '\u{FCCA}', '\u{FCCB}', '\u{FCCC}', '\u{FCCD}', '\u{FCCE}', '\u{FCCF}',
+    '\u{FCD0}', '\u{FCD1}', '\u{FCD2}', '\u{FCD3}', '\u{FCD4}', '\u{FCD5}', '\u{FCD6}', '\u{FCD7}',
+    '\u{FCD8}', '\u{FCD9}', '\u{FCDA}', '\u{FCDB}', '\u{FCDC}', '\u{FCDD}', '\u{FCDE}', '\u{FCDF}',
+    '\u{FCE0}', '\u{FCE1}', '\u{FCE2}', '\u{FCE3}', '\u{FCE4}', '\u{FCE5}', '\u{FCE6}', '\u{FCE7}',
+    '\u{FCE8}', '\u{FCE9}', '\u{FCEA}', '\u{FCEB}', '\u{FCEC}', '\u{FCED}', '\u{FCEE}', '\u{FCEF}',
+    '\u{FCF0}', '\u{FCF1}', '\u{FCF2}', '\u{FCF3}', '\u{FCF4}', '\u{FCF5}', '\u{FCF6}', '\u{FCF7}',
+    '\u{FCF8}', '\u{FCF9}', '\u{FCFA}', '\u{FCFB}', '\u{FCFC}', '\u{FCFD}', '\u{FCFE}', '\u{FCFF}',
+    '\u{FD00}', '\u{FD01}', '\u{FD02}', '\u{FD03}', '\u{FD04}', '\u{FD05}', '\u{FD06}', '\u{FD07}',
+    '\u{FD08}', '\u{FD09}', '\u{FD0A}', '\u{FD0B}', '\u{FD0C}', '\u{FD0D}', '\u{FD0E}', '\u{FD0F}',
+    '\u{FD10}', '\u{FD11}', '\u{FD12}', '\u{FD13}', '\u{FD14}', '\u{FD15}', '\u{FD16}', '\u{FD17}',
+    '\u{FD18}', '\u{FD19}', '\u{FD1A}', '\u{FD1B}', '\u{FD1C}', '\u{FD1D}', '\u{FD1E}', '\u{FD1F}',
+    '\u{FD20}', '\u{FD21}', '\u{FD22}', '\u{FD23}', '\u{FD24}', '\u{FD25}', '\u{FD26}', '\u{FD27}',
+    '\u{FD28}', '\u{FD29}', '\u{FD2A}', '\u{FD2B}', '\u{FD2C}', '\u{FD2D}', '\u{FD2E}', '\u{FD2F}',
+    '\u{FD30}', '\u{FD31}', '\u{FD32}', '\u{FD33}', '\u{FD34}', '\u{FD35}', '\u{FD36}', '\u{FD37}',
+    '\u{FD38}', '\u{FD39}', '\u{FD3A}', '\u{FD3B}', '\u{FD3C}', '\u{FD3D}', '\u{FD50}', '\u{FD51}',
+    '\u{FD52}', '\u{FD53}', '\u{FD54}', '\u{FD55}', '\u{FD56}', '\u{FD57}', '\u{FD58}', '\u{FD59}',
+    '\u{FD5A}', '\u{FD5B}', '\u{FD5C}', '\u{FD5D}', '\u{FD5E}', '\u{FD5F}', '\u{FD60}', '\u{FD61}',
+    '\u{FD62}', '\u{FD63}', '\u{FD64}', '\u{FD65}', '\u{FD66}', '\u{FD67}', '\u{FD68}', '\u{FD69}',
+    '\u{FD6A}', '\u{FD6B}', '\u{FD6C}', '\u{FD6D}', '\u{FD6E}', '\u{FD6F}', '\u{FD70}', '\u{FD71}',
+    '\u{FD72}', '\u{FD73}', '\u{FD74}', '\u{FD75}', '\u{FD76}', '\u{FD77}', '\u{FD78}', '\u{FD79}',
+    '\u{FD7A}', '\u{FD7B}', '\u{FD7C}', '\u{FD7D}', '\u{FD7E}', '\u{FD7F}', '\u{FD80}', '\u{FD81}',
+    '\u{FD82}', '\u{FD83}', '\u{FD84}', '\u{FD85}', '\u{FD86}', '\u{FD87}', '\u{FD88}', '\u{FD89}',
+    '\u{FD8A}', '\u{FD8B}', '\u{FD8C}', '\u{FD8D}', '\u{FD8E}', '\u{FD8F}', '\u{FD92}', '\u{FD93}',
+    '\u{FD94}', '\u{FD95}', '\u{FD96}', '\u{FD97}', '\u{FD98}', '\u{FD99}', '\u{FD9A}', '\u{FD9B}',
+    '\u{FD9C}', '\u{FD9D}', '\u{FD9E}', '\u{FD9F}', '\u{FDA0}', '\u{FDA1}', '\u{FDA2}', '\u{FDA3}',
+    '\u{FDA4}', '\u{FDA5}', '\u{FDA6}', '\u{FDA7}', '\u{FDA8}', '\u{FDA9}', '\u{FDAA}', '\u{FDAB}',
+    '\u{FDAC}', '\u{FDAD}', '\u{FDAE}', '\u{FDAF}', '\u{FDB0}', '\u{FDB1}', '\u{FDB2}', '\u{FDB3}',
+    '\u{FDB4}', '\u{FDB5}', '\u{FDB6}', '\u{FDB7}', '\u{FDB8}', '\u{FDB9}', '\u{FDBA}', '\u{FDBB}',
+    '\u{FDBC}', '\u{FDBD}', '\u{FDBE}', '\u{FDBF}', '\u{FDC0}', '\u{FDC1}', '\u{FDC2}', '\u{FDC3}',
+    '\u{FDC4}', '\u{FDC5}', '\u{FDC6}', '\u{FDC7}', '\u{FDF0}', '\u{FDF1}', '\u{FDF2}', '\u{FDF3}',
+    '\u{FDF4}', '\u{FDF5}', '\u{FDF6}', '\u{FDF7}', '\u{FDF8}', '\u{FDF9}', '\u{FDFA}', '\u{FDFB}',
+    '\u{FDFC}', '\u{FE10}', '\u{FE11}', '\u{FE12}', '\u{FE13}', '\u{FE14}', '\u{FE15}', '\u{FE16}',
+    '\u{FE17}', '\u{FE18}', '\u{FE19}', '\u{FE30}', '\u{FE31}', '\u{FE32}', '\u{FE33}', '\u{FE34}',
+    '\u{FE35}', '\u{FE36}', '\u{FE37}', '\u{FE38}', '\u{FE39}', '\u{FE3A}', '\u{FE3B}', '\u{FE3C}',
+    '\u{FE3D}', '\u{FE3E}', '\u{FE3F}', '\u{FE40}', '\u{FE41}', '\u{FE42}', '\u{FE43}', '\u{FE44}',
+    '\u{FE47}', '\u{FE48}', '\u{FE49}', '\u{FE4A}', '\u{FE4B}', '\u{FE4C}', '\u{FE4D}', '\u{FE4E}',
+    '\u{FE4F}', '\u{FE50}', '\u{FE51}', '\u{FE52}', '\u{FE54}', '\u{FE55}', '\u{FE56}', '\u{FE57}',
+    '\u{FE58}', '\u{FE59}', '\u{FE5A}', '\u{FE5B}', '\u{FE5C}', '\u{FE5D}', '\u{FE5E}', '\u{FE5F}',
+    '\u{FE60}', '\u{FE61}', '\u{FE62}', '\u{FE63}', '\u{FE64}', '\u{FE65}', '\u{FE66}', '\u{FE68}',
+    '\u{FE69}', '\u{FE6A}', '\u{FE6B}', '\u{FE70}', '\u{FE71}', '\u{FE72}', '\u{FE74}', '\u{FE76}',
+    '\u{FE77}', '\u{FE78}', '\u{FE79}', '\u{FE7A}', '\u{FE7B}', '\u{FE7C}', '\u{FE7D}', '\u{FE7E}',
+    '\u{FE7F}', '\u{FE80}', '\u{FE81}', '\u{FE82}', '\u{FE83}', '\u{FE84}', '\u{FE85}', '\u{FE86}',
+    '\u{FE87}', '\u{FE88}', '\u{FE89}', '\u{FE8A}', '\u{FE8B}', '\u{FE8C}', '\u{FE8D}', '\u{FE8E}',
+    '\u{FE8F}', '\u{FE90}', '\u{FE91}', '\u{FE92}', '\u{FE93}', '\u{FE94}', '\u{FE95}', '\u{FE96}',
+    '\u{FE97}', '\u{FE98}', '\u{FE99}', '\u{FE9A}', '\u{FE9B}', '\u{FE9C}', '\u{FE9D}', '\u{FE9E}',
+    '\u{FE9F}', '\u{FEA0}', '\u{FEA1}', '\u{FEA2}', '\u{FEA3}', '\u{FEA4}', '\u{FEA5}', '\u{FEA6}',
+    '\u{FEA7}', '\u{FEA8}', '\u{FEA9}', '\u{FEAA}', '\u{FEAB}', '\u{FEAC}', '\u{FEAD}', '\u{FEAE}',
+    '\u{FEAF}', '\u{FEB0}', '\u{FEB1}', '\u{FEB2}', '\u{FEB3}', '\u{FEB4}', '\u{FEB5}', '\u{FEB6}',
+    '\u{FEB7}', '\u{FEB8}', '\u{FEB9}', '\u{FEBA}', '\u{FEBB}', '\u{FEBC}', '\u{FEBD}', '\u{FEBE}',
+    '\u{FEBF}', '\u{FEC0}', '\u{FEC1}', '\u{FEC2}', '\u{FEC3}', '\u{FEC4}', '\u{FEC5}', '\u{FEC6}',
+    '\u{FEC7}', '\u{FEC8}', '\u{FEC9}', '\u{FECA}', '\u{FECB}', '\u{FECC}', '\u{FECD}', '\u{FECE}',
+    '\u{FECF}', '\u{FED0}', '\u{FED1}', '\u{FED2}', '\u{FED3}', '\u{FED4}', '\u{FED5}', '\u{FED6}',
+    '\u{FED7}', '\u{FED8}', '\u{FED9}', '\u{FEDA}', '\u{FEDB}', '\u{FEDC}', '\u{FEDD}', '\u{FEDE}',
+    '\u{FEDF}', '\u{FEE0}', '\u{FEE1}', '\u{FEE2}', '\u{FEE3}', '\u{FEE4}', '\u{FEE5}', '\u{FEE6}',
+    '\u{FEE7}', '\u{FEE8}', '\u{FEE9}', '\u{FEEA}', '\u{FEEB}', '\u{FEEC}', '\u{FEED}', '\u{FEEE}',
+    '\u{FEEF}', '\u{FEF0}', '\u{FEF1}', '\u{FEF2}', '\u{FEF3}', '\u{FEF4}', '\u{FEF5}', '\u{FEF6}',
+    '\u{FEF7}', '\u{FEF8}', '\u{FEF9}', '\u{FEFA}', '\u{FEFB}', '\u{FEFC}', '\u{FF01}', '\u{FF02}',
+    '\u{FF03}', '\u{FF04}', '\u{FF05}', '\u{FF06}', '\u{FF07}', '\u{FF08}', '\u{FF09}', '\u{FF0A}',
+    '\u{FF0B}', '\u{FF0C}', '\u{FF0D}', '\u{FF0E}', '\u{FF0F}', '\u{FF10}', '\u{FF11}', '\u{FF12}',
+    '\u{FF13}', '\u{FF14}', '\u{FF15}', '\u{FF16}', '\u{FF17}', '\u{FF18}', '\u{FF19}', '\u{FF1A}',
+    '\u{FF1B}', '\u{FF1C}', '\u{FF1D}', '\u{FF1E}', '\u{FF1F}', '\u{FF20}', '\u{FF21}', '\u{FF22}',
+    '\u{FF23}', '\u{FF24}', '\u{FF25}', '\u{FF26}', '\u{FF27}', '\u{FF28}', '\u{FF29}', '\u{FF2A}',
+    '\u{FF2B}', '\u{FF2C}', '\u{FF2D}', '\u{FF2E}', '\u{FF2F}', '\u{FF30}', '\u{FF31}', '\u{FF32}',
+    '\u{FF33}', '\u{FF34}', '\u{FF35}', '\u{FF36}', '\u{FF37}', '\u{FF38}', '\u{FF39}', '\u{FF3A}',
+    '\u{FF3B}', '\u{FF3C}', '\u{FF3D}', '\u{FF3E}', '\u{FF3F}', '\u{FF40}', '\u{FF41}', '\u{FF42}',
+    '\u{FF43}', '\u{FF44}', '\u{FF45}', '\u{FF46}', '\u{FF47}', '\u{FF48}', '\u{FF49}', '\u{FF4A}',
+    '\u{FF4B}', '\u{FF4C}', '\u{FF4D}', '\u{FF4E}', '\u{FF4F}', '\u{FF50}', '\u{FF51}', '\u{FF52}',
+    '\u{FF53}', '\u{FF54}', '\u{FF55}', '\u{FF56}', '\u{FF57}', '\u{FF58}', '\u{FF59}', '\u{FF5A}',
+    '\u{FF5B}', '\u{FF5C}', '\u{FF5D}', '\u{FF5E}', '\u{FF5F}', '\u{FF60}', '\u{FF61}', '\u{FF62}',
+    '\u{FF63}', '\u{FF64}', '\u{FF65}', '\u{FF66}', '\u{FF67}', '\u{FF68}', '\u{FF69}', '\u{FF6A}',
+    '\u{FF6B}', '\u{FF6C}', '\u{FF6D}', '\u{FF6E}', '\u{FF6F}', '\u{FF70}', '\u{FF71}', '\u{FF72}',
+    '\u{FF73}', '\u{FF74}', '\u{FF75}', '\u{FF76}', '\u{FF77}', '\u{FF78}', '\u{FF79}', '\u{FF7A}',
+    '\u{FF7B}', '\u{FF7C}', '\u{FF7D}', '\u{FF7E}', '\u{FF7F}', '\u{FF80}', '\u{FF81}', '\u{FF82}',
+    '\u{FF83}', '\u{FF84}', '\u{FF85}', '\u{FF86}', '\u{FF87}', '\u{FF88}', '\u{FF89}', '\u{FF8A}',
+    '\u{FF8B}', '\u{FF8C}', '\u{FF8D}', '\u{FF8E}', '\u{FF8F}', '\u{FF90}', '\u{FF91}', '\u{FF92}',
+    '\u{FF93}', '\u{FF94}', '\u{FF95}', '\u{FF96}', '\u{FF97}', '\u{FF98}', '\u{FF99}', '\u{FF9A}',
+    '\u{FF9B}', '\u{FF9C}', '\u{FF9D}', '\u{FF9E}', '\u{FF9F}', '\u{FFA1}', '\u{FFA2}', '\u{FFA3}',
+    '\u{FFA4}', '\u{FFA5}', '\u{FFA6}', '\u{FFA7}', '\u{FFA8}', '\u{FFA9}', '\u{FFAA}', '\u{FFAB}',
+    '\u{FFAC}', '\u{FFAD}', '\u{FFAE}', '\u{FFAF}', '\u{FFB0}', '\u{FFB1}', '\u{FFB2}', '\u{FFB3}',
+    '\u{FFB4}', '\u{FFB5}', '\u{FFB6}', '\u{FFB7}', '\u{FFB8}', '\u{FFB9}', '\u{FFBA}', '\u{FFBB}',
+    '\u{FFBC}', '\u{FFBD}', '\u{FFBE}', '\u{FFC2}', '\u{FFC3}', '\u{FFC4}', '\u{FFC5}', '\u{FFC6}',
+    '\u{FFC7}', '\u{FFCA}', '\u{FFCB}', '\u{FFCC}', '\u{FFCD}', '\u{FFCE}', '\u{FFCF}', '\u{FFD2}',
+    '\u{FFD3}', '\u{FFD4}', '\u{FFD5}', '\u{FFD6}', '\u{FFD7}', '\u{FFDA}', '\u{FFDB}', '\u{FFDC}',
+    '\u{FFE0}', '\u{FFE1}', '\u{FFE2}', '\u{FFE3}', '\u{FFE4}', '\u{FFE5}', '\u{FFE6}', '\u{FFE8}',
+    '\u{FFE9}', '\u{FFEA}', '\u{FFEB}', '\u{FFEC}', '\u{FFED}', '\u{FFEE}', '\u{10400}', '\u{10401}',
+    '\u{10402}', '\u{10403}', '\u{10404}', '\u{10405}', '\u{10406}', '\u{10407}', '\u{10408}', '\u{10409}',
+    '\u{1040A}', '\u{1040B}', '\u{1040C}', '\u{1040D}', '\u{1040E}', '\u{1040F}', '\u{10410}', '\u{10411}',
+    '\u{10412}', '\u{10413}', '\u{10414}', '\u{10415}', '\u{10416}', '\u{10417}', '\u{10418}', '\u{10419}',
+    '\u{1041A}', '\u{1041B}', '\u{1041C}', '\u{1041D}', '\u{1041E}', '\u{1041F}', '\u{10420}', '\u{10421}',
+    '\u{10422}', '\u{10423}', '\u{10424}', '\u{10425}', '\u{10426}', '\u{10427}', '\u{104B0}', '\u{104B1}',
+    '\u{104B2}', '\u{104B3}', '\u{104B4}', '\u{104B5}', '\u{104B6}', '\u{104B7}', '\u{104B8}', '\u{104B9}',
+    '\u{104BA}', '\u{104BB}', '\u{104BC}', '\u{104BD}', '\u{104BE}', '\u{104BF}', '\u{104C0}', '\u{104C1}',
+    '\u{104C2}', '\u{104C3}', '\u{104C4}', '\u{104C5}', '\u{104C6}', '\u{104C7}', '\u{104C8}', '\u{104C9}',
+    '\u{104CA}', '\u{104CB}', '\u{104CC}', '\u{104CD}', '\u{104CE}', '\u{104CF}', '\u{104D0}', '\u{104D1}',
+    '\u{104D2}', '\u{104D3}', '\u{10570}', '\u{10571}', '\u{10572}', '\u{10573}', '\u{10574}', '\u{10575}',
+    '\u{10576}', '\u{10577}', '\u{10578}', '\u{10579}', '\u{1057A}', '\u{1057C}', '\u{1057D}', '\u{1057E}',
+    '\u{1057F}', '\u{10580}', '\u{10581}', '\u{10582}', '\u{10583}', '\u{10584}', '\u{10585}', '\u{10586}',
+    '\u{10587}', '\u{10588}', '\u{10589}', '\u{1058A}', '\u{1058C}', '\u{1058D}', '\u{1058E}', '\u{1058F}',
+    '\u{10590}', '\u{10591}', '\u{10592}', '\u{10594}', '\u{10595}', '\u{10781}', '\u{10782}', '\u{10783}',
+    '\u{10784}', '\u{10785}', '\u{10787}', '\u{10788}', '\u{10789}', '\u{1078A}', '\u{1078B}', '\u{1078C}',
+    '\u{1078D}', '\u{1078E}', '\u{1078F}', '\u{10790}', '\u{10791}', '\u{10792}', '\u{10793}', '\u{10794}',
+    '\u{10795}', '\u{10796}', '\u{10797}', '\u{10798}', '\u{10799}', '\u{1079A}', '\u{1079B}', '\u{1079C}',
+    '\u{1079D}', '\u{1079E}', '\u{1079F}', '\u{107A0}', '\u{107A1}', '\u{107A2}', '\u{107A3}', '\u{107A4}',
+    '\u{107A5}', '\u{107A6}', '\u{107A7}', '\u{107A8}', '\u{107A9}', '\u{107AA}', '\u{107AB}', '\u{107AC}',
+    '\u{107AD}', '\u{107AE}', '\u{107AF}', '\u{107B0}', '\u{107B2}', '\u{107B3}', '\u{107B4}', '\u{107B5}',
+    '\u{107B6}', '\u{107B7}', '\u{107B8}', '\u{107B9}', '\u{107BA}', '\u{10C80}', '\u{10C81}', '\u{10C82}',
+    '\u{10C83}', '\u{10C84}', '\u{10C85}', '\u{10C86}', '\u{10C87}', '\u{10C88}', '\u{10C89}', '\u{10C8A}',
+    '\u{10C8B}', '\u{10C8C}', '\u{10C8D}', '\u{10C8E}', '\u{10C8F}', '\u{10C90}', '\u{10C91}', '\u{10C92}',
+    '\u{10C93}', '\u{10C94}', '\u{10C95}', '\u{10C96}', '\u{10C97}', '\u{10C98}', '\u{10C99}', '\u{10C9A}',
+    '\u{10C9B}', '\u{10C9C}', '\u{10C9D}', '\u{10C9E}', '\u{10C9F}', '\u{10CA0}', '\u{10CA1}', '\u{10CA2}',
+    '\u{10CA3}', '\u{10CA4}', '\u{10CA5}', '\u{10CA6}', '\u{10CA7}', '\u{10CA8}', '\u{10CA9}', '\u{10CAA}',
+    '\u{10CAB}', '\u{10CAC}', '\u{10CAD}', '\u{10CAE}', '\u{10CAF}', '\u{10CB0}', '\u{10CB1}', '\u{10CB2}',
+    '\u{10D50}', '\u{10D51}', '\u{10D52}', '\u{10D53}', '\u{10D54}', '\u{10D55}', '\u{10D56}', '\u{10D57}',
+    '\u{10D58}', '\u{10D59}', '\u{10D5A}', '\u{10D5B}', '\u{10D5C}', '\u{10D5D}', '\u{10D5E}', '\u{10D5F}',
+    '\u{10D60}', '\u{10D61}', '\u{10D62}', '\u{10D63}', '\u{10D64}', '\u{10D65}', '\u{118A0}', '\u{118A1}',
+    '\u{118A2}', '\u{118A3}', '\u{118A4}', '\u{118A5}', '\u{118A6}', '\u{118A7}', '\u{118A8}', '\u{118A9}',
+    '\u{118AA}', '\u{118AB}', '\u{118AC}', '\u{118AD}', '\u{118AE}', '\u{118AF}', '\u{118B0}', '\u{118B1}',
+    '\u{118B2}', '\u{118B3}', '\u{118B4}', '\u{118B5}', '\u{118B6}', '\u{118B7}', '\u{118B8}', '\u{118B9}',
+    '\u{118BA}', '\u{118BB}', '\u{118BC}', '\u{118BD}', '\u{118BE}', '\u{118BF}', '\u{16E40}', '\u{16E41}',
+    '\u{16E42}', '\u{16E43}', '\u{16E44}', '\u{16E45}', '\u{16E46}', '\u{16E47}', '\u{16E48}', '\u{16E49}',
+    '\u{16E4A}', '\u{16E4B}', '\u{16E4C}', '\u{16E4D}', '\u{16E4E}', '\u{16E4F}', '\u{16E50}', '\u{16E51}',
+    '\u{16E52}', '\u{16E53}', '\u{16E54}', '\u{16E55}', '\u{16E56}', '\u{16E57}', '\u{16E58}', '\u{16E59}',
+    '\u{16E5A}', '\u{16E5B}', '\u{16E5C}', '\u{16E5D}', '\u{16E5E}', '\u{16E5F}', '\u{16EA0}', '\u{16EA1}',
+    '\u{16EA2}', '\u{16EA3}', '\u{16EA4}', '\u{16EA5}', '\u{16EA6}', '\u{16EA7}', '\u{16EA8}', '\u{16EA9}',
+    '\u{16EAA}', '\u{16EAB}', '\u{16EAC}', '\u{16EAD}', '\u{16EAE}', '\u{16EAF}', '\u{16EB0}', '\u{16EB1}',
+    '\u{16EB2}', '\u{16EB3}', '\u{16EB4}', '\u{16EB5}', '\u{16EB6}', '\u{16EB7}', '\u{16EB8}', '\u{1CCD6}',
+    '\u{1CCD7}', '\u{1CCD8}', '\u{1CCD9}', '\u{1CCDA}', '\u{1CCDB}', '\u{1CCDC}', '\u{1CCDD}', '\u{1CCDE}',
+    '\u{1CCDF}', '\u{1CCE0}', '\u{1CCE1}', '\u{1CCE2}', '\u{1CCE3}', '\u{1CCE4}', '\u{1CCE5}', '\u{1CCE6}',
+    '\u{1CCE7}', '\u{1CCE8}', '\u{1CCE9}', '\u{1CCEA}', '\u{1CCEB}', '\u{1CCEC}', '\u{1CCED}', '\u{1CCEE}',
+    '\u{1CCEF}', '\u{1CCF0}', '\u{1CCF1}', '\u{1CCF2}', '\u{1CCF3}', '\u{1CCF4}', '\u{1CCF5}', '\u{1CCF6}',
+    '\u{1CCF7}', '\u{1CCF8}', '\u{1CCF9}', '\u{1D15E}', '\u{1D15F}', '\u{1D160}', '\u{1D161}', '\u{1D162}',
+    '\u{1D163}', '\u{1D164}', '\u{1D1BB}', '\u{1D1BC}', '\u{1D1BD}', '\u{1D1BE}', '\u{1D1BF}', '\u{1D1C0}',
+    '\u{1D400}', '\u{1D401}', '\u{1D402}', '\u{1D403}', '\u{1D404}', '\u{1D405}', '\u{1D406}', '\u{1D407}',
+    '\u{1D408}', '\u{1D409}', '\u{1D40A}', '\u{1D40B}', '\u{1D40C}', '\u{1D40D}', '\u{1D40E}', '\u{1D40F}',
+    '\u{1D410}', '\u{1D411}', '\u{1D412}', '\u{1D413}', '\u{1D414}', '\u{1D415}', '\u{1D416}', '\u{1D417}',
+    '\u{1D418}', '\u{1D419}', '\u{1D41A}', '\u{1D41B}', '\u{1D41C}', '\u{1D41D}', '\u{1D41E}', '\u{1D41F}',
+    '\u{1D420}', '\u{1D421}', '\u{1D422}', '\u{1D423}', '\u{1D424}', '\u{1D425}', '\u{1D426}', '\u{1D427}',
+    '\u{1D428}', '\u{1D429}', '\u{1D42A}', '\u{1D42B}', '\u{1D42C}', '\u{1D42D}', '\u{1D42E}', '\u{1D42F}',
+    '\u{1D430}', '\u{1D431}', '\u{1D432}', '\u{1D433}', '\u{1D434}', '\u{1D435}', '\u{1D436}', '\u{1D437}',
+    '\u{1D438}', '\u{1D439}', '\u{1D43A}', '\u{1D43B}', '\u{1D43C}', '\u{1D43D}', '\u{1D43E}', '\u{1D43F}',
+    '\u{1D440}', '\u{1D441}', '\u{1D442}', '\u{1D443}', '\u{1D444}', '\u{1D445}', '\u{1D446}', '\u{1D447}',
+    '\u{1D448}', '\u{1D449}', '\u{1D44A}', '\u{1D44B}', '\u{1D44C}', '\u{1D44D}', '\u{1D44E}', '\u{1D44F}',
+    '\u{1D450}', '\u{1D451}', '\u{1D452}', '\u{1D453}', '\u{1D454}', '\u{1D456}', '\u{1D457}', '\u{1D458}',
+    '\u{1D459}', '\u{1D45A}', '\u{1D45B}', '\u{1D45C}', '\u{1D45D}', '\u{1D45E}', '\u{1D45F}', '\u{1D460}',
+    '\u{1D461}', '\u{1D462}', '\u{1D463}', '\u{1D464}', '\u{1D465}', '\u{1D466}', '\u{1D467}', '\u{1D468}',
+    '\u{1D469}', '\u{1D46A}', '\u{1D46B}', '\u{1D46C}', '\u{1D46D}', '\u{1D46E}', '\u{1D46F}', '\u{1D470}',
+    '\u{1D471}', '\u{1D472}', '\u{1D473}', '\u{1D474}', '\u{1D475}', '\u{1D476}', '\u{1D477}', '\u{1D478}',
+    '\u{1D479}', '\u{1D47A}', '\u{1D47B}', '\u{1D47C}', '\u{1D47D}', '\u{1D47E}', '\u{1D47F}', '\u{1D480}',
+    '\u{1D481}', '\u{1D482}', '\u{1D483}', '\u{1D484}', '\u{1D485}', '\u{1D486}', '\u{1D487}', '\u{1D488}',
+    '\u{1D489}', '\u{1D48A}', '\u{1D48B}', '\u{1D48C}', '\u{1D48D}', '\u{1D48E}', '\u{1D48F}', '\u{1D490}',
+    '\u{1D491}', '\u{1D492}', '\u{1D493}', '\u{1D494}', '\u{1D495}', '\u{1D496}', '\u{1D497}', '\u{1D498}',
+    '\u{1D499}', '\u{1D49A}', '\u{1D49B}', '\u{1D49C}', '\u{1D49E}', '\u{1D49F}', '\u{1D4A2}', '\u{1D4A5}',
+    '\u{1D4A6}', '\u{1D4A9}', '\u{1D4AA}', '\u{1D4AB}', '\u{1D4AC}', '\u{1D4AE}', '\u{1D4AF}', '\u{1D4B0}',
+    '\u{1D4B1}', '\u{1D4B2}', '\u{1D4B3}', '\u{1D4B4}', '\u{1D4B5}', '\u{1D4B6}', '\u{1D4B7}', '\u{1D4B8}',
+    '\u{1D4B9}', '\u{1D4BB}', '\u{1D4BD}', '\u{1D4BE}', '\u{1D4BF}', '\u{1D4C0}', '\u{1D4C1}', '\u{1D4C2}',
+    '\u{1D4C3}', '\u{1D4C5}', '\u{1D4C6}', '\u{1D4C7}', '\u{1D4C8}', '\u{1D4C9}', '\u{1D4CA}', '\u{1D4CB}',
+    '\u{1D4CC}', '\u{1D4CD}', '\u{1D4CE}', '\u{1D4CF}', '\u{1D4D0}', '\u{1D4D1}', '\u{1D4D2}', '\u{1D4D3}',
+    '\u{1D4D4}', '\u{1D4D5}', '\u{1D4D6}', '\u{1D4D7}', '\u{1D4D8}', '\u{1D4D9}', '\u{1D4DA}', '\u{1D4DB}',
+    '\u{1D4DC}', '\u{1D4DD}', '\u{1D4DE}', '\u{1D4DF}', '\u{1D4E0}', '\u{1D4E1}', '\u{1D4E2}', '\u{1D4E3}',
+    '\u{1D4E4}', '\u{1D4E5}', '\u{1D4E6}', '\u{1D4E7}', '\u{1D4E8}', '\u{1D4E9}', '\u{1D4EA}', '\u{1D4EB}',
+    '\u{1D4EC}', '\u{1D4ED}', '\u{1D4EE}', '\u{1D4EF}', '\u{1D4F0}', '\u{1D4F1}', '\u{1D4F2}', '\u{1D4F3}',
+    '\u{1D4F4}', '\u{1D4F5}', '\u{1D4F6}', '\u{1D4F7}', '\u{1D4F8}', '\u{1D4F9}', '\u{1D4FA}', '\u{1D4FB}',
+    '\u{1D4FC}', '\u{1D4FD}', '\u{1D4FE}', '\u{1D4FF}', '\u{1D500}', '\u{1D501}', '\u{1D502}', '\u{1D503}',
+    '\u{1D504}', '\u{1D505}', '\u{1D507}', '\u{1D508}', '\u{1D509}', '\u{1D50A}', '\u{1D50D}', '\u{1D50E}',
+    '\u{1D50F}', '\u{1D510}', '\u{1D511}', '\u{1D512}', '\u{1D513}', '\u{1D514}', '\u{1D516}', '\u{1D517}',
+    '\u{1D518}', '\u{1D519}', '\u{1D51A}', '\u{1D51B}', '\u{1D51C}', '\u{1D51E}', '\u{1D51F}', '\u{1D520}',
+    '\u{1D521}', '\u{1D522}', '\u{1D523}', '\u{1D524}', '\u{1D525}', '\u{1D526}', '\u{1D527}', '\u{1D528}',
+    '\u{1D529}', '\u{1D52A}', '\u{1D52B}', '\u{1D52C}', '\u{1D52D}', '\u{1D52E}', '\u{1D52F}', '\u{1D530}',
+    '\u{1D531}', '\u{1D532}', '\u{1D533}', '\u{1D534}', '\u{1D535}', '\u{1D536}', '\u{1D537}', '\u{1D538}',
+    '\u{1D539}', '\u{1D53B}', '\u{1D53C}', '\u{1D53D}', '\u{1D53E}', '\u{1D540}', '\u{1D541}', '\u{1D542}',
+    '\u{1D543}', '\u{1D544}', '\u{1D546}', '\u{1D54A}', '\u{1D54B}', '\u{1D54C}', '\u{1D54D}', '\u{1D54E}',
+    '\u{1D54F}', '\u{1D550}', '\u{1D552}', '\u{1D553}', '\u{1D554}', '\u{1D555}', '\u{1D556}', '\u{1D557}',
+    '\u{1D558}', '\u{1D559}', '\u{1D55A}', '\u{1D55B}', '\u{1D55C}', '\u{1D55D}', '\u{1D55E}', '\u{1D55F}',
+    '\u{1D560}', '\u{1D561}', '\u{1D562}', '\u{1D563}', '\u{1D564}', '\u{1D565}', '\u{1D566}', '\u{1D567}',
+    '\u{1D568}', '\u{1D569}', '\u{1D56A}', '\u{1D56B}', '\u{1D56C}', '\u{1D56D}', '\u{1D56E}', '\u{1D56F}',
+    '\u{1D570}', '\u{1D571}', '\u{1D572}', '\u{1D573}', '\u{1D574}', '\u{1D575}', '\u{1D576}', '\u{1D577}',
+    '\u{1D578}', '\u{1D579}', '\u{1D57A}', '\u{1D57B}', '\u{1D57C}', '\u{1D57D}', '\u{1D57E}', '\u{1D57F}',
+    '\u{1D580}', '\u{1D581}', '\u{1D582}', '\u{1D583}', '\u{1D584}', '\u{1D585}', '\u{1D586}', '\u{1D587}',
+    '\u{1D588}', '\u{1D589}', '\u{1D58A}', '\u{1D58B}', '\u{1D58C}', '\u{1D58D}', '\u{1D58E}', '\u{1D58F}',
+    '\u{1D590}', '\u{1D591}', '\u{1D592}', '\u{1D593}', '\u{1D594}', '\u{1D595}', '\u{1D596}', '\u{1D597}',
+    '\u{1D598}', '\u{1D599}', '\u{1D59A}', '\u{1D59B}', '\u{1D59C}', '\u{1D59D}', '\u{1D59E}', '\u{1D59F}',
+    '\u{1D5A0}', '\u{1D5A1}', '\u{1D5A2}', '\u{1D5A3}', '\u{1D5A4}', '\u{1D5A5}', '\u{1D5A6}', '\u{1D5A7}',
+    '\u{1D5A8}', '\u{1D5A9}', '\u{1D5AA}', '\u{1D5AB}', '\u{1D5AC}', '\u{1D5AD}', '\u{1D5AE}', '\u{1D5AF}',
+    '\u{1D5B0}', '\u{1D5B1}', '\u{1D5B2}', '\u{1D5B3}', '\u{1D5B4}', '\u{1D5B5}', '\u{1D5B6}', '\u{1D5B7}',
+    '\u{1D5B8}', '\u{1D5B9}', '\u{1D5BA}', '\u{1D5BB}', '\u{1D5BC}', '\u{1D5BD}', '\u{1D5BE}', '\u{1D5BF}',
+    '\u{1D5C0}', '\u{1D5C1}', '\u{1D5C2}', '\u{1D5C3}', '\u{1D5C4}', '\u{1D5C5}', '\u{1D5C6}', '\u{1D5C7}',
+    '\u{1D5C8}', '\u{1D5C9}', '\u{1D5CA}', '\u{1D5CB}', '\u{1D5CC}', '\u{1D5CD}', '\u{1D5CE}', '\u{1D5CF}',
+    '\u{1D5D0}', '\u{1D5D1}', '\u{1D5D2}', '\u{1D5D3}', '\u{1D5D4}', '\u{1D5D5}', '\u{1D5D6}', '\u{1D5D7}',
+    '\u{1D5D8}', '\u{1D5D9}', '\u{1D5DA}', '\u{1D5DB}', '\u{1D5DC}', '\u{1D5DD}', '\u{1D5DE}', '\u{1D5DF}',
+    '\u{1D5E0}', '\u{1D5E1}', '\u{1D5E2}', '\u{1D5E3}', '\u{1D5E4}', '\u{1D5E5}', '\u{1D5E6}', '\u{1D5E7}',
+    '\u{1D5E8}', '\u{1D5E9}', '\u{1D5EA}', '\u{1D5EB}', '\u{1D5EC}', '\u{1D5ED}', '\u{1D5EE}', '\u{1D5EF}',
+    '\u{1D5F0}', '\u{1D5F1}', '\u{1D5F2}', '\u{1D5F3}', '\u{1D5F4}', '\u{1D5F5}', '\u{1D5F6}', '\u{1D5F7}',
+    '\u{1D5F8}', '\u{1D5F9}', '\u{1D5FA}', '\u{1D5FB}', '\u{1D5FC}', '\u{1D5FD}', '\u{1D5FE}', '\u{1D5FF}',
+    '\u{1D600}', '\u{1D601}', '\u{1D602}', '\u{1D603}', '\u{1D604}', '\u{1D605}', '\u{1D606}', '\u{1D607}',
+    '\u{1D608}', '\u{1D609}', '\u{1D60A}', '\u{1D60B}', '\u{1D60C}', '\u{1D60D}', '\u{1D60E}', '\u{1D60F}',
+    '\u{1D610}', '\u{1D611}', '\u{1D612}', '\u{1D613}', '\u{1D614}', '\u{1D615}', '\u{1D616}', '\u{1D617}',
+    '\u{1D618}', '\u{1D619}', '\u{1D61A}', '\u{1D61B}', '\u{1D61C}', '\u{1D61D}', '\u{1D61E}', '\u{1D61F}',
+    '\u{1D620}', '\u{1D621}', '\u{1D622}', '\u{1D623}', '\u{1D624}', '\u{1D625}', '\u{1D626}', '\u{1D627}',
+    '\u{1D628}', '\u{1D629}', '\u{1D62A}', '\u{1D62B}', '\u{1D62C}', '\u{1D62D}', '\u{1D62E}', '\u{1D62F}',
+    '\u{1D630}', '\u{1D631}', '\u{1D632}', '\u{1D633}', '\u{1D634}', '\u{1D635}', '\u{1D636}', '\u{1D637}',
+    '\u{1D638}', '\u{1D639}', '\u{1D63A}', '\u{1D63B}', '\u{1D63C}', '\u{1D63D}', '\u{1D63E}', '\u{1D63F}',
+    '\u{1D640}', '\u{1D641}', '\u{1D642}', '\u{1D643}', '\u{1D644}', '\u{1D645}', '\u{1D646}', '\u{1D647}',
+    '\u{1D648}', '\u{1D649}', '\u{1D64A}', '\u{1D64B}', '\u{1D64C}', '\u{1D64D}', '\u{1D64E}', '\u{1D64F}',
+    '\u{1D650}', '\u{1D651}', '\u{1D652}', '\u{1D653}', '\u{1D654}', '\u{1D655}', '\u{1D656}', '\u{1D657}',
+    '\u{1D658}', '\u{1D659}', '\u{1D65A}', '\u{1D65B}', '\u{1D65C}', '\u{1D65D}', '\u{1D65E}', '\u{1D65F}',
+    '\u{1D660}', '\u{1D661}', '\u{1D662}', '\u{1D663}', '\u{1D664}', '\u{1D665}', '\u{1D666}', '\u{1D667}',
+    '\u{1D668}', '\u{1D669}', '\u{1D66A}', '\u{1D66B}', '\u{1D66C}', '\u{1D66D}', '\u{1D66E}', '\u{1D66F}',
+    '\u{1D670}', '\u{1D671}', '\u{1D672}', '\u{1D673}', '\u{1D674}', '\u{1D675}', '\u{1D676}', '\u{1D677}',
+    '\u{1D678}', '\u{1D679}', '\u{1D67A}', '\u{1D67B}', '\u{1D67C}', '\u{1D67D}', '\u{1D67E}', '\u{1D67F}',
+    '\u{1D680}', '\u{1D681}', '\u{1D682}', '\u{1D683}', '\u{1D684}', '\u{1D685}', '\u{1D686}', '\u{1D687}',
+    '\u{1D688}', '\u{1D689}', '\u{1D68A}', '\u{1D68B}', '\u{1D68C}', '\u{1D68D}', '\u{1D68E}', '\u{1D68F}',
+    '\u{1D690}', '\u{1D691}', '\u{1D692}', '\u{1D693}', '\u{1D694}', '\u{1D695}', '\u{1D696}', '\u{1D697}',
+    '\u{1D698}', '\u{1D699}', '\u{1D69A}', '\u{1D69B}', '\u{1D69C}', '\u{1D69D}', '\u{1D69E}', '\u{1D69F}',
+    '\u{1D6A0}', '\u{1D6A1}', '\u{1D6A2}', '\u{1D6A3}', '\u{1D6A4}', '\u{1D6A5}', '\u{1D6A8}', '\u{1D6A9}',
+    '\u{1D6AA}', '\u{1D6AB}', '\u{1D6AC}', '\u{1D6AD}', '\u{1D6AE}', '\u{1D6AF}', '\u{1D6B0}', '\u{1D6B1}',
+    '\u{1D6B2}', '\u{1D6B3}', '\u{1D6B4}', '\u{1D6B5}', '\u{1D6B6}', '\u{1D6B7}', '\u{1D6B8}', '\u{1D6B9}',
+    '\u{1D6BA}', '\u{1D6BB}', '\u{1D6BC}', '\u{1D6BD}', '\u{1D6BE}', '\u{1D6BF}', '\u{1D6C0}', '\u{1D6C1}',
+    '\u{1D6C2}', '\u{1D6C3}', '\u{1D6C4}', '\u{1D6C5}', '\u{1D6C6}', '\u{1D6C7}', '\u{1D6C8}', '\u{1D6C9}',
+    '\u{1D6CA}', '\u{1D6CB}', '\u{1D6CC}', '\u{1D6CD}', '\u{1D6CE}', '\u{1D6CF}', '\u{1D6D0}', '\u{1D6D1}',
+    '\u{1D6D2}', '\u{1D6D3}', '\u{1D6D4}', '\u{1D6D5}', '\u{1D6D6}', '\u{1D6D7}', '\u{1D6D8}', '\u{1D6D9}',
+    '\u{1D6DA}', '\u{1D6DB}', '\u{1D6DC}', '\u{1D6DD}', '\u{1D6DE}', '\u{1D6DF}', '\u{1D6E0}', '\u{1D6E1}',
+    '\u{1D6E2}', '\u{1D6E3}', '\u{1D6E4}', '\u{1D6E5}', '\u{1D6E6}', '\u{1D6E7}', '\u{1D6E8}', '\u{1D6E9}',
+    '\u{1D6EA}', '\u{1D6EB}', '\u{1D6EC}', '\u{1D6ED}', '\u{1D6EE}', '\u{1D6EF}', '\u{1D6F0}', '\u{1D6F1}',
+    '\u{1D6F2}', '\u{1D6F3}', '\u{1D6F4}', '\u{1D6F5}', '\u{1D6F6}', '\u{1D6F7}', '\u{1D6F8}', '\u{1D6F9}',
+    '\u{1D6FA}', '\u{1D6FB}', '\u{1D6FC}', '\u{1D6FD}', '\u{1D6FE}', '\u{1D6FF}', '\u{1D700}', '\u{1D701}',
+    '\u{1D702}', '\u{1D703}', '\u{1D704}', '\u{1D705}', '\u{1D706}', '\u{1D707}', '\u{1D708}', '\u{1D709}',
+    '\u{1D70A}', '\u{1D70B}', '\u{1D70C}', '\u{1D70D}', '\u{1D70E}', '\u{1D70F}', '\u{1D710}', '\u{1D711}',
+    '\u{1D712}', '\u{1D713}', '\u{1D714}', '\u{1D715}', '\u{1D716}', '\u{1D717}', '\u{1D718}', '\u{1D719}',
+    '\u{1D71A}', '\u{1D71B}', '\u{1D71C}', '\u{1D71D}', '\u{1D71E}', '\u{1D71F}', '\u{1D720}', '\u{1D721}',
+    '\u{1D722}', '\u{1D723}', '\u{1D724}', '\u{1D725}', '\u{1D726}', '\u{1D727}', '\u{1D728}', '\u{1D729}',
+    '\u{1D72A}', '\u{1D72B}', '\u{1D72C}', '\u{1D72D}', '\u{1D72E}', '\u{1D72F}', '\u{1D730}', '\u{1D731}',
+    '\u{1D732}', '\u{1D733}', '\u{1D734}', '\u{1D735}', '\u{1D736}', '\u{1D737}', '\u{1D738}', '\u{1D739}',
+    '\u{1D73A}', '\u{1D73B}', '\u{1D73C}', '\u{1D73D}', '\u{1D73E}', '\u{1D73F}', '\u{1D740}', '\u{1D741}',
+    '\u{1D742}', '\u{1D743}', '\u{1D744}', '\u{1D745}', '\u{1D746}', '\u{1D747}', '\u{1D748}', '\u{1D749}',
+    '\u{1D74A}', '\u{1D74B}', '\u{1D74C}', '\u{1D74D}', '\u{1D74E}', '\u{1D74F}', '\u{1D750}', '\u{1D751}',
+    '\u{1D752}', '\u{1D753}', '\u{1D754}', '\u{1D755}', '\u{1D756}', '\u{1D757}', '\u{1D758}', '\u{1D759}',
+    '\u{1D75A}', '\u{1D75B}', '\u{1D75C}', '\u{1D75D}', '\u{1D75E}', '\u{1D75F}', '\u{1D760}', '\u{1D761}',
+    '\u{1D762}', '\u{1D763}', '\u{1D764}', '\u{1D765}', '\u{1D766}', '\u{1D767}', '\u{1D768}', '\u{1D769}',
+    '\u{1D76A}', '\u{1D76B}', '\u{1D76C}', '\u{1D76D}', '\u{1D76E}', '\u{1D76F}', '\u{1D770}', '\u{1D771}',
+    '\u{1D772}', '\u{1D773}', '\u{1D774}', '\u{1D775}', '\u{1D776}', '\u{1D777}', '\u{1D778}', '\u{1D779}',
+    '\u{1D77A}', '\u{1D77B}', '\u{1D77C}', '\u{1D77D}', '\u{1D77E}', '\u{1D77F}', '\u{1D780}', '\u{1D781}',
+    '\u{1D782}', '\u{1D783}', '\u{1D784}', '\u{1D785}', '\u{1D786}', '\u{1D787}', '\u{1D788}', '\u{1D789}',
+    '\u{1D78A}', '\u{1D78B}', '\u{1D78C}', '\u{1D78D}', '\u{1D78E}', '\u{1D78F}', '\u{1D790}', '\u{1D791}',
+    '\u{1D792}', '\u{1D793}', '\u{1D794}', '\u{1D795}', '\u{1D796}', '\u{1D797}', '\u{1D798}', '\u{1D799}',
+    '\u{1D79A}', '\u{1D79B}', '\u{1D79C}', '\u{1D79D}', '\u{1D79E}', '\u{1D79F}', '\u{1D7A0}', '\u{1D7A1}',
+    '\u{1D7A2}', '\u{1D7A3}', '\u{1D7A4}', '\u{1D7A5}', '\u{1D7A6}', '\u{1D7A7}', '\u{1D7A8}', '\u{1D7A9}',
+    '\u{1D7AA}', '\u{1D7AB}', '\u{1D7AC}', '\u{1D7AD}', '\u{1D7AE}', '\u{1D7AF}', '\u{1D7B0}', '\u{1D7B1}',
+    '\u{1D7B2}', '\u{1D7B3}', '\u{1D7B4}', '\u{1D7B5}', '\u{1D7B6}', '\u{1D7B7}', '\u{1D7B8}', '\u{1D7B9}',
+    '\u{1D7BA}', '\u{1D7BB}', '\u{1D7BC}', '\u{1D7BD}', '\u{1D7BE}', '\u{1D7BF}', '\u{1D7C0}', '\u{1D7C1}',
+    '\u{1D7C2}', '\u{1D7C3}', '\u{1D7C4}', '\u{1D7C5}', '\u{1D7C6}', '\u{1D7C7}', '\u{1D7C8}', '\u{1D7C9}',
+    '\u{1D7CA}', '\u{1D7CB}', '\u{1D7CE}', '\u{1D7CF}', '\u{1D7D0}', '\u{1D7D1}', '\u{1D7D2}', '\u{1D7D3}',
+    '\u{1D7D4}', '\u{1D7D5}', '\u{1D7D6}', '\u{1D7D7}', '\u{1D7D8}', '\u{1D7D9}', '\u{1D7DA}', '\u{1D7DB}',
+    '\u{1D7DC}', '\u{1D7DD}', '\u{1D7DE}', '\u{1D7DF}', '\u{1D7E0}', '\u{1D7E1}', '\u{1D7E2}', '\u{1D7E3}',
+    '\u{1D7E4}', '\u{1D7E5}', '\u{1D7E6}', '\u{1D7E7}', '\u{1D7E8}', '\u{1D7E9}', '\u{1D7EA}', '\u{1D7EB}',
+    '\u{1D7EC}', '\u{1D7ED}', '\u{1D7EE}', '\u{1D7EF}', '\u{1D7F0}', '\u{1D7F1}', '\u{1D7F2}', '\u{1D7F3}',
+    '\u{1D7F4}', '\u{1D7F5}', '\u{1D7F6}', '\u{1D7F7}', '\u{1D7F8}', '\u{1D7F9}', '\u{1D7FA}', '\u{1D7FB}',
+    '\u{1D7FC}', '\u{1D7FD}', '\u{1D7FE}', '\u{1D7FF}', '\u{1E030}', '\u{1E031}', '\u{1E032}', '\u{1E033}',
+    '\u{1E034}', '\u{1E035}', '\u{1E036}', '\u{1E037}', '\u{1E038}', '\u{1E039}', '\u{1E03A}', '\u{1E03B}',
+    '\u{1E03C}', '\u{1E03D}', '\u{1E03E}', '\u{1E03F}', '\u{1E040}', '\u{1E041}', '\u{1E042}', '\u{1E043}',
+    '\u{1E044}', '\u{1E045}', '\u{1E046}', '\u{1E047}', '\u{1E048}', '\u{1E049}', '\u{1E04A}', '\u{1E04B}',
+    '\u{1E04C}', '\u{1E04D}', '\u{1E04E}', '\u{1E04F}', '\u{1E050}', '\u{1E051}', '\u{1E052}', '\u{1E053}',
+    '\u{1E054}', '\u{1E055}', '\u{1E056}', '\u{1E057}', '\u{1E058}', '\u{1E059}', '\u{1E05A}', '\u{1E05B}',
+    '\u{1E05C}', '\u{1E05D}', '\u{1E05E}', '\u{1E05F}', '\u{1E060}', '\u{1E061}', '\u{1E062}', '\u{1E063}',
+    '\u{1E064}', '\u{1E065}', '\u{1E066}', '\u{1E067}', '\u{1E068}', '\u{1E069}', '\u{1E06A}', '\u{1E06B}',
+    '\u{1E06C}', '\u{1E06D}', '\u{1E900}', '\u{1E901}', '\u{1E902}', '\u{1E903}', '\u{1E904}', '\u{1E905}',
+    '\u{1E906}', '\u{1E907}', '\u{1E908}', '\u{1E909}', '\u{1E90A}', '\u{1E90B}', '\u{1E90C}', '\u{1E90D}',
+    '\u{1E90E}', '\u{1E90F}', '\u{1E910}', '\u{1E911}', '\u{1E912}', '\u{1E913}', '\u{1E914}', '\u{1E915}',
+    '\u{1E916}', '\u{1E917}', '\u{1E918}', '\u{1E919}', '\u{1E91A}', '\u{1E91B}', '\u{1E91C}', '\u{1E91D}',
+    '\u{1E91E}', '\u{1E91F}', '\u{1E920}', '\u{1E921}', '\u{1EE00}', '\u{1EE01}', '\u{1EE02}', '\u{1EE03}',
+    '\u{1EE05}', '\u{1EE06}', '\u{1EE07}', '\u{1EE08}', '\u{1EE09}', '\u{1EE0A}', '\u{1EE0B}', '\u{1EE0C}',
+    '\u{1EE0D}', '\u{1EE0E}', '\u{1EE0F}', '\u{1EE10}', '\u{1EE11}', '\u{1EE12}', '\u{1EE13}', '\u{1EE14}',
+    '\u{1EE15}', '\u{1EE16}', '\u{1EE17}', '\u{1EE18}', '\u{1EE19}', '\u{1EE1A}', '\u{1EE1B}', '\u{1EE1C}',
+    '\u{1EE1D}', '\u{1EE1E}', '\u{1EE1F}', '\u{1EE21}', '\u{1EE22}', '\u{1EE24}', '\u{1EE27}', '\u{1EE29}',
+    '\u{1EE2A}', '\u{1EE2B}', '\u{1EE2C}', '\u{1EE2D}', '\u{1EE2E}', '\u{1EE2F}', '\u{1EE30}', '\u{1EE31}',
+    '\u{1EE32}', '\u{1EE34}', '\u{1EE35}', '\u{1EE36}', '\u{1EE37}', '\u{1EE39}', '\u{1EE3B}', '\u{1EE42}',
+    '\u{1EE47}', '\u{1EE49}', '\u{1EE4B}', '\u{1EE4D}', '\u{1EE4E}', '\u{1EE4F}', '\u{1EE51}', '\u{1EE52}',
+    '\u{1EE54}', '\u{1EE57}', '\u{1EE59}', '\u{1EE5B}', '\u{1EE5D}', '\u{1EE5F}', '\u{1EE61}', '\u{1EE62}',
+    '\u{1EE64}', '\u{1EE67}', '\u{1EE68}', '\u{1EE69}', '\u{1EE6A}', '\u{1EE6C}', '\u{1EE6D}', '\u{1EE6E}',
+    '\u{1EE6F}', '\u{1EE70}', '\u{1EE71}', '\u{1EE72}', '\u{1EE74}', '\u{1EE75}', '\u{1EE76}', '\u{1EE77}',
+    '\u{1EE79}', '\u{1EE7A}', '\u{1EE7B}', '\u{1EE7C}', '\u{1EE7E}', '\u{1EE80}', '\u{1EE81}', '\u{1EE82}',
+    '\u{1EE83}', '\u{1EE84}', '\u{1EE85}', '\u{1EE86}', '\u{1EE87}', '\u{1EE88}', '\u{1EE89}', '\u{1EE8B}',
+    '\u{1EE8C}', '\u{1EE8D}', '\u{1EE8E}', '\u{1EE8F}', '\u{1EE90}', '\u{1EE91}', '\u{1EE92}', '\u{1EE93}',
+    '\u{1EE94}', '\u{1EE95}', '\u{1EE96}', '\u{1EE97}', '\u{1EE98}', '\u{1EE99}', '\u{1EE9A}', '\u{1EE9B}',
+    '\u{1EEA1}', '\u{1EEA2}', '\u{1EEA3}', '\u{1EEA5}', '\u{1EEA6}', '\u{1EEA7}', '\u{1EEA8}', '\u{1EEA9}',
+    '\u{1EEAB}', '\u{1EEAC}', '\u{1EEAD}', '\u{1EEAE}', '\u{1EEAF}', '\u{1EEB0}', '\u{1EEB1}', '\u{1EEB2}',
+    '\u{1EEB3}', '\u{1EEB4}', '\u{1EEB5}', '\u{1EEB6}', '\u{1EEB7}', '\u{1EEB8}', '\u{1EEB9}', '\u{1EEBA}',
+    '\u{1EEBB}', '\u{1F100}', '\u{1F101}', '\u{1F102}', '\u{1F103}', '\u{1F104}', '\u{1F105}', '\u{1F106}',
+    '\u{1F107}', '\u{1F108}', '\u{1F109}', '\u{1F10A}', '\u{1F110}', '\u{1F111}', '\u{1F112}', '\u{1F113}',
+    '\u{1F114}', '\u{1F115}', '\u{1F116}', '\u{1F117}', '\u{1F118}', '\u{1F119}', '\u{1F11A}', '\u{1F11B}',
+    '\u{1F11C}', '\u{1F11D}', '\u{1F11E}', '\u{1F11F}', '\u{1F120}', '\u{1F121}', '\u{1F122}', '\u{1F123}',
+    '\u{1F124}', '\u{1F125}', '\u{1F126}', '\u{1F127}', '\u{1F128}', '\u{1F129}', '\u{1F12A}', '\u{1F12B}',
+    '\u{1F12C}', '\u{1F12D}', '\u{1F12E}', '\u{1F130}', '\u{1F131}', '\u{1F132}', '\u{1F133}', '\u{1F134}',
+    '\u{1F135}', '\u{1F136}', '\u{1F137}', '\u{1F138}', '\u{1F139}', '\u{1F13A}', '\u{1F13B}', '\u{1F13C}',
+    '\u{1F13D}', '\u{1F13E}', '\u{1F13F}', '\u{1F140}', '\u{1F141}', '\u{1F142}', '\u{1F143}', '\u{1F144}',
+    '\u{1F145}', '\u{1F146}', '\u{1F147}', '\u{1F148}', '\u{1F149}', '\u{1F14A}', '\u{1F14B}', '\u{1F14C}',
+    '\u{1F14D}', '\u{1F14E}', '\u{1F14F}', '\u{1F16A}', '\u{1F16B}', '\u{1F16C}', '\u{1F190}', '\u{1F200}',
+    '\u{1F201}', '\u{1F202}', '\u{1F210}', '\u{1F211}', '\u{1F212}', '\u{1F213}', '\u{1F214}', '\u{1F215}',
+    '\u{1F216}', '\u{1F217}', '\u{1F218}', '\u{1F219}', '\u{1F21A}', '\u{1F21B}', '\u{1F21C}', '\u{1F21D}',
+    '\u{1F21E}', '\u{1F21F}', '\u{1F220}', '\u{1F221}', '\u{1F222}', '\u{1F223}', '\u{1F224}', '\u{1F225}',
+    '\u{1F226}', '\u{1F227}', '\u{1F228}', '\u{1F229}', '\u{1F22A}', '\u{1F22B}', '\u{1F22C}', '\u{1F22D}',
+    '\u{1F22E}', '\u{1F22F}', '\u{1F230}', '\u{1F231}', '\u{1F232}', '\u{1F233}', '\u{1F234}', '\u{1F235}',
+    '\u{1F236}', '\u{1F237}', '\u{1F238}', '\u{1F239}', '\u{1F23A}', '\u{1F23B}', '\u{1F240}', '\u{1F241}',
+    '\u{1F242}', '\u{1F243}', '\u{1F244}', '\u{1F245}', '\u{1F246}', '\u{1F247}', '\u{1F248}', '\u{1F250}',
+    '\u{1F251}', '\u{1FBF0}', '\u{1FBF1}', '\u{1FBF2}', '\u{1FBF3}', '\u{1FBF4}', '\u{1FBF5}', '\u{1FBF6}',
+    '\u{1FBF7}', '\u{1FBF8}', '\u{1FBF9}', '\u{2F800}', '\u{2F801}', '\u{2F802}', '\u{2F803}', '\u{2F804}',
+    '\u{2F805}', '\u{2F806}', '\u{2F807}', '\u{2F808}', '\u{2F809}', '\u{2F80A}', '\u{2F80B}', '\u{2F80C}',
+    '\u{2F80D}', '\u{2F80E}', '\u{2F80F}', '\u{2F810}', '\u{2F811}', '\u{2F812}', '\u{2F813}', '\u{2F814}',
+    '\u{2F815}', '\u{2F816}', '\u{2F817}', '\u{2F818}', '\u{2F819}', '\u{2F81A}', '\u{2F81B}', '\u{2F81C}',
+    '\u{2F81D}', '\u{2F81E}', '\u{2F81F}', '\u{2F820}', '\u{2F821}', '\u{2F822}', '\u{2F823}', '\u{2F824}',
+    '\u{2F825}', '\u{2F826}', '\u{2F827}', '\u{2F828}', '\u{2F829}', '\u{2F82A}', '\u{2F82B}', '\u{2F82C}',
+    '\u{2F82D}', '\u{2F82E}', '\u{2F82F}', '\u{2F830}', '\u{2F831}', '\u{2F832}', '\u{2F833}', '\u{2F834}',
+    '\u{2F835}', '\u{2F836}', '\u{2F837}', '\u{2F838}', '\u{2F839}', '\u{2F83A}', '\u{2F83B}', '\u{2F83C}',
+    '\u{2F83D}', '\u{2F83E}', '\u{2F83F}', '\u{2F840}', '\u{2F841}', '\u{2F842}', '\u{2F843}', '\u{2F844}',
+    '\u{2F845}', '\u{2F846}', '\u{2F847}', '\u{2F848}', '\u{2F849}', '\u{2F84A}', '\u{2F84B}', '\u{2F84C}',
+    '\u{2F84D}', '\u{2F84E}', '\u{2F84F}', '\u{2F850}', '\u{2F851}', '\u{2F852}', '\u{2F853}', '\u{2F854}',
+    '\u{2F855}', '\u{2F856}', '\u{2F857}', '\u{2F858}', '\u{2F859}', '\u{2F85A}', '\u{2F85B}', '\u{2F85C}',
+    '\u{2F85D}', '\u{2F85E}', '\u{2F85F}', '\u{2F860}', '\u{2F861}', '\u{2F862}', '\u{2F863}', '\u{2F864}',
+    '\u{2F865}', '\u{2F866}', '\u{2F867}', '\u{2F868}', '\u{2F869}', '\u{2F86A}', '\u{2F86B}', '\u{2F86C}',
+    '\u{2F86D}', '\u{2F86E}', '\u{2F86F}', '\u{2F870}', '\u{2F871}', '\u{2F872}', '\u{2F873}', '\u{2F874}',
+    '\u{2F875}', '\u{2F876}', '\u{2F877}', '\u{2F878}', '\u{2F879}', '\u{2F87A}', '\u{2F87B}', '\u{2F87C}',
+    '\u{2F87D}', '\u{2F87E}', '\u{2F87F}', '\u{2F880}', '\u{2F881}', '\u{2F882}', '\u{2F883}', '\u{2F884}',
+    '\u{2F885}', '\u{2F886}', '\u{2F887}', '\u{2F888}', '\u{2F889}', '\u{2F88A}', '\u{2F88B}', '\u{2F88C}',
+    '\u{2F88D}', '\u{2F88E}', '\u{2F88F}', '\u{2F890}', '\u{2F891}', '\u{2F892}', '\u{2F893}', '\u{2F894}',
+    '\u{2F895}', '\u{2F896}', '\u{2F897}', '\u{2F898}', '\u{2F899}', '\u{2F89A}', '\u{2F89B}', '\u{2F89C}',
+    '\u{2F89D}', '\u{2F89E}', '\u{2F89F}', '\u{2F8A0}', '\u{2F8A1}', '\u{2F8A2}', '\u{2F8A3}', '\u{2F8A4}',
+    '\u{2F8A5}', '\u{2F8A6}', '\u{2F8A7}', '\u{2F8A8}', '\u{2F8A9}', '\u{2F8AA}', '\u{2F8AB}', '\u{2F8AC}',
+    '\u{2F8AD}', '\u{2F8AE}', '\u{2F8AF}', '\u{2F8B0}', '\u{2F8B1}', '\u{2F8B2}', '\u{2F8B3}', '\u{2F8B4}',
+    '\u{2F8B5}', '\u{2F8B6}', '\u{2F8B7}', '\u{2F8B8}', '\u{2F8B9}', '\u{2F8BA}', '\u{2F8BB}', '\u{2F8BC}',
+    '\u{2F8BD}', '\u{2F8BE}', '\u{2F8BF}', '\u{2F8C0}', '\u{2F8C1}', '\u{2F8C2}', '\u{2F8C3}', '\u{2F8C4}',
+    '\u{2F8C5}', '\u{2F8C6}', '\u{2F8C7}', '\u{2F8C8}', '\u{2F8C9}', '\u{2F8CA}', '\u{2F8CB}', '\u{2F8CC}',
+    '\u{2F8CD}', '\u{2F8CE}', '\u{2F8CF}', '\u{2F8D0}', '\u{2F8D1}', '\u{2F8D2}', '\u{2F8D3}', '\u{2F8D4}',
+    '\u{2F8D5}', '\u{2F8D6}', '\u{2F8D7}', '\u{2F8D8}', '\u{2F8D9}', '\u{2F8DA}', '\u{2F8DB}', '\u{2F8DC}',
+    '\u{2F8DD}', '\u{2F8DE}', '\u{2F8DF}', '\u{2F8E0}', '\u{2F8E1}', '\u{2F8E2}', '\u{2F8E3}', '\u{2F8E4}',
+    '\u{2F8E5}', '\u{2F8E6}', '\u{2F8E7}', '\u{2F8E8}', '\u{2F8E9}', '\u{2F8EA}', '\u{2F8EB}', '\u{2F8EC}',
+    '\u{2F8ED}', '\u{2F8EE}', '\u{2F8EF}', '\u{2F8F0}', '\u{2F8F1}', '\u{2F8F2}', '\u{2F8F3}', '\u{2F8F4}',
+    '\u{2F8F5}', '\u{2F8F6}', '\u{2F8F7}', '\u{2F8F8}', '\u{2F8F9}', '\u{2F8FA}', '\u{2F8FB}', '\u{2F8FC}',
+    '\u{2F8FD}', '\u{2F8FE}', '\u{2F8FF}', '\u{2F900}', '\u{2F901}', '\u{2F902}', '\u{2F903}', '\u{2F904}',
+    '\u{2F905}', '\u{2F906}', '\u{2F907}', '\u{2F908}', '\u{2F909}', '\u{2F90A}', '\u{2F90B}', '\u{2F90C}',
+    '\u{2F90D}', '\u{2F90E}', '\u{2F90F}', '\u{2F910}', '\u{2F911}', '\u{2F912}', '\u{2F913}', '\u{2F914}',
+    '\u{2F915}', '\u{2F916}', '\u{2F917}', '\u{2F918}', '\u{2F919}', '\u{2F91A}', '\u{2F91B}', '\u{2F91C}',
+    '\u{2F91D}', '\u{2F91E}', '\u{2F91F}', '\u{2F920}', '\u{2F921}', '\u{2F922}', '\u{2F923}', '\u{2F924}',
+    '\u{2F925}', '\u{2F926}', '\u{2F927}', '\u{2F928}', '\u{2F929}', '\u{2F92A}', '\u{2F92B}', '\u{2F92C}',
+    '\u{2F92D}', '\u{2F92E}', '\u{2F92F}', '\u{2F930}', '\u{2F931}', '\u{2F932}', '\u{2F933}', '\u{2F934}',
+    '\u{2F935}', '\u{2F936}', '\u{2F937}', '\u{2F938}', '\u{2F939}', '\u{2F93A}', '\u{2F93B}', '\u{2F93C}',
+    '\u{2F93D}', '\u{2F93E}', '\u{2F93F}', '\u{2F940}', '\u{2F941}', '\u{2F942}', '\u{2F943}', '\u{2F944}',
+    '\u{2F945}', '\u{2F946}', '\u{2F947}', '\u{2F948}', '\u{2F949}', '\u{2F94A}', '\u{2F94B}', '\u{2F94C}',
+    '\u{2F94D}', '\u{2F94E}', '\u{2F94F}', '\u{2F950}', '\u{2F951}', '\u{2F952}', '\u{2F953}', '\u{2F954}',
+    '\u{2F955}', '\u{2F956}', '\u{2F957}', '\u{2F958}', '\u{2F959}', '\u{2F95A}', '\u{2F95B}', '\u{2F95C}',
+    '\u{2F95D}', '\u{2F95E}', '\u{2F95F}', '\u{2F960}', '\u{2F961}', '\u{2F962}', '\u{2F963}', '\u{2F964}',
+    '\u{2F965}', '\u{2F966}', '\u{2F967}', '\u{2F968}', '\u{2F969}', '\u{2F96A}', '\u{2F96B}', '\u{2F96C}',
+    '\u{2F96D}', '\u{2F96E}', '\u{2F96F}', '\u{2F970}', '\u{2F971}', '\u{2F972}', '\u{2F973}', '\u{2F974}',
+    '\u{2F975}', '\u{2F976}', '\u{2F977}', '\u{2F978}', '\u{2F979}', '\u{2F97A}', '\u{2F97B}', '\u{2F97C}',
+    '\u{2F97D}', '\u{2F97E}', '\u{2F97F}', '\u{2F980}', '\u{2F981}', '\u{2F982}', '\u{2F983}', '\u{2F984}',
+    '\u{2F985}', '\u{2F986}', '\u{2F987}', '\u{2F988}', '\u{2F989}', '\u{2F98A}', '\u{2F98B}', '\u{2F98C}',
+    '\u{2F98D}', '\u{2F98E}', '\u{2F98F}', '\u{2F990}', '\u{2F991}', '\u{2F992}', '\u{2F993}', '\u{2F994}',
+    '\u{2F995}', '\u{2F996}', '\u{2F997}', '\u{2F998}', '\u{2F999}', '\u{2F99A}', '\u{2F99B}', '\u{2F99C}',
+    '\u{2F99D}', '\u{2F99E}', '\u{2F99F}', '\u{2F9A0}', '\u{2F9A1}', '\u{2F9A2}', '\u{2F9A3}', '\u{2F9A4}',
+    '\u{2F9A5}', '\u{2F9A6}', '\u{2F9A7}', '\u{2F9A8}', '\u{2F9A9}', '\u{2F9AA}', '\u{2F9AB}', '\u{2F9AC}',
+    '\u{2F9AD}', '\u{2F9AE}', '\u{2F9AF}', '\u{2F9B0}', '\u{2F9B1}', '\u{2F9B2}', '\u{2F9B3}', '\u{2F9B4}',
+    '\u{2F9B5}', '\u{2F9B6}', '\u{2F9B7}', '\u{2F9B8}', '\u{2F9B9}', '\u{2F9BA}', '\u{2F9BB}', '\u{2F9BC}',
+    '\u{2F9BD}', '\u{2F9BE}', '\u{2F9BF}', '\u{2F9C0}', '\u{2F9C1}', '\u{2F9C2}', '\u{2F9C3}', '\u{2F9C4}',
+    '\u{2F9C5}', '\u{2F9C6}', '\u{2F9C7}', '\u{2F9C8}', '\u{2F9C9}', '\u{2F9CA}', '\u{2F9CB}', '\u{2F9CC}',
+    '\u{2F9CD}', '\u{2F9CE}', '\u{2F9CF}', '\u{2F9D0}', '\u{2F9D1}', '\u{2F9D2}', '\u{2F9D3}', '\u{2F9D4}',
+    '\u{2F9D5}', '\u{2F9D6}', '\u{2F9D7}', '\u{2F9D8}', '\u{2F9D9}', '\u{2F9DA}', '\u{2F9DB}', '\u{2F9DC}',
+    '\u{2F9DD}', '\u{2F9DE}', '\u{2F9DF}', '\u{2F9E0}', '\u{2F9E1}', '\u{2F9E2}', '\u{2F9E3}', '\u{2F9E4}',
+    '\u{2F9E5}', '\u{2F9E6}', '\u{2F9E7}', '\u{2F9E8}', '\u{2F9E9}', '\u{2F9EA}', '\u{2F9EB}', '\u{2F9EC}',
+    '\u{2F9ED}', '\u{2F9EE}', '\u{2F9EF}', '\u{2F9F0}', '\u{2F9F1}', '\u{2F9F2}', '\u{2F9F3}', '\u{2F9F4}',
+    '\u{2F9F5}', '\u{2F9F6}', '\u{2F9F7}', '\u{2F9F8}', '\u{2F9F9}', '\u{2F9FA}', '\u{2F9FB}', '\u{2F9FC}',
+    '\u{2F9FD}', '\u{2F9FE}', '\u{2F9FF}', '\u{2FA00}', '\u{2FA01}', '\u{2FA02}', '\u{2FA03}', '\u{2FA04}',
+    '\u{2FA05}', '\u{2FA06}', '\u{2FA07}', '\u{2FA08}', '\u{2FA09}', '\u{2FA0A}', '\u{2FA0B}', '\u{2FA0C}',
+    '\u{2FA0D}', '\u{2FA0E}', '\u{2FA0F}', '\u{2FA10}', '\u{2FA11}', '\u{2FA12}', '\u{2FA13}', '\u{2FA14}',
+    '\u{2FA15}', '\u{2FA16}', '\u{2FA17}', '\u{2FA18}', '\u{2FA19}', '\u{2FA1A}', '\u{2FA1B}', '\u{2FA1C}',
+    '\u{2FA1D}',
+];
+pub(crate) static NFKC_CASEFOLD_ENDS: [u16; 6409] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12,
+    13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+    25, 26, 27, 30, 31, 34, 35, 36, 39, 41, 44, 45,
+    46, 51, 56, 61, 63, 65, 67, 69, 71, 73, 75, 77,
+    79, 81, 83, 85, 87, 89, 91, 93, 95, 97, 99, 101,
+    103, 105, 107, 109, 111, 113, 115, 117, 119, 121, 123, 125,
+    127, 129, 131, 133, 135, 137, 139, 141, 143, 145, 147, 149,
+    151, 153, 155, 157, 159, 161, 163, 165, 167, 169, 171, 174,
+    176, 178, 180, 182, 184, 186, 188, 191, 194, 196, 198, 200,
+    202, 205, 207, 209, 211, 213, 215, 217, 219, 221, 223, 225,
+    227, 229, 231, 233, 235, 237, 239, 241, 243, 245, 247, 249,
+    251, 253, 255, 257, 259, 260, 262, 264, 266, 268, 270, 272,
+    274, 276, 278, 280, 282, 284, 286, 288, 290, 292, 294, 296,
+    298, 300, 302, 304, 306, 308, 310, 312, 314, 316, 318, 320,
+    322, 324, 326, 328, 330, 332, 335, 338, 341, 343, 345, 347,
+    349, 351, 353, 355, 357, 359, 361, 363, 365, 367, 369, 371,
+    373, 375, 377, 379, 381, 383, 385, 387, 389, 391, 393, 395,
+    397, 399, 401, 403, 405, 407, 409, 411, 413, 415, 417, 419,
+    421, 423, 425, 427, 429, 431, 433, 435, 437, 439, 441, 443,
+    445, 447, 449, 451, 453, 455, 457, 459, 462, 464, 466, 469,
+    471, 473, 475, 477, 479, 481, 483, 485, 487, 488, 490, 491,
+    492, 494, 496, 498, 499, 500, 503, 506, 509, 512, 515, 518,
+    520, 521, 522, 523, 525, 527, 529, 531, 535, 537, 539, 541,
+    543, 545, 548, 549, 551, 554, 559, 561, 563, 565, 567, 569,
+    571, 573, 575, 577, 579, 581, 583, 585, 587, 589, 591, 593,
+    595, 597, 599, 601, 603, 605, 607, 609, 611, 613, 615, 617,
+    619, 621, 623, 625, 627, 629, 631, 633, 635, 637, 639, 641,
+    643, 645, 647, 649, 651, 653, 655, 657, 659, 661, 663, 665,
+    667, 669, 671, 673, 675, 677, 679, 681, 683, 685, 687, 689,
+    691, 693, 695, 697, 699, 701, 703, 705, 707, 709, 711, 713,
+    715, 717, 719, 721, 723, 725, 727, 729, 731, 733, 735, 737,
+    739, 741, 743, 745, 747, 749, 751, 753, 755, 757, 759, 761,
+    763, 765, 767, 769, 771, 773, 775, 777, 779, 781, 783, 785,
+    787, 789, 791, 793, 795, 797, 799, 801, 803, 805, 807, 809,
+    811, 813, 815, 817, 819, 821, 823, 825, 827, 829, 831, 833,
+    835, 837, 839, 841, 843, 845, 847, 849, 851, 853, 855, 857,
+    859, 861, 863, 865, 867, 869, 871, 873, 875, 877, 879, 881,
+    883, 885, 887, 889, 891, 893, 895, 897, 899, 901, 903, 905,
+    907, 909, 911, 913, 915, 917, 919, 921, 923, 925, 927, 929,
+    931, 933, 935, 937, 939, 941, 943, 945, 947, 949, 951, 953,
+    955, 957, 959, 961, 963, 965, 967, 969, 971, 973, 975, 977,
+    979, 981, 983, 985, 987, 989, 991, 993, 995, 997, 999, 1001,
+    1003, 1005, 1007, 1009, 1011, 1013, 1015, 1017, 1019, 1021, 1023, 1025,
+    1027, 1029, 1031, 1033, 1035, 1037, 1039, 1041, 1043, 1045, 1047, 1049,
+    1051, 1053, 1055, 1057, 1059, 1061, 1063, 1067, 1071, 1075, 1079, 1083,
+    1089, 1095, 1101, 1107, 1113, 1119, 1125, 1131, 1137, 1143, 1149, 1155,
+    1161, 1167, 1173, 1179, 1185, 1191, 1197, 1203, 1209, 1215, 1221, 1224,
+    1230, 1236, 1242, 1248, 1254, 1260, 1266, 1272, 1278, 1287, 1293, 1302,
+    1308, 1314, 1320, 1326, 1332, 1338, 1344, 1347, 1350, 1353, 1356, 1359,
+    1362, 1365, 1368, 1371, 1374, 1377, 1380, 1383, 1386, 1389, 1392, 1395,
+    1398, 1401, 1404, 1407, 1410, 1413, 1416, 1419, 1422, 1425, 1428, 1431,
+    1434, 1437, 1440, 1443, 1446, 1449, 1452, 1455, 1458, 1461, 1464, 1467,
+    1470, 1473, 1476, 1479, 1482, 1485, 1487, 1489, 1491, 1493, 1495, 1497,
+    1499, 1501, 1504, 1507, 1510, 1513, 1516, 1519, 1522, 1525, 1528, 1531,
+    1534, 1537, 1540, 1543, 1546, 1549, 1552, 1555, 1558, 1561, 1564, 1567,
+    1570, 1573, 1576, 1579, 1582, 1585, 1588, 1591, 1594, 1597, 1600, 1603,
+    1606, 1609, 1612, 1615, 1618, 1621, 1624, 1627, 1630, 1633, 1636, 1639,
+    1642, 1645, 1646, 1648, 1649, 1650, 1651, 1653, 1654, 1655, 1656, 1657,
+    1658, 1659, 1660, 1661, 1662, 1664, 1665, 1666, 1667, 1668, 1669, 1670,
+    1672, 1674, 1677, 1678, 1679, 1680, 1682, 1684, 1686, 1687, 1688, 1689,
+    1691, 1692, 1694, 1697, 1700, 1701, 1702, 1703, 1706, 1708, 1709, 1712,
+    1714, 1716, 1718, 1720, 1722, 1723, 1724, 1725, 1726, 1728, 1730, 1732,
+    1734, 1736, 1738, 1740, 1741, 1743, 1745, 1747, 1748, 1750, 1752, 1754,
+    1756, 1758, 1760, 1763, 1765, 1767, 1770, 1772, 1774, 1776, 1778, 1780,
+    1782, 1784, 1786, 1788, 1790, 1792, 1794, 1796, 1799, 1801, 1803, 1804,
+    1806, 1808, 1810, 1812, 1815, 1818, 1821, 1824, 1827, 1830, 1833, 1836,
+    1839, 1842, 1845, 1848, 1851, 1854, 1857, 1860, 1863, 1866, 1869, 1872,
+    1875, 1878, 1881, 1884, 1887, 1890, 1893, 1896, 1899, 1902, 1905, 1908,
+    1911, 1914, 1917, 1920, 1923, 1926, 1929, 1932, 1935, 1938, 1941, 1944,
+    1947, 1950, 1953, 1956, 1959, 1962, 1965, 1968, 1971, 1974, 1977, 1980,
+    1983, 1986, 1989, 1992, 1995, 1998, 2001, 2004, 2007, 2010, 2013, 2016,
+    2019, 2022, 2025, 2028, 2031, 2034, 2037, 2040, 2043, 2045, 2048, 2051,
+    2054, 2057, 2060, 2063, 2066, 2069, 2072, 2075, 2078, 2081, 2084, 2087,
+    2090, 2093, 2096, 2099, 2102, 2105, 2108, 2111, 2114, 2117, 2120, 2123,
+    2126, 2129, 2132, 2135, 2138, 2141, 2144, 2147, 2150, 2153, 2156, 2159,
+    2162, 2165, 2168, 2171, 2174, 2177, 2180, 2183, 2186, 2189, 2192, 2195,
+    2198, 2201, 2204, 2207, 2210, 2213, 2216, 2219, 2222, 2225, 2228, 2231,
+    2234, 2237, 2240, 2243, 2246, 2249, 2252, 2255, 2258, 2261, 2264, 2267,
+    2270, 2273, 2276, 2279, 2282, 2285, 2288, 2291, 2294, 2297, 2300, 2303,
+    2306, 2309, 2312, 2315, 2318, 2321, 2324, 2327, 2330, 2333, 2335, 2337,
+    2339, 2341, 2343, 2345, 2347, 2352, 2357, 2362, 2367, 2372, 2377, 2382,
+    2387, 2392, 2397, 2402, 2407, 2412, 2417, 2422, 2427, 2432, 2437, 2442,
+    2447, 2452, 2457, 2462, 2467, 2472, 2477, 2482, 2487, 2492, 2497, 2502,
+    2507, 2512, 2517, 2522, 2527, 2532, 2537, 2542, 2547, 2552, 2557, 2562,
+    2567, 2572, 2577, 2582, 2587, 2592, 2596, 2600, 2605, 2608, 2611, 2614,
+    2616, 2620, 2623, 2625, 2628, 2631, 2636, 2641, 2645, 2649, 2654, 2657,
+    2659, 2662, 2664, 2668, 2673, 2678, 2683, 2685, 2688, 2691, 2694, 2696,
+    2701, 2706, 2711, 2713, 2716, 2719, 2722, 2724, 2727, 2732, 2737, 2738,
+    2743, 2747, 2751, 2756, 2759, 2761, 2764, 2766, 2770, 2773, 2776, 2777,
+    2778, 2779, 2780, 2781, 2782, 2783, 2784, 2785, 2786, 2787, 2790, 2793,
+    2794, 2796, 2799, 2800, 2806, 2815, 2821, 2830, 2832, 2835, 2837, 2839,
+    2841, 2853, 2854, 2855, 2856, 2857, 2858, 2859, 2860, 2861, 2862, 2863,
+    2866, 2867, 2868, 2869, 2870, 2871, 2872, 2873, 2874, 2875, 2876, 2877,
+    2878, 2879, 2880, 2881, 2884, 2885, 2886, 2887, 2888, 2889, 2890, 2891,
+    2893, 2894, 2895, 2896, 2897, 2898, 2899, 2900, 2901, 2903, 2906, 2909,
+    2910, 2913, 2916, 2919, 2921, 2924, 2925, 2926, 2927, 2928, 2929, 2931,
+    2932, 2933, 2934, 2935, 2936, 2938, 2939, 2940, 2941, 2942, 2943, 2945,
+    2948, 2950, 2951, 2953, 2954, 2955, 2957, 2958, 2959, 2960, 2961, 2962,
+    2965, 2966, 2967, 2969, 2971, 2973, 2975, 2976, 2979, 2981, 2983, 2985,
+    2987, 2990, 2991, 2992, 2993, 2994, 2995, 3000, 3005, 3011, 3016, 3021,
+    3026, 3031, 3036, 3041, 3046, 3051, 3056, 3061, 3066, 3071, 3075, 3076,
+    3078, 3081, 3083, 3084, 3086, 3089, 3093, 3095, 3096, 3098, 3101, 3102,
+    3103, 3104, 3105, 3106, 3108, 3111, 3113, 3114, 3116, 3119, 3123, 3125,
+    3126, 3128, 3131, 3132, 3133, 3134, 3135, 3138, 3143, 3149, 3158, 3164,
+    3173, 3176, 3179, 3180, 3181, 3182, 3183, 3184, 3185, 3186, 3187, 3188,
+    3190, 3192, 3194, 3196, 3198, 3200, 3202, 3204, 3206, 3208, 3210, 3213,
+    3216, 3219, 3222, 3225, 3228, 3231, 3234, 3237, 3241, 3245, 3249, 3253,
+    3257, 3261, 3265, 3269, 3273, 3277, 3281, 3283, 3285, 3287, 3289, 3291,
+    3293, 3295, 3297, 3299, 3302, 3305, 3308, 3311, 3314, 3317, 3320, 3323,
+    3326, 3329, 3332, 3335, 3338, 3341, 3344, 3347, 3350, 3353, 3356, 3359,
+    3362, 3365, 3368, 3371, 3374, 3377, 3380, 3383, 3386, 3389, 3392, 3395,
+    3398, 3401, 3404, 3407, 3410, 3411, 3412, 3413, 3414, 3415, 3416, 3417,
+    3418, 3419, 3420, 3421, 3422, 3423, 3424, 3425, 3426, 3427, 3428, 3429,
+    3430, 3431, 3432, 3433, 3434, 3435, 3436, 3437, 3438, 3439, 3440, 3441,
+    3442, 3443, 3444, 3445, 3446, 3447, 3448, 3449, 3450, 3451, 3452, 3453,
+    3454, 3455, 3456, 3457, 3458, 3459, 3460, 3461, 3462, 3463, 3475, 3478,
+    3480, 3483, 3488, 3491, 3494, 3497, 3500, 3503, 3506, 3509, 3512, 3515,
+    3518, 3521, 3524, 3527, 3530, 3533, 3536, 3539, 3542, 3545, 3548, 3551,
+    3554, 3557, 3560, 3563, 3566, 3569, 3572, 3575, 3578, 3581, 3584, 3587,
+    3590, 3593, 3596, 3599, 3602, 3605, 3608, 3611, 3614, 3617, 3620, 3623,
+    3626, 3629, 3632, 3635, 3637, 3640, 3642, 3645, 3648, 3651, 3653, 3655,
+    3657, 3659, 3662, 3665, 3666, 3667, 3669, 3671, 3674, 3677, 3680, 3683,
+    3686, 3689, 3692, 3695, 3698, 3701, 3704, 3707, 3710, 3713, 3716, 3719,
+    3722, 3725, 3728, 3731, 3734, 3737, 3740, 3743, 3746, 3749, 3752, 3755,
+    3758, 3761, 3764, 3767, 3770, 3773, 3776, 3779, 3782, 3785, 3788, 3791,
+    3794, 3797, 3800, 3803, 3806, 3809, 3812, 3815, 3818, 3821, 3824, 3827,
+    3830, 3833, 3836, 3839, 3842, 3845, 3848, 3851, 3854, 3857, 3860, 3863,
+    3866, 3869, 3872, 3875, 3878, 3881, 3884, 3887, 3890, 3893, 3896, 3899,
+    3902, 3905, 3908, 3911, 3914, 3917, 3920, 3923, 3926, 3929, 3932, 3935,
+    3938, 3941, 3944, 3947, 3950, 3953, 3956, 3959, 3962, 3965, 3968, 3971,
+    3974, 3977, 3980, 3983, 3986, 3989, 3992, 3995, 3998, 4001, 4004, 4007,
+    4010, 4013, 4016, 4019, 4022, 4025, 4028, 4031, 4034, 4037, 4040, 4043,
+    4046, 4049, 4052, 4055, 4058, 4061, 4064, 4067, 4070, 4073, 4076, 4079,
+    4082, 4085, 4088, 4091, 4094, 4097, 4100, 4103, 4106, 4109, 4112, 4115,
+    4118, 4121, 4124, 4127, 4130, 4133, 4136, 4139, 4142, 4145, 4148, 4151,
+    4154, 4157, 4160, 4163, 4166, 4169, 4172, 4175, 4178, 4181, 4184, 4187,
+    4190, 4193, 4196, 4199, 4202, 4205, 4208, 4211, 4214, 4217, 4220, 4223,
+    4226, 4229, 4232, 4235, 4238, 4241, 4244, 4247, 4250, 4253, 4256, 4259,
+    4262, 4265, 4268, 4271, 4274, 4277, 4280, 4283, 4286, 4289, 4292, 4295,
+    4298, 4301, 4304, 4307, 4310, 4313, 4316, 4319, 4322, 4325, 4328, 4331,
+    4334, 4337, 4340, 4343, 4346, 4349, 4352, 4355, 4358, 4361, 4364, 4367,
+    4370, 4373, 4376, 4379, 4382, 4385, 4388, 4391, 4394, 4397, 4400, 4403,
+    4406, 4409, 4412, 4415, 4418, 4421, 4424, 4427, 4430, 4433, 4436, 4439,
+    4442, 4445, 4448, 4451, 4454, 4457, 4460, 4463, 4466, 4469, 4472, 4475,
+    4478, 4481, 4482, 4485, 4488, 4491, 4494, 4498, 4502, 4508, 4514, 4517,
+    4520, 4523, 4526, 4529, 4532, 4535, 4538, 4541, 4544, 4547, 4550, 4553,
+    4556, 4559, 4562, 4565, 4568, 4571, 4574, 4577, 4580, 4583, 4586, 4589,
+    4592, 4595, 4598, 4601, 4604, 4607, 4610, 4613, 4616, 4619, 4622, 4625,
+    4628, 4631, 4634, 4637, 4640, 4643, 4646, 4649, 4652, 4655, 4658, 4661,
+    4664, 4667, 4670, 4673, 4676, 4679, 4682, 4685, 4688, 4691, 4694, 4697,
+    4700, 4703, 4706, 4709, 4712, 4715, 4718, 4721, 4724, 4727, 4730, 4733,
+    4736, 4739, 4742, 4745, 4748, 4751, 4754, 4757, 4760, 4763, 4766, 4769,
+    4772, 4775, 4778, 4781, 4784, 4787, 4790, 4793, 4796, 4799, 4802, 4805,
+    4808, 4811, 4814, 4817, 4820, 4823, 4826, 4829, 4832, 4835, 4840, 4845,
+    4850, 4855, 4860, 4865, 4870, 4875, 4880, 4885, 4890, 4895, 4900, 4905,
+    4910, 4915, 4920, 4925, 4930, 4935, 4940, 4945, 4950, 4955, 4960, 4965,
+    4970, 4975, 4980, 4988, 4996, 5001, 5006, 5011, 5016, 5021, 5026, 5031,
+    5036, 5041, 5046, 5051, 5056, 5061, 5066, 5071, 5076, 5081, 5086, 5091,
+    5096, 5101, 5106, 5111, 5116, 5121, 5126, 5131, 5136, 5141, 5146, 5151,
+    5156, 5161, 5166, 5171, 5176, 5179, 5182, 5185, 5188, 5191, 5193, 5195,
+    5197, 5199, 5201, 5203, 5205, 5207, 5209, 5211, 5213, 5215, 5217, 5219,
+    5221, 5224, 5227, 5230, 5233, 5236, 5239, 5242, 5245, 5248, 5251, 5254,
+    5257, 5260, 5263, 5266, 5269, 5272, 5275, 5278, 5281, 5284, 5287, 5290,
+    5293, 5296, 5299, 5302, 5305, 5311, 5317, 5320, 5323, 5326, 5329, 5332,
+    5335, 5338, 5341, 5344, 5347, 5350, 5353, 5356, 5359, 5362, 5365, 5368,
+    5371, 5374, 5377, 5380, 5383, 5386, 5389, 5392, 5395, 5398, 5401, 5404,
+    5407, 5410, 5413, 5416, 5419, 5422, 5425, 5428, 5431, 5434, 5437, 5440,
+    5443, 5446, 5449, 5452, 5455, 5458, 5461, 5464, 5467, 5469, 5471, 5473,
+    5475, 5477, 5479, 5481, 5483, 5485, 5487, 5489, 5491, 5493, 5495, 5497,
+    5501, 5505, 5509, 5513, 5517, 5521, 5525, 5529, 5533, 5538, 5543, 5548,
+    5550, 5553, 5555, 5558, 5561, 5564, 5567, 5570, 5573, 5576, 5579, 5582,
+    5585, 5588, 5591, 5594, 5597, 5600, 5603, 5606, 5609, 5612, 5615, 5618,
+    5621, 5624, 5627, 5630, 5633, 5636, 5639, 5642, 5645, 5648, 5651, 5654,
+    5657, 5660, 5663, 5666, 5669, 5672, 5675, 5678, 5681, 5684, 5687, 5690,
+    5693, 5696, 5699, 5705, 5717, 5729, 5741, 5750, 5762, 5771, 5780, 5795,
+    5807, 5816, 5825, 5834, 5846, 5858, 5867, 5876, 5882, 5891, 5903, 5915,
+    5921, 5936, 5954, 5969, 5978, 5993, 6008, 6020, 6029, 6038, 6047, 6059,
+    6074, 6086, 6095, 6104, 6113, 6119, 6125, 6131, 6137, 6146, 6155, 6170,
+    6179, 6191, 6206, 6215, 6221, 6227, 6242, 6254, 6269, 6278, 6293, 6299,
+    6308, 6317, 6326, 6335, 6344, 6356, 6365, 6371, 6380, 6389, 6398, 6410,
+    6419, 6428, 6437, 6452, 6464, 6470, 6485, 6491, 6503, 6515, 6524, 6533,
+    6542, 6554, 6560, 6569, 6581, 6587, 6602, 6611, 6615, 6619, 6623, 6627,
+    6631, 6635, 6639, 6643, 6647, 6651, 6656, 6661, 6666, 6671, 6676, 6681,
+    6686, 6691, 6696, 6701, 6706, 6711, 6716, 6721, 6726, 6729, 6731, 6733,
+    6736, 6738, 6740, 6742, 6745, 6748, 6750, 6756, 6762, 6768, 6774, 6786,
+    6788, 6790, 6793, 6795, 6797, 6799, 6801, 6803, 6806, 6810, 6812, 6814,
+    6817, 6820, 6822, 6824, 6826, 6829, 6832, 6835, 6838, 6841, 6843, 6845,
+    6847, 6849, 6851, 6854, 6856, 6858, 6860, 6863, 6866, 6868, 6871, 6874,
+    6877, 6879, 6882, 6887, 6893, 6895, 6898, 6901, 6904, 6907, 6914, 6922,
+    6924, 6926, 6929, 6931, 6933, 6935, 6938, 6940, 6942, 6944, 6946, 6948,
+    6951, 6953, 6955, 6957, 6960, 6963, 6967, 6969, 6971, 6973, 6979, 6982,
+    6984, 6986, 6988, 6990, 6992, 6994, 6996, 6998, 7000, 7002, 7005, 7007,
+    7009, 7012, 7015, 7017, 7021, 7024, 7026, 7028, 7030, 7032, 7037, 7042,
+    7046, 7050, 7054, 7058, 7062, 7066, 7070, 7074, 7078, 7083, 7088, 7093,
+    7098, 7103, 7108, 7113, 7118, 7123, 7128, 7133, 7138, 7143, 7148, 7153,
+    7158, 7163, 7168, 7173, 7178, 7183, 7188, 7191, 7194, 7197, 7200, 7203,
+    7206, 7209, 7212, 7215, 7218, 7221, 7224, 7227, 7230, 7233, 7236, 7239,
+    7242, 7245, 7248, 7251, 7254, 7257, 7260, 7263, 7266, 7269, 7272, 7275,
+    7278, 7281, 7284, 7287, 7290, 7293, 7296, 7299, 7302, 7304, 7306, 7309,
+    7312, 7315, 7318, 7321, 7324, 7327, 7330, 7333, 7336, 7339, 7342, 7345,
+    7348, 7351, 7354, 7357, 7360, 7363, 7366, 7369, 7372, 7375, 7378, 7381,
+    7384, 7387, 7390, 7393, 7396, 7399, 7402, 7405, 7408, 7411, 7414, 7417,
+    7420, 7423, 7426, 7429, 7432, 7435, 7438, 7441, 7444, 7447, 7450, 7452,
+    7455, 7458, 7461, 7464, 7467, 7470, 7473, 7476, 7479, 7482, 7485, 7488,
+    7490, 7492, 7494, 7496, 7498, 7500, 7502, 7504, 7507, 7510, 7513, 7516,
+    7519, 7522, 7525, 7528, 7531, 7534, 7536, 7539, 7542, 7545, 7547, 7550,
+    7553, 7556, 7559, 7562, 7565, 7568, 7571, 7573, 7574, 7575, 7576, 7577,
+    7580, 7582, 7584, 7587, 7590, 7592, 7595, 7597, 7600, 7603, 7606, 7609,
+    7612, 7615, 7618, 7621, 7624, 7627, 7630, 7633, 7636, 7639, 7642, 7645,
+    7648, 7651, 7654, 7657, 7660, 7663, 7666, 7669, 7672, 7675, 7678, 7681,
+    7684, 7687, 7690, 7693, 7696, 7699, 7702, 7705, 7708, 7711, 7714, 7717,
+    7720, 7723, 7726, 7729, 7732, 7735, 7738, 7741, 7744, 7747, 7750, 7753,
+    7756, 7759, 7762, 7765, 7768, 7771, 7774, 7777, 7780, 7783, 7786, 7789,
+    7792, 7795, 7798, 7801, 7804, 7807, 7810, 7813, 7816, 7819, 7822, 7825,
+    7828, 7831, 7834, 7837, 7840, 7843, 7846, 7849, 7852, 7855, 7858, 7861,
+    7864, 7867, 7870, 7873, 7876, 7879, 7882, 7885, 7888, 7891, 7894, 7897,
+    7900, 7903, 7906, 7909, 7912, 7915, 7918, 7921, 7924, 7927, 7930, 7933,
+    7936, 7939, 7942, 7945, 7948, 7951, 7954, 7957, 7960, 7963, 7966, 7969,
+    7972, 7975, 7978, 7981, 7984, 7987, 7990, 7993, 7996, 7999, 8002, 8005,
+    8008, 8011, 8014, 8017, 8020, 8023, 8026, 8029, 8032, 8035, 8038, 8041,
+    8044, 8047, 8050, 8053, 8056, 8059, 8062, 8065, 8068, 8071, 8074, 8077,
+    8080, 8083, 8086, 8089, 8092, 8095, 8098, 8101, 8104, 8107, 8110, 8113,
+    8116, 8119, 8122, 8125, 8128, 8131, 8134, 8137, 8140, 8143, 8146, 8149,
+    8152, 8155, 8158, 8161, 8164, 8167, 8170, 8173, 8176, 8179, 8182, 8185,
+    8188, 8191, 8194, 8197, 8200, 8203, 8206, 8209, 8212, 8215, 8218, 8221,
+    8224, 8227, 8230, 8233, 8236, 8239, 8242, 8245, 8248, 8251, 8254, 8257,
+    8260, 8263, 8266, 8269, 8272, 8275, 8278, 8281, 8284, 8287, 8290, 8293,
+    8296, 8299, 8302, 8305, 8308, 8311, 8314, 8317, 8320, 8323, 8326, 8329,
+    8332, 8335, 8338, 8341, 8344, 8347, 8350, 8353, 8356, 8359, 8362, 8365,
+    8368, 8371, 8374, 8377, 8380, 8383, 8386, 8389, 8392, 8395, 8398, 8401,
+    8404, 8407, 8410, 8413, 8416, 8419, 8422, 8425, 8428, 8431, 8434, 8437,
+    8440, 8443, 8446, 8449, 8452, 8455, 8458, 8461, 8464, 8467, 8470, 8473,
+    8476, 8479, 8482, 8485, 8488, 8491, 8494, 8497, 8500, 8503, 8506, 8509,
+    8512, 8515, 8518, 8521, 8524, 8527, 8530, 8533, 8536, 8539, 8542, 8545,
+    8548, 8551, 8554, 8557, 8560, 8563, 8566, 8569, 8572, 8575, 8578, 8581,
+    8584, 8587, 8590, 8593, 8596, 8599, 8602, 8605, 8608, 8611, 8614, 8617,
+    8620, 8623, 8626, 8629, 8632, 8635, 8638, 8641, 8644, 8647, 8650, 8653,
+    8656, 8659, 8662, 8665, 8668, 8671, 8674, 8677, 8680, 8683, 8686, 8689,
+    8692, 8695, 8698, 8701, 8704, 8707, 8710, 8713, 8716, 8719, 8722, 8725,
+    8728, 8731, 8734, 8737, 8740, 8743, 8746, 8749, 8752, 8755, 8758, 8761,
+    8764, 8767, 8770, 8773, 8776, 8779, 8782, 8785, 8788, 8791, 8794, 8797,
+    8800, 8803, 8806, 8809, 8812, 8815, 8818, 8821, 8824, 8827, 8830, 8833,
+    8836, 8839, 8842, 8845, 8848, 8851, 8854, 8857, 8860, 8863, 8866, 8869,
+    8872, 8875, 8878, 8881, 8884, 8887, 8890, 8893, 8897, 8900, 8903, 8906,
+    8909, 8912, 8915, 8918, 8921, 8924, 8927, 8930, 8933, 8936, 8939, 8942,
+    8945, 8948, 8951, 8954, 8957, 8960, 8963, 8966, 8969, 8972, 8975, 8978,
+    8981, 8984, 8987, 8990, 8993, 8996, 8999, 9002, 9005, 9008, 9011, 9014,
+    9017, 9020, 9023, 9026, 9029, 9032, 9035, 9038, 9041, 9044, 9047, 9050,
+    9053, 9056, 9059, 9062, 9065, 9068, 9071, 9074, 9077, 9080, 9083, 9086,
+    9089, 9092, 9095, 9098, 9101, 9104, 9107, 9110, 9113, 9116, 9119, 9122,
+    9125, 9128, 9131, 9134, 9137, 9140, 9143, 9146, 9149, 9152, 9155, 9158,
+    9161, 9164, 9167, 9170, 9173, 9176, 9179, 9182, 9185, 9189, 9193, 9197,
+    9200, 9203, 9206, 9210, 9214, 9218, 9221, 9224, 9226, 9228, 9230, 9233,
+    9236, 9238, 9240, 9244, 9248, 9252, 9256, 9260, 9264, 9268, 9270, 9272,
+    9274, 9276, 9278, 9280, 9282, 9284, 9286, 9287, 9291, 9295, 9301, 9307,
+    9311, 9315, 9319, 9323, 9327, 9331, 9335, 9339, 9343, 9347, 9351, 9355,
+    9359, 9363, 9367, 9371, 9375, 9379, 9383, 9387, 9391, 9395, 9399, 9403,
+    9407, 9411, 9415, 9419, 9423, 9425, 9427, 9429, 9431, 9433, 9435, 9437,
+    9439, 9441, 9443, 9445, 9447, 9449, 9451, 9453, 9455, 9457, 9459, 9461,
+    9463, 9465, 9467, 9469, 9471, 9473, 9475, 9477, 9479, 9481, 9483, 9485,
+    9487, 9489, 9491, 9493, 9495, 9497, 9499, 9501, 9503, 9505, 9507, 9509,
+    9511, 9513, 9515, 9517, 9519, 9521, 9523, 9525, 9527, 9529, 9531, 9533,
+    9535, 9537, 9539, 9541, 9543, 9545, 9547, 9549, 9551, 9553, 9555, 9557,
+    9559, 9561, 9563, 9565, 9567, 9569, 9571, 9573, 9575, 9577, 9579, 9581,
+    9583, 9585, 9587, 9589, 9591, 9593, 9595, 9597, 9599, 9601, 9603, 9605,
+    9607, 9609, 9611, 9613, 9615, 9617, 9619, 9621, 9623, 9625, 9627, 9629,
+    9631, 9633, 9635, 9637, 9639, 9643, 9645, 9647, 9649, 9651, 9653, 9655,
+    9657, 9659, 9661, 9663, 9665, 9667, 9671, 9675, 9679, 9683, 9687, 9691,
+    9695, 9699, 9703, 9707, 9711, 9715, 9719, 9723, 9727, 9731, 9735, 9739,
+    9741, 9743, 9745, 9747, 9751, 9755, 9759, 9763, 9767, 9771, 9775, 9779,
+    9783, 9787, 9791, 9795, 9799, 9803, 9807, 9811, 9815, 9819, 9823, 9827,
+    9831, 9835, 9839, 9843, 9847, 9851, 9855, 9859, 9863, 9867, 9871, 9875,
+    9879, 9883, 9887, 9891, 9895, 9899, 9903, 9907, 9911, 9915, 9919, 9923,
+    9927, 9931, 9935, 9939, 9943, 9947, 9951, 9955, 9959, 9963, 9967, 9971,
+    9975, 9979, 9983, 9987, 9991, 9995, 9999, 10003, 10007, 10011, 10015, 10019,
+    10023, 10027, 10031, 10035, 10039, 10043, 10047, 10051, 10055, 10059, 10063, 10067,
+    10071, 10075, 10079, 10083, 10087, 10091, 10095, 10099, 10103, 10107, 10111, 10115,
+    10119, 10123, 10128, 10133, 10138, 10143, 10148, 10153, 10157, 10161, 10165, 10169,
+    10173, 10177, 10181, 10185, 10189, 10193, 10197, 10201, 10205, 10209, 10213, 10217,
+    10221, 10225, 10229, 10233, 10237, 10241, 10245, 10249, 10253, 10257, 10261, 10265,
+    10269, 10273, 10277, 10281, 10285, 10289, 10293, 10297, 10301, 10305, 10309, 10313,
+    10317, 10321, 10325, 10329, 10333, 10337, 10341, 10345, 10349, 10353, 10357, 10361,
+    10365, 10369, 10373, 10377, 10381, 10385, 10389, 10393, 10397, 10401, 10405, 10409,
+    10413, 10417, 10421, 10425, 10429, 10433, 10437, 10441, 10445, 10449, 10453, 10457,
+    10461, 10465, 10469, 10473, 10477, 10481, 10485, 10489, 10493, 10497, 10501, 10505,
+    10509, 10513, 10517, 10521, 10525, 10529, 10533, 10537, 10541, 10545, 10549, 10553,
+    10557, 10561, 10565, 10569, 10573, 10577, 10581, 10585, 10589, 10593, 10597, 10601,
+    10605, 10609, 10613, 10617, 10621, 10625, 10629, 10633, 10637, 10641, 10645, 10649,
+    10653, 10657, 10661, 10665, 10669, 10673, 10677, 10681, 10685, 10689, 10693, 10697,
+    10701, 10705, 10709, 10713, 10717, 10721, 10727, 10733, 10739, 10743, 10747, 10751,
+    10755, 10759, 10763, 10767, 10771, 10775, 10779, 10783, 10787, 10791, 10795, 10799,
+    10803, 10807, 10811, 10815, 10819, 10823, 10827, 10831, 10835, 10839, 10843, 10847,
+    10851, 10855, 10859, 10863, 10867, 10871, 10875, 10879, 10883, 10887, 10891, 10895,
+    10899, 10903, 10907, 10911, 10915, 10919, 10923, 10927, 10931, 10935, 10939, 10943,
+    10947, 10951, 10955, 10959, 10963, 10967, 10971, 10975, 10979, 10983, 10987, 10991,
+    10995, 10999, 11003, 11007, 11011, 11015, 11019, 11023, 11027, 11031, 11037, 11043,
+    11049, 11055, 11061, 11067, 11073, 11079, 11085, 11091, 11097, 11103, 11109, 11115,
+    11121, 11127, 11133, 11139, 11145, 11151, 11157, 11163, 11169, 11175, 11181, 11187,
+    11193, 11199, 11205, 11211, 11217, 11223, 11229, 11235, 11241, 11247, 11253, 11259,
+    11265, 11271, 11277, 11283, 11289, 11295, 11301, 11307, 11313, 11319, 11325, 11331,
+    11337, 11343, 11349, 11355, 11361, 11367, 11373, 11379, 11385, 11391, 11397, 11403,
+    11409, 11415, 11421, 11427, 11433, 11439, 11445, 11451, 11457, 11463, 11469, 11475,
+    11481, 11487, 11493, 11499, 11505, 11511, 11517, 11523, 11529, 11535, 11541, 11547,
+    11553, 11559, 11565, 11571, 11577, 11583, 11589, 11595, 11601, 11607, 11613, 11619,
+    11625, 11631, 11637, 11643, 11649, 11655, 11661, 11667, 11673, 11679, 11685, 11691,
+    11697, 11703, 11709, 11715, 11721, 11727, 11733, 11739, 11745, 11751, 11759, 11767,
+    11775, 11783, 11791, 11799, 11807, 11813, 11846, 11861, 11869, 11870, 11873, 11876,
+    11877, 11878, 11879, 11880, 11883, 11886, 11889, 11891, 11894, 11897, 11898, 11899,
+    11900, 11901, 11902, 11903, 11906, 11909, 11912, 11915, 11918, 11921, 11924, 11927,
+    11930, 11933, 11936, 11939, 11940, 11941, 11944, 11947, 11950, 11953, 11954, 11955,
+    11956, 11957, 11960, 11961, 11962, 11963, 11964, 11965, 11968, 11969, 11970, 11971,
+    11972, 11975, 11978, 11979, 11980, 11981, 11982, 11983, 11984, 11985, 11986, 11987,
+    11988, 11989, 11990, 11993, 11997, 12000, 12003, 12006, 12010, 12013, 12017, 12020,
+    12024, 12027, 12031, 12034, 12038, 12040, 12042, 12044, 12046, 12048, 12050, 12052,
+    12054, 12056, 12058, 12060, 12062, 12064, 12066, 12068, 12070, 12072, 12074, 12076,
+    12078, 12080, 12082, 12084, 12086, 12088, 12090, 12092, 12094, 12096, 12098, 12100,
+    12102, 12104, 12106, 12108, 12110, 12112, 12114, 12116, 12118, 12120, 12122, 12124,
+    12126, 12128, 12130, 12132, 12134, 12136, 12138, 12140, 12142, 12144, 12146, 12148,
+    12150, 12152, 12154, 12156, 12158, 12160, 12162, 12164, 12166, 12168, 12170, 12172,
+    12174, 12176, 12178, 12180, 12182, 12184, 12186, 12188, 12190, 12192, 12194, 12196,
+    12198, 12200, 12202, 12204, 12206, 12208, 12210, 12212, 12214, 12216, 12218, 12220,
+    12222, 12224, 12226, 12228, 12230, 12232, 12234, 12236, 12238, 12240, 12242, 12244,
+    12246, 12248, 12250, 12252, 12254, 12256, 12258, 12260, 12262, 12264, 12266, 12268,
+    12270, 12272, 12276, 12280, 12284, 12288, 12292, 12296, 12300, 12304, 12305, 12306,
+    12307, 12308, 12309, 12310, 12311, 12312, 12313, 12314, 12315, 12316, 12317, 12318,
+    12319, 12320, 12321, 12322, 12323, 12324, 12325, 12326, 12327, 12328, 12329, 12330,
+    12331, 12332, 12333, 12334, 12335, 12336, 12337, 12338, 12339, 12340, 12341, 12342,
+    12343, 12344, 12345, 12346, 12347, 12348, 12349, 12350, 12351, 12352, 12353, 12354,
+    12355, 12356, 12357, 12358, 12359, 12360, 12361, 12362, 12363, 12364, 12365, 12366,
+    12367, 12368, 12369, 12370, 12371, 12372, 12373, 12374, 12375, 12376, 12377, 12378,
+    12379, 12380, 12381, 12382, 12383, 12384, 12385, 12386, 12387, 12388, 12389, 12390,
+    12391, 12392, 12393, 12394, 12395, 12396, 12397, 12398, 12401, 12404, 12407, 12410,
+    12413, 12416, 12419, 12422, 12425, 12428, 12431, 12434, 12437, 12440, 12443, 12446,
+    12449, 12452, 12455, 12458, 12461, 12464, 12467, 12470, 12473, 12476, 12479, 12482,
+    12485, 12488, 12491, 12494, 12497, 12500, 12503, 12506, 12509, 12512, 12515, 12518,
+    12521, 12524, 12527, 12530, 12533, 12536, 12539, 12542, 12545, 12548, 12551, 12554,
+    12557, 12560, 12563, 12566, 12569, 12572, 12575, 12578, 12581, 12584, 12587, 12590,
+    12593, 12596, 12599, 12602, 12605, 12608, 12611, 12614, 12617, 12620, 12623, 12626,
+    12629, 12632, 12635, 12638, 12641, 12644, 12647, 12650, 12653, 12656, 12659, 12662,
+    12665, 12668, 12671, 12674, 12677, 12680, 12683, 12686, 12689, 12692, 12695, 12698,
+    12701, 12704, 12707, 12710, 12713, 12716, 12719, 12722, 12725, 12728, 12731, 12734,
+    12737, 12740, 12743, 12746, 12748, 12750, 12752, 12755, 12757, 12759, 12762, 12765,
+    12768, 12771, 12774, 12777, 12780, 12783, 12787, 12791, 12795, 12799, 12803, 12807,
+    12811, 12815, 12819, 12823, 12827, 12831, 12835, 12839, 12843, 12847, 12851, 12855,
+    12859, 12863, 12867, 12871, 12875, 12879, 12883, 12887, 12891, 12895, 12899, 12903,
+    12907, 12911, 12915, 12919, 12923, 12927, 12931, 12935, 12939, 12943, 12947, 12951,
+    12955, 12959, 12963, 12967, 12971, 12975, 12979, 12983, 12987, 12991, 12995, 12999,
+    13003, 13007, 13011, 13015, 13019, 13023, 13027, 13031, 13035, 13039, 13043, 13047,
+    13051, 13055, 13059, 13063, 13067, 13071, 13075, 13079, 13083, 13087, 13091, 13095,
+    13099, 13103, 13107, 13111, 13115, 13119, 13123, 13127, 13131, 13135, 13139, 13143,
+    13147, 13151, 13155, 13159, 13163, 13167, 13171, 13175, 13179, 13183, 13187, 13191,
+    13195, 13199, 13203, 13207, 13211, 13215, 13219, 13223, 13227, 13229, 13231, 13233,
+    13235, 13237, 13239, 13242, 13244, 13246, 13248, 13250, 13253, 13255, 13257, 13259,
+    13261, 13263, 13265, 13267, 13269, 13271, 13273, 13275, 13277, 13279, 13281, 13285,
+    13288, 13290, 13294, 13296, 13300, 13302, 13304, 13306, 13307, 13309, 13313, 13315,
+    13317, 13319, 13321, 13323, 13326, 13328, 13330, 13333, 13335, 13337, 13339, 13341,
+    13343, 13345, 13347, 13351, 13355, 13359, 13363, 13367, 13371, 13375, 13379, 13383,
+    13387, 13391, 13395, 13399, 13403, 13407, 13411, 13415, 13419, 13423, 13427, 13431,
+    13435, 13439, 13443, 13447, 13451, 13455, 13459, 13463, 13467, 13471, 13475, 13479,
+    13483, 13487, 13491, 13495, 13499, 13503, 13507, 13511, 13515, 13519, 13523, 13527,
+    13531, 13535, 13539, 13543, 13547, 13551, 13555, 13559, 13563, 13567, 13571, 13575,
+    13579, 13583, 13587, 13591, 13595, 13599, 13603, 13607, 13611, 13615, 13619, 13623,
+    13627, 13631, 13635, 13639, 13643, 13647, 13651, 13655, 13659, 13663, 13667, 13671,
+    13675, 13679, 13683, 13687, 13691, 13695, 13699, 13703, 13707, 13711, 13715, 13719,
+    13723, 13727, 13731, 13735, 13739, 13743, 13747, 13751, 13755, 13759, 13763, 13767,
+    13771, 13775, 13779, 13783, 13787, 13791, 13795, 13799, 13803, 13807, 13811, 13815,
+    13819, 13823, 13827, 13831, 13835, 13839, 13843, 13847, 13851, 13855, 13859, 13863,
+    13867, 13871, 13875, 13879, 13883, 13887, 13891, 13895, 13899, 13903, 13907, 13911,
+    13915, 13919, 13923, 13927, 13931, 13935, 13939, 13943, 13947, 13951, 13955, 13959,
+    13963, 13967, 13971, 13975, 13979, 13983, 13987, 13991, 13995, 13999, 14003, 14004,
+    14005, 14006, 14007, 14008, 14009, 14010, 14011, 14012, 14013, 14014, 14015, 14016,
+    14017, 14018, 14019, 14020, 14021, 14022, 14023, 14024, 14025, 14026, 14027, 14028,
+    14029, 14030, 14031, 14032, 14033, 14034, 14035, 14036, 14037, 14038, 14039, 14047,
+    14055, 14067, 14079, 14091, 14103, 14115, 14123, 14131, 14143, 14155, 14167, 14179,
+    14180, 14181, 14182, 14183, 14184, 14185, 14186, 14187, 14188, 14189, 14190, 14191,
+    14192, 14193, 14194, 14195, 14196, 14197, 14198, 14199, 14200, 14201, 14202, 14203,
+    14204, 14205, 14206, 14207, 14208, 14209, 14210, 14211, 14212, 14213, 14214, 14215,
+    14216, 14217, 14218, 14219, 14220, 14221, 14222, 14223, 14224, 14225, 14226, 14227,
+    14228, 14229, 14230, 14231, 14232, 14233, 14234, 14235, 14236, 14237, 14238, 14239,
+    14240, 14241, 14242, 14243, 14244, 14245, 14246, 14247, 14248, 14249, 14250, 14251,
+    14252, 14253, 14254, 14255, 14256, 14257, 14258, 14259, 14260, 14261, 14262, 14263,
+    14264, 14265, 14266, 14267, 14268, 14269, 14270, 14271, 14272, 14273, 14274, 14275,
+    14276, 14277, 14278, 14279, 14280, 14281, 14282, 14283, 14284, 14285, 14286, 14287,
+    14288, 14289, 14290, 14291, 14292, 14293, 14294, 14295, 14296, 14297, 14298, 14299,
+    14300, 14301, 14302, 14303, 14304, 14305, 14306, 14307, 14308, 14309, 14310, 14311,
+    14312, 14313, 14314, 14315, 14316, 14317, 14318, 14319, 14320, 14321, 14322, 14323,
+    14324, 14325, 14326, 14327, 14328, 14329, 14330, 14331, 14332, 14333, 14334, 14335,
+    14336, 14337, 14338, 14339, 14340, 14341, 14342, 14343, 14344, 14345, 14346, 14347,
+    14348, 14349, 14350, 14351, 14352, 14353, 14354, 14355, 14356, 14357, 14358, 14359,
+    14360, 14361, 14362, 14363, 14364, 14365, 14366, 14367, 14368, 14369, 14370, 14371,
+    14372, 14373, 14374, 14375, 14376, 14377, 14378, 14379, 14380, 14381, 14382, 14383,
+    14384, 14385, 14386, 14387, 14388, 14389, 14390, 14391, 14392, 14393, 14394, 14395,
+    14396, 14397, 14398, 14399, 14400, 14401, 14402, 14403, 14404, 14405, 14406, 14407,
+    14408, 14409, 14410, 14411, 14412, 14413, 14414, 14415, 14416, 14417, 14418, 14419,
+    14420, 14421, 14422, 14423, 14424, 14425, 14426, 14427, 14428, 14429, 14430, 14431,
+    14432, 14433, 14434, 14435, 14436, 14437, 14438, 14439, 14440, 14441, 14442, 14443,
+    14444, 14445, 14446, 14447, 14448, 14449, 14450, 14451, 14452, 14453, 14454, 14455,
+    14456, 14457, 14458, 14459, 14460, 14461, 14462, 14463, 14464, 14465, 14466, 14467,
+    14468, 14469, 14470, 14471, 14472, 14473, 14474, 14475, 14476, 14477, 14478, 14479,
+    14480, 14481, 14482, 14483, 14484, 14485, 14486, 14487, 14488, 14489, 14490, 14491,
+    14492, 14493, 14494, 14495, 14496, 14497, 14498, 14499, 14500, 14501, 14502, 14503,
+    14504, 14505, 14506, 14507, 14508, 14509, 14510, 14511, 14512, 14513, 14514, 14515,
+    14516, 14517, 14518, 14519, 14520, 14521, 14522, 14523, 14524, 14525, 14526, 14527,
+    14528, 14529, 14530, 14531, 14532, 14533, 14534, 14535, 14536, 14537, 14538, 14539,
+    14540, 14541, 14542, 14543, 14544, 14545, 14546, 14547, 14548, 14549, 14550, 14551,
+    14552, 14553, 14554, 14555, 14556, 14557, 14558, 14559, 14560, 14561, 14562, 14563,
+    14564, 14565, 14566, 14567, 14568, 14569, 14570, 14571, 14572, 14573, 14574, 14575,
+    14576, 14577, 14578, 14579, 14580, 14581, 14582, 14583, 14584, 14585, 14586, 14587,
+    14588, 14589, 14590, 14591, 14592, 14593, 14594, 14595, 14596, 14597, 14598, 14599,
+    14600, 14601, 14602, 14603, 14604, 14605, 14606, 14607, 14608, 14609, 14610, 14611,
+    14612, 14613, 14614, 14615, 14616, 14617, 14618, 14619, 14620, 14621, 14622, 14623,
+    14624, 14625, 14626, 14627, 14628, 14629, 14630, 14631, 14632, 14633, 14634, 14635,
+    14636, 14637, 14638, 14639, 14640, 14641, 14642, 14643, 14644, 14645, 14646, 14647,
+    14648, 14649, 14650, 14651, 14652, 14653, 14654, 14655, 14656, 14657, 14658, 14659,
+    14660, 14661, 14662, 14663, 14664, 14665, 14666, 14667, 14668, 14669, 14670, 14671,
+    14672, 14673, 14674, 14675, 14676, 14677, 14678, 14679, 14680, 14681, 14682, 14683,
+    14684, 14685, 14686, 14687, 14688, 14689, 14690, 14691, 14692, 14693, 14694, 14695,
+    14696, 14697, 14698, 14699, 14700, 14701, 14702, 14703, 14704, 14705, 14706, 14707,
+    14708, 14709, 14710, 14711, 14712, 14713, 14714, 14715, 14716, 14717, 14718, 14719,
+    14720, 14721, 14722, 14723, 14724, 14725, 14726, 14727, 14728, 14729, 14730, 14731,
+    14732, 14733, 14734, 14735, 14736, 14737, 14738, 14739, 14740, 14741, 14742, 14743,
+    14744, 14745, 14746, 14747, 14748, 14749, 14750, 14751, 14752, 14753, 14754, 14755,
+    14756, 14757, 14758, 14759, 14760, 14761, 14762, 14763, 14764, 14765, 14766, 14767,
+    14768, 14769, 14770, 14771, 14772, 14773, 14774, 14775, 14776, 14777, 14778, 14779,
+    14780, 14781, 14782, 14783, 14784, 14785, 14786, 14787, 14788, 14789, 14790, 14791,
+    14792, 14793, 14794, 14795, 14796, 14797, 14798, 14799, 14800, 14801, 14802, 14803,
+    14804, 14805, 14806, 14807, 14808, 14809, 14810, 14811, 14812, 14813, 14814, 14815,
+    14816, 14817, 14818, 14819, 14820, 14821, 14822, 14823, 14824, 14825, 14826, 14827,
+    14828, 14829, 14830, 14831, 14833, 14835, 14837, 14839, 14841, 14843, 14845, 14847,
+    14849, 14851, 14853, 14855, 14857, 14859, 14861, 14863, 14865, 14867, 14869, 14871,
+    14873, 14875, 14877, 14879, 14881, 14883, 14885, 14888, 14890, 14892, 14894, 14896,
+    14898, 14900, 14902, 14904, 14906, 14908, 14910, 14912, 14914, 14916, 14918, 14920,
+    14922, 14924, 14926, 14928, 14930, 14932, 14934, 14936, 14938, 14941, 14943, 14945,
+    14947, 14949, 14951, 14953, 14955, 14957, 14959, 14961, 14963, 14965, 14967, 14969,
+    14971, 14973, 14975, 14977, 14979, 14981, 14983, 14985, 14987, 14989, 14991, 14993,
+    14995, 14997, 14999, 15001, 15003, 15006, 15008, 15010, 15012, 15014, 15016, 15018,
+    15020, 15022, 15024, 15026, 15028, 15030, 15032, 15034, 15036, 15038, 15040, 15042,
+    15044, 15046, 15048, 15050, 15052, 15054, 15056, 15059, 15061, 15063, 15065, 15067,
+    15069, 15071, 15073, 15075, 15077, 15079, 15081, 15083, 15085, 15087, 15089, 15091,
+    15093, 15095, 15097, 15099, 15101, 15103, 15105, 15107, 15109, 15111, 15113, 15115,
+    15117, 15119, 15121, 15124, 15126, 15128, 15130, 15132, 15134, 15136, 15138, 15140,
+    15142, 15144, 15146, 15148, 15150, 15152, 15154, 15156, 15158, 15160, 15162, 15164,
+    15166, 15168, 15170, 15172, 15174, 15177, 15179, 15181, 15183, 15185, 15187, 15189,
+    15191, 15193, 15195, 15197, 15199, 15201, 15203, 15205, 15207, 15209, 15211, 15213,
+    15215, 15217, 15219, 15221, 15223, 15225, 15227, 15229, 15231, 15233, 15235, 15237,
+    15239, 15242, 15244, 15246, 15248, 15250, 15252, 15254, 15256, 15258, 15260, 15262,
+    15264, 15266, 15268, 15270, 15272, 15274, 15276, 15278, 15280, 15282, 15284, 15286,
+    15288, 15290, 15292, 15295, 15297, 15299, 15301, 15303, 15305, 15307, 15309, 15311,
+    15313, 15315, 15317, 15319, 15321, 15323, 15325, 15327, 15329, 15331, 15333, 15335,
+    15337, 15339, 15341, 15343, 15345, 15347, 15349, 15351, 15353, 15355, 15357, 15360,
+    15362, 15364, 15366, 15368, 15370, 15372, 15374, 15376, 15378, 15380, 15382, 15384,
+    15386, 15388, 15390, 15392, 15394, 15396, 15398, 15400, 15402, 15404, 15406, 15408,
+    15410, 15413, 15415, 15417, 15419, 15421, 15423, 15425, 15427, 15429, 15430, 15431,
+    15432, 15433, 15434, 15435, 15436, 15437, 15438, 15439, 15440, 15441, 15442, 15443,
+    15444, 15445, 15446, 15447, 15448, 15449, 15450, 15451, 15452, 15453, 15454, 15455,
+    15456, 15457, 15458, 15459, 15460, 15461, 15462, 15463, 15464, 15465, 15466, 15467,
+    15468, 15469, 15470, 15471, 15472, 15473, 15474, 15475, 15476, 15477, 15478, 15479,
+    15481, 15483, 15485, 15487, 15489, 15491, 15493, 15495, 15497, 15499, 15501, 15503,
+    15505, 15507, 15509, 15511, 15513, 15515, 15517, 15519, 15521, 15523, 15525, 15527,
+    15529, 15531, 15534, 15536, 15538, 15540, 15542, 15544, 15546, 15548, 15550, 15552,
+    15554, 15556, 15558, 15560, 15562, 15564, 15566, 15568, 15570, 15572, 15574, 15576,
+    15578, 15580, 15582, 15584, 15586, 15588, 15590, 15592, 15594, 15596, 15598, 15600,
+    15603, 15605, 15609, 15613, 15617, 15621, 15625, 15629, 15633, 15637, 15641, 15645,
+    15649, 15653, 15657, 15661, 15665, 15669, 15673, 15677, 15681, 15685, 15689, 15693,
+    15697, 15701, 15705, 15709, 15713, 15717, 15721, 15725, 15729, 15733, 15737, 15741,
+    15743, 15745, 15747, 15749, 15751, 15753, 15755, 15757, 15759, 15761, 15763, 15765,
+    15767, 15769, 15771, 15773, 15775, 15777, 15779, 15781, 15783, 15785, 15787, 15789,
+    15791, 15793, 15795, 15797, 15799, 15801, 15803, 15805, 15807, 15809, 15811, 15813,
+    15815, 15817, 15819, 15821, 15823, 15825, 15827, 15829, 15831, 15833, 15835, 15837,
+    15839, 15841, 15843, 15845, 15847, 15849, 15851, 15853, 15855, 15857, 15859, 15861,
+    15863, 15865, 15867, 15869, 15871, 15873, 15875, 15877, 15879, 15881, 15883, 15885,
+    15887, 15889, 15891, 15893, 15895, 15897, 15899, 15901, 15903, 15905, 15907, 15909,
+    15911, 15913, 15915, 15917, 15919, 15921, 15923, 15925, 15927, 15929, 15931, 15933,
+    15935, 15937, 15939, 15941, 15943, 15945, 15947, 15949, 15951, 15953, 15955, 15957,
+    15959, 15961, 15963, 15965, 15967, 15969, 15971, 15973, 15975, 15977, 15979, 15981,
+    15983, 15985, 15987, 15989, 15991, 15993, 15995, 15997, 15999, 16001, 16003, 16005,
+    16007, 16009, 16011, 16013, 16015, 16017, 16019, 16021, 16023, 16025, 16027, 16029,
+    16031, 16033, 16035, 16037, 16039, 16041, 16043, 16045, 16048, 16051, 16054, 16057,
+    16060, 16063, 16066, 16069, 16072, 16075, 16078, 16081, 16084, 16087, 16090, 16093,
+    16096, 16099, 16102, 16105, 16108, 16111, 16114, 16117, 16120, 16123, 16130, 16131,
+    16132, 16134, 16136, 16137, 16138, 16139, 16140, 16141, 16142, 16143, 16144, 16145,
+    16146, 16147, 16148, 16149, 16150, 16151, 16152, 16153, 16154, 16155, 16156, 16157,
+    16158, 16159, 16160, 16161, 16162, 16164, 16166, 16168, 16170, 16173, 16175, 16177,
+    16179, 16181, 16183, 16189, 16195, 16198, 16201, 16204, 16207, 16210, 16213, 16216,
+    16219, 16222, 16225, 16228, 16231, 16234, 16237, 16240, 16243, 16246, 16249, 16252,
+    16255, 16258, 16261, 16264, 16267, 16270, 16273, 16276, 16279, 16282, 16285, 16288,
+    16291, 16294, 16297, 16300, 16303, 16306, 16309, 16312, 16315, 16318, 16321, 16324,
+    16327, 16330, 16339, 16348, 16357, 16366, 16375, 16384, 16393, 16402, 16411, 16414,
+    16417, 16418, 16419, 16420, 16421, 16422, 16423, 16424, 16425, 16426, 16427, 16430,
+    16433, 16436, 16440, 16443, 16446, 16449, 16452, 16455, 16458, 16461, 16464, 16467,
+    16471, 16474, 16477, 16480, 16483, 16487, 16490, 16493, 16496, 16500, 16503, 16506,
+    16509, 16512, 16515, 16519, 16522, 16525, 16528, 16531, 16534, 16537, 16540, 16543,
+    16546, 16549, 16552, 16555, 16558, 16561, 16564, 16567, 16570, 16573, 16576, 16579,
+    16582, 16585, 16588, 16592, 16595, 16598, 16601, 16605, 16608, 16611, 16614, 16617,
+    16620, 16623, 16626, 16629, 16632, 16635, 16638, 16641, 16644, 16647, 16650, 16653,
+    16656, 16659, 16662, 16665, 16668, 16671, 16674, 16677, 16680, 16683, 16686, 16689,
+    16692, 16695, 16698, 16701, 16705, 16708, 16711, 16714, 16717, 16720, 16723, 16727,
+    16731, 16734, 16737, 16740, 16743, 16746, 16749, 16752, 16755, 16758, 16761, 16765,
+    16768, 16771, 16774, 16777, 16781, 16784, 16787, 16790, 16793, 16796, 16799, 16802,
+    16805, 16808, 16812, 16815, 16819, 16822, 16825, 16828, 16831, 16834, 16837, 16840,
+    16843, 16846, 16849, 16852, 16856, 16859, 16862, 16865, 16868, 16871, 16875, 16878,
+    16882, 16886, 16889, 16892, 16895, 16898, 16902, 16906, 16909, 16912, 16915, 16918,
+    16921, 16924, 16927, 16930, 16933, 16936, 16939, 16943, 16946, 16949, 16952, 16955,
+    16958, 16961, 16964, 16967, 16970, 16973, 16976, 16979, 16982, 16985, 16988, 16991,
+    16994, 16997, 17000, 17004, 17007, 17010, 17013, 17016, 17019, 17023, 17026, 17029,
+    17032, 17035, 17038, 17041, 17044, 17047, 17050, 17053, 17056, 17060, 17063, 17066,
+    17069, 17072, 17075, 17078, 17081, 17084, 17087, 17090, 17093, 17096, 17099, 17102,
+    17105, 17108, 17111, 17114, 17118, 17121, 17124, 17127, 17130, 17133, 17137, 17140,
+    17143, 17146, 17149, 17152, 17155, 17158, 17161, 17165, 17168, 17171, 17174, 17178,
+    17181, 17184, 17187, 17190, 17193, 17196, 17200, 17204, 17208, 17211, 17215, 17218,
+    17221, 17224, 17227, 17230, 17233, 17236, 17239, 17242, 17245, 17249, 17252, 17255,
+    17258, 17261, 17264, 17267, 17271, 17274, 17277, 17281, 17285, 17288, 17291, 17294,
+    17297, 17300, 17303, 17306, 17309, 17312, 17316, 17319, 17323, 17326, 17330, 17333,
+    17336, 17339, 17343, 17346, 17349, 17353, 17357, 17360, 17363, 17366, 17369, 17372,
+    17375, 17378, 17381, 17384, 17387, 17390, 17393, 17396, 17400, 17403, 17407, 17410,
+    17414, 17417, 17421, 17425, 17429, 17432, 17435, 17438, 17442, 17446, 17450, 17454,
+    17457, 17460, 17463, 17466, 17469, 17472, 17475, 17478, 17482, 17485, 17488, 17491,
+    17494, 17498, 17501, 17505, 17509, 17512, 17515, 17518, 17521, 17524, 17527, 17531,
+    17535, 17539, 17542, 17545, 17549, 17552, 17555, 17558, 17562, 17565, 17568, 17571,
+    17574, 17577, 17581, 17584, 17587, 17590, 17593, 17596, 17599, 17603, 17607, 17610,
+    17614, 17617, 17621, 17624, 17627, 17630, 17634, 17638, 17641, 17645, 17648, 17652,
+    17655, 17658, 17661, 17664, 17667, 17670, 17674, 17678, 17682, 17686, 17689, 17692,
+    17695, 17698, 17701, 17704, 17707, 17710, 17713, 17716, 17719, 17722, 17726, 17729,
+    17732, 17735, 17738, 17741, 17744, 17747, 17750, 17753, 17756, 17759, 17762, 17766,
+    17770, 17774, 17777, 17780, 17783, 17786, 17790, 17793, 17797, 17800, 17803, 17807,
+    17811, 17814, 17817, 17820, 17823, 17826, 17829, 17832, 17835, 17838, 17841, 17844,
+    17847, 17850, 17853, 17856, 17859, 17862, 17865, 17868, 17872, 17875, 17878, 17881,
+    17884, 17887, 17891, 17895, 17898, 17901, 17904, 17907, 17910, 17913, 17917, 17920,
+    17923, 17926, 17929, 17933, 17937, 17940, 17943, 17946, 17950, 17953, 17956, 17960,
+    17964, 17967, 17970, 17973, 17977, 17980, 17983, 17986, 17989, 17992, 17995, 17998,
+    18002, 18005, 18008, 18011, 18015, 18018, 18021, 18024, 18027, 18031, 18035, 18038,
+    18041, 18044, 18048, 18051, 18055, 18058, 18061, 18064, 18068, 18071, 18074, 18077,
+    18080, 18083, 18086, 18089, 18093, 18096, 18099, 18102, 18105, 18108, 18111, 18115,
+    18118, 18122, 18126, 18130, 18133, 18136, 18139, 18142, 18145, 18148, 18151, 18154,
+    18158,
+];
+pub(crate) static NFKC_CASEFOLD_STRINGS: &str = "\
+    \u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\
+    \u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\
+    \u{79}\u{7A}\u{20}\u{20}\u{308}\u{61}\u{20}\u{304}\u{32}\u{33}\u{20}\u{301}\
+    \u{3BC}\u{20}\u{327}\u{31}\u{6F}\u{31}\u{2044}\u{34}\u{31}\u{2044}\u{32}\u{33}\
+    \u{2044}\u{34}\u{E0}\u{E1}\u{E2}\u{E3}\u{E4}\u{E5}\u{E6}\u{E7}\u{E8}\u{E9}\
+    \u{EA}\u{EB}\u{EC}\u{ED}\u{EE}\u{EF}\u{F0}\u{F1}\u{F2}\u{F3}\u{F4}\u{F5}\
+    \u{F6}\u{F8}\u{F9}\u{FA}\u{FB}\u{FC}\u{FD}\u{FE}\u{73}\u{73}\u{101}\u{103}\
+    \u{105}\u{107}\u{109}\u{10B}\u{10D}\u{10F}\u{111}\u{113}\u{115}\u{117}\u{119}\u{11B}\
+    \u{11D}\u{11F}\u{121}\u{123}\u{125}\u{127}\u{129}\u{12B}\u{12D}\u{12F}\u{69}\u{307}\
+    \u{69}\u{6A}\u{69}\u{6A}\u{135}\u{137}\u{13A}\u{13C}\u{13E}\u{6C}\u{B7}\u{6C}\
+    \u{B7}\u{142}\u{144}\u{146}\u{148}\u{2BC}\u{6E}\u{14B}\u{14D}\u{14F}\u{151}\u{153}\
+    \u{155}\u{157}\u{159}\u{15B}\u{15D}\u{15F}\u{161}\u{163}\u{165}\u{167}\u{169}\u{16B}\
+    \u{16D}\u{16F}\u{171}\u{173}\u{175}\u{177}\u{FF}\u{17A}\u{17C}\u{17E}\u{73}\u{253}\
+    \u{183}\u{185}\u{254}\u{188}\u{256}\u{257}\u{18C}\u{1DD}\u{259}\u{25B}\u{192}\u{260}\
+    \u{263}\u{269}\u{268}\u{199}\u{26F}\u{272}\u{275}\u{1A1}\u{1A3}\u{1A5}\u{280}\u{1A8}\
+    \u{283}\u{1AD}\u{288}\u{1B0}\u{28A}\u{28B}\u{1B4}\u{1B6}\u{292}\u{1B9}\u{1BD}\u{64}\
+    \u{17E}\u{64}\u{17E}\u{64}\u{17E}\u{6C}\u{6A}\u{6C}\u{6A}\u{6C}\u{6A}\u{6E}\
+    \u{6A}\u{6E}\u{6A}\u{6E}\u{6A}\u{1CE}\u{1D0}\u{1D2}\u{1D4}\u{1D6}\u{1D8}\u{1DA}\
+    \u{1DC}\u{1DF}\u{1E1}\u{1E3}\u{1E5}\u{1E7}\u{1E9}\u{1EB}\u{1ED}\u{1EF}\u{64}\u{7A}\
+    \u{64}\u{7A}\u{64}\u{7A}\u{1F5}\u{195}\u{1BF}\u{1F9}\u{1FB}\u{1FD}\u{1FF}\u{201}\
+    \u{203}\u{205}\u{207}\u{209}\u{20B}\u{20D}\u{20F}\u{211}\u{213}\u{215}\u{217}\u{219}\
+    \u{21B}\u{21D}\u{21F}\u{19E}\u{223}\u{225}\u{227}\u{229}\u{22B}\u{22D}\u{22F}\u{231}\
+    \u{233}\u{2C65}\u{23C}\u{19A}\u{2C66}\u{242}\u{180}\u{289}\u{28C}\u{247}\u{249}\u{24B}\
+    \u{24D}\u{24F}\u{68}\u{266}\u{6A}\u{72}\u{279}\u{27B}\u{281}\u{77}\u{79}\u{20}\
+    \u{306}\u{20}\u{307}\u{20}\u{30A}\u{20}\u{328}\u{20}\u{303}\u{20}\u{30B}\u{263}\
+    \u{6C}\u{73}\u{78}\u{295}\u{300}\u{301}\u{313}\u{308}\u{301}\u{3B9}\u{371}\u{373}\
+    \u{2B9}\u{377}\u{20}\u{3B9}\u{3B}\u{3F3}\u{20}\u{301}\u{20}\u{308}\u{301}\u{3AC}\
+    \u{B7}\u{3AD}\u{3AE}\u{3AF}\u{3CC}\u{3CD}\u{3CE}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\
+    \u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\
+    \u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{3CA}\u{3CB}\u{3C3}\u{3D7}\u{3B2}\
+    \u{3B8}\u{3C5}\u{3CD}\u{3CB}\u{3C6}\u{3C0}\u{3D9}\u{3DB}\u{3DD}\u{3DF}\u{3E1}\u{3E3}\
+    \u{3E5}\u{3E7}\u{3E9}\u{3EB}\u{3ED}\u{3EF}\u{3BA}\u{3C1}\u{3C3}\u{3B8}\u{3B5}\u{3F8}\
+    \u{3C3}\u{3FB}\u{37B}\u{37C}\u{37D}\u{450}\u{451}\u{452}\u{453}\u{454}\u{455}\u{456}\
+    \u{457}\u{458}\u{459}\u{45A}\u{45B}\u{45C}\u{45D}\u{45E}\u{45F}\u{430}\u{431}\u{432}\
+    \u{433}\u{434}\u{435}\u{436}\u{437}\u{438}\u{439}\u{43A}\u{43B}\u{43C}\u{43D}\u{43E}\
+    \u{43F}\u{440}\u{441}\u{442}\u{443}\u{444}\u{445}\u{446}\u{447}\u{448}\u{449}\u{44A}\
+    \u{44B}\u{44C}\u{44D}\u{44E}\u{44F}\u{461}\u{463}\u{465}\u{467}\u{469}\u{46B}\u{46D}\
+    \u{46F}\u{471}\u{473}\u{475}\u{477}\u{479}\u{47B}\u{47D}\u{47F}\u{481}\u{48B}\u{48D}\
+    \u{48F}\u{491}\u{493}\u{495}\u{497}\u{499}\u{49B}\u{49D}\u{49F}\u{4A1}\u{4A3}\u{4A5}\
+    \u{4A7}\u{4A9}\u{4AB}\u{4AD}\u{4AF}\u{4B1}\u{4B3}\u{4B5}\u{4B7}\u{4B9}\u{4BB}\u{4BD}\
+    \u{4BF}\u{4CF}\u{4C2}\u{4C4}\u{4C6}\u{4C8}\u{4CA}\u{4CC}\u{4CE}\u{4D1}\u{4D3}\u{4D5}\
+    \u{4D7}\u{4D9}\u{4DB}\u{4DD}\u{4DF}\u{4E1}\u{4E3}\u{4E5}\u{4E7}\u{4E9}\u{4EB}\u{4ED}\
+    \u{4EF}\u{4F1}\u{4F3}\u{4F5}\u{4F7}\u{4F9}\u{4FB}\u{4FD}\u{4FF}\u{501}\u{503}\u{505}\
+    \u{507}\u{509}\u{50B}\u{50D}\u{50F}\u{511}\u{513}\u{515}\u{517}\u{519}\u{51B}\u{51D}\
+    \u{51F}\u{521}\u{523}\u{525}\u{527}\u{529}\u{52B}\u{52D}\u{52F}\u{561}\u{562}\u{563}\
+    \u{564}\u{565}\u{566}\u{567}\u{568}\u{569}\u{56A}\u{56B}\u{56C}\u{56D}\u{56E}\u{56F}\
+    \u{570}\u{571}\u{572}\u{573}\u{574}\u{575}\u{576}\u{577}\u{578}\u{579}\u{57A}\u{57B}\
+    \u{57C}\u{57D}\u{57E}\u{57F}\u{580}\u{581}\u{582}\u{583}\u{584}\u{585}\u{586}\u{565}\
+    \u{582}\u{627}\u{674}\u{648}\u{674}\u{6C7}\u{674}\u{64A}\u{674}\u{915}\u{93C}\u{916}\
+    \u{93C}\u{917}\u{93C}\u{91C}\u{93C}\u{921}\u{93C}\u{922}\u{93C}\u{92B}\u{93C}\u{92F}\
+    \u{93C}\u{9A1}\u{9BC}\u{9A2}\u{9BC}\u{9AF}\u{9BC}\u{A32}\u{A3C}\u{A38}\u{A3C}\u{A16}\
+    \u{A3C}\u{A17}\u{A3C}\u{A1C}\u{A3C}\u{A2B}\u{A3C}\u{B21}\u{B3C}\u{B22}\u{B3C}\u{E4D}\
+    \u{E32}\u{ECD}\u{EB2}\u{EAB}\u{E99}\u{EAB}\u{EA1}\u{F0B}\u{F42}\u{FB7}\u{F4C}\u{FB7}\
+    \u{F51}\u{FB7}\u{F56}\u{FB7}\u{F5B}\u{FB7}\u{F40}\u{FB5}\u{F71}\u{F72}\u{F71}\u{F74}\
+    \u{FB2}\u{F80}\u{FB2}\u{F71}\u{F80}\u{FB3}\u{F80}\u{FB3}\u{F71}\u{F80}\u{F71}\u{F80}\
+    \u{F92}\u{FB7}\u{F9C}\u{FB7}\u{FA1}\u{FB7}\u{FA6}\u{FB7}\u{FAB}\u{FB7}\u{F90}\u{FB5}\
+    \u{2D00}\u{2D01}\u{2D02}\u{2D03}\u{2D04}\u{2D05}\u{2D06}\u{2D07}\u{2D08}\u{2D09}\u{2D0A}\u{2D0B}\
+    \u{2D0C}\u{2D0D}\u{2D0E}\u{2D0F}\u{2D10}\u{2D11}\u{2D12}\u{2D13}\u{2D14}\u{2D15}\u{2D16}\u{2D17}\
+    \u{2D18}\u{2D19}\u{2D1A}\u{2D1B}\u{2D1C}\u{2D1D}\u{2D1E}\u{2D1F}\u{2D20}\u{2D21}\u{2D22}\u{2D23}\
+    \u{2D24}\u{2D25}\u{2D27}\u{2D2D}\u{10DC}\u{13F0}\u{13F1}\u{13F2}\u{13F3}\u{13F4}\u{13F5}\u{432}\
+    \u{434}\u{43E}\u{441}\u{442}\u{442}\u{44A}\u{463}\u{A64B}\u{1C8A}\u{10D0}\u{10D1}\u{10D2}\
+    \u{10D3}\u{10D4}\u{10D5}\u{10D6}\u{10D7}\u{10D8}\u{10D9}\u{10DA}\u{10DB}\u{10DC}\u{10DD}\u{10DE}\
+    \u{10DF}\u{10E0}\u{10E1}\u{10E2}\u{10E3}\u{10E4}\u{10E5}\u{10E6}\u{10E7}\u{10E8}\u{10E9}\u{10EA}\
+    \u{10EB}\u{10EC}\u{10ED}\u{10EE}\u{10EF}\u{10F0}\u{10F1}\u{10F2}\u{10F3}\u{10F4}\u{10F5}\u{10F6}\
+    \u{10F7}\u{10F8}\u{10F9}\u{10FA}\u{10FD}\u{10FE}\u{10FF}\u{61}\u{E6}\u{62}\u{64}\u{65}\
+    \u{1DD}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{223}\u{70}\
+    \u{72}\u{74}\u{75}\u{77}\u{61}\u{250}\u{251}\u{1D02}\u{62}\u{64}\u{65}\u{259}\
+    \u{25B}\u{25C}\u{67}\u{6B}\u{6D}\u{14B}\u{6F}\u{254}\u{1D16}\u{1D17}\u{70}\u{74}\
+    \u{75}\u{1D1D}\u{26F}\u{76}\u{1D25}\u{3B2}\u{3B3}\u{3B4}\u{3C6}\u{3C7}\u{69}\u{72}\
+    \u{75}\u{76}\u{3B2}\u{3B3}\u{3C1}\u{3C6}\u{3C7}\u{43D}\u{252}\u{63}\u{255}\u{F0}\
+    \u{25C}\u{66}\u{25F}\u{261}\u{265}\u{268}\u{269}\u{26A}\u{1D7B}\u{29D}\u{26D}\u{1D85}\
+    \u{29F}\u{271}\u{270}\u{272}\u{273}\u{274}\u{275}\u{278}\u{282}\u{283}\u{1AB}\u{289}\
+    \u{28A}\u{1D1C}\u{28B}\u{28C}\u{7A}\u{290}\u{291}\u{292}\u{3B8}\u{1E01}\u{1E03}\u{1E05}\
+    \u{1E07}\u{1E09}\u{1E0B}\u{1E0D}\u{1E0F}\u{1E11}\u{1E13}\u{1E15}\u{1E17}\u{1E19}\u{1E1B}\u{1E1D}\
+    \u{1E1F}\u{1E21}\u{1E23}\u{1E25}\u{1E27}\u{1E29}\u{1E2B}\u{1E2D}\u{1E2F}\u{1E31}\u{1E33}\u{1E35}\
+    \u{1E37}\u{1E39}\u{1E3B}\u{1E3D}\u{1E3F}\u{1E41}\u{1E43}\u{1E45}\u{1E47}\u{1E49}\u{1E4B}\u{1E4D}\
+    \u{1E4F}\u{1E51}\u{1E53}\u{1E55}\u{1E57}\u{1E59}\u{1E5B}\u{1E5D}\u{1E5F}\u{1E61}\u{1E63}\u{1E65}\
+    \u{1E67}\u{1E69}\u{1E6B}\u{1E6D}\u{1E6F}\u{1E71}\u{1E73}\u{1E75}\u{1E77}\u{1E79}\u{1E7B}\u{1E7D}\
+    \u{1E7F}\u{1E81}\u{1E83}\u{1E85}\u{1E87}\u{1E89}\u{1E8B}\u{1E8D}\u{1E8F}\u{1E91}\u{1E93}\u{1E95}\
+    \u{61}\u{2BE}\u{1E61}\u{73}\u{73}\u{1EA1}\u{1EA3}\u{1EA5}\u{1EA7}\u{1EA9}\u{1EAB}\u{1EAD}\
+    \u{1EAF}\u{1EB1}\u{1EB3}\u{1EB5}\u{1EB7}\u{1EB9}\u{1EBB}\u{1EBD}\u{1EBF}\u{1EC1}\u{1EC3}\u{1EC5}\
+    \u{1EC7}\u{1EC9}\u{1ECB}\u{1ECD}\u{1ECF}\u{1ED1}\u{1ED3}\u{1ED5}\u{1ED7}\u{1ED9}\u{1EDB}\u{1EDD}\
+    \u{1EDF}\u{1EE1}\u{1EE3}\u{1EE5}\u{1EE7}\u{1EE9}\u{1EEB}\u{1EED}\u{1EEF}\u{1EF1}\u{1EF3}\u{1EF5}\
+    \u{1EF7}\u{1EF9}\u{1EFB}\u{1EFD}\u{1EFF}\u{1F00}\u{1F01}\u{1F02}\u{1F03}\u{1F04}\u{1F05}\u{1F06}\
+    \u{1F07}\u{1F10}\u{1F11}\u{1F12}\u{1F13}\u{1F14}\u{1F15}\u{1F20}\u{1F21}\u{1F22}\u{1F23}\u{1F24}\
+    \u{1F25}\u{1F26}\u{1F27}\u{1F30}\u{1F31}\u{1F32}\u{1F33}\u{1F34}\u{1F35}\u{1F36}\u{1F37}\u{1F40}\
+    \u{1F41}\u{1F42}\u{1F43}\u{1F44}\u{1F45}\u{1F51}\u{1F53}\u{1F55}\u{1F57}\u{1F60}\u{1F61}\u{1F62}\
+    \u{1F63}\u{1F64}\u{1F65}\u{1F66}\u{1F67}\u{3AC}\u{3AD}\u{3AE}\u{3AF}\u{3CC}\u{3CD}\u{3CE}\
+    \u{1F00}\u{3B9}\u{1F01}\u{3B9}\u{1F02}\u{3B9}\u{1F03}\u{3B9}\u{1F04}\u{3B9}\u{1F05}\u{3B9}\
+    \u{1F06}\u{3B9}\u{1F07}\u{3B9}\u{1F00}\u{3B9}\u{1F01}\u{3B9}\u{1F02}\u{3B9}\u{1F03}\u{3B9}\
+    \u{1F04}\u{3B9}\u{1F05}\u{3B9}\u{1F06}\u{3B9}\u{1F07}\u{3B9}\u{1F20}\u{3B9}\u{1F21}\u{3B9}\
+    \u{1F22}\u{3B9}\u{1F23}\u{3B9}\u{1F24}\u{3B9}\u{1F25}\u{3B9}\u{1F26}\u{3B9}\u{1F27}\u{3B9}\
+    \u{1F20}\u{3B9}\u{1F21}\u{3B9}\u{1F22}\u{3B9}\u{1F23}\u{3B9}\u{1F24}\u{3B9}\u{1F25}\u{3B9}\
+    \u{1F26}\u{3B9}\u{1F27}\u{3B9}\u{1F60}\u{3B9}\u{1F61}\u{3B9}\u{1F62}\u{3B9}\u{1F63}\u{3B9}\
+    \u{1F64}\u{3B9}\u{1F65}\u{3B9}\u{1F66}\u{3B9}\u{1F67}\u{3B9}\u{1F60}\u{3B9}\u{1F61}\u{3B9}\
+    \u{1F62}\u{3B9}\u{1F63}\u{3B9}\u{1F64}\u{3B9}\u{1F65}\u{3B9}\u{1F66}\u{3B9}\u{1F67}\u{3B9}\
+    \u{1F70}\u{3B9}\u{3B1}\u{3B9}\u{3AC}\u{3B9}\u{1FB6}\u{3B9}\u{1FB0}\u{1FB1}\u{1F70}\u{3AC}\
+    \u{3B1}\u{3B9}\u{20}\u{313}\u{3B9}\u{20}\u{313}\u{20}\u{342}\u{20}\u{308}\u{342}\
+    \u{1F74}\u{3B9}\u{3B7}\u{3B9}\u{3AE}\u{3B9}\u{1FC6}\u{3B9}\u{1F72}\u{3AD}\u{1F74}\u{3AE}\
+    \u{3B7}\u{3B9}\u{20}\u{313}\u{300}\u{20}\u{313}\u{301}\u{20}\u{313}\u{342}\u{390}\
+    \u{1FD0}\u{1FD1}\u{1F76}\u{3AF}\u{20}\u{314}\u{300}\u{20}\u{314}\u{301}\u{20}\u{314}\
+    \u{342}\u{3B0}\u{1FE0}\u{1FE1}\u{1F7A}\u{3CD}\u{1FE5}\u{20}\u{308}\u{300}\u{20}\u{308}\
+    \u{301}\u{60}\u{1F7C}\u{3B9}\u{3C9}\u{3B9}\u{3CE}\u{3B9}\u{1FF6}\u{3B9}\u{1F78}\u{3CC}\
+    \u{1F7C}\u{3CE}\u{3C9}\u{3B9}\u{20}\u{301}\u{20}\u{314}\u{20}\u{20}\u{20}\u{20}\
+    \u{20}\u{20}\u{20}\u{20}\u{20}\u{20}\u{20}\u{2010}\u{20}\u{333}\u{2E}\u{2E}\
+    \u{2E}\u{2E}\u{2E}\u{2E}\u{20}\u{2032}\u{2032}\u{2032}\u{2032}\u{2032}\u{2035}\u{2035}\
+    \u{2035}\u{2035}\u{2035}\u{21}\u{21}\u{20}\u{305}\u{3F}\u{3F}\u{3F}\u{21}\u{21}\
+    \u{3F}\u{2032}\u{2032}\u{2032}\u{2032}\u{20}\u{30}\u{69}\u{34}\u{35}\u{36}\u{37}\
+    \u{38}\u{39}\u{2B}\u{2212}\u{3D}\u{28}\u{29}\u{6E}\u{30}\u{31}\u{32}\u{33}\
+    \u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\u{2B}\u{2212}\u{3D}\u{28}\u{29}\u{61}\
+    \u{65}\u{6F}\u{78}\u{259}\u{68}\u{6B}\u{6C}\u{6D}\u{6E}\u{70}\u{73}\u{74}\
+    \u{72}\u{73}\u{61}\u{2F}\u{63}\u{61}\u{2F}\u{73}\u{63}\u{B0}\u{63}\u{63}\
+    \u{2F}\u{6F}\u{63}\u{2F}\u{75}\u{25B}\u{B0}\u{66}\u{67}\u{68}\u{68}\u{68}\
+    \u{68}\u{127}\u{69}\u{69}\u{6C}\u{6C}\u{6E}\u{6E}\u{6F}\u{70}\u{71}\u{72}\
+    \u{72}\u{72}\u{73}\u{6D}\u{74}\u{65}\u{6C}\u{74}\u{6D}\u{7A}\u{3C9}\u{7A}\
+    \u{6B}\u{E5}\u{62}\u{63}\u{65}\u{65}\u{66}\u{214E}\u{6D}\u{6F}\u{5D0}\u{5D1}\
+    \u{5D2}\u{5D3}\u{69}\u{66}\u{61}\u{78}\u{3C0}\u{3B3}\u{3B3}\u{3C0}\u{2211}\u{64}\
+    \u{64}\u{65}\u{69}\u{6A}\u{31}\u{2044}\u{37}\u{31}\u{2044}\u{39}\u{31}\u{2044}\
+    \u{31}\u{30}\u{31}\u{2044}\u{33}\u{32}\u{2044}\u{33}\u{31}\u{2044}\u{35}\u{32}\
+    \u{2044}\u{35}\u{33}\u{2044}\u{35}\u{34}\u{2044}\u{35}\u{31}\u{2044}\u{36}\u{35}\
+    \u{2044}\u{36}\u{31}\u{2044}\u{38}\u{33}\u{2044}\u{38}\u{35}\u{2044}\u{38}\u{37}\
+    \u{2044}\u{38}\u{31}\u{2044}\u{69}\u{69}\u{69}\u{69}\u{69}\u{69}\u{69}\u{76}\
+    \u{76}\u{76}\u{69}\u{76}\u{69}\u{69}\u{76}\u{69}\u{69}\u{69}\u{69}\u{78}\
+    \u{78}\u{78}\u{69}\u{78}\u{69}\u{69}\u{6C}\u{63}\u{64}\u{6D}\u{69}\u{69}\
+    \u{69}\u{69}\u{69}\u{69}\u{69}\u{76}\u{76}\u{76}\u{69}\u{76}\u{69}\u{69}\
+    \u{76}\u{69}\u{69}\u{69}\u{69}\u{78}\u{78}\u{78}\u{69}\u{78}\u{69}\u{69}\
+    \u{6C}\u{63}\u{64}\u{6D}\u{2184}\u{30}\u{2044}\u{33}\u{222B}\u{222B}\u{222B}\u{222B}\
+    \u{222B}\u{222E}\u{222E}\u{222E}\u{222E}\u{222E}\u{3008}\u{3009}\u{31}\u{32}\u{33}\u{34}\
+    \u{35}\u{36}\u{37}\u{38}\u{39}\u{31}\u{30}\u{31}\u{31}\u{31}\u{32}\u{31}\
+    \u{33}\u{31}\u{34}\u{31}\u{35}\u{31}\u{36}\u{31}\u{37}\u{31}\u{38}\u{31}\
+    \u{39}\u{32}\u{30}\u{28}\u{31}\u{29}\u{28}\u{32}\u{29}\u{28}\u{33}\u{29}\
+    \u{28}\u{34}\u{29}\u{28}\u{35}\u{29}\u{28}\u{36}\u{29}\u{28}\u{37}\u{29}\
+    \u{28}\u{38}\u{29}\u{28}\u{39}\u{29}\u{28}\u{31}\u{30}\u{29}\u{28}\u{31}\
+    \u{31}\u{29}\u{28}\u{31}\u{32}\u{29}\u{28}\u{31}\u{33}\u{29}\u{28}\u{31}\
+    \u{34}\u{29}\u{28}\u{31}\u{35}\u{29}\u{28}\u{31}\u{36}\u{29}\u{28}\u{31}\
+    \u{37}\u{29}\u{28}\u{31}\u{38}\u{29}\u{28}\u{31}\u{39}\u{29}\u{28}\u{32}\
+    \u{30}\u{29}\u{31}\u{2E}\u{32}\u{2E}\u{33}\u{2E}\u{34}\u{2E}\u{35}\u{2E}\
+    \u{36}\u{2E}\u{37}\u{2E}\u{38}\u{2E}\u{39}\u{2E}\u{31}\u{30}\u{2E}\u{31}\
+    \u{31}\u{2E}\u{31}\u{32}\u{2E}\u{31}\u{33}\u{2E}\u{31}\u{34}\u{2E}\u{31}\
+    \u{35}\u{2E}\u{31}\u{36}\u{2E}\u{31}\u{37}\u{2E}\u{31}\u{38}\u{2E}\u{31}\
+    \u{39}\u{2E}\u{32}\u{30}\u{2E}\u{28}\u{61}\u{29}\u{28}\u{62}\u{29}\u{28}\
+    \u{63}\u{29}\u{28}\u{64}\u{29}\u{28}\u{65}\u{29}\u{28}\u{66}\u{29}\u{28}\
+    \u{67}\u{29}\u{28}\u{68}\u{29}\u{28}\u{69}\u{29}\u{28}\u{6A}\u{29}\u{28}\
+    \u{6B}\u{29}\u{28}\u{6C}\u{29}\u{28}\u{6D}\u{29}\u{28}\u{6E}\u{29}\u{28}\
+    \u{6F}\u{29}\u{28}\u{70}\u{29}\u{28}\u{71}\u{29}\u{28}\u{72}\u{29}\u{28}\
+    \u{73}\u{29}\u{28}\u{74}\u{29}\u{28}\u{75}\u{29}\u{28}\u{76}\u{29}\u{28}\
+    \u{77}\u{29}\u{28}\u{78}\u{29}\u{28}\u{79}\u{29}\u{28}\u{7A}\u{29}\u{61}\
+    \u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\
+    \u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\
+    \u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\
+    \u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\
+    \u{78}\u{79}\u{7A}\u{30}\u{222B}\u{222B}\u{222B}\u{222B}\u{3A}\u{3A}\u{3D}\u{3D}\
+    \u{3D}\u{3D}\u{3D}\u{3D}\u{2ADD}\u{338}\u{2C30}\u{2C31}\u{2C32}\u{2C33}\u{2C34}\u{2C35}\
+    \u{2C36}\u{2C37}\u{2C38}\u{2C39}\u{2C3A}\u{2C3B}\u{2C3C}\u{2C3D}\u{2C3E}\u{2C3F}\u{2C40}\u{2C41}\
+    \u{2C42}\u{2C43}\u{2C44}\u{2C45}\u{2C46}\u{2C47}\u{2C48}\u{2C49}\u{2C4A}\u{2C4B}\u{2C4C}\u{2C4D}\
+    \u{2C4E}\u{2C4F}\u{2C50}\u{2C51}\u{2C52}\u{2C53}\u{2C54}\u{2C55}\u{2C56}\u{2C57}\u{2C58}\u{2C59}\
+    \u{2C5A}\u{2C5B}\u{2C5C}\u{2C5D}\u{2C5E}\u{2C5F}\u{2C61}\u{26B}\u{1D7D}\u{27D}\u{2C68}\u{2C6A}\
+    \u{2C6C}\u{251}\u{271}\u{250}\u{252}\u{2C73}\u{2C76}\u{6A}\u{76}\u{23F}\u{240}\u{2C81}\
+    \u{2C83}\u{2C85}\u{2C87}\u{2C89}\u{2C8B}\u{2C8D}\u{2C8F}\u{2C91}\u{2C93}\u{2C95}\u{2C97}\u{2C99}\
+    \u{2C9B}\u{2C9D}\u{2C9F}\u{2CA1}\u{2CA3}\u{2CA5}\u{2CA7}\u{2CA9}\u{2CAB}\u{2CAD}\u{2CAF}\u{2CB1}\
+    \u{2CB3}\u{2CB5}\u{2CB7}\u{2CB9}\u{2CBB}\u{2CBD}\u{2CBF}\u{2CC1}\u{2CC3}\u{2CC5}\u{2CC7}\u{2CC9}\
+    \u{2CCB}\u{2CCD}\u{2CCF}\u{2CD1}\u{2CD3}\u{2CD5}\u{2CD7}\u{2CD9}\u{2CDB}\u{2CDD}\u{2CDF}\u{2CE1}\
+    \u{2CE3}\u{2CEC}\u{2CEE}\u{2CF3}\u{2D61}\u{6BCD}\u{9F9F}\u{4E00}\u{4E28}\u{4E36}\u{4E3F}\u{4E59}\
+    \u{4E85}\u{4E8C}\u{4EA0}\u{4EBA}\u{513F}\u{5165}\u{516B}\u{5182}\u{5196}\u{51AB}\u{51E0}\u{51F5}\
+    \u{5200}\u{529B}\u{52F9}\u{5315}\u{531A}\u{5338}\u{5341}\u{535C}\u{5369}\u{5382}\u{53B6}\u{53C8}\
+    \u{53E3}\u{56D7}\u{571F}\u{58EB}\u{5902}\u{590A}\u{5915}\u{5927}\u{5973}\u{5B50}\u{5B80}\u{5BF8}\
+    \u{5C0F}\u{5C22}\u{5C38}\u{5C6E}\u{5C71}\u{5DDB}\u{5DE5}\u{5DF1}\u{5DFE}\u{5E72}\u{5E7A}\u{5E7F}\
+    \u{5EF4}\u{5EFE}\u{5F0B}\u{5F13}\u{5F50}\u{5F61}\u{5F73}\u{5FC3}\u{6208}\u{6236}\u{624B}\u{652F}\
+    \u{6534}\u{6587}\u{6597}\u{65A4}\u{65B9}\u{65E0}\u{65E5}\u{66F0}\u{6708}\u{6728}\u{6B20}\u{6B62}\
+    \u{6B79}\u{6BB3}\u{6BCB}\u{6BD4}\u{6BDB}\u{6C0F}\u{6C14}\u{6C34}\u{706B}\u{722A}\u{7236}\u{723B}\
+    \u{723F}\u{7247}\u{7259}\u{725B}\u{72AC}\u{7384}\u{7389}\u{74DC}\u{74E6}\u{7518}\u{751F}\u{7528}\
+    \u{7530}\u{758B}\u{7592}\u{7676}\u{767D}\u{76AE}\u{76BF}\u{76EE}\u{77DB}\u{77E2}\u{77F3}\u{793A}\
+    \u{79B8}\u{79BE}\u{7A74}\u{7ACB}\u{7AF9}\u{7C73}\u{7CF8}\u{7F36}\u{7F51}\u{7F8A}\u{7FBD}\u{8001}\
+    \u{800C}\u{8012}\u{8033}\u{807F}\u{8089}\u{81E3}\u{81EA}\u{81F3}\u{81FC}\u{820C}\u{821B}\u{821F}\
+    \u{826E}\u{8272}\u{8278}\u{864D}\u{866B}\u{8840}\u{884C}\u{8863}\u{897E}\u{898B}\u{89D2}\u{8A00}\
+    \u{8C37}\u{8C46}\u{8C55}\u{8C78}\u{8C9D}\u{8D64}\u{8D70}\u{8DB3}\u{8EAB}\u{8ECA}\u{8F9B}\u{8FB0}\
+    \u{8FB5}\u{9091}\u{9149}\u{91C6}\u{91CC}\u{91D1}\u{9577}\u{9580}\u{961C}\u{96B6}\u{96B9}\u{96E8}\
+    \u{9751}\u{975E}\u{9762}\u{9769}\u{97CB}\u{97ED}\u{97F3}\u{9801}\u{98A8}\u{98DB}\u{98DF}\u{9996}\
+    \u{9999}\u{99AC}\u{9AA8}\u{9AD8}\u{9ADF}\u{9B25}\u{9B2F}\u{9B32}\u{9B3C}\u{9B5A}\u{9CE5}\u{9E75}\
+    \u{9E7F}\u{9EA5}\u{9EBB}\u{9EC3}\u{9ECD}\u{9ED1}\u{9EF9}\u{9EFD}\u{9F0E}\u{9F13}\u{9F20}\u{9F3B}\
+    \u{9F4A}\u{9F52}\u{9F8D}\u{9F9C}\u{9FA0}\u{20}\u{3012}\u{5341}\u{5344}\u{5345}\u{20}\u{3099}\
+    \u{20}\u{309A}\u{3088}\u{308A}\u{30B3}\u{30C8}\u{1100}\u{1101}\u{11AA}\u{1102}\u{11AC}\u{11AD}\
+    \u{1103}\u{1104}\u{1105}\u{11B0}\u{11B1}\u{11B2}\u{11B3}\u{11B4}\u{11B5}\u{111A}\u{1106}\u{1107}\
+    \u{1108}\u{1121}\u{1109}\u{110A}\u{110B}\u{110C}\u{110D}\u{110E}\u{110F}\u{1110}\u{1111}\u{1112}\
+    \u{1161}\u{1162}\u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116A}\u{116B}\u{116C}\
+    \u{116D}\u{116E}\u{116F}\u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}\u{1114}\u{1115}\u{11C7}\
+    \u{11C8}\u{11CC}\u{11CE}\u{11D3}\u{11D7}\u{11D9}\u{111C}\u{11DD}\u{11DF}\u{111D}\u{111E}\u{1120}\
+    \u{1122}\u{1123}\u{1127}\u{1129}\u{112B}\u{112C}\u{112D}\u{112E}\u{112F}\u{1132}\u{1136}\u{1140}\
+    \u{1147}\u{114C}\u{11F1}\u{11F2}\u{1157}\u{1158}\u{1159}\u{1184}\u{1185}\u{1188}\u{1191}\u{1192}\
+    \u{1194}\u{119E}\u{11A1}\u{4E00}\u{4E8C}\u{4E09}\u{56DB}\u{4E0A}\u{4E2D}\u{4E0B}\u{7532}\u{4E59}\
+    \u{4E19}\u{4E01}\u{5929}\u{5730}\u{4EBA}\u{28}\u{1100}\u{29}\u{28}\u{1102}\u{29}\u{28}\
+    \u{1103}\u{29}\u{28}\u{1105}\u{29}\u{28}\u{1106}\u{29}\u{28}\u{1107}\u{29}\u{28}\
+    \u{1109}\u{29}\u{28}\u{110B}\u{29}\u{28}\u{110C}\u{29}\u{28}\u{110E}\u{29}\u{28}\
+    \u{110F}\u{29}\u{28}\u{1110}\u{29}\u{28}\u{1111}\u{29}\u{28}\u{1112}\u{29}\u{28}\
+    \u{AC00}\u{29}\u{28}\u{B098}\u{29}\u{28}\u{B2E4}\u{29}\u{28}\u{B77C}\u{29}\u{28}\
+    \u{B9C8}\u{29}\u{28}\u{BC14}\u{29}\u{28}\u{C0AC}\u{29}\u{28}\u{C544}\u{29}\u{28}\
+    \u{C790}\u{29}\u{28}\u{CC28}\u{29}\u{28}\u{CE74}\u{29}\u{28}\u{D0C0}\u{29}\u{28}\
+    \u{D30C}\u{29}\u{28}\u{D558}\u{29}\u{28}\u{C8FC}\u{29}\u{28}\u{C624}\u{C804}\u{29}\
+    \u{28}\u{C624}\u{D6C4}\u{29}\u{28}\u{4E00}\u{29}\u{28}\u{4E8C}\u{29}\u{28}\u{4E09}\
+    \u{29}\u{28}\u{56DB}\u{29}\u{28}\u{4E94}\u{29}\u{28}\u{516D}\u{29}\u{28}\u{4E03}\
+    \u{29}\u{28}\u{516B}\u{29}\u{28}\u{4E5D}\u{29}\u{28}\u{5341}\u{29}\u{28}\u{6708}\
+    \u{29}\u{28}\u{706B}\u{29}\u{28}\u{6C34}\u{29}\u{28}\u{6728}\u{29}\u{28}\u{91D1}\
+    \u{29}\u{28}\u{571F}\u{29}\u{28}\u{65E5}\u{29}\u{28}\u{682A}\u{29}\u{28}\u{6709}\
+    \u{29}\u{28}\u{793E}\u{29}\u{28}\u{540D}\u{29}\u{28}\u{7279}\u{29}\u{28}\u{8CA1}\
+    \u{29}\u{28}\u{795D}\u{29}\u{28}\u{52B4}\u{29}\u{28}\u{4EE3}\u{29}\u{28}\u{547C}\
+    \u{29}\u{28}\u{5B66}\u{29}\u{28}\u{76E3}\u{29}\u{28}\u{4F01}\u{29}\u{28}\u{8CC7}\
+    \u{29}\u{28}\u{5354}\u{29}\u{28}\u{796D}\u{29}\u{28}\u{4F11}\u{29}\u{28}\u{81EA}\
+    \u{29}\u{28}\u{81F3}\u{29}\u{554F}\u{5E7C}\u{6587}\u{7B8F}\u{70}\u{74}\u{65}\u{32}\
+    \u{31}\u{32}\u{32}\u{32}\u{33}\u{32}\u{34}\u{32}\u{35}\u{32}\u{36}\u{32}\
+    \u{37}\u{32}\u{38}\u{32}\u{39}\u{33}\u{30}\u{33}\u{31}\u{33}\u{32}\u{33}\
+    \u{33}\u{33}\u{34}\u{33}\u{35}\u{1100}\u{1102}\u{1103}\u{1105}\u{1106}\u{1107}\u{1109}\
+    \u{110B}\u{110C}\u{110E}\u{110F}\u{1110}\u{1111}\u{1112}\u{AC00}\u{B098}\u{B2E4}\u{B77C}\u{B9C8}\
+    \u{BC14}\u{C0AC}\u{C544}\u{C790}\u{CC28}\u{CE74}\u{D0C0}\u{D30C}\u{D558}\u{CC38}\u{ACE0}\u{C8FC}\
+    \u{C758}\u{C6B0}\u{4E00}\u{4E8C}\u{4E09}\u{56DB}\u{4E94}\u{516D}\u{4E03}\u{516B}\u{4E5D}\u{5341}\
+    \u{6708}\u{706B}\u{6C34}\u{6728}\u{91D1}\u{571F}\u{65E5}\u{682A}\u{6709}\u{793E}\u{540D}\u{7279}\
+    \u{8CA1}\u{795D}\u{52B4}\u{79D8}\u{7537}\u{5973}\u{9069}\u{512A}\u{5370}\u{6CE8}\u{9805}\u{4F11}\
+    \u{5199}\u{6B63}\u{4E0A}\u{4E2D}\u{4E0B}\u{5DE6}\u{53F3}\u{533B}\u{5B97}\u{5B66}\u{76E3}\u{4F01}\
+    \u{8CC7}\u{5354}\u{591C}\u{33}\u{36}\u{33}\u{37}\u{33}\u{38}\u{33}\u{39}\u{34}\
+    \u{30}\u{34}\u{31}\u{34}\u{32}\u{34}\u{33}\u{34}\u{34}\u{34}\u{35}\u{34}\
+    \u{36}\u{34}\u{37}\u{34}\u{38}\u{34}\u{39}\u{35}\u{30}\u{31}\u{6708}\u{32}\
+    \u{6708}\u{33}\u{6708}\u{34}\u{6708}\u{35}\u{6708}\u{36}\u{6708}\u{37}\u{6708}\u{38}\
+    \u{6708}\u{39}\u{6708}\u{31}\u{30}\u{6708}\u{31}\u{31}\u{6708}\u{31}\u{32}\u{6708}\
+    \u{68}\u{67}\u{65}\u{72}\u{67}\u{65}\u{76}\u{6C}\u{74}\u{64}\u{30A2}\u{30A4}\
+    \u{30A6}\u{30A8}\u{30AA}\u{30AB}\u{30AD}\u{30AF}\u{30B1}\u{30B3}\u{30B5}\u{30B7}\u{30B9}\u{30BB}\
+    \u{30BD}\u{30BF}\u{30C1}\u{30C4}\u{30C6}\u{30C8}\u{30CA}\u{30CB}\u{30CC}\u{30CD}\u{30CE}\u{30CF}\
+    \u{30D2}\u{30D5}\u{30D8}\u{30DB}\u{30DE}\u{30DF}\u{30E0}\u{30E1}\u{30E2}\u{30E4}\u{30E6}\u{30E8}\
+    \u{30E9}\u{30EA}\u{30EB}\u{30EC}\u{30ED}\u{30EF}\u{30F0}\u{30F1}\u{30F2}\u{4EE4}\u{548C}\u{30A2}\
+    \u{30D1}\u{30FC}\u{30C8}\u{30A2}\u{30EB}\u{30D5}\u{30A1}\u{30A2}\u{30F3}\u{30DA}\u{30A2}\u{30A2}\
+    \u{30FC}\u{30EB}\u{30A4}\u{30CB}\u{30F3}\u{30B0}\u{30A4}\u{30F3}\u{30C1}\u{30A6}\u{30A9}\u{30F3}\
+    \u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C9}\u{30A8}\u{30FC}\u{30AB}\u{30FC}\u{30AA}\u{30F3}\u{30B9}\
+    \u{30AA}\u{30FC}\u{30E0}\u{30AB}\u{30A4}\u{30EA}\u{30AB}\u{30E9}\u{30C3}\u{30C8}\u{30AB}\u{30ED}\
+    \u{30EA}\u{30FC}\u{30AC}\u{30ED}\u{30F3}\u{30AC}\u{30F3}\u{30DE}\u{30AE}\u{30AC}\u{30AE}\u{30CB}\
+    \u{30FC}\u{30AD}\u{30E5}\u{30EA}\u{30FC}\u{30AE}\u{30EB}\u{30C0}\u{30FC}\u{30AD}\u{30ED}\u{30AD}\
+    \u{30ED}\u{30B0}\u{30E9}\u{30E0}\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}\u{30AD}\u{30ED}\
+    \u{30EF}\u{30C3}\u{30C8}\u{30B0}\u{30E9}\u{30E0}\u{30B0}\u{30E9}\u{30E0}\u{30C8}\u{30F3}\u{30AF}\
+    \u{30EB}\u{30BC}\u{30A4}\u{30ED}\u{30AF}\u{30ED}\u{30FC}\u{30CD}\u{30B1}\u{30FC}\u{30B9}\u{30B3}\
+    \u{30EB}\u{30CA}\u{30B3}\u{30FC}\u{30DD}\u{30B5}\u{30A4}\u{30AF}\u{30EB}\u{30B5}\u{30F3}\u{30C1}\
+    \u{30FC}\u{30E0}\u{30B7}\u{30EA}\u{30F3}\u{30B0}\u{30BB}\u{30F3}\u{30C1}\u{30BB}\u{30F3}\u{30C8}\
+    \u{30C0}\u{30FC}\u{30B9}\u{30C7}\u{30B7}\u{30C9}\u{30EB}\u{30C8}\u{30F3}\u{30CA}\u{30CE}\u{30CE}\
+    \u{30C3}\u{30C8}\u{30CF}\u{30A4}\u{30C4}\u{30D1}\u{30FC}\u{30BB}\u{30F3}\u{30C8}\u{30D1}\u{30FC}\
+    \u{30C4}\u{30D0}\u{30FC}\u{30EC}\u{30EB}\u{30D4}\u{30A2}\u{30B9}\u{30C8}\u{30EB}\u{30D4}\u{30AF}\
+    \u{30EB}\u{30D4}\u{30B3}\u{30D3}\u{30EB}\u{30D5}\u{30A1}\u{30E9}\u{30C3}\u{30C9}\u{30D5}\u{30A3}\
+    \u{30FC}\u{30C8}\u{30D6}\u{30C3}\u{30B7}\u{30A7}\u{30EB}\u{30D5}\u{30E9}\u{30F3}\u{30D8}\u{30AF}\
+    \u{30BF}\u{30FC}\u{30EB}\u{30DA}\u{30BD}\u{30DA}\u{30CB}\u{30D2}\u{30D8}\u{30EB}\u{30C4}\u{30DA}\
+    \u{30F3}\u{30B9}\u{30DA}\u{30FC}\u{30B8}\u{30D9}\u{30FC}\u{30BF}\u{30DD}\u{30A4}\u{30F3}\u{30C8}\
+    \u{30DC}\u{30EB}\u{30C8}\u{30DB}\u{30F3}\u{30DD}\u{30F3}\u{30C9}\u{30DB}\u{30FC}\u{30EB}\u{30DB}\
+    \u{30FC}\u{30F3}\u{30DE}\u{30A4}\u{30AF}\u{30ED}\u{30DE}\u{30A4}\u{30EB}\u{30DE}\u{30C3}\u{30CF}\
+    \u{30DE}\u{30EB}\u{30AF}\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}\u{30DF}\u{30AF}\u{30ED}\u{30F3}\
+    \u{30DF}\u{30EA}\u{30DF}\u{30EA}\u{30D0}\u{30FC}\u{30EB}\u{30E1}\u{30AC}\u{30E1}\u{30AC}\u{30C8}\
+    \u{30F3}\u{30E1}\u{30FC}\u{30C8}\u{30EB}\u{30E4}\u{30FC}\u{30C9}\u{30E4}\u{30FC}\u{30EB}\u{30E6}\
+    \u{30A2}\u{30F3}\u{30EA}\u{30C3}\u{30C8}\u{30EB}\u{30EA}\u{30E9}\u{30EB}\u{30D4}\u{30FC}\u{30EB}\
+    \u{30FC}\u{30D6}\u{30EB}\u{30EC}\u{30E0}\u{30EC}\u{30F3}\u{30C8}\u{30B2}\u{30F3}\u{30EF}\u{30C3}\
+    \u{30C8}\u{30}\u{70B9}\u{31}\u{70B9}\u{32}\u{70B9}\u{33}\u{70B9}\u{34}\u{70B9}\u{35}\
+    \u{70B9}\u{36}\u{70B9}\u{37}\u{70B9}\u{38}\u{70B9}\u{39}\u{70B9}\u{31}\u{30}\u{70B9}\
+    \u{31}\u{31}\u{70B9}\u{31}\u{32}\u{70B9}\u{31}\u{33}\u{70B9}\u{31}\u{34}\u{70B9}\
+    \u{31}\u{35}\u{70B9}\u{31}\u{36}\u{70B9}\u{31}\u{37}\u{70B9}\u{31}\u{38}\u{70B9}\
+    \u{31}\u{39}\u{70B9}\u{32}\u{30}\u{70B9}\u{32}\u{31}\u{70B9}\u{32}\u{32}\u{70B9}\
+    \u{32}\u{33}\u{70B9}\u{32}\u{34}\u{70B9}\u{68}\u{70}\u{61}\u{64}\u{61}\u{61}\
+    \u{75}\u{62}\u{61}\u{72}\u{6F}\u{76}\u{70}\u{63}\u{64}\u{6D}\u{64}\u{6D}\
+    \u{32}\u{64}\u{6D}\u{33}\u{69}\u{75}\u{5E73}\u{6210}\u{662D}\u{548C}\u{5927}\u{6B63}\
+    \u{660E}\u{6CBB}\u{682A}\u{5F0F}\u{4F1A}\u{793E}\u{70}\u{61}\u{6E}\u{61}\u{3BC}\u{61}\
+    \u{6D}\u{61}\u{6B}\u{61}\u{6B}\u{62}\u{6D}\u{62}\u{67}\u{62}\u{63}\u{61}\
+    \u{6C}\u{6B}\u{63}\u{61}\u{6C}\u{70}\u{66}\u{6E}\u{66}\u{3BC}\u{66}\u{3BC}\
+    \u{67}\u{6D}\u{67}\u{6B}\u{67}\u{68}\u{7A}\u{6B}\u{68}\u{7A}\u{6D}\u{68}\
+    \u{7A}\u{67}\u{68}\u{7A}\u{74}\u{68}\u{7A}\u{3BC}\u{6C}\u{6D}\u{6C}\u{64}\
+    \u{6C}\u{6B}\u{6C}\u{66}\u{6D}\u{6E}\u{6D}\u{3BC}\u{6D}\u{6D}\u{6D}\u{63}\
+    \u{6D}\u{6B}\u{6D}\u{6D}\u{6D}\u{32}\u{63}\u{6D}\u{32}\u{6D}\u{32}\u{6B}\
+    \u{6D}\u{32}\u{6D}\u{6D}\u{33}\u{63}\u{6D}\u{33}\u{6D}\u{33}\u{6B}\u{6D}\
+    \u{33}\u{6D}\u{2215}\u{73}\u{6D}\u{2215}\u{73}\u{32}\u{70}\u{61}\u{6B}\u{70}\
+    \u{61}\u{6D}\u{70}\u{61}\u{67}\u{70}\u{61}\u{72}\u{61}\u{64}\u{72}\u{61}\
+    \u{64}\u{2215}\u{73}\u{72}\u{61}\u{64}\u{2215}\u{73}\u{32}\u{70}\u{73}\u{6E}\
+    \u{73}\u{3BC}\u{73}\u{6D}\u{73}\u{70}\u{76}\u{6E}\u{76}\u{3BC}\u{76}\u{6D}\
+    \u{76}\u{6B}\u{76}\u{6D}\u{76}\u{70}\u{77}\u{6E}\u{77}\u{3BC}\u{77}\u{6D}\
+    \u{77}\u{6B}\u{77}\u{6D}\u{77}\u{6B}\u{3C9}\u{6D}\u{3C9}\u{61}\u{2E}\u{6D}\
+    \u{2E}\u{62}\u{71}\u{63}\u{63}\u{63}\u{64}\u{63}\u{2215}\u{6B}\u{67}\u{63}\
+    \u{6F}\u{2E}\u{64}\u{62}\u{67}\u{79}\u{68}\u{61}\u{68}\u{70}\u{69}\u{6E}\
+    \u{6B}\u{6B}\u{6B}\u{6D}\u{6B}\u{74}\u{6C}\u{6D}\u{6C}\u{6E}\u{6C}\u{6F}\
+    \u{67}\u{6C}\u{78}\u{6D}\u{62}\u{6D}\u{69}\u{6C}\u{6D}\u{6F}\u{6C}\u{70}\
+    \u{68}\u{70}\u{2E}\u{6D}\u{2E}\u{70}\u{70}\u{6D}\u{70}\u{72}\u{73}\u{72}\
+    \u{73}\u{76}\u{77}\u{62}\u{76}\u{2215}\u{6D}\u{61}\u{2215}\u{6D}\u{31}\u{65E5}\
+    \u{32}\u{65E5}\u{33}\u{65E5}\u{34}\u{65E5}\u{35}\u{65E5}\u{36}\u{65E5}\u{37}\u{65E5}\
+    \u{38}\u{65E5}\u{39}\u{65E5}\u{31}\u{30}\u{65E5}\u{31}\u{31}\u{65E5}\u{31}\u{32}\
+    \u{65E5}\u{31}\u{33}\u{65E5}\u{31}\u{34}\u{65E5}\u{31}\u{35}\u{65E5}\u{31}\u{36}\
+    \u{65E5}\u{31}\u{37}\u{65E5}\u{31}\u{38}\u{65E5}\u{31}\u{39}\u{65E5}\u{32}\u{30}\
+    \u{65E5}\u{32}\u{31}\u{65E5}\u{32}\u{32}\u{65E5}\u{32}\u{33}\u{65E5}\u{32}\u{34}\
+    \u{65E5}\u{32}\u{35}\u{65E5}\u{32}\u{36}\u{65E5}\u{32}\u{37}\u{65E5}\u{32}\u{38}\
+    \u{65E5}\u{32}\u{39}\u{65E5}\u{33}\u{30}\u{65E5}\u{33}\u{31}\u{65E5}\u{67}\u{61}\
+    \u{6C}\u{A641}\u{A643}\u{A645}\u{A647}\u{A649}\u{A64B}\u{A64D}\u{A64F}\u{A651}\u{A653}\u{A655}\
+    \u{A657}\u{A659}\u{A65B}\u{A65D}\u{A65F}\u{A661}\u{A663}\u{A665}\u{A667}\u{A669}\u{A66B}\u{A66D}\
+    \u{A681}\u{A683}\u{A685}\u{A687}\u{A689}\u{A68B}\u{A68D}\u{A68F}\u{A691}\u{A693}\u{A695}\u{A697}\
+    \u{A699}\u{A69B}\u{44A}\u{44C}\u{A723}\u{A725}\u{A727}\u{A729}\u{A72B}\u{A72D}\u{A72F}\u{A733}\
+    \u{A735}\u{A737}\u{A739}\u{A73B}\u{A73D}\u{A73F}\u{A741}\u{A743}\u{A745}\u{A747}\u{A749}\u{A74B}\
+    \u{A74D}\u{A74F}\u{A751}\u{A753}\u{A755}\u{A757}\u{A759}\u{A75B}\u{A75D}\u{A75F}\u{A761}\u{A763}\
+    \u{A765}\u{A767}\u{A769}\u{A76B}\u{A76D}\u{A76F}\u{A76F}\u{A77A}\u{A77C}\u{1D79}\u{A77F}\u{A781}\
+    \u{A783}\u{A785}\u{A787}\u{A78C}\u{265}\u{A791}\u{A793}\u{A797}\u{A799}\u{A79B}\u{A79D}\u{A79F}\
+    \u{A7A1}\u{A7A3}\u{A7A5}\u{A7A7}\u{A7A9}\u{266}\u{25C}\u{261}\u{26C}\u{26A}\u{29E}\u{287}\
+    \u{29D}\u{AB53}\u{A7B5}\u{A7B7}\u{A7B9}\u{A7BB}\u{A7BD}\u{A7BF}\u{A7C1}\u{A7C3}\u{A794}\u{282}\
+    \u{1D8E}\u{A7C8}\u{A7CA}\u{264}\u{A7CD}\u{A7CF}\u{A7D1}\u{A7D3}\u{A7D5}\u{A7D7}\u{A7D9}\u{A7DB}\
+    \u{19B}\u{73}\u{63}\u{66}\u{71}\u{A7F6}\u{127}\u{153}\u{A727}\u{AB37}\u{26B}\u{AB52}\
+    \u{28D}\u{13A0}\u{13A1}\u{13A2}\u{13A3}\u{13A4}\u{13A5}\u{13A6}\u{13A7}\u{13A8}\u{13A9}\u{13AA}\
+    \u{13AB}\u{13AC}\u{13AD}\u{13AE}\u{13AF}\u{13B0}\u{13B1}\u{13B2}\u{13B3}\u{13B4}\u{13B5}\u{13B6}\
+    \u{13B7}\u{13B8}\u{13B9}\u{13BA}\u{13BB}\u{13BC}\u{13BD}\u{13BE}\u{13BF}\u{13C0}\u{13C1}\u{13C2}\
+    \u{13C3}\u{13C4}\u{13C5}\u{13C6}\u{13C7}\u{13C8}\u{13C9}\u{13CA}\u{13CB}\u{13CC}\u{13CD}\u{13CE}\
+    \u{13CF}\u{13D0}\u{13D1}\u{13D2}\u{13D3}\u{13D4}\u{13D5}\u{13D6}\u{13D7}\u{13D8}\u{13D9}\u{13DA}\
+    \u{13DB}\u{13DC}\u{13DD}\u{13DE}\u{13DF}\u{13E0}\u{13E1}\u{13E2}\u{13E3}\u{13E4}\u{13E5}\u{13E6}\
+    \u{13E7}\u{13E8}\u{13E9}\u{13EA}\u{13EB}\u{13EC}\u{13ED}\u{13EE}\u{13EF}\u{8C48}\u{66F4}\u{8ECA}\
+    \u{8CC8}\u{6ED1}\u{4E32}\u{53E5}\u{9F9C}\u{9F9C}\u{5951}\u{91D1}\u{5587}\u{5948}\u{61F6}\u{7669}\
+    \u{7F85}\u{863F}\u{87BA}\u{88F8}\u{908F}\u{6A02}\u{6D1B}\u{70D9}\u{73DE}\u{843D}\u{916A}\u{99F1}\
+    \u{4E82}\u{5375}\u{6B04}\u{721B}\u{862D}\u{9E1E}\u{5D50}\u{6FEB}\u{85CD}\u{8964}\u{62C9}\u{81D8}\
+    \u{881F}\u{5ECA}\u{6717}\u{6D6A}\u{72FC}\u{90CE}\u{4F86}\u{51B7}\u{52DE}\u{64C4}\u{6AD3}\u{7210}\
+    \u{76E7}\u{8001}\u{8606}\u{865C}\u{8DEF}\u{9732}\u{9B6F}\u{9DFA}\u{788C}\u{797F}\u{7DA0}\u{83C9}\
+    \u{9304}\u{9E7F}\u{8AD6}\u{58DF}\u{5F04}\u{7C60}\u{807E}\u{7262}\u{78CA}\u{8CC2}\u{96F7}\u{58D8}\
+    \u{5C62}\u{6A13}\u{6DDA}\u{6F0F}\u{7D2F}\u{7E37}\u{964B}\u{52D2}\u{808B}\u{51DC}\u{51CC}\u{7A1C}\
+    \u{7DBE}\u{83F1}\u{9675}\u{8B80}\u{62CF}\u{6A02}\u{8AFE}\u{4E39}\u{5BE7}\u{6012}\u{7387}\u{7570}\
+    \u{5317}\u{78FB}\u{4FBF}\u{5FA9}\u{4E0D}\u{6CCC}\u{6578}\u{7D22}\u{53C3}\u{585E}\u{7701}\u{8449}\
+    \u{8AAA}\u{6BBA}\u{8FB0}\u{6C88}\u{62FE}\u{82E5}\u{63A0}\u{7565}\u{4EAE}\u{5169}\u{51C9}\u{6881}\
+    \u{7CE7}\u{826F}\u{8AD2}\u{91CF}\u{52F5}\u{5442}\u{5973}\u{5EEC}\u{65C5}\u{6FFE}\u{792A}\u{95AD}\
+    \u{9A6A}\u{9E97}\u{9ECE}\u{529B}\u{66C6}\u{6B77}\u{8F62}\u{5E74}\u{6190}\u{6200}\u{649A}\u{6F23}\
+    \u{7149}\u{7489}\u{79CA}\u{7DF4}\u{806F}\u{8F26}\u{84EE}\u{9023}\u{934A}\u{5217}\u{52A3}\u{54BD}\
+    \u{70C8}\u{88C2}\u{8AAA}\u{5EC9}\u{5FF5}\u{637B}\u{6BAE}\u{7C3E}\u{7375}\u{4EE4}\u{56F9}\u{5BE7}\
+    \u{5DBA}\u{601C}\u{73B2}\u{7469}\u{7F9A}\u{8046}\u{9234}\u{96F6}\u{9748}\u{9818}\u{4F8B}\u{79AE}\
+    \u{91B4}\u{96B8}\u{60E1}\u{4E86}\u{50DA}\u{5BEE}\u{5C3F}\u{6599}\u{6A02}\u{71CE}\u{7642}\u{84FC}\
+    \u{907C}\u{9F8D}\u{6688}\u{962E}\u{5289}\u{677B}\u{67F3}\u{6D41}\u{6E9C}\u{7409}\u{7559}\u{786B}\
+    \u{7D10}\u{985E}\u{516D}\u{622E}\u{9678}\u{502B}\u{5D19}\u{6DEA}\u{8F2A}\u{5F8B}\u{6144}\u{6817}\
+    \u{7387}\u{9686}\u{5229}\u{540F}\u{5C65}\u{6613}\u{674E}\u{68A8}\u{6CE5}\u{7406}\u{75E2}\u{7F79}\
+    \u{88CF}\u{88E1}\u{91CC}\u{96E2}\u{533F}\u{6EBA}\u{541D}\u{71D0}\u{7498}\u{85FA}\u{96A3}\u{9C57}\
+    \u{9E9F}\u{6797}\u{6DCB}\u{81E8}\u{7ACB}\u{7B20}\u{7C92}\u{72C0}\u{7099}\u{8B58}\u{4EC0}\u{8336}\
+    \u{523A}\u{5207}\u{5EA6}\u{62D3}\u{7CD6}\u{5B85}\u{6D1E}\u{66B4}\u{8F3B}\u{884C}\u{964D}\u{898B}\
+    \u{5ED3}\u{5140}\u{55C0}\u{585A}\u{6674}\u{51DE}\u{732A}\u{76CA}\u{793C}\u{795E}\u{7965}\u{798F}\
+    \u{9756}\u{7CBE}\u{7FBD}\u{8612}\u{8AF8}\u{9038}\u{90FD}\u{98EF}\u{98FC}\u{9928}\u{9DB4}\u{90DE}\
+    \u{96B7}\u{4FAE}\u{50E7}\u{514D}\u{52C9}\u{52E4}\u{5351}\u{559D}\u{5606}\u{5668}\u{5840}\u{58A8}\
+    \u{5C64}\u{5C6E}\u{6094}\u{6168}\u{618E}\u{61F2}\u{654F}\u{65E2}\u{6691}\u{6885}\u{6D77}\u{6E1A}\
+    \u{6F22}\u{716E}\u{722B}\u{7422}\u{7891}\u{793E}\u{7949}\u{7948}\u{7950}\u{7956}\u{795D}\u{798D}\
+    \u{798E}\u{7A40}\u{7A81}\u{7BC0}\u{7DF4}\u{7E09}\u{7E41}\u{7F72}\u{8005}\u{81ED}\u{8279}\u{8279}\
+    \u{8457}\u{8910}\u{8996}\u{8B01}\u{8B39}\u{8CD3}\u{8D08}\u{8FB6}\u{9038}\u{96E3}\u{97FF}\u{983B}\
+    \u{6075}\u{242EE}\u{8218}\u{4E26}\u{51B5}\u{5168}\u{4F80}\u{5145}\u{5180}\u{52C7}\u{52FA}\u{559D}\
+    \u{5555}\u{5599}\u{55E2}\u{585A}\u{58B3}\u{5944}\u{5954}\u{5A62}\u{5B28}\u{5ED2}\u{5ED9}\u{5F69}\
+    \u{5FAD}\u{60D8}\u{614E}\u{6108}\u{618E}\u{6160}\u{61F2}\u{6234}\u{63C4}\u{641C}\u{6452}\u{6556}\
+    \u{6674}\u{6717}\u{671B}\u{6756}\u{6B79}\u{6BBA}\u{6D41}\u{6EDB}\u{6ECB}\u{6F22}\u{701E}\u{716E}\
+    \u{77A7}\u{7235}\u{72AF}\u{732A}\u{7471}\u{7506}\u{753B}\u{761D}\u{761F}\u{76CA}\u{76DB}\u{76F4}\
+    \u{774A}\u{7740}\u{78CC}\u{7AB1}\u{7BC0}\u{7C7B}\u{7D5B}\u{7DF4}\u{7F3E}\u{8005}\u{8352}\u{83EF}\
+    \u{8779}\u{8941}\u{8986}\u{8996}\u{8ABF}\u{8AF8}\u{8ACB}\u{8B01}\u{8AFE}\u{8AED}\u{8B39}\u{8B8A}\
+    \u{8D08}\u{8F38}\u{9072}\u{9199}\u{9276}\u{967C}\u{96E3}\u{9756}\u{97DB}\u{97FF}\u{980B}\u{983B}\
+    \u{9B12}\u{9F9C}\u{2284A}\u{22844}\u{233D5}\u{3B9D}\u{4018}\u{4039}\u{25249}\u{25CD0}\u{27ED3}\u{9F43}\
+    \u{9F8E}\u{66}\u{66}\u{66}\u{69}\u{66}\u{6C}\u{66}\u{66}\u{69}\u{66}\u{66}\
+    \u{6C}\u{73}\u{74}\u{73}\u{74}\u{574}\u{576}\u{574}\u{565}\u{574}\u{56B}\u{57E}\
+    \u{576}\u{574}\u{56D}\u{5D9}\u{5B4}\u{5F2}\u{5B7}\u{5E2}\u{5D0}\u{5D3}\u{5D4}\u{5DB}\
+    \u{5DC}\u{5DD}\u{5E8}\u{5EA}\u{2B}\u{5E9}\u{5C1}\u{5E9}\u{5C2}\u{5E9}\u{5BC}\u{5C1}\
+    \u{5E9}\u{5BC}\u{5C2}\u{5D0}\u{5B7}\u{5D0}\u{5B8}\u{5D0}\u{5BC}\u{5D1}\u{5BC}\u{5D2}\
+    \u{5BC}\u{5D3}\u{5BC}\u{5D4}\u{5BC}\u{5D5}\u{5BC}\u{5D6}\u{5BC}\u{5D8}\u{5BC}\u{5D9}\
+    \u{5BC}\u{5DA}\u{5BC}\u{5DB}\u{5BC}\u{5DC}\u{5BC}\u{5DE}\u{5BC}\u{5E0}\u{5BC}\u{5E1}\
+    \u{5BC}\u{5E3}\u{5BC}\u{5E4}\u{5BC}\u{5E6}\u{5BC}\u{5E7}\u{5BC}\u{5E8}\u{5BC}\u{5E9}\
+    \u{5BC}\u{5EA}\u{5BC}\u{5D5}\u{5B9}\u{5D1}\u{5BF}\u{5DB}\u{5BF}\u{5E4}\u{5BF}\u{5D0}\
+    \u{5DC}\u{671}\u{671}\u{67B}\u{67B}\u{67B}\u{67B}\u{67E}\u{67E}\u{67E}\u{67E}\u{680}\
+    \u{680}\u{680}\u{680}\u{67A}\u{67A}\u{67A}\u{67A}\u{67F}\u{67F}\u{67F}\u{67F}\u{679}\
+    \u{679}\u{679}\u{679}\u{6A4}\u{6A4}\u{6A4}\u{6A4}\u{6A6}\u{6A6}\u{6A6}\u{6A6}\u{684}\
+    \u{684}\u{684}\u{684}\u{683}\u{683}\u{683}\u{683}\u{686}\u{686}\u{686}\u{686}\u{687}\
+    \u{687}\u{687}\u{687}\u{68D}\u{68D}\u{68C}\u{68C}\u{68E}\u{68E}\u{688}\u{688}\u{698}\
+    \u{698}\u{691}\u{691}\u{6A9}\u{6A9}\u{6A9}\u{6A9}\u{6AF}\u{6AF}\u{6AF}\u{6AF}\u{6B3}\
+    \u{6B3}\u{6B3}\u{6B3}\u{6B1}\u{6B1}\u{6B1}\u{6B1}\u{6BA}\u{6BA}\u{6BB}\u{6BB}\u{6BB}\
+    \u{6BB}\u{6C0}\u{6C0}\u{6C1}\u{6C1}\u{6C1}\u{6C1}\u{6BE}\u{6BE}\u{6BE}\u{6BE}\u{6D2}\
+    \u{6D2}\u{6D3}\u{6D3}\u{6AD}\u{6AD}\u{6AD}\u{6AD}\u{6C7}\u{6C7}\u{6C6}\u{6C6}\u{6C8}\
+    \u{6C8}\u{6C7}\u{674}\u{6CB}\u{6CB}\u{6C5}\u{6C5}\u{6C9}\u{6C9}\u{6D0}\u{6D0}\u{6D0}\
+    \u{6D0}\u{649}\u{649}\u{626}\u{627}\u{626}\u{627}\u{626}\u{6D5}\u{626}\u{6D5}\u{626}\
+    \u{648}\u{626}\u{648}\u{626}\u{6C7}\u{626}\u{6C7}\u{626}\u{6C6}\u{626}\u{6C6}\u{626}\
+    \u{6C8}\u{626}\u{6C8}\u{626}\u{6D0}\u{626}\u{6D0}\u{626}\u{6D0}\u{626}\u{649}\u{626}\
+    \u{649}\u{626}\u{649}\u{6CC}\u{6CC}\u{6CC}\u{6CC}\u{626}\u{62C}\u{626}\u{62D}\u{626}\
+    \u{645}\u{626}\u{649}\u{626}\u{64A}\u{628}\u{62C}\u{628}\u{62D}\u{628}\u{62E}\u{628}\
+    \u{645}\u{628}\u{649}\u{628}\u{64A}\u{62A}\u{62C}\u{62A}\u{62D}\u{62A}\u{62E}\u{62A}\
+    \u{645}\u{62A}\u{649}\u{62A}\u{64A}\u{62B}\u{62C}\u{62B}\u{645}\u{62B}\u{649}\u{62B}\
+    \u{64A}\u{62C}\u{62D}\u{62C}\u{645}\u{62D}\u{62C}\u{62D}\u{645}\u{62E}\u{62C}\u{62E}\
+    \u{62D}\u{62E}\u{645}\u{633}\u{62C}\u{633}\u{62D}\u{633}\u{62E}\u{633}\u{645}\u{635}\
+    \u{62D}\u{635}\u{645}\u{636}\u{62C}\u{636}\u{62D}\u{636}\u{62E}\u{636}\u{645}\u{637}\
+    \u{62D}\u{637}\u{645}\u{638}\u{645}\u{639}\u{62C}\u{639}\u{645}\u{63A}\u{62C}\u{63A}\
+    \u{645}\u{641}\u{62C}\u{641}\u{62D}\u{641}\u{62E}\u{641}\u{645}\u{641}\u{649}\u{641}\
+    \u{64A}\u{642}\u{62D}\u{642}\u{645}\u{642}\u{649}\u{642}\u{64A}\u{643}\u{627}\u{643}\
+    \u{62C}\u{643}\u{62D}\u{643}\u{62E}\u{643}\u{644}\u{643}\u{645}\u{643}\u{649}\u{643}\
+    \u{64A}\u{644}\u{62C}\u{644}\u{62D}\u{644}\u{62E}\u{644}\u{645}\u{644}\u{649}\u{644}\
+    \u{64A}\u{645}\u{62C}\u{645}\u{62D}\u{645}\u{62E}\u{645}\u{645}\u{645}\u{649}\u{645}\
+    \u{64A}\u{646}\u{62C}\u{646}\u{62D}\u{646}\u{62E}\u{646}\u{645}\u{646}\u{649}\u{646}\
+    \u{64A}\u{647}\u{62C}\u{647}\u{645}\u{647}\u{649}\u{647}\u{64A}\u{64A}\u{62C}\u{64A}\
+    \u{62D}\u{64A}\u{62E}\u{64A}\u{645}\u{64A}\u{649}\u{64A}\u{64A}\u{630}\u{670}\u{631}\
+    \u{670}\u{649}\u{670}\u{20}\u{64C}\u{651}\u{20}\u{64D}\u{651}\u{20}\u{64E}\u{651}\
+    \u{20}\u{64F}\u{651}\u{20}\u{650}\u{651}\u{20}\u{651}\u{670}\u{626}\u{631}\u{626}\
+    \u{632}\u{626}\u{645}\u{626}\u{646}\u{626}\u{649}\u{626}\u{64A}\u{628}\u{631}\u{628}\
+    \u{632}\u{628}\u{645}\u{628}\u{646}\u{628}\u{649}\u{628}\u{64A}\u{62A}\u{631}\u{62A}\
+    \u{632}\u{62A}\u{645}\u{62A}\u{646}\u{62A}\u{649}\u{62A}\u{64A}\u{62B}\u{631}\u{62B}\
+    \u{632}\u{62B}\u{645}\u{62B}\u{646}\u{62B}\u{649}\u{62B}\u{64A}\u{641}\u{649}\u{641}\
+    \u{64A}\u{642}\u{649}\u{642}\u{64A}\u{643}\u{627}\u{643}\u{644}\u{643}\u{645}\u{643}\
+    \u{649}\u{643}\u{64A}\u{644}\u{645}\u{644}\u{649}\u{644}\u{64A}\u{645}\u{627}\u{645}\
+    \u{645}\u{646}\u{631}\u{646}\u{632}\u{646}\u{645}\u{646}\u{646}\u{646}\u{649}\u{646}\
+    \u{64A}\u{649}\u{670}\u{64A}\u{631}\u{64A}\u{632}\u{64A}\u{645}\u{64A}\u{646}\u{64A}\
+    \u{649}\u{64A}\u{64A}\u{626}\u{62C}\u{626}\u{62D}\u{626}\u{62E}\u{626}\u{645}\u{626}\
+    \u{647}\u{628}\u{62C}\u{628}\u{62D}\u{628}\u{62E}\u{628}\u{645}\u{628}\u{647}\u{62A}\
+    \u{62C}\u{62A}\u{62D}\u{62A}\u{62E}\u{62A}\u{645}\u{62A}\u{647}\u{62B}\u{645}\u{62C}\
+    \u{62D}\u{62C}\u{645}\u{62D}\u{62C}\u{62D}\u{645}\u{62E}\u{62C}\u{62E}\u{645}\u{633}\
+    \u{62C}\u{633}\u{62D}\u{633}\u{62E}\u{633}\u{645}\u{635}\u{62D}\u{635}\u{62E}\u{635}\
+    \u{645}\u{636}\u{62C}\u{636}\u{62D}\u{636}\u{62E}\u{636}\u{645}\u{637}\u{62D}\u{638}\
+    \u{645}\u{639}\u{62C}\u{639}\u{645}\u{63A}\u{62C}\u{63A}\u{645}\u{641}\u{62C}\u{641}\
+    \u{62D}\u{641}\u{62E}\u{641}\u{645}\u{642}\u{62D}\u{642}\u{645}\u{643}\u{62C}\u{643}\
+    \u{62D}\u{643}\u{62E}\u{643}\u{644}\u{643}\u{645}\u{644}\u{62C}\u{644}\u{62D}\u{644}\
+    \u{62E}\u{644}\u{645}\u{644}\u{647}\u{645}\u{62C}\u{645}\u{62D}\u{645}\u{62E}\u{645}\
+    \u{645}\u{646}\u{62C}\u{646}\u{62D}\u{646}\u{62E}\u{646}\u{645}\u{646}\u{647}\u{647}\
+    \u{62C}\u{647}\u{645}\u{647}\u{670}\u{64A}\u{62C}\u{64A}\u{62D}\u{64A}\u{62E}\u{64A}\
+    \u{645}\u{64A}\u{647}\u{626}\u{645}\u{626}\u{647}\u{628}\u{645}\u{628}\u{647}\u{62A}\
+    \u{645}\u{62A}\u{647}\u{62B}\u{645}\u{62B}\u{647}\u{633}\u{645}\u{633}\u{647}\u{634}\
+    \u{645}\u{634}\u{647}\u{643}\u{644}\u{643}\u{645}\u{644}\u{645}\u{646}\u{645}\u{646}\
+    \u{647}\u{64A}\u{645}\u{64A}\u{647}\u{640}\u{64E}\u{651}\u{640}\u{64F}\u{651}\u{640}\
+    \u{650}\u{651}\u{637}\u{649}\u{637}\u{64A}\u{639}\u{649}\u{639}\u{64A}\u{63A}\u{649}\
+    \u{63A}\u{64A}\u{633}\u{649}\u{633}\u{64A}\u{634}\u{649}\u{634}\u{64A}\u{62D}\u{649}\
+    \u{62D}\u{64A}\u{62C}\u{649}\u{62C}\u{64A}\u{62E}\u{649}\u{62E}\u{64A}\u{635}\u{649}\
+    \u{635}\u{64A}\u{636}\u{649}\u{636}\u{64A}\u{634}\u{62C}\u{634}\u{62D}\u{634}\u{62E}\
+    \u{634}\u{645}\u{634}\u{631}\u{633}\u{631}\u{635}\u{631}\u{636}\u{631}\u{637}\u{649}\
+    \u{637}\u{64A}\u{639}\u{649}\u{639}\u{64A}\u{63A}\u{649}\u{63A}\u{64A}\u{633}\u{649}\
+    \u{633}\u{64A}\u{634}\u{649}\u{634}\u{64A}\u{62D}\u{649}\u{62D}\u{64A}\u{62C}\u{649}\
+    \u{62C}\u{64A}\u{62E}\u{649}\u{62E}\u{64A}\u{635}\u{649}\u{635}\u{64A}\u{636}\u{649}\
+    \u{636}\u{64A}\u{634}\u{62C}\u{634}\u{62D}\u{634}\u{62E}\u{634}\u{645}\u{634}\u{631}\
+    \u{633}\u{631}\u{635}\u{631}\u{636}\u{631}\u{634}\u{62C}\u{634}\u{62D}\u{634}\u{62E}\
+    \u{634}\u{645}\u{633}\u{647}\u{634}\u{647}\u{637}\u{645}\u{633}\u{62C}\u{633}\u{62D}\
+    \u{633}\u{62E}\u{634}\u{62C}\u{634}\u{62D}\u{634}\u{62E}\u{637}\u{645}\u{638}\u{645}\
+    \u{627}\u{64B}\u{627}\u{64B}\u{62A}\u{62C}\u{645}\u{62A}\u{62D}\u{62C}\u{62A}\u{62D}\
+    \u{62C}\u{62A}\u{62D}\u{645}\u{62A}\u{62E}\u{645}\u{62A}\u{645}\u{62C}\u{62A}\u{645}\
+    \u{62D}\u{62A}\u{645}\u{62E}\u{62C}\u{645}\u{62D}\u{62C}\u{645}\u{62D}\u{62D}\u{645}\
+    \u{64A}\u{62D}\u{645}\u{649}\u{633}\u{62D}\u{62C}\u{633}\u{62C}\u{62D}\u{633}\u{62C}\
+    \u{649}\u{633}\u{645}\u{62D}\u{633}\u{645}\u{62D}\u{633}\u{645}\u{62C}\u{633}\u{645}\
+    \u{645}\u{633}\u{645}\u{645}\u{635}\u{62D}\u{62D}\u{635}\u{62D}\u{62D}\u{635}\u{645}\
+    \u{645}\u{634}\u{62D}\u{645}\u{634}\u{62D}\u{645}\u{634}\u{62C}\u{64A}\u{634}\u{645}\
+    \u{62E}\u{634}\u{645}\u{62E}\u{634}\u{645}\u{645}\u{634}\u{645}\u{645}\u{636}\u{62D}\
+    \u{649}\u{636}\u{62E}\u{645}\u{636}\u{62E}\u{645}\u{637}\u{645}\u{62D}\u{637}\u{645}\
+    \u{62D}\u{637}\u{645}\u{645}\u{637}\u{645}\u{64A}\u{639}\u{62C}\u{645}\u{639}\u{645}\
+    \u{645}\u{639}\u{645}\u{645}\u{639}\u{645}\u{649}\u{63A}\u{645}\u{645}\u{63A}\u{645}\
+    \u{64A}\u{63A}\u{645}\u{649}\u{641}\u{62E}\u{645}\u{641}\u{62E}\u{645}\u{642}\u{645}\
+    \u{62D}\u{642}\u{645}\u{645}\u{644}\u{62D}\u{645}\u{644}\u{62D}\u{64A}\u{644}\u{62D}\
+    \u{649}\u{644}\u{62C}\u{62C}\u{644}\u{62C}\u{62C}\u{644}\u{62E}\u{645}\u{644}\u{62E}\
+    \u{645}\u{644}\u{645}\u{62D}\u{644}\u{645}\u{62D}\u{645}\u{62D}\u{62C}\u{645}\u{62D}\
+    \u{645}\u{645}\u{62D}\u{64A}\u{645}\u{62C}\u{62D}\u{645}\u{62C}\u{645}\u{645}\u{62E}\
+    \u{62C}\u{645}\u{62E}\u{645}\u{645}\u{62C}\u{62E}\u{647}\u{645}\u{62C}\u{647}\u{645}\
+    \u{645}\u{646}\u{62D}\u{645}\u{646}\u{62D}\u{649}\u{646}\u{62C}\u{645}\u{646}\u{62C}\
+    \u{645}\u{646}\u{62C}\u{649}\u{646}\u{645}\u{64A}\u{646}\u{645}\u{649}\u{64A}\u{645}\
+    \u{645}\u{64A}\u{645}\u{645}\u{628}\u{62E}\u{64A}\u{62A}\u{62C}\u{64A}\u{62A}\u{62C}\
+    \u{649}\u{62A}\u{62E}\u{64A}\u{62A}\u{62E}\u{649}\u{62A}\u{645}\u{64A}\u{62A}\u{645}\
+    \u{649}\u{62C}\u{645}\u{64A}\u{62C}\u{62D}\u{649}\u{62C}\u{645}\u{649}\u{633}\u{62E}\
+    \u{649}\u{635}\u{62D}\u{64A}\u{634}\u{62D}\u{64A}\u{636}\u{62D}\u{64A}\u{644}\u{62C}\
+    \u{64A}\u{644}\u{645}\u{64A}\u{64A}\u{62D}\u{64A}\u{64A}\u{62C}\u{64A}\u{64A}\u{645}\
+    \u{64A}\u{645}\u{645}\u{64A}\u{642}\u{645}\u{64A}\u{646}\u{62D}\u{64A}\u{642}\u{645}\
+    \u{62D}\u{644}\u{62D}\u{645}\u{639}\u{645}\u{64A}\u{643}\u{645}\u{64A}\u{646}\u{62C}\
+    \u{62D}\u{645}\u{62E}\u{64A}\u{644}\u{62C}\u{645}\u{643}\u{645}\u{645}\u{644}\u{62C}\
+    \u{645}\u{646}\u{62C}\u{62D}\u{62C}\u{62D}\u{64A}\u{62D}\u{62C}\u{64A}\u{645}\u{62C}\
+    \u{64A}\u{641}\u{645}\u{64A}\u{628}\u{62D}\u{64A}\u{643}\u{645}\u{645}\u{639}\u{62C}\
+    \u{645}\u{635}\u{645}\u{645}\u{633}\u{62E}\u{64A}\u{646}\u{62C}\u{64A}\u{635}\u{644}\
+    \u{6D2}\u{642}\u{644}\u{6D2}\u{627}\u{644}\u{644}\u{647}\u{627}\u{643}\u{628}\u{631}\
+    \u{645}\u{62D}\u{645}\u{62F}\u{635}\u{644}\u{639}\u{645}\u{631}\u{633}\u{648}\u{644}\
+    \u{639}\u{644}\u{64A}\u{647}\u{648}\u{633}\u{644}\u{645}\u{635}\u{644}\u{649}\u{635}\
+    \u{644}\u{649}\u{20}\u{627}\u{644}\u{644}\u{647}\u{20}\u{639}\u{644}\u{64A}\u{647}\
+    \u{20}\u{648}\u{633}\u{644}\u{645}\u{62C}\u{644}\u{20}\u{62C}\u{644}\u{627}\u{644}\
+    \u{647}\u{631}\u{6CC}\u{627}\u{644}\u{2C}\u{3001}\u{3002}\u{3A}\u{3B}\u{21}\u{3F}\
+    \u{3016}\u{3017}\u{2E}\u{2E}\u{2E}\u{2E}\u{2E}\u{2014}\u{2013}\u{5F}\u{5F}\u{28}\
+    \u{29}\u{7B}\u{7D}\u{3014}\u{3015}\u{3010}\u{3011}\u{300A}\u{300B}\u{3008}\u{3009}\u{300C}\
+    \u{300D}\u{300E}\u{300F}\u{5B}\u{5D}\u{20}\u{305}\u{20}\u{305}\u{20}\u{305}\u{20}\
+    \u{305}\u{5F}\u{5F}\u{5F}\u{2C}\u{3001}\u{2E}\u{3B}\u{3A}\u{3F}\u{21}\u{2014}\
+    \u{28}\u{29}\u{7B}\u{7D}\u{3014}\u{3015}\u{23}\u{26}\u{2A}\u{2B}\u{2D}\u{3C}\
+    \u{3E}\u{3D}\u{5C}\u{24}\u{25}\u{40}\u{20}\u{64B}\u{640}\u{64B}\u{20}\u{64C}\
+    \u{20}\u{64D}\u{20}\u{64E}\u{640}\u{64E}\u{20}\u{64F}\u{640}\u{64F}\u{20}\u{650}\
+    \u{640}\u{650}\u{20}\u{651}\u{640}\u{651}\u{20}\u{652}\u{640}\u{652}\u{621}\u{622}\
+    \u{622}\u{623}\u{623}\u{624}\u{624}\u{625}\u{625}\u{626}\u{626}\u{626}\u{626}\u{627}\
+    \u{627}\u{628}\u{628}\u{628}\u{628}\u{629}\u{629}\u{62A}\u{62A}\u{62A}\u{62A}\u{62B}\
+    \u{62B}\u{62B}\u{62B}\u{62C}\u{62C}\u{62C}\u{62C}\u{62D}\u{62D}\u{62D}\u{62D}\u{62E}\
+    \u{62E}\u{62E}\u{62E}\u{62F}\u{62F}\u{630}\u{630}\u{631}\u{631}\u{632}\u{632}\u{633}\
+    \u{633}\u{633}\u{633}\u{634}\u{634}\u{634}\u{634}\u{635}\u{635}\u{635}\u{635}\u{636}\
+    \u{636}\u{636}\u{636}\u{637}\u{637}\u{637}\u{637}\u{638}\u{638}\u{638}\u{638}\u{639}\
+    \u{639}\u{639}\u{639}\u{63A}\u{63A}\u{63A}\u{63A}\u{641}\u{641}\u{641}\u{641}\u{642}\
+    \u{642}\u{642}\u{642}\u{643}\u{643}\u{643}\u{643}\u{644}\u{644}\u{644}\u{644}\u{645}\
+    \u{645}\u{645}\u{645}\u{646}\u{646}\u{646}\u{646}\u{647}\u{647}\u{647}\u{647}\u{648}\
+    \u{648}\u{649}\u{649}\u{64A}\u{64A}\u{64A}\u{64A}\u{644}\u{622}\u{644}\u{622}\u{644}\
+    \u{623}\u{644}\u{623}\u{644}\u{625}\u{644}\u{625}\u{644}\u{627}\u{644}\u{627}\u{21}\
+    \u{22}\u{23}\u{24}\u{25}\u{26}\u{27}\u{28}\u{29}\u{2A}\u{2B}\u{2C}\u{2D}\
+    \u{2E}\u{2F}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\
+    \u{3A}\u{3B}\u{3C}\u{3D}\u{3E}\u{3F}\u{40}\u{61}\u{62}\u{63}\u{64}\u{65}\
+    \u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\
+    \u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{5B}\u{5C}\u{5D}\
+    \u{5E}\u{5F}\u{60}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\
+    \u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\
+    \u{76}\u{77}\u{78}\u{79}\u{7A}\u{7B}\u{7C}\u{7D}\u{7E}\u{2985}\u{2986}\u{3002}\
+    \u{300C}\u{300D}\u{3001}\u{30FB}\u{30F2}\u{30A1}\u{30A3}\u{30A5}\u{30A7}\u{30A9}\u{30E3}\u{30E5}\
+    \u{30E7}\u{30C3}\u{30FC}\u{30A2}\u{30A4}\u{30A6}\u{30A8}\u{30AA}\u{30AB}\u{30AD}\u{30AF}\u{30B1}\
+    \u{30B3}\u{30B5}\u{30B7}\u{30B9}\u{30BB}\u{30BD}\u{30BF}\u{30C1}\u{30C4}\u{30C6}\u{30C8}\u{30CA}\
+    \u{30CB}\u{30CC}\u{30CD}\u{30CE}\u{30CF}\u{30D2}\u{30D5}\u{30D8}\u{30DB}\u{30DE}\u{30DF}\u{30E0}\
+    \u{30E1}\u{30E2}\u{30E4}\u{30E6}\u{30E8}\u{30E9}\u{30EA}\u{30EB}\u{30EC}\u{30ED}\u{30EF}\u{30F3}\
+    \u{3099}\u{309A}\u{1100}\u{1101}\u{11AA}\u{1102}\u{11AC}\u{11AD}\u{1103}\u{1104}\u{1105}\u{11B0}\
+    \u{11B1}\u{11B2}\u{11B3}\u{11B4}\u{11B5}\u{111A}\u{1106}\u{1107}\u{1108}\u{1121}\u{1109}\u{110A}\
+    \u{110B}\u{110C}\u{110D}\u{110E}\u{110F}\u{1110}\u{1111}\u{1112}\u{1161}\u{1162}\u{1163}\u{1164}\
+    \u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116A}\u{116B}\u{116C}\u{116D}\u{116E}\u{116F}\u{1170}\
+    \u{1171}\u{1172}\u{1173}\u{1174}\u{1175}\u{A2}\u{A3}\u{AC}\u{20}\u{304}\u{A6}\u{A5}\
+    \u{20A9}\u{2502}\u{2190}\u{2191}\u{2192}\u{2193}\u{25A0}\u{25CB}\u{10428}\u{10429}\u{1042A}\u{1042B}\
+    \u{1042C}\u{1042D}\u{1042E}\u{1042F}\u{10430}\u{10431}\u{10432}\u{10433}\u{10434}\u{10435}\u{10436}\u{10437}\
+    \u{10438}\u{10439}\u{1043A}\u{1043B}\u{1043C}\u{1043D}\u{1043E}\u{1043F}\u{10440}\u{10441}\u{10442}\u{10443}\
+    \u{10444}\u{10445}\u{10446}\u{10447}\u{10448}\u{10449}\u{1044A}\u{1044B}\u{1044C}\u{1044D}\u{1044E}\u{1044F}\
+    \u{104D8}\u{104D9}\u{104DA}\u{104DB}\u{104DC}\u{104DD}\u{104DE}\u{104DF}\u{104E0}\u{104E1}\u{104E2}\u{104E3}\
+    \u{104E4}\u{104E5}\u{104E6}\u{104E7}\u{104E8}\u{104E9}\u{104EA}\u{104EB}\u{104EC}\u{104ED}\u{104EE}\u{104EF}\
+    \u{104F0}\u{104F1}\u{104F2}\u{104F3}\u{104F4}\u{104F5}\u{104F6}\u{104F7}\u{104F8}\u{104F9}\u{104FA}\u{104FB}\
+    \u{10597}\u{10598}\u{10599}\u{1059A}\u{1059B}\u{1059C}\u{1059D}\u{1059E}\u{1059F}\u{105A0}\u{105A1}\u{105A3}\
+    \u{105A4}\u{105A5}\u{105A6}\u{105A7}\u{105A8}\u{105A9}\u{105AA}\u{105AB}\u{105AC}\u{105AD}\u{105AE}\u{105AF}\
+    \u{105B0}\u{105B1}\u{105B3}\u{105B4}\u{105B5}\u{105B6}\u{105B7}\u{105B8}\u{105B9}\u{105BB}\u{105BC}\u{2D0}\
+    \u{2D1}\u{E6}\u{299}\u{253}\u{2A3}\u{AB66}\u{2A5}\u{2A4}\u{256}\u{257}\u{1D91}\u{258}\
+    \u{25E}\u{2A9}\u{264}\u{262}\u{260}\u{29B}\u{127}\u{29C}\u{267}\u{284}\u{2AA}\u{2AB}\
+    \u{26C}\u{1DF04}\u{A78E}\u{26E}\u{1DF05}\u{28E}\u{1DF06}\u{F8}\u{276}\u{277}\u{71}\u{27A}\
+    \u{1DF08}\u{27D}\u{27E}\u{280}\u{2A8}\u{2A6}\u{AB67}\u{2A7}\u{288}\u{2C71}\u{28F}\u{2A1}\
+    \u{2A2}\u{298}\u{1C0}\u{1C1}\u{1C2}\u{1DF0A}\u{1DF1E}\u{10CC0}\u{10CC1}\u{10CC2}\u{10CC3}\u{10CC4}\
+    \u{10CC5}\u{10CC6}\u{10CC7}\u{10CC8}\u{10CC9}\u{10CCA}\u{10CCB}\u{10CCC}\u{10CCD}\u{10CCE}\u{10CCF}\u{10CD0}\
+    \u{10CD1}\u{10CD2}\u{10CD3}\u{10CD4}\u{10CD5}\u{10CD6}\u{10CD7}\u{10CD8}\u{10CD9}\u{10CDA}\u{10CDB}\u{10CDC}\
+    \u{10CDD}\u{10CDE}\u{10CDF}\u{10CE0}\u{10CE1}\u{10CE2}\u{10CE3}\u{10CE4}\u{10CE5}\u{10CE6}\u{10CE7}\u{10CE8}\
+    \u{10CE9}\u{10CEA}\u{10CEB}\u{10CEC}\u{10CED}\u{10CEE}\u{10CEF}\u{10CF0}\u{10CF1}\u{10CF2}\u{10D70}\u{10D71}\
+    \u{10D72}\u{10D73}\u{10D74}\u{10D75}\u{10D76}\u{10D77}\u{10D78}\u{10D79}\u{10D7A}\u{10D7B}\u{10D7C}\u{10D7D}\
+    \u{10D7E}\u{10D7F}\u{10D80}\u{10D81}\u{10D82}\u{10D83}\u{10D84}\u{10D85}\u{118C0}\u{118C1}\u{118C2}\u{118C3}\
+    \u{118C4}\u{118C5}\u{118C6}\u{118C7}\u{118C8}\u{118C9}\u{118CA}\u{118CB}\u{118CC}\u{118CD}\u{118CE}\u{118CF}\
+    \u{118D0}\u{118D1}\u{118D2}\u{118D3}\u{118D4}\u{118D5}\u{118D6}\u{118D7}\u{118D8}\u{118D9}\u{118DA}\u{118DB}\
+    \u{118DC}\u{118DD}\u{118DE}\u{118DF}\u{16E60}\u{16E61}\u{16E62}\u{16E63}\u{16E64}\u{16E65}\u{16E66}\u{16E67}\
+    \u{16E68}\u{16E69}\u{16E6A}\u{16E6B}\u{16E6C}\u{16E6D}\u{16E6E}\u{16E6F}\u{16E70}\u{16E71}\u{16E72}\u{16E73}\
+    \u{16E74}\u{16E75}\u{16E76}\u{16E77}\u{16E78}\u{16E79}\u{16E7A}\u{16E7B}\u{16E7C}\u{16E7D}\u{16E7E}\u{16E7F}\
+    \u{16EBB}\u{16EBC}\u{16EBD}\u{16EBE}\u{16EBF}\u{16EC0}\u{16EC1}\u{16EC2}\u{16EC3}\u{16EC4}\u{16EC5}\u{16EC6}\
+    \u{16EC7}\u{16EC8}\u{16EC9}\u{16ECA}\u{16ECB}\u{16ECC}\u{16ECD}\u{16ECE}\u{16ECF}\u{16ED0}\u{16ED1}\u{16ED2}\
+    \u{16ED3}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\
+    \u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\
+    \u{78}\u{79}\u{7A}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\
+    \u{39}\u{1D157}\u{1D165}\u{1D158}\u{1D165}\u{1D158}\u{1D165}\u{1D16E}\u{1D158}\u{1D165}\u{1D16F}\u{1D158}\
+    \u{1D165}\u{1D170}\u{1D158}\u{1D165}\u{1D171}\u{1D158}\u{1D165}\u{1D172}\u{1D1B9}\u{1D165}\u{1D1BA}\u{1D165}\
+    \u{1D1B9}\u{1D165}\u{1D16E}\u{1D1BA}\u{1D165}\u{1D16E}\u{1D1B9}\u{1D165}\u{1D16F}\u{1D1BA}\u{1D165}\u{1D16F}\
+    \u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\
+    \u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\
+    \u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\
+    \u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\
+    \u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\
+    \u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\
+    \u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\
+    \u{67}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\
+    \u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\
+    \u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\
+    \u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\
+    \u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\
+    \u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\
+    \u{63}\u{64}\u{67}\u{6A}\u{6B}\u{6E}\u{6F}\u{70}\u{71}\u{73}\u{74}\u{75}\
+    \u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{66}\u{68}\u{69}\
+    \u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\
+    \u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\
+    \u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\
+    \u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\
+    \u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\
+    \u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{64}\u{65}\
+    \u{66}\u{67}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{73}\u{74}\
+    \u{75}\u{76}\u{77}\u{78}\u{79}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\
+    \u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\
+    \u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{64}\u{65}\u{66}\
+    \u{67}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6F}\u{73}\u{74}\u{75}\u{76}\u{77}\
+    \u{78}\u{79}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\
+    \u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\
+    \u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\
+    \u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\
+    \u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\
+    \u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\
+    \u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\
+    \u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\
+    \u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\
+    \u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\
+    \u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\
+    \u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\
+    \u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\
+    \u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\
+    \u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\
+    \u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\
+    \u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\
+    \u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\
+    \u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\
+    \u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\
+    \u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\
+    \u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\u{61}\u{62}\
+    \u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\
+    \u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\
+    \u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\
+    \u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\
+    \u{79}\u{7A}\u{61}\u{62}\u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\
+    \u{6B}\u{6C}\u{6D}\u{6E}\u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\
+    \u{77}\u{78}\u{79}\u{7A}\u{131}\u{237}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\
+    \u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3B8}\
+    \u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2207}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\
+    \u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\
+    \u{3C1}\u{3C3}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2202}\u{3B5}\u{3B8}\
+    \u{3BA}\u{3C6}\u{3C1}\u{3C0}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\
+    \u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3B8}\u{3C3}\u{3C4}\
+    \u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2207}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\
+    \u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3C3}\
+    \u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2202}\u{3B5}\u{3B8}\u{3BA}\u{3C6}\
+    \u{3C1}\u{3C0}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\
+    \u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3B8}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\
+    \u{3C7}\u{3C8}\u{3C9}\u{2207}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\
+    \u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3C3}\u{3C3}\u{3C4}\
+    \u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2202}\u{3B5}\u{3B8}\u{3BA}\u{3C6}\u{3C1}\u{3C0}\
+    \u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\
+    \u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3B8}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\
+    \u{3C9}\u{2207}\u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\
+    \u{3BB}\u{3BC}\u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3C3}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\
+    \u{3C7}\u{3C8}\u{3C9}\u{2202}\u{3B5}\u{3B8}\u{3BA}\u{3C6}\u{3C1}\u{3C0}\u{3B1}\u{3B2}\
+    \u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\u{3BD}\u{3BE}\
+    \u{3BF}\u{3C0}\u{3C1}\u{3B8}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\u{3C9}\u{2207}\
+    \u{3B1}\u{3B2}\u{3B3}\u{3B4}\u{3B5}\u{3B6}\u{3B7}\u{3B8}\u{3B9}\u{3BA}\u{3BB}\u{3BC}\
+    \u{3BD}\u{3BE}\u{3BF}\u{3C0}\u{3C1}\u{3C3}\u{3C3}\u{3C4}\u{3C5}\u{3C6}\u{3C7}\u{3C8}\
+    \u{3C9}\u{2202}\u{3B5}\u{3B8}\u{3BA}\u{3C6}\u{3C1}\u{3C0}\u{3DD}\u{3DD}\u{30}\u{31}\
+    \u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\u{30}\u{31}\u{32}\u{33}\
+    \u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\
+    \u{36}\u{37}\u{38}\u{39}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\
+    \u{38}\u{39}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\u{39}\
+    \u{430}\u{431}\u{432}\u{433}\u{434}\u{435}\u{436}\u{437}\u{438}\u{43A}\u{43B}\u{43C}\
+    \u{43E}\u{43F}\u{440}\u{441}\u{442}\u{443}\u{444}\u{445}\u{446}\u{447}\u{448}\u{44B}\
+    \u{44D}\u{44E}\u{A689}\u{4D9}\u{456}\u{458}\u{4E9}\u{4AF}\u{4CF}\u{430}\u{431}\u{432}\
+    \u{433}\u{434}\u{435}\u{436}\u{437}\u{438}\u{43A}\u{43B}\u{43E}\u{43F}\u{441}\u{443}\
+    \u{444}\u{445}\u{446}\u{447}\u{448}\u{44A}\u{44B}\u{491}\u{456}\u{455}\u{45F}\u{4AB}\
+    \u{A651}\u{4B1}\u{1E922}\u{1E923}\u{1E924}\u{1E925}\u{1E926}\u{1E927}\u{1E928}\u{1E929}\u{1E92A}\u{1E92B}\
+    \u{1E92C}\u{1E92D}\u{1E92E}\u{1E92F}\u{1E930}\u{1E931}\u{1E932}\u{1E933}\u{1E934}\u{1E935}\u{1E936}\u{1E937}\
+    \u{1E938}\u{1E939}\u{1E93A}\u{1E93B}\u{1E93C}\u{1E93D}\u{1E93E}\u{1E93F}\u{1E940}\u{1E941}\u{1E942}\u{1E943}\
+    \u{627}\u{628}\u{62C}\u{62F}\u{648}\u{632}\u{62D}\u{637}\u{64A}\u{643}\u{644}\u{645}\
+    \u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{631}\u{634}\u{62A}\u{62B}\u{62E}\u{630}\
+    \u{636}\u{638}\u{63A}\u{66E}\u{6BA}\u{6A1}\u{66F}\u{628}\u{62C}\u{647}\u{62D}\u{64A}\
+    \u{643}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{634}\u{62A}\u{62B}\
+    \u{62E}\u{636}\u{63A}\u{62C}\u{62D}\u{64A}\u{644}\u{646}\u{633}\u{639}\u{635}\u{642}\
+    \u{634}\u{62E}\u{636}\u{63A}\u{6BA}\u{66F}\u{628}\u{62C}\u{647}\u{62D}\u{637}\u{64A}\
+    \u{643}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{634}\u{62A}\u{62B}\u{62E}\
+    \u{636}\u{638}\u{63A}\u{66E}\u{6A1}\u{627}\u{628}\u{62C}\u{62F}\u{647}\u{648}\u{632}\
+    \u{62D}\u{637}\u{64A}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{631}\
+    \u{634}\u{62A}\u{62B}\u{62E}\u{630}\u{636}\u{638}\u{63A}\u{628}\u{62C}\u{62F}\u{648}\
+    \u{632}\u{62D}\u{637}\u{64A}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\
+    \u{631}\u{634}\u{62A}\u{62B}\u{62E}\u{630}\u{636}\u{638}\u{63A}\u{30}\u{2E}\u{30}\
+    \u{2C}\u{31}\u{2C}\u{32}\u{2C}\u{33}\u{2C}\u{34}\u{2C}\u{35}\u{2C}\u{36}\
+    \u{2C}\u{37}\u{2C}\u{38}\u{2C}\u{39}\u{2C}\u{28}\u{61}\u{29}\u{28}\u{62}\
+    \u{29}\u{28}\u{63}\u{29}\u{28}\u{64}\u{29}\u{28}\u{65}\u{29}\u{28}\u{66}\
+    \u{29}\u{28}\u{67}\u{29}\u{28}\u{68}\u{29}\u{28}\u{69}\u{29}\u{28}\u{6A}\
+    \u{29}\u{28}\u{6B}\u{29}\u{28}\u{6C}\u{29}\u{28}\u{6D}\u{29}\u{28}\u{6E}\
+    \u{29}\u{28}\u{6F}\u{29}\u{28}\u{70}\u{29}\u{28}\u{71}\u{29}\u{28}\u{72}\
+    \u{29}\u{28}\u{73}\u{29}\u{28}\u{74}\u{29}\u{28}\u{75}\u{29}\u{28}\u{76}\
+    \u{29}\u{28}\u{77}\u{29}\u{28}\u{78}\u{29}\u{28}\u{79}\u{29}\u{28}\u{7A}\
+    \u{29}\u{3014}\u{73}\u{3015}\u{63}\u{72}\u{63}\u{64}\u{77}\u{7A}\u{61}\u{62}\
+    \u{63}\u{64}\u{65}\u{66}\u{67}\u{68}\u{69}\u{6A}\u{6B}\u{6C}\u{6D}\u{6E}\
+    \u{6F}\u{70}\u{71}\u{72}\u{73}\u{74}\u{75}\u{76}\u{77}\u{78}\u{79}\u{7A}\
+    \u{68}\u{76}\u{6D}\u{76}\u{73}\u{64}\u{73}\u{73}\u{70}\u{70}\u{76}\u{77}\
+    \u{63}\u{6D}\u{63}\u{6D}\u{64}\u{6D}\u{72}\u{64}\u{6A}\u{307B}\u{304B}\u{30B3}\
+    \u{30B3}\u{30B5}\u{624B}\u{5B57}\u{53CC}\u{30C7}\u{4E8C}\u{591A}\u{89E3}\u{5929}\u{4EA4}\u{6620}\
+    \u{7121}\u{6599}\u{524D}\u{5F8C}\u{518D}\u{65B0}\u{521D}\u{7D42}\u{751F}\u{8CA9}\u{58F0}\u{5439}\
+    \u{6F14}\u{6295}\u{6355}\u{4E00}\u{4E09}\u{904A}\u{5DE6}\u{4E2D}\u{53F3}\u{6307}\u{8D70}\u{6253}\
+    \u{7981}\u{7A7A}\u{5408}\u{6E80}\u{6709}\u{6708}\u{7533}\u{5272}\u{55B6}\u{914D}\u{3014}\u{672C}\
+    \u{3015}\u{3014}\u{4E09}\u{3015}\u{3014}\u{4E8C}\u{3015}\u{3014}\u{5B89}\u{3015}\u{3014}\u{70B9}\
+    \u{3015}\u{3014}\u{6253}\u{3015}\u{3014}\u{76D7}\u{3015}\u{3014}\u{52DD}\u{3015}\u{3014}\u{6557}\
+    \u{3015}\u{5F97}\u{53EF}\u{30}\u{31}\u{32}\u{33}\u{34}\u{35}\u{36}\u{37}\u{38}\
+    \u{39}\u{4E3D}\u{4E38}\u{4E41}\u{20122}\u{4F60}\u{4FAE}\u{4FBB}\u{5002}\u{507A}\u{5099}\u{50E7}\
+    \u{50CF}\u{349E}\u{2063A}\u{514D}\u{5154}\u{5164}\u{5177}\u{2051C}\u{34B9}\u{5167}\u{518D}\u{2054B}\
+    \u{5197}\u{51A4}\u{4ECC}\u{51AC}\u{51B5}\u{291DF}\u{51F5}\u{5203}\u{34DF}\u{523B}\u{5246}\u{5272}\
+    \u{5277}\u{3515}\u{52C7}\u{52C9}\u{52E4}\u{52FA}\u{5305}\u{5306}\u{5317}\u{5349}\u{5351}\u{535A}\
+    \u{5373}\u{537D}\u{537F}\u{537F}\u{537F}\u{20A2C}\u{7070}\u{53CA}\u{53DF}\u{20B63}\u{53EB}\u{53F1}\
+    \u{5406}\u{549E}\u{5438}\u{5448}\u{5468}\u{54A2}\u{54F6}\u{5510}\u{5553}\u{5563}\u{5584}\u{5584}\
+    \u{5599}\u{55AB}\u{55B3}\u{55C2}\u{5716}\u{5606}\u{5717}\u{5651}\u{5674}\u{5207}\u{58EE}\u{57CE}\
+    \u{57F4}\u{580D}\u{578B}\u{5832}\u{5831}\u{58AC}\u{214E4}\u{58F2}\u{58F7}\u{5906}\u{591A}\u{5922}\
+    \u{5962}\u{216A8}\u{216EA}\u{59EC}\u{5A1B}\u{5A27}\u{59D8}\u{5A66}\u{36EE}\u{36FC}\u{5B08}\u{5B3E}\
+    \u{5B3E}\u{219C8}\u{5BC3}\u{5BD8}\u{5BE7}\u{5BF3}\u{21B18}\u{5BFF}\u{5C06}\u{5F53}\u{5C22}\u{3781}\
+    \u{5C60}\u{5C6E}\u{5CC0}\u{5C8D}\u{21DE4}\u{5D43}\u{21DE6}\u{5D6E}\u{5D6B}\u{5D7C}\u{5DE1}\u{5DE2}\
+    \u{382F}\u{5DFD}\u{5E28}\u{5E3D}\u{5E69}\u{3862}\u{22183}\u{387C}\u{5EB0}\u{5EB3}\u{5EB6}\u{5ECA}\
+    \u{2A392}\u{5EFE}\u{22331}\u{22331}\u{8201}\u{5F22}\u{5F22}\u{38C7}\u{232B8}\u{261DA}\u{5F62}\u{5F6B}\
+    \u{38E3}\u{5F9A}\u{5FCD}\u{5FD7}\u{5FF9}\u{6081}\u{393A}\u{391C}\u{6094}\u{226D4}\u{60C7}\u{6148}\
+    \u{614C}\u{614E}\u{614C}\u{617A}\u{618E}\u{61B2}\u{61A4}\u{61AF}\u{61DE}\u{61F2}\u{61F6}\u{6210}\
+    \u{621B}\u{625D}\u{62B1}\u{62D4}\u{6350}\u{22B0C}\u{633D}\u{62FC}\u{6368}\u{6383}\u{63E4}\u{22BF1}\
+    \u{6422}\u{63C5}\u{63A9}\u{3A2E}\u{6469}\u{647E}\u{649D}\u{6477}\u{3A6C}\u{654F}\u{656C}\u{2300A}\
+    \u{65E3}\u{66F8}\u{6649}\u{3B19}\u{6691}\u{3B08}\u{3AE4}\u{5192}\u{5195}\u{6700}\u{669C}\u{80AD}\
+    \u{43D9}\u{6717}\u{671B}\u{6721}\u{675E}\u{6753}\u{233C3}\u{3B49}\u{67FA}\u{6785}\u{6852}\u{6885}\
+    \u{2346D}\u{688E}\u{681F}\u{6914}\u{3B9D}\u{6942}\u{69A3}\u{69EA}\u{6AA8}\u{236A3}\u{6ADB}\u{3C18}\
+    \u{6B21}\u{238A7}\u{6B54}\u{3C4E}\u{6B72}\u{6B9F}\u{6BBA}\u{6BBB}\u{23A8D}\u{21D0B}\u{23AFA}\u{6C4E}\
+    \u{23CBC}\u{6CBF}\u{6CCD}\u{6C67}\u{6D16}\u{6D3E}\u{6D77}\u{6D41}\u{6D69}\u{6D78}\u{6D85}\u{23D1E}\
+    \u{6D34}\u{6E2F}\u{6E6E}\u{3D33}\u{6ECB}\u{6EC7}\u{23ED1}\u{6DF9}\u{6F6E}\u{23F5E}\u{23F8E}\u{6FC6}\
+    \u{7039}\u{701E}\u{701B}\u{3D96}\u{704A}\u{707D}\u{7077}\u{70AD}\u{20525}\u{7145}\u{24263}\u{719C}\
+    \u{243AB}\u{7228}\u{7235}\u{7250}\u{24608}\u{7280}\u{7295}\u{24735}\u{24814}\u{737A}\u{738B}\u{3EAC}\
+    \u{73A5}\u{3EB8}\u{3EB8}\u{7447}\u{745C}\u{7471}\u{7485}\u{74CA}\u{3F1B}\u{7524}\u{24C36}\u{753E}\
+    \u{24C92}\u{7570}\u{2219F}\u{7610}\u{24FA1}\u{24FB8}\u{25044}\u{3FFC}\u{4008}\u{76F4}\u{250F3}\u{250F2}\
+    \u{25119}\u{25133}\u{771E}\u{771F}\u{771F}\u{774A}\u{4039}\u{778B}\u{4046}\u{4096}\u{2541D}\u{784E}\
+    \u{788C}\u{78CC}\u{40E3}\u{25626}\u{7956}\u{2569A}\u{256C5}\u{798F}\u{79EB}\u{412F}\u{7A40}\u{7A4A}\
+    \u{7A4F}\u{2597C}\u{25AA7}\u{25AA7}\u{7AEE}\u{4202}\u{25BAB}\u{7BC6}\u{7BC9}\u{4227}\u{25C80}\u{7CD2}\
+    \u{42A0}\u{7CE8}\u{7CE3}\u{7D00}\u{25F86}\u{7D63}\u{4301}\u{7DC7}\u{7E02}\u{7E45}\u{4334}\u{26228}\
+    \u{26247}\u{4359}\u{262D9}\u{7F7A}\u{2633E}\u{7F95}\u{7FFA}\u{8005}\u{264DA}\u{26523}\u{8060}\u{265A8}\
+    \u{8070}\u{2335F}\u{43D5}\u{80B2}\u{8103}\u{440B}\u{813E}\u{5AB5}\u{267A7}\u{267B5}\u{23393}\u{2339C}\
+    \u{8201}\u{8204}\u{8F9E}\u{446B}\u{8291}\u{828B}\u{829D}\u{52B3}\u{82B1}\u{82B3}\u{82BD}\u{82E6}\
+    \u{26B3C}\u{82E5}\u{831D}\u{8363}\u{83AD}\u{8323}\u{83BD}\u{83E7}\u{8457}\u{8353}\u{83CA}\u{83CC}\
+    \u{83DC}\u{26C36}\u{26D6B}\u{26CD5}\u{452B}\u{84F1}\u{84F3}\u{8516}\u{273CA}\u{8564}\u{26F2C}\u{455D}\
+    \u{4561}\u{26FB1}\u{270D2}\u{456B}\u{8650}\u{865C}\u{8667}\u{8669}\u{86A9}\u{8688}\u{870E}\u{86E2}\
+    \u{8779}\u{8728}\u{876B}\u{8786}\u{45D7}\u{87E1}\u{8801}\u{45F9}\u{8860}\u{8863}\u{27667}\u{88D7}\
+    \u{88DE}\u{4635}\u{88FA}\u{34BB}\u{278AE}\u{27966}\u{46BE}\u{46C7}\u{8AA0}\u{8AED}\u{8B8A}\u{8C55}\
+    \u{27CA8}\u{8CAB}\u{8CC1}\u{8D1B}\u{8D77}\u{27F2F}\u{20804}\u{8DCB}\u{8DBC}\u{8DF0}\u{208DE}\u{8ED4}\
+    \u{8F38}\u{285D2}\u{285ED}\u{9094}\u{90F1}\u{9111}\u{2872E}\u{911B}\u{9238}\u{92D7}\u{92D8}\u{927C}\
+    \u{93F9}\u{9415}\u{28BFA}\u{958B}\u{4995}\u{95B7}\u{28D77}\u{49E6}\u{96C3}\u{5DB2}\u{9723}\u{29145}\
+    \u{2921A}\u{4A6E}\u{4A76}\u{97E0}\u{2940A}\u{4AB2}\u{29496}\u{980B}\u{980B}\u{9829}\u{295B6}\u{98E2}\
+    \u{4B33}\u{9929}\u{99A7}\u{99C2}\u{99FE}\u{4BCE}\u{29B30}\u{9B12}\u{9C40}\u{9CFD}\u{4CCE}\u{4CED}\
+    \u{9D67}\u{2A0CE}\u{4CF8}\u{2A105}\u{2A20E}\u{2A291}\u{9EBB}\u{4D56}\u{9EF9}\u{9EFE}\u{9F05}\u{9F0F}\
+    \u{9F16}\u{9F3B}\u{2A600}\
+";
@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+
+use unicode_ident::{ident_casefold, ident_eq_ignore_case, is_default_ignorable};
+
+fn casefold(string: &str) -> String {
+    ident_casefold(string).collect()
+}
+
+#[test]
+fn test_casefold() {
+    for (ident, expected) in [
+        ("", ""),
+        ("snake_case", "snake_case"),
+        ("CamelCase", "camelcase"),
+        ("Stra\u{df}e", "strasse"),
+        ("STRA\u{1e9e}E", "strasse"),
+        ("\u{3a3}\u{3b1}\u{3c2}", "\u{3c3}\u{3b1}\u{3c3}"),
+        ("\u{13a0}\u{ab70}", "\u{13a0}\u{13a0}"),
+        ("\u{fb01}le", "file"),
+        ("\u{ff29}\u{ff24}", "id"),
+        ("x\u{2082}", "x2"),
+        ("\u{2163}", "iv"),
+        ("soft\u{ad}ware", "software"),
+        ("\u{130}", "i\u{307}"),
+        ("\u{212b}ngstr\u{f6}m", "\u{e5}ngstr\u{f6}m"),
+        ("A\u{30a}", "\u{e5}"),
+        ("\u{3131}\u{314f}", "\u{ac00}"),
+        ("\u{1100}\u{1161}\u{11a8}", "\u{ac01}"),
+        ("a\u{302}\u{323}", "\u{1ead}"),
+        ("\u{1e0c}\u{307}", "\u{1e0d}\u{307}"),
+    ] {
+        assert_eq!(casefold(ident), expected, "{ident:?}");
+    }
+}
+
+#[test]
+fn test_eq_ignore_case() {
+    for (a, b) in [
+        ("ident", "IDENT"),
+        ("stra\u{df}e", "STRASSE"),
+        ("\u{3c3}\u{3c2}", "\u{3a3}\u{3a3}"),
+        ("\u{e9}t\u{e9}", "E\u{301}T\u{c9}"),
+        ("a\u{323}\u{302}", "a\u{302}\u{323}"),
+        ("\u{1d400}", "a"),
+        ("zero\u{200b}width", "ZEROWIDTH"),
+    ] {
+        assert!(ident_eq_ignore_case(a, b), "{a:?} {b:?}");
+        assert!(ident_eq_ignore_case(b, a), "{b:?} {a:?}");
+    }
+
+    for (a, b) in [
+        ("ident", "idents"),
+        ("i", "\u{131}"),
+        ("a\u{301}\u{300}", "a\u{300}\u{301}"),
+    ] {
+        assert!(!ident_eq_ignore_case(a, b), "{a:?} {b:?}");
+    }
+}
+
+#[test]
+fn test_idempotent() {
+    let mut buf = [0; 4];
+    for ch in '\0'..=char::MAX {
+        let folded = casefold(ch.encode_utf8(&mut buf));
+        assert_eq!(casefold(&folded), folded, "U+{:04X}", ch as u32);
+        assert_eq!(folded.is_empty(), is_default_ignorable(ch));
+    }
+}
@@ -17,6 +17,18 @@ fn compare_all_implementations() {
         let thought_to_be_start = unicode_ident::is_xid_start(ch);
         let thought_to_be_continue = unicode_ident::is_xid_continue(ch);
 
+        // const fn
+        assert_eq!(
+            thought_to_be_start,
+            unicode_ident::is_xid_start_const(ch),
+            "{ch:?}",
+        );
+        assert_eq!(
+            thought_to_be_continue,
+            unicode_ident::is_xid_continue_const(ch),
+            "{ch:?}",
+        );
+
         // unicode-xid
         assert_eq!(
             thought_to_be_start,
@@ -0,0 +1,53 @@
+use unicode_ident::EmojiProfile;
+
+const PROFILE: EmojiProfile = EmojiProfile::new();
+
+#[test]
+fn test_valid() {
+    for ident in [
+        "abc",
+        "\u{1F680}",                                                       // 🚀
+        "\u{1F680}_launch",                                                // 🚀_launch
+        "done_\u{2705}",                                                   // done_✅
+        "\u{2764}\u{FE0F}",                                                // ❤️
+        "\u{1F44D}\u{1F3FD}",                                              // 👍🏽
+        "\u{1F469}\u{200D}\u{1F4BB}",                                      // 👩‍💻
+        "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B0}",                             // 👨🏻‍🦰
+        "\u{1F1EF}\u{1F1F5}",                                              // 🇯🇵
+        "x\u{1F1EF}\u{1F1F5}\u{1F1FA}\u{1F1F8}",                           // x🇯🇵🇺🇸
+        "1\u{FE0F}\u{20E3}",                                               // 1️⃣
+        "level_#\u{FE0F}\u{20E3}",                                         // level_#️⃣
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}", // 🏴󠁧󠁢󠁳󠁣󠁴󠁿
+        "\u{1FAE9}", // FACE WITH BAGS UNDER EYES, Emoji 16.0
+    ] {
+        assert!(PROFILE.is_valid(ident), "{ident:?}");
+        assert_eq!(PROFILE.scan(ident), ident.len());
+    }
+}
+
+#[test]
+fn test_invalid() {
+    for (ident, offset, ch) in [
+        ("", 0, None),
+        ("1", 0, Some('1')),
+        ("#", 0, Some('#')),
+        ("1\u{20E3}", 0, Some('1')),
+        ("_\u{1F680}", 0, Some('_')),
+        ("a\u{20E3}", 1, Some('\u{20E3}')),
+        ("a\u{1F3FB}b\u{E0067}", 6, Some('\u{E0067}')),
+        ("\u{1F3F4}\u{E0067}\u{E0062}", 4, Some('\u{E0067}')),
+        ("a-b", 1, Some('-')),
+    ] {
+        assert!(!PROFILE.is_valid(ident), "{ident:?}");
+        let err = PROFILE.validate(ident).unwrap_err();
+        assert_eq!(err.offset(), offset, "{ident:?}");
+        assert_eq!(err.ch(), ch, "{ident:?}");
+    }
+}
+
+#[test]
+fn test_scan() {
+    assert_eq!(PROFILE.scan("\u{1F680} = 1"), 4);
+    assert_eq!(PROFILE.scan("x\u{1F469}\u{1F3FB}+"), 9);
+    assert_eq!(PROFILE.scan("1\u{FE0F}\u{20E3}x"), 8);
+}
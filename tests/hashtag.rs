@@ -0,0 +1,94 @@
+use unicode_ident::hashtag::{
+    is_hashtag, is_hashtag_continue, is_hashtag_start, scan_hashtag, validate_hashtag,
+};
+use unicode_ident::is_xid_continue;
+
+#[test]
+fn test_start() {
+    for ch in '\0'..=char::MAX {
+        let expected = matches!(ch, '#' | '\u{fe5f}' | '\u{ff03}');
+        assert_eq!(is_hashtag_start(ch), expected, "{ch:?}");
+        if expected {
+            assert!(!is_hashtag_continue(ch), "{ch:?}");
+        }
+    }
+}
+
+#[test]
+fn test_continue() {
+    for ch in '\0'..=char::MAX {
+        if is_xid_continue(ch) {
+            assert!(is_hashtag_continue(ch), "{ch:?}");
+        }
+    }
+    for ch in [
+        '-',
+        '+',
+        '_',
+        '*',
+        '0',
+        '\u{a9}',
+        '\u{200d}',
+        '\u{20e3}',
+        '\u{fe0f}',
+        '\u{1f1ef}',
+        '\u{1f3fb}',
+        '\u{1f680}',
+        '\u{1f9b0}',
+        '\u{e0067}',
+        '\u{e007f}',
+    ] {
+        assert!(is_hashtag_continue(ch), "{ch:?}");
+    }
+    for ch in [
+        ' ',
+        '.',
+        ',',
+        '!',
+        '#',
+        '@',
+        '\u{a0}',
+        '\u{2026}',
+        '\u{e0080}',
+    ] {
+        assert!(!is_hashtag_continue(ch), "{ch:?}");
+    }
+}
+
+#[test]
+fn test_hashtag() {
+    for tag in [
+        "#",
+        "#rust",
+        "#2024",
+        "#M\u{f6}tleyCr\u{fc}e",
+        "#C++",
+        "#well-known",
+        "\u{ff03}\u{65e5}\u{672c}",
+        "\u{fe5f}tag",
+        "#\u{1f680}launch",
+        "#\u{1f469}\u{200d}\u{1f4bb}",
+        "#1\u{fe0f}\u{20e3}",
+    ] {
+        assert!(is_hashtag(tag), "{tag:?}");
+    }
+    for (tag, offset, ch) in [
+        ("", 0, None),
+        ("rust", 0, Some('r')),
+        ("##", 1, Some('#')),
+        ("#a#b", 2, Some('#')),
+        ("#a.b", 2, Some('.')),
+    ] {
+        let err = validate_hashtag(tag).unwrap_err();
+        assert_eq!(err.offset(), offset, "{tag:?}");
+        assert_eq!(err.ch(), ch, "{tag:?}");
+    }
+}
+
+#[test]
+fn test_scan() {
+    assert_eq!(scan_hashtag("#rust, #unicode"), 5);
+    assert_eq!(scan_hashtag("#rust"), 5);
+    assert_eq!(scan_hashtag("# rust"), 1);
+    assert_eq!(scan_hashtag("rust"), 0);
+}
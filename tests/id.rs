@@ -0,0 +1,31 @@
+use unicode_ident::{is_id_continue, is_id_start, is_xid_continue, is_xid_start};
+
+// Characters that are removed from ID_Start and ID_Continue in order to make
+// XID_Start and XID_Continue closed under NFKC normalization.
+// https://www.unicode.org/reports/tr31/#NFKC_Modifications
+const NOT_XID_START: &[char] = &[
+    '\u{037A}', '\u{0E33}', '\u{0EB3}', '\u{309B}', '\u{309C}', '\u{FC5E}', '\u{FC5F}', '\u{FC60}',
+    '\u{FC61}', '\u{FC62}', '\u{FC63}', '\u{FDFA}', '\u{FDFB}', '\u{FE70}', '\u{FE72}', '\u{FE74}',
+    '\u{FE76}', '\u{FE78}', '\u{FE7A}', '\u{FE7C}', '\u{FE7E}', '\u{FF9E}', '\u{FF9F}',
+];
+const NOT_XID_CONTINUE: &[char] = &[
+    '\u{037A}', '\u{309B}', '\u{309C}', '\u{FC5E}', '\u{FC5F}', '\u{FC60}', '\u{FC61}', '\u{FC62}',
+    '\u{FC63}', '\u{FDFA}', '\u{FDFB}', '\u{FE70}', '\u{FE72}', '\u{FE74}', '\u{FE76}', '\u{FE78}',
+    '\u{FE7A}', '\u{FE7C}', '\u{FE7E}',
+];
+
+#[test]
+fn test_id_vs_xid() {
+    for ch in '\0'..=char::MAX {
+        assert_eq!(
+            is_id_start(ch),
+            is_xid_start(ch) || NOT_XID_START.contains(&ch),
+            "{ch:?}",
+        );
+        assert_eq!(
+            is_id_continue(ch),
+            is_xid_continue(ch) || NOT_XID_CONTINUE.contains(&ch),
+            "{ch:?}",
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use unicode_ident::{is_ident, is_ident_str, scan_ident, scan_ident_utf8, validate_ident};
+
+#[test]
+fn test_valid() {
+    for ident in [
+        "a",
+        "abc",
+        "a_b",
+        "a1",
+        "x\u{301}",
+        "\u{e9}t\u{e9}",
+        "\u{4e2d}\u{6587}",
+        "\u{3b1}\u{3b2}",
+    ] {
+        assert!(is_ident(ident), "{ident:?}");
+        assert_eq!(validate_ident(ident), Ok(()));
+    }
+}
+
+#[test]
+fn test_empty() {
+    let err = validate_ident("").unwrap_err();
+    assert_eq!(err.offset(), 0);
+    assert_eq!(err.ch(), None);
+    assert_eq!(err.to_string(), "empty identifier");
+}
+
+#[test]
+fn test_invalid_start() {
+    for (ident, ch) in [
+        ("_", '_'),
+        ("_a", '_'),
+        ("1a", '1'),
+        ("\u{301}x", '\u{301}'),
+        (" a", ' '),
+    ] {
+        assert!(!is_ident(ident), "{ident:?}");
+        let err = validate_ident(ident).unwrap_err();
+        assert_eq!(err.offset(), 0);
+        assert_eq!(err.ch(), Some(ch));
+    }
+    assert_eq!(
+        validate_ident("1a").unwrap_err().to_string(),
+        "identifier cannot start with '1'",
+    );
+}
+
+#[test]
+fn test_invalid_continue() {
+    for (ident, offset, ch) in [
+        ("a-b", 1, '-'),
+        ("ab ", 2, ' '),
+        ("\u{e9}\u{e9}$", 4, '$'),
+        ("a\u{1f980}", 1, '\u{1f980}'),
+    ] {
+        assert!(!is_ident(ident), "{ident:?}");
+        let err = validate_ident(ident).unwrap_err();
+        assert_eq!(err.offset(), offset);
+        assert_eq!(err.ch(), Some(ch));
+    }
+    assert_eq!(
+        validate_ident("a-b").unwrap_err().to_string(),
+        "identifier cannot contain '-' (at byte offset 1)",
+    );
+}
+
+fn naive_scan(string: &str) -> usize {
+    let mut chars = string.char_indices();
+    match chars.next() {
+        Some((_, ch)) if unicode_ident::is_xid_start(ch) => {}
+        _ => return 0,
+    }
+    for (i, ch) in chars {
+        if !unicode_ident::is_xid_continue(ch) {
+            return i;
+        }
+    }
+    string.len()
+}
+
+#[test]
+fn test_scan() {
+    assert_eq!(scan_ident(""), 0);
+    assert_eq!(scan_ident("_"), 0);
+    assert_eq!(scan_ident("a"), 1);
+    assert_eq!(scan_ident("abc def"), 3);
+    assert_eq!(scan_ident("long_identifier_name_0123456789.len()"), 31);
+    assert_eq!(scan_ident("x\u{301}\u{4e2d}\u{6587}_\u{1f980}"), 10);
+    assert_eq!(scan_ident_utf8(b"ab\xFFcd"), 2);
+    assert_eq!(scan_ident_utf8(b"abcdefghij\xE4\xB8"), 10);
+}
+
+#[test]
+fn test_scan_every_ascii_position() {
+    for byte in 0u8..0x80 {
+        for prefix_len in 0..70 {
+            let mut string = "x".repeat(prefix_len + 1);
+            string.push(byte as char);
+            string.push_str("yz\u{e9}\u{4e2d}w+");
+            assert_eq!(scan_ident(&string), naive_scan(&string), "{string:?}");
+            string.insert(prefix_len, '\u{e9}');
+            assert_eq!(scan_ident(&string), naive_scan(&string), "{string:?}");
+        }
+    }
+}
+
+#[test]
+fn test_const() {
+    const VALID: [bool; 4] = [
+        is_ident_str("a"),
+        is_ident_str("x\u{301}"),
+        is_ident_str("\u{4e2d}\u{6587}"),
+        is_ident_str("\u{10480}\u{104a0}"),
+    ];
+    const INVALID: [bool; 4] = [
+        is_ident_str(""),
+        is_ident_str("_a"),
+        is_ident_str("a-b"),
+        is_ident_str("a\u{1f980}"),
+    ];
+    assert_eq!(VALID, [true; 4]);
+    assert_eq!(INVALID, [false; 4]);
+
+    for string in [
+        "a",
+        "\u{e9}\u{e9}",
+        "\u{301}",
+        "\u{10ffff}",
+        "z\u{1f600}",
+        "\u{aa}b1_",
+    ] {
+        assert_eq!(is_ident_str(string), is_ident(string), "{string:?}");
+    }
+}
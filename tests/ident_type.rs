@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+use unicode_ident::Ident;
+
+const NAME: &Ident = Ident::new_const("\u{3bb}_expr");
+
+#[test]
+fn test_ident() {
+    let ident = Ident::new("foo_bar").unwrap();
+    assert_eq!(ident, "foo_bar");
+    assert_eq!(ident.as_str(), "foo_bar");
+    assert!(ident.starts_with("foo"));
+    assert_eq!(ident.to_string(), "foo_bar");
+    assert_eq!(format!("{ident:?}"), "\"foo_bar\"");
+    assert_eq!(NAME, "\u{3bb}_expr");
+
+    let ident: &Ident = "x1".try_into().unwrap();
+    assert_eq!(ident, "x1");
+
+    for (string, offset) in [("", 0), ("1", 0), ("a-b", 1)] {
+        let err = Ident::new(string).unwrap_err();
+        assert_eq!(err.offset(), offset, "{string:?}");
+    }
+}
+
+#[test]
+fn test_borrow() {
+    let set: HashSet<&Ident> = ["a", "b"]
+        .into_iter()
+        .map(|s| Ident::new(s).unwrap())
+        .collect();
+    assert!(set.contains(Ident::new("a").unwrap()));
+}
+
+#[cfg(feature = "alloc")]
+mod buf {
+    use std::collections::HashSet;
+    use unicode_ident::{Ident, IdentBuf};
+
+    #[test]
+    fn test_ident_buf() {
+        let mut ident: IdentBuf = "field".parse().unwrap();
+        ident.push('_').unwrap();
+        ident.push_str("0\u{301}").unwrap();
+        ident.push_ident(Ident::new("x").unwrap());
+        assert_eq!(ident, "field_0\u{301}x");
+        assert_eq!(ident.len(), 10);
+
+        let err = ident.push('-').unwrap_err();
+        assert_eq!(err.offset(), 10);
+        assert_eq!(err.ch(), Some('-'));
+        let err = ident.push_str("a b").unwrap_err();
+        assert_eq!(err.offset(), 11);
+        assert_eq!(ident, "field_0\u{301}x");
+
+        assert!("1".parse::<IdentBuf>().is_err());
+        assert!(IdentBuf::new(String::new()).is_err());
+        assert_eq!(String::from(ident.clone()), "field_0\u{301}x");
+        assert_eq!(IdentBuf::try_from("a").unwrap().into_string(), "a");
+    }
+
+    #[test]
+    fn test_borrow() {
+        let ident = Ident::new("a").unwrap();
+        let owned: IdentBuf = ident.to_owned();
+        assert_eq!(owned, ident);
+
+        let set: HashSet<IdentBuf> = HashSet::from([owned]);
+        assert!(set.contains("a"));
+        assert!(set.contains(ident));
+    }
+}
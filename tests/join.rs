@@ -0,0 +1,63 @@
+use unicode_ident::{is_ident, JoinControlProfile};
+
+const PROFILE: JoinControlProfile = JoinControlProfile::new();
+
+#[test]
+fn test_default_syntax() {
+    // Since Unicode 15.1, ZWNJ and ZWJ are XID_Continue without conditions.
+    assert!(is_ident("a\u{200c}b"));
+    assert!(is_ident("a\u{200d}"));
+    assert!(!is_ident("\u{200c}a"));
+}
+
+#[test]
+fn test_cursive_connection() {
+    for ident in [
+        // می‌خواهم
+        "\u{645}\u{6cc}\u{200c}\u{62e}\u{648}\u{627}\u{647}\u{645}",
+        // Transparent marks on either side: بَ‌ٌب
+        "\u{628}\u{64e}\u{200c}\u{64c}\u{628}",
+    ] {
+        assert!(PROFILE.is_valid(ident), "{ident:?}");
+    }
+    for (ident, offset) in [
+        // ALEF is right-joining only, so there is no connection to break.
+        ("\u{627}\u{200c}\u{628}", 2),
+        // Nothing to join to on the right.
+        ("\u{628}\u{200c}", 2),
+        ("\u{628}\u{200c}a", 2),
+        // ZWJ is not allowed in this context.
+        ("\u{628}\u{200d}\u{628}", 2),
+    ] {
+        let err = PROFILE.validate(ident).unwrap_err();
+        assert_eq!(err.offset(), offset, "{ident:?}");
+    }
+}
+
+#[test]
+fn test_conjunct() {
+    for ident in [
+        "\u{915}\u{94d}\u{200d}\u{937}",        // क्‍ष
+        "\u{915}\u{94d}\u{200c}\u{937}",        // क्‌ष
+        "\u{915}\u{93c}\u{94d}\u{200c}\u{937}", // nukta between letter and virama
+        "\u{d15}\u{d4d}\u{200d}",               // ക്‍
+        "\u{1780}\u{17d2}\u{200c}\u{1780}",     // Khmer COENG as virama
+    ] {
+        assert!(PROFILE.is_valid(ident), "{ident:?}");
+    }
+    for (ident, offset) in [
+        ("\u{915}\u{200d}\u{937}", 3),
+        ("a\u{200c}b", 1),
+        ("a\u{200d}", 1),
+        ("1\u{94d}\u{200d}", 0),
+    ] {
+        let err = PROFILE.validate(ident).unwrap_err();
+        assert_eq!(err.offset(), offset, "{ident:?}");
+    }
+}
+
+#[test]
+fn test_scan() {
+    assert_eq!(PROFILE.scan("ab\u{200c}cd"), 2);
+    assert_eq!(PROFILE.scan("\u{915}\u{94d}\u{200d}\u{937} = 1"), 12);
+}
@@ -0,0 +1,143 @@
+use unicode_ident::Language;
+
+#[test]
+fn test_rust() {
+    let rust = Language::Rust;
+    for ident in [
+        "a",
+        "_a",
+        "__",
+        "r#async",
+        "r#r",
+        "\u{3c0}",
+        "\u{441}\u{43b}\u{43e}\u{43d}",
+    ] {
+        assert!(rust.is_valid(ident), "{ident:?}");
+    }
+    for (ident, offset) in [
+        ("_", 0),
+        ("r#_", 1),
+        ("r#", 1),
+        ("r#1", 1),
+        ("1a", 0),
+        ("a-b", 1),
+    ] {
+        let err = rust.validate(ident).unwrap_err();
+        assert_eq!(err.offset(), offset, "{ident:?}");
+    }
+}
+
+#[test]
+fn test_ecmascript() {
+    let js = Language::EcmaScript;
+    for ident in [
+        "$",
+        "_",
+        "$scope",
+        "a\u{200d}",
+        "\\u0061",
+        "a\\u{62}c",
+        "\\u{00000061}",
+        // U+037A GREEK YPOGEGRAMMENI is ID_Start but not XID_Start.
+        "\u{37a}",
+    ] {
+        assert!(js.is_valid(ident), "{ident:?}");
+    }
+    for (ident, offset) in [
+        ("\\u0031", 0),
+        ("a\\u002d", 1),
+        ("a\\u{110000}", 1),
+        ("a\\u{}", 1),
+        ("a\\u12", 1),
+        ("a\\U00000062", 1),
+        ("a-b", 1),
+    ] {
+        let err = js.validate(ident).unwrap_err();
+        assert_eq!(err.offset(), offset, "{ident:?}");
+    }
+}
+
+#[test]
+fn test_c() {
+    for lang in [Language::C, Language::Cpp] {
+        for ident in ["_", "a1", "\\u03c0", "x\\U0001D4B3", "\u{3c0}"] {
+            assert!(lang.is_valid(ident), "{lang:?} {ident:?}");
+        }
+        for ident in ["$a", "1a", "\\u0031", "\\u037a"] {
+            assert!(!lang.is_valid(ident), "{lang:?} {ident:?}");
+        }
+        // Universal character names below U+00A0 are not allowed.
+        for (ident, offset) in [
+            ("\\u0061bc", 0),
+            ("a\\u005f", 1),
+            ("a\\U00000062", 1),
+            ("a\\u0024", 1),
+        ] {
+            let err = lang.validate(ident).unwrap_err();
+            assert_eq!(err.offset(), offset, "{lang:?} {ident:?}");
+        }
+    }
+    assert!(!Language::Cpp.is_valid("\\u{61}"));
+    assert!(!Language::Cpp.is_valid("a\\u{7f}"));
+    assert!(Language::Cpp.is_valid("\\u{3c0}"));
+    assert!(!Language::C.is_valid("\\u{3c0}"));
+    assert!(Language::Python.is_valid("_\u{3c0}"));
+    assert!(!Language::Python.is_valid("\\u03c0"));
+}
+
+#[test]
+fn test_go() {
+    let go = Language::Go;
+    let mut start = 0;
+    let mut continue_ = 0;
+    for ch in '\0'..=char::MAX {
+        if go.is_start(ch) {
+            start += 1;
+            assert!(go.is_continue(ch), "{ch:?}");
+        }
+        if go.is_continue(ch) {
+            continue_ += 1;
+        }
+    }
+    // Letters, plus '_'.
+    assert_eq!(start, 145_673);
+    // Decimal numbers.
+    assert_eq!(continue_ - start, 770);
+
+    for ident in ["_x9", "\u{3b1}\u{3b2}", "x\u{661}"] {
+        assert!(go.is_valid(ident), "{ident:?}");
+    }
+    // Go has no combining marks or letter numbers in identifiers.
+    for ident in ["e\u{301}", "\u{2160}", "x\u{b7}"] {
+        assert!(!go.is_valid(ident), "{ident:?}");
+    }
+}
+
+#[test]
+fn test_java() {
+    let java = Language::Java;
+    let mut start = 0;
+    let mut continue_ = 0;
+    for ch in '\0'..=char::MAX {
+        if java.is_start(ch) {
+            start += 1;
+            assert!(java.is_continue(ch), "{ch:?}");
+        }
+        if java.is_continue(ch) {
+            continue_ += 1;
+        }
+    }
+    // Letters, letter numbers, currency symbols and connector punctuation.
+    assert_eq!(start, 145_985);
+    // Decimal numbers, marks, format characters and ignorable controls.
+    assert_eq!(continue_ - start, 3526);
+
+    for ident in [
+        "$", "_", "\u{a2}x", "\u{2160}", "e\u{301}", "a\u{ad}b", "a\u{0}",
+    ] {
+        assert!(java.is_valid(ident), "{ident:?}");
+    }
+    for ident in ["1", "\u{301}", "a-b", "a\u{b7}"] {
+        assert!(!java.is_valid(ident), "{ident:?}");
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::Cow;
+use unicode_ident::{decode_ident, encode_ident, is_ident};
+
+#[test]
+fn test_unescaped() {
+    for ident in ["a", "snake_case", "CamelCase", "a1", "u", "u1", "uu_"] {
+        let encoded = encode_ident(ident);
+        assert!(matches!(encoded, Cow::Borrowed(_)), "{ident:?}");
+        assert_eq!(encoded, ident);
+        assert!(matches!(decode_ident(ident), Some(Cow::Borrowed(_))));
+    }
+}
+
+#[test]
+fn test_punycode() {
+    // From RFC 3492 section 7.1, with the delimiter replaced by '_'.
+    for (string, expected) in [
+        ("m\u{fc}nchen", "u_mnchen_3ya"),
+        (
+            "\u{644}\u{64a}\u{647}\u{645}\u{627}\u{628}\u{62a}\u{643}\u{644}\u{645}\u{648}\u{634}\u{639}\u{631}\u{628}\u{64a}\u{61f}",
+            "u_egbpdaj6bu4bxfgehfvwxn",
+        ),
+        (
+            "\u{4ed6}\u{4eec}\u{4e3a}\u{4ec0}\u{4e48}\u{4e0d}\u{8bf4}\u{4e2d}\u{6587}",
+            "u_ihqwcrb4cv8a8dqg056pqjye",
+        ),
+        (
+            "3\u{5e74}B\u{7d44}\u{91d1}\u{516b}\u{5148}\u{751f}",
+            "u_3B_ww4c5e180e575a65lsy2b",
+        ),
+    ] {
+        assert_eq!(encode_ident(string), expected, "{string:?}");
+        assert_eq!(decode_ident(expected).unwrap(), string, "{expected:?}");
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for string in [
+        "",
+        "_",
+        "1",
+        "u_",
+        "u_u_",
+        "_private",
+        "kebab-case",
+        "first name",
+        "a.b::c",
+        "\u{1f980} crab",
+        "\u{0}\u{7f}",
+        "\u{10ffff}",
+        "\u{3c0}",
+        "\u{e9}t\u{e9}",
+    ] {
+        let encoded = encode_ident(string);
+        assert!(is_ident(&encoded), "{string:?} {encoded:?}");
+        assert!(encoded.is_ascii(), "{string:?} {encoded:?}");
+        assert_eq!(decode_ident(&encoded).unwrap(), string, "{encoded:?}");
+    }
+}
+
+#[test]
+fn test_invalid() {
+    for ident in [
+        "",
+        "_",
+        "1",
+        "a-b",
+        "\u{3c0}",
+        // Uppercase Punycode digits are not what encode_ident produces.
+        "u_mnchen_3YA",
+        // Basic code points that did not need encoding.
+        "u_abc_",
+        "u_mnchen_",
+        "u_mnchen_3y",
+        "u_mnchen_3y!",
+        "u_zzzzzzzzzzzzzz",
+    ] {
+        assert_eq!(decode_ident(ident), None, "{ident:?}");
+    }
+}
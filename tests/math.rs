@@ -0,0 +1,55 @@
+use unicode_ident::{is_id_compat_math_continue, is_id_compat_math_start, MathProfile};
+
+#[test]
+fn test_id_compat_math() {
+    let mut start = 0;
+    let mut continue_ = 0;
+    for ch in '\0'..=char::MAX {
+        if is_id_compat_math_start(ch) {
+            start += 1;
+            assert!(is_id_compat_math_continue(ch), "{ch:?}");
+        }
+        if is_id_compat_math_continue(ch) {
+            continue_ += 1;
+        }
+    }
+    assert_eq!(start, 13);
+    assert_eq!(continue_, 43);
+
+    for ch in ['\u{2202}', '\u{2207}', '\u{221e}', '\u{1d6c1}'] {
+        assert!(is_id_compat_math_start(ch), "{ch:?}");
+    }
+    for ch in ['\u{b2}', '\u{2070}', '\u{207a}', '\u{2081}', '\u{208e}'] {
+        assert!(!is_id_compat_math_start(ch), "{ch:?}");
+        assert!(is_id_compat_math_continue(ch), "{ch:?}");
+    }
+    for ch in ['a', '1', '+', '\u{2200}', '\u{2071}'] {
+        assert!(!is_id_compat_math_continue(ch), "{ch:?}");
+    }
+}
+
+#[test]
+fn test_profile() {
+    let profile = MathProfile::new();
+    for ident in [
+        "x",
+        "\u{2202}x",
+        "\u{2207}\u{3c6}",
+        "x\u{2081}",
+        "x\u{2099}\u{208a}\u{2081}",
+        "\u{221e}",
+    ] {
+        assert!(profile.is_valid(ident), "{ident:?}");
+    }
+    for (ident, offset, ch) in [
+        ("", 0, None),
+        ("\u{2081}", 0, Some('\u{2081}')),
+        ("_\u{2202}", 0, Some('_')),
+        ("x\u{2032}", 1, Some('\u{2032}')),
+    ] {
+        let err = profile.validate(ident).unwrap_err();
+        assert_eq!(err.offset(), offset, "{ident:?}");
+        assert_eq!(err.ch(), ch, "{ident:?}");
+    }
+    assert_eq!(profile.scan("\u{2202}f/\u{2202}x"), 4);
+}
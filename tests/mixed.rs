@@ -0,0 +1,67 @@
+use unicode_ident::{mixed_script_info, AugmentedScriptSet, Script, ScriptSet};
+
+#[test]
+fn test_single_script() {
+    for ident in [
+        "",
+        "_",
+        "x1",
+        "snake_case",
+        "\u{3b1}\u{3b2}\u{3b3}",                            // αβγ
+        "\u{43f}\u{440}\u{438}\u{432}\u{435}\u{442}",       // привет
+        "\u{6771}\u{4eac}\u{30bf}\u{30ef}\u{30fc}",         // 東京タワー
+        "\u{3072}\u{3089}\u{304c}\u{306a}\u{6f22}\u{5b57}", // ひらがな漢字
+        "\u{d55c}\u{ad6d}\u{c5b4}\u{6f22}\u{5b57}",         // 한국어漢字
+        "\u{3105}\u{3106}\u{6f22}",                         // ㄅㄆ漢
+    ] {
+        assert!(mixed_script_info(ident).is_single_script(), "{ident:?}");
+    }
+}
+
+#[test]
+fn test_mixed_script() {
+    for ident in [
+        "p\u{430}ypal",     // CYRILLIC SMALL LETTER A
+        "\u{3b1}lpha",      // GREEK SMALL LETTER ALPHA
+        "\u{3072}\u{d55c}", // ひ한
+        "\u{30bf}\u{3105}", // タㄅ
+        "x\u{6f22}",        // x漢
+    ] {
+        assert!(mixed_script_info(ident).is_mixed_script(), "{ident:?}");
+    }
+}
+
+#[test]
+fn test_scripts() {
+    let info = mixed_script_info("p\u{430}ypal_1");
+    assert_eq!(
+        info.scripts(),
+        ScriptSet::from_iter([Script::Latin, Script::Cyrillic]),
+    );
+    assert!(info.resolved().is_empty());
+
+    let info = mixed_script_info("\u{6771}\u{4eac}\u{30bf}\u{30ef}\u{30fc}");
+    assert_eq!(
+        info.scripts(),
+        ScriptSet::from_iter([Script::Han, Script::Hiragana, Script::Katakana]),
+    );
+    let resolved = info.resolved();
+    assert!(resolved.contains_jpan());
+    assert!(!resolved.contains_kore());
+    assert!(!resolved.contains(Script::Han));
+    assert_eq!(format!("{resolved:?}"), "{Jpan}");
+}
+
+#[test]
+fn test_all() {
+    let info = mixed_script_info("_123");
+    assert!(info.resolved().is_all());
+    assert!(info.scripts().is_empty());
+    assert_eq!(info.resolved(), AugmentedScriptSet::ALL);
+    assert_eq!(format!("{:?}", AugmentedScriptSet::ALL), "ALL");
+
+    let han = AugmentedScriptSet::for_char('\u{6f22}');
+    assert_eq!(AugmentedScriptSet::ALL.intersection(&han), han);
+    assert!(han.contains(Script::Han));
+    assert!(han.contains_hanb() && han.contains_jpan() && han.contains_kore());
+}
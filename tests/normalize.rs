@@ -0,0 +1,167 @@
+use unicode_ident::QuickCheck::{Maybe, No, Yes};
+use unicode_ident::{is_nfc_quick_check, is_nfkc_quick_check};
+
+#[test]
+fn test_quick_check() {
+    for (string, nfc, nfkc) in [
+        ("", Yes, Yes),
+        ("ident", Yes, Yes),
+        ("caf\u{e9}", Yes, Yes),
+        ("cafe\u{301}", Maybe, Maybe),
+        ("\u{fb01}le", Yes, No),
+        ("\u{212b}", No, No),
+        // In NFC, but U+0302 could compose with a preceding character.
+        ("a\u{323}\u{302}", Maybe, Maybe),
+        ("a\u{302}\u{323}", No, No),
+        ("\u{ac00}", Yes, Yes),
+        ("\u{1100}\u{1161}", Maybe, Maybe),
+        ("x\u{2082}", Yes, No),
+        // U+113C5 decomposes to U+113C2 U+113C2, which composes with U+1138B.
+        ("\u{1138b}\u{113c5}", Maybe, Maybe),
+        ("\u{1611e}\u{16121}", Maybe, Maybe),
+        ("\u{16d67}\u{16d68}", Maybe, Maybe),
+        ("\u{301}\u{212b}", No, No),
+    ] {
+        assert_eq!(is_nfc_quick_check(string), nfc, "{string:?}");
+        assert_eq!(is_nfkc_quick_check(string), nfkc, "{string:?}");
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    use std::borrow::Cow;
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::path::Path;
+    use unicode_ident::{
+        is_nfc_quick_check, is_nfkc_quick_check, is_xid_continue, is_xid_start,
+        normalize_ident_nfc, normalize_ident_nfkc, QuickCheck,
+    };
+
+    #[test]
+    fn test_normalize() {
+        for (string, nfc, nfkc) in [
+            ("ident", "ident", "ident"),
+            ("cafe\u{301}", "caf\u{e9}", "caf\u{e9}"),
+            ("\u{fb01}le", "\u{fb01}le", "file"),
+            ("\u{212b}", "\u{c5}", "\u{c5}"),
+            ("a\u{302}\u{323}", "\u{1ead}", "\u{1ead}"),
+            ("\u{1100}\u{1161}\u{11a8}", "\u{ac01}", "\u{ac01}"),
+            ("\u{3131}\u{314f}", "\u{3131}\u{314f}", "\u{ac00}"),
+            ("\u{1e9b}\u{323}", "\u{1e9b}\u{323}", "\u{1e69}"),
+            ("\u{ff21}\u{ff22}", "\u{ff21}\u{ff22}", "AB"),
+            (
+                "\u{1138b}\u{113c5}",
+                "\u{1138e}\u{113c2}",
+                "\u{1138e}\u{113c2}",
+            ),
+        ] {
+            assert_eq!(normalize_ident_nfc(string), nfc, "{string:?}");
+            assert_eq!(normalize_ident_nfkc(string), nfkc, "{string:?}");
+        }
+    }
+
+    #[test]
+    fn test_borrowed() {
+        for string in ["ident", "caf\u{e9}", "\u{1ead}", "\u{e0}\u{301}"] {
+            assert!(matches!(normalize_ident_nfc(string), Cow::Borrowed(_)));
+            assert!(matches!(normalize_ident_nfkc(string), Cow::Borrowed(_)));
+        }
+    }
+
+    #[test]
+    fn test_every_char() {
+        let mut buf = [0; 4];
+        for ch in '\0'..=char::MAX {
+            let string = &*ch.encode_utf8(&mut buf);
+            let composed = normalize_ident_nfc(string);
+            let compatible = normalize_ident_nfkc(string);
+            assert_eq!(normalize_ident_nfc(&composed), composed);
+            assert_eq!(normalize_ident_nfkc(&compatible), compatible);
+            assert_eq!(normalize_ident_nfc(&compatible), compatible);
+            if is_nfc_quick_check(string) == QuickCheck::Yes {
+                assert!(matches!(composed, Cow::Borrowed(_)));
+            }
+            if is_nfkc_quick_check(string) == QuickCheck::Yes {
+                assert!(matches!(compatible, Cow::Borrowed(_)));
+            }
+
+            // XID_Start and XID_Continue are closed under NFKC.
+            let mut chars = compatible.chars();
+            if is_xid_start(ch) {
+                assert!(chars.next().is_some_and(is_xid_start), "{ch:?}");
+            }
+            if is_xid_continue(ch) {
+                assert!(chars.all(is_xid_continue), "{ch:?}");
+            }
+        }
+    }
+
+    // Requires UCD/NormalizationTest.txt, downloaded as described at the top
+    // of generate/src/main.rs.
+    #[test]
+    #[ignore = "requires UCD/NormalizationTest.txt"]
+    fn test_conformance() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("UCD/NormalizationTest.txt");
+        let contents = fs::read_to_string(path).unwrap();
+        let mut part1 = BTreeSet::new();
+        let mut in_part1 = false;
+        for line in contents.lines() {
+            if let Some(part) = line.strip_prefix('@') {
+                in_part1 = part.starts_with("Part1 ");
+                continue;
+            }
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let columns: Vec<String> = line
+                .split(';')
+                .take(5)
+                .map(|column| {
+                    column
+                        .split(' ')
+                        .map(|hex| u32::from_str_radix(hex, 16).unwrap())
+                        .map(|code| char::from_u32(code).unwrap())
+                        .collect()
+                })
+                .collect();
+            let [c1, c2, c3, c4, c5] = &columns[..] else {
+                panic!("malformed line: {line}");
+            };
+            if in_part1 {
+                part1.insert(c1.chars().next().unwrap());
+            }
+            for string in [c1, c2, c3] {
+                assert_eq!(normalize_ident_nfc(string), **c2, "{string:?}");
+            }
+            for string in [c4, c5] {
+                assert_eq!(normalize_ident_nfc(string), **c4, "{string:?}");
+            }
+            for string in &columns {
+                assert_eq!(normalize_ident_nfkc(string), **c4, "{string:?}");
+                let composed = normalize_ident_nfc(string) == **string;
+                match is_nfc_quick_check(string) {
+                    QuickCheck::Yes => assert!(composed, "{string:?}"),
+                    QuickCheck::No => assert!(!composed, "{string:?}"),
+                    QuickCheck::Maybe => {}
+                }
+                match is_nfkc_quick_check(string) {
+                    QuickCheck::Yes => assert_eq!(string, c4, "{string:?}"),
+                    QuickCheck::No => assert_ne!(string, c4, "{string:?}"),
+                    QuickCheck::Maybe => {}
+                }
+            }
+        }
+
+        // Characters not listed in part 1 are unchanged by every normalization.
+        let mut buf = [0; 4];
+        for ch in '\0'..=char::MAX {
+            if !part1.contains(&ch) {
+                let string = &*ch.encode_utf8(&mut buf);
+                assert!(matches!(normalize_ident_nfc(string), Cow::Borrowed(_)));
+                assert!(matches!(normalize_ident_nfkc(string), Cow::Borrowed(_)));
+            }
+        }
+    }
+}
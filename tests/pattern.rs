@@ -0,0 +1,44 @@
+use unicode_ident::{is_id_continue, is_pattern_syntax, is_pattern_white_space};
+
+#[test]
+fn test_pattern_white_space() {
+    let expected = [
+        '\t', '\n', '\u{b}', '\u{c}', '\r', ' ', '\u{85}', '\u{200e}', '\u{200f}', '\u{2028}',
+        '\u{2029}',
+    ];
+    for ch in '\0'..=char::MAX {
+        assert_eq!(is_pattern_white_space(ch), expected.contains(&ch), "{ch:?}");
+    }
+}
+
+#[test]
+fn test_pattern_syntax() {
+    for ch in "!\"#$%&'()*+,-./:;<=>?@[\\]^`{|}~".chars() {
+        assert!(is_pattern_syntax(ch), "{ch:?}");
+    }
+    for ch in [
+        '\u{a7}', '\u{d7}', '\u{2192}', '\u{2200}', '\u{3008}', '\u{fd3e}',
+    ] {
+        assert!(is_pattern_syntax(ch), "{ch:?}");
+    }
+    for ch in ['_', 'a', '0', ' ', '\u{a0}', '\u{b5}', '\u{ff0b}'] {
+        assert!(!is_pattern_syntax(ch), "{ch:?}");
+    }
+}
+
+#[test]
+fn test_disjoint() {
+    let mut syntax = 0;
+    for ch in '\0'..=char::MAX {
+        if is_pattern_syntax(ch) {
+            syntax += 1;
+            assert!(!is_pattern_white_space(ch), "{ch:?}");
+            assert!(!is_id_continue(ch), "{ch:?}");
+        }
+        if is_pattern_white_space(ch) {
+            assert!(!is_id_continue(ch), "{ch:?}");
+        }
+    }
+    // Pattern_Syntax is immutable, so its size never changes.
+    assert_eq!(syntax, 2760);
+}
@@ -0,0 +1,121 @@
+use unicode_ident::{is_default_ignorable, is_ident, IdentProfile};
+
+#[test]
+fn test_default_ignorable() {
+    let mut count = 0;
+    for ch in '\0'..=char::MAX {
+        if is_default_ignorable(ch) {
+            count += 1;
+        }
+    }
+    assert_eq!(count, 4174);
+
+    for ch in [
+        '\u{ad}',
+        '\u{34f}',
+        '\u{200d}',
+        '\u{fe0f}',
+        '\u{1d173}',
+        '\u{e0001}',
+    ] {
+        assert!(is_default_ignorable(ch), "{ch:?}");
+    }
+    for ch in ['a', ' ', '\u{a0}', '\u{2028}', '\u{1d172}'] {
+        assert!(!is_default_ignorable(ch), "{ch:?}");
+    }
+}
+
+#[test]
+fn test_default_syntax() {
+    const PROFILE: IdentProfile = IdentProfile::new();
+    for ident in [
+        "a",
+        "_a",
+        "a1",
+        "\u{3c0}",
+        "a\u{200c}b",
+        "_",
+        "1",
+        "",
+        "a-b",
+    ] {
+        assert_eq!(PROFILE.is_valid(ident), is_ident(ident), "{ident:?}");
+    }
+}
+
+#[test]
+fn test_additions() {
+    const PROFILE: IdentProfile = IdentProfile::new()
+        .allow_start(&['_', '$'])
+        .allow_continue(&['-']);
+
+    for ident in ["$", "$scope", "a$b", "kebab-case", "a-", "_"] {
+        assert!(PROFILE.is_valid(ident), "{ident:?}");
+    }
+    for (ident, offset) in [("-flag", 0), ("a b", 1), ("a+b", 1)] {
+        let err = PROFILE.validate(ident).unwrap_err();
+        assert_eq!(err.offset(), offset, "{ident:?}");
+    }
+    assert_eq!(PROFILE.scan("kebab-case = 1"), 10);
+}
+
+#[test]
+fn test_removals() {
+    const PROFILE: IdentProfile = IdentProfile::new()
+        .allow_start(&['$'])
+        .disallow(&['$', '\u{b7}']);
+    const NO_IGNORABLE: IdentProfile = IdentProfile::new().exclude_default_ignorable();
+    // Explicit additions take precedence over the exclusion.
+    const ZWJ: IdentProfile = NO_IGNORABLE.allow_continue(&['\u{200d}']);
+
+    assert!(!PROFILE.is_valid("$"));
+    assert!(!PROFILE.is_valid("a\u{b7}b"));
+    assert!(PROFILE.is_valid("ab"));
+
+    for ch in '\0'..=char::MAX {
+        if is_default_ignorable(ch) {
+            assert!(!NO_IGNORABLE.is_continue(ch), "{ch:?}");
+        }
+    }
+    assert!(!NO_IGNORABLE.is_valid("a\u{200c}b"));
+    assert!(!NO_IGNORABLE.is_valid("a\u{fe00}"));
+    assert!(!NO_IGNORABLE.is_valid("\u{3164}"));
+
+    assert!(ZWJ.is_valid("a\u{200d}b"));
+}
+
+#[test]
+fn test_id_properties() {
+    const PROFILE: IdentProfile = IdentProfile::new().id_properties();
+    // U+037A GREEK YPOGEGRAMMENI is ID_Start but not XID_Start.
+    assert!(PROFILE.is_valid("\u{37a}"));
+    assert!(!IdentProfile::new().is_valid("\u{37a}"));
+}
+
+#[test]
+fn test_emoji() {
+    const PROFILE: IdentProfile = IdentProfile::new().with_emoji();
+    // Emoji sequences are made of default ignorable joiners and selectors.
+    const NO_IGNORABLE: IdentProfile = PROFILE.exclude_default_ignorable();
+
+    assert!(PROFILE.is_valid("\u{1f680}_launch"));
+    assert!(NO_IGNORABLE.is_valid("\u{1f469}\u{200d}\u{1f4bb}"));
+    assert!(!NO_IGNORABLE.is_valid("a\u{200d}b"));
+}
+
+#[test]
+fn test_emoji_disallow() {
+    const NO_ROCKET: IdentProfile = IdentProfile::new().disallow(&['\u{1f680}']).with_emoji();
+    const NO_ZWJ: IdentProfile = IdentProfile::new().disallow(&['\u{200d}']).with_emoji();
+
+    let err = NO_ROCKET.validate("a\u{1f680}").unwrap_err();
+    assert_eq!(err.offset(), 1);
+    assert_eq!(err.ch(), Some('\u{1f680}'));
+    assert!(NO_ROCKET.is_valid("a\u{2705}"));
+
+    // The woman emoji on its own is fine, but not joined to the laptop.
+    let err = NO_ZWJ.validate("\u{1f469}\u{200d}\u{1f4bb}").unwrap_err();
+    assert_eq!(err.offset(), 4);
+    assert_eq!(err.ch(), Some('\u{200d}'));
+    assert!(NO_ZWJ.is_valid("\u{1f469}\u{1f4bb}"));
+}
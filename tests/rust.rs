@@ -0,0 +1,58 @@
+use unicode_ident::rust::{is_keyword, is_rust_ident, Edition};
+
+const EDITIONS: [Edition; 4] = [
+    Edition::Edition2015,
+    Edition::Edition2018,
+    Edition::Edition2021,
+    Edition::Edition2024,
+];
+
+#[test]
+fn test_ident() {
+    for edition in EDITIONS {
+        for ident in [
+            "a",
+            "_a",
+            "\u{3c0}",
+            "union",
+            "macro_rules",
+            "r#fn",
+            "r#async",
+            "r#gen",
+        ] {
+            assert!(is_rust_ident(ident, edition), "{edition:?} {ident:?}");
+        }
+        for ident in [
+            "", "_", "1a", "a-b", "fn", "Self", "yield", "r#", "r#_", "r#1",
+        ] {
+            assert!(!is_rust_ident(ident, edition), "{edition:?} {ident:?}");
+        }
+        for ident in ["r#crate", "r#self", "r#super", "r#Self"] {
+            assert!(!is_rust_ident(ident, edition), "{edition:?} {ident:?}");
+        }
+    }
+}
+
+#[test]
+fn test_edition_keywords() {
+    for (word, since) in [
+        ("async", Edition::Edition2018),
+        ("await", Edition::Edition2018),
+        ("dyn", Edition::Edition2018),
+        ("try", Edition::Edition2018),
+        ("gen", Edition::Edition2024),
+    ] {
+        for edition in EDITIONS {
+            assert_eq!(
+                is_keyword(word, edition),
+                edition >= since,
+                "{edition:?} {word:?}"
+            );
+            assert_eq!(
+                is_rust_ident(word, edition),
+                edition < since,
+                "{edition:?} {word:?}"
+            );
+        }
+    }
+}
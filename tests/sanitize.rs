@@ -0,0 +1,70 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::Cow;
+use unicode_ident::{sanitize_ident, IdentProfile};
+
+const PROFILE: IdentProfile = IdentProfile::new()
+    .allow_start(&['_'])
+    .keywords(&["type", "type_", "match"]);
+
+#[test]
+fn test_unchanged() {
+    for ident in [
+        "a",
+        "_a",
+        "first_name",
+        "\u{3c0}",
+        "gr\u{f6}\u{df}e",
+        "types",
+    ] {
+        let sanitized = sanitize_ident(ident, &PROFILE);
+        assert!(matches!(sanitized, Cow::Borrowed(_)), "{ident:?}");
+        assert_eq!(sanitized, ident);
+    }
+}
+
+#[test]
+fn test_sanitize() {
+    for (text, expected) in [
+        ("", "_"),
+        ("first name", "first_name"),
+        ("2fa", "_2fa"),
+        ("-flag", "_flag"),
+        ("a.b.c", "a_b_c"),
+        ("price $", "price__"),
+        ("\u{1f680}", "_"),
+        ("\u{1f680}launch", "_launch"),
+        ("caf\u{e9} cr\u{e8}me", "caf\u{e9}_cr\u{e8}me"),
+        ("match", "match_"),
+        // Appending to a keyword must not produce another keyword.
+        ("type", "type__"),
+    ] {
+        let sanitized = sanitize_ident(text, &PROFILE);
+        assert_eq!(sanitized, expected, "{text:?}");
+        assert!(PROFILE.is_valid(&sanitized), "{text:?}");
+    }
+}
+
+#[test]
+fn test_other_profiles() {
+    // '_' is not XID_Start, so the Default Identifier Syntax gets 'x' instead.
+    assert_eq!(sanitize_ident("2fa", &IdentProfile::new()), "x2fa");
+    assert_eq!(sanitize_ident("_", &IdentProfile::new()), "x_");
+
+    let emoji = IdentProfile::new().with_emoji();
+    assert_eq!(
+        sanitize_ident("\u{1f680} launch", &emoji),
+        "\u{1f680}_launch"
+    );
+
+    let join = IdentProfile::new().restrict_join_controls();
+    assert_eq!(sanitize_ident("a\u{200c}b", &join), "a_b");
+
+    let no_underscore = IdentProfile::new().disallow(&['_']);
+    assert_eq!(sanitize_ident("a b", &no_underscore), "axb");
+
+    let neither = IdentProfile::new().disallow(&['_', 'x']);
+    assert_eq!(sanitize_ident("a b", &neither), "a0b");
+    assert_eq!(sanitize_ident("2fa", &neither), "A2fa");
+    assert_eq!(sanitize_ident("", &neither), "A");
+}
@@ -0,0 +1,88 @@
+use unicode_ident::{script, script_extensions, Script, ScriptSet};
+
+#[test]
+fn test_script() {
+    assert_eq!(script('a'), Script::Latin);
+    assert_eq!(script('Z'), Script::Latin);
+    assert_eq!(script('_'), Script::Common);
+    assert_eq!(script('0'), Script::Common);
+    assert_eq!(script('\u{e9}'), Script::Latin);
+    assert_eq!(script('\u{430}'), Script::Cyrillic);
+    assert_eq!(script('\u{3b1}'), Script::Greek);
+    assert_eq!(script('\u{300}'), Script::Inherited);
+    assert_eq!(script('\u{4e2d}'), Script::Han);
+    assert_eq!(script('\u{3042}'), Script::Hiragana);
+    assert_eq!(script('\u{378}'), Script::Unknown);
+    assert_eq!(script('\u{e0100}'), Script::Inherited);
+    assert_eq!(script(char::MAX), Script::Unknown);
+}
+
+#[test]
+fn test_script_names() {
+    assert_eq!(Script::Latin.short_name(), "Latn");
+    assert_eq!(Script::Latin.long_name(), "Latin");
+    assert_eq!(Script::OldItalic.short_name(), "Ital");
+    assert_eq!(Script::OldItalic.long_name(), "Old_Italic");
+    assert_eq!(Script::Common.short_name(), "Zyyy");
+    assert_eq!(Script::Inherited.short_name(), "Zinh");
+    assert_eq!(Script::Unknown.short_name(), "Zzzz");
+}
+
+#[test]
+fn test_script_extensions() {
+    let latin = script_extensions('a');
+    assert_eq!(latin, ScriptSet::from(Script::Latin));
+    assert_eq!(latin.len(), 1);
+
+    let prolonged_sound_mark = script_extensions('\u{30fc}');
+    let expected = ScriptSet::from_iter([Script::Hiragana, Script::Katakana]);
+    assert_eq!(prolonged_sound_mark, expected);
+    assert_eq!(
+        prolonged_sound_mark.iter().collect::<Vec<_>>(),
+        [Script::Hiragana, Script::Katakana],
+    );
+
+    let arabic_indic_digit = script_extensions('\u{660}');
+    assert!(arabic_indic_digit.contains(Script::Arabic));
+    assert!(arabic_indic_digit.contains(Script::Thaana));
+    assert!(!arabic_indic_digit.contains(Script::Common));
+}
+
+#[test]
+fn test_consistent() {
+    for ch in '\0'..=char::MAX {
+        let script = script(ch);
+        let extensions = script_extensions(ch);
+        assert!(!extensions.is_empty(), "{ch:?}");
+        if let Script::Common | Script::Inherited = script {
+            if extensions.len() > 1 {
+                assert!(!extensions.contains(script), "{ch:?}");
+            }
+        } else {
+            assert!(extensions.contains(script), "{ch:?}");
+        }
+    }
+}
+
+#[test]
+fn test_script_set() {
+    let mut set = ScriptSet::new();
+    assert!(set.is_empty());
+    set.insert(Script::Latin);
+    set.insert(Script::Yi);
+    set.insert(Script::Adlam);
+    assert_eq!(set.len(), 3);
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        [Script::Adlam, Script::Latin, Script::Yi],
+    );
+    assert_eq!(format!("{set:?}"), "{Adlam, Latin, Yi}");
+
+    let other = ScriptSet::from_iter([Script::Latin, Script::Cyrillic]);
+    assert_eq!(set.intersection(&other), ScriptSet::from(Script::Latin));
+    assert_eq!(set.union(&other).len(), 4);
+
+    set.remove(Script::Latin);
+    assert!(!set.contains(Script::Latin));
+    assert_eq!(set.len(), 2);
+}
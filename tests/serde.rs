@@ -0,0 +1,45 @@
+#![cfg(feature = "serde")]
+
+use unicode_ident::Ident;
+
+#[test]
+fn test_borrowed() {
+    let ident: &Ident = serde_json::from_str("\"\u{3bb}_expr\"").unwrap();
+    assert_eq!(ident, "\u{3bb}_expr");
+    assert_eq!(serde_json::to_string(ident).unwrap(), "\"\u{3bb}_expr\"");
+
+    let err = serde_json::from_str::<&Ident>("\"foo-bar\"").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid identifier \"foo-bar\": '-' at byte offset 3 is not XID_Continue at line 1 column 9",
+    );
+
+    // Escapes require an owned string.
+    let err = serde_json::from_str::<&Ident>("\"\\u03bb\"").unwrap_err();
+    assert!(err.to_string().starts_with("invalid type: string"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_owned() {
+    use unicode_ident::IdentBuf;
+
+    let ident: IdentBuf = serde_json::from_str("\"\\u03bb_expr\"").unwrap();
+    assert_eq!(ident, "\u{3bb}_expr");
+    assert_eq!(serde_json::to_string(&ident).unwrap(), "\"\u{3bb}_expr\"");
+
+    for (json, message) in [
+        ("\"\"", "invalid identifier: empty string at line 1 column 2"),
+        (
+            "\"1st\"",
+            "invalid identifier \"1st\": '1' at byte offset 0 is not XID_Start at line 1 column 5",
+        ),
+        (
+            "\"\\u00e9t\\u00e9 \"",
+            "invalid identifier \"\u{e9}t\u{e9} \": ' ' at byte offset 5 is not XID_Continue at line 1 column 16",
+        ),
+    ] {
+        let err = serde_json::from_str::<IdentBuf>(json).unwrap_err();
+        assert_eq!(err.to_string(), message, "{json}");
+    }
+}
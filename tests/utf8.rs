@@ -0,0 +1,63 @@
+use unicode_ident::{is_xid_continue, is_xid_continue_utf8, is_xid_start, is_xid_start_utf8};
+
+#[test]
+fn test_all_chars() {
+    let mut buf = [0u8; 4];
+    for ch in '\0'..=char::MAX {
+        let len = ch.len_utf8();
+        let utf8 = ch.encode_utf8(&mut buf).as_bytes();
+        assert_eq!(
+            is_xid_start_utf8(utf8),
+            Some((is_xid_start(ch), len)),
+            "{ch:?}",
+        );
+        assert_eq!(
+            is_xid_continue_utf8(utf8),
+            Some((is_xid_continue(ch), len)),
+            "{ch:?}",
+        );
+    }
+}
+
+#[test]
+fn test_trailing_bytes() {
+    assert_eq!(is_xid_start_utf8(b"a+"), Some((true, 1)));
+    assert_eq!(
+        is_xid_start_utf8("\u{e9}\u{301}".as_bytes()),
+        Some((true, 2))
+    );
+    assert_eq!(is_xid_start_utf8("\u{301}x".as_bytes()), Some((false, 2)));
+    assert_eq!(is_xid_continue_utf8("\u{301}x".as_bytes()), Some((true, 2)));
+    assert_eq!(
+        is_xid_start_utf8("\u{4e00}\u{ff}".as_bytes()),
+        Some((true, 3))
+    );
+    assert_eq!(is_xid_start_utf8(b"\xF0\x9F\x98\x80\xFF"), Some((false, 4)));
+}
+
+#[test]
+fn test_malformed() {
+    for utf8 in [
+        &b""[..],
+        b"\x80",
+        b"\xBF",
+        b"\xC0\x80",
+        b"\xC1\xBF",
+        b"\xC3",
+        b"\xC3\x28",
+        b"\xE0\x80\x80",
+        b"\xE0\x9F\xBF",
+        b"\xE4\xB8",
+        b"\xED\xA0\x80",
+        b"\xED\xBF\xBF",
+        b"\xF0\x80\x80\x80",
+        b"\xF0\x8F\xBF\xBF",
+        b"\xF0\x9F\x98",
+        b"\xF4\x90\x80\x80",
+        b"\xF5\x80\x80\x80",
+        b"\xFF",
+    ] {
+        assert_eq!(is_xid_start_utf8(utf8), None, "{utf8:x?}");
+        assert_eq!(is_xid_continue_utf8(utf8), None, "{utf8:x?}");
+    }
+}